@@ -18,9 +18,9 @@ pub async fn handle(command: &DatabaseCommand) -> anyhow::Result<()> {
       ..
     } => restore(restore_folder.as_deref(), *index, *yes).await,
     DatabaseCommand::Prune { yes, .. } => prune(*yes).await,
-    DatabaseCommand::Copy { yes, index, .. } => {
-      copy(*index, *yes).await
-    }
+    DatabaseCommand::Copy {
+      yes, index, resume, ..
+    } => copy(*index, *resume, *yes).await,
   }
 }
 
@@ -34,8 +34,7 @@ async fn backup(yes: bool) -> anyhow::Result<()> {
   );
   println!(
     "\n{}\n",
-    " - Backup all database contents to gzip compressed files."
-      .dimmed()
+    " - Backup all database contents to compressed files.".dimmed()
   );
   if let Some(uri) = optional_string(&config.database.uri) {
     println!("{}: {}", " - Source URI".dimmed(), sanitize_uri(&uri));
@@ -65,12 +64,26 @@ async fn backup(yes: bool) -> anyhow::Result<()> {
   } else {
     println!("{}: {}", " - Max Backups".dimmed(), config.max_backups);
   }
+  println!(
+    "{}: {}",
+    " - Compression".dimmed(),
+    config.backup_compression
+  );
+  if config.backup_encryption_key.is_some() {
+    println!("{}: {}", " - Encryption".dimmed(), "enabled".green());
+  }
 
   crate::command::wait_for_enter("start backup", yes)?;
 
   let db = database::init(&config.database).await?;
 
-  database::utils::backup(&db, &config.backups_folder).await?;
+  database::utils::backup(
+    &db,
+    &config.backups_folder,
+    config.backup_compression,
+    config.backup_encryption_key.as_deref(),
+  )
+  .await?;
 
   // Early return if backup pruning disabled
   if config.max_backups == 0 {
@@ -97,7 +110,7 @@ async fn restore(
   );
   println!(
     "\n{}\n",
-    " - Restores database contents from gzip compressed files."
+    " - Restores database contents from compressed backup files."
       .dimmed()
   );
   if let Some(uri) = optional_string(&config.database_target.uri) {
@@ -146,6 +159,8 @@ async fn restore(
     &db,
     &config.backups_folder,
     restore_folder,
+    config.backup_encryption_key.as_deref(),
+    false,
   )
   .await
 }
@@ -253,7 +268,11 @@ async fn prune_inner() -> anyhow::Result<()> {
   Ok(())
 }
 
-async fn copy(index: bool, yes: bool) -> anyhow::Result<()> {
+async fn copy(
+  index: bool,
+  resume: bool,
+  yes: bool,
+) -> anyhow::Result<()> {
   let config = cli_config();
 
   println!(
@@ -306,6 +325,23 @@ async fn copy(index: bool, yes: bool) -> anyhow::Result<()> {
       "DISABLED".red(),
     );
   }
+  if resume {
+    println!("{}: {}", " - Resume".dimmed(), "ENABLED".green());
+  }
+  if config.copy_batch_size > 0 {
+    println!(
+      "{}: {}",
+      " - Batch Size".dimmed(),
+      config.copy_batch_size
+    );
+  }
+  if !config.copy_collections.is_empty() {
+    println!(
+      "{}: {}",
+      " - Collections".dimmed(),
+      config.copy_collections.join(", ")
+    );
+  }
 
   crate::command::wait_for_enter("start copy", yes)?;
 
@@ -316,5 +352,12 @@ async fn copy(index: bool, yes: bool) -> anyhow::Result<()> {
     database::init(&config.database_target).await?
   };
 
-  database::utils::copy(&source_db, &target_db).await
+  database::utils::copy(
+    &source_db,
+    &target_db,
+    config.copy_batch_size,
+    &config.copy_collections,
+    resume,
+  )
+  .await
 }
@@ -167,6 +167,15 @@ pub async fn handle(
     Execution::PruneSystem(data) => {
       println!("{}: {data:?}", "Data".dimmed())
     }
+    Execution::RunCommandOnGroup(data) => {
+      println!("{}: {data:?}", "Data".dimmed())
+    }
+    Execution::PruneImagesOnGroup(data) => {
+      println!("{}: {data:?}", "Data".dimmed())
+    }
+    Execution::PruneSystemOnGroup(data) => {
+      println!("{}: {data:?}", "Data".dimmed())
+    }
     Execution::RunSync(data) => {
       println!("{}: {data:?}", "Data".dimmed())
     }
@@ -414,6 +423,18 @@ pub async fn handle(
       .execute(request)
       .await
       .map(|u| ExecutionResult::Single(u.into())),
+    Execution::RunCommandOnGroup(request) => client
+      .execute(request)
+      .await
+      .map(|u| ExecutionResult::Single(u.into())),
+    Execution::PruneImagesOnGroup(request) => client
+      .execute(request)
+      .await
+      .map(|u| ExecutionResult::Single(u.into())),
+    Execution::PruneSystemOnGroup(request) => client
+      .execute(request)
+      .await
+      .map(|u| ExecutionResult::Single(u.into())),
     Execution::RunSync(request) => client
       .execute(request)
       .await
@@ -0,0 +1,225 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use colored::Colorize;
+use comfy_table::{Attribute, Cell, Color};
+use futures_util::future::join_all;
+use komodo_client::{
+  api::read::{GetSystemStats, ListFullServers},
+  entities::{
+    ResourceTargetVariant,
+    config::cli::args::server::{ServerCommand, ServerStatus},
+    resource_link,
+    server::{Server, ServerState},
+    stats::SystemStats,
+  },
+};
+use serde::Serialize;
+
+use crate::{
+  command::{
+    PrintTable, matches_wildcards, parse_wildcards, print_items,
+  },
+  config::cli_config,
+};
+
+pub async fn handle(command: &ServerCommand) -> anyhow::Result<()> {
+  match command {
+    ServerCommand::Status(status) => server_status(status).await,
+  }
+}
+
+async fn server_status(status: &ServerStatus) -> anyhow::Result<()> {
+  if !status.watch {
+    return print_server_status(status).await;
+  }
+  let interval = Duration::from_secs(status.interval.max(1));
+  loop {
+    let res = print_server_status_watch(status).await;
+    if let Err(e) = res {
+      println!("{}: {e:#}", "ERROR".red());
+    }
+    tokio::time::sleep(interval).await;
+  }
+}
+
+async fn print_server_status_watch(
+  status: &ServerStatus,
+) -> anyhow::Result<()> {
+  let items = get_server_status(status).await?;
+  // Clear the screen and move cursor to top left
+  print!("\x1b[2J\x1b[H");
+  println!(
+    "Every {}s | {}\n",
+    status.interval.max(1),
+    chrono::Local::now().format("%m/%d %H:%M:%S")
+  );
+  print_items(items, status.format, false)
+}
+
+async fn print_server_status(
+  status: &ServerStatus,
+) -> anyhow::Result<()> {
+  let items = get_server_status(status).await?;
+  if items.is_empty() {
+    println!(
+      "{}: Did not find any servers matching '{}'",
+      "INFO".green(),
+      status.name_pattern.as_deref().unwrap_or("*").bold()
+    );
+    return Ok(());
+  }
+  print_items(items, status.format, false)
+}
+
+async fn get_server_status(
+  ServerStatus {
+    name_pattern,
+    timeout,
+    ..
+  }: &ServerStatus,
+) -> anyhow::Result<Vec<ServerStatusItem>> {
+  let client = super::komodo_client().await?;
+  let names = name_pattern.iter().cloned().collect::<Vec<_>>();
+  let names = parse_wildcards(&names);
+  let mut servers = client
+    .read(ListFullServers::default())
+    .await
+    .context("Failed to list servers")?
+    .into_iter()
+    .filter(|server| {
+      matches_wildcards(&names, &[server.name.as_str()])
+    })
+    .collect::<Vec<_>>();
+  servers.sort_by(|a, b| a.name.cmp(&b.name));
+
+  let timeout = Duration::from_secs(*timeout);
+  let futures = servers.into_iter().map(|server| async move {
+    // Avoid blocking on unreachable servers.
+    let stats = tokio::time::timeout(
+      timeout,
+      client.read(GetSystemStats {
+        server: server.id.clone(),
+      }),
+    )
+    .await;
+    let (state, stats) = match stats {
+      Ok(Ok(stats)) => (ServerState::Ok, Some(stats)),
+      Ok(Err(_)) | Err(_) => {
+        if server.config.enabled {
+          (ServerState::NotOk, None)
+        } else {
+          (ServerState::Disabled, None)
+        }
+      }
+    };
+    ServerStatusItem {
+      state,
+      stats,
+      server,
+    }
+  });
+
+  Ok(join_all(futures).await)
+}
+
+#[derive(Serialize)]
+struct ServerStatusItem {
+  #[serde(skip)]
+  server: Server,
+  state: ServerState,
+  stats: Option<SystemStats>,
+}
+
+impl PrintTable for ServerStatusItem {
+  fn header(links: bool) -> &'static [&'static str] {
+    if links {
+      &["Server", "State", "CPU", "Memory", "Disk", "Link"]
+    } else {
+      &["Server", "State", "CPU", "Memory", "Disk"]
+    }
+  }
+  fn row(self, links: bool) -> Vec<Cell> {
+    let state_color = match self.state {
+      ServerState::Ok => Color::Green,
+      ServerState::NotOk => Color::Red,
+      ServerState::Disabled => Color::Blue,
+    };
+    let config = &self.server.config;
+    let mut res = vec![
+      Cell::new(&self.server.name).add_attribute(Attribute::Bold),
+      Cell::new(self.state.to_string())
+        .fg(state_color)
+        .add_attribute(Attribute::Bold),
+    ];
+    match &self.stats {
+      Some(stats) => {
+        let (disk_used_gb, disk_total_gb) = stats.disks.iter().fold(
+          (0.0, 0.0),
+          |(used, total), disk| {
+            (used + disk.used_gb, total + disk.total_gb)
+          },
+        );
+        res.extend([
+          usage_cell(
+            format!("{:.1}%", stats.cpu_perc),
+            stats.cpu_perc as f64,
+            config.cpu_warning as f64,
+            config.cpu_critical as f64,
+          ),
+          usage_cell(
+            format!(
+              "{:.1} / {:.1} GB",
+              stats.mem_used_gb, stats.mem_total_gb
+            ),
+            percentage(stats.mem_used_gb, stats.mem_total_gb),
+            config.mem_warning,
+            config.mem_critical,
+          ),
+          usage_cell(
+            format!("{disk_used_gb:.1} / {disk_total_gb:.1} GB"),
+            percentage(disk_used_gb, disk_total_gb),
+            config.disk_warning,
+            config.disk_critical,
+          ),
+        ]);
+      }
+      None => {
+        res.extend([Cell::new("-"), Cell::new("-"), Cell::new("-")])
+      }
+    }
+    if links {
+      res.push(Cell::new(resource_link(
+        &cli_config().host,
+        ResourceTargetVariant::Server,
+        &self.server.id,
+      )));
+    }
+    res
+  }
+}
+
+/// Colors the usage based on the server alert thresholds.
+fn usage_cell(
+  text: String,
+  perc: f64,
+  warning: f64,
+  critical: f64,
+) -> Cell {
+  let color = if perc >= critical {
+    Color::Red
+  } else if perc >= warning {
+    Color::DarkYellow
+  } else {
+    Color::Green
+  };
+  Cell::new(text).fg(color)
+}
+
+fn percentage(used: f64, total: f64) -> f64 {
+  if total > 0.0 {
+    100.0 * used / total
+  } else {
+    0.0
+  }
+}
@@ -27,6 +27,7 @@ pub async fn update(
   let Ok(existing) = client
     .read(GetVariable {
       name: name.to_string(),
+      server_id: None,
     })
     .await
   else {
@@ -37,6 +38,7 @@ pub async fn update(
         value: value.to_string(),
         is_secret: secret.unwrap_or_default(),
         description: Default::default(),
+        server_id: None,
       })
       .await
       .context("Failed to create variable")?;
@@ -48,6 +50,7 @@ pub async fn update(
     .write(UpdateVariableValue {
       name: name.to_string(),
       value: value.to_string(),
+      server_id: None,
     })
     .await
     .context("Failed to update variable 'value'")?;
@@ -60,6 +63,7 @@ pub async fn update(
       .write(UpdateVariableIsSecret {
         name: name.to_string(),
         is_secret: secret,
+        server_id: None,
       })
       .await
       .context("Failed to update variable 'is_secret'")?;
@@ -109,6 +109,12 @@ pub fn cli_config() -> &'static CliConfig {
       } => backups_folder.clone(),
       _ => None,
     };
+    let compression = match &args.command {
+      Command::Database {
+        command: DatabaseCommand::Backup { compression, .. },
+      } => *compression,
+      _ => None,
+    };
     let (uri, address, username, password, db_name) =
       match &args.command {
         Command::Database {
@@ -130,6 +136,17 @@ pub fn cli_config() -> &'static CliConfig {
         ),
         _ => (None, None, None, None, None),
       };
+    let (copy_batch_size, copy_collections) = match &args.command {
+      Command::Database {
+        command:
+          DatabaseCommand::Copy {
+            batch_size,
+            collections,
+            ..
+          },
+      } => (*batch_size, collections.clone()),
+      _ => (None, None),
+    };
 
     let profile = args
       .profile
@@ -208,6 +225,19 @@ pub fn cli_config() -> &'static CliConfig {
       max_backups: env
         .komodo_cli_max_backups
         .unwrap_or(config.max_backups),
+      backup_compression: compression
+        .or(env.komodo_cli_backup_compression)
+        .unwrap_or(config.backup_compression),
+      backup_encryption_key: maybe_read_item_from_file(
+        env.komodo_cli_backup_encryption_key_file,
+        env.komodo_cli_backup_encryption_key,
+      ),
+      copy_batch_size: copy_batch_size
+        .or(env.komodo_cli_copy_batch_size)
+        .unwrap_or(config.copy_batch_size),
+      copy_collections: copy_collections
+        .or(env.komodo_cli_copy_collections)
+        .unwrap_or(config.copy_collections),
       database_target: DatabaseConfig {
         uri: uri
           .or(env.komodo_cli_database_target_uri)
@@ -225,6 +255,7 @@ pub fn cli_config() -> &'static CliConfig {
           .or(env.komodo_cli_database_target_db_name)
           .unwrap_or(config.database_target.db_name),
         app_name: config.database_target.app_name,
+        ..config.database_target
       },
       database: DatabaseConfig {
         uri: maybe_read_item_from_file(
@@ -249,6 +280,7 @@ pub fn cli_config() -> &'static CliConfig {
           .komodo_database_db_name
           .unwrap_or(config.database.db_name),
         app_name: config.database.app_name,
+        ..config.database
       },
       cli_logging: LogConfig {
         level: env
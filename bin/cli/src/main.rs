@@ -51,6 +51,9 @@ async fn app() -> anyhow::Result<()> {
     args::Command::Execute(args) => {
       command::execute::handle(&args.execution, args.yes).await
     }
+    args::Command::Server { command } => {
+      command::server::handle(command).await
+    }
     args::Command::Update { command } => {
       command::update::handle(command).await
     }
@@ -106,6 +106,21 @@ pub async fn send_alert(
         "{level} | **{name}**{region} disk usage at **{percentage:.1}%** 💿\nmount point: `{path:?}`\nusing **{used_gb:.1} GiB** / **{total_gb:.1} GiB**\n{link}"
       )
     }
+    AlertData::ServerInodes {
+      id,
+      name,
+      region,
+      path,
+      used,
+      total,
+    } => {
+      let region = fmt_region(region);
+      let link = resource_link(ResourceTargetVariant::Server, id);
+      let percentage = 100.0 * *used as f64 / *total as f64;
+      format!(
+        "{level} | **{name}**{region} inode usage at **{percentage:.1}%** 💿\nmount point: `{path:?}`\nusing **{used}** / **{total}** inodes\n{link}"
+      )
+    }
     AlertData::ContainerStateChange {
       id,
       name,
@@ -186,6 +201,24 @@ pub async fn send_alert(
         "⬆ Stack **{name}** was updated automatically ⏫\nserver: **{server_name}**\n{images_label}: **{images}**\n{link}"
       )
     }
+    AlertData::ContainerUnhealthy {
+      id,
+      name,
+      resource_type,
+      server_id: _server_id,
+      server_name,
+      container,
+      service,
+    } => {
+      let link = resource_link(*resource_type, id);
+      let service = service
+        .as_ref()
+        .map(|service| format!("\nservice: **{service}**"))
+        .unwrap_or_default();
+      format!(
+        "{level} | 🩺 {resource_type} **{name}** container **{container}** is **unhealthy**\nserver: **{server_name}**{service}\n{link}"
+      )
+    }
     AlertData::AwsBuilderTerminationFailed {
       instance_id,
       message,
@@ -243,7 +276,7 @@ pub async fn send_alert(
   };
   if !content.is_empty() {
     let VariablesAndSecrets { variables, secrets } =
-      get_variables_and_secrets().await?;
+      get_variables_and_secrets(None).await?;
     let mut url_interpolated = url.to_string();
 
     let mut interpolator =
@@ -251,12 +284,13 @@ pub async fn send_alert(
 
     interpolator.interpolate_string(&mut url_interpolated)?;
 
-    send_message(&url_interpolated, &content)
-      .await
-      .map_err(|e| {
+    // Long alerts are sent as multiple messages, in order.
+    for chunk in split_message(&content) {
+      send_message(&url_interpolated, chunk).await.map_err(|e| {
         let replacers = interpolator
           .secret_replacers
-          .into_iter()
+          .iter()
+          .cloned()
           .collect::<Vec<_>>();
         let sanitized_error =
           svi::replace_in_string(&format!("{e:?}"), &replacers);
@@ -264,10 +298,39 @@ pub async fn send_alert(
           "Error with slack request: {sanitized_error}"
         ))
       })?;
+    }
   }
   Ok(())
 }
 
+/// Discord rejects messages with content longer than this.
+const MAX_MESSAGE_LENGTH: usize = 2000;
+
+/// Splits the content into chunks under the Discord message limit,
+/// breaking on line boundaries where possible. Measured in bytes,
+/// which is never less than the character count Discord uses.
+fn split_message(content: &str) -> Vec<&str> {
+  let mut chunks = Vec::new();
+  let mut rest = content;
+  while rest.len() > MAX_MESSAGE_LENGTH {
+    let mut end = MAX_MESSAGE_LENGTH;
+    while !rest.is_char_boundary(end) {
+      end -= 1;
+    }
+    if let Some(newline) = rest[..end].rfind('\n')
+      && newline > 0
+    {
+      end = newline;
+    }
+    chunks.push(&rest[..end]);
+    rest = rest[end..].strip_prefix('\n').unwrap_or(&rest[end..]);
+  }
+  if !rest.is_empty() {
+    chunks.push(rest);
+  }
+  chunks
+}
+
 async fn send_message(
   url: &str,
   content: &str,
@@ -304,3 +367,46 @@ fn http_client() -> &'static reqwest::Client {
 struct DiscordMessageBody<'a> {
   content: &'a str,
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn splits_long_alert_on_line_boundaries() {
+    let header = "🚨 **CRITICAL** | Stack **app** is unhealthy";
+    let trace = (0..100)
+      .map(|i| format!("{i:03}: {}", "x".repeat(44)))
+      .collect::<Vec<_>>()
+      .join("\n");
+    let content = format!("{header}\n{trace}");
+    assert!(content.len() >= 5000);
+
+    let chunks = split_message(&content);
+
+    assert_eq!(chunks.len(), 3);
+    assert!(
+      chunks.iter().all(|chunk| chunk.len() <= MAX_MESSAGE_LENGTH)
+    );
+    assert!(chunks[0].starts_with(header));
+    // Broken only on line boundaries, preserving order
+    assert_eq!(chunks.join("\n"), content);
+  }
+
+  #[test]
+  fn splits_long_line_at_limit() {
+    let content = "é".repeat(2500);
+    let chunks = split_message(&content);
+    assert_eq!(chunks.len(), 3);
+    assert!(
+      chunks.iter().all(|chunk| chunk.len() <= MAX_MESSAGE_LENGTH)
+    );
+    assert_eq!(chunks.concat(), content);
+  }
+
+  #[test]
+  fn short_alert_is_one_message() {
+    assert_eq!(split_message("short"), ["short"]);
+    assert!(split_message("").is_empty());
+  }
+}
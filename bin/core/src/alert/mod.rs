@@ -2,6 +2,7 @@ use ::slack::types::Block;
 use anyhow::{Context, anyhow};
 use database::mungos::{find::find_collect, mongodb::bson::doc};
 use derive_variants::ExtractVariant;
+use formatting::{Color, colored};
 use futures::future::join_all;
 use interpolate::Interpolator;
 use komodo_client::entities::{
@@ -125,8 +126,8 @@ pub async fn send_alert_to_alerter(
         )
       })
     }
-    AlerterEndpoint::Slack(SlackAlerterEndpoint { url }) => {
-      slack::send_alert(url, alert).await.with_context(|| {
+    AlerterEndpoint::Slack(endpoint) => {
+      slack::send_alert(endpoint, alert).await.with_context(|| {
         format!(
           "Failed to send alert to Slack Alerter {}",
           alerter.name
@@ -168,7 +169,7 @@ async fn send_custom_alert(
   alert: &Alert,
 ) -> anyhow::Result<()> {
   let VariablesAndSecrets { variables, secrets } =
-    get_variables_and_secrets().await?;
+    get_variables_and_secrets(None).await?;
   let mut url_interpolated = url.to_string();
 
   let mut interpolator =
@@ -241,6 +242,16 @@ fn fmt_level(level: SeverityLevel) -> &'static str {
   }
 }
 
+/// The alert level colored for display in update logs.
+pub fn colored_level(level: SeverityLevel) -> String {
+  let color = match level {
+    SeverityLevel::Critical => Color::Red,
+    SeverityLevel::Warning => Color::Yellow,
+    SeverityLevel::Ok => Color::Green,
+  };
+  colored(level, color)
+}
+
 fn resource_link(
   resource_type: ResourceTargetVariant,
   id: &str,
@@ -350,6 +361,21 @@ fn standard_alert_content(alert: &Alert) -> String {
         "{level} | {name}{region} disk usage at {percentage:.1}%💿\nmount point: {path:?}\nusing {used_gb:.1} GiB / {total_gb:.1} GiB\n{link}",
       )
     }
+    AlertData::ServerInodes {
+      id,
+      name,
+      region,
+      path,
+      used,
+      total,
+    } => {
+      let region = fmt_region(region);
+      let link = resource_link(ResourceTargetVariant::Server, id);
+      let percentage = 100.0 * *used as f64 / *total as f64;
+      format!(
+        "{level} | {name}{region} inode usage at {percentage:.1}%💿\nmount point: {path:?}\nusing {used} / {total} inodes\n{link}",
+      )
+    }
     AlertData::ContainerStateChange {
       id,
       name,
@@ -430,6 +456,24 @@ fn standard_alert_content(alert: &Alert) -> String {
         "⬆ Stack {name} was updated automatically ⏫\nserver: {server_name}\n{images_label}: {images_str}\n{link}",
       )
     }
+    AlertData::ContainerUnhealthy {
+      id,
+      name,
+      resource_type,
+      server_id: _server_id,
+      server_name,
+      container,
+      service,
+    } => {
+      let link = resource_link(*resource_type, id);
+      let service = service
+        .as_ref()
+        .map(|service| format!("\nservice: {service}"))
+        .unwrap_or_default();
+      format!(
+        "{level} | 🩺 {resource_type} {name} container {container} is unhealthy\nserver: {server_name}{service}\n{link}",
+      )
+    }
     AlertData::AwsBuilderTerminationFailed {
       instance_id,
       message,
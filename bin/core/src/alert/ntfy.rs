@@ -10,7 +10,7 @@ pub async fn send_alert(
 ) -> anyhow::Result<()> {
   let content = standard_alert_content(alert);
   if !content.is_empty() {
-    send_message(url, email, content).await?;
+    send_message(url, email, alert.level, content).await?;
   }
   Ok(())
 }
@@ -18,19 +18,13 @@ pub async fn send_alert(
 async fn send_message(
   url: &str,
   email: Option<&str>,
+  level: SeverityLevel,
   content: String,
 ) -> anyhow::Result<()> {
-  let mut request = http_client()
-    .post(url)
-    .header("Title", "ntfy Alert")
-    .body(content);
-
-  if let Some(email) = email {
-    request = request.header("X-Email", email);
-  }
-
-  let response =
-    request.send().await.context("Failed to send message")?;
+  let response = message_request(url, email, level, content)
+    .send()
+    .await
+    .context("Failed to send message")?;
 
   let status = response.status();
   if status.is_success() {
@@ -50,7 +44,83 @@ async fn send_message(
   }
 }
 
+fn message_request(
+  url: &str,
+  email: Option<&str>,
+  level: SeverityLevel,
+  content: String,
+) -> reqwest::RequestBuilder {
+  let (priority, tags) = priority_and_tags(level);
+  let mut request = http_client()
+    .post(url)
+    .header("Title", "ntfy Alert")
+    .header("Priority", priority)
+    .header("Tags", tags)
+    .body(content);
+
+  if let Some(email) = email {
+    request = request.header("X-Email", email);
+  }
+
+  request
+}
+
+/// Maps the severity to the ntfy priority (1-5) and emoji tags,
+/// which drive how the phone notification behaves.
+fn priority_and_tags(
+  level: SeverityLevel,
+) -> (&'static str, &'static str) {
+  match level {
+    SeverityLevel::Critical => ("5", "rotating_light"),
+    SeverityLevel::Warning => ("4", "warning"),
+    SeverityLevel::Ok => ("3", "white_check_mark"),
+  }
+}
+
 fn http_client() -> &'static reqwest::Client {
   static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
   CLIENT.get_or_init(reqwest::Client::new)
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn headers(
+    email: Option<&str>,
+    level: SeverityLevel,
+  ) -> reqwest::header::HeaderMap {
+    message_request(
+      "https://ntfy.sh/komodo",
+      email,
+      level,
+      String::from("alert"),
+    )
+    .build()
+    .unwrap()
+    .headers()
+    .clone()
+  }
+
+  #[test]
+  fn headers_per_severity() {
+    for (level, priority, tags) in [
+      (SeverityLevel::Critical, "5", "rotating_light"),
+      (SeverityLevel::Warning, "4", "warning"),
+      (SeverityLevel::Ok, "3", "white_check_mark"),
+    ] {
+      let headers = headers(None, level);
+      assert_eq!(headers["Priority"], priority, "{level:?}");
+      assert_eq!(headers["Tags"], tags, "{level:?}");
+      assert!(!headers.contains_key("X-Email"));
+    }
+  }
+
+  #[test]
+  fn email_header_forwarded() {
+    let headers =
+      headers(Some("ops@example.com"), SeverityLevel::Critical);
+    assert_eq!(headers["X-Email"], "ops@example.com");
+    assert_eq!(headers["Priority"], "5");
+  }
+}
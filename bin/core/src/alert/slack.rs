@@ -1,8 +1,12 @@
+use std::sync::OnceLock;
+
+use serde::Serialize;
+
 use super::*;
 
 #[instrument(level = "debug")]
 pub async fn send_alert(
-  url: &str,
+  endpoint: &SlackAlerterEndpoint,
   alert: &Alert,
 ) -> anyhow::Result<()> {
   let level = fmt_level(alert.level);
@@ -236,6 +240,34 @@ pub async fn send_alert(
         }
       }
     }
+    AlertData::ServerInodes {
+      id,
+      name,
+      region,
+      path,
+      used,
+      total,
+    } => {
+      let region = fmt_region(region);
+      let percentage = 100.0 * *used as f64 / *total as f64;
+      let text = format!(
+        "{level} | *{name}*{region} inode usage at *{percentage:.1}%* | mount point: *{path:?}* 💿"
+      );
+      let blocks = vec![
+        Block::header(level),
+        Block::section(format!(
+          "*{name}*{region} inode usage at *{percentage:.1}%* 💿"
+        )),
+        Block::section(format!(
+          "mount point: {path:?} | using *{used}* / *{total}* inodes"
+        )),
+        Block::section(resource_link(
+          ResourceTargetVariant::Server,
+          id,
+        )),
+      ];
+      (text, blocks.into())
+    }
     AlertData::ContainerStateChange {
       name,
       server_name,
@@ -367,6 +399,29 @@ pub async fn send_alert(
       ];
       (text, blocks.into())
     }
+    AlertData::ContainerUnhealthy {
+      id,
+      name,
+      resource_type,
+      server_name,
+      container,
+      service,
+      ..
+    } => {
+      let text = format!(
+        "{level} | 🩺 {resource_type} *{name}* container *{container}* is *unhealthy*"
+      );
+      let service = service
+        .as_ref()
+        .map(|service| format!("\nservice: {service}"))
+        .unwrap_or_default();
+      let blocks = vec![
+        Block::header(text.clone()),
+        Block::section(format!("server: {server_name}{service}")),
+        Block::section(resource_link(*resource_type, id)),
+      ];
+      (text, blocks.into())
+    }
     AlertData::AwsBuilderTerminationFailed {
       instance_id,
       message,
@@ -468,16 +523,17 @@ pub async fn send_alert(
   };
   if !text.is_empty() {
     let VariablesAndSecrets { variables, secrets } =
-      get_variables_and_secrets().await?;
-    let mut url_interpolated = url.to_string();
+      get_variables_and_secrets(None).await?;
+    let mut url_interpolated = endpoint.url.clone();
 
     let mut interpolator =
       Interpolator::new(Some(&variables), &secrets);
 
     interpolator.interpolate_string(&mut url_interpolated)?;
 
-    let slack = ::slack::Client::new(url_interpolated);
-    slack.send_message(text, blocks).await.map_err(|e| {
+    let body = SlackMessageBody::new(endpoint, &text, blocks);
+
+    send_message(&url_interpolated, &body).await.map_err(|e| {
       let replacers = interpolator
         .secret_replacers
         .into_iter()
@@ -491,3 +547,112 @@ pub async fn send_alert(
   }
   Ok(())
 }
+
+async fn send_message(
+  url: &str,
+  body: &SlackMessageBody<'_>,
+) -> anyhow::Result<()> {
+  let response = http_client()
+    .post(url)
+    .json(body)
+    .send()
+    .await
+    .context("Failed to send message")?;
+
+  let status = response.status();
+
+  if status.is_success() {
+    Ok(())
+  } else {
+    let text = response.text().await.with_context(|| {
+      format!("Failed to send message to Slack | {status} | failed to get response text")
+    })?;
+    Err(anyhow::anyhow!(
+      "Failed to send message to Slack | {status} | {text}"
+    ))
+  }
+}
+
+fn http_client() -> &'static reqwest::Client {
+  static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+  CLIENT.get_or_init(reqwest::Client::new)
+}
+
+/// The webhook payload. The channel / username / icon overrides
+/// are only included when configured, otherwise Slack uses
+/// the webhook defaults.
+#[derive(Serialize)]
+struct SlackMessageBody<'a> {
+  text: &'a str,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  blocks: Option<Vec<Block>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  channel: Option<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  username: Option<&'a str>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  icon_emoji: Option<&'a str>,
+}
+
+impl<'a> SlackMessageBody<'a> {
+  fn new(
+    endpoint: &'a SlackAlerterEndpoint,
+    text: &'a str,
+    blocks: Option<Vec<Block>>,
+  ) -> SlackMessageBody<'a> {
+    let non_empty = |value: &'a Option<String>| {
+      value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    };
+    SlackMessageBody {
+      text,
+      blocks,
+      channel: non_empty(&endpoint.channel),
+      username: non_empty(&endpoint.username),
+      icon_emoji: non_empty(&endpoint.icon_emoji),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn payload_includes_configured_overrides() {
+    let endpoint = SlackAlerterEndpoint {
+      channel: Some(String::from("#alerts")),
+      username: Some(String::from("Komodo")),
+      icon_emoji: Some(String::from(":lizard:")),
+      ..Default::default()
+    };
+    let body = SlackMessageBody::new(&endpoint, "alert", None);
+    assert_eq!(
+      serde_json::to_value(&body).unwrap(),
+      json!({
+        "text": "alert",
+        "channel": "#alerts",
+        "username": "Komodo",
+        "icon_emoji": ":lizard:",
+      })
+    );
+  }
+
+  #[test]
+  fn payload_omits_empty_overrides() {
+    let endpoint = SlackAlerterEndpoint {
+      channel: Some(String::from("  ")),
+      username: Some(String::new()),
+      ..Default::default()
+    };
+    let body = SlackMessageBody::new(&endpoint, "alert", None);
+    assert_eq!(
+      serde_json::to_value(&body).unwrap(),
+      json!({ "text": "alert" })
+    );
+  }
+}
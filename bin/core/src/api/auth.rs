@@ -1,13 +1,17 @@
-use std::{sync::OnceLock, time::Instant};
+use std::{net::SocketAddr, sync::OnceLock, time::Instant};
 
-use axum::{Router, extract::Path, http::HeaderMap, routing::post};
+use axum::{
+  Router,
+  extract::{ConnectInfo, Path},
+  http::HeaderMap,
+  routing::post,
+};
 use derive_variants::{EnumVariants, ExtractVariant};
 use komodo_client::{api::auth::*, entities::user::User};
 use reqwest::StatusCode;
 use resolver_api::Resolve;
 use response::Response;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
 use serror::{AddStatusCode, Json};
 use typeshare::typeshare;
 use uuid::Uuid;
@@ -18,17 +22,21 @@ use crate::{
     github::{self, client::github_oauth_client},
     google::{self, client::google_oauth_client},
     oidc::{self, client::oidc_client},
+    request_ip,
   },
   config::core_config,
   helpers::query::get_user,
   state::jwt_client,
 };
 
-use super::Variant;
+use super::{Variant, parse_variant_request};
 
 #[derive(Default)]
 pub struct AuthArgs {
   pub headers: HeaderMap,
+  /// The ip of the client making the request.
+  /// Empty when resolved internally.
+  pub ip: String,
 }
 
 #[typeshare]
@@ -78,19 +86,18 @@ pub fn router() -> Router {
 
 async fn variant_handler(
   headers: HeaderMap,
+  peer: ConnectInfo<SocketAddr>,
   Path(Variant { variant }): Path<Variant>,
   Json(params): Json<serde_json::Value>,
 ) -> serror::Result<axum::response::Response> {
-  let req: AuthRequest = serde_json::from_value(json!({
-    "type": variant,
-    "params": params,
-  }))?;
-  handler(headers, Json(req)).await
+  let req: AuthRequest = parse_variant_request(&variant, params)?;
+  handler(headers, peer, Json(req)).await
 }
 
 #[instrument(name = "AuthHandler", level = "debug", skip(headers))]
 async fn handler(
   headers: HeaderMap,
+  ConnectInfo(peer): ConnectInfo<SocketAddr>,
   Json(request): Json<AuthRequest>,
 ) -> serror::Result<axum::response::Response> {
   let timer = Instant::now();
@@ -99,7 +106,8 @@ async fn handler(
     "/auth request {req_id} | METHOD: {:?}",
     request.extract_variant()
   );
-  let res = request.resolve(&AuthArgs { headers }).await;
+  let ip = request_ip(&headers, peer);
+  let res = request.resolve(&AuthArgs { headers, ip }).await;
   if let Err(e) = &res {
     debug!("/auth request {req_id} | error: {:#}", e.error);
   }
@@ -151,7 +159,7 @@ impl Resolve<AuthArgs> for GetUser {
   #[instrument(name = "GetUser", level = "debug", skip(self))]
   async fn resolve(
     self,
-    AuthArgs { headers }: &AuthArgs,
+    AuthArgs { headers, .. }: &AuthArgs,
   ) -> serror::Result<User> {
     let user_id = get_user_id_from_headers(headers)
       .await
@@ -11,6 +11,7 @@ use config::merge_objects;
 use database::mungos::{
   by_id::update_one_by_id, mongodb::bson::to_document,
 };
+use formatting::format_serror;
 use interpolate::Interpolator;
 use komodo_client::{
   api::{
@@ -18,7 +19,7 @@ use komodo_client::{
     user::{CreateApiKey, CreateApiKeyResponse, DeleteApiKey},
   },
   entities::{
-    FileFormat, JsonObject,
+    FileFormat, JsonObject, JsonValue, KOMODO_OUTPUT,
     action::Action,
     alert::{Alert, AlertData, SeverityLevel},
     config::core::CoreConfig,
@@ -65,8 +66,12 @@ impl Resolve<ExecuteArgs> for BatchRunAction {
     ExecuteArgs { user, .. }: &ExecuteArgs,
   ) -> serror::Result<BatchExecutionResponse> {
     Ok(
-      super::batch_execute::<BatchRunAction>(&self.pattern, user)
-        .await?,
+      super::batch_execute::<BatchRunAction>(
+        &self.pattern,
+        self.combinator,
+        user,
+      )
+      .await?,
     )
   }
 }
@@ -122,6 +127,7 @@ impl Resolve<ExecuteArgs> for RunAction {
     let CreateApiKeyResponse { key, secret } = CreateApiKey {
       name: update.id.clone(),
       expires: 0,
+      permission_ceiling: None,
     }
     .resolve(&UserArgs {
       user: action_user().to_owned(),
@@ -184,6 +190,9 @@ impl Resolve<ExecuteArgs> for RunAction {
 
     cleanup_run(file + ".js", &path).await;
 
+    // Parse after secrets are replaced, so they don't leak into output.
+    let output = parse_action_output(&res.stdout);
+
     if let Err(e) = (DeleteApiKey { key })
       .resolve(&UserArgs {
         user: action_user().to_owned(),
@@ -197,6 +206,13 @@ impl Resolve<ExecuteArgs> for RunAction {
     };
 
     update.logs.push(res);
+    match output {
+      Ok(output) => update.output = output,
+      Err(e) => update.push_error_log(
+        "Parse Action Output",
+        format_serror(&e.into()),
+      ),
+    }
     update.finalize();
 
     // Need to manually update the update before cache refresh,
@@ -249,7 +265,7 @@ async fn interpolate(
   let VariablesAndSecrets {
     variables,
     mut secrets,
-  } = get_variables_and_secrets().await?;
+  } = get_variables_and_secrets(None).await?;
 
   secrets.insert(String::from("ACTION_API_KEY"), key);
   secrets.insert(String::from("ACTION_API_SECRET"), secret);
@@ -322,6 +338,22 @@ main()
   )
 }
 
+/// Parses the last [KOMODO_OUTPUT] line printed by the Action.
+fn parse_action_output(
+  stdout: &str,
+) -> anyhow::Result<Option<JsonValue>> {
+  let Some(output) = stdout
+    .lines()
+    .rev()
+    .find_map(|line| line.trim().strip_prefix(KOMODO_OUTPUT))
+  else {
+    return Ok(None);
+  };
+  serde_json::from_str(output)
+    .context("Failed to parse Action output as JSON")
+    .map(Some)
+}
+
 /// Cleans up file at given path.
 /// ALSO if $DENO_DIR is set,
 /// will clean up the generated file matching "file"
@@ -424,3 +456,28 @@ fn parse_action_arguments(
       .context("Failed to parse Json to action args"),
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn action_output_uses_last_output_line() {
+    let stdout = format!(
+      "building...\n{KOMODO_OUTPUT}{{\"tag\":\"1.2.2\"}}\n  {KOMODO_OUTPUT}{{\"tag\":\"1.2.3\"}}  \ndone"
+    );
+    assert_eq!(
+      parse_action_output(&stdout).unwrap(),
+      Some(serde_json::json!({ "tag": "1.2.3" }))
+    );
+  }
+
+  #[test]
+  fn action_output_missing_or_invalid() {
+    assert_eq!(parse_action_output("no output here").unwrap(), None);
+    assert!(
+      parse_action_output(&format!("{KOMODO_OUTPUT}not json"))
+        .is_err()
+    );
+  }
+}
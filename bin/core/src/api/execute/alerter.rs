@@ -1,5 +1,7 @@
+use std::time::Instant;
+
 use anyhow::{Context, anyhow};
-use formatting::format_serror;
+use formatting::{format_serror, muted};
 use futures::{TryStreamExt, stream::FuturesUnordered};
 use komodo_client::{
   api::execute::{SendAlert, TestAlerter},
@@ -15,8 +17,10 @@ use resolver_api::Resolve;
 use serror::AddStatusCodeError;
 
 use crate::{
-  alert::send_alert_to_alerter, helpers::update::update_update,
-  permission::get_check_permissions, resource::list_full_for_user,
+  alert::{colored_level, send_alert_to_alerter},
+  helpers::update::update_update,
+  permission::get_check_permissions,
+  resource::list_full_for_user,
 };
 
 use super::ExecuteArgs;
@@ -63,10 +67,24 @@ impl Resolve<ExecuteArgs> for TestAlerter {
       resolved_ts: Some(ts),
     };
 
-    if let Err(e) = send_alert_to_alerter(&alerter, &alert).await {
-      update.push_error_log("Test Alerter", format_serror(&e.into()));
+    // This is the total send time, including building the message
+    // and interpolating variables, not just the request to the endpoint.
+    let start = Instant::now();
+    let res = send_alert_to_alerter(&alerter, &alert).await;
+    let send_time = start.elapsed().as_millis();
+
+    if let Err(e) = res {
+      update.push_error_log(
+        "Test Alerter",
+        format_serror(
+          &e.context(format!(
+            "Failed after {send_time}ms total send time"
+          ))
+          .into(),
+        ),
+      );
     } else {
-      update.push_simple_log("Test Alerter", String::from("Alert sent successfully. It should be visible at your alerting destination."));
+      update.push_simple_log("Test Alerter", format!("Alert sent successfully ({send_time}ms total send time). It should be visible at your alerting destination."));
     };
 
     update.finalize();
@@ -127,8 +145,13 @@ impl Resolve<ExecuteArgs> for SendAlert {
 
     update.push_simple_log(
       "Send alert",
-      serde_json::to_string_pretty(&alert)
-        .context("Failed to serialize alert to JSON")?,
+      format!(
+        "{}: {}\n\n{}",
+        muted("level"),
+        colored_level(alert.level),
+        serde_json::to_string_pretty(&alert)
+          .context("Failed to serialize alert to JSON")?
+      ),
     );
 
     if let Err(e) = alerters
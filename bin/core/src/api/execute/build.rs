@@ -15,7 +15,6 @@ use database::mungos::{
 };
 use formatting::format_serror;
 use futures::future::join_all;
-use interpolate::Interpolator;
 use komodo_client::{
   api::execute::{
     BatchExecutionResponse, BatchRunBuild, CancelBuild, Deploy,
@@ -34,7 +33,7 @@ use komodo_client::{
     user::auto_redeploy_user,
   },
 };
-use periphery_client::api;
+use periphery_client::{PeripheryClient, api};
 use resolver_api::Resolve;
 use tokio_util::sync::CancellationToken;
 
@@ -44,6 +43,7 @@ use crate::{
     build_git_token,
     builder::{cleanup_builder_instance, get_builder_periphery},
     channel::build_cancel_channel,
+    periphery_interpolator,
     query::{
       VariablesAndSecrets, get_deployment_state,
       get_variables_and_secrets,
@@ -72,8 +72,12 @@ impl Resolve<ExecuteArgs> for BatchRunBuild {
     ExecuteArgs { user, .. }: &ExecuteArgs,
   ) -> serror::Result<BatchExecutionResponse> {
     Ok(
-      super::batch_execute::<BatchRunBuild>(&self.pattern, user)
-        .await?,
+      super::batch_execute::<BatchRunBuild>(
+        &self.pattern,
+        self.combinator,
+        user,
+      )
+      .await?,
     )
   }
 }
@@ -104,7 +108,7 @@ impl Resolve<ExecuteArgs> for RunBuild {
     let VariablesAndSecrets {
       mut variables,
       secrets,
-    } = get_variables_and_secrets().await?;
+    } = get_variables_and_secrets(None).await?;
 
     // Add the $VERSION to variables. Use with [[$VERSION]]
     variables.insert(
@@ -214,7 +218,8 @@ impl Resolve<ExecuteArgs> for RunBuild {
     // INTERPOLATE VARIABLES
     let secret_replacers = if !build.config.skip_secret_interp {
       let mut interpolator =
-        Interpolator::new(Some(&variables), &secrets);
+        periphery_interpolator(&variables, &secrets, &periphery)
+          .await?;
 
       interpolator.interpolate_build(&mut build)?;
 
@@ -284,8 +289,10 @@ impl Resolve<ExecuteArgs> for RunBuild {
     if all_logs_success(&update.logs) {
       // RUN BUILD
       let res = tokio::select! {
-        res = periphery
-          .request(api::build::Build {
+        res = build_with_progress(
+          &periphery,
+          &build.name,
+          api::build::Build {
             build: build.clone(),
             repo,
             registry_tokens,
@@ -294,9 +301,12 @@ impl Resolve<ExecuteArgs> for RunBuild {
             commit_hash: optional_string(&update.commit_hash),
             // Unused for now
             additional_tags: Default::default(),
-          }) => res.context("failed at call to periphery to build"),
+            track_progress: true,
+          }
+        ) => res.context("failed at call to periphery to build"),
         _ = cancel.cancelled() => {
           info!("build cancelled during build, cleaning up builder");
+          set_build_progress(&build.name, None).await;
           update.push_error_log("build cancelled", String::from("user cancelled build during docker build"));
           cleanup_builder_instance(cleanup_data, &mut update)
             .await;
@@ -324,6 +334,18 @@ impl Resolve<ExecuteArgs> for RunBuild {
     let db = db_client();
 
     if update.success {
+      // UI defined dockerfiles are hashed directly, otherwise
+      // use the hash from the latest cache refresh.
+      let built_dockerfile_hash =
+        if !build.config.dockerfile.is_empty()
+          && !build.config.files_on_host
+          && build.config.repo.is_empty()
+          && build.config.linked_repo.is_empty()
+        {
+          Some(resource::dockerfile_hash(&build.config.dockerfile))
+        } else {
+          build.info.dockerfile_hash.clone()
+        };
       let _ = db
         .builds
         .update_one(
@@ -333,7 +355,9 @@ impl Resolve<ExecuteArgs> for RunBuild {
               .context("failed at converting version to bson")?,
             "info.last_built_at": komodo_timestamp(),
             "info.built_hash": &update.commit_hash,
-            "info.built_message": commit_message
+            "info.built_message": commit_message,
+            "info.built_dockerfile_hash": built_dockerfile_hash,
+            "info.needs_rebuild": false,
           }},
         )
         .await;
@@ -394,6 +418,57 @@ impl Resolve<ExecuteArgs> for RunBuild {
   }
 }
 
+/// Runs the build on Periphery, polling the build progress
+/// into the build info while it runs.
+async fn build_with_progress(
+  periphery: &PeripheryClient,
+  build_name: &str,
+  request: api::build::Build,
+) -> anyhow::Result<Vec<Log>> {
+  let poll_progress = async {
+    loop {
+      tokio::time::sleep(Duration::from_secs(3)).await;
+      match periphery
+        .request(api::build::GetBuildProgress {
+          name: build_name.to_string(),
+        })
+        .await
+      {
+        Ok(progress) => {
+          set_build_progress(build_name, progress).await
+        }
+        // Older Periphery versions don't report build progress.
+        Err(e) => debug!("Failed to get build progress | {e:#}"),
+      }
+    }
+  };
+  let res = tokio::select! {
+    res = periphery.request(request) => res,
+    _ = poll_progress => unreachable!(),
+  };
+  set_build_progress(build_name, None).await;
+  res
+}
+
+async fn set_build_progress(
+  build_name: &str,
+  progress: Option<api::build::BuildProgress>,
+) {
+  let (step, total) = progress
+    .map(|progress| (progress.step, progress.total))
+    .unzip();
+  let _ = db_client()
+    .builds
+    .update_one(
+      doc! { "name": build_name },
+      doc! { "$set": {
+        "info.build_step": step,
+        "info.build_step_total": total,
+      }},
+    )
+    .await;
+}
+
 #[instrument(skip(update))]
 async fn handle_early_return(
   mut update: Update,
@@ -3,7 +3,6 @@ use std::sync::OnceLock;
 use anyhow::{Context, anyhow};
 use cache::TimeoutCache;
 use formatting::format_serror;
-use interpolate::Interpolator;
 use komodo_client::{
   api::execute::*,
   entities::{
@@ -24,7 +23,7 @@ use resolver_api::Resolve;
 
 use crate::{
   helpers::{
-    periphery_client,
+    periphery_client, periphery_interpolator,
     query::{VariablesAndSecrets, get_variables_and_secrets},
     registry_token,
     update::update_update,
@@ -55,8 +54,12 @@ impl Resolve<ExecuteArgs> for BatchDeploy {
     ExecuteArgs { user, .. }: &ExecuteArgs,
   ) -> serror::Result<BatchExecutionResponse> {
     Ok(
-      super::batch_execute::<BatchDeploy>(&self.pattern, user)
-        .await?,
+      super::batch_execute::<BatchDeploy>(
+        &self.pattern,
+        self.combinator,
+        user,
+      )
+      .await?,
     )
   }
 }
@@ -186,10 +189,15 @@ impl Resolve<ExecuteArgs> for Deploy {
     // periphery so it may sanitize the final command for safe logging (avoids exposing secret values)
     let secret_replacers = if !deployment.config.skip_secret_interp {
       let VariablesAndSecrets { variables, secrets } =
-        get_variables_and_secrets().await?;
+        get_variables_and_secrets(Some(&deployment.config.server_id))
+          .await?;
 
-      let mut interpolator =
-        Interpolator::new(Some(&variables), &secrets);
+      let mut interpolator = periphery_interpolator(
+        &variables,
+        &secrets,
+        &periphery_client(&server)?,
+      )
+      .await?;
 
       interpolator
         .interpolate_deployment(&mut deployment)?
@@ -656,6 +664,7 @@ impl Resolve<ExecuteArgs> for BatchDestroyDeployment {
     Ok(
       super::batch_execute::<BatchDestroyDeployment>(
         &self.pattern,
+        self.combinator,
         user,
       )
       .await?,
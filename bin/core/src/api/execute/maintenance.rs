@@ -1,21 +1,43 @@
-use std::sync::OnceLock;
+use std::{
+  ffi::OsStr,
+  path::{Component, Path, PathBuf},
+  sync::OnceLock,
+};
 
 use anyhow::{Context, anyhow};
 use command::run_komodo_command;
-use database::mungos::{find::find_collect, mongodb::bson::doc};
+use database::mungos::{
+  by_id::update_one_by_id,
+  find::find_collect,
+  mongodb::{
+    bson::{doc, to_document},
+    options::UpdateOptions,
+  },
+};
+use environment_file::maybe_read_item_from_file;
 use formatting::{bold, format_serror};
 use komodo_client::{
   api::execute::{
     BackupCoreDatabase, ClearRepoCache, GlobalAutoUpdate,
+    RestoreDatabase,
   },
   entities::{
-    deployment::DeploymentState, server::ServerState,
-    stack::StackState,
+    ResourceTargetVariant,
+    config::cli::{CliConfig, Env as CliEnv, RemoteBackupTarget},
+    deployment::{
+      Deployment, DeploymentState, extract_registry_domain,
+    },
+    docker::image::{ImageDigests, ImageUpdateCheck},
+    optional_string,
+    server::{Server, ServerState},
+    stack::{Stack, StackState},
+    update::{Log, Update},
   },
 };
+use periphery_client::api;
 use reqwest::StatusCode;
 use resolver_api::Resolve;
-use serror::AddStatusCodeError;
+use serror::{AddStatusCode, AddStatusCodeError};
 use tokio::sync::Mutex;
 
 use crate::{
@@ -23,7 +45,11 @@ use crate::{
     ExecuteArgs, pull_deployment_inner, pull_stack_inner,
   },
   config::core_config,
-  helpers::update::update_update,
+  helpers::{
+    periphery_client, query::get_tag, registry_token,
+    update::update_update,
+  },
+  resource,
   state::{
     db_client, deployment_status_cache, server_status_cache,
     stack_status_cache,
@@ -105,7 +131,7 @@ impl Resolve<ExecuteArgs> for ClearRepoCache {
 
 //
 
-/// Makes sure the method can only be called once at a time
+/// Makes sure only one database backup / restore runs at a time
 fn backup_database_lock() -> &'static Mutex<()> {
   static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
   LOCK.get_or_init(Default::default)
@@ -130,20 +156,46 @@ impl Resolve<ExecuteArgs> for BackupCoreDatabase {
 
     let _lock = backup_database_lock()
       .try_lock()
-      .context("Backup already in progress...")?;
+      .context("Backup or restore already in progress...")?;
 
     let mut update = update.clone();
 
     update_update(update.clone()).await?;
 
-    let res = run_komodo_command(
-      "Backup Core Database",
-      None,
-      "km database backup --yes",
-    )
-    .await;
+    // The encryption key is only read by the CLI from
+    // KOMODO_CLI_BACKUP_ENCRYPTION_KEY in the Core environment.
+    let command = match self.compression {
+      Some(compression) => {
+        format!(
+          "km database backup --yes --compression {compression}"
+        )
+      }
+      None => String::from("km database backup --yes"),
+    };
 
+    let res =
+      run_komodo_command("Backup Core Database", None, command).await;
+
+    let success = res.success;
     update.logs.push(res);
+
+    if success && !self.remote_target.is_empty() {
+      let log = match core_config()
+        .backup_remote_targets
+        .get(&self.remote_target)
+      {
+        Some(remote_target) => upload_backup(remote_target).await,
+        None => Log::error(
+          "Upload Backup",
+          format!(
+            "No remote target named '{}' in the Core config backup_remote_targets",
+            self.remote_target
+          ),
+        ),
+      };
+      update.logs.push(log);
+    }
+
     update.finalize();
 
     update_update(update.clone()).await?;
@@ -152,6 +204,185 @@ impl Resolve<ExecuteArgs> for BackupCoreDatabase {
   }
 }
 
+/// Uploads the backup just taken by the CLI to the remote target.
+/// The CLI runs in the Core container, so the backups folder and
+/// max backups are read from the same KOMODO_CLI_* environment.
+async fn upload_backup(remote_target: &RemoteBackupTarget) -> Log {
+  let (backups_folder, max_backups) = cli_backups_config();
+  match database::utils::upload_latest_backup(
+    &backups_folder,
+    remote_target,
+    max_backups,
+  )
+  .await
+  {
+    Ok(uploaded) => Log::simple(
+      "Upload Backup",
+      format!("Uploaded backup files:\n{}", uploaded.join("\n")),
+    ),
+    Err(e) => Log::error(
+      "Upload Backup",
+      format_serror(&e.context("Failed to upload backup").into()),
+    ),
+  }
+}
+
+/// The backups folder and max backups used by the CLI.
+fn cli_backups_config() -> (PathBuf, u16) {
+  let defaults = CliConfig::default();
+  match cli_env() {
+    Some(env) => (
+      env
+        .komodo_cli_backups_folder
+        .unwrap_or(defaults.backups_folder),
+      env.komodo_cli_max_backups.unwrap_or(defaults.max_backups),
+    ),
+    None => (defaults.backups_folder, defaults.max_backups),
+  }
+}
+
+fn cli_env() -> Option<CliEnv> {
+  envy::from_env::<CliEnv>()
+    .inspect_err(|e| {
+      warn!("Failed to parse KOMODO_CLI_* env | {e:?}")
+    })
+    .ok()
+}
+
+//
+
+const RESTORE_DATABASE_CONFIRMATION: &str =
+  "I understand this is destructive";
+
+impl Resolve<ExecuteArgs> for RestoreDatabase {
+  #[instrument(
+    name = "RestoreDatabase",
+    skip(user, update),
+    fields(user_id = user.id, update_id = update.id)
+  )]
+  async fn resolve(
+    self,
+    ExecuteArgs { user, update }: &ExecuteArgs,
+  ) -> Result<Self::Response, Self::Error> {
+    if !user.super_admin {
+      return Err(
+        anyhow!("This method is super admin only.")
+          .status_code(StatusCode::FORBIDDEN),
+      );
+    }
+
+    if self.confirmation != RESTORE_DATABASE_CONFIRMATION {
+      return Err(
+        anyhow!(
+          "Confirmation must be '{RESTORE_DATABASE_CONFIRMATION}'"
+        )
+        .status_code(StatusCode::BAD_REQUEST),
+      );
+    }
+
+    let (backups_folder, _) = cli_backups_config();
+    let restore_folder =
+      restore_folder_name(&backups_folder, &self.backup_path)
+        .status_code(StatusCode::BAD_REQUEST)?;
+
+    let _lock = backup_database_lock()
+      .try_lock()
+      .context("Backup or restore already in progress...")?;
+
+    let mut update = update.clone();
+
+    update_update(update.clone()).await?;
+
+    let encryption_key = cli_env().and_then(|env| {
+      maybe_read_item_from_file(
+        env.komodo_cli_backup_encryption_key_file,
+        env.komodo_cli_backup_encryption_key,
+      )
+    });
+
+    let res = async {
+      let db = &db_client().db;
+      database::utils::restore(
+        db,
+        &backups_folder,
+        Some(Path::new(restore_folder)),
+        encryption_key.as_deref(),
+        true,
+      )
+      .await?;
+      // Dropping the collections also dropped the indexes
+      database::Client::from_database(db.clone())
+        .await
+        .context("Failed to recreate database indexes")?;
+      anyhow::Ok(())
+    }
+    .await;
+
+    let log = match res {
+      Ok(_) => Log::simple(
+        "Restore Database",
+        format!(
+          "Restored database from {}. Restart Core to clear any cached state.",
+          bold(&self.backup_path)
+        ),
+      ),
+      Err(e) => Log::error(
+        "Restore Database",
+        format_serror(
+          &e.context("Failed to restore database").into(),
+        ),
+      ),
+    };
+
+    update.logs.push(log);
+    update.finalize();
+
+    // The Update collection was recreated from the backup,
+    // so this update needs to be inserted again.
+    update_one_by_id(
+      &db_client().updates,
+      &update.id,
+      doc! { "$set": to_document(&update).context("Failed to serialize update")? },
+      Some(UpdateOptions::builder().upsert(true).build()),
+    )
+    .await
+    .context("Failed to write restore update to db")?;
+
+    update_update(update.clone()).await?;
+
+    Ok(update)
+  }
+}
+
+/// Resolves the backup path to the name of a backup folder
+/// directly inside the backups folder. The path can be just the
+/// folder name, or its full path in the Core container.
+/// Paths leading anywhere else are rejected.
+fn restore_folder_name<'a>(
+  backups_folder: &Path,
+  backup_path: &'a str,
+) -> anyhow::Result<&'a OsStr> {
+  let backup_path = Path::new(backup_path);
+  let relative = if backup_path.is_absolute() {
+    backup_path.strip_prefix(backups_folder).with_context(|| {
+      format!(
+        "Backup path must be inside the backups folder {backups_folder:?}"
+      )
+    })?
+  } else {
+    backup_path
+  };
+  let mut components = relative
+    .components()
+    .filter(|component| !matches!(component, Component::CurDir));
+  match (components.next(), components.next()) {
+    (Some(Component::Normal(name)), None) => Ok(name),
+    _ => Err(anyhow!(
+      "Backup path must be a backup folder directly inside the backups folder {backups_folder:?}"
+    )),
+  }
+}
+
 //
 
 /// Makes sure the method can only be called once at a time
@@ -185,21 +416,18 @@ impl Resolve<ExecuteArgs> for GlobalAutoUpdate {
 
     update_update(update.clone()).await?;
 
-    // This is all done in sequence because there is no rush,
-    // the pulls / deploys happen spaced out to ease the load on system.
-    let servers = find_collect(&db_client().servers, None, None)
-      .await
-      .context("Failed to query for servers from database")?;
+    let (stacks, deployments) =
+      get_auto_update_resources(&self).await?;
 
-    let query = doc! {
-      "$or": [
-        { "config.poll_for_updates": true },
-        { "config.auto_update": true }
-      ]
-    };
+    if self.dry_run {
+      return global_auto_update_dry_run(update, stacks, deployments)
+        .await;
+    }
 
-    let (stacks, repos) = tokio::try_join!(
-      find_collect(&db_client().stacks, query.clone(), None),
+    // This is all done in sequence because there is no rush,
+    // the pulls / deploys happen spaced out to ease the load on system.
+    let (servers, repos) = tokio::try_join!(
+      find_collect(&db_client().servers, None, None),
       find_collect(&db_client().repos, None, None)
     )
     .context("Failed to query for resources from database")?;
@@ -267,10 +495,6 @@ impl Resolve<ExecuteArgs> for GlobalAutoUpdate {
     }
 
     let deployment_status_cache = deployment_status_cache();
-    let deployments =
-      find_collect(&db_client().deployments, query, None)
-        .await
-        .context("Failed to query for deployments from database")?;
     for deployment in deployments {
       let Some(status) =
         deployment_status_cache.get(&deployment.id).await
@@ -317,3 +541,295 @@ impl Resolve<ExecuteArgs> for GlobalAutoUpdate {
     Ok(update)
   }
 }
+
+/// Gets the Stacks / Deployments with `poll_for_updates` or
+/// `auto_update` enabled, which match the request filters.
+async fn get_auto_update_resources(
+  GlobalAutoUpdate {
+    filter_tags,
+    filter_server_ids,
+    filter_resource_types,
+    ..
+  }: &GlobalAutoUpdate,
+) -> anyhow::Result<(Vec<Stack>, Vec<Deployment>)> {
+  let mut query = doc! {
+    "$or": [
+      { "config.poll_for_updates": true },
+      { "config.auto_update": true }
+    ]
+  };
+
+  if !filter_tags.is_empty() {
+    let mut tag_ids = Vec::with_capacity(filter_tags.len());
+    for tag in filter_tags {
+      tag_ids.push(get_tag(tag).await?.id);
+    }
+    query.insert("tags", doc! { "$all": tag_ids });
+  }
+
+  if !filter_server_ids.is_empty() {
+    let mut server_ids = Vec::with_capacity(filter_server_ids.len());
+    for server in filter_server_ids {
+      server_ids.push(resource::get::<Server>(server).await?.id);
+    }
+    query.insert("config.server_id", doc! { "$in": server_ids });
+  }
+
+  let include = |variant: ResourceTargetVariant| {
+    filter_resource_types.is_empty()
+      || filter_resource_types.contains(&variant)
+  };
+
+  let stacks = async {
+    if include(ResourceTargetVariant::Stack) {
+      find_collect(&db_client().stacks, query.clone(), None)
+        .await
+        .context("Failed to query for stacks from database")
+    } else {
+      Ok(Vec::new())
+    }
+  };
+  let deployments = async {
+    if include(ResourceTargetVariant::Deployment) {
+      find_collect(&db_client().deployments, query.clone(), None)
+        .await
+        .context("Failed to query for deployments from database")
+    } else {
+      Ok(Vec::new())
+    }
+  };
+
+  tokio::try_join!(stacks, deployments)
+}
+
+/// Checks the registry for updates to the images running
+/// the same Stacks / Deployments [GlobalAutoUpdate] would pull,
+/// without pulling or redeploying anything.
+async fn global_auto_update_dry_run(
+  mut update: Update,
+  stacks: Vec<Stack>,
+  deployments: Vec<Deployment>,
+) -> serror::Result<Update> {
+  let servers = find_collect(&db_client().servers, None, None)
+    .await
+    .context("Failed to query for servers from database")?;
+
+  let server_status_cache = server_status_cache();
+  let stack_status_cache = stack_status_cache();
+  let deployment_status_cache = deployment_status_cache();
+
+  let mut checks = Vec::new();
+
+  for stack in stacks {
+    let Some(status) = stack_status_cache.get(&stack.id).await else {
+      continue;
+    };
+    if !matches!(status.curr.state, StackState::Running) {
+      continue;
+    }
+    let Some(server) =
+      servers.iter().find(|s| s.id == stack.config.server_id)
+    else {
+      continue;
+    };
+    if !server_status_cache
+      .get(&server.id)
+      .await
+      .map(|s| matches!(s.state, ServerState::Ok))
+      .unwrap_or_default()
+    {
+      continue;
+    }
+    for service in &status.curr.services {
+      let Some(image) = service
+        .container
+        .as_ref()
+        .and_then(|container| container.image.clone())
+      else {
+        continue;
+      };
+      let name = format!("{} | {}", stack.name, service.service);
+      let account = if extract_registry_domain(&image)
+        .map(|domain| domain == stack.config.registry_provider)
+        .unwrap_or_default()
+      {
+        stack.config.registry_account.as_str()
+      } else {
+        ""
+      };
+      match check_image_update(
+        server,
+        ResourceTargetVariant::Stack,
+        name.clone(),
+        image,
+        account,
+      )
+      .await
+      {
+        Ok(check) => checks.push(check),
+        Err(e) => update.push_error_log(
+          &format!("Check Stack {name}"),
+          format_serror(&e.into()),
+        ),
+      }
+    }
+  }
+
+  for deployment in deployments {
+    let Some(status) =
+      deployment_status_cache.get(&deployment.id).await
+    else {
+      continue;
+    };
+    if !matches!(status.curr.state, DeploymentState::Running) {
+      continue;
+    }
+    let Some(image) = status
+      .curr
+      .container
+      .as_ref()
+      .and_then(|container| container.image.clone())
+    else {
+      continue;
+    };
+    let Some(server) =
+      servers.iter().find(|s| s.id == deployment.config.server_id)
+    else {
+      continue;
+    };
+    if !server_status_cache
+      .get(&server.id)
+      .await
+      .map(|s| matches!(s.state, ServerState::Ok))
+      .unwrap_or_default()
+    {
+      continue;
+    }
+    match check_image_update(
+      server,
+      ResourceTargetVariant::Deployment,
+      deployment.name.clone(),
+      image,
+      &deployment.config.image_registry_account,
+    )
+    .await
+    {
+      Ok(check) => checks.push(check),
+      Err(e) => update.push_error_log(
+        &format!("Check Deployment {}", deployment.name),
+        format_serror(&e.into()),
+      ),
+    }
+  }
+
+  let summary = checks
+    .iter()
+    .map(|check| {
+      if check.has_update {
+        format!(
+          "{} {}: {} -> {}",
+          check.resource_type,
+          bold(&check.resource_name),
+          check.current_image,
+          check.latest_image
+        )
+      } else {
+        format!(
+          "{} {}: Up to date ✅",
+          check.resource_type,
+          bold(&check.resource_name)
+        )
+      }
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+  update.push_simple_log("Check Image Updates", summary);
+
+  update.output = Some(
+    serde_json::to_value(&checks)
+      .context("Failed to serialize image update checks")?,
+  );
+
+  update.finalize();
+  update_update(update.clone()).await?;
+
+  Ok(update)
+}
+
+async fn check_image_update(
+  server: &Server,
+  resource_type: ResourceTargetVariant,
+  resource_name: String,
+  image: String,
+  account: &str,
+) -> anyhow::Result<ImageUpdateCheck> {
+  let token = if account.is_empty() {
+    None
+  } else {
+    let domain = extract_registry_domain(&image)?;
+    registry_token(&domain, account).await.with_context(|| {
+      format!("Failed to get registry token | {domain} | {account}")
+    })?
+  };
+  let ImageDigests { local, remote } = periphery_client(server)?
+    .request(api::image::GetImageDigests {
+      name: image.clone(),
+      account: optional_string(account),
+      token,
+    })
+    .await?;
+  // Strip any digest already on the image name
+  let image = image.split('@').next().unwrap_or(&image);
+  Ok(ImageUpdateCheck {
+    resource_type,
+    resource_name,
+    current_image: local
+      .first()
+      .map(|digest| format!("{image}@{digest}"))
+      .unwrap_or_else(|| image.to_string()),
+    latest_image: format!("{image}@{remote}"),
+    has_update: !local.contains(&remote),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn restore_folder_inside_backups_folder() {
+    let backups_folder = Path::new("/backups");
+    for backup_path in [
+      "2025-08-01_05-04-53",
+      "./2025-08-01_05-04-53",
+      "/backups/2025-08-01_05-04-53",
+      "/backups/2025-08-01_05-04-53/",
+    ] {
+      assert_eq!(
+        restore_folder_name(backups_folder, backup_path).unwrap(),
+        "2025-08-01_05-04-53"
+      );
+    }
+  }
+
+  #[test]
+  fn restore_folder_outside_backups_folder_rejected() {
+    let backups_folder = Path::new("/backups");
+    for backup_path in [
+      "",
+      "..",
+      "../etc",
+      "2025-08-01_05-04-53/..",
+      "2025-08-01_05-04-53/nested",
+      "/backups",
+      "/backups/../etc",
+      "/etc/komodo",
+      "/backups-other/2025-08-01_05-04-53",
+    ] {
+      assert!(
+        restore_folder_name(backups_folder, backup_path).is_err(),
+        "{backup_path} should be rejected"
+      );
+    }
+  }
+}
@@ -1,8 +1,12 @@
-use std::{pin::Pin, time::Instant};
+use std::{net::SocketAddr, pin::Pin, sync::OnceLock, time::Instant};
 
 use anyhow::Context;
 use axum::{
-  Extension, Router, extract::Path, middleware, routing::post,
+  Extension, Router,
+  extract::{ConnectInfo, Path},
+  http::HeaderMap,
+  middleware,
+  routing::post,
 };
 use axum_extra::{TypedHeader, headers::ContentType};
 use database::mungos::by_id::find_one_by_id;
@@ -12,7 +16,7 @@ use futures::future::join_all;
 use komodo_client::{
   api::execute::*,
   entities::{
-    Operation,
+    Operation, SearchCombinator,
     permission::PermissionLevel,
     update::{Log, Update},
     user::User,
@@ -21,14 +25,19 @@ use komodo_client::{
 use resolver_api::Resolve;
 use response::JsonString;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
 use serror::Json;
+use tokio_util::task::TaskTracker;
 use typeshare::typeshare;
 use uuid::Uuid;
 
 use crate::{
-  auth::auth_request,
-  helpers::update::{init_execution_update, update_update},
+  auth::{
+    ApiKeyCeiling, auth_request, check_api_key_ceiling, request_ip,
+  },
+  helpers::{
+    audit::{audit_entry, record_audit_entry},
+    update::{CLIENT_IP, init_execution_update, update_update},
+  },
   resource::{KomodoResource, list_full_for_user_using_pattern},
   state::db_client,
 };
@@ -41,10 +50,11 @@ mod maintenance;
 mod procedure;
 mod repo;
 mod server;
+mod server_group;
 mod stack;
 mod sync;
 
-use super::Variant;
+use super::{Variant, parse_variant_request, read_only_guard};
 
 pub use {
   deployment::pull_deployment_inner, stack::pull_stack_inner,
@@ -88,6 +98,11 @@ pub enum ExecuteRequest {
   PruneBuildx(PruneBuildx),
   PruneSystem(PruneSystem),
 
+  // ==== SERVER GROUP ====
+  RunCommandOnGroup(RunCommandOnGroup),
+  PruneImagesOnGroup(PruneImagesOnGroup),
+  PruneSystemOnGroup(PruneSystemOnGroup),
+
   // ==== STACK ====
   DeployStack(DeployStack),
   BatchDeployStack(BatchDeployStack),
@@ -148,6 +163,7 @@ pub enum ExecuteRequest {
   // ==== MAINTENANCE ====
   ClearRepoCache(ClearRepoCache),
   BackupCoreDatabase(BackupCoreDatabase),
+  RestoreDatabase(RestoreDatabase),
   GlobalAutoUpdate(GlobalAutoUpdate),
 }
 
@@ -156,25 +172,45 @@ pub fn router() -> Router {
     .route("/", post(handler))
     .route("/{variant}", post(variant_handler))
     .layer(middleware::from_fn(auth_request))
+    .layer(middleware::from_fn(read_only_guard))
 }
 
 async fn variant_handler(
+  headers: HeaderMap,
+  peer: ConnectInfo<SocketAddr>,
   user: Extension<User>,
+  ceiling: Option<Extension<ApiKeyCeiling>>,
   Path(Variant { variant }): Path<Variant>,
   Json(params): Json<serde_json::Value>,
 ) -> serror::Result<(TypedHeader<ContentType>, String)> {
-  let req: ExecuteRequest = serde_json::from_value(json!({
-    "type": variant,
-    "params": params,
-  }))?;
-  handler(user, Json(req)).await
+  let req: ExecuteRequest = parse_variant_request(&variant, params)?;
+  handler(headers, peer, user, ceiling, Json(req)).await
 }
 
 async fn handler(
+  headers: HeaderMap,
+  ConnectInfo(peer): ConnectInfo<SocketAddr>,
   Extension(user): Extension<User>,
+  ceiling: Option<Extension<ApiKeyCeiling>>,
   Json(request): Json<ExecuteRequest>,
 ) -> serror::Result<(TypedHeader<ContentType>, String)> {
-  let res = match inner_handler(request, user).await? {
+  let request_type = format!("{:?}", request.extract_variant());
+  check_api_key_ceiling(
+    ceiling.as_deref(),
+    PermissionLevel::Execute,
+    &request_type,
+  )?;
+  let ip = request_ip(&headers, peer);
+  let mut audit = audit_entry(&user, request_type, ip.clone());
+  let res = CLIENT_IP.scope(ip, inner_handler(request, user)).await;
+  // Executions run in the background, so this records
+  // whether the execution was accepted.
+  audit.success = res.is_ok();
+  if let Ok(ExecutionResult::Single(update)) = &res {
+    audit.resource_target = Some(update.target.clone());
+  }
+  record_audit_entry(audit);
+  let res = match res? {
     ExecutionResult::Single(update) => serde_json::to_string(&update)
       .context("Failed to serialize Update")?,
     ExecutionResult::Batch(res) => res,
@@ -182,6 +218,13 @@ async fn handler(
   Ok((TypedHeader(ContentType::json()), res))
 }
 
+/// Tracks the spawned execution tasks,
+/// so they can be drained on shutdown.
+pub fn execution_tasks() -> &'static TaskTracker {
+  static EXECUTION_TASKS: OnceLock<TaskTracker> = OnceLock::new();
+  EXECUTION_TASKS.get_or_init(Default::default)
+}
+
 #[typeshare(serialized_as = "Update")]
 type BoxUpdate = Box<Update>;
 
@@ -222,9 +265,14 @@ pub fn inner_handler(
     }
 
     // Spawn a task for the execution which continues
-    // running after this method returns.
-    let handle =
-      tokio::spawn(task(req_id, request, user, update.clone()));
+    // running after this method returns. It is tracked
+    // so shutdown can wait for it to finish.
+    let handle = execution_tasks().spawn(task(
+      req_id,
+      request,
+      user,
+      update.clone(),
+    ));
 
     // Spawns another task to monitor the first for failures,
     // and add the log to Update about it (which primary task can't do because it errored out)
@@ -315,10 +363,12 @@ trait BatchExecute {
 
 async fn batch_execute<E: BatchExecute>(
   pattern: &str,
+  combinator: Option<SearchCombinator>,
   user: &User,
 ) -> anyhow::Result<BatchExecutionResponse> {
   let resources = list_full_for_user_using_pattern::<E::Resource>(
     pattern,
+    combinator.unwrap_or_default(),
     Default::default(),
     user,
     PermissionLevel::Execute.into(),
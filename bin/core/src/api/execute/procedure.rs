@@ -44,8 +44,12 @@ impl Resolve<ExecuteArgs> for BatchRunProcedure {
     ExecuteArgs { user, .. }: &ExecuteArgs,
   ) -> serror::Result<BatchExecutionResponse> {
     Ok(
-      super::batch_execute::<BatchRunProcedure>(&self.pattern, user)
-        .await?,
+      super::batch_execute::<BatchRunProcedure>(
+        &self.pattern,
+        self.combinator,
+        user,
+      )
+      .await?,
     )
   }
 }
@@ -57,8 +57,12 @@ impl Resolve<ExecuteArgs> for BatchCloneRepo {
     ExecuteArgs { user, update }: &ExecuteArgs,
   ) -> serror::Result<BatchExecutionResponse> {
     Ok(
-      super::batch_execute::<BatchCloneRepo>(&self.pattern, user)
-        .await?,
+      super::batch_execute::<BatchCloneRepo>(
+        &self.pattern,
+        self.combinator,
+        user,
+      )
+      .await?,
     )
   }
 }
@@ -171,8 +175,12 @@ impl Resolve<ExecuteArgs> for BatchPullRepo {
     ExecuteArgs { user, .. }: &ExecuteArgs,
   ) -> serror::Result<BatchExecutionResponse> {
     Ok(
-      super::batch_execute::<BatchPullRepo>(&self.pattern, user)
-        .await?,
+      super::batch_execute::<BatchPullRepo>(
+        &self.pattern,
+        self.combinator,
+        user,
+      )
+      .await?,
     )
   }
 }
@@ -327,8 +335,12 @@ impl Resolve<ExecuteArgs> for BatchBuildRepo {
     ExecuteArgs { user, .. }: &ExecuteArgs,
   ) -> serror::Result<BatchExecutionResponse> {
     Ok(
-      super::batch_execute::<BatchBuildRepo>(&self.pattern, user)
-        .await?,
+      super::batch_execute::<BatchBuildRepo>(
+        &self.pattern,
+        self.combinator,
+        user,
+      )
+      .await?,
     )
   }
 }
@@ -714,7 +726,7 @@ async fn interpolate(
 ) -> anyhow::Result<HashSet<(String, String)>> {
   if !repo.config.skip_secret_interp {
     let VariablesAndSecrets { variables, secrets } =
-      get_variables_and_secrets().await?;
+      get_variables_and_secrets(Some(&repo.config.server_id)).await?;
 
     let mut interpolator =
       Interpolator::new(Some(&variables), &secrets);
@@ -1,5 +1,5 @@
 use anyhow::Context;
-use formatting::format_serror;
+use formatting::{code, format_serror};
 use komodo_client::{
   api::execute::*,
   entities::{
@@ -1013,7 +1013,8 @@ impl Resolve<ExecuteArgs> for PruneDockerBuilders {
         Err(e) => Log::error(
           "prune builders",
           format!(
-            "failed to docker builder prune on server {} | {e:#?}",
+            "failed to {} on server {} | {e:#?}",
+            code("docker builder prune"),
             server.name
           ),
         ),
@@ -1065,7 +1066,8 @@ impl Resolve<ExecuteArgs> for PruneBuildx {
         Err(e) => Log::error(
           "prune buildx",
           format!(
-            "failed to docker buildx prune on server {} | {e:#?}",
+            "failed to {} on server {} | {e:#?}",
+            code("docker buildx prune"),
             server.name
           ),
         ),
@@ -1116,7 +1118,8 @@ impl Resolve<ExecuteArgs> for PruneSystem {
       Err(e) => Log::error(
         "prune system",
         format!(
-          "failed to docker system prune on server {} | {e:#?}",
+          "failed to {} on server {} | {e:#?}",
+          code("docker system prune"),
           server.name
         ),
       ),
@@ -0,0 +1,196 @@
+use anyhow::anyhow;
+use formatting::format_serror;
+use futures::future::join_all;
+use komodo_client::{
+  api::execute::*,
+  entities::{
+    SystemCommand,
+    permission::PermissionLevel,
+    server::Server,
+    server_group::ServerGroup,
+    update::{Log, Update},
+  },
+};
+use periphery_client::api;
+use resolver_api::Resolve;
+
+use crate::{
+  helpers::{
+    periphery_client, query::get_system_info, update::update_update,
+  },
+  monitor::update_cache_for_server,
+  permission::get_check_permissions,
+  resource,
+  state::action_states,
+};
+
+use super::ExecuteArgs;
+
+impl Resolve<ExecuteArgs> for RunCommandOnGroup {
+  #[instrument(name = "RunCommandOnGroup", skip(self, user, update), fields(user_id = user.id, update_id = update.id))]
+  async fn resolve(
+    self,
+    ExecuteArgs { user, update }: &ExecuteArgs,
+  ) -> serror::Result<Update> {
+    let group = get_check_permissions::<ServerGroup>(
+      &self.server_group,
+      user,
+      PermissionLevel::Execute.terminal(),
+    )
+    .await?;
+
+    let command = SystemCommand {
+      path: self.path,
+      command: self.command,
+    };
+
+    let mut update = update.clone();
+
+    update_update(update.clone()).await?;
+
+    let logs = run_on_members(&group, |server| {
+      let command = command.clone();
+      async move {
+        let disabled = get_system_info(&server)
+          .await
+          .map(|info| info.terminals_disabled)
+          .unwrap_or_default();
+        if disabled {
+          return Err(anyhow!("Server has terminals disabled"));
+        }
+        periphery_client(&server)?
+          .request(api::RunCommand { command })
+          .await
+      }
+    })
+    .await?;
+
+    update.logs.extend(logs);
+    update.finalize();
+    update_update(update.clone()).await?;
+
+    Ok(update)
+  }
+}
+
+impl Resolve<ExecuteArgs> for PruneImagesOnGroup {
+  #[instrument(name = "PruneImagesOnGroup", skip(user, update), fields(user_id = user.id, update_id = update.id))]
+  async fn resolve(
+    self,
+    ExecuteArgs { user, update }: &ExecuteArgs,
+  ) -> serror::Result<Update> {
+    let group = get_check_permissions::<ServerGroup>(
+      &self.server_group,
+      user,
+      PermissionLevel::Execute.into(),
+    )
+    .await?;
+
+    let mut update = update.clone();
+
+    update_update(update.clone()).await?;
+
+    let logs = run_on_members(&group, |server| async move {
+      let action_state = action_states()
+        .server
+        .get_or_insert_default(&server.id)
+        .await;
+      let _action_guard =
+        action_state.update(|state| state.pruning_images = true)?;
+      let log = periphery_client(&server)?
+        .request(api::image::PruneImages {})
+        .await?;
+      update_cache_for_server(&server, true).await;
+      Ok(log)
+    })
+    .await?;
+
+    update.logs.extend(logs);
+    update.finalize();
+    update_update(update.clone()).await?;
+
+    Ok(update)
+  }
+}
+
+impl Resolve<ExecuteArgs> for PruneSystemOnGroup {
+  #[instrument(name = "PruneSystemOnGroup", skip(user, update), fields(user_id = user.id, update_id = update.id))]
+  async fn resolve(
+    self,
+    ExecuteArgs { user, update }: &ExecuteArgs,
+  ) -> serror::Result<Update> {
+    let group = get_check_permissions::<ServerGroup>(
+      &self.server_group,
+      user,
+      PermissionLevel::Execute.into(),
+    )
+    .await?;
+
+    let mut update = update.clone();
+
+    update_update(update.clone()).await?;
+
+    let logs = run_on_members(&group, |server| async move {
+      let action_state = action_states()
+        .server
+        .get_or_insert_default(&server.id)
+        .await;
+      let _action_guard =
+        action_state.update(|state| state.pruning_system = true)?;
+      let log = periphery_client(&server)?
+        .request(api::PruneSystem {})
+        .await?;
+      update_cache_for_server(&server, true).await;
+      Ok(log)
+    })
+    .await?;
+
+    update.logs.extend(logs);
+    update.finalize();
+    update_update(update.clone()).await?;
+
+    Ok(update)
+  }
+}
+
+/// Runs the execution on all the group members in parallel.
+/// Returns a log for each member, with the server name
+/// added to the stage.
+async fn run_on_members<F, Fut>(
+  group: &ServerGroup,
+  execution: F,
+) -> anyhow::Result<Vec<Log>>
+where
+  F: Fn(Server) -> Fut,
+  Fut: Future<Output = anyhow::Result<Log>>,
+{
+  if group.config.members.is_empty() {
+    return Err(anyhow!(
+      "Server group {} has no members",
+      group.name
+    ));
+  }
+  let futures = group.config.members.iter().map(|member| async {
+    let server = match resource::get::<Server>(member).await {
+      Ok(server) => server,
+      Err(e) => {
+        return Log::error(
+          &format!("Get Server | {member}"),
+          format_serror(&e.into()),
+        );
+      }
+    };
+    let name = server.name.clone();
+    match execution(server).await {
+      Ok(mut log) => {
+        log.stage = format!("{} | {name}", log.stage);
+        log
+      }
+      Err(e) => Log::error(
+        &format!("Execution | {name}"),
+        format_serror(&e.into()),
+      ),
+    }
+  });
+  Ok(join_all(futures).await)
+}
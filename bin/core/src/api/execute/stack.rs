@@ -60,8 +60,12 @@ impl Resolve<ExecuteArgs> for BatchDeployStack {
     ExecuteArgs { user, .. }: &ExecuteArgs,
   ) -> serror::Result<BatchExecutionResponse> {
     Ok(
-      super::batch_execute::<BatchDeployStack>(&self.pattern, user)
-        .await?,
+      super::batch_execute::<BatchDeployStack>(
+        &self.pattern,
+        self.combinator,
+        user,
+      )
+      .await?,
     )
   }
 }
@@ -127,7 +131,8 @@ impl Resolve<ExecuteArgs> for DeployStack {
     // periphery so it may sanitize the final command for safe logging (avoids exposing secret values)
     let secret_replacers = if !stack.config.skip_secret_interp {
       let VariablesAndSecrets { variables, secrets } =
-        get_variables_and_secrets().await?;
+        get_variables_and_secrets(Some(&stack.config.server_id))
+          .await?;
 
       let mut interpolator =
         Interpolator::new(Some(&variables), &secrets);
@@ -163,6 +168,8 @@ impl Resolve<ExecuteArgs> for DeployStack {
         git_token,
         registry_token,
         replacers: secret_replacers.into_iter().collect(),
+        parallel_limit: (stack.config.compose_parallel_limit > 0)
+          .then_some(stack.config.compose_parallel_limit),
       })
       .await?;
 
@@ -195,6 +202,7 @@ impl Resolve<ExecuteArgs> for DeployStack {
               .map(|f| FileContents {
                 path: f.path.clone(),
                 contents: f.contents.clone(),
+                base64_contents: f.base64_contents,
               })
               .collect(),
           ),
@@ -221,6 +229,7 @@ impl Resolve<ExecuteArgs> for DeployStack {
         deployed_hash,
         deployed_message,
         latest_services,
+        services: stack.info.services,
         remote_contents: stack
           .config
           .file_contents
@@ -288,6 +297,7 @@ impl Resolve<ExecuteArgs> for BatchDeployStackIfChanged {
     Ok(
       super::batch_execute::<BatchDeployStackIfChanged>(
         &self.pattern,
+        self.combinator,
         user,
       )
       .await?,
@@ -538,6 +548,7 @@ async fn update_deployed_contents_with_latest(
     .map(|f| FileContents {
       path: f.path,
       contents: f.contents,
+      base64_contents: f.base64_contents,
     })
     .collect::<Vec<_>>();
   if let Err(e) = (async {
@@ -668,8 +679,12 @@ impl Resolve<ExecuteArgs> for BatchPullStack {
     ExecuteArgs { user, .. }: &ExecuteArgs,
   ) -> serror::Result<BatchExecutionResponse> {
     Ok(
-      super::batch_execute::<BatchPullStack>(&self.pattern, user)
-        .await?,
+      super::batch_execute::<BatchPullStack>(
+        &self.pattern,
+        self.combinator,
+        user,
+      )
+      .await?,
     )
   }
 }
@@ -730,7 +745,8 @@ pub async fn pull_stack_inner(
   // interpolate variables / secrets
   let secret_replacers = if !stack.config.skip_secret_interp {
     let VariablesAndSecrets { variables, secrets } =
-      get_variables_and_secrets().await?;
+      get_variables_and_secrets(Some(&stack.config.server_id))
+        .await?;
 
     let mut interpolator =
       Interpolator::new(Some(&variables), &secrets);
@@ -934,9 +950,13 @@ impl Resolve<ExecuteArgs> for BatchDestroyStack {
     self,
     ExecuteArgs { user, .. }: &ExecuteArgs,
   ) -> serror::Result<BatchExecutionResponse> {
-    super::batch_execute::<BatchDestroyStack>(&self.pattern, user)
-      .await
-      .map_err(Into::into)
+    super::batch_execute::<BatchDestroyStack>(
+      &self.pattern,
+      self.combinator,
+      user,
+    )
+    .await
+    .map_err(Into::into)
   }
 }
 
@@ -1004,7 +1024,8 @@ impl Resolve<ExecuteArgs> for RunStackService {
 
     let secret_replacers = if !stack.config.skip_secret_interp {
       let VariablesAndSecrets { variables, secrets } =
-        get_variables_and_secrets().await?;
+        get_variables_and_secrets(Some(&stack.config.server_id))
+          .await?;
 
       let mut interpolator =
         Interpolator::new(Some(&variables), &secrets);
@@ -20,6 +20,7 @@ use komodo_client::{
     procedure::Procedure,
     repo::Repo,
     server::Server,
+    server_group::ServerGroup,
     stack::Stack,
     sync::ResourceSync,
     update::{Log, Update},
@@ -58,6 +59,7 @@ impl Resolve<ExecuteArgs> for RunSync {
       sync,
       resource_type: match_resource_type,
       resources: match_resources,
+      dry_run,
     } = self;
     let sync = get_check_permissions::<entities::sync::ResourceSync>(
       &sync,
@@ -66,6 +68,8 @@ impl Resolve<ExecuteArgs> for RunSync {
     )
     .await?;
 
+    crate::sync::check_namespace_scope(&sync.config)?;
+
     let repo = if !sync.config.files_on_host
       && !sync.config.linked_repo.is_empty()
     {
@@ -165,6 +169,10 @@ impl Resolve<ExecuteArgs> for RunSync {
                 .syncs
                 .get(&name_or_id)
                 .map(|s| s.name.clone()),
+              ResourceTargetVariant::ServerGroup => all_resources
+                .server_groups
+                .get(&name_or_id)
+                .map(|s| s.name.clone()),
               ResourceTargetVariant::System => None,
             },
             Err(_) => Some(name_or_id),
@@ -224,6 +232,8 @@ impl Resolve<ExecuteArgs> for RunSync {
         match_resources.as_deref(),
         &id_to_tags,
         &sync.config.match_tags,
+        &sync.config.namespace,
+        dry_run,
       )
       .await?
     } else {
@@ -237,6 +247,8 @@ impl Resolve<ExecuteArgs> for RunSync {
         match_resources.as_deref(),
         &id_to_tags,
         &sync.config.match_tags,
+        &sync.config.namespace,
+        dry_run,
       )
       .await?
     } else {
@@ -250,6 +262,8 @@ impl Resolve<ExecuteArgs> for RunSync {
         match_resources.as_deref(),
         &id_to_tags,
         &sync.config.match_tags,
+        &sync.config.namespace,
+        dry_run,
       )
       .await?
     } else {
@@ -263,6 +277,8 @@ impl Resolve<ExecuteArgs> for RunSync {
         match_resources.as_deref(),
         &id_to_tags,
         &sync.config.match_tags,
+        &sync.config.namespace,
+        dry_run,
       )
       .await?
     } else {
@@ -276,6 +292,8 @@ impl Resolve<ExecuteArgs> for RunSync {
         match_resources.as_deref(),
         &id_to_tags,
         &sync.config.match_tags,
+        &sync.config.namespace,
+        dry_run,
       )
       .await?
     } else {
@@ -289,6 +307,8 @@ impl Resolve<ExecuteArgs> for RunSync {
         match_resources.as_deref(),
         &id_to_tags,
         &sync.config.match_tags,
+        &sync.config.namespace,
+        dry_run,
       )
       .await?
     } else {
@@ -302,6 +322,8 @@ impl Resolve<ExecuteArgs> for RunSync {
         match_resources.as_deref(),
         &id_to_tags,
         &sync.config.match_tags,
+        &sync.config.namespace,
+        dry_run,
       )
       .await?
     } else {
@@ -315,6 +337,8 @@ impl Resolve<ExecuteArgs> for RunSync {
         match_resources.as_deref(),
         &id_to_tags,
         &sync.config.match_tags,
+        &sync.config.namespace,
+        dry_run,
       )
       .await?
     } else {
@@ -328,6 +352,23 @@ impl Resolve<ExecuteArgs> for RunSync {
         match_resources.as_deref(),
         &id_to_tags,
         &sync.config.match_tags,
+        &sync.config.namespace,
+        dry_run,
+      )
+      .await?
+    } else {
+      Default::default()
+    };
+    let server_group_deltas = if sync.config.include_resources {
+      get_updates_for_execution::<ServerGroup>(
+        resources.server_groups,
+        delete,
+        match_resource_type,
+        match_resources.as_deref(),
+        &id_to_tags,
+        &sync.config.match_tags,
+        &sync.config.namespace,
+        dry_run,
       )
       .await?
     } else {
@@ -341,6 +382,8 @@ impl Resolve<ExecuteArgs> for RunSync {
         match_resources.as_deref(),
         &id_to_tags,
         &sync.config.match_tags,
+        &sync.config.namespace,
+        dry_run,
       )
       .await?
     } else {
@@ -363,6 +406,19 @@ impl Resolve<ExecuteArgs> for RunSync {
     } else {
       Default::default()
     };
+    let (tags_to_create, tags_to_update, tags_to_delete) =
+      if match_resource_type.is_none()
+        && match_resources.is_none()
+        && sync.config.include_tags
+      {
+        crate::sync::tags::get_updates_for_execution(
+          resources.tags,
+          delete,
+        )
+        .await?
+      } else {
+        Default::default()
+      };
     let (
       user_groups_to_create,
       user_groups_to_update,
@@ -391,6 +447,10 @@ impl Resolve<ExecuteArgs> for RunSync {
       && repo_deltas.no_changes()
       && procedure_deltas.no_changes()
       && action_deltas.no_changes()
+      && server_group_deltas.no_changes()
+      && tags_to_create.is_empty()
+      && tags_to_update.is_empty()
+      && tags_to_delete.is_empty()
       && user_groups_to_create.is_empty()
       && user_groups_to_update.is_empty()
       && user_groups_to_delete.is_empty()
@@ -410,6 +470,42 @@ impl Resolve<ExecuteArgs> for RunSync {
       return Ok(update);
     }
 
+    if dry_run {
+      let logs = [
+        crate::sync::variables::dry_run_log(
+          &variables_to_create,
+          &variables_to_update,
+          &variables_to_delete,
+        ),
+        crate::sync::tags::dry_run_log(
+          &tags_to_create,
+          &tags_to_update,
+          &tags_to_delete,
+        ),
+        crate::sync::user_groups::dry_run_log(
+          &user_groups_to_create,
+          &user_groups_to_update,
+          &user_groups_to_delete,
+        ),
+        resource_sync_deltas.dry_run_log("Dry Run Resource Syncs"),
+        server_deltas.dry_run_log("Dry Run Servers"),
+        alerter_deltas.dry_run_log("Dry Run Alerters"),
+        action_deltas.dry_run_log("Dry Run Actions"),
+        builder_deltas.dry_run_log("Dry Run Builders"),
+        server_group_deltas.dry_run_log("Dry Run Server Groups"),
+        repo_deltas.dry_run_log("Dry Run Repos"),
+        build_deltas.dry_run_log("Dry Run Builds"),
+        deployment_deltas.dry_run_log("Dry Run Deployments"),
+        stack_deltas.dry_run_log("Dry Run Stacks"),
+        procedure_deltas.dry_run_log("Dry Run Procedures"),
+        crate::sync::deploy::dry_run_log(&deploy_cache),
+      ];
+      update.logs.extend(logs.into_iter().flatten());
+      update.finalize();
+      update_update(update.clone()).await?;
+      return Ok(update);
+    }
+
     // =================
 
     // No deps
@@ -422,6 +518,15 @@ impl Resolve<ExecuteArgs> for RunSync {
       )
       .await,
     );
+    maybe_extend(
+      &mut update.logs,
+      crate::sync::tags::run_updates(
+        tags_to_create,
+        tags_to_update,
+        tags_to_delete,
+      )
+      .await,
+    );
     maybe_extend(
       &mut update.logs,
       crate::sync::user_groups::run_updates(
@@ -457,6 +562,10 @@ impl Resolve<ExecuteArgs> for RunSync {
       &mut update.logs,
       Repo::execute_sync_updates(repo_deltas).await,
     );
+    maybe_extend(
+      &mut update.logs,
+      ServerGroup::execute_sync_updates(server_group_deltas).await,
+    );
 
     // Dependant on builder
     maybe_extend(
@@ -1,7 +1,19 @@
+use anyhow::{Context, anyhow};
+use axum::{
+  extract::Request, http::header::CONTENT_LENGTH, middleware::Next,
+  response::Response,
+};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serror::AddStatusCodeError;
+
+use crate::config::core_config;
+
 pub mod auth;
 pub mod execute;
 pub mod read;
 pub mod terminal;
+pub mod update;
 pub mod user;
 pub mod write;
 
@@ -9,3 +21,164 @@ pub mod write;
 struct Variant {
   variant: String,
 }
+
+/// Parses the request for the `/{variant}` handlers.
+/// On failure, the error names the invalid field
+/// along with the type expected by the variant.
+fn parse_variant_request<T: DeserializeOwned>(
+  variant: &str,
+  params: serde_json::Value,
+) -> serror::Result<T> {
+  // The "type" must come before "params" so the params are deserialized
+  // directly into the variant, and the path to the invalid field is kept.
+  let body = format!(
+    r#"{{"type":{},"params":{params}}}"#,
+    serde_json::to_string(variant)
+      .context("Failed to serialize request variant")?
+  );
+  let deserializer = &mut serde_json::Deserializer::from_str(&body);
+  serde_path_to_error::deserialize(deserializer).map_err(|e| {
+    let path = e.path().to_string();
+    let field = path.strip_prefix("params.").unwrap_or(&path);
+    anyhow::Error::from(e.into_inner())
+      .context(format!(
+        "Invalid {variant} request | field: '{field}'"
+      ))
+      .status_code(StatusCode::BAD_REQUEST)
+  })
+}
+
+/// Rejects all requests while Core is configured as `read_only`.
+/// Layered onto the `/write`, `/execute` and `/listener` routers.
+pub async fn read_only_guard(
+  req: Request,
+  next: Next,
+) -> serror::Result<Response> {
+  read_only_guard_inner(core_config().read_only, req, next).await
+}
+
+async fn read_only_guard_inner(
+  read_only: bool,
+  req: Request,
+  next: Next,
+) -> serror::Result<Response> {
+  check_read_only(read_only)
+    .status_code(StatusCode::SERVICE_UNAVAILABLE)?;
+  Ok(next.run(req).await)
+}
+
+/// Errors when Core is configured as `read_only`.
+/// Also checked wherever executions are dispatched,
+/// so schedules and procedure stages don't run either.
+pub fn check_read_only(read_only: bool) -> anyhow::Result<()> {
+  if read_only {
+    Err(anyhow!("Komodo Core is in read only mode"))
+  } else {
+    Ok(())
+  }
+}
+
+/// Rejects requests with a body larger than `max_request_body_mb`,
+/// with an error naming the limit.
+/// Layered onto the `/write` and `/execute` routers.
+pub async fn request_body_limit_guard(
+  req: Request,
+  next: Next,
+) -> serror::Result<Response> {
+  let max_mb = core_config().max_request_body_mb;
+  let content_length = req
+    .headers()
+    .get(CONTENT_LENGTH)
+    .and_then(|value| value.to_str().ok())
+    .and_then(|value| value.parse::<u64>().ok());
+  if content_length
+    .is_some_and(|length| length > max_mb.saturating_mul(1024 * 1024))
+  {
+    return Err(
+      anyhow!(
+        "Request body exceeds the {max_mb} MB limit. \
+        This can be raised with `max_request_body_mb` in the Core config."
+      )
+      .status_code(StatusCode::PAYLOAD_TOO_LARGE),
+    );
+  }
+  Ok(next.run(req).await)
+}
+
+#[cfg(test)]
+mod tests {
+  use axum::{Router, body::Body, middleware, routing::post};
+  use tower::ServiceExt;
+
+  use super::*;
+
+  fn router(read_only: bool) -> Router {
+    Router::new()
+      .route(
+        "/write",
+        post(|| async { "written" }).layer(middleware::from_fn(
+          move |req: Request, next: Next| {
+            read_only_guard_inner(read_only, req, next)
+          },
+        )),
+      )
+      .route("/read", post(|| async { "read" }))
+  }
+
+  async fn status(router: Router, uri: &str) -> StatusCode {
+    router
+      .oneshot(
+        axum::http::Request::post(uri).body(Body::empty()).unwrap(),
+      )
+      .await
+      .unwrap()
+      .status()
+  }
+
+  #[tokio::test]
+  async fn read_only_rejects_writes() {
+    assert_eq!(
+      status(router(true), "/write").await,
+      StatusCode::SERVICE_UNAVAILABLE
+    );
+    assert_eq!(status(router(true), "/read").await, StatusCode::OK);
+    assert_eq!(status(router(false), "/write").await, StatusCode::OK);
+  }
+
+  #[derive(Debug, serde::Deserialize)]
+  #[serde(tag = "type", content = "params")]
+  enum TestRequest {
+    ListUpdates(komodo_client::api::read::ListUpdates),
+  }
+
+  #[test]
+  fn variant_request_names_wrong_typed_field() {
+    let e = parse_variant_request::<TestRequest>(
+      "ListUpdates",
+      serde_json::json!({ "page": "two" }),
+    )
+    .unwrap_err();
+    assert_eq!(e.status, StatusCode::BAD_REQUEST);
+    let message = format!("{:#}", e.error);
+    assert!(message.contains("Invalid ListUpdates request"));
+    assert!(message.contains("field: 'page'"));
+    assert!(message.contains("expected u32"), "{message}");
+  }
+
+  #[test]
+  fn variant_request_parses_valid_params() {
+    let TestRequest::ListUpdates(req) =
+      parse_variant_request::<TestRequest>(
+        "ListUpdates",
+        serde_json::json!({ "page": 2 }),
+      )
+      .unwrap();
+    assert_eq!(req.page, 2);
+  }
+
+  #[test]
+  fn check_read_only_errors_only_when_read_only() {
+    assert!(check_read_only(false).is_ok());
+    assert!(check_read_only(true).is_err());
+  }
+}
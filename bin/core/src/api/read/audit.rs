@@ -0,0 +1,52 @@
+use anyhow::{Context, anyhow};
+use database::mungos::{
+  find::find_collect,
+  mongodb::{bson::doc, options::FindOptions},
+};
+use komodo_client::api::read::{ListAuditLog, ListAuditLogResponse};
+use reqwest::StatusCode;
+use resolver_api::Resolve;
+use serror::AddStatusCodeError;
+
+use crate::state::db_client;
+
+use super::ReadArgs;
+
+const MAX_AUDIT_ENTRIES: i64 = 1000;
+
+impl Resolve<ReadArgs> for ListAuditLog {
+  async fn resolve(
+    self,
+    ReadArgs { user }: &ReadArgs,
+  ) -> serror::Result<ListAuditLogResponse> {
+    if !user.admin {
+      return Err(
+        anyhow!("This method is admin only.")
+          .status_code(StatusCode::FORBIDDEN),
+      );
+    }
+    let mut filter = doc! {};
+    if let Some(user_id) = self.user_id {
+      filter.insert("user_id", user_id);
+    }
+    if let Some(target) = &self.resource_target {
+      let (variant, id) = target.extract_variant_id();
+      filter.insert("resource_target.type", variant.as_ref());
+      filter.insert("resource_target.id", id.as_str());
+    }
+    let mut timestamp = doc! { "$gte": self.from_ts };
+    if self.to_ts > 0 {
+      timestamp.insert("$lte", self.to_ts);
+    }
+    filter.insert("timestamp", timestamp);
+    let mut options = FindOptions::builder()
+      .sort(doc! { "timestamp": -1 })
+      .build();
+    options.limit = Some(MAX_AUDIT_ENTRIES);
+    let entries =
+      find_collect(&db_client().audit_log, filter, options)
+        .await
+        .context("Failed to query db for audit log")?;
+    Ok(entries)
+  }
+}
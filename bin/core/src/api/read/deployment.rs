@@ -1,6 +1,7 @@
 use std::{cmp, collections::HashSet};
 
 use anyhow::{Context, anyhow};
+use interpolate::Interpolator;
 use komodo_client::{
   api::read::*,
   entities::{
@@ -14,11 +15,19 @@ use komodo_client::{
     update::Log,
   },
 };
-use periphery_client::api::{self, container::InspectContainer};
+use periphery_client::api::{
+  self,
+  container::{GetContainerEnv, InspectContainer},
+};
 use resolver_api::Resolve;
 
 use crate::{
-  helpers::{periphery_client, query::get_all_tags},
+  helpers::{
+    periphery_client,
+    query::{
+      VariablesAndSecrets, get_all_tags, get_variables_and_secrets,
+    },
+  },
   permission::get_check_permissions,
   resource,
   state::{
@@ -241,6 +250,65 @@ impl Resolve<ReadArgs> for InspectDeploymentContainer {
   }
 }
 
+impl Resolve<ReadArgs> for GetDeploymentContainerEnv {
+  async fn resolve(
+    self,
+    ReadArgs { user }: &ReadArgs,
+  ) -> serror::Result<Vec<String>> {
+    let mut deployment = get_check_permissions::<Deployment>(
+      &self.deployment,
+      user,
+      PermissionLevel::Read.inspect(),
+    )
+    .await?;
+    if deployment.config.server_id.is_empty() {
+      return Err(
+        anyhow!(
+          "Cannot get container env, deployment not attached to any server"
+        )
+        .into(),
+      );
+    }
+    let server =
+      resource::get::<Server>(&deployment.config.server_id).await?;
+    let cache = server_status_cache()
+      .get_or_insert_default(&server.id)
+      .await;
+    if cache.state != ServerState::Ok {
+      return Err(
+        anyhow!(
+          "Cannot get container env: server is {:?}",
+          cache.state
+        )
+        .into(),
+      );
+    }
+    let env = periphery_client(&server)?
+      .request(GetContainerEnv {
+        container: deployment.name.clone(),
+      })
+      .await?;
+    // Interpolate the deployment the same way as on deploy,
+    // to find the secret values which need to be redacted.
+    let VariablesAndSecrets { variables, secrets } =
+      get_variables_and_secrets(Some(&deployment.config.server_id))
+        .await?;
+    let mut interpolator =
+      Interpolator::new(Some(&variables), &secrets);
+    interpolator.interpolate_deployment(&mut deployment)?;
+    let replacers = interpolator
+      .secret_replacers
+      .into_iter()
+      .collect::<Vec<_>>();
+    Ok(
+      env
+        .into_iter()
+        .map(|line| svi::replace_in_string(&line, &replacers))
+        .collect(),
+    )
+  }
+}
+
 impl Resolve<ReadArgs> for GetDeploymentStats {
   async fn resolve(
     self,
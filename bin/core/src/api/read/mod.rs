@@ -21,21 +21,23 @@ use komodo_client::{
 use resolver_api::Resolve;
 use response::Response;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
 use serror::Json;
 use typeshare::typeshare;
 use uuid::Uuid;
 
 use crate::{
-  auth::auth_request, config::core_config, helpers::periphery_client,
+  auth::{ApiKeyCeiling, auth_request, check_api_key_ceiling},
+  config::core_config,
+  helpers::periphery_client,
   resource,
 };
 
-use super::Variant;
+use super::{Variant, parse_variant_request};
 
 mod action;
 mod alert;
 mod alerter;
+mod audit;
 mod build;
 mod builder;
 mod deployment;
@@ -45,6 +47,7 @@ mod provider;
 mod repo;
 mod schedule;
 mod server;
+mod server_group;
 mod stack;
 mod sync;
 mod tag;
@@ -76,10 +79,13 @@ enum ReadRequest {
   GetPermission(GetPermission),
   FindUser(FindUser),
   ListUsers(ListUsers),
+  ListServiceAccounts(ListServiceAccounts),
+  ListUserSessions(ListUserSessions),
   ListApiKeys(ListApiKeys),
   ListApiKeysForServiceUser(ListApiKeysForServiceUser),
   ListPermissions(ListPermissions),
   ListUserTargetPermissions(ListUserTargetPermissions),
+  ListAuditLog(ListAuditLog),
 
   // ==== USER GROUP ====
   GetUserGroup(GetUserGroup),
@@ -119,6 +125,7 @@ enum ReadRequest {
   InspectDockerImage(InspectDockerImage),
   ListDockerImageHistory(ListDockerImageHistory),
   InspectDockerVolume(InspectDockerVolume),
+  GetVolumeBrowserListing(GetVolumeBrowserListing),
   GetDockerContainersSummary(GetDockerContainersSummary),
   ListAllDockerContainers(ListAllDockerContainers),
   ListDockerContainers(ListDockerContainers),
@@ -128,6 +135,11 @@ enum ReadRequest {
   ListComposeProjects(ListComposeProjects),
   ListTerminals(ListTerminals),
 
+  // ==== SERVER GROUP ====
+  GetServerGroup(GetServerGroup),
+  ListServerGroups(ListServerGroups),
+  ListFullServerGroups(ListFullServerGroups),
+
   // ==== SERVER STATS ====
   GetSystemInformation(GetSystemInformation),
   GetSystemStats(GetSystemStats),
@@ -139,8 +151,10 @@ enum ReadRequest {
   GetStackActionState(GetStackActionState),
   GetStackWebhooksEnabled(GetStackWebhooksEnabled),
   GetStackLog(GetStackLog),
+  GetStackServiceLogs(GetStackServiceLogs),
   SearchStackLog(SearchStackLog),
   InspectStackContainer(InspectStackContainer),
+  GetStackContainerEnv(GetStackContainerEnv),
   ListStacks(ListStacks),
   ListFullStacks(ListFullStacks),
   ListStackServices(ListStackServices),
@@ -156,6 +170,7 @@ enum ReadRequest {
   GetDeploymentLog(GetDeploymentLog),
   SearchDeploymentLog(SearchDeploymentLog),
   InspectDeploymentContainer(InspectDeploymentContainer),
+  GetDeploymentContainerEnv(GetDeploymentContainerEnv),
   ListDeployments(ListDeployments),
   ListFullDeployments(ListFullDeployments),
   ListCommonDeploymentExtraArgs(ListCommonDeploymentExtraArgs),
@@ -183,6 +198,7 @@ enum ReadRequest {
   GetResourceSyncsSummary(GetResourceSyncsSummary),
   GetResourceSync(GetResourceSync),
   GetResourceSyncActionState(GetResourceSyncActionState),
+  ListResourceSyncConflicts(ListResourceSyncConflicts),
   GetSyncWebhooksEnabled(GetSyncWebhooksEnabled),
   ListResourceSyncs(ListResourceSyncs),
   ListFullResourceSyncs(ListFullResourceSyncs),
@@ -202,6 +218,7 @@ enum ReadRequest {
   // ==== TOML ====
   ExportAllResourcesToToml(ExportAllResourcesToToml),
   ExportResourcesToToml(ExportResourcesToToml),
+  ImportResourcesToToml(ImportResourcesToToml),
 
   // ==== TAG ====
   GetTag(GetTag),
@@ -218,6 +235,7 @@ enum ReadRequest {
   // ==== VARIABLE ====
   GetVariable(GetVariable),
   ListVariables(ListVariables),
+  GetAllVariables(GetAllVariables),
 
   // ==== PROVIDER ====
   GetGitProviderAccount(GetGitProviderAccount),
@@ -235,21 +253,25 @@ pub fn router() -> Router {
 
 async fn variant_handler(
   user: Extension<User>,
+  ceiling: Option<Extension<ApiKeyCeiling>>,
   Path(Variant { variant }): Path<Variant>,
   Json(params): Json<serde_json::Value>,
 ) -> serror::Result<axum::response::Response> {
-  let req: ReadRequest = serde_json::from_value(json!({
-    "type": variant,
-    "params": params,
-  }))?;
-  handler(user, Json(req)).await
+  let req: ReadRequest = parse_variant_request(&variant, params)?;
+  handler(user, ceiling, Json(req)).await
 }
 
-#[instrument(name = "ReadHandler", level = "debug", skip(user), fields(user_id = user.id))]
+#[instrument(name = "ReadHandler", level = "debug", skip(user, ceiling), fields(user_id = user.id))]
 async fn handler(
   Extension(user): Extension<User>,
+  ceiling: Option<Extension<ApiKeyCeiling>>,
   Json(request): Json<ReadRequest>,
 ) -> serror::Result<axum::response::Response> {
+  check_api_key_ceiling(
+    ceiling.as_deref(),
+    PermissionLevel::Read,
+    "read requests",
+  )?;
   let timer = Instant::now();
   let req_id = Uuid::new_v4();
   debug!("/read request | user: {}", user.username);
@@ -5,9 +5,7 @@ use std::{
 };
 
 use anyhow::{Context, anyhow};
-use async_timing_util::{
-  FIFTEEN_SECONDS_MS, get_timelength_in_ms, unix_timestamp_ms,
-};
+use async_timing_util::{FIFTEEN_SECONDS_MS, unix_timestamp_ms};
 use database::mungos::{
   find::find_collect,
   mongodb::{bson::doc, options::FindOptions},
@@ -23,7 +21,7 @@ use komodo_client::{
       },
       image::{Image, ImageHistoryResponseItem},
       network::Network,
-      volume::Volume,
+      volume::{Volume, VolumeEntry},
     },
     komodo_timestamp,
     permission::PermissionLevel,
@@ -322,9 +320,7 @@ impl Resolve<ReadArgs> for GetHistoricalServerStats {
       PermissionLevel::Read.into(),
     )
     .await?;
-    let granularity =
-      get_timelength_in_ms(granularity.to_string().parse().unwrap())
-        as i64;
+    let granularity = granularity.as_millis();
     let mut ts_vec = Vec::<i64>::new();
     let curr_ts = unix_timestamp_ms() as i64;
     let mut curr_ts = curr_ts
@@ -792,6 +788,36 @@ impl Resolve<ReadArgs> for InspectDockerVolume {
   }
 }
 
+impl Resolve<ReadArgs> for GetVolumeBrowserListing {
+  async fn resolve(
+    self,
+    ReadArgs { user }: &ReadArgs,
+  ) -> serror::Result<Vec<VolumeEntry>> {
+    let server = get_check_permissions::<Server>(
+      &self.server,
+      user,
+      PermissionLevel::Read.inspect(),
+    )
+    .await?;
+    let cache = server_status_cache()
+      .get_or_insert_default(&server.id)
+      .await;
+    if cache.state != ServerState::Ok {
+      return Err(
+        anyhow!("Cannot browse volume: server is {:?}", cache.state)
+          .into(),
+      );
+    }
+    let res = periphery_client(&server)?
+      .request(periphery::volume::GetVolumeBrowserListing {
+        volume: self.volume,
+        path: self.path,
+      })
+      .await?;
+    Ok(res)
+  }
+}
+
 impl Resolve<ReadArgs> for ListComposeProjects {
   async fn resolve(
     self,
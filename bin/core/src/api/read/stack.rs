@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
 use anyhow::{Context, anyhow};
+use interpolate::Interpolator;
 use komodo_client::{
   api::read::*,
   entities::{
@@ -13,13 +14,18 @@ use komodo_client::{
 };
 use periphery_client::api::{
   compose::{GetComposeLog, GetComposeLogSearch},
-  container::InspectContainer,
+  container::{GetContainerEnv, InspectContainer},
 };
 use resolver_api::Resolve;
 
 use crate::{
   config::core_config,
-  helpers::{periphery_client, query::get_all_tags},
+  helpers::{
+    periphery_client,
+    query::{
+      VariablesAndSecrets, get_all_tags, get_variables_and_secrets,
+    },
+  },
   permission::get_check_permissions,
   resource,
   stack::get_stack_and_server,
@@ -102,6 +108,54 @@ impl Resolve<ReadArgs> for GetStackLog {
   }
 }
 
+impl Resolve<ReadArgs> for GetStackServiceLogs {
+  async fn resolve(
+    self,
+    ReadArgs { user }: &ReadArgs,
+  ) -> serror::Result<GetStackServiceLogsResponse> {
+    let GetStackServiceLogs {
+      stack,
+      service,
+      tail,
+      timestamps,
+    } = self;
+    let (stack, server) = get_stack_and_server(
+      &stack,
+      user,
+      PermissionLevel::Read.logs(),
+      true,
+    )
+    .await?;
+    let known = stack_status_cache()
+      .get(&stack.id)
+      .await
+      .unwrap_or_default()
+      .curr
+      .services
+      .iter()
+      .any(|s| s.service == service);
+    if !known {
+      return Err(
+        anyhow!(
+          "No service found matching '{service}' on Stack {}",
+          stack.name
+        )
+        .into(),
+      );
+    }
+    let res = periphery_client(&server)?
+      .request(GetComposeLog {
+        project: stack.project_name(false),
+        services: vec![service],
+        tail,
+        timestamps,
+      })
+      .await
+      .context("Failed to get stack service log from periphery")?;
+    Ok(res)
+  }
+}
+
 impl Resolve<ReadArgs> for SearchStackLog {
   async fn resolve(
     self,
@@ -191,6 +245,79 @@ impl Resolve<ReadArgs> for InspectStackContainer {
   }
 }
 
+impl Resolve<ReadArgs> for GetStackContainerEnv {
+  async fn resolve(
+    self,
+    ReadArgs { user }: &ReadArgs,
+  ) -> serror::Result<Vec<String>> {
+    let GetStackContainerEnv { stack, service } = self;
+    let mut stack = get_check_permissions::<Stack>(
+      &stack,
+      user,
+      PermissionLevel::Read.inspect(),
+    )
+    .await?;
+    if stack.config.server_id.is_empty() {
+      return Err(
+        anyhow!(
+          "Cannot get container env, stack not attached to any server"
+        )
+        .into(),
+      );
+    }
+    let server =
+      resource::get::<Server>(&stack.config.server_id).await?;
+    let cache = server_status_cache()
+      .get_or_insert_default(&server.id)
+      .await;
+    if cache.state != ServerState::Ok {
+      return Err(
+        anyhow!(
+          "Cannot get container env: server is {:?}",
+          cache.state
+        )
+        .into(),
+      );
+    }
+    let services = &stack_status_cache()
+      .get(&stack.id)
+      .await
+      .unwrap_or_default()
+      .curr
+      .services;
+    let Some(container) = services
+      .iter()
+      .find(|s| s.service == service)
+      .and_then(|s| s.container.as_ref().map(|c| c.name.clone()))
+    else {
+      return Err(anyhow!(
+        "No service found matching '{service}'. Was the stack last deployed manually?"
+      ).into());
+    };
+    let env = periphery_client(&server)?
+      .request(GetContainerEnv { container })
+      .await?;
+    // Interpolate the stack the same way as on deploy,
+    // to find the secret values which need to be redacted.
+    let VariablesAndSecrets { variables, secrets } =
+      get_variables_and_secrets(Some(&stack.config.server_id))
+        .await?;
+    let mut interpolator =
+      Interpolator::new(Some(&variables), &secrets);
+    interpolator.interpolate_stack(&mut stack)?;
+    let replacers = interpolator
+      .secret_replacers
+      .into_iter()
+      .collect::<Vec<_>>();
+    Ok(
+      env
+        .into_iter()
+        .map(|line| svi::replace_in_string(&line, &replacers))
+        .collect(),
+    )
+  }
+}
+
 impl Resolve<ReadArgs> for ListCommonStackExtraArgs {
   async fn resolve(
     self,
@@ -1,11 +1,15 @@
+use std::collections::BTreeSet;
+
 use anyhow::Context;
 use komodo_client::{
   api::read::*,
   entities::{
+    ResourceTarget,
     config::core::CoreConfig,
     permission::PermissionLevel,
     sync::{
-      ResourceSync, ResourceSyncActionState, ResourceSyncListItem,
+      DiffData, ResourceSync, ResourceSyncActionState,
+      ResourceSyncListItem, SyncConflict,
     },
   },
 };
@@ -102,6 +106,131 @@ impl Resolve<ReadArgs> for GetResourceSyncActionState {
   }
 }
 
+impl Resolve<ReadArgs> for ListResourceSyncConflicts {
+  async fn resolve(
+    self,
+    args: &ReadArgs,
+  ) -> serror::Result<ListResourceSyncConflictsResponse> {
+    let sync = get_check_permissions::<ResourceSync>(
+      &self.sync,
+      &args.user,
+      PermissionLevel::Read.into(),
+    )
+    .await?;
+    let mut conflicts = Vec::new();
+    for diff in sync.info.resource_updates {
+      // Creates / deletes don't have a live / sync counterpart to compare
+      let DiffData::Update { proposed, .. } = diff.data else {
+        continue;
+      };
+      // Export the live resource now, rather than using
+      // the state stored at the last refresh.
+      let live = match (ExportResourcesToToml {
+        targets: vec![diff.target.clone()],
+        user_groups: Vec::new(),
+        include_variables: false,
+        include_tags: false,
+        namespace: String::new(),
+      })
+      .resolve(args)
+      .await
+      {
+        Ok(live) => live.toml,
+        Err(e) => {
+          // Don't fail the other targets, eg if the resource
+          // was deleted since the last refresh.
+          warn!(
+            "Failed to export {:?} for sync {} conflicts | {:#}",
+            diff.target, sync.name, e.error
+          );
+          continue;
+        }
+      };
+      match target_conflicts(&diff.target, &live, &proposed) {
+        Ok(target_conflicts) => conflicts.extend(target_conflicts),
+        Err(e) => warn!(
+          "Failed to compare {:?} for sync {} conflicts | {e:#}",
+          diff.target, sync.name
+        ),
+      }
+    }
+    Ok(conflicts)
+  }
+}
+
+/// Compares the live and proposed resource tomls.
+fn target_conflicts(
+  target: &ResourceTarget,
+  live: &str,
+  proposed: &str,
+) -> anyhow::Result<Vec<SyncConflict>> {
+  let mut live = resource_toml_table(live)?;
+  let mut proposed = resource_toml_table(proposed)?;
+  // These only control how the sync is run,
+  // they aren't part of the live resource.
+  for key in ["deploy", "after"] {
+    live.remove(key);
+    proposed.remove(key);
+  }
+  let mut conflicts = Vec::new();
+  push_conflicts(target, "", &live, &proposed, &mut conflicts);
+  Ok(conflicts)
+}
+
+/// The resource toml contains a single `[[resource_type]]` entry.
+fn resource_toml_table(
+  contents: &str,
+) -> anyhow::Result<toml::Table> {
+  toml::from_str::<toml::Table>(contents)
+    .context("Failed to parse resource toml")?
+    .into_iter()
+    .next()
+    .and_then(|(_, resources)| match resources {
+      toml::Value::Array(resources) => resources.into_iter().next(),
+      _ => None,
+    })
+    .and_then(|resource| match resource {
+      toml::Value::Table(resource) => Some(resource),
+      _ => None,
+    })
+    .context("Resource toml is missing the resource table")
+}
+
+/// Structurally compares the tables,
+/// recursing into nested tables like `config`.
+fn push_conflicts(
+  target: &ResourceTarget,
+  prefix: &str,
+  live: &toml::Table,
+  proposed: &toml::Table,
+  conflicts: &mut Vec<SyncConflict>,
+) {
+  let keys =
+    live.keys().chain(proposed.keys()).collect::<BTreeSet<_>>();
+  for key in keys {
+    let field = if prefix.is_empty() {
+      key.to_string()
+    } else {
+      format!("{prefix}.{key}")
+    };
+    match (live.get(key), proposed.get(key)) {
+      (
+        Some(toml::Value::Table(live)),
+        Some(toml::Value::Table(proposed)),
+      ) => push_conflicts(target, &field, live, proposed, conflicts),
+      (live, proposed) if live != proposed => {
+        conflicts.push(SyncConflict {
+          resource_target: target.clone(),
+          field,
+          live_value: live.map(toml::Value::to_string),
+          sync_value: proposed.map(toml::Value::to_string),
+        })
+      }
+      _ => {}
+    }
+  }
+}
+
 impl Resolve<ReadArgs> for GetResourceSyncsSummary {
   async fn resolve(
     self,
@@ -127,6 +256,7 @@ impl Resolve<ReadArgs> for GetResourceSyncsSummary {
       if !(resource_sync.info.pending_deploy.to_deploy == 0
         && resource_sync.info.resource_updates.is_empty()
         && resource_sync.info.variable_updates.is_empty()
+        && resource_sync.info.tag_updates.is_empty()
         && resource_sync.info.user_group_updates.is_empty())
       {
         res.pending += 1;
@@ -242,3 +372,88 @@ impl Resolve<ReadArgs> for GetSyncWebhooksEnabled {
     })
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const LIVE: &str = r#"
+[[deployment]]
+name = "api"
+tags = ["prod"]
+
+[deployment.config]
+server = "server-1"
+image = "api:1.0"
+ports = ["8080:8080"]
+"#;
+
+  const PROPOSED: &str = r#"
+[[deployment]]
+name = "api"
+tags = ["prod"]
+deploy = true
+
+[deployment.config]
+server = "server-1"
+image = "api:1.1"
+network = "host"
+"#;
+
+  fn target() -> ResourceTarget {
+    ResourceTarget::Deployment(String::from("api"))
+  }
+
+  #[test]
+  fn reads_resource_table() {
+    let table = resource_toml_table(LIVE).unwrap();
+    assert_eq!(table["name"].as_str(), Some("api"));
+    assert!(table["config"].is_table());
+  }
+
+  #[test]
+  fn rejects_toml_without_resource() {
+    assert!(resource_toml_table("").is_err());
+    assert!(resource_toml_table("name = \"api\"").is_err());
+    assert!(resource_toml_table("not toml [").is_err());
+  }
+
+  #[test]
+  fn finds_nested_field_conflicts() {
+    let conflicts = target_conflicts(&target(), LIVE, PROPOSED)
+      .unwrap()
+      .into_iter()
+      .map(|conflict| {
+        (conflict.field, conflict.live_value, conflict.sync_value)
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      conflicts,
+      [
+        (
+          String::from("config.image"),
+          Some(String::from("\"api:1.0\"")),
+          Some(String::from("\"api:1.1\""))
+        ),
+        (
+          String::from("config.network"),
+          None,
+          Some(String::from("\"host\""))
+        ),
+        (
+          String::from("config.ports"),
+          Some(String::from("[\"8080:8080\"]")),
+          None
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn no_conflicts_for_equal_tables() {
+    let live = resource_toml_table(LIVE).unwrap();
+    let mut conflicts = Vec::new();
+    push_conflicts(&target(), "", &live, &live, &mut conflicts);
+    assert!(conflicts.is_empty());
+  }
+}
@@ -1,17 +1,32 @@
-use anyhow::Context;
-use database::mungos::find::find_collect;
+use std::collections::HashMap;
+
+use anyhow::{Context, anyhow};
+use database::mungos::{find::find_collect, mongodb::bson::doc};
 use komodo_client::{
   api::read::{
     ExportAllResourcesToToml, ExportAllResourcesToTomlResponse,
     ExportResourcesToToml, ExportResourcesToTomlResponse,
-    ListUserGroups,
+    ImportResourcesToToml, ImportToTomlResponse, ListUserGroups,
+    PendingTomlUpdate,
   },
   entities::{
-    ResourceTarget, action::Action, alerter::Alerter, build::Build,
-    builder::Builder, deployment::Deployment,
-    permission::PermissionLevel, procedure::Procedure, repo::Repo,
-    resource::ResourceQuery, server::Server, stack::Stack,
-    sync::ResourceSync, toml::ResourcesToml, user::User,
+    ResourceTarget,
+    action::Action,
+    alerter::Alerter,
+    build::Build,
+    builder::Builder,
+    deployment::Deployment,
+    permission::PermissionLevel,
+    procedure::Procedure,
+    repo::Repo,
+    resource::ResourceQuery,
+    server::Server,
+    server_group::ServerGroup,
+    stack::Stack,
+    sync::{DiffData, ResourceDiff, ResourceSync},
+    tag::Tag,
+    toml::{ResourceToml, ResourcesToml, TagToml},
+    user::User,
   },
 };
 use resolver_api::Resolve;
@@ -24,9 +39,12 @@ use crate::{
   resource,
   state::db_client,
   sync::{
+    ResourceSyncTrait, line_diff,
+    tags::tag_to_toml,
     toml::{ToToml, convert_resource},
     user_groups::{convert_user_groups, user_group_to_toml},
     variables::variable_to_toml,
+    view::push_updates_for_view,
   },
 };
 
@@ -75,6 +93,17 @@ async fn get_all_targets(
     .into_iter()
     .map(|resource| ResourceTarget::Server(resource.id)),
   );
+  targets.extend(
+    resource::list_full_for_user::<ServerGroup>(
+      ResourceQuery::builder().tags(tags).build(),
+      user,
+      PermissionLevel::Read.into(),
+      &all_tags,
+    )
+    .await?
+    .into_iter()
+    .map(|resource| ResourceTarget::ServerGroup(resource.id)),
+  );
   targets.extend(
     resource::list_full_for_user::<Stack>(
       ResourceQuery::builder().tags(tags).build(),
@@ -186,6 +215,8 @@ impl Resolve<ReadArgs> for ExportAllResourcesToToml {
       targets,
       user_groups,
       include_variables: self.include_variables,
+      include_tags: self.include_tags,
+      namespace: self.namespace,
     }
     .resolve(args)
     .await
@@ -201,6 +232,8 @@ impl Resolve<ReadArgs> for ExportResourcesToToml {
       targets,
       user_groups,
       include_variables,
+      include_tags,
+      namespace,
     } = self;
     let mut res = ResourcesToml::default();
     let id_to_tags = get_id_to_tags(None).await?;
@@ -363,6 +396,22 @@ impl Resolve<ReadArgs> for ExportResourcesToToml {
             &id_to_tags,
           ));
         }
+        ResourceTarget::ServerGroup(id) => {
+          let mut server_group =
+            get_check_permissions::<ServerGroup>(
+              &id,
+              user,
+              PermissionLevel::Read.into(),
+            )
+            .await?;
+          ServerGroup::replace_ids(&mut server_group);
+          res.server_groups.push(convert_resource::<ServerGroup>(
+            server_group,
+            false,
+            vec![],
+            &id_to_tags,
+          ));
+        }
         ResourceTarget::System(_) => continue,
       };
     }
@@ -372,20 +421,37 @@ impl Resolve<ReadArgs> for ExportResourcesToToml {
       .context("failed to add user groups")?;
 
     if include_variables {
-      res.variables =
-        find_collect(&db_client().variables, None, None)
-          .await
-          .context("failed to get variables from db")?
-          .into_iter()
-          .map(|mut variable| {
-            if !user.admin && variable.is_secret {
-              variable.value = "#".repeat(variable.value.len())
-            }
-            variable
-          })
-          .collect();
+      res.variables = find_collect(
+        &db_client().variables,
+        doc! { "server_id": null },
+        None,
+      )
+      .await
+      .context("failed to get variables from db")?
+      .into_iter()
+      .map(|mut variable| {
+        if !user.admin && variable.is_secret {
+          variable.value = "#".repeat(variable.value.len())
+        }
+        variable
+      })
+      .collect();
     }
 
+    if include_tags {
+      res.tags = find_collect(&db_client().tags, None, None)
+        .await
+        .context("failed to get tags from db")?
+        .into_iter()
+        .map(|tag| TagToml {
+          name: tag.name,
+          color: tag.color,
+        })
+        .collect();
+    }
+
+    crate::sync::strip_namespace(&mut res, &namespace);
+
     let toml = serialize_resources_toml(res)
       .context("failed to serialize resources to toml")?;
 
@@ -393,6 +459,108 @@ impl Resolve<ReadArgs> for ExportResourcesToToml {
   }
 }
 
+impl Resolve<ReadArgs> for ImportResourcesToToml {
+  async fn resolve(
+    self,
+    ReadArgs { user }: &ReadArgs,
+  ) -> serror::Result<ImportToTomlResponse> {
+    // The preview includes the toml of all current resources.
+    if !user.admin {
+      return Err(anyhow!("this method is admin only").into());
+    }
+
+    let resources =
+      crate::sync::deserialize_resources_toml(&self.toml)
+        .context("Failed to parse resource toml")?;
+
+    let id_to_tags = get_id_to_tags(None).await?;
+    let mut res = ImportToTomlResponse::default();
+
+    push_pending::<Server>(resources.servers, &id_to_tags, &mut res)
+      .await?;
+    push_pending::<Stack>(resources.stacks, &id_to_tags, &mut res)
+      .await?;
+    push_pending::<Deployment>(
+      resources.deployments,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    push_pending::<Build>(resources.builds, &id_to_tags, &mut res)
+      .await?;
+    push_pending::<Repo>(resources.repos, &id_to_tags, &mut res)
+      .await?;
+    push_pending::<Procedure>(
+      resources.procedures,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    push_pending::<Action>(resources.actions, &id_to_tags, &mut res)
+      .await?;
+    push_pending::<Alerter>(
+      resources.alerters,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    push_pending::<Builder>(
+      resources.builders,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    push_pending::<ResourceSync>(
+      resources.resource_syncs,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    push_pending::<ServerGroup>(
+      resources.server_groups,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+
+    Ok(res)
+  }
+}
+
+async fn push_pending<Resource: ResourceSyncTrait>(
+  resources: Vec<ResourceToml<Resource::PartialConfig>>,
+  id_to_tags: &HashMap<String, Tag>,
+  res: &mut ImportToTomlResponse,
+) -> anyhow::Result<()> {
+  let mut diffs = Vec::new();
+  push_updates_for_view::<Resource>(
+    resources,
+    true,
+    None,
+    None,
+    id_to_tags,
+    &[],
+    "",
+    &mut diffs,
+  )
+  .await?;
+  for ResourceDiff { target, data } in diffs {
+    match data {
+      DiffData::Create { name, .. } => {
+        res.pending_creates.push(Resource::resource_target(name))
+      }
+      DiffData::Update { proposed, current } => {
+        res.pending_updates.push(PendingTomlUpdate {
+          target,
+          diff: line_diff(&current, &proposed),
+        })
+      }
+      DiffData::Delete { .. } => res.pending_deletes.push(target),
+    }
+  }
+  Ok(())
+}
+
 async fn add_user_groups(
   user_groups: Vec<String>,
   res: &mut ResourcesToml,
@@ -498,6 +666,21 @@ fn serialize_resources_toml(
     ResourceSync::push_to_toml_string(resource_sync, &mut toml)?;
   }
 
+  for server_group in resources.server_groups {
+    if !toml.is_empty() {
+      toml.push_str("\n\n##\n\n");
+    }
+    toml.push_str("[[server_group]]\n");
+    ServerGroup::push_to_toml_string(server_group, &mut toml)?;
+  }
+
+  for tag in &resources.tags {
+    if !toml.is_empty() {
+      toml.push_str("\n\n##\n\n");
+    }
+    toml.push_str(&tag_to_toml(tag)?);
+  }
+
   for variable in &resources.variables {
     if !toml.is_empty() {
       toml.push_str("\n\n##\n\n");
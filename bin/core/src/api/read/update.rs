@@ -19,6 +19,7 @@ use komodo_client::{
     procedure::Procedure,
     repo::Repo,
     server::Server,
+    server_group::ServerGroup,
     stack::Stack,
     sync::ResourceSync,
     update::{Update, UpdateListItem},
@@ -139,6 +140,17 @@ impl Resolve<ReadArgs> for ListUpdates {
       })
       .unwrap_or_else(|| doc! { "target.type": "ResourceSync" });
 
+      let server_group_query = get_resource_ids_for_user::<
+        ServerGroup,
+      >(user)
+      .await?
+      .map(|ids| {
+        doc! {
+          "target.type": "ServerGroup", "target.id": { "$in": ids }
+        }
+      })
+      .unwrap_or_else(|| doc! { "target.type": "ServerGroup" });
+
       let mut query = self.query.unwrap_or_default();
       query.extend(doc! {
         "$or": [
@@ -152,6 +164,7 @@ impl Resolve<ReadArgs> for ListUpdates {
           alerter_query,
           builder_query,
           resource_sync_query,
+          server_group_query,
         ]
       });
       query.into()
@@ -308,6 +321,14 @@ impl Resolve<ReadArgs> for GetUpdate {
         )
         .await?;
       }
+      ResourceTarget::ServerGroup(id) => {
+        get_check_permissions::<ServerGroup>(
+          id,
+          user,
+          PermissionLevel::Read.into(),
+        )
+        .await?;
+      }
     }
     Ok(update)
   }
@@ -9,7 +9,9 @@ use komodo_client::{
     FindUser, FindUserResponse, GetUsername, GetUsernameResponse,
     ListApiKeys, ListApiKeysForServiceUser,
     ListApiKeysForServiceUserResponse, ListApiKeysResponse,
-    ListUsers, ListUsersResponse,
+    ListServiceAccounts, ListServiceAccountsResponse,
+    ListUserSessions, ListUserSessionsResponse, ListUsers,
+    ListUsersResponse,
   },
   entities::user::{UserConfig, admin_service_user},
 };
@@ -31,10 +33,22 @@ impl Resolve<ReadArgs> for GetUsername {
       });
     }
 
-    let user = find_one_by_id(&db_client().users, &self.user_id)
+    let db = db_client();
+    let Some(user) = find_one_by_id(&db.users, &self.user_id)
       .await
       .context("failed at mongo query for user")?
-      .context("no user found with id")?;
+    else {
+      // Service accounts act as a user with the account id.
+      let service_account =
+        find_one_by_id(&db.service_accounts, &self.user_id)
+          .await
+          .context("failed at mongo query for service account")?
+          .context("no user found with id")?;
+      return Ok(GetUsernameResponse {
+        username: service_account.actor(),
+        avatar: None,
+      });
+    };
 
     let avatar = match user.config {
       UserConfig::Github { avatar, .. } => Some(avatar),
@@ -83,6 +97,53 @@ impl Resolve<ReadArgs> for ListUsers {
   }
 }
 
+impl Resolve<ReadArgs> for ListServiceAccounts {
+  async fn resolve(
+    self,
+    ReadArgs { user }: &ReadArgs,
+  ) -> serror::Result<ListServiceAccountsResponse> {
+    if !user.admin {
+      return Err(anyhow!("This method is admin only.").into());
+    }
+    let mut service_accounts = find_collect(
+      &db_client().service_accounts,
+      None,
+      FindOptions::builder().sort(doc! { "name": 1 }).build(),
+    )
+    .await
+    .context("failed to pull service accounts from db")?;
+    service_accounts
+      .iter_mut()
+      .for_each(|service_account| service_account.sanitize());
+    Ok(service_accounts)
+  }
+}
+
+impl Resolve<ReadArgs> for ListUserSessions {
+  async fn resolve(
+    self,
+    ReadArgs { user: admin }: &ReadArgs,
+  ) -> serror::Result<ListUserSessionsResponse> {
+    if !admin.admin {
+      return Err(anyhow!("This method is admin only.").into());
+    }
+    let filter = match self.user {
+      Some(user) => doc! { "user_id": get_user(&user).await?.id },
+      None => doc! {},
+    };
+    let sessions = find_collect(
+      &db_client().sessions,
+      filter,
+      FindOptions::builder()
+        .sort(doc! { "issued_at": -1 })
+        .build(),
+    )
+    .await
+    .context("failed to query db for sessions")?;
+    Ok(sessions)
+  }
+}
+
 impl Resolve<ReadArgs> for ListApiKeys {
   async fn resolve(
     self,
@@ -1,12 +1,15 @@
-use anyhow::Context;
+use anyhow::{Context, anyhow};
 use database::mongo_indexed::doc;
 use database::mungos::{
   find::find_collect, mongodb::options::FindOptions,
 };
-use komodo_client::api::read::*;
+use komodo_client::{api::read::*, entities::variable::Variable};
 use resolver_api::Resolve;
 
-use crate::{helpers::query::get_variable, state::db_client};
+use crate::{
+  helpers::query::get_variable,
+  state::{db_client, variable_references_cache},
+};
 
 use super::ReadArgs;
 
@@ -15,7 +18,16 @@ impl Resolve<ReadArgs> for GetVariable {
     self,
     ReadArgs { user }: &ReadArgs,
   ) -> serror::Result<GetVariableResponse> {
-    let mut variable = get_variable(&self.name).await?;
+    let scoped = match self.server_id.as_deref() {
+      Some(server_id) if !server_id.is_empty() => {
+        get_variable(&self.name, Some(server_id)).await.ok()
+      }
+      _ => None,
+    };
+    let mut variable = with_references(match scoped {
+      Some(variable) => variable,
+      None => get_variable(&self.name, None).await?,
+    });
     if !variable.is_secret || user.admin {
       return Ok(variable);
     }
@@ -35,12 +47,13 @@ impl Resolve<ReadArgs> for ListVariables {
       FindOptions::builder().sort(doc! { "name": 1 }).build(),
     )
     .await
-    .context("failed to query db for variables")?;
+    .context("failed to query db for variables")?
+    .into_iter()
+    .map(with_references);
     if user.admin {
-      return Ok(variables);
+      return Ok(variables.collect());
     }
     let variables = variables
-      .into_iter()
       .map(|mut variable| {
         if variable.is_secret {
           variable.value = "#".repeat(variable.value.len());
@@ -51,3 +64,45 @@ impl Resolve<ReadArgs> for ListVariables {
     Ok(variables)
   }
 }
+
+impl Resolve<ReadArgs> for GetAllVariables {
+  async fn resolve(
+    self,
+    ReadArgs { user }: &ReadArgs,
+  ) -> serror::Result<GetAllVariablesResponse> {
+    if !user.admin {
+      return Err(anyhow!("this method is admin only").into());
+    }
+    let variables = find_collect(
+      &db_client().variables,
+      None,
+      FindOptions::builder().sort(doc! { "name": 1 }).build(),
+    )
+    .await
+    .context("failed to query db for variables")?;
+    let summaries = variables
+      .into_iter()
+      .map(with_references)
+      .map(|variable| VariableSummary {
+        name: variable.name,
+        description: variable.description,
+        is_secret: variable.is_secret,
+        server_id: variable.server_id,
+        referenced_by: variable.referenced_by,
+      })
+      .collect();
+    Ok(summaries)
+  }
+}
+
+/// Attach the resources referencing the variable,
+/// from the periodically refreshed cache.
+fn with_references(mut variable: Variable) -> Variable {
+  if let Some(references) =
+    variable_references_cache().load().get(&variable.name)
+  {
+    variable.reference_count = references.len() as u32;
+    variable.referenced_by = references.clone();
+  }
+  variable
+}
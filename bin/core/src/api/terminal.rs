@@ -1,5 +1,11 @@
 use anyhow::Context;
-use axum::{Extension, Router, middleware, routing::post};
+use axum::{
+  Extension, Router,
+  extract::Request,
+  middleware::{self, Next},
+  response::Response,
+  routing::post,
+};
 use komodo_client::{
   api::terminal::*,
   entities::{
@@ -11,8 +17,10 @@ use serror::Json;
 use uuid::Uuid;
 
 use crate::{
-  auth::auth_request, helpers::periphery_client,
-  permission::get_check_permissions, resource::get,
+  auth::{ApiKeyCeiling, auth_request, check_api_key_ceiling},
+  helpers::periphery_client,
+  permission::get_check_permissions,
+  resource::get,
   state::stack_status_cache,
 };
 
@@ -22,9 +30,24 @@ pub fn router() -> Router {
     .route("/execute/container", post(execute_container_exec))
     .route("/execute/deployment", post(execute_deployment_exec))
     .route("/execute/stack", post(execute_stack_exec))
+    .layer(middleware::from_fn(api_key_ceiling_guard))
     .layer(middleware::from_fn(auth_request))
 }
 
+/// Api keys with a permission ceiling
+/// must allow `Terminal` to use these apis.
+async fn api_key_ceiling_guard(
+  req: Request,
+  next: Next,
+) -> serror::Result<Response> {
+  check_api_key_ceiling(
+    req.extensions().get::<ApiKeyCeiling>(),
+    PermissionLevel::Write,
+    "Terminal",
+  )?;
+  Ok(next.run(req).await)
+}
+
 // =================
 //  ExecuteTerminal
 // =================
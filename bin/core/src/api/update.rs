@@ -0,0 +1,139 @@
+use std::convert::Infallible;
+
+use axum::{
+  Extension, Router,
+  extract::Path,
+  middleware,
+  response::{
+    IntoResponse,
+    sse::{Event, KeepAlive, Sse},
+  },
+  routing::get,
+};
+use database::mungos::by_id::find_one_by_id;
+use futures::{StreamExt, stream};
+use komodo_client::{
+  api::read::GetUpdate,
+  entities::{
+    permission::PermissionLevel,
+    update::{Update, UpdateListItem, UpdateStatus},
+    user::User,
+  },
+};
+use resolver_api::Resolve;
+use serde_json::json;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::{
+  auth::{ApiKeyCeiling, auth_request, check_api_key_ceiling},
+  helpers::channel::update_channel,
+  state::db_client,
+};
+
+use super::read::ReadArgs;
+
+pub fn router() -> Router {
+  Router::new()
+    .route("/{id}/stream", get(stream_update))
+    .layer(middleware::from_fn(auth_request))
+}
+
+/// Streams the logs of an update as Server-Sent Events,
+/// for clients which can't easily use the update websocket.
+/// Authenticated with the same jwt / api keys as the websocket login,
+/// and the user must be able to read the update.
+///
+/// Events:
+/// - `log`: A log appended to the update, as the JSON `Log`.
+///   Logs already on the update are sent first.
+/// - `complete`: Sent once the update finalizes, as JSON
+///   `{ "status": "Complete", "success": bool }`.
+///   The stream closes after this event.
+async fn stream_update(
+  Extension(user): Extension<User>,
+  ceiling: Option<Extension<ApiKeyCeiling>>,
+  Path(id): Path<String>,
+) -> serror::Result<impl IntoResponse> {
+  check_api_key_ceiling(
+    ceiling.as_deref(),
+    PermissionLevel::Read,
+    "GetUpdate",
+  )?;
+  // Subscribe before getting the update so no changes are missed.
+  let receiver = update_channel().receiver.resubscribe();
+  let update = GetUpdate { id }.resolve(&ReadArgs { user }).await?;
+  let state = StreamState {
+    receiver,
+    update,
+    sent: 0,
+    done: false,
+  };
+  let stream =
+    stream::unfold(state, next_events).flat_map(|events| {
+      stream::iter(events.into_iter().map(Ok::<_, Infallible>))
+    });
+  Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+struct StreamState {
+  receiver: broadcast::Receiver<UpdateListItem>,
+  update: Update,
+  /// The number of logs already sent.
+  sent: usize,
+  done: bool,
+}
+
+async fn next_events(
+  mut state: StreamState,
+) -> Option<(Vec<Event>, StreamState)> {
+  if state.done {
+    return None;
+  }
+  loop {
+    let events = new_events(&mut state);
+    if !events.is_empty() || state.done {
+      return Some((events, state));
+    }
+    // Wait for the update to change before getting it again.
+    loop {
+      match state.receiver.recv().await {
+        Ok(item) if item.id == state.update.id => break,
+        Ok(_) => continue,
+        // May have missed the change, so check anyways.
+        Err(RecvError::Lagged(_)) => break,
+        Err(RecvError::Closed) => return None,
+      }
+    }
+    state.update =
+      find_one_by_id(&db_client().updates, &state.update.id)
+        .await
+        .ok()
+        .flatten()?;
+  }
+}
+
+fn new_events(state: &mut StreamState) -> Vec<Event> {
+  let mut events = state
+    .update
+    .logs
+    .iter()
+    .skip(state.sent)
+    .filter_map(|log| {
+      Event::default().event("log").json_data(log).ok()
+    })
+    .collect::<Vec<_>>();
+  state.sent = state.update.logs.len();
+  if state.update.status == UpdateStatus::Complete {
+    state.done = true;
+    events.extend(
+      Event::default()
+        .event("complete")
+        .json_data(json!({
+          "status": state.update.status,
+          "success": state.update.success,
+        }))
+        .ok(),
+    );
+  }
+  events
+}
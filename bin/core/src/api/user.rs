@@ -8,25 +8,29 @@ use database::mongo_indexed::doc;
 use database::mungos::{
   by_id::update_one_by_id, mongodb::bson::to_bson,
 };
-use derive_variants::EnumVariants;
+use derive_variants::{EnumVariants, ExtractVariant};
 use komodo_client::{
   api::user::*,
-  entities::{api_key::ApiKey, komodo_timestamp, user::User},
+  entities::{
+    api_key::ApiKey, komodo_timestamp, permission::PermissionLevel,
+    user::User,
+  },
 };
+use reqwest::StatusCode;
 use resolver_api::Resolve;
 use response::Response;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serror::AddStatusCodeError;
 use typeshare::typeshare;
 use uuid::Uuid;
 
 use crate::{
-  auth::auth_request,
+  auth::{ApiKeyCeiling, auth_request, check_api_key_ceiling},
   helpers::{query::get_user, random_string},
   state::db_client,
 };
 
-use super::Variant;
+use super::{Variant, parse_variant_request};
 
 pub struct UserArgs {
   pub user: User,
@@ -36,6 +40,7 @@ pub struct UserArgs {
 #[derive(
   Serialize, Deserialize, Debug, Clone, Resolve, EnumVariants,
 )]
+#[variant_derive(Debug)]
 #[args(UserArgs)]
 #[response(Response)]
 #[error(serror::Error)]
@@ -45,6 +50,7 @@ enum UserRequest {
   SetLastSeenUpdate(SetLastSeenUpdate),
   CreateApiKey(CreateApiKey),
   DeleteApiKey(DeleteApiKey),
+  RotateApiKey(RotateApiKey),
 }
 
 pub fn router() -> Router {
@@ -56,21 +62,44 @@ pub fn router() -> Router {
 
 async fn variant_handler(
   user: Extension<User>,
+  ceiling: Option<Extension<ApiKeyCeiling>>,
   Path(Variant { variant }): Path<Variant>,
   Json(params): Json<serde_json::Value>,
 ) -> serror::Result<axum::response::Response> {
-  let req: UserRequest = serde_json::from_value(json!({
-    "type": variant,
-    "params": params,
-  }))?;
-  handler(user, Json(req)).await
+  let req: UserRequest = parse_variant_request(&variant, params)?;
+  handler(user, ceiling, Json(req)).await
 }
 
-#[instrument(name = "UserHandler", level = "debug", skip(user))]
+#[instrument(
+  name = "UserHandler",
+  level = "debug",
+  skip(user, ceiling)
+)]
 async fn handler(
   Extension(user): Extension<User>,
+  ceiling: Option<Extension<ApiKeyCeiling>>,
   Json(request): Json<UserRequest>,
 ) -> serror::Result<axum::response::Response> {
+  // Keys with a ceiling must not be able to create uncapped keys,
+  // or get the secret of one by rotating it.
+  if ceiling.is_some()
+    && matches!(
+      request,
+      UserRequest::CreateApiKey(_) | UserRequest::RotateApiKey(_)
+    )
+  {
+    return Err(
+      anyhow!(
+        "Api keys with a permission ceiling cannot create or rotate api keys"
+      )
+      .status_code(StatusCode::FORBIDDEN),
+    );
+  }
+  check_api_key_ceiling(
+    ceiling.as_deref(),
+    PermissionLevel::Write,
+    &format!("{:?}", request.extract_variant()),
+  )?;
   let timer = Instant::now();
   let req_id = Uuid::new_v4();
   debug!(
@@ -154,8 +183,8 @@ impl Resolve<UserArgs> for SetLastSeenUpdate {
   }
 }
 
-const SECRET_LENGTH: usize = 40;
-const BCRYPT_COST: u32 = 10;
+pub(super) const SECRET_LENGTH: usize = 40;
+pub(super) const BCRYPT_COST: u32 = 10;
 
 impl Resolve<UserArgs> for CreateApiKey {
   #[instrument(name = "CreateApiKey", level = "debug", skip(user))]
@@ -177,6 +206,8 @@ impl Resolve<UserArgs> for CreateApiKey {
       user_id: user.id.clone(),
       created_at: komodo_timestamp(),
       expires: self.expires,
+      last_used_at: 0,
+      permission_ceiling: self.permission_ceiling,
     };
     db_client()
       .api_keys
@@ -211,3 +242,37 @@ impl Resolve<UserArgs> for DeleteApiKey {
     Ok(DeleteApiKeyResponse {})
   }
 }
+
+impl Resolve<UserArgs> for RotateApiKey {
+  #[instrument(name = "RotateApiKey", level = "debug", skip(user))]
+  async fn resolve(
+    self,
+    UserArgs { user }: &UserArgs,
+  ) -> serror::Result<RotateApiKeyResponse> {
+    let client = db_client();
+    let key = client
+      .api_keys
+      .find_one(doc! { "key": &self.key })
+      .await
+      .context("failed at db query")?
+      .context("no api key with key found")?;
+    if user.id != key.user_id {
+      return Err(anyhow!("api key does not belong to user").into());
+    }
+    let secret = format!("S-{}", random_string(SECRET_LENGTH));
+    let secret_hash = bcrypt::hash(&secret, BCRYPT_COST)
+      .context("failed at hashing secret string")?;
+    client
+      .api_keys
+      .update_one(
+        doc! { "key": &key.key },
+        doc! { "$set": { "secret": secret_hash } },
+      )
+      .await
+      .context("failed to update api key secret on db")?;
+    Ok(RotateApiKeyResponse {
+      key: key.key,
+      secret,
+    })
+  }
+}
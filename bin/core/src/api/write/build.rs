@@ -3,7 +3,7 @@ use std::{path::PathBuf, str::FromStr, time::Duration};
 use anyhow::{Context, anyhow};
 use database::mongo_indexed::doc;
 use database::mungos::mongodb::bson::to_document;
-use formatting::format_serror;
+use formatting::{code, code_block, format_serror};
 use komodo_client::{
   api::write::*,
   entities::{
@@ -12,6 +12,7 @@ use komodo_client::{
     build::{Build, BuildInfo, PartialBuildConfig},
     builder::{Builder, BuilderConfig},
     config::core::CoreConfig,
+    optional_string,
     permission::PermissionLevel,
     repo::Repo,
     server::ServerState,
@@ -121,7 +122,10 @@ impl Resolve<WriteArgs> for WriteBuildFileContents {
     let mut update =
       make_update(&build, Operation::WriteDockerfile, &args.user);
 
-    update.push_simple_log("Dockerfile to write", &self.contents);
+    update.push_simple_log(
+      "Dockerfile to write",
+      code_block(&self.contents),
+    );
 
     if build.config.files_on_host {
       match get_on_host_periphery(&build)
@@ -279,7 +283,7 @@ async fn write_dockerfile_contents_git(
   } else {
     update.push_simple_log(
       "Write Dockerfile",
-      format!("File written to {full_path:?}"),
+      format!("File written to {}", code(full_path.display())),
     );
   };
 
@@ -356,7 +360,7 @@ impl Resolve<WriteArgs> for RefreshBuildCache {
       // FILES ON HOST
       // =============
       match get_on_host_dockerfile(&build).await {
-        Ok(FileContents { path, contents }) => {
+        Ok(FileContents { path, contents, .. }) => {
           (Some(path), Some(contents), None, None, None)
         }
         Err(e) => {
@@ -384,6 +388,23 @@ impl Resolve<WriteArgs> for RefreshBuildCache {
       (None, None, None, None, None)
     };
 
+    // UI defined dockerfiles aren't fetched as remote contents
+    let contents = if !build.config.files_on_host
+      && repo.is_none()
+      && build.config.repo.is_empty()
+    {
+      optional_string(&build.config.dockerfile)
+    } else {
+      remote_contents.clone()
+    };
+    let dockerfile_hash =
+      contents.as_deref().map(resource::dockerfile_hash);
+    let needs_rebuild =
+      match (&build.info.built_dockerfile_hash, &dockerfile_hash) {
+        (Some(built), Some(latest)) => built != latest,
+        _ => false,
+      };
+
     let info = BuildInfo {
       last_built_at: build.info.last_built_at,
       built_hash: build.info.built_hash,
@@ -394,6 +415,11 @@ impl Resolve<WriteArgs> for RefreshBuildCache {
       remote_error,
       latest_hash,
       latest_message,
+      build_step: build.info.build_step,
+      build_step_total: build.info.build_step_total,
+      dockerfile_hash,
+      built_dockerfile_hash: build.info.built_dockerfile_hash,
+      needs_rebuild,
     };
 
     let info = to_document(&info)
@@ -1,22 +1,38 @@
-use std::time::Instant;
+use std::{net::SocketAddr, time::Instant};
 
-use anyhow::Context;
+use anyhow::{Context, anyhow};
 use axum::{
-  Extension, Router, extract::Path, middleware, routing::post,
+  Extension, Router,
+  extract::{ConnectInfo, Path},
+  http::{HeaderMap, StatusCode},
+  middleware,
+  routing::post,
 };
 use derive_variants::{EnumVariants, ExtractVariant};
-use komodo_client::{api::write::*, entities::user::User};
+use komodo_client::{
+  api::write::*,
+  entities::{
+    audit::AuditEntry, permission::PermissionLevel, user::User,
+  },
+};
 use resolver_api::Resolve;
 use response::Response;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
-use serror::Json;
+use serror::{AddStatusCodeError, Json};
 use typeshare::typeshare;
 use uuid::Uuid;
 
-use crate::auth::auth_request;
+use crate::{
+  auth::{
+    ApiKeyCeiling, auth_request, check_api_key_ceiling, request_ip,
+  },
+  helpers::{
+    audit::{audit_entry, record_audit_entry, with_audit_target},
+    update::CLIENT_IP,
+  },
+};
 
-use super::Variant;
+use super::{Variant, parse_variant_request, read_only_guard};
 
 mod action;
 mod alerter;
@@ -29,6 +45,8 @@ mod provider;
 mod repo;
 mod resource;
 mod server;
+mod server_group;
+mod service_account;
 mod service_user;
 mod stack;
 mod sync;
@@ -56,6 +74,7 @@ pub enum WriteRequest {
   UpdateUserUsername(UpdateUserUsername),
   UpdateUserPassword(UpdateUserPassword),
   DeleteUser(DeleteUser),
+  RevokeUserSession(RevokeUserSession),
 
   // ==== SERVICE USER ====
   CreateServiceUser(CreateServiceUser),
@@ -63,6 +82,12 @@ pub enum WriteRequest {
   CreateApiKeyForServiceUser(CreateApiKeyForServiceUser),
   DeleteApiKeyForServiceUser(DeleteApiKeyForServiceUser),
 
+  // ==== SERVICE ACCOUNT ====
+  CreateServiceAccount(CreateServiceAccount),
+  DeleteServiceAccount(DeleteServiceAccount),
+  CreateServiceAccountApiKey(CreateServiceAccountApiKey),
+  DeleteServiceAccountApiKey(DeleteServiceAccountApiKey),
+
   // ==== USER GROUP ====
   CreateUserGroup(CreateUserGroup),
   RenameUserGroup(RenameUserGroup),
@@ -71,6 +96,7 @@ pub enum WriteRequest {
   RemoveUserFromUserGroup(RemoveUserFromUserGroup),
   SetUsersInUserGroup(SetUsersInUserGroup),
   SetEveryoneUserGroup(SetEveryoneUserGroup),
+  UpdateUserGroupTagPermission(UpdateUserGroupTagPermission),
 
   // ==== PERMISSIONS ====
   UpdateUserAdmin(UpdateUserAdmin),
@@ -88,10 +114,20 @@ pub enum WriteRequest {
   UpdateServer(UpdateServer),
   RenameServer(RenameServer),
   CreateNetwork(CreateNetwork),
+  CopyFileToContainer(CopyFileToContainer),
+  CopyFileFromContainer(CopyFileFromContainer),
+  RunContainerCommand(RunContainerCommand),
   CreateTerminal(CreateTerminal),
   DeleteTerminal(DeleteTerminal),
   DeleteAllTerminals(DeleteAllTerminals),
 
+  // ==== SERVER GROUP ====
+  CreateServerGroup(CreateServerGroup),
+  CopyServerGroup(CopyServerGroup),
+  DeleteServerGroup(DeleteServerGroup),
+  UpdateServerGroup(UpdateServerGroup),
+  RenameServerGroup(RenameServerGroup),
+
   // ==== STACK ====
   CreateStack(CreateStack),
   CopyStack(CopyStack),
@@ -168,6 +204,7 @@ pub enum WriteRequest {
   RenameResourceSync(RenameResourceSync),
   WriteSyncFileContents(WriteSyncFileContents),
   CommitSync(CommitSync),
+  ImportResourcesFromToml(ImportResourcesFromToml),
   RefreshResourceSyncPending(RefreshResourceSyncPending),
   CreateSyncWebhook(CreateSyncWebhook),
   DeleteSyncWebhook(DeleteSyncWebhook),
@@ -199,36 +236,67 @@ pub fn router() -> Router {
     .route("/", post(handler))
     .route("/{variant}", post(variant_handler))
     .layer(middleware::from_fn(auth_request))
+    .layer(middleware::from_fn(read_only_guard))
 }
 
 async fn variant_handler(
+  headers: HeaderMap,
+  peer: ConnectInfo<SocketAddr>,
   user: Extension<User>,
+  ceiling: Option<Extension<ApiKeyCeiling>>,
   Path(Variant { variant }): Path<Variant>,
   Json(params): Json<serde_json::Value>,
 ) -> serror::Result<axum::response::Response> {
-  let req: WriteRequest = serde_json::from_value(json!({
-    "type": variant,
-    "params": params,
-  }))?;
-  handler(user, Json(req)).await
+  let req: WriteRequest = parse_variant_request(&variant, params)?;
+  handler(headers, peer, user, ceiling, Json(req)).await
 }
 
 async fn handler(
+  headers: HeaderMap,
+  ConnectInfo(peer): ConnectInfo<SocketAddr>,
   Extension(user): Extension<User>,
+  ceiling: Option<Extension<ApiKeyCeiling>>,
   Json(request): Json<WriteRequest>,
 ) -> serror::Result<axum::response::Response> {
+  // Keys with a ceiling must not be able to mint keys
+  // for service users or service accounts, which could be uncapped.
+  if ceiling.is_some()
+    && matches!(
+      request,
+      WriteRequest::CreateApiKeyForServiceUser(_)
+        | WriteRequest::CreateServiceAccountApiKey(_)
+    )
+  {
+    return Err(
+      anyhow!(
+        "Api keys with a permission ceiling cannot create api keys"
+      )
+      .status_code(StatusCode::FORBIDDEN),
+    );
+  }
+  let request_type = format!("{:?}", request.extract_variant());
+  check_api_key_ceiling(
+    ceiling.as_deref(),
+    PermissionLevel::Write,
+    &request_type,
+  )?;
+
   let req_id = Uuid::new_v4();
+  let ip = request_ip(&headers, peer);
+  let audit = audit_entry(&user, request_type, ip.clone());
 
-  let res = tokio::spawn(task(req_id, request, user))
-    .await
-    .context("failure in spawned task");
+  let res = tokio::spawn(
+    CLIENT_IP.scope(ip, task(req_id, request, user, audit)),
+  )
+  .await
+  .context("failure in spawned task");
 
   res?
 }
 
 #[instrument(
   name = "WriteRequest",
-  skip(user, request),
+  skip(user, request, audit),
   fields(
     user_id = user.id,
     request = format!("{:?}", request.extract_variant())
@@ -238,12 +306,18 @@ async fn task(
   req_id: Uuid,
   request: WriteRequest,
   user: User,
+  mut audit: AuditEntry,
 ) -> serror::Result<axum::response::Response> {
   info!("/write request | user: {}", user.username);
 
   let timer = Instant::now();
 
-  let res = request.resolve(&WriteArgs { user }).await;
+  let (res, target) =
+    with_audit_target(request.resolve(&WriteArgs { user })).await;
+
+  audit.success = res.is_ok();
+  audit.resource_target = target;
+  record_audit_entry(audit);
 
   if let Err(e) = &res {
     warn!("/write request {req_id} error: {:#}", e.error);
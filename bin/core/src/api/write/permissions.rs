@@ -440,5 +440,19 @@ async fn extract_resource_target_with_validation(
         .id;
       Ok((ResourceTargetVariant::Stack, id))
     }
+    ResourceTarget::ServerGroup(ident) => {
+      let filter = match ObjectId::from_str(ident) {
+        Ok(id) => doc! { "_id": id },
+        Err(_) => doc! { "name": ident },
+      };
+      let id = db_client()
+        .server_groups
+        .find_one(filter)
+        .await
+        .context("failed to query db for server groups")?
+        .context("no matching server group found")?
+        .id;
+      Ok((ResourceTargetVariant::ServerGroup, id))
+    }
   }
 }
@@ -4,7 +4,8 @@ use komodo_client::{
   entities::{
     ResourceTarget, action::Action, alerter::Alerter, build::Build,
     builder::Builder, deployment::Deployment, procedure::Procedure,
-    repo::Repo, server::Server, stack::Stack, sync::ResourceSync,
+    repo::Repo, server::Server, server_group::ServerGroup,
+    stack::Stack, sync::ResourceSync,
   },
 };
 use resolver_api::Resolve;
@@ -62,6 +63,9 @@ impl Resolve<WriteArgs> for UpdateResourceMeta {
       ResourceTarget::Stack(id) => {
         resource::update_meta::<Stack>(&id, meta, args).await?;
       }
+      ResourceTarget::ServerGroup(id) => {
+        resource::update_meta::<ServerGroup>(&id, meta, args).await?;
+      }
     }
     Ok(UpdateResourceMetaResponse {})
   }
@@ -1,9 +1,9 @@
-use anyhow::Context;
+use anyhow::{Context, anyhow};
 use formatting::format_serror;
 use komodo_client::{
   api::write::*,
   entities::{
-    NoData, Operation,
+    FileContents, NoData, Operation,
     permission::PermissionLevel,
     server::Server,
     to_docker_compatible_name,
@@ -119,6 +119,158 @@ impl Resolve<WriteArgs> for CreateNetwork {
   }
 }
 
+impl Resolve<WriteArgs> for CopyFileToContainer {
+  #[instrument(name = "CopyFileToContainer", skip(self, user), fields(server = self.server, container = self.container, dest_path = self.dest_path))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<Update> {
+    let server = get_check_permissions::<Server>(
+      &self.server,
+      user,
+      PermissionLevel::Execute.terminal(),
+    )
+    .await?;
+
+    let periphery = periphery_client(&server)?;
+
+    let mut update =
+      make_update(&server, Operation::CopyFileToContainer, user);
+    update.status = UpdateStatus::InProgress;
+    update.id = add_update(update.clone()).await?;
+
+    match periphery
+      .request(api::container::CopyFileToContainer {
+        container: self.container,
+        dest_path: self.dest_path,
+        contents: self.contents,
+        base64: self.base64,
+      })
+      .await
+    {
+      Ok(log) => update.logs.push(log),
+      Err(e) => update.push_error_log(
+        "Copy File To Container",
+        format_serror(
+          &e.context("Failed to copy file to container").into(),
+        ),
+      ),
+    };
+
+    update.finalize();
+    update_update(update.clone()).await?;
+
+    Ok(update)
+  }
+}
+
+impl Resolve<WriteArgs> for CopyFileFromContainer {
+  #[instrument(name = "CopyFileFromContainer", skip(user))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<FileContents> {
+    let server = get_check_permissions::<Server>(
+      &self.server,
+      user,
+      PermissionLevel::Execute.terminal(),
+    )
+    .await?;
+
+    let periphery = periphery_client(&server)?;
+
+    let mut update =
+      make_update(&server, Operation::CopyFileFromContainer, user);
+    update.status = UpdateStatus::InProgress;
+    update.id = add_update(update.clone()).await?;
+
+    let res = match periphery
+      .request(api::container::CopyFileFromContainer {
+        container: self.container.clone(),
+        src_path: self.src_path.clone(),
+      })
+      .await
+    {
+      // Only record the copy, the contents stay out of the update.
+      Ok(file) => {
+        update.push_simple_log(
+          "Copy File From Container",
+          format!(
+            "Copied {} from container {} ({} base64 bytes)",
+            self.src_path,
+            self.container,
+            file.contents.len()
+          ),
+        );
+        Some(file)
+      }
+      Err(e) => {
+        update.push_error_log(
+          "Copy File From Container",
+          format_serror(
+            &e.context("Failed to copy file from container").into(),
+          ),
+        );
+        None
+      }
+    };
+
+    update.finalize();
+    update_update(update).await?;
+
+    res.ok_or_else(|| {
+      anyhow!(
+        "Failed to copy file from container | See update for details"
+      )
+      .into()
+    })
+  }
+}
+
+impl Resolve<WriteArgs> for RunContainerCommand {
+  #[instrument(name = "RunContainerCommand", skip(self, user), fields(server = self.server, container = self.container))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<Update> {
+    let server = get_check_permissions::<Server>(
+      &self.server,
+      user,
+      PermissionLevel::Execute.terminal(),
+    )
+    .await?;
+
+    let periphery = periphery_client(&server)?;
+
+    let mut update =
+      make_update(&server, Operation::RunContainerCommand, user);
+    update.status = UpdateStatus::InProgress;
+    update.id = add_update(update.clone()).await?;
+
+    match periphery
+      .request(api::container::ExecInContainer {
+        container: self.container,
+        command: self.command,
+        workdir: self.workdir,
+      })
+      .await
+    {
+      Ok(log) => update.logs.push(log),
+      Err(e) => update.push_error_log(
+        "Run Container Command",
+        format_serror(
+          &e.context("Failed to run container command").into(),
+        ),
+      ),
+    };
+
+    update.finalize();
+    update_update(update.clone()).await?;
+
+    Ok(update)
+  }
+}
+
 impl Resolve<WriteArgs> for CreateTerminal {
   #[instrument(name = "CreateTerminal", skip(user))]
   async fn resolve(
@@ -0,0 +1,77 @@
+use komodo_client::{
+  api::write::*,
+  entities::{
+    permission::PermissionLevel, server_group::ServerGroup,
+    update::Update,
+  },
+};
+use resolver_api::Resolve;
+
+use crate::{permission::get_check_permissions, resource};
+
+use super::WriteArgs;
+
+impl Resolve<WriteArgs> for CreateServerGroup {
+  #[instrument(name = "CreateServerGroup", skip(user))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<ServerGroup> {
+    resource::create::<ServerGroup>(&self.name, self.config, user)
+      .await
+  }
+}
+
+impl Resolve<WriteArgs> for CopyServerGroup {
+  #[instrument(name = "CopyServerGroup", skip(user))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<ServerGroup> {
+    let ServerGroup { config, .. } =
+      get_check_permissions::<ServerGroup>(
+        &self.id,
+        user,
+        PermissionLevel::Write.into(),
+      )
+      .await?;
+    resource::create::<ServerGroup>(&self.name, config.into(), user)
+      .await
+  }
+}
+
+impl Resolve<WriteArgs> for DeleteServerGroup {
+  #[instrument(name = "DeleteServerGroup", skip(args))]
+  async fn resolve(
+    self,
+    args: &WriteArgs,
+  ) -> serror::Result<ServerGroup> {
+    Ok(resource::delete::<ServerGroup>(&self.id, args).await?)
+  }
+}
+
+impl Resolve<WriteArgs> for UpdateServerGroup {
+  #[instrument(name = "UpdateServerGroup", skip(user))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<ServerGroup> {
+    Ok(
+      resource::update::<ServerGroup>(&self.id, self.config, user)
+        .await?,
+    )
+  }
+}
+
+impl Resolve<WriteArgs> for RenameServerGroup {
+  #[instrument(name = "RenameServerGroup", skip(user))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<Update> {
+    Ok(
+      resource::rename::<ServerGroup>(&self.id, &self.name, user)
+        .await?,
+    )
+  }
+}
@@ -0,0 +1,182 @@
+use std::str::FromStr;
+
+use anyhow::{Context, anyhow};
+use database::mungos::{
+  by_id::{delete_one_by_id, update_one_by_id},
+  mongodb::bson::{doc, oid::ObjectId, to_bson},
+};
+use komodo_client::{
+  api::{user::CreateApiKeyResponse, write::*},
+  entities::{
+    api_key::ApiKey, komodo_timestamp,
+    service_account::ServiceAccount,
+  },
+};
+use reqwest::StatusCode;
+use resolver_api::Resolve;
+use serror::AddStatusCodeError;
+
+use crate::{
+  api::user::{BCRYPT_COST, SECRET_LENGTH},
+  helpers::{query::get_service_account, random_string},
+  state::db_client,
+};
+
+use super::WriteArgs;
+
+impl Resolve<WriteArgs> for CreateServiceAccount {
+  #[instrument(name = "CreateServiceAccount", skip(user))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<CreateServiceAccountResponse> {
+    if !user.admin {
+      return Err(
+        anyhow!("This method is admin-only.")
+          .status_code(StatusCode::FORBIDDEN),
+      );
+    }
+    if self.name.is_empty() {
+      return Err(
+        anyhow!("Service account name cannot be empty").into(),
+      );
+    }
+    if ObjectId::from_str(&self.name).is_ok() {
+      return Err(anyhow!("Name cannot be valid ObjectId").into());
+    }
+    let mut service_account = ServiceAccount {
+      id: Default::default(),
+      name: self.name,
+      description: self.description,
+      permissions: self.permissions,
+      api_keys: Vec::new(),
+      updated_at: komodo_timestamp(),
+    };
+    service_account.id = db_client()
+      .service_accounts
+      .insert_one(&service_account)
+      .await
+      .context("Failed to create service account on db")?
+      .inserted_id
+      .as_object_id()
+      .context("inserted id is not object id")?
+      .to_string();
+    Ok(service_account)
+  }
+}
+
+impl Resolve<WriteArgs> for DeleteServiceAccount {
+  #[instrument(name = "DeleteServiceAccount", skip(user))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<DeleteServiceAccountResponse> {
+    if !user.admin {
+      return Err(
+        anyhow!("This method is admin-only.")
+          .status_code(StatusCode::FORBIDDEN),
+      );
+    }
+    let mut service_account =
+      get_service_account(&self.service_account).await?;
+    let db = db_client();
+    delete_one_by_id(&db.service_accounts, &service_account.id, None)
+      .await
+      .context("Failed to delete service account from database")?;
+    // Also remove the service account from all user groups
+    if let Err(e) = db
+      .user_groups
+      .update_many(
+        doc! {},
+        doc! { "$pull": { "users": &service_account.id } },
+      )
+      .await
+    {
+      warn!(
+        "Failed to remove deleted service account from user groups | {e:?}"
+      );
+    };
+    service_account.sanitize();
+    Ok(service_account)
+  }
+}
+
+impl Resolve<WriteArgs> for CreateServiceAccountApiKey {
+  #[instrument(name = "CreateServiceAccountApiKey", skip(user))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<CreateServiceAccountApiKeyResponse> {
+    if !user.admin {
+      return Err(
+        anyhow!("This method is admin-only.")
+          .status_code(StatusCode::FORBIDDEN),
+      );
+    }
+    let service_account =
+      get_service_account(&self.service_account).await?;
+
+    let key = format!("K-{}", random_string(SECRET_LENGTH));
+    let secret = format!("S-{}", random_string(SECRET_LENGTH));
+    let secret_hash = bcrypt::hash(&secret, BCRYPT_COST)
+      .context("failed at hashing secret string")?;
+
+    let api_key = ApiKey {
+      name: self.key_name,
+      key: key.clone(),
+      secret: secret_hash,
+      user_id: service_account.id.clone(),
+      created_at: komodo_timestamp(),
+      expires: self.expires,
+      last_used_at: 0,
+      permission_ceiling: self.permission_ceiling,
+    };
+    update_one_by_id(
+      &db_client().service_accounts,
+      &service_account.id,
+      doc! {
+        "$push": {
+          "api_keys": to_bson(&api_key)
+            .context("Failed to serialize api key")?
+        },
+        "$set": { "updated_at": komodo_timestamp() },
+      },
+      None,
+    )
+    .await
+    .context("Failed to add api key to service account")?;
+    Ok(CreateApiKeyResponse { key, secret })
+  }
+}
+
+impl Resolve<WriteArgs> for DeleteServiceAccountApiKey {
+  #[instrument(name = "DeleteServiceAccountApiKey", skip(user))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<DeleteServiceAccountApiKeyResponse> {
+    if !user.admin {
+      return Err(
+        anyhow!("This method is admin-only.")
+          .status_code(StatusCode::FORBIDDEN),
+      );
+    }
+    let res = db_client()
+      .service_accounts
+      .update_one(
+        doc! { "api_keys.key": &self.key },
+        doc! {
+          "$pull": { "api_keys": { "key": &self.key } },
+          "$set": { "updated_at": komodo_timestamp() },
+        },
+      )
+      .await
+      .context("Failed to delete api key on db")?;
+    if res.matched_count == 0 {
+      return Err(
+        anyhow!("No service account api key matching key").into(),
+      );
+    }
+    Ok(DeleteServiceAccountApiKeyResponse {})
+  }
+}
@@ -118,6 +118,7 @@ impl Resolve<WriteArgs> for CreateApiKeyForServiceUser {
     CreateApiKey {
       name: self.name,
       expires: self.expires,
+      permission_ceiling: self.permission_ceiling,
     }
     .resolve(&UserArgs { user: service_user })
     .await
@@ -1,8 +1,9 @@
 use std::path::PathBuf;
 
 use anyhow::{Context, anyhow};
+use base64::Engine;
 use database::mungos::mongodb::bson::{doc, to_document};
-use formatting::format_serror;
+use formatting::{code, format_serror};
 use komodo_client::{
   api::write::*,
   entities::{
@@ -109,6 +110,7 @@ impl Resolve<WriteArgs> for WriteStackFileContents {
       stack,
       file_path,
       contents,
+      base64_contents,
     } = self;
     let stack = get_check_permissions::<Stack>(
       &stack,
@@ -129,14 +131,32 @@ impl Resolve<WriteArgs> for WriteStackFileContents {
     let mut update =
       make_update(&stack, Operation::WriteStackContents, user);
 
-    update.push_simple_log("File contents to write", &contents);
+    if base64_contents {
+      update.push_simple_log(
+        "File contents to write",
+        format!("<base64 encoded, {} characters>", contents.len()),
+      );
+    } else {
+      update.push_simple_log("File contents to write", &contents);
+    }
 
     if stack.config.files_on_host {
       write_stack_file_contents_on_host(
-        stack, file_path, contents, update,
+        stack,
+        file_path,
+        contents,
+        base64_contents,
+        update,
       )
       .await
     } else {
+      let contents = if base64_contents {
+        base64::engine::general_purpose::STANDARD
+          .decode(contents.trim())
+          .context("Failed to decode base64 file contents")?
+      } else {
+        contents.into_bytes()
+      };
       write_stack_file_contents_git(
         stack,
         &file_path,
@@ -153,6 +173,7 @@ async fn write_stack_file_contents_on_host(
   stack: Stack,
   file_path: String,
   contents: String,
+  base64_contents: bool,
   mut update: Update,
 ) -> serror::Result<Update> {
   if stack.config.server_id.is_empty() {
@@ -176,6 +197,7 @@ async fn write_stack_file_contents_on_host(
       run_directory: stack.config.run_directory,
       file_path,
       contents,
+      base64_contents,
     })
     .await
     .context("Failed to write contents to host")
@@ -223,7 +245,7 @@ async fn write_stack_file_contents_on_host(
 async fn write_stack_file_contents_git(
   mut stack: Stack,
   file_path: &str,
-  contents: &str,
+  contents: &[u8],
   username: &str,
   mut update: Update,
 ) -> serror::Result<Update> {
@@ -316,7 +338,7 @@ async fn write_stack_file_contents_git(
   } else {
     update.push_simple_log(
       "Write File",
-      format!("File written to {full_path:?}"),
+      format!("File written to {}", code(full_path.display())),
     );
   };
 
@@ -439,6 +461,7 @@ impl Resolve<WriteArgs> for RefreshStackCache {
               errors: vec![FileContents {
                 path: stack.config.run_directory.clone(),
                 contents: format_serror(&e.into()),
+                base64_contents: false,
               }],
             },
           };
@@ -542,6 +565,7 @@ impl Resolve<WriteArgs> for RefreshStackCache {
       deployed_hash: stack.info.deployed_hash.clone(),
       deployed_message: stack.info.deployed_message.clone(),
       latest_services,
+      services: stack.info.services.clone(),
       remote_contents,
       remote_errors,
       latest_hash,
@@ -1,5 +1,5 @@
 use std::{
-  collections::HashMap,
+  collections::{BTreeMap, HashMap},
   path::{Path, PathBuf},
 };
 
@@ -8,7 +8,7 @@ use database::mungos::{
   by_id::update_one_by_id,
   mongodb::bson::{doc, to_document},
 };
-use formatting::format_serror;
+use formatting::{code, code_block, format_serror};
 use komodo_client::{
   api::{read::ExportAllResourcesToToml, write::*},
   entities::{
@@ -26,12 +26,15 @@ use komodo_client::{
     procedure::Procedure,
     repo::Repo,
     server::Server,
+    server_group::ServerGroup,
     stack::Stack,
     sync::{
       PartialResourceSyncConfig, ResourceSync, ResourceSyncInfo,
       SyncDeployUpdate,
     },
+    tag::Tag,
     to_path_compatible_name,
+    toml::{ResourceToml, ResourcesToml},
     update::{Log, Update},
     user::sync_user,
   },
@@ -39,7 +42,10 @@ use komodo_client::{
 use octorust::types::{
   ReposCreateWebhookRequest, ReposCreateWebhookRequestConfig,
 };
+use reqwest::StatusCode;
 use resolver_api::Resolve;
+use serde::Serialize;
+use serror::AddStatusCodeError;
 
 use crate::{
   alert::send_alerts,
@@ -55,7 +61,9 @@ use crate::{
   resource,
   state::{db_client, github_client},
   sync::{
-    deploy::SyncDeployParams, remote::RemoteResources,
+    deploy::SyncDeployParams,
+    execute::{ExecuteResourceSync, get_updates_for_execution},
+    remote::RemoteResources,
     view::push_updates_for_view,
   },
 };
@@ -162,7 +170,8 @@ impl Resolve<WriteArgs> for WriteSyncFileContents {
     let mut update =
       make_update(&sync, Operation::WriteSyncContents, &args.user);
 
-    update.push_simple_log("File contents", &self.contents);
+    update
+      .push_simple_log("File contents", code_block(&self.contents));
 
     if sync.config.files_on_host {
       write_sync_file_contents_on_host(self, args, sync, update).await
@@ -216,7 +225,7 @@ async fn write_sync_file_contents_on_host(
   } else {
     update.push_simple_log(
       "Write File",
-      format!("File written to {full_path:?}"),
+      format!("File written to {}", code(full_path.display())),
     );
   };
 
@@ -352,7 +361,7 @@ async fn write_sync_file_contents_git(
   } else {
     update.push_simple_log(
       "Write File",
-      format!("File written to {full_path:?}"),
+      format!("File written to {}", code(full_path.display())),
     );
   };
 
@@ -405,6 +414,8 @@ impl Resolve<WriteArgs> for CommitSync {
     )
     .await?;
 
+    crate::sync::check_namespace_scope(&sync.config)?;
+
     let repo = if !sync.config.files_on_host
       && !sync.config.linked_repo.is_empty()
     {
@@ -460,7 +471,9 @@ impl Resolve<WriteArgs> for CommitSync {
       include_resources: sync.config.include_resources,
       tags: sync.config.match_tags.clone(),
       include_variables: sync.config.include_variables,
+      include_tags: sync.config.include_tags,
       include_user_groups: sync.config.include_user_groups,
+      namespace: sync.config.namespace.clone(),
     }
     .resolve(&ReadArgs {
       user: sync_user().to_owned(),
@@ -502,7 +515,10 @@ impl Resolve<WriteArgs> for CommitSync {
       } else {
         update.push_simple_log(
           "Write contents",
-          format!("File contents written to {file_path:?}"),
+          format!(
+            "File contents written to {}",
+            code(file_path.display())
+          ),
         );
       }
     } else if let Some(repo) = &repo {
@@ -511,9 +527,16 @@ impl Resolve<WriteArgs> for CommitSync {
         unreachable!()
       };
       let args: RepoExecutionArgs = repo.into();
-      if let Err(e) =
-        commit_git_sync(args, &resource_path, &res.toml, &mut update)
-          .await
+      let message =
+        sync_commit_message(&sync, &resource_path, &res.toml);
+      if let Err(e) = commit_git_sync(
+        args,
+        &resource_path,
+        &res.toml,
+        &message,
+        &mut update,
+      )
+      .await
       {
         update.push_error_log(
           "Write resource file",
@@ -529,9 +552,16 @@ impl Resolve<WriteArgs> for CommitSync {
         unreachable!()
       };
       let args: RepoExecutionArgs = (&sync).into();
-      if let Err(e) =
-        commit_git_sync(args, &resource_path, &res.toml, &mut update)
-          .await
+      let message =
+        sync_commit_message(&sync, &resource_path, &res.toml);
+      if let Err(e) = commit_git_sync(
+        args,
+        &resource_path,
+        &res.toml,
+        &message,
+        &mut update,
+      )
+      .await
       {
         update.push_error_log(
           "Write resource file",
@@ -583,6 +613,7 @@ async fn commit_git_sync(
   mut args: RepoExecutionArgs,
   resource_path: &Path,
   toml: &str,
+  message: &str,
   update: &mut Update,
 ) -> anyhow::Result<()> {
   let root = args.unique_path(&core_config().repo_directory)?;
@@ -609,8 +640,8 @@ async fn commit_git_sync(
     return Ok(());
   }
 
-  let res = git::write_commit_file(
-    "Commit Sync",
+  let res = git::write_commit_file_with_message(
+    message,
     &root,
     resource_path,
     toml,
@@ -622,6 +653,341 @@ async fn commit_git_sync(
   Ok(())
 }
 
+/// The first line is `komodo: sync <name>`, followed by
+/// the resources created / updated / deleted in the file
+/// compared to its last known contents.
+fn sync_commit_message(
+  sync: &ResourceSync,
+  resource_path: &Path,
+  toml: &str,
+) -> String {
+  let mut message = format!("komodo: sync {}", sync.name);
+  let previous = sync
+    .info
+    .remote_contents
+    .iter()
+    .filter(|file| {
+      Path::new(&file.resource_path).join(&file.path) == resource_path
+    })
+    .map(|file| file.contents.as_str())
+    .collect::<Vec<_>>()
+    .join("\n");
+  let (Ok(previous), Ok(next)) = (
+    crate::sync::deserialize_resources_toml(&previous),
+    crate::sync::deserialize_resources_toml(toml),
+  ) else {
+    return message;
+  };
+  let previous = resource_entries(previous);
+  let next = resource_entries(next);
+
+  let mut created = Vec::new();
+  let mut updated = Vec::new();
+  for (key, contents) in &next {
+    match previous.get(key) {
+      Some(prev) if prev == contents => {}
+      Some(_) => updated.push(key.as_str()),
+      None => created.push(key.as_str()),
+    }
+  }
+  let deleted = previous
+    .keys()
+    .filter(|key| !next.contains_key(*key))
+    .map(String::as_str)
+    .collect::<Vec<_>>();
+
+  let mut first = true;
+  for (label, keys) in [
+    ("Created", created),
+    ("Updated", updated),
+    ("Deleted", deleted),
+  ] {
+    if keys.is_empty() {
+      continue;
+    }
+    if first {
+      message.push('\n');
+      first = false;
+    }
+    message.push_str(&format!("\n- {label}: {}", keys.join(", ")));
+  }
+  message
+}
+
+/// Maps `type/name` to the serialized entry, for comparison.
+fn resource_entries(
+  resources: ResourcesToml,
+) -> BTreeMap<String, String> {
+  let mut entries = BTreeMap::new();
+  push_entries(&mut entries, "server", resources.servers, |r| {
+    &r.name
+  });
+  push_entries(&mut entries, "stack", resources.stacks, |r| &r.name);
+  push_entries(
+    &mut entries,
+    "deployment",
+    resources.deployments,
+    |r| &r.name,
+  );
+  push_entries(&mut entries, "build", resources.builds, |r| &r.name);
+  push_entries(&mut entries, "repo", resources.repos, |r| &r.name);
+  push_entries(
+    &mut entries,
+    "procedure",
+    resources.procedures,
+    |r| &r.name,
+  );
+  push_entries(&mut entries, "action", resources.actions, |r| {
+    &r.name
+  });
+  push_entries(&mut entries, "alerter", resources.alerters, |r| {
+    &r.name
+  });
+  push_entries(&mut entries, "builder", resources.builders, |r| {
+    &r.name
+  });
+  push_entries(
+    &mut entries,
+    "resource_sync",
+    resources.resource_syncs,
+    |r| &r.name,
+  );
+  push_entries(
+    &mut entries,
+    "server_group",
+    resources.server_groups,
+    |r| &r.name,
+  );
+  push_entries(&mut entries, "tag", resources.tags, |r| &r.name);
+  push_entries(&mut entries, "variable", resources.variables, |r| {
+    &r.name
+  });
+  push_entries(
+    &mut entries,
+    "user_group",
+    resources.user_groups,
+    |r| &r.name,
+  );
+  entries
+}
+
+fn push_entries<T: Serialize>(
+  entries: &mut BTreeMap<String, String>,
+  resource_type: &str,
+  resources: Vec<T>,
+  name: impl Fn(&T) -> &String,
+) {
+  for resource in resources {
+    let Ok(contents) = serde_json::to_string(&resource) else {
+      continue;
+    };
+    entries.insert(
+      format!("{resource_type}/{}", name(&resource)),
+      contents,
+    );
+  }
+}
+
+impl Resolve<WriteArgs> for ImportResourcesFromToml {
+  #[instrument(name = "ImportResourcesFromToml", skip(user, self), fields(dry_run = self.dry_run))]
+  async fn resolve(
+    self,
+    WriteArgs { user }: &WriteArgs,
+  ) -> serror::Result<ImportResourcesFromTomlResponse> {
+    // Sync execution runs with full permissions,
+    // so the one-shot import is restricted to admins.
+    if !user.admin {
+      return Err(
+        anyhow!("this method is admin only")
+          .status_code(StatusCode::FORBIDDEN),
+      );
+    }
+
+    let ImportResourcesFromToml { toml, dry_run } = self;
+
+    let resources = crate::sync::deserialize_resources_toml(&toml)
+      .context("Failed to parse resource toml")
+      .status_code(StatusCode::BAD_REQUEST)?;
+
+    let id_to_tags = get_id_to_tags(None).await?;
+
+    let mut res = ImportResourcesFromTomlResponse {
+      dry_run,
+      ..Default::default()
+    };
+
+    res.variable_updates =
+      crate::sync::variables::get_updates_for_view(
+        &resources.variables,
+        false,
+      )
+      .await?;
+    res.user_group_updates =
+      crate::sync::user_groups::get_updates_for_view(
+        resources.user_groups.clone(),
+        false,
+      )
+      .await?;
+
+    if !dry_run {
+      let (to_create, to_update, to_delete) =
+        crate::sync::variables::get_updates_for_execution(
+          resources.variables,
+          false,
+        )
+        .await?;
+      res.logs.extend(
+        crate::sync::variables::run_updates(
+          to_create, to_update, to_delete,
+        )
+        .await,
+      );
+      let (to_create, to_update, to_delete) =
+        crate::sync::user_groups::get_updates_for_execution(
+          resources.user_groups,
+          false,
+        )
+        .await?;
+      res.logs.extend(
+        crate::sync::user_groups::run_updates(
+          to_create, to_update, to_delete,
+        )
+        .await,
+      );
+    }
+
+    // Same order as sync execution, so dependencies are created first.
+    import_resources::<ResourceSync>(
+      resources.resource_syncs,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    import_resources::<Server>(
+      resources.servers,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    import_resources::<Alerter>(
+      resources.alerters,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    import_resources::<Action>(
+      resources.actions,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    import_resources::<Builder>(
+      resources.builders,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    import_resources::<ServerGroup>(
+      resources.server_groups,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    import_resources::<Repo>(resources.repos, &id_to_tags, &mut res)
+      .await?;
+    import_resources::<Build>(
+      resources.builds,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    import_resources::<Deployment>(
+      resources.deployments,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    import_resources::<Stack>(
+      resources.stacks,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+    import_resources::<Procedure>(
+      resources.procedures,
+      &id_to_tags,
+      &mut res,
+    )
+    .await?;
+
+    Ok(res)
+  }
+}
+
+/// Computes the diff for a single resource type,
+/// and applies it unless `dry_run`.
+async fn import_resources<Resource: ExecuteResourceSync>(
+  resources: Vec<ResourceToml<Resource::PartialConfig>>,
+  id_to_tags: &HashMap<String, Tag>,
+  res: &mut ImportResourcesFromTomlResponse,
+) -> anyhow::Result<()> {
+  if resources.is_empty() {
+    return Ok(());
+  }
+
+  let names =
+    resources.iter().map(|r| r.name.clone()).collect::<Vec<_>>();
+
+  push_updates_for_view::<Resource>(
+    resources.clone(),
+    false,
+    None,
+    None,
+    id_to_tags,
+    &[],
+    "",
+    &mut res.resource_updates,
+  )
+  .await?;
+
+  let deltas = get_updates_for_execution::<Resource>(
+    resources,
+    false,
+    None,
+    None,
+    id_to_tags,
+    &[],
+    "",
+    false,
+  )
+  .await?;
+
+  for name in names {
+    let created = deltas.to_create.iter().any(|r| r.name == name);
+    let updated =
+      deltas.to_update.iter().any(|u| u.resource.name == name);
+    let item = ImportedResource {
+      resource_type: Resource::resource_type(),
+      name,
+    };
+    if created {
+      res.created.push(item);
+    } else if updated {
+      res.updated.push(item);
+    } else {
+      res.skipped.push(item);
+    }
+  }
+
+  if !res.dry_run {
+    res
+      .logs
+      .extend(Resource::execute_sync_updates(deltas).await);
+  }
+
+  Ok(())
+}
+
 impl Resolve<WriteArgs> for RefreshResourceSyncPending {
   #[instrument(
     name = "RefreshResourceSyncPending",
@@ -688,6 +1054,8 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
         ));
       }
 
+      crate::sync::check_namespace_scope(&sync.config)?;
+
       let resources = resources?;
       let delete = sync.config.managed || sync.config.delete;
       let all_resources = AllResourcesById::load().await?;
@@ -729,6 +1097,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
             None,
             &id_to_tags,
             &sync.config.match_tags,
+            &sync.config.namespace,
             &mut diffs,
           )
           .await?;
@@ -739,6 +1108,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
             None,
             &id_to_tags,
             &sync.config.match_tags,
+            &sync.config.namespace,
             &mut diffs,
           )
           .await?;
@@ -749,6 +1119,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
             None,
             &id_to_tags,
             &sync.config.match_tags,
+            &sync.config.namespace,
             &mut diffs,
           )
           .await?;
@@ -759,6 +1130,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
             None,
             &id_to_tags,
             &sync.config.match_tags,
+            &sync.config.namespace,
             &mut diffs,
           )
           .await?;
@@ -769,6 +1141,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
             None,
             &id_to_tags,
             &sync.config.match_tags,
+            &sync.config.namespace,
             &mut diffs,
           )
           .await?;
@@ -779,6 +1152,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
             None,
             &id_to_tags,
             &sync.config.match_tags,
+            &sync.config.namespace,
             &mut diffs,
           )
           .await?;
@@ -789,6 +1163,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
             None,
             &id_to_tags,
             &sync.config.match_tags,
+            &sync.config.namespace,
             &mut diffs,
           )
           .await?;
@@ -799,6 +1174,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
             None,
             &id_to_tags,
             &sync.config.match_tags,
+            &sync.config.namespace,
             &mut diffs,
           )
           .await?;
@@ -809,6 +1185,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
             None,
             &id_to_tags,
             &sync.config.match_tags,
+            &sync.config.namespace,
             &mut diffs,
           )
           .await?;
@@ -819,6 +1196,18 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
             None,
             &id_to_tags,
             &sync.config.match_tags,
+            &sync.config.namespace,
+            &mut diffs,
+          )
+          .await?;
+          push_updates_for_view::<ServerGroup>(
+            resources.server_groups,
+            delete,
+            None,
+            None,
+            &id_to_tags,
+            &sync.config.match_tags,
+            &sync.config.namespace,
             &mut diffs,
           )
           .await?;
@@ -838,6 +1227,16 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
         Default::default()
       };
 
+      let tag_updates = if sync.config.include_tags {
+        crate::sync::tags::get_updates_for_view(
+          &resources.tags,
+          delete,
+        )
+        .await?
+      } else {
+        Default::default()
+      };
+
       let user_group_updates = if sync.config.include_user_groups {
         crate::sync::user_groups::get_updates_for_view(
           resources.user_groups,
@@ -852,6 +1251,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
         resource_updates,
         deploy_updates,
         variable_updates,
+        tag_updates,
         user_group_updates,
       ))
     }
@@ -861,15 +1261,17 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
       resource_updates,
       deploy_updates,
       variable_updates,
+      tag_updates,
       user_group_updates,
       pending_error,
     ) = match res {
-      Ok(res) => (res.0, res.1, res.2, res.3, None),
+      Ok(res) => (res.0, res.1, res.2, res.3, res.4, None),
       Err(e) => (
         Default::default(),
         Default::default(),
         Default::default(),
         Default::default(),
+        Default::default(),
         Some(format_serror(&e.into())),
       ),
     };
@@ -877,6 +1279,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
     let has_updates = !resource_updates.is_empty()
       || !deploy_updates.to_deploy == 0
       || !variable_updates.is_empty()
+      || !tag_updates.is_empty()
       || !user_group_updates.is_empty();
 
     let info = ResourceSyncInfo {
@@ -890,6 +1293,7 @@ impl Resolve<WriteArgs> for RefreshResourceSyncPending {
       pending_deploy: deploy_updates,
       resource_updates,
       variable_updates,
+      tag_updates,
       user_group_updates,
       pending_error,
     };
@@ -10,7 +10,8 @@ use komodo_client::{
   entities::{
     action::Action, alerter::Alerter, build::Build, builder::Builder,
     deployment::Deployment, procedure::Procedure, repo::Repo,
-    server::Server, stack::Stack, sync::ResourceSync, tag::Tag,
+    server::Server, server_group::ServerGroup, stack::Stack,
+    sync::ResourceSync, tag::Tag,
   },
 };
 use reqwest::StatusCode;
@@ -132,9 +133,21 @@ impl Resolve<WriteArgs> for DeleteTag {
       resource::remove_tag_from_all::<ResourceSync>(&self.id),
       resource::remove_tag_from_all::<Builder>(&self.id),
       resource::remove_tag_from_all::<Alerter>(&self.id),
+      resource::remove_tag_from_all::<ServerGroup>(&self.id),
     )?;
 
-    delete_one_by_id(&db_client().tags, &self.id, None).await?;
+    let db = db_client();
+
+    let field = format!("tags.{}", self.id);
+    db.user_groups
+      .update_many(
+        doc! { &field: { "$exists": true } },
+        doc! { "$unset": { &field: "" } },
+      )
+      .await
+      .context("failed to remove tag from user groups")?;
+
+    delete_one_by_id(&db.tags, &self.id, None).await?;
 
     Ok(tag)
   }
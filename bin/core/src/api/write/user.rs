@@ -228,6 +228,36 @@ impl Resolve<WriteArgs> for DeleteUser {
     {
       warn!("Failed to remove deleted user from user groups | {e:?}");
     };
+    // Also revoke all of the user's sessions
+    if let Err(e) =
+      db.sessions.delete_many(doc! { "user_id": &user.id }).await
+    {
+      warn!("Failed to delete sessions for deleted user | {e:?}");
+    };
     Ok(user)
   }
 }
+
+//
+
+impl Resolve<WriteArgs> for RevokeUserSession {
+  #[instrument(name = "RevokeUserSession", skip(admin), fields(admin_id = admin.id))]
+  async fn resolve(
+    self,
+    WriteArgs { user: admin }: &WriteArgs,
+  ) -> serror::Result<RevokeUserSessionResponse> {
+    if !admin.admin {
+      return Err(
+        anyhow!("This method is admin-only.")
+          .status_code(StatusCode::FORBIDDEN),
+      );
+    }
+    let session = db_client()
+      .sessions
+      .find_one_and_delete(doc! { "jwt_id": &self.jwt_id })
+      .await
+      .context("Failed to delete session from database")?
+      .context("No session found with given jwt id")?;
+    Ok(session)
+  }
+}
@@ -4,17 +4,20 @@ use anyhow::{Context, anyhow};
 use database::mungos::{
   by_id::{delete_one_by_id, find_one_by_id, update_one_by_id},
   find::find_collect,
-  mongodb::bson::{doc, oid::ObjectId},
+  mongodb::bson::{doc, oid::ObjectId, to_bson},
 };
 use komodo_client::{
   api::write::*,
-  entities::{komodo_timestamp, user_group::UserGroup},
+  entities::{
+    komodo_timestamp, permission::PermissionLevel,
+    user_group::UserGroup,
+  },
 };
 use reqwest::StatusCode;
 use resolver_api::Resolve;
 use serror::AddStatusCodeError;
 
-use crate::state::db_client;
+use crate::{helpers::query::get_tag, state::db_client};
 
 use super::WriteArgs;
 
@@ -36,6 +39,7 @@ impl Resolve<WriteArgs> for CreateUserGroup {
       everyone: Default::default(),
       users: Default::default(),
       all: Default::default(),
+      tags: Default::default(),
       updated_at: komodo_timestamp(),
     };
     let db = db_client();
@@ -300,3 +304,48 @@ impl Resolve<WriteArgs> for SetEveryoneUserGroup {
     Ok(res)
   }
 }
+
+impl Resolve<WriteArgs> for UpdateUserGroupTagPermission {
+  #[instrument(name = "UpdateUserGroupTagPermission", skip(admin), fields(admin = admin.username))]
+  async fn resolve(
+    self,
+    WriteArgs { user: admin }: &WriteArgs,
+  ) -> serror::Result<UserGroup> {
+    if !admin.admin {
+      return Err(
+        anyhow!("This call is admin-only")
+          .status_code(StatusCode::FORBIDDEN),
+      );
+    }
+
+    let tag = get_tag(&self.tag).await?;
+
+    let db = db_client();
+
+    let filter = match ObjectId::from_str(&self.user_group) {
+      Ok(id) => doc! { "_id": id },
+      Err(_) => doc! { "name": &self.user_group },
+    };
+    let field = format!("tags.{}", tag.id);
+    let update = if self.permission.level == PermissionLevel::None
+      && self.permission.specific.is_empty()
+    {
+      doc! { "$unset": { &field: "" } }
+    } else {
+      let permission = to_bson(&self.permission)
+        .context("permission is not Bson")?;
+      doc! { "$set": { &field: permission } }
+    };
+    db.user_groups
+      .update_one(filter.clone(), update)
+      .await
+      .context("failed to set tag permission on user group")?;
+    let res = db
+      .user_groups
+      .find_one(filter)
+      .await
+      .context("failed to query db for UserGroups")?
+      .context("no user group with given id")?;
+    Ok(res)
+  }
+}
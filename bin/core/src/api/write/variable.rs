@@ -1,8 +1,11 @@
 use anyhow::{Context, anyhow};
 use database::mungos::mongodb::bson::doc;
+use formatting::{Color, colored};
 use komodo_client::{
   api::write::*,
-  entities::{Operation, ResourceTarget, variable::Variable},
+  entities::{
+    Operation, ResourceTarget, server::Server, variable::Variable,
+  },
 };
 use reqwest::StatusCode;
 use resolver_api::Resolve;
@@ -13,7 +16,8 @@ use crate::{
     query::get_variable,
     update::{add_update, make_update},
   },
-  state::db_client,
+  resource,
+  state::{db_client, variable_references_cache},
 };
 
 use super::WriteArgs;
@@ -36,13 +40,26 @@ impl Resolve<WriteArgs> for CreateVariable {
       value,
       description,
       is_secret,
+      server_id,
     } = self;
 
+    let server_id = match server_id {
+      Some(server_id) if !server_id.is_empty() => Some(
+        resource::get::<Server>(&server_id)
+          .await
+          .context("Failed to get Server to scope variable")?
+          .id,
+      ),
+      _ => None,
+    };
+
     let variable = Variable {
       name,
       value,
       description,
       is_secret,
+      server_id,
+      ..Default::default()
     };
 
     db_client()
@@ -63,7 +80,10 @@ impl Resolve<WriteArgs> for CreateVariable {
 
     add_update(update).await?;
 
-    Ok(get_variable(&variable.name).await?)
+    Ok(
+      get_variable(&variable.name, variable.server_id.as_deref())
+        .await?,
+    )
   }
 }
 
@@ -80,9 +100,13 @@ impl Resolve<WriteArgs> for UpdateVariableValue {
       );
     }
 
-    let UpdateVariableValue { name, value } = self;
+    let UpdateVariableValue {
+      name,
+      value,
+      server_id,
+    } = self;
 
-    let variable = get_variable(&name).await?;
+    let variable = get_variable(&name, server_id.as_deref()).await?;
 
     if value == variable.value {
       return Ok(variable);
@@ -91,7 +115,7 @@ impl Resolve<WriteArgs> for UpdateVariableValue {
     db_client()
       .variables
       .update_one(
-        doc! { "name": &name },
+        doc! { "name": &name, "server_id": &server_id },
         doc! { "$set": { "value": &value } },
       )
       .await
@@ -120,7 +144,7 @@ impl Resolve<WriteArgs> for UpdateVariableValue {
 
     add_update(update).await?;
 
-    Ok(get_variable(&name).await?)
+    Ok(get_variable(&name, server_id.as_deref()).await?)
   }
 }
 
@@ -139,12 +163,12 @@ impl Resolve<WriteArgs> for UpdateVariableDescription {
     db_client()
       .variables
       .update_one(
-        doc! { "name": &self.name },
+        doc! { "name": &self.name, "server_id": &self.server_id },
         doc! { "$set": { "description": &self.description } },
       )
       .await
       .context("Failed to update variable description on db")?;
-    Ok(get_variable(&self.name).await?)
+    Ok(get_variable(&self.name, self.server_id.as_deref()).await?)
   }
 }
 
@@ -163,12 +187,12 @@ impl Resolve<WriteArgs> for UpdateVariableIsSecret {
     db_client()
       .variables
       .update_one(
-        doc! { "name": &self.name },
+        doc! { "name": &self.name, "server_id": &self.server_id },
         doc! { "$set": { "is_secret": self.is_secret } },
       )
       .await
       .context("Failed to update variable is secret on db")?;
-    Ok(get_variable(&self.name).await?)
+    Ok(get_variable(&self.name, self.server_id.as_deref()).await?)
   }
 }
 
@@ -183,10 +207,13 @@ impl Resolve<WriteArgs> for DeleteVariable {
           .status_code(StatusCode::FORBIDDEN),
       );
     }
-    let variable = get_variable(&self.name).await?;
+    let variable =
+      get_variable(&self.name, self.server_id.as_deref()).await?;
     db_client()
       .variables
-      .delete_one(doc! { "name": &self.name })
+      .delete_one(
+        doc! { "name": &self.name, "server_id": &self.server_id },
+      )
       .await
       .context("Failed to delete variable on db")?;
 
@@ -198,6 +225,29 @@ impl Resolve<WriteArgs> for DeleteVariable {
 
     update
       .push_simple_log("Delete Variable", format!("{variable:#?}"));
+
+    if let Some(references) =
+      variable_references_cache().load().get(&variable.name)
+      && !references.is_empty()
+    {
+      let count = references.len();
+      let references = references
+        .iter()
+        .map(|target| {
+          let (variant, id) = target.extract_variant_id();
+          format!("\n{variant}: {id}")
+        })
+        .collect::<String>();
+      update.push_simple_log(
+        "Variable References",
+        format!(
+          "{}: Variable is still referenced by {} resources:{references}",
+          colored("WARN", Color::Yellow),
+          count,
+        ),
+      );
+    }
+
     update.finalize();
 
     add_update(update).await?;
@@ -2,7 +2,7 @@ use std::sync::OnceLock;
 
 use anyhow::{Context, anyhow};
 use komodo_client::entities::config::core::{
-  CoreConfig, OauthCredentials,
+  CoreConfig, GithubOauthCredentials,
 };
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
@@ -26,6 +26,7 @@ pub struct GithubOauthClient {
   client_secret: String,
   redirect_uri: String,
   scopes: String,
+  allowed_orgs: Vec<String>,
   states: Mutex<Vec<String>>,
   user_agent: String,
 }
@@ -34,10 +35,11 @@ impl GithubOauthClient {
   pub fn new(
     CoreConfig {
       github_oauth:
-        OauthCredentials {
+        GithubOauthCredentials {
           enabled,
           id,
           secret,
+          allowed_orgs,
         },
       host,
       ..
@@ -70,7 +72,13 @@ impl GithubOauthClient {
       client_secret: secret.clone(),
       redirect_uri: format!("{host}/auth/github/callback"),
       user_agent: Default::default(),
-      scopes: Default::default(),
+      // Needed to see the user's private org memberships.
+      scopes: if allowed_orgs.is_empty() {
+        Default::default()
+      } else {
+        String::from("read:org")
+      },
+      allowed_orgs: allowed_orgs.clone(),
       states: Default::default(),
     }
     .into()
@@ -141,6 +149,40 @@ impl GithubOauthClient {
       .context("failed to get github user using access token")
   }
 
+  /// Errors if `allowed_orgs` is configured
+  /// and the user isn't a member of any of them.
+  #[instrument(level = "debug", skip(self))]
+  pub async fn check_allowed_orgs(
+    &self,
+    token: &str,
+  ) -> anyhow::Result<()> {
+    if self.allowed_orgs.is_empty() {
+      return Ok(());
+    }
+    let orgs = self
+      .get::<Vec<GithubOrgResponse>>(
+        "https://api.github.com/user/orgs",
+        &[("per_page", "100")],
+        Some(token),
+      )
+      .await
+      .context("failed to get github user orgs using access token")?;
+    // Github logins are case insensitive.
+    let allowed = orgs.iter().any(|org| {
+      self
+        .allowed_orgs
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(&org.login))
+    });
+    if allowed {
+      Ok(())
+    } else {
+      Err(anyhow!(
+        "Github user is not a member of any allowed organization"
+      ))
+    }
+  }
+
   #[instrument(level = "debug", skip(self))]
   async fn get<R: DeserializeOwned>(
     &self,
@@ -233,3 +275,8 @@ pub struct GithubUserResponse {
   pub avatar_url: String,
   // pub email: Option<String>,
 }
+
+#[derive(Deserialize)]
+pub struct GithubOrgResponse {
+  pub login: String,
+}
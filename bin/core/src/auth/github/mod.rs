@@ -1,6 +1,7 @@
 use anyhow::{Context, anyhow};
 use axum::{
-  Router, extract::Query, response::Redirect, routing::get,
+  Router, extract::Query, http::HeaderMap, response::Redirect,
+  routing::get,
 };
 use database::mongo_indexed::Document;
 use database::mungos::mongodb::bson::doc;
@@ -10,7 +11,7 @@ use komodo_client::entities::{
 };
 use reqwest::StatusCode;
 use serde::Deserialize;
-use serror::AddStatusCode;
+use serror::{AddStatusCode, AddStatusCodeError};
 
 use crate::{
   config::core_config,
@@ -18,7 +19,7 @@ use crate::{
   state::{db_client, jwt_client},
 };
 
-use self::client::github_oauth_client;
+use self::client::{GithubUserResponse, github_oauth_client};
 
 use super::{RedirectQuery, STATE_PREFIX_LENGTH};
 
@@ -39,12 +40,7 @@ pub fn router() -> Router {
         )
       }),
     )
-    .route(
-      "/callback",
-      get(|query| async {
-        callback(query).await.status_code(StatusCode::UNAUTHORIZED)
-      }),
-    )
+    .route("/callback", get(callback))
 }
 
 #[derive(Debug, Deserialize)]
@@ -53,17 +49,40 @@ struct CallbackQuery {
   code: String,
 }
 
-#[instrument(name = "GithubCallback", level = "debug")]
+#[instrument(name = "GithubCallback", level = "debug", skip(headers))]
 async fn callback(
+  headers: HeaderMap,
   Query(query): Query<CallbackQuery>,
-) -> anyhow::Result<Redirect> {
+) -> serror::Result<Redirect> {
   let client = github_oauth_client().as_ref().unwrap();
   if !client.check_state(&query.state).await {
-    return Err(anyhow!("state mismatch"));
+    return Err(
+      anyhow!("state mismatch").status_code(StatusCode::UNAUTHORIZED),
+    );
   }
-  let token = client.get_access_token(&query.code).await?;
-  let github_user =
-    client.get_github_user(&token.access_token).await?;
+  let token = client
+    .get_access_token(&query.code)
+    .await
+    .status_code(StatusCode::UNAUTHORIZED)?;
+  let github_user = client
+    .get_github_user(&token.access_token)
+    .await
+    .status_code(StatusCode::UNAUTHORIZED)?;
+  client
+    .check_allowed_orgs(&token.access_token)
+    .await
+    .status_code(StatusCode::FORBIDDEN)?;
+  login(headers, github_user, &query.state)
+    .await
+    .status_code(StatusCode::UNAUTHORIZED)
+}
+
+/// Logs in the Github user, creating the Komodo user if needed.
+async fn login(
+  headers: HeaderMap,
+  github_user: GithubUserResponse,
+  state: &str,
+) -> anyhow::Result<Redirect> {
   let github_id = github_user.id.to_string();
   let db_client = db_client();
   let user = db_client
@@ -73,7 +92,8 @@ async fn callback(
     .context("failed at find user query from database")?;
   let jwt = match user {
     Some(user) => jwt_client()
-      .encode(user.id)
+      .encode(user.id, &headers)
+      .await
       .context("failed to generate jwt")?,
     None => {
       let ts = komodo_timestamp();
@@ -124,12 +144,13 @@ async fn callback(
         .context("inserted_id is not ObjectId")?
         .to_string();
       jwt_client()
-        .encode(user_id)
+        .encode(user_id, &headers)
+        .await
         .context("failed to generate jwt")?
     }
   };
   let exchange_token = jwt_client().create_exchange_token(jwt).await;
-  let redirect = &query.state[STATE_PREFIX_LENGTH..];
+  let redirect = &state[STATE_PREFIX_LENGTH..];
   let redirect_url = if redirect.is_empty() {
     format!("{}?token={exchange_token}", core_config().host)
   } else {
@@ -1,7 +1,8 @@
 use anyhow::{Context, anyhow};
 use async_timing_util::unix_timestamp_ms;
 use axum::{
-  Router, extract::Query, response::Redirect, routing::get,
+  Router, extract::Query, http::HeaderMap, response::Redirect,
+  routing::get,
 };
 use database::mongo_indexed::Document;
 use database::mungos::mongodb::bson::doc;
@@ -39,8 +40,10 @@ pub fn router() -> Router {
     )
     .route(
       "/callback",
-      get(|query| async {
-        callback(query).await.status_code(StatusCode::UNAUTHORIZED)
+      get(|headers, query| async {
+        callback(headers, query)
+          .await
+          .status_code(StatusCode::UNAUTHORIZED)
       }),
     )
 }
@@ -52,8 +55,9 @@ struct CallbackQuery {
   error: Option<String>,
 }
 
-#[instrument(name = "GoogleCallback", level = "debug")]
+#[instrument(name = "GoogleCallback", level = "debug", skip(headers))]
 async fn callback(
+  headers: HeaderMap,
   Query(query): Query<CallbackQuery>,
 ) -> anyhow::Result<Redirect> {
   // Safe: the method is only called after the client is_some
@@ -82,7 +86,8 @@ async fn callback(
     .context("failed at find user query from mongo")?;
   let jwt = match user {
     Some(user) => jwt_client()
-      .encode(user.id)
+      .encode(user.id, &headers)
+      .await
       .context("failed to generate jwt")?,
     None => {
       let ts = unix_timestamp_ms() as i64;
@@ -138,7 +143,8 @@ async fn callback(
         .context("inserted_id is not ObjectId")?
         .to_string();
       jwt_client()
-        .encode(user_id)
+        .encode(user_id, &headers)
+        .await
         .context("failed to generate jwt")?
     }
   };
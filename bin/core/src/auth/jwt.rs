@@ -4,17 +4,19 @@ use anyhow::{Context, anyhow};
 use async_timing_util::{
   Timelength, get_timelength_in_ms, unix_timestamp_ms,
 };
+use axum::http::HeaderMap;
 use database::mungos::mongodb::bson::doc;
 use jsonwebtoken::{
   DecodingKey, EncodingKey, Header, Validation, decode, encode,
 };
 use komodo_client::{
-  api::auth::JwtResponse, entities::config::core::CoreConfig,
+  api::auth::JwtResponse,
+  entities::{config::core::CoreConfig, user_session::UserSession},
 };
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
 
-use crate::helpers::random_string;
+use crate::{helpers::random_string, state::db_client};
 
 type ExchangeTokenMap = Mutex<HashMap<String, (JwtResponse, u128)>>;
 
@@ -23,6 +25,9 @@ pub struct JwtClaims {
   pub id: String,
   pub iat: u128,
   pub exp: u128,
+  /// The id of the associated [UserSession]
+  #[serde(default)]
+  pub jti: String,
 }
 
 pub struct JwtClient {
@@ -46,16 +51,17 @@ impl JwtClient {
       validation: Validation::new(Default::default()),
       encoding_key: EncodingKey::from_secret(secret.as_bytes()),
       decoding_key: DecodingKey::from_secret(secret.as_bytes()),
-      ttl_ms: get_timelength_in_ms(
-        config.jwt_ttl.to_string().parse()?,
-      ),
+      ttl_ms: config.jwt_ttl.as_millis() as u128,
       exchange_tokens: Default::default(),
     })
   }
 
-  pub fn encode(
+  /// Issues a JWT for the user, creating the [UserSession]
+  /// which must exist for the JWT to authenticate.
+  pub async fn encode(
     &self,
     user_id: String,
+    headers: &HeaderMap,
   ) -> anyhow::Result<JwtResponse> {
     let iat = unix_timestamp_ms();
     let exp = iat + self.ttl_ms;
@@ -63,9 +69,26 @@ impl JwtClient {
       id: user_id.clone(),
       iat,
       exp,
+      jti: random_string(40),
     };
     let jwt = encode(&self.header, &claims, &self.encoding_key)
       .context("failed at signing claim")?;
+    let session = UserSession {
+      jwt_id: claims.jti,
+      user_id: user_id.clone(),
+      issued_at: iat as i64,
+      expires_at: exp as i64,
+      user_agent: headers
+        .get("user-agent")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string),
+      ip: super::client_ip(headers).map(str::to_string),
+    };
+    db_client()
+      .sessions
+      .insert_one(session)
+      .await
+      .context("failed to create session on db")?;
     Ok(JwtResponse { user_id, jwt })
   }
 
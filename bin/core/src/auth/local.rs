@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use anyhow::{Context, anyhow};
 use async_timing_util::unix_timestamp_ms;
+use axum::http::HeaderMap;
 use database::{
   hash_password,
   mungos::mongodb::bson::{Document, doc, oid::ObjectId},
@@ -13,7 +14,9 @@ use komodo_client::{
   },
   entities::user::{User, UserConfig},
 };
+use reqwest::StatusCode;
 use resolver_api::Resolve;
+use serror::AddStatusCode;
 
 use crate::{
   api::auth::AuthArgs,
@@ -21,11 +24,13 @@ use crate::{
   state::{db_client, jwt_client},
 };
 
+use super::login_limiter;
+
 impl Resolve<AuthArgs> for SignUpLocalUser {
-  #[instrument(name = "SignUpLocalUser", skip(self))]
+  #[instrument(name = "SignUpLocalUser", skip(self, headers))]
   async fn resolve(
     self,
-    _: &AuthArgs,
+    AuthArgs { headers, .. }: &AuthArgs,
   ) -> serror::Result<SignUpLocalUserResponse> {
     let core_config = core_config();
 
@@ -97,53 +102,74 @@ impl Resolve<AuthArgs> for SignUpLocalUser {
       .to_string();
 
     jwt_client()
-      .encode(user_id.clone())
+      .encode(user_id.clone(), headers)
+      .await
       .context("failed to generate jwt for user")
       .map_err(Into::into)
   }
 }
 
 impl Resolve<AuthArgs> for LoginLocalUser {
-  #[instrument(name = "LoginLocalUser", level = "debug", skip(self))]
+  #[instrument(
+    name = "LoginLocalUser",
+    level = "debug",
+    skip(self, headers)
+  )]
   async fn resolve(
     self,
-    _: &AuthArgs,
+    AuthArgs { headers, ip }: &AuthArgs,
   ) -> serror::Result<LoginLocalUserResponse> {
     if !core_config().local_auth {
       return Err(anyhow!("local auth is not enabled").into());
     }
 
-    let user = db_client()
-      .users
-      .find_one(doc! { "username": &self.username })
-      .await
-      .context("failed at db query for users")?
-      .with_context(|| {
-        format!("did not find user with username {}", self.username)
-      })?;
-
-    let UserConfig::Local {
-      password: user_pw_hash,
-    } = user.config
-    else {
-      return Err(
-        anyhow!(
-          "non-local auth users can not log in with a password"
-        )
-        .into(),
-      );
-    };
+    login_limiter::check(&self.username, ip)
+      .status_code(StatusCode::TOO_MANY_REQUESTS)?;
 
-    let verified = bcrypt::verify(self.password, &user_pw_hash)
-      .context("failed at verify password")?;
+    let res =
+      login_local_user(&self.username, self.password, headers).await;
 
-    if !verified {
-      return Err(anyhow!("invalid credentials").into());
+    match &res {
+      Ok(_) => login_limiter::reset(&self.username, ip),
+      Err(_) => login_limiter::record_failure(&self.username, ip),
     }
 
-    jwt_client()
-      .encode(user.id.clone())
-      .context("failed at generating jwt for user")
-      .map_err(Into::into)
+    res.map_err(Into::into)
   }
 }
+
+async fn login_local_user(
+  username: &str,
+  password: String,
+  headers: &HeaderMap,
+) -> anyhow::Result<LoginLocalUserResponse> {
+  let user = db_client()
+    .users
+    .find_one(doc! { "username": username })
+    .await
+    .context("failed at db query for users")?
+    .with_context(|| {
+      format!("did not find user with username {username}")
+    })?;
+
+  let UserConfig::Local {
+    password: user_pw_hash,
+  } = user.config
+  else {
+    return Err(anyhow!(
+      "non-local auth users can not log in with a password"
+    ));
+  };
+
+  let verified = bcrypt::verify(password, &user_pw_hash)
+    .context("failed at verify password")?;
+
+  if !verified {
+    return Err(anyhow!("invalid credentials"));
+  }
+
+  jwt_client()
+    .encode(user.id.clone(), headers)
+    .await
+    .context("failed at generating jwt for user")
+}
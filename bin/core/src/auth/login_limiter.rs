@@ -0,0 +1,124 @@
+use std::{
+  collections::{HashMap, VecDeque},
+  sync::{Mutex, OnceLock},
+  time::{Duration, Instant},
+};
+
+use anyhow::anyhow;
+use komodo_client::entities::Timelength;
+
+use crate::config::core_config;
+
+#[derive(Default)]
+struct FailedLogins {
+  /// Times of failures within the window, oldest first.
+  failures: VecDeque<Instant>,
+  locked_until: Option<Instant>,
+}
+
+impl FailedLogins {
+  fn is_stale(&self, now: Instant, window: Duration) -> bool {
+    self.locked_until.is_none_or(|until| until <= now)
+      && self
+        .failures
+        .back()
+        .is_none_or(|last| now.duration_since(*last) > window)
+  }
+}
+
+fn failed_logins() -> &'static Mutex<HashMap<String, FailedLogins>> {
+  static FAILED_LOGINS: OnceLock<
+    Mutex<HashMap<String, FailedLogins>>,
+  > = OnceLock::new();
+  FAILED_LOGINS.get_or_init(Default::default)
+}
+
+fn duration(timelength: Timelength) -> Duration {
+  Duration::from_millis(timelength.as_millis() as u64)
+}
+
+/// Failures are tracked per username, and also per client ip.
+fn keys(username: &str, ip: &str) -> Vec<String> {
+  let mut keys = vec![format!("user:{username}")];
+  if !ip.is_empty() {
+    keys.push(format!("ip:{ip}"));
+  }
+  keys
+}
+
+/// Errors if the username or client ip is locked out
+/// from too many failed login attempts.
+pub fn check(username: &str, ip: &str) -> anyhow::Result<()> {
+  let config = core_config();
+  if config.login_max_failed_attempts == 0 {
+    return Ok(());
+  }
+  let now = Instant::now();
+  let map = failed_logins().lock().unwrap();
+  for key in keys(username, ip) {
+    if let Some(until) =
+      map.get(&key).and_then(|entry| entry.locked_until)
+      && until > now
+    {
+      return Err(anyhow!(
+        "Too many failed login attempts. Try again in {}s.",
+        until.duration_since(now).as_secs() + 1
+      ));
+    }
+  }
+  Ok(())
+}
+
+/// Record a failed login, locking out the username / client ip
+/// once the max attempts are reached within the window.
+pub fn record_failure(username: &str, ip: &str) {
+  let config = core_config();
+  let max = config.login_max_failed_attempts as usize;
+  if max == 0 {
+    return;
+  }
+  let window = duration(config.login_failed_attempts_window);
+  let lockout = duration(config.login_lockout_duration);
+  let now = Instant::now();
+  let mut map = failed_logins().lock().unwrap();
+  // Prune entries which no longer affect login,
+  // so the map can't grow unbounded.
+  map.retain(|_, entry| !entry.is_stale(now, window));
+  for key in keys(username, ip) {
+    let entry = map.entry(key).or_default();
+    while entry
+      .failures
+      .front()
+      .is_some_and(|first| now.duration_since(*first) > window)
+    {
+      entry.failures.pop_front();
+    }
+    entry.failures.push_back(now);
+    if entry.failures.len() >= max {
+      entry.failures.clear();
+      entry.locked_until = Some(now + lockout);
+    }
+  }
+}
+
+/// Clear failures after a successful login.
+pub fn reset(username: &str, ip: &str) {
+  let mut map = failed_logins().lock().unwrap();
+  for key in keys(username, ip) {
+    map.remove(&key);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn keys_include_peer_ip() {
+    assert_eq!(
+      keys("admin", "10.0.0.7"),
+      ["user:admin", "ip:10.0.0.7"]
+    );
+    assert_eq!(keys("admin", ""), ["user:admin"]);
+  }
+}
@@ -1,16 +1,26 @@
+use std::net::SocketAddr;
+
 use anyhow::{Context, anyhow};
 use async_timing_util::unix_timestamp_ms;
 use axum::{
-  extract::Request, http::HeaderMap, middleware::Next,
-  response::Response,
+  extract::Request,
+  http::{HeaderMap, HeaderValue, header::RETRY_AFTER},
+  middleware::Next,
+  response::{IntoResponse, Response},
 };
 use database::mungos::mongodb::bson::doc;
-use komodo_client::entities::{komodo_timestamp, user::User};
+use komodo_client::entities::{
+  api_key::{ApiKey, ApiKeyPermissionCeiling},
+  komodo_timestamp,
+  permission::PermissionLevel,
+  user::User,
+};
 use reqwest::StatusCode;
 use serde::Deserialize;
-use serror::AddStatusCode;
+use serror::{AddStatusCode, AddStatusCodeError};
 
 use crate::{
+  config::core_config,
   helpers::query::get_user,
   state::{db_client, jwt_client},
 };
@@ -21,33 +31,116 @@ pub mod github;
 pub mod google;
 pub mod jwt;
 pub mod oidc;
+pub mod scim;
 
 mod local;
+mod login_limiter;
+mod rate_limiter;
 
 const STATE_PREFIX_LENGTH: usize = 20;
 
+const API_KEY_LAST_USED_INTERVAL_MS: i64 = 60_000;
+
 #[derive(Debug, Deserialize)]
 struct RedirectQuery {
   redirect: Option<String>,
 }
 
+/// The client ip, when it is forwarded by a reverse proxy
+/// and `trust_forwarded_for` is enabled.
+pub fn client_ip(headers: &HeaderMap) -> Option<&str> {
+  if !core_config().trust_forwarded_for {
+    return None;
+  }
+  headers
+    .get("x-forwarded-for")
+    .and_then(|value| value.to_str().ok())
+    .and_then(|value| value.split(',').next())
+    .or_else(|| {
+      headers
+        .get("x-real-ip")
+        .and_then(|value| value.to_str().ok())
+    })
+    .map(str::trim)
+    .filter(|ip| !ip.is_empty())
+}
+
+/// The ip of the client making the request.
+/// Uses the forwarded client ip when trusted,
+/// otherwise the address of the connecting peer.
+pub fn request_ip(headers: &HeaderMap, peer: SocketAddr) -> String {
+  client_ip(headers)
+    .map(str::to_string)
+    .unwrap_or_else(|| peer.ip().to_string())
+}
+
 #[instrument(level = "debug")]
 pub async fn auth_request(
   headers: HeaderMap,
   mut req: Request,
   next: Next,
 ) -> serror::Result<Response> {
-  let user = authenticate_check_enabled(&headers)
+  let (user, ceiling) = authenticate_check_enabled(&headers)
     .await
     .status_code(StatusCode::UNAUTHORIZED)?;
+  if let Err(retry_after) = rate_limiter::check(&user) {
+    let secs = retry_after.as_secs() + 1;
+    let mut res = anyhow!("Too many requests. Try again in {secs}s.")
+      .status_code(StatusCode::TOO_MANY_REQUESTS)
+      .into_response();
+    res
+      .headers_mut()
+      .insert(RETRY_AFTER, HeaderValue::from(secs));
+    return Ok(res);
+  }
   req.extensions_mut().insert(user);
+  if let Some(ceiling) = ceiling {
+    req.extensions_mut().insert(ApiKeyCeiling(ceiling));
+  }
   Ok(next.run(req).await)
 }
 
+/// Attached to the request extensions when authenticated
+/// using an api key which has a permission ceiling.
+#[derive(Clone)]
+pub struct ApiKeyCeiling(pub ApiKeyPermissionCeiling);
+
+/// Errors if the request is authenticated with an api key
+/// whose permission ceiling doesn't allow the request.
+pub fn check_api_key_ceiling(
+  ceiling: Option<&ApiKeyCeiling>,
+  level: PermissionLevel,
+  request: &str,
+) -> serror::Result<()> {
+  match ceiling {
+    Some(ApiKeyCeiling(ceiling))
+      if !ceiling.allows(level, request) =>
+    {
+      Err(
+        anyhow!(
+          "Api key permission ceiling does not allow {request}"
+        )
+        .status_code(StatusCode::FORBIDDEN),
+      )
+    }
+    _ => Ok(()),
+  }
+}
+
 #[instrument(level = "debug")]
 pub async fn get_user_id_from_headers(
   headers: &HeaderMap,
 ) -> anyhow::Result<String> {
+  authenticate_headers(headers)
+    .await
+    .map(|(user_id, _)| user_id)
+}
+
+/// Returns the user id, along with the api key
+/// permission ceiling if one applies.
+async fn authenticate_headers(
+  headers: &HeaderMap,
+) -> anyhow::Result<(String, Option<ApiKeyPermissionCeiling>)> {
   match (
     headers.get("authorization"),
     headers.get("x-api-key"),
@@ -56,17 +149,19 @@ pub async fn get_user_id_from_headers(
     (Some(jwt), _, _) => {
       // USE JWT
       let jwt = jwt.to_str().context("jwt is not str")?;
-      auth_jwt_get_user_id(jwt)
+      let user_id = auth_jwt_get_user_id(jwt)
         .await
-        .context("failed to authenticate jwt")
+        .context("failed to authenticate jwt")?;
+      Ok((user_id, None))
     }
     (None, Some(key), Some(secret)) => {
       // USE API KEY / SECRET
       let key = key.to_str().context("key is not str")?;
       let secret = secret.to_str().context("secret is not str")?;
-      auth_api_key_get_user_id(key, secret)
+      let key = auth_api_key(key, secret)
         .await
-        .context("failed to authenticate api key")
+        .context("failed to authenticate api key")?;
+      Ok((key.user_id, key.permission_ceiling))
     }
     _ => {
       // AUTH FAIL
@@ -80,11 +175,11 @@ pub async fn get_user_id_from_headers(
 #[instrument(level = "debug")]
 pub async fn authenticate_check_enabled(
   headers: &HeaderMap,
-) -> anyhow::Result<User> {
-  let user_id = get_user_id_from_headers(headers).await?;
+) -> anyhow::Result<(User, Option<ApiKeyPermissionCeiling>)> {
+  let (user_id, ceiling) = authenticate_headers(headers).await?;
   let user = get_user(&user_id).await?;
   if user.enabled {
-    Ok(user)
+    Ok((user, ceiling))
   } else {
     Err(anyhow!("user not enabled"))
   }
@@ -95,11 +190,21 @@ pub async fn auth_jwt_get_user_id(
   jwt: &str,
 ) -> anyhow::Result<String> {
   let claims: JwtClaims = jwt_client().decode(jwt)?;
-  if claims.exp > unix_timestamp_ms() {
-    Ok(claims.id)
-  } else {
-    Err(anyhow!("token has expired"))
+  if claims.exp <= unix_timestamp_ms() {
+    return Err(anyhow!("token has expired"));
   }
+  // Tokens issued before sessions were tracked have no id,
+  // and can't be revoked, so they are no longer accepted.
+  if claims.jti.is_empty() {
+    return Err(anyhow!("token has no session, log in again"));
+  }
+  db_client()
+    .sessions
+    .find_one(doc! { "jwt_id": &claims.jti })
+    .await
+    .context("failed to query db for session")?
+    .context("token session has been revoked")?;
+  Ok(claims.id)
 }
 
 #[instrument(level = "debug")]
@@ -111,16 +216,20 @@ pub async fn auth_jwt_check_enabled(
 }
 
 #[instrument(level = "debug")]
-pub async fn auth_api_key_get_user_id(
+async fn auth_api_key(
   key: &str,
   secret: &str,
-) -> anyhow::Result<String> {
-  let key = db_client()
+) -> anyhow::Result<ApiKey> {
+  let db = db_client();
+  let (key, service_account) = match db
     .api_keys
     .find_one(doc! { "key": key })
     .await
     .context("failed to query db")?
-    .context("no api key matching key")?;
+  {
+    Some(key) => (key, false),
+    None => (service_account_api_key(key).await?, true),
+  };
   if key.expires != 0 && key.expires < komodo_timestamp() {
     return Err(anyhow!("api key expired"));
   }
@@ -128,20 +237,62 @@ pub async fn auth_api_key_get_user_id(
     .context("failed to verify secret hash")?
   {
     // secret matches
-    Ok(key.user_id)
+    let now = komodo_timestamp();
+    // Throttled so busy keys don't write to the db on every request.
+    if now - key.last_used_at > API_KEY_LAST_USED_INTERVAL_MS {
+      let res = if service_account {
+        db.service_accounts
+          .update_one(
+            doc! { "api_keys.key": &key.key },
+            doc! { "$set": { "api_keys.$.last_used_at": now } },
+          )
+          .await
+      } else {
+        db.api_keys
+          .update_one(
+            doc! { "key": &key.key },
+            doc! { "$set": { "last_used_at": now } },
+          )
+          .await
+      };
+      if let Err(e) = res {
+        warn!("Failed to update api key last_used_at | {e:?}");
+      }
+    }
+    Ok(key)
   } else {
     // secret mismatch
     Err(anyhow!("invalid api secret"))
   }
 }
 
+/// Finds the api key among the service account api keys.
+/// Their `user_id` is the service account id.
+async fn service_account_api_key(
+  key: &str,
+) -> anyhow::Result<ApiKey> {
+  db_client()
+    .service_accounts
+    .find_one(doc! { "api_keys.key": key })
+    .await
+    .context("failed to query db")?
+    .and_then(|service_account| {
+      service_account
+        .api_keys
+        .into_iter()
+        .find(|api_key| api_key.key == key)
+    })
+    .context("no api key matching key")
+}
+
 #[instrument(level = "debug")]
 pub async fn auth_api_key_check_enabled(
   key: &str,
   secret: &str,
-) -> anyhow::Result<User> {
-  let user_id = auth_api_key_get_user_id(key, secret).await?;
-  check_enabled(user_id).await
+) -> anyhow::Result<(User, Option<ApiKeyPermissionCeiling>)> {
+  let key = auth_api_key(key, secret).await?;
+  let user = check_enabled(key.user_id).await?;
+  Ok((user, key.permission_ceiling))
 }
 
 #[instrument(level = "debug")]
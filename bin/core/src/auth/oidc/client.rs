@@ -3,15 +3,17 @@ use std::{sync::OnceLock, time::Duration};
 use anyhow::Context;
 use arc_swap::ArcSwapOption;
 use openidconnect::{
-  Client, ClientId, ClientSecret, EmptyAdditionalClaims,
-  EndpointMaybeSet, EndpointNotSet, EndpointSet, IssuerUrl,
-  RedirectUrl, StandardErrorResponse, core::*,
+  Client, ClientId, ClientSecret, EndpointMaybeSet, EndpointNotSet,
+  EndpointSet, IssuerUrl, RedirectUrl, StandardErrorResponse,
+  core::*,
 };
 
 use crate::config::core_config;
 
+use super::GroupClaims;
+
 type OidcClient = Client<
-  EmptyAdditionalClaims,
+  GroupClaims,
   CoreAuthDisplay,
   CoreGenderClaim,
   CoreJweContentEncryptionAlgorithm,
@@ -39,7 +41,8 @@ pub fn oidc_client() -> &'static ArcSwapOption<OidcClient> {
 /// The OIDC client must be reinitialized to
 /// pick up the latest provider JWKs. This
 /// function spawns a management thread to do this
-/// on a loop.
+/// on a loop, along with a thread to prune expired
+/// CSRF verifier tokens.
 pub async fn spawn_oidc_client_management() {
   let config = core_config();
   if !config.oidc_enabled
@@ -59,6 +62,12 @@ pub async fn spawn_oidc_client_management() {
       }
     }
   });
+  tokio::spawn(async move {
+    loop {
+      tokio::time::sleep(Duration::from_secs(60)).await;
+      super::prune_expired_verifier_tokens();
+    }
+  });
 }
 
 async fn reset_oidc_client() -> anyhow::Result<()> {
@@ -71,7 +80,7 @@ async fn reset_oidc_client() -> anyhow::Result<()> {
   .await
   .context("Failed to get OIDC /.well-known/openid-configuration")?;
 
-  let client = CoreClient::from_provider_metadata(
+  let client: OidcClient = Client::from_provider_metadata(
     provider_metadata,
     ClientId::new(config.oidc_client_id.to_string()),
     // The secret may be empty / ommitted if auth provider supports PKCE
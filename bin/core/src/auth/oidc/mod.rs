@@ -1,24 +1,25 @@
-use std::sync::OnceLock;
+use std::{str::FromStr, sync::OnceLock};
 
 use anyhow::{Context, anyhow};
 use axum::{
-  Router, extract::Query, response::Redirect, routing::get,
+  Router, extract::Query, http::HeaderMap, response::Redirect,
+  routing::get,
 };
 use client::oidc_client;
 use dashmap::DashMap;
-use database::mungos::mongodb::bson::{Document, doc};
+use database::mungos::mongodb::bson::{Document, doc, oid::ObjectId};
 use komodo_client::entities::{
   komodo_timestamp,
   user::{User, UserConfig},
 };
 use openidconnect::{
-  AccessTokenHash, AuthorizationCode, CsrfToken,
-  EmptyAdditionalClaims, Nonce, OAuth2TokenResponse,
-  PkceCodeChallenge, PkceCodeVerifier, Scope, TokenResponse,
+  AccessTokenHash, AdditionalClaims, AuthorizationCode, CsrfToken,
+  Nonce, OAuth2TokenResponse, PkceCodeChallenge, PkceCodeVerifier,
+  Scope, TokenResponse,
   core::{CoreAuthenticationFlow, CoreGenderClaim},
 };
 use reqwest::StatusCode;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serror::AddStatusCode;
 
 use crate::{
@@ -59,6 +60,31 @@ fn verifier_tokens() -> &'static VerifierMap {
   VERIFIERS.get_or_init(Default::default)
 }
 
+/// Removes the tokens of abandoned logins,
+/// which are otherwise never removed from the map.
+fn prune_expired_verifier_tokens() {
+  let now = komodo_timestamp();
+  verifier_tokens()
+    .retain(|_, (_, _, _, valid_until)| *valid_until > now);
+}
+
+/// The default scopes followed by `oidc_additional_scopes`,
+/// skipping empty and duplicate entries.
+fn scopes() -> Vec<Scope> {
+  let mut scopes = vec![
+    String::from("openid"),
+    String::from("profile"),
+    String::from("email"),
+  ];
+  for scope in &core_config().oidc_additional_scopes {
+    let scope = scope.trim();
+    if !scope.is_empty() && !scopes.iter().any(|s| s == scope) {
+      scopes.push(scope.to_string());
+    }
+  }
+  scopes.into_iter().map(Scope::new).collect()
+}
+
 pub fn router() -> Router {
   Router::new()
     .route(
@@ -69,8 +95,10 @@ pub fn router() -> Router {
     )
     .route(
       "/callback",
-      get(|query| async {
-        callback(query).await.status_code(StatusCode::UNAUTHORIZED)
+      get(|headers, query| async {
+        callback(headers, query)
+          .await
+          .status_code(StatusCode::UNAUTHORIZED)
       }),
     )
 }
@@ -94,9 +122,7 @@ async fn login(
       Nonce::new_random,
     )
     .set_pkce_challenge(pkce_challenge)
-    .add_scope(Scope::new("openid".to_string()))
-    .add_scope(Scope::new("profile".to_string()))
-    .add_scope(Scope::new("email".to_string()))
+    .add_scopes(scopes())
     .url();
 
   // Data inserted here will be matched on callback side for csrf protection.
@@ -131,6 +157,108 @@ async fn login(
   Ok(redirect)
 }
 
+/// Captures all additional claims returned in the ID token
+/// or user info, so the configured groups claim can be read.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupClaims {
+  #[serde(flatten)]
+  claims: serde_json::Map<String, serde_json::Value>,
+}
+
+impl AdditionalClaims for GroupClaims {}
+
+impl GroupClaims {
+  /// The configured `oidc_groups_claim`, if present.
+  /// The claim may be a nested path like `realm_access.roles`.
+  fn groups_claim(&self) -> Option<&serde_json::Value> {
+    let mut path = core_config().oidc_groups_claim.split('.');
+    let mut value =
+      path.next().and_then(|key| self.claims.get(key))?;
+    for key in path {
+      value = value.get(key)?;
+    }
+    Some(value)
+  }
+
+  /// The groups in the configured `oidc_groups_claim`.
+  /// The value may be either a list of groups or a single group.
+  fn groups(&self) -> Vec<&str> {
+    match self.groups_claim() {
+      Some(serde_json::Value::String(group)) => vec![group.as_str()],
+      Some(serde_json::Value::Array(groups)) => {
+        groups.iter().filter_map(|group| group.as_str()).collect()
+      }
+      _ => Vec::new(),
+    }
+  }
+
+  /// Whether the user is in one of `oidc_admin_groups`.
+  fn in_admin_group(&self) -> bool {
+    let admin_groups = &core_config().oidc_admin_groups;
+    !admin_groups.is_empty()
+      && self
+        .groups()
+        .into_iter()
+        .any(|group| admin_groups.iter().any(|g| g == group))
+  }
+
+  /// Adds the user to the User Groups in `oidc_group_map`
+  /// matching their groups, and removes them from the rest.
+  async fn sync_user_groups(
+    &self,
+    user_id: &str,
+  ) -> anyhow::Result<()> {
+    let group_map = &core_config().oidc_group_map;
+    if group_map.is_empty() {
+      return Ok(());
+    }
+    let groups = self.groups();
+    let (add, remove): (Vec<_>, Vec<_>) = group_map
+      .iter()
+      .partition(|map| groups.contains(&map.claim_value.as_str()));
+    let add = add
+      .into_iter()
+      .map(|map| map.user_group_name.as_str())
+      .collect::<Vec<_>>();
+    // Another claim value may still map the user to the same group.
+    let remove = remove
+      .into_iter()
+      .map(|map| map.user_group_name.as_str())
+      .filter(|name| !add.contains(name))
+      .collect::<Vec<_>>();
+    let user_groups = &db_client().user_groups;
+    let ts = komodo_timestamp();
+    if !add.is_empty() {
+      user_groups
+        .update_many(
+          doc! {
+            "name": { "$in": add },
+            "users": { "$ne": user_id },
+          },
+          doc! {
+            "$addToSet": { "users": user_id },
+            "$set": { "updated_at": ts },
+          },
+        )
+        .await
+        .context("Failed to add user to mapped user groups")?;
+    }
+    if !remove.is_empty() {
+      user_groups
+        .update_many(
+          doc! { "name": { "$in": remove }, "users": user_id },
+          doc! {
+            "$pull": { "users": user_id },
+            "$set": { "updated_at": ts },
+          },
+        )
+        .await
+        .context("Failed to remove user from mapped user groups")?;
+    }
+    Ok(())
+  }
+}
+
 #[derive(Debug, Deserialize)]
 struct CallbackQuery {
   state: Option<String>,
@@ -138,8 +266,9 @@ struct CallbackQuery {
   error: Option<String>,
 }
 
-#[instrument(name = "OidcCallback", level = "debug")]
+#[instrument(name = "OidcCallback", level = "debug", skip(headers))]
 async fn callback(
+  headers: HeaderMap,
   Query(query): Query<CallbackQuery>,
 ) -> anyhow::Result<Redirect> {
   let client = oidc_client().load();
@@ -222,30 +351,83 @@ async fn callback(
     .await
     .context("failed at find user query from database")?;
 
+  let config = core_config();
+  let admin_groups = &config.oidc_admin_groups;
+
+  let id_token_claims = claims.additional_claims();
+  let groups_in_id_token = id_token_claims.groups_claim().is_some();
+
+  // User info is needed to create new users, and to evaluate
+  // admin / mapped groups when the ID token has no groups claim.
+  let user_info = if user.is_none()
+    || (!groups_in_id_token
+      && (!admin_groups.is_empty()
+        || !config.oidc_group_map.is_empty()))
+  {
+    let user_info = client
+      .user_info(
+        token_response.access_token().clone(),
+        claims.subject().clone().into(),
+      )
+      .context("Invalid user info request")?
+      .request_async::<GroupClaims, _, CoreGenderClaim>(
+        reqwest_client,
+      )
+      .await
+      .context("Failed to fetch user info")?;
+    Some(user_info)
+  } else {
+    None
+  };
+
+  // Prefer the groups claim in the ID token, falling back to user info.
+  let group_claims = if groups_in_id_token {
+    Some(id_token_claims)
+  } else {
+    user_info
+      .as_ref()
+      .map(|user_info| user_info.additional_claims())
+  };
+
+  let in_admin_group = group_claims
+    .map(GroupClaims::in_admin_group)
+    .unwrap_or_default();
+
   let jwt = match user {
-    Some(user) => jwt_client()
-      .encode(user.id)
-      .context("failed to generate jwt")?,
+    Some(user) => {
+      // Group membership is the source of truth for admin when
+      // configured. Super admins are never demoted.
+      if !admin_groups.is_empty()
+        && !user.super_admin
+        && user.admin != in_admin_group
+      {
+        db_client
+          .users
+          .update_one(
+            doc! { "_id": ObjectId::from_str(&user.id)? },
+            doc! { "$set": { "admin": in_admin_group } },
+          )
+          .await
+          .context("Failed to update user admin from groups")?;
+      }
+      if let Some(group_claims) = group_claims {
+        group_claims.sync_user_groups(&user.id).await?;
+      }
+      jwt_client()
+        .encode(user.id, &headers)
+        .await
+        .context("failed to generate jwt")?
+    }
     None => {
       let ts = komodo_timestamp();
       let no_users_exist =
         db_client.users.find_one(Document::new()).await?.is_none();
-      let core_config = core_config();
-      if !no_users_exist && core_config.disable_user_registration {
+      if !no_users_exist && config.disable_user_registration {
         return Err(anyhow!("User registration is disabled"));
       }
 
-      // Fetch user info
-      let user_info = client
-        .user_info(
-          token_response.access_token().clone(),
-          claims.subject().clone().into(),
-        )
-        .context("Invalid user info request")?
-        .request_async::<EmptyAdditionalClaims, _, CoreGenderClaim>(
-          reqwest_client,
-        )
-        .await
+      let user_info = user_info
+        .as_ref()
         .context("Failed to fetch user info for new user")?;
 
       // Will use preferred_username, then email, then user_id if it isn't available.
@@ -257,7 +439,7 @@ async fn callback(
             .email()
             .map(|email| email.as_str())
             .unwrap_or(user_id);
-          if core_config.oidc_use_full_email {
+          if config.oidc_use_full_email {
             email
           } else {
             email
@@ -283,8 +465,8 @@ async fn callback(
       let user = User {
         id: Default::default(),
         username,
-        enabled: no_users_exist || core_config.enable_new_users,
-        admin: no_users_exist,
+        enabled: no_users_exist || config.enable_new_users,
+        admin: no_users_exist || in_admin_group,
         super_admin: no_users_exist,
         create_server_permissions: no_users_exist,
         create_build_permissions: no_users_exist,
@@ -293,7 +475,7 @@ async fn callback(
         recents: Default::default(),
         all: Default::default(),
         config: UserConfig::Oidc {
-          provider: core_config.oidc_provider.clone(),
+          provider: config.oidc_provider.clone(),
           user_id: user_id.to_string(),
         },
       };
@@ -308,8 +490,13 @@ async fn callback(
         .context("inserted_id is not ObjectId")?
         .to_string();
 
+      if let Some(group_claims) = group_claims {
+        group_claims.sync_user_groups(&user_id).await?;
+      }
+
       jwt_client()
-        .encode(user_id)
+        .encode(user_id, &headers)
+        .await
         .context("failed to generate jwt")?
     }
   };
@@ -322,3 +509,35 @@ async fn callback(
   };
   Ok(Redirect::to(&redirect_url))
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn insert_verifier_token(csrf: &str, valid_until: i64) {
+    verifier_tokens().insert(
+      csrf.to_string(),
+      (
+        PkceCodeVerifier::new(String::from("verifier")),
+        Nonce::new(String::from("nonce")),
+        None,
+        valid_until,
+      ),
+    );
+  }
+
+  #[test]
+  fn prune_removes_only_expired_verifier_tokens() {
+    let now = komodo_timestamp();
+    insert_verifier_token("prune-test-expired", now - 1_000);
+    insert_verifier_token(
+      "prune-test-valid",
+      now + CSRF_VALID_FOR_MS,
+    );
+
+    prune_expired_verifier_tokens();
+
+    assert!(!verifier_tokens().contains_key("prune-test-expired"));
+    assert!(verifier_tokens().contains_key("prune-test-valid"));
+  }
+}
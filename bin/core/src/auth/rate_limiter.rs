@@ -0,0 +1,61 @@
+use std::{
+  collections::HashMap,
+  sync::{Mutex, OnceLock},
+  time::{Duration, Instant},
+};
+
+use komodo_client::entities::user::{User, UserConfig};
+
+use crate::config::core_config;
+
+/// A token bucket holding up to a minute's worth of requests,
+/// refilled continuously at the per minute rate.
+struct TokenBucket {
+  tokens: f64,
+  updated_at: Instant,
+}
+
+fn buckets() -> &'static Mutex<HashMap<String, TokenBucket>> {
+  static BUCKETS: OnceLock<Mutex<HashMap<String, TokenBucket>>> =
+    OnceLock::new();
+  BUCKETS.get_or_init(Default::default)
+}
+
+fn limit_per_minute(user: &User) -> u64 {
+  let config = core_config();
+  if user.admin {
+    config.admin_rate_limit_per_minute
+  } else if matches!(user.config, UserConfig::Service { .. }) {
+    config.service_user_rate_limit_per_minute
+  } else {
+    config.rate_limit_per_minute
+  }
+}
+
+/// Takes a request from the user's bucket.
+/// Errors with the time to wait before retrying
+/// if the user is over their limit.
+pub fn check(user: &User) -> Result<(), Duration> {
+  let limit = limit_per_minute(user);
+  if limit == 0 {
+    return Ok(());
+  }
+  let capacity = limit as f64;
+  let per_sec = capacity / 60.0;
+  let now = Instant::now();
+  let mut buckets = buckets().lock().unwrap();
+  let bucket =
+    buckets.entry(user.id.clone()).or_insert(TokenBucket {
+      tokens: capacity,
+      updated_at: now,
+    });
+  let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+  bucket.tokens = (bucket.tokens + elapsed * per_sec).min(capacity);
+  bucket.updated_at = now;
+  if bucket.tokens >= 1.0 {
+    bucket.tokens -= 1.0;
+    Ok(())
+  } else {
+    Err(Duration::from_secs_f64((1.0 - bucket.tokens) / per_sec))
+  }
+}
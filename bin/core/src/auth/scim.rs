@@ -0,0 +1,471 @@
+//! SCIM 2.0 user provisioning, so an identity provider
+//! can manage the OIDC users of Komodo.
+//!
+//! SCIM `displayName` maps to the Komodo `username`,
+//! `active` to `enabled`, and `externalId` to the OIDC subject
+//! used to match the user on login.
+
+use std::str::FromStr;
+
+use anyhow::Context;
+use axum::{
+  Json, Router,
+  extract::{Path, Query, Request},
+  http::{HeaderMap, StatusCode, header::CONTENT_TYPE},
+  middleware::{self, Next},
+  response::{IntoResponse, Response},
+  routing::get,
+};
+use database::mungos::{
+  find::find_collect,
+  mongodb::{
+    bson::{Document, doc, oid::ObjectId},
+    options::FindOptions,
+  },
+};
+use komodo_client::entities::{
+  komodo_timestamp,
+  user::{User, UserConfig},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+  config::core_config, listener::constant_time_eq, state::db_client,
+};
+
+const SCIM_CONTENT_TYPE: &str = "application/scim+json";
+const USER_SCHEMA: &str =
+  "urn:ietf:params:scim:schemas:core:2.0:User";
+const LIST_RESPONSE_SCHEMA: &str =
+  "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+const ERROR_SCHEMA: &str =
+  "urn:ietf:params:scim:api:messages:2.0:Error";
+
+pub fn router() -> Router {
+  Router::new()
+    .route("/Users", get(list_users).post(create_user))
+    .route(
+      "/Users/{id}",
+      get(get_user).put(replace_user).delete(delete_user),
+    )
+    .layer(middleware::from_fn(authenticate))
+}
+
+async fn authenticate(
+  headers: HeaderMap,
+  req: Request,
+  next: Next,
+) -> Result<Response, ScimError> {
+  let api_key = &core_config().scim_api_key;
+  let authorized = !api_key.is_empty()
+    && headers
+      .get("authorization")
+      .and_then(|value| value.to_str().ok())
+      .and_then(|value| value.strip_prefix("Bearer "))
+      .is_some_and(|token| {
+        constant_time_eq(token.trim().as_bytes(), api_key.as_bytes())
+      });
+  if !authorized {
+    return Err(ScimError::new(
+      StatusCode::UNAUTHORIZED,
+      "Invalid SCIM api key",
+    ));
+  }
+  Ok(next.run(req).await)
+}
+
+struct ScimError {
+  status: StatusCode,
+  scim_type: Option<&'static str>,
+  detail: String,
+}
+
+impl ScimError {
+  fn new(status: StatusCode, detail: impl Into<String>) -> ScimError {
+    ScimError {
+      status,
+      scim_type: None,
+      detail: detail.into(),
+    }
+  }
+
+  fn not_found() -> ScimError {
+    ScimError::new(StatusCode::NOT_FOUND, "User not found")
+  }
+
+  fn uniqueness(detail: impl Into<String>) -> ScimError {
+    ScimError {
+      status: StatusCode::CONFLICT,
+      scim_type: Some("uniqueness"),
+      detail: detail.into(),
+    }
+  }
+}
+
+impl From<anyhow::Error> for ScimError {
+  fn from(e: anyhow::Error) -> ScimError {
+    ScimError::new(
+      StatusCode::INTERNAL_SERVER_ERROR,
+      format!("{e:#}"),
+    )
+  }
+}
+
+impl IntoResponse for ScimError {
+  fn into_response(self) -> Response {
+    let mut body = json!({
+      "schemas": [ERROR_SCHEMA],
+      "status": self.status.as_u16().to_string(),
+      "detail": self.detail,
+    });
+    if let Some(scim_type) = self.scim_type {
+      body["scimType"] = scim_type.into();
+    }
+    scim_response(self.status, body)
+  }
+}
+
+fn scim_response(
+  status: StatusCode,
+  body: impl Serialize,
+) -> Response {
+  (status, [(CONTENT_TYPE, SCIM_CONTENT_TYPE)], Json(body))
+    .into_response()
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScimUser {
+  schemas: [&'static str; 1],
+  id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  external_id: Option<String>,
+  user_name: String,
+  display_name: String,
+  active: bool,
+  meta: ScimMeta,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScimMeta {
+  resource_type: &'static str,
+  location: String,
+}
+
+impl From<User> for ScimUser {
+  fn from(user: User) -> ScimUser {
+    let external_id = match user.config {
+      UserConfig::Oidc { user_id, .. } => Some(user_id),
+      _ => None,
+    };
+    ScimUser {
+      schemas: [USER_SCHEMA],
+      external_id,
+      user_name: user.username.clone(),
+      display_name: user.username,
+      active: user.enabled,
+      meta: ScimMeta {
+        resource_type: "User",
+        location: format!(
+          "{}/scim/v2/Users/{}",
+          core_config().host,
+          user.id
+        ),
+      },
+      id: user.id,
+    }
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ScimUserBody {
+  #[serde(default)]
+  user_name: String,
+  #[serde(default)]
+  display_name: String,
+  external_id: Option<String>,
+  #[serde(default = "default_active")]
+  active: bool,
+}
+
+fn default_active() -> bool {
+  true
+}
+
+impl ScimUserBody {
+  /// Uses `displayName`, falling back to `userName`.
+  fn username(&self) -> Result<&str, ScimError> {
+    [&self.display_name, &self.user_name]
+      .into_iter()
+      .map(|name| name.trim())
+      .find(|name| !name.is_empty())
+      .ok_or_else(|| {
+        ScimError::new(
+          StatusCode::BAD_REQUEST,
+          "Must provide displayName or userName",
+        )
+      })
+  }
+
+  /// Uses `externalId`, falling back to `userName`.
+  fn external_id(&self) -> &str {
+    self
+      .external_id
+      .as_deref()
+      .map(str::trim)
+      .filter(|id| !id.is_empty())
+      .unwrap_or(self.user_name.trim())
+  }
+}
+
+/// SCIM only manages the users of the configured OIDC provider.
+fn oidc_users_filter() -> Document {
+  doc! {
+    "config.type": "Oidc",
+    "config.data.provider": &core_config().oidc_provider,
+  }
+}
+
+fn user_filter(id: &str) -> Result<Document, ScimError> {
+  let Ok(id) = ObjectId::from_str(id) else {
+    return Err(ScimError::not_found());
+  };
+  let mut filter = oidc_users_filter();
+  filter.insert("_id", id);
+  Ok(filter)
+}
+
+async fn find_user(id: &str) -> Result<User, ScimError> {
+  db_client()
+    .users
+    .find_one(user_filter(id)?)
+    .await
+    .context("Failed to query users collection")?
+    .ok_or_else(ScimError::not_found)
+}
+
+async fn ensure_username_available(
+  username: &str,
+  id: Option<&str>,
+) -> Result<(), ScimError> {
+  let existing = db_client()
+    .users
+    .find_one(doc! { "username": username })
+    .await
+    .context("Failed to query users collection")?;
+  match existing {
+    Some(user) if Some(user.id.as_str()) != id => {
+      Err(ScimError::uniqueness(format!(
+        "Username '{username}' is already taken"
+      )))
+    }
+    _ => Ok(()),
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListQuery {
+  filter: Option<String>,
+  start_index: Option<u64>,
+  count: Option<i64>,
+}
+
+/// Supports the `eq` filters identity providers use
+/// to look up a user before provisioning,
+/// eg `userName eq "alice"`.
+fn parse_filter(filter: &str) -> Result<Document, ScimError> {
+  let invalid = || ScimError {
+    status: StatusCode::BAD_REQUEST,
+    scim_type: Some("invalidFilter"),
+    detail: format!("Unsupported filter: {filter}"),
+  };
+  let mut parts = filter.trim().splitn(3, ' ');
+  let (Some(attribute), Some(op), Some(value)) =
+    (parts.next(), parts.next(), parts.next())
+  else {
+    return Err(invalid());
+  };
+  if !op.eq_ignore_ascii_case("eq") {
+    return Err(invalid());
+  }
+  let value = value.trim().trim_matches('"');
+  let field = match attribute.to_ascii_lowercase().as_str() {
+    "username" | "displayname" => "username",
+    "externalid" => "config.data.user_id",
+    _ => return Err(invalid()),
+  };
+  Ok(doc! { field: value })
+}
+
+async fn list_users(
+  Query(query): Query<ListQuery>,
+) -> Result<Response, ScimError> {
+  let mut filter = oidc_users_filter();
+  if let Some(extra) = query.filter.as_deref() {
+    filter.extend(parse_filter(extra)?);
+  }
+  let start_index = query.start_index.unwrap_or(1).max(1);
+  let users = &db_client().users;
+  let total = users
+    .count_documents(filter.clone())
+    .await
+    .context("Failed to count users")?;
+  // Per the spec, a count of 0 (or less) only returns totalResults.
+  let users = match query.count.map(|count| count.max(0)) {
+    Some(0) => Vec::new(),
+    count => {
+      let mut options = FindOptions::builder()
+        .sort(doc! { "username": 1 })
+        .skip(start_index - 1)
+        .build();
+      options.limit = count;
+      find_collect(users, filter, options)
+        .await
+        .context("Failed to query users collection")?
+        .into_iter()
+        .map(ScimUser::from)
+        .collect::<Vec<_>>()
+    }
+  };
+  Ok(scim_response(
+    StatusCode::OK,
+    json!({
+      "schemas": [LIST_RESPONSE_SCHEMA],
+      "totalResults": total,
+      "startIndex": start_index,
+      "itemsPerPage": users.len(),
+      "Resources": users,
+    }),
+  ))
+}
+
+async fn get_user(
+  Path(id): Path<String>,
+) -> Result<Response, ScimError> {
+  let user = find_user(&id).await?;
+  Ok(scim_response(StatusCode::OK, ScimUser::from(user)))
+}
+
+async fn create_user(
+  Json(body): Json<ScimUserBody>,
+) -> Result<Response, ScimError> {
+  let config = core_config();
+  let username = body.username()?;
+  let external_id = body.external_id();
+  if external_id.is_empty() {
+    return Err(ScimError::new(
+      StatusCode::BAD_REQUEST,
+      "Must provide externalId or userName",
+    ));
+  }
+  ensure_username_available(username, None).await?;
+  let db = db_client();
+  let mut filter = oidc_users_filter();
+  filter.insert("config.data.user_id", external_id);
+  if db
+    .users
+    .find_one(filter)
+    .await
+    .context("Failed to query users collection")?
+    .is_some()
+  {
+    return Err(ScimError::uniqueness(format!(
+      "User with externalId '{external_id}' already exists"
+    )));
+  }
+  let mut user = User {
+    id: Default::default(),
+    username: username.to_string(),
+    enabled: body.active,
+    admin: false,
+    super_admin: false,
+    create_server_permissions: false,
+    create_build_permissions: false,
+    updated_at: komodo_timestamp(),
+    last_update_view: 0,
+    recents: Default::default(),
+    all: Default::default(),
+    config: UserConfig::Oidc {
+      provider: config.oidc_provider.clone(),
+      user_id: external_id.to_string(),
+    },
+  };
+  user.id = db
+    .users
+    .insert_one(&user)
+    .await
+    .context("Failed to create user on database")?
+    .inserted_id
+    .as_object_id()
+    .context("inserted_id is not ObjectId")?
+    .to_string();
+  info!("SCIM created user {}", user.username);
+  Ok(scim_response(StatusCode::CREATED, ScimUser::from(user)))
+}
+
+async fn replace_user(
+  Path(id): Path<String>,
+  Json(body): Json<ScimUserBody>,
+) -> Result<Response, ScimError> {
+  let user = find_user(&id).await?;
+  let username = body.username()?;
+  if user.super_admin && !body.active {
+    return Err(ScimError::new(
+      StatusCode::FORBIDDEN,
+      "Cannot disable a super admin user",
+    ));
+  }
+  ensure_username_available(username, Some(&user.id)).await?;
+  let mut update = doc! {
+    "username": username,
+    "enabled": body.active,
+    "updated_at": komodo_timestamp(),
+  };
+  if body.external_id.is_some() {
+    update.insert("config.data.user_id", body.external_id());
+  }
+  let db = db_client();
+  db.users
+    .update_one(user_filter(&id)?, doc! { "$set": update })
+    .await
+    .context("Failed to update user on database")?;
+  let user = find_user(&id).await?;
+  Ok(scim_response(StatusCode::OK, ScimUser::from(user)))
+}
+
+async fn delete_user(
+  Path(id): Path<String>,
+) -> Result<Response, ScimError> {
+  let user = find_user(&id).await?;
+  if user.super_admin {
+    return Err(ScimError::new(
+      StatusCode::FORBIDDEN,
+      "Cannot delete a super admin user",
+    ));
+  }
+  let db = db_client();
+  db.users
+    .delete_one(user_filter(&id)?)
+    .await
+    .context("Failed to delete user from database")?;
+  // Also remove user id from all user groups
+  if let Err(e) = db
+    .user_groups
+    .update_many(doc! {}, doc! { "$pull": { "users": &user.id } })
+    .await
+  {
+    warn!("Failed to remove deleted user from user groups | {e:?}");
+  };
+  // Also revoke all of the user's sessions
+  if let Err(e) =
+    db.sessions.delete_many(doc! { "user_id": &user.id }).await
+  {
+    warn!("Failed to delete sessions for deleted user | {e:?}");
+  };
+  info!("SCIM deleted user {}", user.username);
+  Ok(StatusCode::NO_CONTENT.into_response())
+}
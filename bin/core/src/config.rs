@@ -10,8 +10,9 @@ use komodo_client::entities::{
   config::{
     DatabaseConfig,
     core::{
-      AwsCredentials, CoreConfig, Env, GithubWebhookAppConfig,
-      GithubWebhookAppInstallationConfig, OauthCredentials,
+      AwsCredentials, CoreConfig, Env, GithubOauthCredentials,
+      GithubWebhookAppConfig, GithubWebhookAppInstallationConfig,
+      OauthCredentials, OidcGroupMap,
     },
   },
   logger::LogConfig,
@@ -85,6 +86,31 @@ pub fn core_config() -> &'static CoreConfig {
       }
     };
 
+    let oidc_group_map = match (
+      env.komodo_oidc_group_map_claim_values,
+      env.komodo_oidc_group_map_user_group_names
+    ) {
+      (Some(claim_values), Some(user_group_names)) => {
+        if claim_values.len() != user_group_names.len() {
+          panic!("KOMODO_OIDC_GROUP_MAP_CLAIM_VALUES length and KOMODO_OIDC_GROUP_MAP_USER_GROUP_NAMES length mismatch. Got {claim_values:?} and {user_group_names:?}")
+        }
+        claim_values
+          .into_iter()
+          .zip(user_group_names)
+          .map(|(claim_value, user_group_name)| OidcGroupMap {
+            claim_value,
+            user_group_name
+          })
+          .collect()
+      },
+      (Some(_), None) | (None, Some(_)) => {
+        panic!("Got only one of KOMODO_OIDC_GROUP_MAP_CLAIM_VALUES or KOMODO_OIDC_GROUP_MAP_USER_GROUP_NAMES, both MUST be provided");
+      }
+      (None, None) => {
+        config.oidc_group_map
+      }
+    };
+
     // recreating CoreConfig here makes sure apply all env overrides applied.
     CoreConfig {
       // Secret things overridden with file
@@ -108,6 +134,18 @@ pub fn core_config() -> &'static CoreConfig {
         db_name: env
           .komodo_database_db_name
           .unwrap_or(config.database.db_name),
+        max_pool_size: env
+          .komodo_database_max_pool_size
+          .or(config.database.max_pool_size),
+        min_pool_size: env
+          .komodo_database_min_pool_size
+          .or(config.database.min_pool_size),
+        connect_timeout_ms: env
+          .komodo_database_connect_timeout_ms
+          .or(config.database.connect_timeout_ms),
+        server_selection_timeout_ms: env
+          .komodo_database_server_selection_timeout_ms
+          .or(config.database.server_selection_timeout_ms),
       },
       init_admin_username: maybe_read_item_from_file(
         env.komodo_init_admin_username_file,
@@ -131,6 +169,18 @@ pub fn core_config() -> &'static CoreConfig {
       oidc_additional_audiences: maybe_read_list_from_file(env.komodo_oidc_additional_audiences_file,env
         .komodo_oidc_additional_audiences)
         .unwrap_or(config.oidc_additional_audiences),
+      oidc_additional_scopes: env.komodo_oidc_additional_scopes
+        .unwrap_or(config.oidc_additional_scopes),
+      oidc_admin_groups: env.komodo_oidc_admin_groups
+        .unwrap_or(config.oidc_admin_groups),
+      oidc_groups_claim: env.komodo_oidc_groups_claim
+        .unwrap_or(config.oidc_groups_claim),
+      oidc_group_map,
+      scim_enabled: env.komodo_scim_enabled
+        .unwrap_or(config.scim_enabled),
+      scim_api_key: maybe_read_item_from_file(env.komodo_scim_api_key_file, env
+        .komodo_scim_api_key)
+        .unwrap_or(config.scim_api_key),
       google_oauth: OauthCredentials {
         enabled: env
           .komodo_google_oauth_enabled
@@ -142,7 +192,7 @@ pub fn core_config() -> &'static CoreConfig {
           .komodo_google_oauth_secret)
           .unwrap_or(config.google_oauth.secret),
       },
-      github_oauth: OauthCredentials {
+      github_oauth: GithubOauthCredentials {
         enabled: env
           .komodo_github_oauth_enabled
           .unwrap_or(config.github_oauth.enabled),
@@ -152,6 +202,8 @@ pub fn core_config() -> &'static CoreConfig {
         secret: maybe_read_item_from_file(env.komodo_github_oauth_secret_file,env
           .komodo_github_oauth_secret)
           .unwrap_or(config.github_oauth.secret),
+        allowed_orgs: env.komodo_github_oauth_allowed_orgs
+          .unwrap_or(config.github_oauth.allowed_orgs),
       },
       aws: AwsCredentials {
         access_key_id: maybe_read_item_from_file(env.komodo_aws_access_key_id_file, env
@@ -176,6 +228,9 @@ pub fn core_config() -> &'static CoreConfig {
       host: env.komodo_host.unwrap_or(config.host),
       port: env.komodo_port.unwrap_or(config.port),
       bind_ip: env.komodo_bind_ip.unwrap_or(config.bind_ip),
+      shutdown_grace_period: env
+        .komodo_shutdown_grace_period
+        .unwrap_or(config.shutdown_grace_period),
       timezone: env.komodo_timezone.unwrap_or(config.timezone),
       first_server: env.komodo_first_server.or(config.first_server),
       first_server_name: env.komodo_first_server_name.unwrap_or(config.first_server_name),
@@ -192,18 +247,33 @@ pub fn core_config() -> &'static CoreConfig {
       action_directory: env
         .komodo_action_directory
         .unwrap_or(config.action_directory),
+      terminal_recording_directory: env
+        .komodo_terminal_recording_directory
+        .unwrap_or(config.terminal_recording_directory),
       resource_poll_interval: env
         .komodo_resource_poll_interval
         .unwrap_or(config.resource_poll_interval),
       monitoring_interval: env
         .komodo_monitoring_interval
         .unwrap_or(config.monitoring_interval),
+      prune_interval: env
+        .komodo_prune_interval
+        .unwrap_or(config.prune_interval),
       keep_stats_for_days: env
         .komodo_keep_stats_for_days
         .unwrap_or(config.keep_stats_for_days),
       keep_alerts_for_days: env
         .komodo_keep_alerts_for_days
         .unwrap_or(config.keep_alerts_for_days),
+      keep_terminal_recordings_for_days: env
+        .komodo_keep_terminal_recordings_for_days
+        .unwrap_or(config.keep_terminal_recordings_for_days),
+      keep_updates_for_days: env
+        .komodo_keep_updates_for_days
+        .unwrap_or(config.keep_updates_for_days),
+      keep_audit_log_for_days: env
+        .komodo_keep_audit_log_for_days
+        .unwrap_or(config.keep_audit_log_for_days),
       webhook_base_url: env
         .komodo_webhook_base_url
         .unwrap_or(config.webhook_base_url),
@@ -213,6 +283,15 @@ pub fn core_config() -> &'static CoreConfig {
       ui_write_disabled: env
         .komodo_ui_write_disabled
         .unwrap_or(config.ui_write_disabled),
+      read_only: env
+        .komodo_read_only
+        .unwrap_or(config.read_only),
+      record_terminals: env
+        .komodo_record_terminals
+        .unwrap_or(config.record_terminals),
+      max_terminals_per_user: env
+        .komodo_max_terminals_per_user
+        .unwrap_or(config.max_terminals_per_user),
       disable_confirm_dialog: env.komodo_disable_confirm_dialog
         .unwrap_or(config.disable_confirm_dialog),
       disable_websocket_reconnect: env.komodo_disable_websocket_reconnect
@@ -231,6 +310,18 @@ pub fn core_config() -> &'static CoreConfig {
         .unwrap_or(config.lock_login_credentials_for),
       local_auth: env.komodo_local_auth
         .unwrap_or(config.local_auth),
+      login_max_failed_attempts: env.komodo_login_max_failed_attempts
+        .unwrap_or(config.login_max_failed_attempts),
+      login_failed_attempts_window: env.komodo_login_failed_attempts_window
+        .unwrap_or(config.login_failed_attempts_window),
+      login_lockout_duration: env.komodo_login_lockout_duration
+        .unwrap_or(config.login_lockout_duration),
+      rate_limit_per_minute: env.komodo_rate_limit_per_minute
+        .unwrap_or(config.rate_limit_per_minute),
+      admin_rate_limit_per_minute: env.komodo_admin_rate_limit_per_minute
+        .unwrap_or(config.admin_rate_limit_per_minute),
+      service_user_rate_limit_per_minute: env.komodo_service_user_rate_limit_per_minute
+        .unwrap_or(config.service_user_rate_limit_per_minute),
       logging: LogConfig {
         level: env
           .komodo_logging_level
@@ -255,8 +346,17 @@ pub fn core_config() -> &'static CoreConfig {
       ssl_enabled: env.komodo_ssl_enabled.unwrap_or(config.ssl_enabled),
       ssl_key_file: env.komodo_ssl_key_file.unwrap_or(config.ssl_key_file),
       ssl_cert_file: env.komodo_ssl_cert_file.unwrap_or(config.ssl_cert_file),
+      cors_allowed_origins: env.komodo_cors_allowed_origins
+        .unwrap_or(config.cors_allowed_origins),
+      max_request_body_mb: env.komodo_max_request_body_mb
+        .unwrap_or(config.max_request_body_mb),
+      trust_forwarded_for: env.komodo_trust_forwarded_for
+        .unwrap_or(config.trust_forwarded_for),
+      strict_interpolation: env.komodo_strict_interpolation
+        .unwrap_or(config.strict_interpolation),
 
       // These can't be overridden on env
+      backup_remote_targets: config.backup_remote_targets,
       secrets: config.secrets,
       git_providers: config.git_providers,
       docker_registries: config.docker_registries,
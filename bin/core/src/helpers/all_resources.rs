@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 
 use komodo_client::entities::{
-  action::Action, alerter::Alerter, build::Build, builder::Builder,
-  deployment::Deployment, procedure::Procedure, repo::Repo,
-  server::Server, stack::Stack, sync::ResourceSync,
+  ResourceTarget, action::Action, alerter::Alerter, build::Build,
+  builder::Builder, deployment::Deployment, procedure::Procedure,
+  repo::Repo, resource::Resource, server::Server,
+  server_group::ServerGroup, stack::Stack, sync::ResourceSync,
 };
+use serde::Serialize;
 
 #[derive(Debug, Default)]
 pub struct AllResourcesById {
@@ -18,6 +20,7 @@ pub struct AllResourcesById {
   pub builders: HashMap<String, Builder>,
   pub alerters: HashMap<String, Alerter>,
   pub syncs: HashMap<String, ResourceSync>,
+  pub server_groups: HashMap<String, ServerGroup>,
 }
 
 impl AllResourcesById {
@@ -68,6 +71,103 @@ impl AllResourcesById {
         id_to_tags, match_tags,
       )
       .await?,
+      server_groups: crate::resource::get_id_to_resource_map::<
+        ServerGroup,
+      >(id_to_tags, match_tags)
+      .await?,
     })
   }
+
+  /// Find the resources which interpolate the variable
+  /// into their config using `[[NAME]]`.
+  /// Returns the targets along with the resource names.
+  pub fn variable_references(
+    &self,
+    name: &str,
+  ) -> Vec<(ResourceTarget, String)> {
+    let token = format!("[[{name}]]");
+    self
+      .serialized_configs()
+      .into_iter()
+      .filter(|(_, _, config)| config.contains(&token))
+      .map(|(target, name, _)| (target, name.to_string()))
+      .collect()
+  }
+
+  /// Find the resources which reference each of the variables,
+  /// serializing each resource config only once.
+  pub fn variable_references_by_name<'a>(
+    &self,
+    names: impl IntoIterator<Item = &'a str>,
+  ) -> HashMap<String, Vec<ResourceTarget>> {
+    let configs = self.serialized_configs();
+    names
+      .into_iter()
+      .map(|name| {
+        let token = format!("[[{name}]]");
+        let targets = configs
+          .iter()
+          .filter(|(_, _, config)| config.contains(&token))
+          .map(|(target, _, _)| target.clone())
+          .collect();
+        (name.to_string(), targets)
+      })
+      .collect()
+  }
+
+  /// All resource configs serialized to json,
+  /// along with the resource target and name.
+  fn serialized_configs(
+    &self,
+  ) -> Vec<(ResourceTarget, &str, String)> {
+    let mut configs = Vec::new();
+    push_configs(&self.servers, ResourceTarget::Server, &mut configs);
+    push_configs(
+      &self.deployments,
+      ResourceTarget::Deployment,
+      &mut configs,
+    );
+    push_configs(&self.stacks, ResourceTarget::Stack, &mut configs);
+    push_configs(&self.builds, ResourceTarget::Build, &mut configs);
+    push_configs(&self.repos, ResourceTarget::Repo, &mut configs);
+    push_configs(
+      &self.procedures,
+      ResourceTarget::Procedure,
+      &mut configs,
+    );
+    push_configs(&self.actions, ResourceTarget::Action, &mut configs);
+    push_configs(
+      &self.builders,
+      ResourceTarget::Builder,
+      &mut configs,
+    );
+    push_configs(
+      &self.alerters,
+      ResourceTarget::Alerter,
+      &mut configs,
+    );
+    push_configs(
+      &self.syncs,
+      ResourceTarget::ResourceSync,
+      &mut configs,
+    );
+    configs
+  }
+}
+
+fn push_configs<'a, Config: Serialize + Default, Info: Default>(
+  resources: &'a HashMap<String, Resource<Config, Info>>,
+  target: fn(String) -> ResourceTarget,
+  configs: &mut Vec<(ResourceTarget, &'a str, String)>,
+) {
+  for resource in resources.values() {
+    let Ok(config) = serde_json::to_string(&resource.config) else {
+      continue;
+    };
+    configs.push((
+      target(resource.id.clone()),
+      resource.name.as_str(),
+      config,
+    ));
+  }
 }
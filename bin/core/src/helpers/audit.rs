@@ -0,0 +1,92 @@
+use std::cell::RefCell;
+
+use komodo_client::entities::{
+  ResourceTarget, audit::AuditEntry, komodo_timestamp, user::User,
+};
+
+use crate::state::db_client;
+
+tokio::task_local! {
+  /// The target of the first Update made by the current
+  /// `/write` request, recorded on its audit entry.
+  static AUDIT_TARGET: RefCell<Option<ResourceTarget>>;
+}
+
+/// Runs the request, returning the target of the first Update it made.
+pub async fn with_audit_target<T>(
+  request: impl Future<Output = T>,
+) -> (T, Option<ResourceTarget>) {
+  AUDIT_TARGET
+    .scope(RefCell::new(None), async {
+      let res = request.await;
+      (res, AUDIT_TARGET.with(RefCell::take))
+    })
+    .await
+}
+
+/// Records the target on the audit entry of the current request,
+/// if it doesn't have one yet.
+pub fn set_audit_target(target: &ResourceTarget) {
+  let _ = AUDIT_TARGET.try_with(|current| {
+    current.borrow_mut().get_or_insert_with(|| target.clone());
+  });
+}
+
+/// Starts an audit entry for a request made by the user.
+/// The caller fills in the outcome before [record_audit_entry].
+pub fn audit_entry(
+  user: &User,
+  request_type: String,
+  ip: String,
+) -> AuditEntry {
+  AuditEntry {
+    id: Default::default(),
+    user_id: user.id.clone(),
+    username: user.username.clone(),
+    request_type,
+    resource_target: None,
+    timestamp: komodo_timestamp(),
+    ip: Some(ip),
+    success: false,
+  }
+}
+
+/// Inserts the entry in the background,
+/// so the request isn't held up by the audit log.
+pub fn record_audit_entry(entry: AuditEntry) {
+  tokio::spawn(async move {
+    if let Err(e) = db_client().audit_log.insert_one(&entry).await {
+      warn!(
+        "Failed to record audit entry for {} | {e:#}",
+        entry.request_type
+      );
+    }
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn audit_target_is_first_update_target() {
+    let ((), target) = with_audit_target(async {
+      set_audit_target(&ResourceTarget::Stack(String::from("first")));
+      set_audit_target(&ResourceTarget::Stack(String::from(
+        "second",
+      )));
+    })
+    .await;
+    assert_eq!(
+      target,
+      Some(ResourceTarget::Stack(String::from("first")))
+    );
+  }
+
+  #[tokio::test]
+  async fn audit_target_outside_request_is_ignored() {
+    set_audit_target(&ResourceTarget::Stack(String::from("stack")));
+    let ((), target) = with_audit_target(async {}).await;
+    assert_eq!(target, None);
+  }
+}
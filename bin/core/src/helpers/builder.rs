@@ -3,7 +3,7 @@ use std::time::Duration;
 use anyhow::{Context, anyhow};
 use formatting::muted;
 use komodo_client::entities::{
-  Version,
+  SystemCommand, Version,
   builder::{AwsBuilderConfig, Builder, BuilderConfig},
   komodo_timestamp,
   server::Server,
@@ -76,6 +76,55 @@ pub async fn get_builder_periphery(
   }
 }
 
+/// Checks `docker buildx` is available on the builder host.
+/// Aws builders are launched on demand, so can't be checked ahead of the build.
+pub async fn check_builder_buildx(
+  builder: &Builder,
+) -> anyhow::Result<()> {
+  let periphery = match &builder.config {
+    BuilderConfig::Url(config) => {
+      if config.address.is_empty() {
+        return Err(anyhow!(
+          "Builder has not yet configured an address"
+        ));
+      }
+      PeripheryClient::new(
+        &config.address,
+        if config.passkey.is_empty() {
+          &core_config().passkey
+        } else {
+          &config.passkey
+        },
+        Duration::from_secs(3),
+      )
+    }
+    BuilderConfig::Server(config) => {
+      if config.server_id.is_empty() {
+        return Err(anyhow!("Builder has not configured a server"));
+      }
+      let server = resource::get::<Server>(&config.server_id).await?;
+      periphery_client(&server)?
+    }
+    BuilderConfig::Aws(_) => return Ok(()),
+  };
+  let log = periphery
+    .request(api::RunCommand {
+      command: SystemCommand {
+        path: String::new(),
+        command: String::from("docker buildx version"),
+      },
+    })
+    .await
+    .context("Failed to check buildx on builder")?;
+  if log.success {
+    Ok(())
+  } else {
+    Err(anyhow!("{}", log.combined()).context(
+      "Builder does not have buildx set up, which is required for multi-platform builds",
+    ))
+  }
+}
+
 #[instrument(skip_all, fields(resource_name, update_id = update.id))]
 async fn get_aws_builder(
   resource_name: &str,
@@ -1,9 +1,10 @@
-use std::{fmt::Write, time::Duration};
+use std::{collections::HashMap, fmt::Write, time::Duration};
 
 use anyhow::{Context, anyhow};
 use database::mongo_indexed::Document;
 use database::mungos::mongodb::bson::{Bson, doc};
 use indexmap::IndexSet;
+use interpolate::Interpolator;
 use komodo_client::entities::{
   ResourceTarget,
   build::Build,
@@ -22,6 +23,7 @@ use crate::{config::core_config, state::db_client};
 
 pub mod action_state;
 pub mod all_resources;
+pub mod audit;
 pub mod builder;
 pub mod cache;
 pub mod channel;
@@ -192,7 +194,7 @@ pub fn periphery_client(
     return Err(anyhow!("server not enabled"));
   }
 
-  let client = PeripheryClient::new(
+  let mut client = PeripheryClient::new(
     &server.config.address,
     if server.config.passkey.is_empty() {
       &core_config().passkey
@@ -202,9 +204,34 @@ pub fn periphery_client(
     Duration::from_secs(server.config.timeout_seconds as u64),
   );
 
+  if !server.config.client_cert_path.is_empty() {
+    client = client.with_client_cert(&server.config.client_cert_path);
+  }
+
   Ok(client)
 }
 
+/// The interpolator for a deploy or build on the Periphery.
+/// With `strict_interpolation`, it errors on unresolved tokens,
+/// except for the secrets configured on the Periphery.
+pub async fn periphery_interpolator<'a>(
+  variables: &'a HashMap<String, String>,
+  secrets: &'a HashMap<String, String>,
+  periphery: &PeripheryClient,
+) -> anyhow::Result<Interpolator<'a>> {
+  if !core_config().strict_interpolation {
+    return Ok(Interpolator::new(Some(variables), secrets));
+  }
+  let periphery_secrets = periphery
+    .request(periphery_client::api::ListSecrets {})
+    .await
+    .context("Failed to get Periphery secrets")?;
+  Ok(
+    Interpolator::new_strict(Some(variables), secrets)
+      .with_periphery_secrets(periphery_secrets),
+  )
+}
+
 #[instrument]
 pub async fn create_permission<T>(
   user: &User,
@@ -7,11 +7,12 @@ use futures::future::join_all;
 use komodo_client::{
   api::execute::*,
   entities::{
+    SearchCombinator,
     action::Action,
     build::Build,
     deployment::Deployment,
     permission::PermissionLevel,
-    procedure::Procedure,
+    procedure::{Procedure, StageRunCondition},
     repo::Repo,
     stack::Stack,
     update::{Log, Update},
@@ -37,10 +38,26 @@ pub async fn execute_procedure(
   procedure: &Procedure,
   update: &Mutex<Update>,
 ) -> anyhow::Result<()> {
+  // The first stage failure. Later stages are run
+  // or skipped based on their run condition.
+  let mut failure: Option<anyhow::Error> = None;
   for stage in &procedure.config.stages {
     if !stage.enabled {
       continue;
     }
+    if !should_run_stage(stage.run_condition, failure.is_some()) {
+      add_line_to_update(
+        update,
+        &format!(
+          "{}: Skipping stage '{}' | Run condition: {}",
+          muted("INFO"),
+          bold(&stage.name),
+          stage.run_condition,
+        ),
+      )
+      .await;
+      continue;
+    }
     add_line_to_update(
       update,
       &format!(
@@ -51,7 +68,7 @@ pub async fn execute_procedure(
     )
     .await;
     let timer = Instant::now();
-    execute_stage(
+    let execution = execute_stage(
       stage
         .executions
         .iter()
@@ -61,6 +78,10 @@ pub async fn execute_procedure(
       &procedure.id,
       &procedure.name,
       update,
+    );
+    let res = run_with_timeout(
+      execution,
+      stage.timeout_seconds.map(Duration::from_secs),
     )
     .await
     .with_context(|| {
@@ -69,7 +90,20 @@ pub async fn execute_procedure(
         bold(&stage.name),
         timer.elapsed(),
       )
-    })?;
+    });
+    if let Err(e) = res {
+      if failure.is_none() {
+        failure = Some(e);
+      } else {
+        // Already failed, just log this one.
+        add_line_to_update(
+          update,
+          &format!("{}: {e:#}", colored("ERROR", Color::Red)),
+        )
+        .await;
+      }
+      continue;
+    }
     add_line_to_update(
       update,
       &format!(
@@ -83,7 +117,39 @@ pub async fn execute_procedure(
     .await;
   }
 
-  Ok(())
+  match failure {
+    Some(e) => Err(e),
+    None => Ok(()),
+  }
+}
+
+/// Whether a stage with the given run condition should run,
+/// given whether a previous stage has failed.
+fn should_run_stage(
+  condition: StageRunCondition,
+  failed: bool,
+) -> bool {
+  match condition {
+    StageRunCondition::Always => true,
+    StageRunCondition::OnSuccess => !failed,
+    StageRunCondition::OnFailure => failed,
+  }
+}
+
+/// Fails the stage execution if it
+/// doesn't finish within the timeout.
+async fn run_with_timeout(
+  execution: impl Future<Output = anyhow::Result<()>>,
+  timeout: Option<Duration>,
+) -> anyhow::Result<()> {
+  let Some(timeout) = timeout else {
+    return execution.await;
+  };
+  tokio::time::timeout(timeout, execution)
+    .await
+    .unwrap_or_else(|_| {
+      Err(anyhow!("Stage timed out after {timeout:?}"))
+    })
 }
 
 #[allow(dependency_on_unit_never_type_fallback)]
@@ -100,6 +166,7 @@ async fn execute_stage(
       Execution::BatchRunAction(exec) => {
         extend_batch_exection::<BatchRunAction>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -107,6 +174,7 @@ async fn execute_stage(
       Execution::BatchRunProcedure(exec) => {
         extend_batch_exection::<BatchRunProcedure>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -114,6 +182,7 @@ async fn execute_stage(
       Execution::BatchRunBuild(exec) => {
         extend_batch_exection::<BatchRunBuild>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -121,6 +190,7 @@ async fn execute_stage(
       Execution::BatchCloneRepo(exec) => {
         extend_batch_exection::<BatchCloneRepo>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -128,6 +198,7 @@ async fn execute_stage(
       Execution::BatchPullRepo(exec) => {
         extend_batch_exection::<BatchPullRepo>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -135,6 +206,7 @@ async fn execute_stage(
       Execution::BatchBuildRepo(exec) => {
         extend_batch_exection::<BatchBuildRepo>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -142,6 +214,7 @@ async fn execute_stage(
       Execution::BatchDeploy(exec) => {
         extend_batch_exection::<BatchDeploy>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -149,6 +222,7 @@ async fn execute_stage(
       Execution::BatchDestroyDeployment(exec) => {
         extend_batch_exection::<BatchDestroyDeployment>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -156,6 +230,7 @@ async fn execute_stage(
       Execution::BatchDeployStack(exec) => {
         extend_batch_exection::<BatchDeployStack>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -163,6 +238,7 @@ async fn execute_stage(
       Execution::BatchDeployStackIfChanged(exec) => {
         extend_batch_exection::<BatchDeployStackIfChanged>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -170,6 +246,7 @@ async fn execute_stage(
       Execution::BatchPullStack(exec) => {
         extend_batch_exection::<BatchPullStack>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -177,6 +254,7 @@ async fn execute_stage(
       Execution::BatchDestroyStack(exec) => {
         extend_batch_exection::<BatchDestroyStack>(
           &exec.pattern,
+          exec.combinator,
           &mut executions,
         )
         .await?;
@@ -907,6 +985,57 @@ async fn execute_execution(
       )
       .await?
     }
+    Execution::RunCommandOnGroup(req) => {
+      let req = ExecuteRequest::RunCommandOnGroup(req);
+      let update = init_execution_update(&req, &user).await?;
+      let ExecuteRequest::RunCommandOnGroup(req) = req else {
+        unreachable!()
+      };
+      let update_id = update.id.clone();
+      handle_resolve_result(
+        req
+          .resolve(&ExecuteArgs { user, update })
+          .await
+          .map_err(|e| e.error)
+          .context("Failed at RunCommandOnGroup"),
+        &update_id,
+      )
+      .await?
+    }
+    Execution::PruneImagesOnGroup(req) => {
+      let req = ExecuteRequest::PruneImagesOnGroup(req);
+      let update = init_execution_update(&req, &user).await?;
+      let ExecuteRequest::PruneImagesOnGroup(req) = req else {
+        unreachable!()
+      };
+      let update_id = update.id.clone();
+      handle_resolve_result(
+        req
+          .resolve(&ExecuteArgs { user, update })
+          .await
+          .map_err(|e| e.error)
+          .context("Failed at PruneImagesOnGroup"),
+        &update_id,
+      )
+      .await?
+    }
+    Execution::PruneSystemOnGroup(req) => {
+      let req = ExecuteRequest::PruneSystemOnGroup(req);
+      let update = init_execution_update(&req, &user).await?;
+      let ExecuteRequest::PruneSystemOnGroup(req) = req else {
+        unreachable!()
+      };
+      let update_id = update.id.clone();
+      handle_resolve_result(
+        req
+          .resolve(&ExecuteArgs { user, update })
+          .await
+          .map_err(|e| e.error)
+          .context("Failed at PruneSystemOnGroup"),
+        &update_id,
+      )
+      .await?
+    }
     Execution::RunSync(req) => {
       let req = ExecuteRequest::RunSync(req);
       let update = init_execution_update(&req, &user).await?;
@@ -1269,10 +1398,12 @@ async fn add_line_to_update(update: &Mutex<Update>, line: &str) {
 
 async fn extend_batch_exection<E: ExtendBatch>(
   pattern: &str,
+  combinator: Option<SearchCombinator>,
   executions: &mut Vec<Execution>,
 ) -> anyhow::Result<()> {
   let more = list_full_for_user_using_pattern::<E::Resource>(
     pattern,
+    combinator.unwrap_or_default(),
     Default::default(),
     procedure_user(),
     PermissionLevel::Read.into(),
@@ -1399,3 +1530,54 @@ impl ExtendBatch for BatchDestroyStack {
     })
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn on_success_stage_skipped_after_failure() {
+    assert!(should_run_stage(StageRunCondition::OnSuccess, false));
+    assert!(!should_run_stage(StageRunCondition::OnSuccess, true));
+  }
+
+  #[test]
+  fn on_failure_stage_runs_only_after_failure() {
+    assert!(!should_run_stage(StageRunCondition::OnFailure, false));
+    assert!(should_run_stage(StageRunCondition::OnFailure, true));
+  }
+
+  #[test]
+  fn always_stage_runs_after_failure() {
+    assert!(should_run_stage(StageRunCondition::Always, false));
+    assert!(should_run_stage(StageRunCondition::Always, true));
+  }
+
+  #[tokio::test]
+  async fn sleep_stage_fails_after_timeout() {
+    let execution = execute_execution(
+      Execution::Sleep(Sleep { duration_ms: 5_000 }),
+      "",
+      "",
+    );
+    let res =
+      run_with_timeout(execution, Some(Duration::from_millis(50)))
+        .await;
+    assert_eq!(
+      res.unwrap_err().to_string(),
+      "Stage timed out after 50ms"
+    );
+  }
+
+  #[tokio::test]
+  async fn sleep_stage_finishes_within_timeout() {
+    let execution = execute_execution(
+      Execution::Sleep(Sleep { duration_ms: 10 }),
+      "",
+      "",
+    );
+    run_with_timeout(execution, Some(Duration::from_secs(5)))
+      .await
+      .unwrap();
+  }
+}
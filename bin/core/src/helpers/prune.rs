@@ -11,11 +11,30 @@ use crate::{config::core_config, state::db_client};
 use super::periphery_client;
 
 pub fn spawn_prune_loop() {
+  let interval: Timelength = core_config()
+    .prune_interval
+    .try_into()
+    .expect("Invalid prune interval");
   tokio::spawn(async move {
     loop {
-      wait_until_timelength(Timelength::OneDay, 5000).await;
-      let (images_res, stats_res, alerts_res) =
-        tokio::join!(prune_images(), prune_stats(), prune_alerts());
+      wait_until_timelength(interval, 5000).await;
+      let (
+        images_res,
+        stats_res,
+        alerts_res,
+        recordings_res,
+        sessions_res,
+        updates_res,
+        audit_log_res,
+      ) = tokio::join!(
+        prune_images(),
+        prune_stats(),
+        prune_alerts(),
+        prune_terminal_recordings(),
+        prune_sessions(),
+        prune_updates(),
+        prune_audit_log()
+      );
       if let Err(e) = images_res {
         error!("error in pruning images | {e:#}");
       }
@@ -25,10 +44,29 @@ pub fn spawn_prune_loop() {
       if let Err(e) = alerts_res {
         error!("error in pruning alerts | {e:#}");
       }
+      if let Err(e) = recordings_res {
+        error!("error in pruning terminal recordings | {e:#}");
+      }
+      if let Err(e) = sessions_res {
+        error!("error in pruning sessions | {e:#}");
+      }
+      if let Err(e) = updates_res {
+        error!("error in pruning updates | {e:#}");
+      }
+      if let Err(e) = audit_log_res {
+        error!("error in pruning audit log | {e:#}");
+      }
     }
   });
 }
 
+/// The timestamp before which documents kept
+/// for the given number of days are deleted.
+fn delete_before_ts(keep_for_days: u64) -> i64 {
+  unix_timestamp_ms()
+    .saturating_sub(keep_for_days as u128 * ONE_DAY_MS) as i64
+}
+
 async fn prune_images() -> anyhow::Result<()> {
   let mut futures = find_collect(
     &db_client().servers,
@@ -65,9 +103,8 @@ async fn prune_stats() -> anyhow::Result<()> {
   if core_config().keep_stats_for_days == 0 {
     return Ok(());
   }
-  let delete_before_ts = (unix_timestamp_ms()
-    - core_config().keep_stats_for_days as u128 * ONE_DAY_MS)
-    as i64;
+  let delete_before_ts =
+    delete_before_ts(core_config().keep_stats_for_days);
   let res = db_client()
     .stats
     .delete_many(doc! {
@@ -84,9 +121,8 @@ async fn prune_alerts() -> anyhow::Result<()> {
   if core_config().keep_alerts_for_days == 0 {
     return Ok(());
   }
-  let delete_before_ts = (unix_timestamp_ms()
-    - core_config().keep_alerts_for_days as u128 * ONE_DAY_MS)
-    as i64;
+  let delete_before_ts =
+    delete_before_ts(core_config().keep_alerts_for_days);
   let res = db_client()
     .alerts
     .delete_many(doc! {
@@ -98,3 +134,115 @@ async fn prune_alerts() -> anyhow::Result<()> {
   }
   Ok(())
 }
+
+/// Only completed updates are deleted,
+/// so in progress updates keep receiving logs.
+async fn prune_updates() -> anyhow::Result<()> {
+  let keep_for_days = core_config().keep_updates_for_days;
+  if keep_for_days == 0 {
+    return Ok(());
+  }
+  let res = db_client()
+    .updates
+    .delete_many(doc! {
+      "start_ts": { "$lt": delete_before_ts(keep_for_days) },
+      "status": "Complete",
+    })
+    .await?;
+  if res.deleted_count > 0 {
+    info!("deleted {} updates from db", res.deleted_count);
+  }
+  Ok(())
+}
+
+async fn prune_audit_log() -> anyhow::Result<()> {
+  let keep_for_days = core_config().keep_audit_log_for_days;
+  if keep_for_days == 0 {
+    return Ok(());
+  }
+  let res = db_client()
+    .audit_log
+    .delete_many(doc! {
+      "timestamp": { "$lt": delete_before_ts(keep_for_days) }
+    })
+    .await?;
+  if res.deleted_count > 0 {
+    info!("deleted {} audit log entries from db", res.deleted_count);
+  }
+  Ok(())
+}
+
+async fn prune_sessions() -> anyhow::Result<()> {
+  let res = db_client()
+    .sessions
+    .delete_many(doc! {
+      "expires_at": { "$lt": unix_timestamp_ms() as i64 }
+    })
+    .await?;
+  if res.deleted_count > 0 {
+    info!("deleted {} expired sessions from db", res.deleted_count);
+  }
+  Ok(())
+}
+
+async fn prune_terminal_recordings() -> anyhow::Result<()> {
+  let config = core_config();
+  if config.keep_terminal_recordings_for_days == 0
+    || !config.terminal_recording_directory.exists()
+  {
+    return Ok(());
+  }
+  let delete_before_ts =
+    delete_before_ts(config.keep_terminal_recordings_for_days);
+  let mut entries =
+    tokio::fs::read_dir(&config.terminal_recording_directory)
+      .await
+      .context("failed to read terminal recording directory")?;
+  let mut deleted_count = 0;
+  while let Some(entry) = entries.next_entry().await? {
+    // Recording file names are prefixed with the session start timestamp.
+    let Some(started_at) = entry
+      .file_name()
+      .to_str()
+      .and_then(|name| name.split('_').next())
+      .and_then(|ts| ts.parse::<i64>().ok())
+    else {
+      continue;
+    };
+    if started_at >= delete_before_ts {
+      continue;
+    }
+    if let Err(e) = tokio::fs::remove_file(entry.path()).await {
+      warn!(
+        "failed to delete terminal recording at {:?} | {e:?}",
+        entry.path()
+      );
+    } else {
+      deleted_count += 1;
+    }
+  }
+  if deleted_count > 0 {
+    info!("deleted {deleted_count} terminal recordings");
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn delete_before_ts_is_keep_for_days_before_now() {
+    let before = unix_timestamp_ms() as i64;
+    let ts = delete_before_ts(30);
+    let after = unix_timestamp_ms() as i64;
+    let keep_for_ms = 30 * ONE_DAY_MS as i64;
+    assert!(ts >= before - keep_for_ms);
+    assert!(ts <= after - keep_for_ms);
+  }
+
+  #[test]
+  fn delete_before_ts_saturates_at_zero() {
+    assert_eq!(delete_before_ts(u64::MAX / ONE_DAY_MS as u64), 0);
+  }
+}
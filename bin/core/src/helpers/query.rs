@@ -29,6 +29,8 @@ use komodo_client::{
     procedure::{Procedure, ProcedureState},
     repo::Repo,
     server::{Server, ServerState},
+    server_group::ServerGroup,
+    service_account::ServiceAccount,
     stack::{Stack, StackServiceNames, StackState},
     stats::SystemInformation,
     sync::ResourceSync,
@@ -62,14 +64,41 @@ pub async fn get_user(user: &str) -> anyhow::Result<User> {
   if let Some(user) = admin_service_user(user) {
     return Ok(user);
   }
-  db_client()
+  let db = db_client();
+  if let Some(user) = db
     .users
     .find_one(id_or_username_filter(user))
     .await
     .context("failed to query mongo for user")?
+  {
+    return Ok(user);
+  }
+  // Service accounts act as a user with the account id.
+  let Ok(id) = ObjectId::from_str(user) else {
+    return Err(anyhow!("no user found with {user}"));
+  };
+  db.service_accounts
+    .find_one(doc! { "_id": id })
+    .await
+    .context("failed to query mongo for service account")?
+    .map(|service_account| service_account.user())
     .with_context(|| format!("no user found with {user}"))
 }
 
+#[instrument(level = "debug")]
+pub async fn get_service_account(
+  id_or_name: &str,
+) -> anyhow::Result<ServiceAccount> {
+  db_client()
+    .service_accounts
+    .find_one(id_or_name_filter(id_or_name))
+    .await
+    .context("failed to query mongo for service account")?
+    .with_context(|| {
+      format!("no service account found with {id_or_name}")
+    })
+}
+
 #[instrument(level = "debug")]
 pub async fn get_server_with_state(
   server_id_or_name: &str,
@@ -335,6 +364,9 @@ pub async fn get_user_permission_on_target(
     ResourceTarget::Stack(id) => {
       get_user_permission_on_resource::<Stack>(user, id).await
     }
+    ResourceTarget::ServerGroup(id) => {
+      get_user_permission_on_resource::<ServerGroup>(user, id).await
+    }
   }
 }
 
@@ -352,14 +384,22 @@ pub fn id_or_username_filter(id_or_username: &str) -> Document {
   }
 }
 
-pub async fn get_variable(name: &str) -> anyhow::Result<Variable> {
+/// Get the variable defined in exactly the given scope,
+/// global when `server_id` is None.
+pub async fn get_variable(
+  name: &str,
+  server_id: Option<&str>,
+) -> anyhow::Result<Variable> {
   db_client()
     .variables
-    .find_one(doc! { "name": &name })
+    .find_one(doc! { "name": &name, "server_id": server_id })
     .await
     .context("failed at call to db")?
-    .with_context(|| {
-      format!("no variable found with given name: {name}")
+    .with_context(|| match server_id {
+      Some(server_id) => format!(
+        "no variable found with given name: {name} | server: {server_id}"
+      ),
+      None => format!("no variable found with given name: {name}"),
     })
 }
 
@@ -389,11 +429,32 @@ pub struct VariablesAndSecrets {
   pub secrets: HashMap<String, String>,
 }
 
-pub async fn get_variables_and_secrets()
--> anyhow::Result<VariablesAndSecrets> {
-  let variables = find_collect(&db_client().variables, None, None)
+/// Pass `server_id` for resources running on a Server, so variables
+/// scoped to that Server override the global ones with the same name.
+pub async fn get_variables_and_secrets(
+  server_id: Option<&str>,
+) -> anyhow::Result<VariablesAndSecrets> {
+  let filter = match server_id.filter(|id| !id.is_empty()) {
+    Some(server_id) => doc! { "$or": [
+      { "server_id": null },
+      { "server_id": server_id }
+    ] },
+    None => doc! { "server_id": null },
+  };
+  let mut variables = HashMap::<String, Variable>::new();
+  for variable in find_collect(&db_client().variables, filter, None)
     .await
-    .context("failed to get all variables from db")?;
+    .context("failed to get all variables from db")?
+  {
+    if variable.server_id.is_none()
+      && variables.contains_key(&variable.name)
+    {
+      // The server scoped variable takes precedence
+      continue;
+    }
+    variables.insert(variable.name.clone(), variable);
+  }
+  let variables = variables.into_values().collect::<Vec<_>>();
   let mut secrets = core_config().secrets.clone();
 
   // extend secrets with secret variables
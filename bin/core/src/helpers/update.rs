@@ -1,7 +1,6 @@
 use anyhow::Context;
 use database::mungos::{
-  by_id::{find_one_by_id, update_one_by_id},
-  mongodb::bson::to_document,
+  by_id::update_one_by_id, mongodb::bson::to_document,
 };
 use komodo_client::entities::{
   Operation, ResourceTarget,
@@ -13,6 +12,7 @@ use komodo_client::entities::{
   procedure::Procedure,
   repo::Repo,
   server::Server,
+  server_group::ServerGroup,
   stack::Stack,
   sync::ResourceSync,
   update::{Update, UpdateListItem},
@@ -20,22 +20,36 @@ use komodo_client::entities::{
 };
 
 use crate::{
-  api::execute::ExecuteRequest, resource, state::db_client,
+  api::{check_read_only, execute::ExecuteRequest},
+  config::core_config,
+  resource,
+  state::db_client,
 };
 
-use super::channel::update_channel;
+use super::{
+  audit::set_audit_target, channel::update_channel, query::get_user,
+};
+
+tokio::task_local! {
+  /// The ip of the client making the current `/write` or `/execute`
+  /// request, stored on the Updates the request creates.
+  pub static CLIENT_IP: String;
+}
 
 pub fn make_update(
   target: impl Into<ResourceTarget>,
   operation: Operation,
   user: &User,
 ) -> Update {
+  let target = target.into();
+  set_audit_target(&target);
   Update {
     start_ts: komodo_timestamp(),
-    target: target.into(),
+    target,
     operation,
     operator: user.id.clone(),
     success: true,
+    client_ip: CLIENT_IP.try_with(Clone::clone).ok(),
     ..Default::default()
   }
 }
@@ -92,13 +106,8 @@ async fn update_list_item(
   let username = if User::is_service_user(&update.operator) {
     update.operator.clone()
   } else {
-    find_one_by_id(&db_client().users, &update.operator)
-      .await
-      .context("failed to query mongo for user")?
-      .with_context(|| {
-        format!("no user found with id {}", update.operator)
-      })?
-      .username
+    // Also resolves the operator for service accounts
+    get_user(&update.operator).await?.username
   };
   let update = UpdateListItem {
     id: update.id,
@@ -125,6 +134,9 @@ pub async fn init_execution_update(
   request: &ExecuteRequest,
   user: &User,
 ) -> anyhow::Result<Update> {
+  // Every execution, including webhooks, schedules
+  // and procedure stages, creates its update here.
+  check_read_only(core_config().read_only)?;
   let (operation, target) = match &request {
     // Server
     ExecuteRequest::StartContainer(data) => (
@@ -254,6 +266,26 @@ pub async fn init_execution_update(
       ),
     ),
 
+    // Server Group
+    ExecuteRequest::RunCommandOnGroup(data) => (
+      Operation::RunCommandOnGroup,
+      ResourceTarget::ServerGroup(
+        resource::get::<ServerGroup>(&data.server_group).await?.id,
+      ),
+    ),
+    ExecuteRequest::PruneImagesOnGroup(data) => (
+      Operation::PruneImagesOnGroup,
+      ResourceTarget::ServerGroup(
+        resource::get::<ServerGroup>(&data.server_group).await?.id,
+      ),
+    ),
+    ExecuteRequest::PruneSystemOnGroup(data) => (
+      Operation::PruneSystemOnGroup,
+      ResourceTarget::ServerGroup(
+        resource::get::<ServerGroup>(&data.server_group).await?.id,
+      ),
+    ),
+
     // Deployment
     ExecuteRequest::Deploy(data) => (
       Operation::Deploy,
@@ -517,6 +549,9 @@ pub async fn init_execution_update(
     ExecuteRequest::BackupCoreDatabase(_data) => {
       (Operation::BackupCoreDatabase, ResourceTarget::system())
     }
+    ExecuteRequest::RestoreDatabase(_data) => {
+      (Operation::RestoreDatabase, ResourceTarget::system())
+    }
     ExecuteRequest::GlobalAutoUpdate(_data) => {
       (Operation::GlobalAutoUpdate, ResourceTarget::system())
     }
@@ -0,0 +1,80 @@
+use anyhow::{Context, anyhow};
+use axum::http::HeaderMap;
+use serde::Deserialize;
+
+use crate::{
+  config::core_config,
+  listener::{ExtractBranch, VerifySecret},
+};
+
+use super::constant_time_eq;
+
+/// Listener implementation for any other git host / CI system.
+/// Configured using the webhook URL query, eg
+/// `/listener/custom/build/{id}?header=x-ci-token&branch_pointer=/ref`
+#[derive(Deserialize)]
+pub struct Custom {
+  /// The header containing the plaintext webhook secret.
+  #[serde(default = "default_header")]
+  header: String,
+  /// The JSON pointer to the branch in the webhook body.
+  #[serde(default = "default_branch_pointer")]
+  branch_pointer: String,
+}
+
+fn default_header() -> String {
+  String::from("x-komodo-token")
+}
+
+fn default_branch_pointer() -> String {
+  String::from("/ref")
+}
+
+impl VerifySecret for Custom {
+  #[instrument("VerifyCustomSecret", skip_all)]
+  fn verify_secret(
+    &self,
+    headers: HeaderMap,
+    _body: &str,
+    custom_secret: &str,
+  ) -> anyhow::Result<()> {
+    let token = headers
+      .get(&self.header)
+      .with_context(|| format!("No {} in headers", self.header))?;
+    let token =
+      token.to_str().context("Failed to get token as string")?;
+    let secret = if custom_secret.is_empty() {
+      core_config().webhook_secret.as_str()
+    } else {
+      custom_secret
+    };
+    if constant_time_eq(token.as_bytes(), secret.as_bytes()) {
+      Ok(())
+    } else {
+      Err(anyhow!("Webhook secret does not match expected."))
+    }
+  }
+}
+
+impl ExtractBranch for Custom {
+  fn extract_branch(&self, body: &str) -> anyhow::Result<String> {
+    let body = serde_json::from_str::<serde_json::Value>(body)
+      .context("Failed to parse custom webhook body as JSON")?;
+    let branch = body
+      .pointer(&self.branch_pointer)
+      .with_context(|| {
+        format!(
+          "No value at '{}' in custom webhook body",
+          self.branch_pointer
+        )
+      })?
+      .as_str()
+      .with_context(|| {
+        format!(
+          "Value at '{}' in custom webhook body is not a string",
+          self.branch_pointer
+        )
+      })?;
+    Ok(branch.replace("refs/heads/", ""))
+  }
+}
@@ -13,11 +13,13 @@ use crate::{
 type HmacSha256 = Hmac<Sha256>;
 
 /// Listener implementation for Github type API, including Gitea
-pub struct Github;
+#[derive(Deserialize)]
+pub struct Github {}
 
 impl VerifySecret for Github {
   #[instrument("VerifyGithubSecret", skip_all)]
   fn verify_secret(
+    &self,
     headers: HeaderMap,
     body: &str,
     custom_secret: &str,
@@ -54,7 +56,7 @@ struct GithubWebhookBody {
 }
 
 impl ExtractBranch for Github {
-  fn extract_branch(body: &str) -> anyhow::Result<String> {
+  fn extract_branch(&self, body: &str) -> anyhow::Result<String> {
     let branch = serde_json::from_str::<GithubWebhookBody>(body)
       .context("Failed to parse github request body")?
       .branch
@@ -1,32 +1,53 @@
 use anyhow::{Context, anyhow};
+use async_timing_util::unix_timestamp_ms;
+use axum::http::HeaderMap;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use hmac::{Hmac, Mac};
 use serde::Deserialize;
+use sha2::Sha256;
 
 use crate::{
   config::core_config,
   listener::{ExtractBranch, VerifySecret},
 };
 
+use super::constant_time_eq;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Secrets with this prefix are verified using the
+/// HMAC-SHA256 webhook signature rather than the plaintext token.
+const HMAC_SECRET_PREFIX: &str = "hmac:";
+
+/// Max seconds between the signed webhook timestamp and now.
+const WEBHOOK_TIMESTAMP_TOLERANCE_SECS: i64 = 5 * 60;
+
 /// Listener implementation for Gitlab type API
-pub struct Gitlab;
+#[derive(Deserialize)]
+pub struct Gitlab {}
 
 impl VerifySecret for Gitlab {
   #[instrument("VerifyGitlabSecret", skip_all)]
   fn verify_secret(
-    headers: axum::http::HeaderMap,
-    _body: &str,
+    &self,
+    headers: HeaderMap,
+    body: &str,
     custom_secret: &str,
   ) -> anyhow::Result<()> {
-    let token = headers
-      .get("x-gitlab-token")
-      .context("No gitlab token in headers")?;
-    let token =
-      token.to_str().context("Failed to get token as string")?;
     let secret = if custom_secret.is_empty() {
       core_config().webhook_secret.as_str()
     } else {
       custom_secret
     };
-    if token == secret {
+    if let Some(secret) = secret.strip_prefix(HMAC_SECRET_PREFIX) {
+      return verify_signature(&headers, body, secret);
+    }
+    let token = headers
+      .get("x-gitlab-token")
+      .context("No gitlab token in headers")?;
+    let token =
+      token.to_str().context("Failed to get token as string")?;
+    if constant_time_eq(token.as_bytes(), secret.as_bytes()) {
       Ok(())
     } else {
       Err(anyhow!("Webhook secret does not match expected."))
@@ -34,6 +55,66 @@ impl VerifySecret for Gitlab {
   }
 }
 
+/// Verifies the Gitlab signing token signature, which follows the Standard Webhooks spec:
+/// `webhook-signature: v1,{base64(hmac_sha256("{webhook-id}.{webhook-timestamp}.{body}"))}`
+///
+/// Secrets prefixed with `whsec_` are base64 decoded to get the key bytes.
+fn verify_signature(
+  headers: &HeaderMap,
+  body: &str,
+  secret: &str,
+) -> anyhow::Result<()> {
+  let header = |name: &str| {
+    headers
+      .get(name)
+      .with_context(|| format!("No {name} in headers"))?
+      .to_str()
+      .with_context(|| format!("Failed to get {name} as string"))
+  };
+  let id = header("webhook-id")?;
+  let timestamp = header("webhook-timestamp")?;
+  let signatures = header("webhook-signature")?;
+
+  // Reject old (or future) timestamps, so a captured
+  // signed webhook can't be replayed.
+  let sent_at = timestamp
+    .parse::<i64>()
+    .context("webhook-timestamp is not a unix timestamp")?;
+  let now = (unix_timestamp_ms() / 1000) as i64;
+  if (now - sent_at).abs() > WEBHOOK_TIMESTAMP_TOLERANCE_SECS {
+    return Err(anyhow!(
+      "webhook-timestamp is outside the allowed {WEBHOOK_TIMESTAMP_TOLERANCE_SECS}s window"
+    ));
+  }
+
+  let key = match secret.strip_prefix("whsec_") {
+    Some(secret) => STANDARD
+      .decode(secret)
+      .context("Failed to decode whsec_ secret as base64")?,
+    None => secret.as_bytes().to_vec(),
+  };
+
+  // Multiple space separated signatures may be sent, eg during secret rotation.
+  for signature in signatures.split(' ') {
+    let Some(signature) = signature.strip_prefix("v1,") else {
+      continue;
+    };
+    let Ok(signature) = STANDARD.decode(signature) else {
+      continue;
+    };
+    let mut mac = HmacSha256::new_from_slice(&key)
+      .context("Failed to create hmac sha256 from secret")?;
+    mac.update(format!("{id}.{timestamp}.").as_bytes());
+    mac.update(body.as_bytes());
+    // verify_slice uses a constant time comparison
+    if mac.verify_slice(&signature).is_ok() {
+      return Ok(());
+    }
+  }
+
+  Err(anyhow!("Signature does not equal expected"))
+}
+
 #[derive(Deserialize)]
 struct GitlabWebhookBody {
   #[serde(rename = "ref")]
@@ -41,7 +122,7 @@ struct GitlabWebhookBody {
 }
 
 impl ExtractBranch for Gitlab {
-  fn extract_branch(body: &str) -> anyhow::Result<String> {
+  fn extract_branch(&self, body: &str) -> anyhow::Result<String> {
     let branch = serde_json::from_str::<GitlabWebhookBody>(body)
       .context("Failed to parse gitlab request body")?
       .branch
@@ -49,3 +130,142 @@ impl ExtractBranch for Gitlab {
     Ok(branch)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn token_headers(token: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-gitlab-token", token.parse().unwrap());
+    headers
+  }
+
+  #[test]
+  fn rejects_wrong_token() {
+    let err = Gitlab {}
+      .verify_secret(token_headers("wrong-secret"), "{}", "secret")
+      .unwrap_err();
+    assert_eq!(
+      err.to_string(),
+      "Webhook secret does not match expected."
+    );
+  }
+
+  #[test]
+  fn rejects_missing_token() {
+    let err = Gitlab {}
+      .verify_secret(HeaderMap::new(), "{}", "secret")
+      .unwrap_err();
+    assert_eq!(err.to_string(), "No gitlab token in headers");
+  }
+
+  #[test]
+  fn accepts_matching_token() {
+    Gitlab {}
+      .verify_secret(token_headers("secret"), "{}", "secret")
+      .unwrap();
+  }
+
+  const BODY: &str = r#"{"ref":"refs/heads/main"}"#;
+
+  fn now() -> i64 {
+    (unix_timestamp_ms() / 1000) as i64
+  }
+
+  fn signed_headers(key: &[u8], timestamp: i64) -> HeaderMap {
+    let mut mac = HmacSha256::new_from_slice(key).unwrap();
+    mac.update(format!("msg_1.{timestamp}.{BODY}").as_bytes());
+    let signature = STANDARD.encode(mac.finalize().into_bytes());
+    let mut headers = HeaderMap::new();
+    headers.insert("webhook-id", "msg_1".parse().unwrap());
+    headers.insert(
+      "webhook-timestamp",
+      timestamp.to_string().parse().unwrap(),
+    );
+    headers.insert(
+      "webhook-signature",
+      format!("v1,{signature}").parse().unwrap(),
+    );
+    headers
+  }
+
+  #[test]
+  fn accepts_valid_signature() {
+    Gitlab {}
+      .verify_secret(
+        signed_headers(b"secret", now()),
+        BODY,
+        "hmac:secret",
+      )
+      .unwrap();
+  }
+
+  #[test]
+  fn accepts_valid_signature_with_whsec_secret() {
+    let secret = format!("hmac:whsec_{}", STANDARD.encode(b"key"));
+    Gitlab {}
+      .verify_secret(signed_headers(b"key", now()), BODY, &secret)
+      .unwrap();
+  }
+
+  #[test]
+  fn rejects_wrong_signature() {
+    let err = Gitlab {}
+      .verify_secret(
+        signed_headers(b"other", now()),
+        BODY,
+        "hmac:secret",
+      )
+      .unwrap_err();
+    assert_eq!(err.to_string(), "Signature does not equal expected");
+  }
+
+  #[test]
+  fn rejects_modified_body() {
+    Gitlab {}
+      .verify_secret(
+        signed_headers(b"secret", now()),
+        r#"{"ref":"refs/heads/other"}"#,
+        "hmac:secret",
+      )
+      .unwrap_err();
+  }
+
+  #[test]
+  fn accepts_timestamp_inside_window() {
+    let timestamp = now() - WEBHOOK_TIMESTAMP_TOLERANCE_SECS + 30;
+    Gitlab {}
+      .verify_secret(
+        signed_headers(b"secret", timestamp),
+        BODY,
+        "hmac:secret",
+      )
+      .unwrap();
+  }
+
+  #[test]
+  fn rejects_stale_timestamp() {
+    let timestamp = now() - WEBHOOK_TIMESTAMP_TOLERANCE_SECS - 30;
+    let err = Gitlab {}
+      .verify_secret(
+        signed_headers(b"secret", timestamp),
+        BODY,
+        "hmac:secret",
+      )
+      .unwrap_err();
+    assert!(err.to_string().contains("outside the allowed"));
+  }
+
+  #[test]
+  fn rejects_future_timestamp() {
+    let timestamp = now() + WEBHOOK_TIMESTAMP_TOLERANCE_SECS + 30;
+    Gitlab {}
+      .verify_secret(
+        signed_headers(b"secret", timestamp),
+        BODY,
+        "hmac:secret",
+      )
+      .unwrap_err();
+  }
+}
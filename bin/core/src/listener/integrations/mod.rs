@@ -1,2 +1,11 @@
+use subtle::ConstantTimeEq;
+
+pub mod custom;
 pub mod github;
 pub mod gitlab;
+
+/// Compares the bytes without returning early on the first mismatch,
+/// to avoid leaking the secret through response timing.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+  a.ct_eq(b).into()
+}
@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
 use anyhow::anyhow;
-use axum::{Router, http::HeaderMap};
+use axum::{Router, http::HeaderMap, middleware};
 use komodo_client::entities::resource::Resource;
 use tokio::sync::Mutex;
 
-use crate::{helpers::cache::Cache, resource::KomodoResource};
+use crate::{
+  api::read_only_guard, helpers::cache::Cache,
+  resource::KomodoResource,
+};
 
 mod integrations;
 mod resources;
@@ -13,10 +16,14 @@ mod router;
 
 use integrations::*;
 
+pub use integrations::constant_time_eq;
+
 pub fn router() -> Router {
   Router::new()
     .nest("/github", router::router::<github::Github>())
     .nest("/gitlab", router::router::<gitlab::Gitlab>())
+    .nest("/custom", router::router::<custom::Custom>())
+    .layer(middleware::from_fn(read_only_guard))
 }
 
 type ListenerLockCache = Cache<String, Arc<Mutex<()>>>;
@@ -28,9 +35,11 @@ trait CustomSecret: KomodoResource {
   ) -> &str;
 }
 
-/// Implemented on the integration struct, eg [integrations::github::Github]
+/// Implemented on the integration struct, eg [integrations::github::Github].
+/// The integration is deserialized from the webhook URL query.
 trait VerifySecret {
   fn verify_secret(
+    &self,
     headers: HeaderMap,
     body: &str,
     custom_secret: &str,
@@ -39,9 +48,13 @@ trait VerifySecret {
 
 /// Implemented on the integration struct, eg [integrations::github::Github]
 trait ExtractBranch {
-  fn extract_branch(body: &str) -> anyhow::Result<String>;
-  fn verify_branch(body: &str, expected: &str) -> anyhow::Result<()> {
-    let branch = Self::extract_branch(body)?;
+  fn extract_branch(&self, body: &str) -> anyhow::Result<String>;
+  fn verify_branch(
+    &self,
+    body: &str,
+    expected: &str,
+  ) -> anyhow::Result<()> {
+    let branch = self.extract_branch(body)?;
     if branch == expected {
       Ok(())
     } else {
@@ -41,6 +41,7 @@ fn build_locks() -> &'static ListenerLockCache {
 }
 
 pub async fn handle_build_webhook<B: super::ExtractBranch>(
+  integration: &B,
   build: Build,
   body: String,
 ) -> anyhow::Result<()> {
@@ -54,7 +55,7 @@ pub async fn handle_build_webhook<B: super::ExtractBranch>(
   let lock = build_locks().get_or_insert_default(&build.id).await;
   let _lock = lock.lock().await;
 
-  B::verify_branch(&body, &build.config.branch)?;
+  integration.verify_branch(&body, &build.config.branch)?;
 
   let user = git_webhook_user().to_owned();
   let req = ExecuteRequest::RunBuild(RunBuild { build: build.id });
@@ -157,19 +158,35 @@ pub enum RepoWebhookOption {
 }
 
 pub async fn handle_repo_webhook<B: super::ExtractBranch>(
+  integration: &B,
   option: RepoWebhookOption,
   repo: Repo,
   body: String,
 ) -> anyhow::Result<()> {
   match option {
     RepoWebhookOption::Clone => {
-      handle_repo_webhook_inner::<B, CloneRepo>(repo, body).await
+      handle_repo_webhook_inner::<B, CloneRepo>(
+        integration,
+        repo,
+        body,
+      )
+      .await
     }
     RepoWebhookOption::Pull => {
-      handle_repo_webhook_inner::<B, PullRepo>(repo, body).await
+      handle_repo_webhook_inner::<B, PullRepo>(
+        integration,
+        repo,
+        body,
+      )
+      .await
     }
     RepoWebhookOption::Build => {
-      handle_repo_webhook_inner::<B, BuildRepo>(repo, body).await
+      handle_repo_webhook_inner::<B, BuildRepo>(
+        integration,
+        repo,
+        body,
+      )
+      .await
     }
   }
 }
@@ -178,6 +195,7 @@ async fn handle_repo_webhook_inner<
   B: super::ExtractBranch,
   E: RepoExecution,
 >(
+  integration: &B,
   repo: Repo,
   body: String,
 ) -> anyhow::Result<()> {
@@ -191,7 +209,7 @@ async fn handle_repo_webhook_inner<
   let lock = repo_locks().get_or_insert_default(&repo.id).await;
   let _lock = lock.lock().await;
 
-  B::verify_branch(&body, &repo.config.branch)?;
+  integration.verify_branch(&body, &repo.config.branch)?;
 
   E::resolve(repo).await
 }
@@ -271,17 +289,27 @@ pub enum StackWebhookOption {
 }
 
 pub async fn handle_stack_webhook<B: super::ExtractBranch>(
+  integration: &B,
   option: StackWebhookOption,
   stack: Stack,
   body: String,
 ) -> anyhow::Result<()> {
   match option {
     StackWebhookOption::Refresh => {
-      handle_stack_webhook_inner::<B, RefreshStackCache>(stack, body)
-        .await
+      handle_stack_webhook_inner::<B, RefreshStackCache>(
+        integration,
+        stack,
+        body,
+      )
+      .await
     }
     StackWebhookOption::Deploy => {
-      handle_stack_webhook_inner::<B, DeployStack>(stack, body).await
+      handle_stack_webhook_inner::<B, DeployStack>(
+        integration,
+        stack,
+        body,
+      )
+      .await
     }
   }
 }
@@ -290,6 +318,7 @@ pub async fn handle_stack_webhook_inner<
   B: super::ExtractBranch,
   E: StackExecution,
 >(
+  integration: &B,
   stack: Stack,
   body: String,
 ) -> anyhow::Result<()> {
@@ -303,7 +332,7 @@ pub async fn handle_stack_webhook_inner<
   let lock = stack_locks().get_or_insert_default(&stack.id).await;
   let _lock = lock.lock().await;
 
-  B::verify_branch(&body, &stack.config.branch)?;
+  integration.verify_branch(&body, &stack.config.branch)?;
 
   E::resolve(stack).await.map_err(|e| e.error)
 }
@@ -346,6 +375,7 @@ impl SyncExecution for RunSync {
       sync: sync.id,
       resource_type: None,
       resources: None,
+      dry_run: false,
     });
     let update = init_execution_update(&req, &user).await?;
     let ExecuteRequest::RunSync(req) = req else {
@@ -367,6 +397,7 @@ pub enum SyncWebhookOption {
 }
 
 pub async fn handle_sync_webhook<B: super::ExtractBranch>(
+  integration: &B,
   option: SyncWebhookOption,
   sync: ResourceSync,
   body: String,
@@ -374,12 +405,15 @@ pub async fn handle_sync_webhook<B: super::ExtractBranch>(
   match option {
     SyncWebhookOption::Refresh => {
       handle_sync_webhook_inner::<B, RefreshResourceSyncPending>(
-        sync, body,
+        integration,
+        sync,
+        body,
       )
       .await
     }
     SyncWebhookOption::Sync => {
-      handle_sync_webhook_inner::<B, RunSync>(sync, body).await
+      handle_sync_webhook_inner::<B, RunSync>(integration, sync, body)
+        .await
     }
   }
 }
@@ -388,6 +422,7 @@ async fn handle_sync_webhook_inner<
   B: super::ExtractBranch,
   E: SyncExecution,
 >(
+  integration: &B,
   sync: ResourceSync,
   body: String,
 ) -> anyhow::Result<()> {
@@ -401,7 +436,7 @@ async fn handle_sync_webhook_inner<
   let lock = sync_locks().get_or_insert_default(&sync.id).await;
   let _lock = lock.lock().await;
 
-  B::verify_branch(&body, &sync.config.branch)?;
+  integration.verify_branch(&body, &sync.config.branch)?;
 
   E::resolve(sync).await
 }
@@ -423,6 +458,7 @@ fn procedure_locks() -> &'static ListenerLockCache {
 }
 
 pub async fn handle_procedure_webhook<B: super::ExtractBranch>(
+  integration: &B,
   procedure: Procedure,
   target_branch: &str,
   body: String,
@@ -439,7 +475,7 @@ pub async fn handle_procedure_webhook<B: super::ExtractBranch>(
   let _lock = lock.lock().await;
 
   if target_branch != ANY_BRANCH {
-    B::verify_branch(&body, target_branch)?;
+    integration.verify_branch(&body, target_branch)?;
   }
 
   let user = git_webhook_user().to_owned();
@@ -473,6 +509,7 @@ fn action_locks() -> &'static ListenerLockCache {
 }
 
 pub async fn handle_action_webhook<B: super::ExtractBranch>(
+  integration: &B,
   action: Action,
   target_branch: &str,
   body: String,
@@ -487,7 +524,7 @@ pub async fn handle_action_webhook<B: super::ExtractBranch>(
   let lock = action_locks().get_or_insert_default(&action.id).await;
   let _lock = lock.lock().await;
 
-  let branch = B::extract_branch(&body)?;
+  let branch = integration.extract_branch(&body)?;
 
   if target_branch != ANY_BRANCH && branch != target_branch {
     return Err(anyhow!("request branch does not match expected"));
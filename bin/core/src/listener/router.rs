@@ -1,10 +1,15 @@
-use axum::{Router, extract::Path, http::HeaderMap, routing::post};
+use axum::{
+  Router,
+  extract::{Path, Query},
+  http::HeaderMap,
+  routing::post,
+};
 use komodo_client::entities::{
   action::Action, build::Build, procedure::Procedure, repo::Repo,
   resource::Resource, stack::Stack, sync::ResourceSync,
 };
 use reqwest::StatusCode;
-use serde::Deserialize;
+use serde::{Deserialize, de::DeserializeOwned};
 use serror::AddStatusCode;
 use tracing::Instrument;
 
@@ -42,19 +47,26 @@ fn default_branch() -> String {
   String::from("main")
 }
 
-pub fn router<P: VerifySecret + ExtractBranch>() -> Router {
+pub fn router<
+  P: VerifySecret
+    + ExtractBranch
+    + DeserializeOwned
+    + Send
+    + Sync
+    + 'static,
+>() -> Router {
   Router::new()
   .route(
     "/build/{id}",
     post(
-      |Path(Id { id }), headers: HeaderMap, body: String| async move {
+      |Path(Id { id }), Query(integration): Query<P>, headers: HeaderMap, body: String| async move {
         let build =
-          auth_webhook::<P, Build>(&id, headers, &body).await?;
+          auth_webhook::<P, Build>(&integration, &id, headers, &body).await?;
         tokio::spawn(async move {
           let span = info_span!("BuildWebhook", id);
           async {
             let res = handle_build_webhook::<P>(
-              build, body,
+              &integration, build, body,
             )
             .await;
             if let Err(e) = res {
@@ -73,14 +85,14 @@ pub fn router<P: VerifySecret + ExtractBranch>() -> Router {
   .route(
     "/repo/{id}/{option}",
     post(
-      |Path(IdAndOption::<RepoWebhookOption> { id, option }), headers: HeaderMap, body: String| async move {
+      |Path(IdAndOption::<RepoWebhookOption> { id, option }), Query(integration): Query<P>, headers: HeaderMap, body: String| async move {
         let repo =
-          auth_webhook::<P, Repo>(&id, headers, &body).await?;
+          auth_webhook::<P, Repo>(&integration, &id, headers, &body).await?;
         tokio::spawn(async move {
           let span = info_span!("RepoWebhook", id);
           async {
             let res = handle_repo_webhook::<P>(
-              option, repo, body,
+              &integration, option, repo, body,
             )
             .await;
             if let Err(e) = res {
@@ -99,14 +111,14 @@ pub fn router<P: VerifySecret + ExtractBranch>() -> Router {
   .route(
     "/stack/{id}/{option}",
     post(
-      |Path(IdAndOption::<StackWebhookOption> { id, option }), headers: HeaderMap, body: String| async move {
+      |Path(IdAndOption::<StackWebhookOption> { id, option }), Query(integration): Query<P>, headers: HeaderMap, body: String| async move {
         let stack =
-          auth_webhook::<P, Stack>(&id, headers, &body).await?;
+          auth_webhook::<P, Stack>(&integration, &id, headers, &body).await?;
         tokio::spawn(async move {
           let span = info_span!("StackWebhook", id);
           async {
             let res = handle_stack_webhook::<P>(
-              option, stack, body,
+              &integration, option, stack, body,
             )
             .await;
             if let Err(e) = res {
@@ -125,14 +137,14 @@ pub fn router<P: VerifySecret + ExtractBranch>() -> Router {
   .route(
     "/sync/{id}/{option}",
     post(
-      |Path(IdAndOption::<SyncWebhookOption> { id, option }), headers: HeaderMap, body: String| async move {
+      |Path(IdAndOption::<SyncWebhookOption> { id, option }), Query(integration): Query<P>, headers: HeaderMap, body: String| async move {
         let sync =
-          auth_webhook::<P, ResourceSync>(&id, headers, &body).await?;
+          auth_webhook::<P, ResourceSync>(&integration, &id, headers, &body).await?;
         tokio::spawn(async move {
           let span = info_span!("ResourceSyncWebhook", id);
           async {
             let res = handle_sync_webhook::<P>(
-              option, sync, body,
+              &integration, option, sync, body,
             )
             .await;
             if let Err(e) = res {
@@ -151,14 +163,14 @@ pub fn router<P: VerifySecret + ExtractBranch>() -> Router {
   .route(
     "/procedure/{id}/{branch}",
     post(
-      |Path(IdAndBranch { id, branch }), headers: HeaderMap, body: String| async move {
+      |Path(IdAndBranch { id, branch }), Query(integration): Query<P>, headers: HeaderMap, body: String| async move {
         let procedure =
-          auth_webhook::<P, Procedure>(&id, headers, &body).await?;
+          auth_webhook::<P, Procedure>(&integration, &id, headers, &body).await?;
         tokio::spawn(async move {
           let span = info_span!("ProcedureWebhook", id);
           async {
             let res = handle_procedure_webhook::<P>(
-              procedure, &branch, body,
+              &integration, procedure, &branch, body,
             )
             .await;
             if let Err(e) = res {
@@ -177,14 +189,14 @@ pub fn router<P: VerifySecret + ExtractBranch>() -> Router {
   .route(
     "/action/{id}/{branch}",
     post(
-      |Path(IdAndBranch { id, branch }), headers: HeaderMap, body: String| async move {
+      |Path(IdAndBranch { id, branch }), Query(integration): Query<P>, headers: HeaderMap, body: String| async move {
         let action =
-          auth_webhook::<P, Action>(&id, headers, &body).await?;
+          auth_webhook::<P, Action>(&integration, &id, headers, &body).await?;
         tokio::spawn(async move {
           let span = info_span!("ActionWebhook", id);
           async {
             let res = handle_action_webhook::<P>(
-              action, &branch, body,
+              &integration, action, &branch, body,
             )
             .await;
             if let Err(e) = res {
@@ -203,6 +215,7 @@ pub fn router<P: VerifySecret + ExtractBranch>() -> Router {
 }
 
 async fn auth_webhook<P, R>(
+  integration: &P,
   id: &str,
   headers: HeaderMap,
   body: &str,
@@ -214,7 +227,8 @@ where
   let resource = crate::resource::get::<R>(id)
     .await
     .status_code(StatusCode::BAD_REQUEST)?;
-  P::verify_secret(headers, body, R::custom_secret(&resource))
+  integration
+    .verify_secret(headers, body, R::custom_secret(&resource))
     .status_code(StatusCode::UNAUTHORIZED)?;
   Ok(resource)
 }
@@ -1,13 +1,15 @@
 #[macro_use]
 extern crate tracing;
 
-use std::{net::SocketAddr, str::FromStr};
+use std::{net::SocketAddr, str::FromStr, time::Duration};
 
-use anyhow::Context;
-use axum::Router;
+use anyhow::{Context, anyhow};
+use axum::{
+  Router, extract::DefaultBodyLimit, http::HeaderValue, middleware,
+};
 use axum_server::{Handle, tls_rustls::RustlsConfig};
 use tower_http::{
-  cors::{Any, CorsLayer},
+  cors::{AllowHeaders, AllowMethods, Any, CorsLayer},
   services::{ServeDir, ServeFile},
 };
 
@@ -32,7 +34,7 @@ mod sync;
 mod ts_client;
 mod ws;
 
-async fn app() -> anyhow::Result<()> {
+async fn app(handle: Handle) -> anyhow::Result<()> {
   dotenvy::dotenv().ok();
   let config = core_config();
   logger::init(&config.logging)?;
@@ -74,6 +76,7 @@ async fn app() -> anyhow::Result<()> {
   resource::spawn_repo_state_refresh_loop();
   resource::spawn_procedure_state_refresh_loop();
   resource::spawn_action_state_refresh_loop();
+  resource::spawn_variable_references_refresh_loop();
   schedule::spawn_schedule_executor();
   helpers::prune::spawn_prune_loop();
 
@@ -84,31 +87,37 @@ async fn app() -> anyhow::Result<()> {
   let serve_frontend = ServeDir::new(frontend_path)
     .not_found_service(frontend_index.clone());
 
-  let app = Router::new()
+  let mut app = Router::new()
     .nest("/auth", api::auth::router())
     .nest("/user", api::user::router())
     .nest("/read", api::read::router())
-    .nest("/write", api::write::router())
-    .nest("/execute", api::execute::router())
+    .nest("/write", with_body_limit(api::write::router()))
+    .nest("/execute", with_body_limit(api::execute::router()))
     .nest("/terminal", api::terminal::router())
+    .nest("/update", api::update::router())
     .nest("/listener", listener::router())
     .nest("/ws", ws::router())
-    .nest("/client", ts_client::router())
+    .nest("/client", ts_client::router());
+
+  if config.scim_enabled {
+    if config.oidc_enabled {
+      info!("🔑 SCIM Provisioning Enabled");
+      app = app.nest("/scim/v2", auth::scim::router());
+    } else {
+      warn!("SCIM provisioning requires OIDC to be enabled");
+    }
+  }
+
+  let app = app
     .fallback_service(serve_frontend)
-    .layer(
-      CorsLayer::new()
-        .allow_origin(Any)
-        .allow_methods(Any)
-        .allow_headers(Any),
-    )
-    .into_make_service();
+    .layer(cors_layer(&config.cors_allowed_origins)?)
+    .into_make_service_with_connect_info::<SocketAddr>();
 
   let addr =
     format!("{}:{}", core_config().bind_ip, core_config().port);
   let socket_addr = SocketAddr::from_str(&addr)
     .context("failed to parse listen address")?;
 
-  let handle = Handle::new();
   tokio::spawn({
     // Cannot run actions until the server is available.
     // We can use a handle for the server, and wait until
@@ -148,13 +157,104 @@ async fn app() -> anyhow::Result<()> {
   }
 }
 
+/// Limits request bodies to `max_request_body_mb`.
+fn with_body_limit(router: Router) -> Router {
+  let max_bytes = core_config()
+    .max_request_body_mb
+    .saturating_mul(1024 * 1024);
+  router
+    // Enforces the limit on bodies without a content length.
+    .layer(DefaultBodyLimit::max(
+      max_bytes.try_into().unwrap_or(usize::MAX),
+    ))
+    .layer(middleware::from_fn(api::request_body_limit_guard))
+}
+
+/// Allows any origin when `allowed_origins` is empty.
+/// Otherwise only the given origins, with credentials.
+fn cors_layer(
+  allowed_origins: &[String],
+) -> anyhow::Result<CorsLayer> {
+  if allowed_origins.is_empty() {
+    return Ok(
+      CorsLayer::new()
+        .allow_origin(Any)
+        .allow_methods(Any)
+        .allow_headers(Any),
+    );
+  }
+  let origins = allowed_origins
+    .iter()
+    .map(|origin| {
+      parse_origin(origin).with_context(|| {
+        format!("Invalid origin in cors_allowed_origins: {origin}")
+      })
+    })
+    .collect::<anyhow::Result<Vec<_>>>()?;
+  // Wildcards are not allowed together with credentials,
+  // so mirror the request methods / headers instead.
+  Ok(
+    CorsLayer::new()
+      .allow_origin(origins)
+      .allow_methods(AllowMethods::mirror_request())
+      .allow_headers(AllowHeaders::mirror_request())
+      .allow_credentials(true),
+  )
+}
+
+/// Origins must be `scheme://host[:port]`, with no path.
+fn parse_origin(origin: &str) -> anyhow::Result<HeaderValue> {
+  let url = reqwest::Url::parse(origin)?;
+  if !matches!(url.scheme(), "http" | "https") {
+    return Err(anyhow!("Origin scheme must be http or https"));
+  }
+  if url.host_str().is_none() {
+    return Err(anyhow!("Origin is missing host"));
+  }
+  if url.path() != "/"
+    || url.query().is_some()
+    || url.fragment().is_some()
+  {
+    return Err(anyhow!("Origin cannot include path or query"));
+  }
+  HeaderValue::from_str(&url.origin().ascii_serialization())
+    .context("Origin is not a valid header value")
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
   let mut term_signal = tokio::signal::unix::signal(
     tokio::signal::unix::SignalKind::terminate(),
   )?;
+  let handle = Handle::new();
   tokio::select! {
-    res = tokio::spawn(app()) => res?,
-    _ = term_signal.recv() => Ok(()),
+    res = tokio::spawn(app(handle.clone())) => return res?,
+    _ = term_signal.recv() => {}
   }
+
+  let grace_period = Duration::from_millis(
+    core_config().shutdown_grace_period.as_millis() as u64,
+  );
+  let tasks = api::execute::execution_tasks();
+  info!(
+    "Received SIGTERM, waiting up to {grace_period:?} for {} running executions",
+    tasks.len()
+  );
+
+  // Stop accepting new requests
+  handle.graceful_shutdown(Some(grace_period));
+  tasks.close();
+
+  tokio::select! {
+    _ = tasks.wait() => info!("All executions finished, exiting"),
+    _ = tokio::time::sleep(grace_period) => warn!(
+      "Shutdown grace period elapsed with {} executions still running",
+      tasks.len()
+    ),
+    _ = term_signal.recv() => {
+      warn!("Received second SIGTERM, exiting immediately")
+    }
+  }
+
+  Ok(())
 }
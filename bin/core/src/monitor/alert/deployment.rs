@@ -1,11 +1,12 @@
 use std::collections::HashMap;
 
 use komodo_client::entities::{
-  ResourceTarget,
+  ResourceTarget, ResourceTargetVariant,
   alert::{Alert, AlertData, SeverityLevel},
   deployment::{Deployment, DeploymentState},
 };
 
+use super::container_became_unhealthy;
 use crate::{
   alert::send_alerts,
   monitor::deployment_status_cache,
@@ -48,42 +49,73 @@ pub async fn alert_deployments(
       continue;
     }
 
-    if status.curr.state != prev {
-      // send alert
-      let Ok(deployment) =
-        resource::get::<Deployment>(&status.curr.id)
-          .await
-          .inspect_err(|e| {
-            error!("failed to get deployment from db | {e:#?}")
-          })
-      else {
-        continue;
-      };
-      if !deployment.config.send_alerts {
-        continue;
-      }
-      let target: ResourceTarget = (&deployment).into();
+    let state_changed = status.curr.state != prev;
+    let unhealthy_container =
+      status.curr.container.as_ref().filter(|container| {
+        container_became_unhealthy(&status.curr.id, container)
+      });
+
+    if !state_changed && unhealthy_container.is_none() {
+      continue;
+    }
+
+    // send alert
+    let Ok(deployment) = resource::get::<Deployment>(&status.curr.id)
+      .await
+      .inspect_err(|e| {
+        error!("failed to get deployment from db | {e:#?}")
+      })
+    else {
+      continue;
+    };
+    if !deployment.config.send_alerts {
+      continue;
+    }
+    let target: ResourceTarget = (&deployment).into();
+    let server_name = server_names
+      .get(&deployment.config.server_id)
+      .cloned()
+      .unwrap_or(String::from("unknown"));
+
+    if state_changed {
       let data = AlertData::ContainerStateChange {
         id: status.curr.id.clone(),
-        name: deployment.name,
-        server_name: server_names
-          .get(&deployment.config.server_id)
-          .cloned()
-          .unwrap_or(String::from("unknown")),
-        server_id: deployment.config.server_id,
+        name: deployment.name.clone(),
+        server_name: server_name.clone(),
+        server_id: deployment.config.server_id.clone(),
         from: prev,
         to: status.curr.state,
       };
-      let alert = Alert {
+      alerts.push(Alert {
         id: Default::default(),
         level: SeverityLevel::Warning,
         resolved: true,
         resolved_ts: ts.into(),
-        target,
+        target: target.clone(),
         data,
         ts,
+      });
+    }
+
+    if let Some(container) = unhealthy_container {
+      let data = AlertData::ContainerUnhealthy {
+        id: status.curr.id.clone(),
+        name: deployment.name,
+        resource_type: ResourceTargetVariant::Deployment,
+        server_id: deployment.config.server_id,
+        server_name,
+        container: container.name.clone(),
+        service: None,
       };
-      alerts.push(alert);
+      alerts.push(Alert {
+        id: Default::default(),
+        level: SeverityLevel::Warning,
+        resolved: true,
+        resolved_ts: ts.into(),
+        target,
+        data,
+        ts,
+      });
     }
   }
   if alerts.is_empty() {
@@ -1,9 +1,15 @@
-use std::collections::HashMap;
+use std::{
+  collections::{HashMap, HashSet},
+  sync::{Mutex, OnceLock},
+};
 
 use anyhow::Context;
 use komodo_client::entities::{
-  permission::PermissionLevel, resource::ResourceQuery,
-  server::Server, user::User,
+  docker::container::{ContainerListItem, HealthStatusEnum},
+  permission::PermissionLevel,
+  resource::ResourceQuery,
+  server::Server,
+  user::User,
 };
 
 use crate::resource;
@@ -58,3 +64,28 @@ async fn get_all_servers_map()
 
   Ok((servers, server_names))
 }
+
+/// (ResourceId, ContainerName) of containers already alerted as unhealthy
+fn unhealthy_alert_sent_cache()
+-> &'static Mutex<HashSet<(String, String)>> {
+  static CACHE: OnceLock<Mutex<HashSet<(String, String)>>> =
+    OnceLock::new();
+  CACHE.get_or_init(Default::default)
+}
+
+/// Whether the container has become unhealthy since the last check.
+/// Containers without a healthcheck have no health,
+/// so they never trigger this.
+fn container_became_unhealthy(
+  resource_id: &str,
+  container: &ContainerListItem,
+) -> bool {
+  let key = (resource_id.to_string(), container.name.clone());
+  let mut cache = unhealthy_alert_sent_cache().lock().unwrap();
+  if matches!(container.health, Some(HealthStatusEnum::Unhealthy)) {
+    cache.insert(key)
+  } else {
+    cache.remove(&key);
+    false
+  }
+}
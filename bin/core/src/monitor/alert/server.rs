@@ -80,14 +80,14 @@ pub async fn alert_servers(
 ) {
   let server_statuses = server_status_cache().get_list().await;
 
-  let (open_alerts, open_disk_alerts) = match get_open_alerts().await
-  {
-    Ok(alerts) => alerts,
-    Err(e) => {
-      error!("{e:#}");
-      return;
-    }
-  };
+  let (open_alerts, open_disk_alerts, open_inode_alerts) =
+    match get_open_alerts().await {
+      Ok(alerts) => alerts,
+      Err(e) => {
+        error!("{e:#}");
+        return;
+      }
+    };
 
   let mut alerts_to_open = Vec::<(Alert, SendAlerts)>::new();
   let mut alerts_to_update = Vec::<(Alert, SendAlerts)>::new();
@@ -538,6 +538,99 @@ pub async fn alert_servers(
         }
       }
     }
+
+    // ===================
+    // SERVER INODES
+    // ===================
+
+    let server_inode_alerts = open_inode_alerts
+      .get(&ResourceTarget::Server(server_status.id.clone()));
+
+    let inode_data = |path: &PathBuf| {
+      let disk = server_status.stats.as_ref().and_then(|stats| {
+        stats.disks.iter().find(|disk| disk.mount == *path)
+      });
+      AlertData::ServerInodes {
+        id: server_status.id.clone(),
+        name: server.name.clone(),
+        region: optional_string(&server.config.region),
+        path: path.to_owned(),
+        used: disk.map(|d| d.inodes_used).unwrap_or_default(),
+        total: disk.map(|d| d.inodes_total).unwrap_or_default(),
+      }
+    };
+
+    for (path, health) in &health.inodes {
+      let inode_alert = server_inode_alerts
+        .as_ref()
+        .and_then(|alerts| alerts.get(path))
+        .cloned();
+      match (health.level, inode_alert, health.should_close_alert) {
+        (
+          SeverityLevel::Warning | SeverityLevel::Critical,
+          None,
+          _,
+        ) => {
+          // Only open inode alert if not in maintenance and buffer is ready
+          if !in_maintenance
+            && buffer.ready_to_open(
+              server_status.id.clone(),
+              AlertDataVariant::ServerInodes,
+            )
+          {
+            let alert = Alert {
+              id: Default::default(),
+              ts,
+              resolved: false,
+              resolved_ts: None,
+              level: health.level,
+              target: ResourceTarget::Server(
+                server_status.id.clone(),
+              ),
+              data: inode_data(path),
+            };
+            alerts_to_open
+              .push((alert, server.config.send_disk_alerts));
+          }
+        }
+        (
+          SeverityLevel::Warning | SeverityLevel::Critical,
+          Some(mut alert),
+          _,
+        ) => {
+          // modify alert level only if it has increased and not in maintenance
+          if !in_maintenance && alert.level < health.level {
+            alert.level = health.level;
+            alert.data = inode_data(path);
+            alerts_to_update
+              .push((alert, server.config.send_disk_alerts));
+          }
+        }
+        (SeverityLevel::Ok, Some(mut alert), true) => {
+          alert.level = health.level;
+          alert.data = inode_data(path);
+          alert_ids_to_close
+            .push((alert, server.config.send_disk_alerts))
+        }
+        (SeverityLevel::Ok, _, _) => buffer.reset(
+          server_status.id.clone(),
+          AlertDataVariant::ServerInodes,
+        ),
+      }
+    }
+
+    // Close any open ones on disks no longer reported,
+    // or if inode thresholds have been disabled.
+    if let Some(inode_alerts) = server_inode_alerts {
+      for (path, alert) in inode_alerts {
+        if !health.inodes.contains_key(path) {
+          let mut alert = alert.clone();
+          alert.level = SeverityLevel::Ok;
+          alert_ids_to_close
+            .push((alert, server.config.send_disk_alerts));
+        }
+      }
+    }
   }
 
   tokio::join!(
@@ -702,7 +795,8 @@ async fn resolve_alerts(alerts: &[(Alert, SendAlerts)]) {
 
 #[instrument(level = "debug")]
 async fn get_open_alerts()
--> anyhow::Result<(OpenAlertMap, OpenDiskAlertMap)> {
+-> anyhow::Result<(OpenAlertMap, OpenDiskAlertMap, OpenDiskAlertMap)>
+{
   let alerts = find_collect(
     &db_client().alerts,
     doc! { "resolved": false },
@@ -713,6 +807,7 @@ async fn get_open_alerts()
 
   let mut map = OpenAlertMap::new();
   let mut disk_map = OpenDiskAlertMap::new();
+  let mut inode_map = OpenDiskAlertMap::new();
 
   for alert in alerts {
     match &alert.data {
@@ -720,6 +815,11 @@ async fn get_open_alerts()
         let inner = disk_map.entry(alert.target.clone()).or_default();
         inner.insert(path.to_owned(), alert);
       }
+      AlertData::ServerInodes { path, .. } => {
+        let inner =
+          inode_map.entry(alert.target.clone()).or_default();
+        inner.insert(path.to_owned(), alert);
+      }
       _ => {
         let inner = map.entry(alert.target.clone()).or_default();
         inner.insert(alert.data.extract_variant(), alert);
@@ -727,5 +827,5 @@ async fn get_open_alerts()
     }
   }
 
-  Ok((map, disk_map))
+  Ok((map, disk_map, inode_map))
 }
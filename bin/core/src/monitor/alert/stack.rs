@@ -1,11 +1,12 @@
 use std::collections::HashMap;
 
 use komodo_client::entities::{
-  ResourceTarget,
+  ResourceTarget, ResourceTargetVariant,
   alert::{Alert, AlertData, SeverityLevel},
   stack::{Stack, StackState},
 };
 
+use super::container_became_unhealthy;
 use crate::{
   alert::send_alerts,
   resource,
@@ -47,40 +48,80 @@ pub async fn alert_stacks(
       continue;
     }
 
-    if status.curr.state != prev {
-      // send alert
-      let Ok(stack) =
-        resource::get::<Stack>(&status.curr.id).await.inspect_err(
-          |e| error!("failed to get stack from db | {e:#?}"),
-        )
-      else {
-        continue;
-      };
-      if !stack.config.send_alerts {
-        continue;
-      }
-      let target: ResourceTarget = (&stack).into();
+    let state_changed = status.curr.state != prev;
+    // Collect first so the unhealthy cache is updated
+    // for every service container.
+    let unhealthy_services = status
+      .curr
+      .services
+      .iter()
+      .filter_map(|service| {
+        let container = service.container.as_ref()?;
+        container_became_unhealthy(&status.curr.id, container)
+          .then_some((service, container))
+      })
+      .collect::<Vec<_>>();
+
+    if !state_changed && unhealthy_services.is_empty() {
+      continue;
+    }
+
+    // send alert
+    let Ok(stack) =
+      resource::get::<Stack>(&status.curr.id).await.inspect_err(
+        |e| error!("failed to get stack from db | {e:#?}"),
+      )
+    else {
+      continue;
+    };
+    if !stack.config.send_alerts {
+      continue;
+    }
+    let target: ResourceTarget = (&stack).into();
+    let server_name = server_names
+      .get(&stack.config.server_id)
+      .cloned()
+      .unwrap_or(String::from("unknown"));
+
+    if state_changed {
       let data = AlertData::StackStateChange {
         id: status.curr.id.clone(),
-        name: stack.name,
-        server_name: server_names
-          .get(&stack.config.server_id)
-          .cloned()
-          .unwrap_or(String::from("unknown")),
-        server_id: stack.config.server_id,
+        name: stack.name.clone(),
+        server_name: server_name.clone(),
+        server_id: stack.config.server_id.clone(),
         from: prev,
         to: status.curr.state,
       };
-      let alert = Alert {
+      alerts.push(Alert {
         id: Default::default(),
         level: SeverityLevel::Warning,
         resolved: true,
         resolved_ts: ts.into(),
-        target,
+        target: target.clone(),
         data,
         ts,
+      });
+    }
+
+    for (service, container) in unhealthy_services {
+      let data = AlertData::ContainerUnhealthy {
+        id: status.curr.id.clone(),
+        name: stack.name.clone(),
+        resource_type: ResourceTargetVariant::Stack,
+        server_id: stack.config.server_id.clone(),
+        server_name: server_name.clone(),
+        container: container.name.clone(),
+        service: Some(service.service.clone()),
       };
-      alerts.push(alert);
+      alerts.push(Alert {
+        id: Default::default(),
+        level: SeverityLevel::Warning,
+        resolved: true,
+        resolved_ts: ts.into(),
+        target: target.clone(),
+        data,
+        ts,
+      });
     }
   }
   if alerts.is_empty() {
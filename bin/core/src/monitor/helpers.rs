@@ -149,6 +149,8 @@ fn get_server_health(
     mem_critical,
     disk_warning,
     disk_critical,
+    inode_warning,
+    inode_critical,
     ..
   } = &server.config;
   let mut health = ServerHealth::default();
@@ -176,9 +178,32 @@ fn get_server_health(
     mount,
     used_gb,
     total_gb,
+    inodes_used,
+    inodes_total,
     ..
   } in disks
   {
+    if (*inode_warning > 0.0 || *inode_critical > 0.0)
+      && *inodes_total > 0
+    {
+      let perc = 100.0 * *inodes_used as f64 / *inodes_total as f64;
+      // Close below the lowest enabled threshold
+      let lowest = if *inode_warning > 0.0 {
+        *inode_warning
+      } else {
+        *inode_critical
+      };
+      let mut state = ServerHealthState::default();
+      if *inode_critical > 0.0 && perc >= *inode_critical {
+        state.level = SeverityLevel::Critical;
+      } else if *inode_warning > 0.0 && perc >= *inode_warning {
+        state.level = SeverityLevel::Warning;
+      } else if perc < lowest - (ALERT_PERCENTAGE_THRESHOLD as f64) {
+        state.should_close_alert = true;
+      };
+      health.inodes.insert(mount.clone(), state);
+    }
+
     let perc = 100.0 * used_gb / total_gb;
     let mut state = ServerHealthState::default();
     if perc >= *disk_critical {
@@ -195,3 +220,52 @@ fn get_server_health(
 
   health
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn stats() -> SystemStats {
+    SystemStats {
+      cpu_perc: 95.0,
+      mem_used_gb: 8.0,
+      mem_total_gb: 10.0,
+      disks: vec![SingleDiskUsage {
+        mount: "/".into(),
+        file_system: "ext4".into(),
+        used_gb: 80.0,
+        total_gb: 100.0,
+        inodes_used: 0,
+        inodes_total: 0,
+      }],
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn default_thresholds_fire() {
+    let health = get_server_health(&Server::default(), &stats());
+    assert_eq!(health.cpu.level, SeverityLevel::Warning);
+    assert_eq!(health.mem.level, SeverityLevel::Warning);
+    assert_eq!(
+      health.disks.get(std::path::Path::new("/")).unwrap().level,
+      SeverityLevel::Warning
+    );
+  }
+
+  #[test]
+  fn server_thresholds_override_defaults() {
+    let mut server = Server::default();
+    server.config.cpu_warning = 97.0;
+    server.config.cpu_critical = 99.5;
+    server.config.mem_warning = 85.0;
+    server.config.disk_warning = 85.0;
+    let health = get_server_health(&server, &stats());
+    assert_eq!(health.cpu.level, SeverityLevel::Ok);
+    assert_eq!(health.mem.level, SeverityLevel::Ok);
+    assert_eq!(
+      health.disks.get(std::path::Path::new("/")).unwrap().level,
+      SeverityLevel::Ok
+    );
+  }
+}
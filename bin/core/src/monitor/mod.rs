@@ -1,4 +1,7 @@
-use std::sync::{Arc, OnceLock};
+use std::{
+  sync::{Arc, OnceLock},
+  time::Duration,
+};
 
 use async_timing_util::wait_until_timelength;
 use database::mungos::{find::find_collect, mongodb::bson::doc};
@@ -112,11 +115,11 @@ async fn refresh_server_cache(ts: i64) {
         return;
       }
     };
-  let futures = servers.into_iter().map(|server| async move {
-    update_cache_for_server(&server, false).await;
+  let futures = servers.iter().map(|server| async move {
+    update_cache_for_server(server, false).await;
   });
   join_all(futures).await;
-  tokio::join!(check_alerts(ts), record_server_stats(ts));
+  tokio::join!(check_alerts(ts), record_server_stats(ts, &servers));
 }
 
 /// Makes sure cache for server doesn't update too frequently / simultaneously.
@@ -202,27 +205,37 @@ pub async fn update_cache_for_server(server: &Server, force: bool) {
     return;
   };
 
-  let version = match periphery.request(api::GetVersion {}).await {
-    Ok(version) => version.version,
-    Err(e) => {
-      insert_deployments_status_unknown(deployments).await;
-      insert_stacks_status_unknown(stacks).await;
-      insert_repos_status_unknown(repos).await;
-      insert_server_status(
-        server,
-        ServerState::NotOk,
-        String::from("Unknown"),
-        None,
-        (None, None, None, None, None),
-        Serror::from(&e),
-      )
-      .await;
-      return;
-    }
-  };
+  // Quick requests used to determine server health are bounded
+  // by the server timeout, so hanging servers are reported quickly.
+  let health_periphery = periphery.clone().with_request_timeout(
+    Duration::from_secs(server.config.timeout_seconds as u64),
+  );
+
+  let version =
+    match health_periphery.request(api::GetVersion {}).await {
+      Ok(version) => version.version,
+      Err(e) => {
+        insert_deployments_status_unknown(deployments).await;
+        insert_stacks_status_unknown(stacks).await;
+        insert_repos_status_unknown(repos).await;
+        insert_server_status(
+          server,
+          ServerState::NotOk,
+          String::from("Unknown"),
+          None,
+          (None, None, None, None, None),
+          Serror::from(&e),
+        )
+        .await;
+        return;
+      }
+    };
 
   let stats = if server.config.stats_monitoring {
-    match periphery.request(api::stats::GetSystemStats {}).await {
+    match health_periphery
+      .request(api::stats::GetSystemStats {})
+      .await
+    {
       Ok(stats) => Some(filter_volumes(server, stats)),
       Err(e) => {
         insert_deployments_status_unknown(deployments).await;
@@ -249,6 +262,10 @@ pub async fn update_cache_for_server(server: &Server, force: bool) {
       containers.iter_mut().for_each(|container| {
         container.server_id = Some(server.id.clone())
       });
+      resources::update_stack_services_info(
+        &periphery, &stacks, &projects,
+      )
+      .await;
       tokio::join!(
         resources::update_deployment_cache(
           server.name.clone(),
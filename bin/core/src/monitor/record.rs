@@ -1,11 +1,23 @@
-use komodo_client::entities::stats::{
-  SystemStatsRecord, TotalDiskUsage, sum_disk_usage,
+use std::collections::HashMap;
+
+use komodo_client::entities::{
+  server::Server,
+  stats::{
+    SingleNetworkInterfaceUsage, SystemStats, SystemStatsRecord,
+    TotalDiskUsage, sum_disk_usage,
+  },
 };
 
 use crate::state::{db_client, server_status_cache};
 
-#[instrument(level = "debug")]
-pub async fn record_server_stats(ts: i64) {
+#[instrument(level = "debug", skip(servers))]
+pub async fn record_server_stats(ts: i64, servers: &[Server]) {
+  let network_interfaces = servers
+    .iter()
+    .map(|server| {
+      (server.id.as_str(), server.config.network_interface.as_str())
+    })
+    .collect::<HashMap<_, _>>();
   let status = server_status_cache().get_list().await;
   let records = status
     .into_iter()
@@ -17,6 +29,18 @@ pub async fn record_server_stats(ts: i64) {
         total_gb: disk_total_gb,
       } = sum_disk_usage(&stats.disks);
 
+      let (
+        network_ingress_bytes_per_sec,
+        network_egress_bytes_per_sec,
+        network_interface,
+      ) = headline_network_rates(
+        stats,
+        network_interfaces
+          .get(status.id.as_str())
+          .copied()
+          .unwrap_or_default(),
+      );
+
       Some(SystemStatsRecord {
         ts,
         sid: status.id.clone(),
@@ -29,6 +53,9 @@ pub async fn record_server_stats(ts: i64) {
         disks: stats.disks.clone(),
         network_ingress_bytes: stats.network_ingress_bytes,
         network_egress_bytes: stats.network_egress_bytes,
+        network_ingress_bytes_per_sec,
+        network_egress_bytes_per_sec,
+        network_interfaces: network_interface.into_iter().collect(),
       })
     })
     .collect::<Vec<_>>();
@@ -39,3 +66,72 @@ pub async fn record_server_stats(ts: i64) {
     }
   }
 }
+
+/// Uses the server's configured network interface for the headline
+/// rates if it reports it, otherwise the rates over all interfaces.
+/// Only the selected interface is returned to be stored,
+/// to keep the stats history small.
+fn headline_network_rates(
+  stats: &SystemStats,
+  network_interface: &str,
+) -> (f64, f64, Option<SingleNetworkInterfaceUsage>) {
+  match stats.network_interfaces.iter().find(|network| {
+    !network_interface.is_empty() && network.name == network_interface
+  }) {
+    Some(network) => (
+      network.ingress_bytes_per_sec,
+      network.egress_bytes_per_sec,
+      Some(network.clone()),
+    ),
+    None => (
+      stats.network_ingress_bytes_per_sec,
+      stats.network_egress_bytes_per_sec,
+      None,
+    ),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn stats() -> SystemStats {
+    SystemStats {
+      network_ingress_bytes_per_sec: 300.0,
+      network_egress_bytes_per_sec: 30.0,
+      network_interfaces: vec![
+        SingleNetworkInterfaceUsage {
+          name: String::from("eth0"),
+          ingress_bytes_per_sec: 100.0,
+          egress_bytes_per_sec: 10.0,
+          ..Default::default()
+        },
+        SingleNetworkInterfaceUsage {
+          name: String::from("eth1"),
+          ingress_bytes_per_sec: 200.0,
+          egress_bytes_per_sec: 20.0,
+          ..Default::default()
+        },
+      ],
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn uses_configured_interface() {
+    let (ingress, egress, network) =
+      headline_network_rates(&stats(), "eth1");
+    assert_eq!((ingress, egress), (200.0, 20.0));
+    assert_eq!(network.unwrap().name, "eth1");
+  }
+
+  #[test]
+  fn falls_back_to_all_interfaces() {
+    for interface in ["", "wlan0"] {
+      let (ingress, egress, network) =
+        headline_network_rates(&stats(), interface);
+      assert_eq!((ingress, egress), (300.0, 30.0));
+      assert!(network.is_none());
+    }
+  }
+}
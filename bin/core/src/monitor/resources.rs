@@ -4,6 +4,11 @@ use std::{
 };
 
 use anyhow::Context;
+use database::mungos::{
+  by_id::update_one_by_id,
+  mongodb::bson::{doc, to_bson},
+};
+use futures::{StreamExt, stream};
 use komodo_client::{
   api::execute::{Deploy, DeployStack},
   entities::{
@@ -16,11 +21,18 @@ use komodo_client::{
       image::ImageListItem,
     },
     komodo_timestamp,
-    stack::{Stack, StackService, StackServiceNames, StackState},
+    stack::{
+      ComposeProject, Stack, StackService, StackServiceNames,
+      StackState,
+    },
     user::auto_redeploy_user,
   },
 };
 
+use periphery_client::{
+  PeripheryClient, api::compose::ListComposeServices,
+};
+
 use crate::{
   alert::send_alerts,
   api::execute::{self, ExecuteRequest},
@@ -425,3 +437,70 @@ pub async fn update_stack_cache(
       .await;
   }
 }
+
+/// Max number of concurrent `docker compose ps` calls
+/// made to a single server.
+const MAX_CONCURRENT_SERVICES_REQUESTS: usize = 5;
+
+/// Refreshes `info.services` for the stacks on the server,
+/// using `docker compose ps` on the host.
+pub async fn update_stack_services_info(
+  periphery: &PeripheryClient,
+  stacks: &[Stack],
+  projects: &[ComposeProject],
+) {
+  stream::iter(stacks)
+    .for_each_concurrent(MAX_CONCURRENT_SERVICES_REQUESTS, |stack| {
+      update_one_stack_services_info(periphery, stack, projects)
+    })
+    .await
+}
+
+async fn update_one_stack_services_info(
+  periphery: &PeripheryClient,
+  stack: &Stack,
+  projects: &[ComposeProject],
+) {
+  let project = stack.project_name(false);
+  let services = if projects.iter().any(|p| p.name == project) {
+    match periphery.request(ListComposeServices { project }).await {
+      Ok(services) => services,
+      Err(e) => {
+        warn!(
+          "Failed to list services for Stack {} | {e:#}",
+          stack.name
+        );
+        return;
+      }
+    }
+  } else {
+    Vec::new()
+  };
+  // Avoid writing to db when nothing changed.
+  if services == stack.info.services {
+    return;
+  }
+  let services = match to_bson(&services) {
+    Ok(services) => services,
+    Err(e) => {
+      warn!(
+        "Failed to serialize Stack {} services to bson | {e:#}",
+        stack.name
+      );
+      return;
+    }
+  };
+  if let Err(e) = update_one_by_id(
+    &db_client().stacks,
+    &stack.id,
+    doc! { "$set": { "info.services": services } },
+    None,
+  )
+  .await
+  {
+    warn!(
+      "Failed to update Stack {} services info on db | {e:#}",
+      stack.name
+    );
+  }
+}
@@ -11,6 +11,7 @@ use komodo_client::{
     permission::{PermissionLevel, PermissionLevelAndSpecifics},
     resource::Resource,
     user::User,
+    user_group::UserGroup,
   },
 };
 use resolver_api::Resolve;
@@ -138,6 +139,9 @@ pub fn get_user_permission_on_resource<'a, T: KomodoResource>(
       }
     }
 
+    // Overlay any user groups tag permissions, for tags on the resource
+    overlay_tag_permissions(&mut permission, &groups, &resource.tags);
+
     // Overlay any specific permissions
     let permission = find_collect(
       &db_client().permissions,
@@ -192,7 +196,10 @@ pub async fn get_resource_ids_for_user<T: KomodoResource>(
     }
   }
 
-  let (base, perms) = tokio::try_join!(
+  // Tags which give the user groups access to resources carrying them
+  let tag_ids = granting_tag_ids(&groups);
+
+  let (base, perms, tagged) = tokio::try_join!(
     // Get any resources with non-none base permission,
     find_collect(
       T::coll(),
@@ -215,7 +222,18 @@ pub async fn get_resource_ids_for_user<T: KomodoResource>(
       },
       None,
     )
-    .map(|res| res.context("failed to query permissions on db"))
+    .map(|res| res.context("failed to query permissions on db")),
+    // And any resources with tags the user groups have permission on
+    async move {
+      if tag_ids.is_empty() {
+        return anyhow::Ok(Vec::new());
+      }
+      find_collect(T::coll(), doc! { "tags": { "$in": tag_ids } }, None)
+        .await
+        .with_context(|| {
+          format!("failed to query tagged {resource_type} on db")
+        })
+    }
   )?;
 
   // Add specific ids
@@ -224,8 +242,101 @@ pub async fn get_resource_ids_for_user<T: KomodoResource>(
     .map(|p| p.resource_target.extract_variant_id().1.to_string())
     // Chain in the ones with non-None base permissions
     .chain(base.into_iter().map(|res| res.id))
+    // Chain in the ones with tags granting permission
+    .chain(tagged.into_iter().map(|res| res.id))
     // collect into hashset first to remove any duplicates
     .collect::<HashSet<_>>();
 
   Ok(Some(ids.into_iter().collect()))
 }
+
+/// Raises the permission with the user groups tag permissions,
+/// for the tags on the resource.
+fn overlay_tag_permissions(
+  permission: &mut PermissionLevelAndSpecifics,
+  groups: &[UserGroup],
+  resource_tags: &[String],
+) {
+  for group in groups {
+    for (tag_id, tag_permission) in &group.tags {
+      if !resource_tags.contains(tag_id) {
+        continue;
+      }
+      if tag_permission.level > permission.level {
+        permission.level = tag_permission.level;
+      }
+      permission
+        .specific
+        .extend(tag_permission.specific.iter().cloned());
+    }
+  }
+}
+
+/// The tag ids giving the user groups any access
+/// to the resources carrying them.
+fn granting_tag_ids(groups: &[UserGroup]) -> Vec<String> {
+  groups
+    .iter()
+    .flat_map(|group| {
+      group
+        .tags
+        .iter()
+        .filter(|(_, permission)| {
+          permission.level > PermissionLevel::None
+        })
+        .map(|(tag_id, _)| tag_id.clone())
+    })
+    .collect::<HashSet<_>>()
+    .into_iter()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn group() -> UserGroup {
+    let mut group = UserGroup::default();
+    group
+      .tags
+      .insert(String::from("prod"), PermissionLevel::Execute.into());
+    group
+      .tags
+      .insert(String::from("unused"), PermissionLevel::None.into());
+    group
+  }
+
+  #[test]
+  fn tagging_resource_grants_group_permission() {
+    let groups = [group()];
+    let mut resource_tags = vec![String::from("dev")];
+
+    let mut permission = PermissionLevelAndSpecifics::default();
+    overlay_tag_permissions(&mut permission, &groups, &resource_tags);
+    assert_eq!(permission.level, PermissionLevel::None);
+
+    // Tagging the resource makes it visible on the next check
+    resource_tags.push(String::from("prod"));
+    let mut permission = PermissionLevelAndSpecifics::default();
+    overlay_tag_permissions(&mut permission, &groups, &resource_tags);
+    assert_eq!(permission.level, PermissionLevel::Execute);
+  }
+
+  #[test]
+  fn tag_permission_does_not_lower_level() {
+    let mut permission: PermissionLevelAndSpecifics =
+      PermissionLevel::Write.into();
+    overlay_tag_permissions(
+      &mut permission,
+      &[group()],
+      &[String::from("prod")],
+    );
+    assert_eq!(permission.level, PermissionLevel::Write);
+  }
+
+  #[test]
+  fn only_granting_tags_are_queried() {
+    assert_eq!(granting_tag_ids(&[group(), group()]), ["prod"]);
+    assert!(granting_tag_ids(&[UserGroup::default()]).is_empty());
+  }
+}
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use anyhow::Context;
+use anyhow::{Context, anyhow};
 use database::mungos::{
   find::find_collect,
   mongodb::{Collection, bson::doc, options::FindOptions},
@@ -26,12 +26,14 @@ use komodo_client::{
   },
 };
 use resolver_api::Resolve;
+use sha2::{Digest, Sha256};
 
 use crate::{
   api::write::WriteArgs,
   config::core_config,
   helpers::{
-    empty_or_only_spaces, query::get_latest_update, repo_link,
+    builder::check_builder_buildx, empty_or_only_spaces,
+    query::get_latest_update, repo_link,
   },
   permission::get_check_permissions,
   state::{
@@ -123,6 +125,7 @@ impl super::KomodoResource for Build {
           .and_then(|r| optional_string(&r.domain)),
         built_hash: build.info.built_hash,
         latest_hash: build.info.latest_hash,
+        needs_rebuild: build.info.needs_rebuild,
         state,
       },
     }
@@ -153,7 +156,15 @@ impl super::KomodoResource for Build {
     config: &mut Self::PartialConfig,
     user: &User,
   ) -> anyhow::Result<()> {
-    validate_config(config, user).await
+    validate_config(config, user).await?;
+    if config
+      .platforms
+      .as_ref()
+      .is_some_and(|platforms| !platforms.is_empty())
+    {
+      validate_multi_platform(config).await?;
+    }
+    Ok(())
   }
 
   async fn post_create(
@@ -291,9 +302,110 @@ async fn validate_config(
     environment_vars_from_str(secret_args)
       .context("Invalid secret_args")?;
   }
+  if let Some(build_secrets) = &config.build_secrets {
+    for secret in build_secrets {
+      if secret.id.is_empty() {
+        return Err(anyhow!("Build secret id cannot be empty"));
+      }
+      if secret.id.contains(['=', ',', '/']) {
+        return Err(anyhow!(
+          "Invalid build secret id '{}', cannot contain '=', ',' or '/'",
+          secret.id
+        ));
+      }
+    }
+  }
   if let Some(extra_args) = &mut config.extra_args {
     extra_args.retain(|v| !empty_or_only_spaces(v))
   }
+  if let Some(cache_from) = &mut config.cache_from {
+    validate_cache_options("cache_from", cache_from)?;
+  }
+  if let Some(cache_to) = &mut config.cache_to {
+    validate_cache_options("cache_to", cache_to)?;
+  }
+  if let Some(build_contexts) = &mut config.build_contexts {
+    build_contexts.retain(|v| !empty_or_only_spaces(v));
+    for context in build_contexts.iter() {
+      if !context
+        .split_once('=')
+        .is_some_and(|(name, _)| !name.trim().is_empty())
+      {
+        return Err(anyhow!(
+          "Invalid build context '{context}', must be like 'name=value'"
+        ));
+      }
+    }
+  }
+  if let Some(platforms) = &mut config.platforms {
+    platforms.retain(|v| !empty_or_only_spaces(v));
+    for platform in platforms.iter() {
+      // eg 'linux/amd64' or 'linux/arm/v7'
+      let parts = platform.split('/').collect::<Vec<_>>();
+      if !(2..=3).contains(&parts.len())
+        || parts.iter().any(|part| part.trim().is_empty())
+      {
+        return Err(anyhow!(
+          "Invalid platform '{platform}', must be like 'os/arch' or 'os/arch/variant'"
+        ));
+      }
+    }
+  }
+  Ok(())
+}
+
+/// Sha256 hex digest of the dockerfile contents,
+/// used to detect dockerfile changes since the last build.
+pub fn dockerfile_hash(contents: &str) -> String {
+  hex::encode(Sha256::digest(contents.as_bytes()))
+}
+
+/// Cache options are comma separated key=value pairs
+/// which must include the cache `type`,
+/// eg 'type=inline' or 'type=registry,ref=registry.example.com/app:cache'.
+fn validate_cache_options(
+  field: &str,
+  options: &mut Vec<String>,
+) -> anyhow::Result<()> {
+  options.retain(|v| !empty_or_only_spaces(v));
+  for option in options.iter() {
+    if option.contains(char::is_whitespace) {
+      return Err(anyhow!(
+        "Invalid {field} '{option}', cannot contain whitespace"
+      ));
+    }
+    let has_type = option.split(',').any(|part| {
+      part.strip_prefix("type=").is_some_and(|t| !t.is_empty())
+    });
+    if !has_type {
+      return Err(anyhow!(
+        "Invalid {field} '{option}', must include the cache type, eg 'type=registry,ref=...'"
+      ));
+    }
+  }
+  Ok(())
+}
+
+/// Multi-platform builds are pushed directly to the registry using buildx,
+/// so require an image registry and buildx available on the builder.
+async fn validate_multi_platform(
+  config: &PartialBuildConfig,
+) -> anyhow::Result<()> {
+  if !config.image_registry.as_ref().is_some_and(|registries| {
+    registries
+      .iter()
+      .any(|registry| !registry.domain.is_empty())
+  }) {
+    return Err(anyhow!(
+      "Multi-platform builds must push to an image registry"
+    ));
+  }
+  if let Some(builder_id) = &config.builder_id
+    && !builder_id.is_empty()
+  {
+    let builder = crate::resource::get::<Builder>(builder_id).await?;
+    check_builder_buildx(&builder).await?;
+  }
   Ok(())
 }
 
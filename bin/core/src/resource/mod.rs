@@ -20,7 +20,7 @@ use komodo_client::{
   api::{read::ExportResourcesToToml, write::CreateTag},
   entities::{
     Operation, ResourceTarget, ResourceTargetVariant,
-    komodo_timestamp,
+    SearchCombinator, komodo_timestamp,
     permission::{
       PermissionLevel, PermissionLevelAndSpecifics,
       SpecificPermission,
@@ -59,14 +59,17 @@ mod procedure;
 mod refresh;
 mod repo;
 mod server;
+mod server_group;
 mod stack;
 mod sync;
+mod variable;
 
 pub use action::{
   refresh_action_state_cache, spawn_action_state_refresh_loop,
 };
 pub use build::{
-  refresh_build_state_cache, spawn_build_state_refresh_loop,
+  dockerfile_hash, refresh_build_state_cache,
+  spawn_build_state_refresh_loop,
 };
 pub use procedure::{
   refresh_procedure_state_cache, spawn_procedure_state_refresh_loop,
@@ -79,6 +82,7 @@ pub use refresh::{
 pub use repo::{
   refresh_repo_state_cache, spawn_repo_state_refresh_loop,
 };
+pub use variable::spawn_variable_references_refresh_loop;
 
 /// Implement on each Komodo resource for common methods
 pub trait KomodoResource {
@@ -316,16 +320,19 @@ pub async fn list_for_user_using_document<T: KomodoResource>(
 }
 
 /// Lists full resource matching wildcard syntax,
-/// or regex if wrapped with "\\"
+/// or regex if wrapped with "\\".
+/// With [SearchCombinator::And], resources must match all the patterns,
+/// otherwise they need only match one.
 ///
 /// ## Example
 /// ```
-/// let items = list_full_for_user_using_match_string::<Build>("foo-*", Default::default(), user, all_tags).await?;
-/// let items = list_full_for_user_using_match_string::<Build>("\\^foo-.*$\\", Default::default(), user, all_tags).await?;
+/// let items = list_full_for_user_using_match_string::<Build>("foo-*", SearchCombinator::Or, Default::default(), user, all_tags).await?;
+/// let items = list_full_for_user_using_match_string::<Build>("\\^foo-.*$\\", SearchCombinator::Or, Default::default(), user, all_tags).await?;
 /// ```
 #[instrument(level = "debug")]
 pub async fn list_full_for_user_using_pattern<T: KomodoResource>(
   pattern: &str,
+  combinator: SearchCombinator,
   query: ResourceQuery<T::QuerySpecifics>,
   user: &User,
   permissions: PermissionLevelAndSpecifics,
@@ -335,33 +342,68 @@ pub async fn list_full_for_user_using_pattern<T: KomodoResource>(
     list_full_for_user::<T>(query, user, permissions, all_tags)
       .await?;
 
+  let names = names_matching_patterns(
+    resources.iter().map(|resource| resource.name.as_str()),
+    pattern,
+    combinator,
+  )?
+  .into_iter()
+  .map(str::to_string)
+  .collect::<HashSet<_>>();
+
+  Ok(
+    resources
+      .into_iter()
+      .filter(|resource| names.contains(&resource.name))
+      .collect(),
+  )
+}
+
+/// The names matching the wildcard / regex patterns,
+/// combined using the combinator.
+fn names_matching_patterns<'a>(
+  names: impl IntoIterator<Item = &'a str>,
+  pattern: &str,
+  combinator: SearchCombinator,
+) -> anyhow::Result<Vec<&'a str>> {
+  let names = names.into_iter().collect::<Vec<_>>();
   let patterns = parse_string_list(pattern);
-  let mut names = HashSet::<String>::new();
+  let patterns_len = patterns.len();
+  // Name -> number of patterns it matched
+  let mut matches = HashMap::<&str, usize>::new();
 
   for pattern in patterns {
     if pattern.starts_with('\\') && pattern.ends_with('\\') {
       let regex = regex::Regex::new(&pattern[1..(pattern.len() - 1)])
         .context("Regex matching string invalid")?;
-      for resource in &resources {
-        if regex.is_match(&resource.name) {
-          names.insert(resource.name.clone());
+      for name in &names {
+        if regex.is_match(name) {
+          *matches.entry(*name).or_default() += 1;
         }
       }
     } else {
       let wildcard = wildcard::Wildcard::new(pattern.as_bytes())
         .context("Wildcard matching string invalid")?;
-      for resource in &resources {
-        if wildcard.is_match(resource.name.as_bytes()) {
-          names.insert(resource.name.clone());
+      for name in &names {
+        if wildcard.is_match(name.as_bytes()) {
+          *matches.entry(*name).or_default() += 1;
         }
       }
     };
   }
 
   Ok(
-    resources
+    names
       .into_iter()
-      .filter(|resource| names.contains(resource.name.as_str()))
+      .filter(|name| {
+        let Some(matched) = matches.get(name) else {
+          return false;
+        };
+        match combinator {
+          SearchCombinator::Or => true,
+          SearchCombinator::And => *matched == patterns_len,
+        }
+      })
       .collect(),
   )
 }
@@ -685,6 +727,9 @@ fn resource_target<T: KomodoResource>(id: String) -> ResourceTarget {
     }
     ResourceTargetVariant::Stack => ResourceTarget::Stack(id),
     ResourceTargetVariant::Action => ResourceTarget::Action(id),
+    ResourceTargetVariant::ServerGroup => {
+      ResourceTarget::ServerGroup(id)
+    }
   }
 }
 
@@ -966,6 +1011,7 @@ where
     ResourceTarget::Builder(id) => ("recents.Builder", id),
     ResourceTarget::Alerter(id) => ("recents.Alerter", id),
     ResourceTarget::ResourceSync(id) => ("recents.ResourceSync", id),
+    ResourceTarget::ServerGroup(id) => ("recents.ServerGroup", id),
     ResourceTarget::System(_) => return,
   };
   if let Err(e) = db_client()
@@ -984,3 +1030,53 @@ where
     warn!("{e:#}");
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const NAMES: [&str; 4] =
+    ["api-prod", "api-dev", "web-prod", "web-dev"];
+
+  fn matching(
+    pattern: &str,
+    combinator: SearchCombinator,
+  ) -> Vec<&str> {
+    names_matching_patterns(NAMES, pattern, combinator).unwrap()
+  }
+
+  #[test]
+  fn or_matches_any_wildcard() {
+    assert_eq!(
+      matching("api-*, *-prod", SearchCombinator::Or),
+      ["api-prod", "api-dev", "web-prod"]
+    );
+  }
+
+  #[test]
+  fn and_matches_all_wildcards() {
+    assert_eq!(
+      matching("api-*, *-prod", SearchCombinator::And),
+      ["api-prod"]
+    );
+  }
+
+  #[test]
+  fn and_applies_to_regex_and_wildcard() {
+    assert_eq!(
+      matching("\\^web-.*$\\\n*-dev", SearchCombinator::And),
+      ["web-dev"]
+    );
+    assert_eq!(
+      matching("\\^web-.*$\\\n*-dev", SearchCombinator::Or),
+      ["api-dev", "web-prod", "web-dev"]
+    );
+  }
+
+  #[test]
+  fn and_with_no_common_match_is_empty() {
+    assert!(
+      matching("api-*, web-*", SearchCombinator::And).is_empty()
+    );
+  }
+}
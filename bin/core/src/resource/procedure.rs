@@ -23,6 +23,7 @@ use komodo_client::{
     repo::Repo,
     resource::Resource,
     server::Server,
+    server_group::ServerGroup,
     stack::Stack,
     sync::ResourceSync,
     update::Update,
@@ -589,6 +590,36 @@ async fn validate_config(
           .await?;
           params.server = server.id;
         }
+        Execution::RunCommandOnGroup(params) => {
+          let server_group =
+            super::get_check_permissions::<ServerGroup>(
+              &params.server_group,
+              user,
+              PermissionLevel::Execute.terminal(),
+            )
+            .await?;
+          params.server_group = server_group.id;
+        }
+        Execution::PruneImagesOnGroup(params) => {
+          let server_group =
+            super::get_check_permissions::<ServerGroup>(
+              &params.server_group,
+              user,
+              PermissionLevel::Execute.into(),
+            )
+            .await?;
+          params.server_group = server_group.id;
+        }
+        Execution::PruneSystemOnGroup(params) => {
+          let server_group =
+            super::get_check_permissions::<ServerGroup>(
+              &params.server_group,
+              user,
+              PermissionLevel::Execute.into(),
+            )
+            .await?;
+          params.server_group = server_group.id;
+        }
         Execution::RunSync(params) => {
           let sync = super::get_check_permissions::<ResourceSync>(
             &params.sync,
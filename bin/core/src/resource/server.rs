@@ -201,6 +201,14 @@ impl super::KomodoResource for Server {
       .await
       .context("failed to detach server from repos")?;
 
+    db.server_groups
+      .update_many(
+        doc! { "config.members": &id },
+        doc! { "$pull": { "config.members": &id } },
+      )
+      .await
+      .context("failed to remove server from server groups")?;
+
     db.alerts
       .update_many(
         doc! { "target.type": "Server", "target.id": &id },
@@ -0,0 +1,160 @@
+use database::mungos::mongodb::Collection;
+use indexmap::IndexSet;
+use komodo_client::entities::{
+  Operation, ResourceTarget, ResourceTargetVariant,
+  permission::{PermissionLevel, SpecificPermission},
+  resource::Resource,
+  server::Server,
+  server_group::{
+    PartialServerGroupConfig, ServerGroup, ServerGroupConfig,
+    ServerGroupConfigDiff, ServerGroupListItem,
+    ServerGroupListItemInfo, ServerGroupQuerySpecifics,
+  },
+  update::Update,
+  user::User,
+};
+
+use crate::{config::core_config, state::db_client};
+
+impl super::KomodoResource for ServerGroup {
+  type Config = ServerGroupConfig;
+  type PartialConfig = PartialServerGroupConfig;
+  type ConfigDiff = ServerGroupConfigDiff;
+  type Info = ();
+  type ListItem = ServerGroupListItem;
+  type QuerySpecifics = ServerGroupQuerySpecifics;
+
+  fn resource_type() -> ResourceTargetVariant {
+    ResourceTargetVariant::ServerGroup
+  }
+
+  fn resource_target(id: impl Into<String>) -> ResourceTarget {
+    ResourceTarget::ServerGroup(id.into())
+  }
+
+  fn creator_specific_permissions() -> IndexSet<SpecificPermission> {
+    [SpecificPermission::Terminal].into_iter().collect()
+  }
+
+  fn coll() -> &'static Collection<Resource<Self::Config, Self::Info>>
+  {
+    &db_client().server_groups
+  }
+
+  async fn to_list_item(
+    server_group: Resource<Self::Config, Self::Info>,
+  ) -> Self::ListItem {
+    ServerGroupListItem {
+      name: server_group.name,
+      id: server_group.id,
+      template: server_group.template,
+      tags: server_group.tags,
+      resource_type: ResourceTargetVariant::ServerGroup,
+      info: ServerGroupListItemInfo {
+        members: server_group.config.members,
+      },
+    }
+  }
+
+  async fn busy(_id: &String) -> anyhow::Result<bool> {
+    Ok(false)
+  }
+
+  // CREATE
+
+  fn create_operation() -> Operation {
+    Operation::CreateServerGroup
+  }
+
+  fn user_can_create(user: &User) -> bool {
+    user.admin
+      || (!core_config().disable_non_admin_create
+        && user.create_server_permissions)
+  }
+
+  async fn validate_create_config(
+    config: &mut Self::PartialConfig,
+    user: &User,
+  ) -> anyhow::Result<()> {
+    validate_config(config, user).await
+  }
+
+  async fn post_create(
+    _created: &Resource<Self::Config, Self::Info>,
+    _update: &mut Update,
+  ) -> anyhow::Result<()> {
+    Ok(())
+  }
+
+  // UPDATE
+
+  fn update_operation() -> Operation {
+    Operation::UpdateServerGroup
+  }
+
+  async fn validate_update_config(
+    _id: &str,
+    config: &mut Self::PartialConfig,
+    user: &User,
+  ) -> anyhow::Result<()> {
+    validate_config(config, user).await
+  }
+
+  async fn post_update(
+    _updated: &Self,
+    _update: &mut Update,
+  ) -> anyhow::Result<()> {
+    Ok(())
+  }
+
+  // RENAME
+
+  fn rename_operation() -> Operation {
+    Operation::RenameServerGroup
+  }
+
+  // DELETE
+
+  fn delete_operation() -> Operation {
+    Operation::DeleteServerGroup
+  }
+
+  async fn pre_delete(
+    _resource: &Resource<Self::Config, Self::Info>,
+    _update: &mut Update,
+  ) -> anyhow::Result<()> {
+    Ok(())
+  }
+
+  async fn post_delete(
+    _resource: &Resource<Self::Config, Self::Info>,
+    _update: &mut Update,
+  ) -> anyhow::Result<()> {
+    Ok(())
+  }
+}
+
+/// Group executions act on every member, so adding a server
+/// requires the user have Write + Terminal permission on it.
+/// Also converts member names to ids.
+#[instrument(skip(user))]
+async fn validate_config(
+  config: &mut PartialServerGroupConfig,
+  user: &User,
+) -> anyhow::Result<()> {
+  let Some(members) = &mut config.members else {
+    return Ok(());
+  };
+  let mut ids = IndexSet::new();
+  for member in members.iter().filter(|member| !member.is_empty()) {
+    let server = super::get_check_permissions::<Server>(
+      member,
+      user,
+      PermissionLevel::Write.terminal(),
+    )
+    .await?;
+    ids.insert(server.id);
+  }
+  *members = ids.into_iter().collect();
+  Ok(())
+}
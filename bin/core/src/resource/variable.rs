@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use database::mungos::find::find_collect;
+
+use crate::state::{
+  all_resources_cache, db_client, variable_references_cache,
+};
+
+pub fn spawn_variable_references_refresh_loop() {
+  tokio::spawn(async move {
+    let mut interval = tokio::time::interval(Duration::from_secs(30));
+    loop {
+      interval.tick().await;
+      refresh_variable_references_cache().await;
+    }
+  });
+}
+
+/// Scans all resource configs for `[[VARIABLE_NAME]]`,
+/// mapping each variable to the resources which use it.
+async fn refresh_variable_references_cache() {
+  let _ = async {
+    let variables = find_collect(&db_client().variables, None, None)
+      .await
+      .context("Failed to get Variables from db")?;
+    let references =
+      all_resources_cache().load().variable_references_by_name(
+        variables.iter().map(|variable| variable.name.as_str()),
+      );
+    variable_references_cache().store(references.into());
+    anyhow::Ok(())
+  }
+  .await
+  .inspect_err(|e| {
+    error!("Failed to refresh variable references cache | {e:#}")
+  });
+}
@@ -1,7 +1,10 @@
+use interpolate::Interpolator;
 use komodo_client::{
   api::execute::*,
   entities::{
+    all_logs_success,
     permission::PermissionLevel,
+    repo::Repo,
     stack::{Stack, StackActionState},
     update::{Log, Update},
     user::User,
@@ -10,7 +13,11 @@ use komodo_client::{
 use periphery_client::{PeripheryClient, api::compose::*};
 
 use crate::{
-  helpers::{periphery_client, update::update_update},
+  helpers::{
+    periphery_client,
+    query::{VariablesAndSecrets, get_variables_and_secrets},
+    update::update_update,
+  },
   monitor::update_cache_for_server,
   state::action_states,
 };
@@ -20,6 +27,17 @@ use super::get_stack_and_server;
 pub trait ExecuteCompose {
   type Extras;
 
+  /// Runs before the compose execution.
+  /// If any of the pushed logs are unsuccessful,
+  /// the compose execution will not be run.
+  async fn pre_execute(
+    _periphery: &PeripheryClient,
+    _stack: &Stack,
+    _logs: &mut Vec<Log>,
+  ) -> anyhow::Result<()> {
+    Ok(())
+  }
+
   async fn execute(
     periphery: PeripheryClient,
     stack: Stack,
@@ -67,9 +85,13 @@ pub async fn execute_compose<T: ExecuteCompose>(
     ))
   }
 
-  update
-    .logs
-    .push(T::execute(periphery, stack, services, extras).await?);
+  T::pre_execute(&periphery, &stack, &mut update.logs).await?;
+
+  if all_logs_success(&update.logs) {
+    update
+      .logs
+      .push(T::execute(periphery, stack, services, extras).await?);
+  }
 
   // Ensure cached stack state up to date by updating server cache
   update_cache_for_server(&server, true).await;
@@ -181,6 +203,60 @@ impl ExecuteCompose for StopStack {
 
 impl ExecuteCompose for DestroyStack {
   type Extras = (Option<i32>, bool);
+
+  async fn pre_execute(
+    periphery: &PeripheryClient,
+    stack: &Stack,
+    logs: &mut Vec<Log>,
+  ) -> anyhow::Result<()> {
+    if stack.config.on_destroy.is_none() {
+      return Ok(());
+    }
+
+    let mut stack = stack.clone();
+    let mut repo = if !stack.config.files_on_host
+      && !stack.config.linked_repo.is_empty()
+    {
+      crate::resource::get::<Repo>(&stack.config.linked_repo)
+        .await?
+        .into()
+    } else {
+      None
+    };
+
+    let secret_replacers = if !stack.config.skip_secret_interp {
+      let VariablesAndSecrets { variables, secrets } =
+        get_variables_and_secrets(Some(&stack.config.server_id))
+          .await?;
+
+      let mut interpolator =
+        Interpolator::new(Some(&variables), &secrets);
+
+      interpolator.interpolate_stack(&mut stack)?;
+      if let Some(repo) = repo.as_mut()
+        && !repo.config.skip_secret_interp
+      {
+        interpolator.interpolate_repo(repo)?;
+      }
+      interpolator.push_logs(logs);
+
+      interpolator.secret_replacers
+    } else {
+      Default::default()
+    };
+
+    let log = periphery
+      .request(ComposeOnDestroy {
+        stack,
+        repo,
+        replacers: secret_replacers.into_iter().collect(),
+      })
+      .await?;
+    logs.push(log);
+
+    Ok(())
+  }
+
   async fn execute(
     periphery: PeripheryClient,
     stack: Stack,
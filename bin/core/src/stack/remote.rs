@@ -54,12 +54,14 @@ pub async fn get_repo_compose_contents(
       Ok(contents) => successful.push(StackRemoteFileContents {
         path: file.path,
         contents,
+        base64_contents: false,
         services: file.services,
         requires: file.requires,
       }),
       Err(e) => errored.push(FileContents {
         path: file.path,
         contents: format_serror(&e.into()),
+        base64_contents: false,
       }),
     }
   }
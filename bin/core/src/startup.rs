@@ -325,9 +325,14 @@ async fn ensure_init_user_and_resources() {
       .stages(vec![ProcedureStage {
         name: String::from("Stage 1"),
         enabled: true,
+        run_condition: Default::default(),
+        timeout_seconds: None,
         executions: vec![
           EnabledExecution {
-            execution: Execution::BackupCoreDatabase(BackupCoreDatabase {}),
+            execution: Execution::BackupCoreDatabase(BackupCoreDatabase {
+              compression: None,
+              remote_target: String::new(),
+            }),
             enabled: true
           }
         ]
@@ -368,9 +373,16 @@ async fn ensure_init_user_and_resources() {
       .stages(vec![ProcedureStage {
         name: String::from("Stage 1"),
         enabled: true,
+        run_condition: Default::default(),
+        timeout_seconds: None,
         executions: vec![
           EnabledExecution {
-            execution: Execution::GlobalAutoUpdate(GlobalAutoUpdate {}),
+            execution: Execution::GlobalAutoUpdate(GlobalAutoUpdate {
+              dry_run: false,
+              filter_tags: Default::default(),
+              filter_server_ids: Default::default(),
+              filter_resource_types: Default::default(),
+            }),
             enabled: true
           }
         ]
@@ -7,6 +7,7 @@ use anyhow::Context;
 use arc_swap::ArcSwap;
 use database::Client;
 use komodo_client::entities::{
+  ResourceTarget,
   action::ActionState,
   build::BuildState,
   config::core::{CoreConfig, GithubWebhookAppConfig},
@@ -209,3 +210,13 @@ pub fn all_resources_cache() -> &'static ArcSwap<AllResourcesById> {
     OnceLock::new();
   ALL_RESOURCES.get_or_init(Default::default)
 }
+
+/// Variable name -> The resources which reference the variable.
+pub type VariableReferences = HashMap<String, Vec<ResourceTarget>>;
+
+pub fn variable_references_cache()
+-> &'static ArcSwap<VariableReferences> {
+  static VARIABLE_REFERENCES: OnceLock<ArcSwap<VariableReferences>> =
+    OnceLock::new();
+  VARIABLE_REFERENCES.get_or_init(Default::default)
+}
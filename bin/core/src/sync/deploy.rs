@@ -190,6 +190,26 @@ pub async fn deploy_from_cache(
   logs.push(Log::simple("Sync Deploy", log));
 }
 
+/// Log the deploys which would run, without executing them.
+pub fn dry_run_log(to_deploy: &ToDeployCache) -> Option<Log> {
+  if to_deploy.is_empty() {
+    return None;
+  }
+  let log = to_deploy
+    .iter()
+    .map(|(target, reason, _)| {
+      let (resource, name) = target.extract_variant_id();
+      format!(
+        "{}: {resource} '{}' with reason: {reason}",
+        colored("DEPLOY", Color::Green),
+        bold(name),
+      )
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+  Some(Log::simple("Dry Run Deploy", log))
+}
+
 pub async fn get_updates_for_view(
   params: SyncDeployParams<'_>,
 ) -> SyncDeployUpdate {
@@ -377,6 +397,7 @@ fn build_cache_for_deployment<'a>(
           || diff.image.is_some()
           || diff.image_registry_account.is_some()
           || diff.skip_secret_interp.is_some()
+          || diff.skip_variable_interp.is_some()
           || diff.network.is_some()
           || diff.restart.is_some()
           || diff.command.is_some()
@@ -560,6 +581,7 @@ fn build_cache_for_stack<'a>(
             for StackRemoteFileContents {
               path,
               contents,
+              base64_contents: _base64_contents,
               services: _services,
               requires: _requires,
             } in remote_contents
@@ -613,6 +635,7 @@ fn build_cache_for_stack<'a>(
           || diff.file_paths.is_some()
           || diff.file_contents.is_some()
           || diff.skip_secret_interp.is_some()
+          || diff.skip_variable_interp.is_some()
           || diff.extra_args.is_some()
           || diff.environment.is_some()
           || diff.env_file_path.is_some()
@@ -23,21 +23,24 @@ pub async fn get_updates_for_execution<
   match_resources: Option<&[String]>,
   id_to_tags: &HashMap<String, Tag>,
   match_tags: &[String],
+  namespace: &str,
+  dry_run: bool,
 ) -> anyhow::Result<SyncDeltas<Resource::PartialConfig>> {
   let map = find_collect(Resource::coll(), None, None)
     .await
     .context("failed to get resources from db")?
     .into_iter()
     .filter(|r| {
-      Resource::include_resource(
-        &r.name,
-        &r.config,
-        match_resource_type,
-        match_resources,
-        &r.tags,
-        id_to_tags,
-        match_tags,
-      )
+      super::in_namespace(&r.name, namespace)
+        && Resource::include_resource(
+          &r.name,
+          &r.config,
+          match_resource_type,
+          match_resources,
+          &r.tags,
+          id_to_tags,
+          match_tags,
+        )
     })
     .map(|r| (r.name.clone(), r))
     .collect::<HashMap<_, _>>();
@@ -76,6 +79,12 @@ pub async fn get_updates_for_execution<
 
         Resource::validate_partial_config(&mut resource.config);
 
+        // The toml diff is only needed for the dry run log.
+        let toml_diff = dry_run.then(|| {
+          toml_diff::<Resource>(original, &resource, id_to_tags)
+            .unwrap_or_else(|e| format!("- failed to diff | {e:#}"))
+        });
+
         let mut diff = Resource::get_diff(
           original.config.clone(),
           resource.config,
@@ -109,6 +118,7 @@ pub async fn get_updates_for_execution<
           update_template: resource.template != original.template,
           update_tags: resource.tags != original_tags,
           resource,
+          diff: toml_diff,
         };
 
         deltas.to_update.push(update);
@@ -120,6 +130,27 @@ pub async fn get_updates_for_execution<
   Ok(deltas)
 }
 
+/// Diff the resource toml the same way as the sync view.
+fn toml_diff<Resource: ResourceSyncTrait>(
+  original: &komodo_client::entities::resource::Resource<
+    Resource::Config,
+    Resource::Info,
+  >,
+  resource: &ResourceToml<Resource::PartialConfig>,
+  id_to_tags: &HashMap<String, Tag>,
+) -> anyhow::Result<String> {
+  let proposed = super::toml::resource_toml_to_toml_string::<Resource>(
+    resource.clone(),
+  )?;
+  let current = super::toml::resource_to_toml::<Resource>(
+    original.clone(),
+    resource.deploy,
+    resource.after.clone(),
+    id_to_tags,
+  )?;
+  Ok(super::line_diff(&current, &proposed))
+}
+
 pub trait ExecuteResourceSync: ResourceSyncTrait {
   async fn execute_sync_updates(
     SyncDeltas {
@@ -188,6 +219,7 @@ pub trait ExecuteResourceSync: ResourceSyncTrait {
       update_description,
       update_template,
       update_tags,
+      ..
     } in to_update
     {
       let name = resource.name.clone();
@@ -273,6 +273,10 @@ pub fn extend_resources(
   resources
     .resource_syncs
     .extend(filter_by_tag(more.resource_syncs, match_tags));
+  resources
+    .server_groups
+    .extend(filter_by_tag(more.server_groups, match_tags));
+  resources.tags.extend(more.tags);
   resources.user_groups.extend(more.user_groups);
   resources.variables.extend(more.variables);
 }
@@ -2,10 +2,13 @@ use std::{collections::HashMap, str::FromStr};
 
 use anyhow::anyhow;
 use database::mungos::mongodb::bson::oid::ObjectId;
+use formatting::{Color, bold, colored};
 use komodo_client::entities::{
   ResourceTargetVariant,
+  sync::ResourceSyncConfig,
   tag::Tag,
   toml::{ResourceToml, ResourcesToml},
+  update::Log,
 };
 use toml::ToToml;
 
@@ -16,6 +19,7 @@ pub mod execute;
 pub mod file;
 pub mod remote;
 pub mod resources;
+pub mod tags;
 pub mod toml;
 pub mod user_groups;
 pub mod variables;
@@ -34,6 +38,18 @@ impl<T: Default> SyncDeltas<T> {
       && self.to_update.is_empty()
       && self.to_delete.is_empty()
   }
+
+  /// Log the pending changes without executing them.
+  pub fn dry_run_log(&self, stage: &str) -> Option<Log> {
+    dry_run_log(
+      stage,
+      self.to_create.iter().map(|resource| resource.name.as_str()),
+      self.to_update.iter().map(|item| {
+        (item.resource.name.as_str(), item.diff.as_deref())
+      }),
+      self.to_delete.iter().map(String::as_str),
+    )
+  }
 }
 
 pub struct ToUpdateItem<T: Default> {
@@ -42,6 +58,9 @@ pub struct ToUpdateItem<T: Default> {
   pub update_description: bool,
   pub update_template: bool,
   pub update_tags: bool,
+  /// The line diff of the resource toml, for the dry run log.
+  /// Only computed on dry runs.
+  pub diff: Option<String>,
 }
 
 pub trait ResourceSyncTrait: ToToml + Sized {
@@ -145,7 +164,105 @@ pub fn include_resource_by_resource_type_and_name<
   }
 }
 
-fn deserialize_resources_toml(
+/// A sync with a namespace only manages the resources
+/// with names prefixed by `<namespace>-`.
+pub fn in_namespace(name: &str, namespace: &str) -> bool {
+  namespace.is_empty()
+    || name
+      .strip_prefix(namespace)
+      .is_some_and(|name| name.starts_with('-'))
+}
+
+/// Variables and user groups are global, so they can't be
+/// scoped to a namespace. Syncs with a namespace can't manage them.
+pub fn check_namespace_scope(
+  config: &ResourceSyncConfig,
+) -> anyhow::Result<()> {
+  if config.namespace.is_empty() {
+    return Ok(());
+  }
+  if config.include_variables {
+    return Err(anyhow!(
+      "Syncs with a namespace cannot include variables"
+    ));
+  }
+  if config.include_user_groups {
+    return Err(anyhow!(
+      "Syncs with a namespace cannot include user groups"
+    ));
+  }
+  Ok(())
+}
+
+/// Prefixes the resource names from the sync files with `<namespace>-`,
+/// so they can be compared against the live resources.
+pub fn add_namespace(resources: &mut ResourcesToml, namespace: &str) {
+  fn add<T: Default>(
+    resources: &mut [ResourceToml<T>],
+    namespace: &str,
+  ) {
+    for resource in resources {
+      resource.name = format!("{namespace}-{}", resource.name);
+    }
+  }
+  if namespace.is_empty() {
+    return;
+  }
+  add(&mut resources.servers, namespace);
+  add(&mut resources.deployments, namespace);
+  add(&mut resources.stacks, namespace);
+  add(&mut resources.builds, namespace);
+  add(&mut resources.repos, namespace);
+  add(&mut resources.procedures, namespace);
+  add(&mut resources.actions, namespace);
+  add(&mut resources.alerters, namespace);
+  add(&mut resources.builders, namespace);
+  add(&mut resources.resource_syncs, namespace);
+  add(&mut resources.server_groups, namespace);
+}
+
+/// Removes the `<namespace>-` prefix from the resource names
+/// before writing them to the sync files.
+/// Resources outside the namespace are removed.
+pub fn strip_namespace(
+  resources: &mut ResourcesToml,
+  namespace: &str,
+) {
+  fn strip<T: Default>(
+    resources: &mut Vec<ResourceToml<T>>,
+    namespace: &str,
+  ) {
+    resources.retain_mut(|resource| {
+      match resource
+        .name
+        .strip_prefix(namespace)
+        .and_then(|name| name.strip_prefix('-'))
+      {
+        Some(name) => {
+          resource.name = name.to_string();
+          true
+        }
+        None => false,
+      }
+    });
+  }
+  if namespace.is_empty() {
+    return;
+  }
+  strip(&mut resources.servers, namespace);
+  strip(&mut resources.deployments, namespace);
+  strip(&mut resources.stacks, namespace);
+  strip(&mut resources.builds, namespace);
+  strip(&mut resources.repos, namespace);
+  strip(&mut resources.procedures, namespace);
+  strip(&mut resources.actions, namespace);
+  strip(&mut resources.alerters, namespace);
+  strip(&mut resources.builders, namespace);
+  strip(&mut resources.resource_syncs, namespace);
+  strip(&mut resources.server_groups, namespace);
+}
+
+pub fn deserialize_resources_toml(
   toml_str: &str,
 ) -> anyhow::Result<ResourcesToml> {
   ::toml::from_str::<ResourcesToml>(&escape_between_triple_string(
@@ -171,3 +288,167 @@ fn escape_between_triple_string(toml_str: &str) -> String {
     .collect::<Vec<_>>()
     .join(r#"""""#)
 }
+
+/// Formats the names which would be created / updated / deleted
+/// by a sync, along with the diff of each update when available.
+/// Returns None if there are no changes.
+pub fn dry_run_log<'a>(
+  stage: &str,
+  to_create: impl IntoIterator<Item = &'a str>,
+  to_update: impl IntoIterator<Item = (&'a str, Option<&'a str>)>,
+  to_delete: impl IntoIterator<Item = &'a str>,
+) -> Option<Log> {
+  let mut lines = Vec::new();
+  for name in to_create {
+    lines.push(format!(
+      "{}: {}",
+      colored("CREATE", Color::Green),
+      bold(name)
+    ));
+  }
+  for (name, diff) in to_update {
+    lines.push(format!(
+      "{}: {}",
+      colored("UPDATE", Color::Blue),
+      bold(name)
+    ));
+    for line in diff.into_iter().flat_map(str::lines) {
+      let line = if line.starts_with('+') {
+        colored(line, Color::Green)
+      } else {
+        colored(line, Color::Red)
+      };
+      lines.push(format!("  {line}"));
+    }
+  }
+  for name in to_delete {
+    lines.push(format!(
+      "{}: {}",
+      colored("DELETE", Color::Red),
+      bold(name)
+    ));
+  }
+  if lines.is_empty() {
+    return None;
+  }
+  Some(Log::simple(stage, lines.join("\n")))
+}
+
+/// Minimal line diff using the longest common subsequence.
+/// Only changed lines are included.
+pub fn line_diff(current: &str, proposed: &str) -> String {
+  let current = current.lines().collect::<Vec<_>>();
+  let proposed = proposed.lines().collect::<Vec<_>>();
+  let (n, m) = (current.len(), proposed.len());
+  // lcs[i][j] = LCS length of current[i..] and proposed[j..]
+  let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+  for i in (0..n).rev() {
+    for j in (0..m).rev() {
+      lcs[i][j] = if current[i] == proposed[j] {
+        lcs[i + 1][j + 1] + 1
+      } else {
+        lcs[i + 1][j].max(lcs[i][j + 1])
+      };
+    }
+  }
+  let mut diff = Vec::new();
+  let (mut i, mut j) = (0, 0);
+  while i < n || j < m {
+    if i < n && j < m && current[i] == proposed[j] {
+      i += 1;
+      j += 1;
+    } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+      diff.push(format!("+ {}", proposed[j]));
+      j += 1;
+    } else {
+      diff.push(format!("- {}", current[i]));
+      i += 1;
+    }
+  }
+  diff.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn resources(names: &[&str]) -> ResourcesToml {
+    ResourcesToml {
+      stacks: names
+        .iter()
+        .map(|name| ResourceToml {
+          name: name.to_string(),
+          description: Default::default(),
+          template: false,
+          tags: Vec::new(),
+          deploy: false,
+          after: Vec::new(),
+          config: Default::default(),
+        })
+        .collect(),
+      ..Default::default()
+    }
+  }
+
+  fn stack_names(resources: &ResourcesToml) -> Vec<&str> {
+    resources.stacks.iter().map(|r| r.name.as_str()).collect()
+  }
+
+  #[test]
+  fn in_namespace_requires_prefix_and_dash() {
+    assert!(in_namespace("anything", ""));
+    assert!(in_namespace("team-a-stack", "team-a"));
+    assert!(!in_namespace("team-ab-stack", "team-a"));
+    assert!(!in_namespace("team-a", "team-a"));
+    assert!(!in_namespace("other-stack", "team-a"));
+  }
+
+  #[test]
+  fn namespace_round_trip() {
+    let mut toml = resources(&["web", "db"]);
+    add_namespace(&mut toml, "team-a");
+    assert_eq!(stack_names(&toml), ["team-a-web", "team-a-db"]);
+    assert!(
+      toml
+        .stacks
+        .iter()
+        .all(|stack| in_namespace(&stack.name, "team-a"))
+    );
+    strip_namespace(&mut toml, "team-a");
+    assert_eq!(stack_names(&toml), ["web", "db"]);
+  }
+
+  #[test]
+  fn strip_namespace_drops_other_resources() {
+    let mut toml = resources(&["team-a-web", "team-b-web", "web"]);
+    strip_namespace(&mut toml, "team-a");
+    assert_eq!(stack_names(&toml), ["web"]);
+  }
+
+  #[test]
+  fn empty_namespace_leaves_names() {
+    let mut toml = resources(&["web"]);
+    add_namespace(&mut toml, "");
+    assert_eq!(stack_names(&toml), ["web"]);
+    strip_namespace(&mut toml, "");
+    assert_eq!(stack_names(&toml), ["web"]);
+  }
+
+  #[test]
+  fn namespace_rejects_global_resources() {
+    let mut config = ResourceSyncConfig {
+      namespace: String::from("team-a"),
+      include_variables: false,
+      include_user_groups: false,
+      ..Default::default()
+    };
+    assert!(check_namespace_scope(&config).is_ok());
+    config.include_variables = true;
+    assert!(check_namespace_scope(&config).is_err());
+    config.include_variables = false;
+    config.include_user_groups = true;
+    assert!(check_namespace_scope(&config).is_err());
+    config.namespace.clear();
+    assert!(check_namespace_scope(&config).is_ok());
+  }
+}
@@ -22,19 +22,24 @@ pub struct RemoteResources {
 }
 
 /// Use `match_tags` to filter resources by tag.
+/// Resource names are prefixed with the sync `namespace`.
 pub async fn get_remote_resources(
   sync: &ResourceSync,
   repo: Option<&Repo>,
 ) -> anyhow::Result<RemoteResources> {
-  if sync.config.files_on_host {
-    get_files_on_host(sync).await
+  let mut remote = if sync.config.files_on_host {
+    get_files_on_host(sync).await?
   } else if let Some(repo) = repo {
-    get_repo(sync, repo.into()).await
+    get_repo(sync, repo.into()).await?
   } else if !sync.config.repo.is_empty() {
-    get_repo(sync, sync.into()).await
+    get_repo(sync, sync.into()).await?
   } else {
-    get_ui_defined(sync).await
+    get_ui_defined(sync).await?
+  };
+  if let Ok(resources) = &mut remote.resources {
+    super::add_namespace(resources, &sync.config.namespace);
   }
+  Ok(remote)
 }
 
 async fn get_files_on_host(
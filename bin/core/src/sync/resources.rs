@@ -13,6 +13,7 @@ use komodo_client::{
     procedure::Procedure,
     repo::Repo,
     server::Server,
+    server_group::ServerGroup,
     stack::Stack,
     sync::ResourceSync,
     tag::Tag,
@@ -164,6 +165,24 @@ impl ResourceSyncTrait for Repo {
 
 impl ExecuteResourceSync for Repo {}
 
+impl ResourceSyncTrait for ServerGroup {
+  fn get_diff(
+    mut original: Self::Config,
+    update: Self::PartialConfig,
+  ) -> anyhow::Result<Self::ConfigDiff> {
+    let resources = all_resources_cache().load();
+    // need to replace the member server ids with names
+    for member in &mut original.members {
+      if let Some(server) = resources.servers.get(member) {
+        member.clone_from(&server.name);
+      }
+    }
+    Ok(original.partial_diff(update))
+  }
+}
+
+impl ExecuteResourceSync for ServerGroup {}
+
 impl ResourceSyncTrait for Alerter {
   fn get_diff(
     original: Self::Config,
@@ -581,6 +600,27 @@ impl ResourceSyncTrait for Procedure {
               .map(|d| d.name.clone())
               .unwrap_or_default();
           }
+          Execution::RunCommandOnGroup(config) => {
+            config.server_group = resources
+              .server_groups
+              .get(&config.server_group)
+              .map(|d| d.name.clone())
+              .unwrap_or_default();
+          }
+          Execution::PruneImagesOnGroup(config) => {
+            config.server_group = resources
+              .server_groups
+              .get(&config.server_group)
+              .map(|d| d.name.clone())
+              .unwrap_or_default();
+          }
+          Execution::PruneSystemOnGroup(config) => {
+            config.server_group = resources
+              .server_groups
+              .get(&config.server_group)
+              .map(|d| d.name.clone())
+              .unwrap_or_default();
+          }
           Execution::RunSync(config) => {
             config.sync = resources
               .syncs
@@ -763,6 +803,7 @@ impl ExecuteResourceSync for Procedure {
         update_description,
         update_template,
         update_tags,
+        ..
       } in &to_update
       {
         let name = resource.name.clone();
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use database::mungos::find::find_collect;
+use formatting::{Color, bold, colored, muted};
+use komodo_client::{
+  api::write::{CreateTag, DeleteTag, UpdateTagColor},
+  entities::{
+    sync::DiffData, tag::Tag, toml::TagToml, update::Log,
+    user::sync_user,
+  },
+};
+use resolver_api::Resolve;
+
+use crate::{api::write::WriteArgs, state::db_client};
+
+use super::toml::TOML_PRETTY_OPTIONS;
+
+pub fn tag_to_toml(tag: &TagToml) -> anyhow::Result<String> {
+  let inner = toml_pretty::to_string(tag, TOML_PRETTY_OPTIONS)
+    .context("failed to serialize tag to toml")?;
+  Ok(format!("[[tag]]\n{inner}"))
+}
+
+fn convert_tag(tag: &Tag) -> TagToml {
+  TagToml {
+    name: tag.name.clone(),
+    color: tag.color.clone(),
+  }
+}
+
+pub async fn get_updates_for_view(
+  tags: &[TagToml],
+  delete: bool,
+) -> anyhow::Result<Vec<DiffData>> {
+  let map = find_collect(&db_client().tags, None, None)
+    .await
+    .context("failed to query db for tags")?
+    .into_iter()
+    .map(|t| (t.name.clone(), t))
+    .collect::<HashMap<_, _>>();
+
+  let mut diffs = Vec::<DiffData>::new();
+
+  if delete {
+    for tag in map.values() {
+      if !tags.iter().any(|t| t.name == tag.name) {
+        diffs.push(DiffData::Delete {
+          current: tag_to_toml(&convert_tag(tag))?,
+        });
+      }
+    }
+  }
+
+  for tag in tags {
+    match map.get(&tag.name) {
+      Some(original) => {
+        if original.color.as_ref() == tag.color.as_ref() {
+          continue;
+        }
+        diffs.push(DiffData::Update {
+          proposed: tag_to_toml(tag)?,
+          current: tag_to_toml(&convert_tag(original))?,
+        });
+      }
+      None => {
+        diffs.push(DiffData::Create {
+          name: tag.name.clone(),
+          proposed: tag_to_toml(tag)?,
+        });
+      }
+    }
+  }
+
+  Ok(diffs)
+}
+
+/// Returns tags to create, tags to update color, and tags to delete.
+pub async fn get_updates_for_execution(
+  tags: Vec<TagToml>,
+  delete: bool,
+) -> anyhow::Result<(Vec<TagToml>, Vec<TagToml>, Vec<Tag>)> {
+  let map = find_collect(&db_client().tags, None, None)
+    .await
+    .context("failed to query db for tags")?
+    .into_iter()
+    .map(|t| (t.name.clone(), t))
+    .collect::<HashMap<_, _>>();
+
+  let mut to_create = Vec::<TagToml>::new();
+  let mut to_update = Vec::<TagToml>::new();
+  let mut to_delete = Vec::<Tag>::new();
+
+  if delete {
+    for tag in map.values() {
+      if !tags.iter().any(|t| t.name == tag.name) {
+        to_delete.push(tag.clone());
+      }
+    }
+  }
+
+  for tag in tags {
+    match map.get(&tag.name) {
+      Some(original) => {
+        if original.color.as_ref() != tag.color.as_ref() {
+          to_update.push(tag);
+        }
+      }
+      None => to_create.push(tag),
+    }
+  }
+
+  Ok((to_create, to_update, to_delete))
+}
+
+pub fn dry_run_log(
+  to_create: &[TagToml],
+  to_update: &[TagToml],
+  to_delete: &[Tag],
+) -> Option<Log> {
+  super::dry_run_log(
+    "Dry Run Tags",
+    to_create.iter().map(|tag| tag.name.as_str()),
+    to_update.iter().map(|tag| (tag.name.as_str(), None)),
+    to_delete.iter().map(|tag| tag.name.as_str()),
+  )
+}
+
+pub async fn run_updates(
+  to_create: Vec<TagToml>,
+  to_update: Vec<TagToml>,
+  to_delete: Vec<Tag>,
+) -> Option<Log> {
+  if to_create.is_empty()
+    && to_update.is_empty()
+    && to_delete.is_empty()
+  {
+    return None;
+  }
+
+  let mut has_error = false;
+  let mut log = String::from("running updates on Tags");
+
+  for tag in to_create {
+    if let Err(e) = (CreateTag {
+      name: tag.name.clone(),
+      color: Some(tag.color),
+    })
+    .resolve(&WriteArgs {
+      user: sync_user().to_owned(),
+    })
+    .await
+    {
+      has_error = true;
+      log.push_str(&format!(
+        "\n{}: failed to create tag '{}' | {:#}",
+        colored("ERROR", Color::Red),
+        bold(&tag.name),
+        e.error
+      ));
+    } else {
+      log.push_str(&format!(
+        "\n{}: {} tag '{}'",
+        muted("INFO"),
+        colored("created", Color::Green),
+        bold(&tag.name)
+      ))
+    };
+  }
+
+  for tag in to_update {
+    if let Err(e) = (UpdateTagColor {
+      tag: tag.name.clone(),
+      color: tag.color,
+    })
+    .resolve(&WriteArgs {
+      user: sync_user().to_owned(),
+    })
+    .await
+    {
+      has_error = true;
+      log.push_str(&format!(
+        "\n{}: failed to update tag color for '{}' | {:#}",
+        colored("ERROR", Color::Red),
+        bold(&tag.name),
+        e.error
+      ))
+    } else {
+      log.push_str(&format!(
+        "\n{}: {} tag '{}' color",
+        muted("INFO"),
+        colored("updated", Color::Blue),
+        bold(&tag.name)
+      ))
+    };
+  }
+
+  for tag in to_delete {
+    if let Err(e) = (DeleteTag { id: tag.id })
+      .resolve(&WriteArgs {
+        user: sync_user().to_owned(),
+      })
+      .await
+    {
+      has_error = true;
+      log.push_str(&format!(
+        "\n{}: failed to delete tag '{}' | {:#}",
+        colored("ERROR", Color::Red),
+        bold(&tag.name),
+        e.error
+      ))
+    } else {
+      log.push_str(&format!(
+        "\n{}: {} tag '{}'",
+        muted("INFO"),
+        colored("deleted", Color::Red),
+        bold(&tag.name)
+      ))
+    }
+  }
+
+  let stage = "Update Tags";
+  Some(if has_error {
+    Log::error(stage, log)
+  } else {
+    Log::simple(stage, log)
+  })
+}
@@ -14,6 +14,7 @@ use komodo_client::{
     repo::Repo,
     resource::Resource,
     server::Server,
+    server_group::ServerGroup,
     stack::Stack,
     sync::ResourceSync,
     tag::Tag,
@@ -161,6 +162,17 @@ impl ToToml for Alerter {}
 impl ToToml for Server {}
 impl ToToml for Action {}
 
+impl ToToml for ServerGroup {
+  fn replace_ids(resource: &mut Resource<Self::Config, Self::Info>) {
+    let all = all_resources_cache().load();
+    for member in &mut resource.config.members {
+      if let Some(server) = all.servers.get(member) {
+        member.clone_from(&server.name);
+      }
+    }
+  }
+}
+
 impl ToToml for ResourceSync {
   fn replace_ids(resource: &mut Resource<Self::Config, Self::Info>) {
     let all = all_resources_cache().load();
@@ -698,6 +710,33 @@ impl ToToml for Procedure {
               .map(|r| &r.name)
               .unwrap_or(&String::new()),
           ),
+          Execution::RunCommandOnGroup(exec) => {
+            exec.server_group.clone_from(
+              all
+                .server_groups
+                .get(&exec.server_group)
+                .map(|r| &r.name)
+                .unwrap_or(&String::new()),
+            )
+          }
+          Execution::PruneImagesOnGroup(exec) => {
+            exec.server_group.clone_from(
+              all
+                .server_groups
+                .get(&exec.server_group)
+                .map(|r| &r.name)
+                .unwrap_or(&String::new()),
+            )
+          }
+          Execution::PruneSystemOnGroup(exec) => {
+            exec.server_group.clone_from(
+              all
+                .server_groups
+                .get(&exec.server_group)
+                .map(|r| &r.name)
+                .unwrap_or(&String::new()),
+            )
+          }
           Execution::RunSync(exec) => exec.sync.clone_from(
             all
               .syncs
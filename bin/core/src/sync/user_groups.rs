@@ -393,6 +393,13 @@ pub async fn get_updates_for_execution(
             .map(|b| b.name.clone())
             .unwrap_or_default()
         }
+        ResourceTarget::ServerGroup(id) => {
+          *id = all_resources
+            .server_groups
+            .get(id)
+            .map(|b| b.name.clone())
+            .unwrap_or_default()
+        }
       }
       PermissionToml {
         target: p.resource_target,
@@ -484,6 +491,21 @@ fn sort_permissions(
   }
 }
 
+pub fn dry_run_log(
+  to_create: &[UserGroupToml],
+  to_update: &[UpdateItem],
+  to_delete: &[DeleteItem],
+) -> Option<Log> {
+  super::dry_run_log(
+    "Dry Run User Groups",
+    to_create.iter().map(|user_group| user_group.name.as_str()),
+    to_update
+      .iter()
+      .map(|item| (item.user_group.name.as_str(), None)),
+    to_delete.iter().map(|item| item.name.as_str()),
+  )
+}
+
 pub async fn run_updates(
   to_create: Vec<UserGroupToml>,
   to_update: Vec<UpdateItem>,
@@ -918,6 +940,20 @@ async fn expand_user_group_permissions(
           });
         expanded.extend(permissions);
       }
+      ResourceTargetVariant::ServerGroup => {
+        let permissions = all_resources
+          .server_groups
+          .values()
+          .filter(|resource| matcher.is_match(&resource.name))
+          .map(|resource| PermissionToml {
+            target: ResourceTarget::ServerGroup(
+              resource.name.clone(),
+            ),
+            level: permission.level,
+            specific: permission.specific.clone(),
+          });
+        expanded.extend(permissions);
+      }
       ResourceTargetVariant::System => {}
     }
   }
@@ -1110,6 +1146,13 @@ pub async fn convert_user_groups(
             .map(|r| r.name.clone())
             .unwrap_or_default()
         }
+        ResourceTarget::ServerGroup(id) => {
+          *id = all
+            .server_groups
+            .get(id)
+            .map(|r| r.name.clone())
+            .unwrap_or_default()
+        }
         ResourceTarget::System(_) => {}
       }
       PermissionToml {
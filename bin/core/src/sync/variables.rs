@@ -1,17 +1,24 @@
 use std::collections::HashMap;
 
 use anyhow::Context;
-use database::mungos::find::find_collect;
+use database::mungos::{
+  find::find_collect,
+  mongodb::bson::{Document, doc},
+};
 use formatting::{Color, bold, colored, muted};
 use komodo_client::{
   api::write::*,
   entities::{
-    sync::DiffData, update::Log, user::sync_user, variable::Variable,
+    resource::Resource, sync::DiffData, update::Log, user::sync_user,
+    variable::Variable,
   },
 };
 use resolver_api::Resolve;
 
-use crate::{api::write::WriteArgs, state::db_client};
+use crate::{
+  api::write::WriteArgs,
+  state::{all_resources_cache, db_client},
+};
 
 use super::toml::TOML_PRETTY_OPTIONS;
 
@@ -34,21 +41,28 @@ pub async fn get_updates_for_view(
   variables: &[Variable],
   delete: bool,
 ) -> anyhow::Result<Vec<DiffData>> {
-  let map = find_collect(&db_client().variables, None, None)
-    .await
-    .context("failed to query db for variables")?
-    .into_iter()
-    .map(|v| (v.name.clone(), v))
-    .collect::<HashMap<_, _>>();
+  let map =
+    find_collect(&db_client().variables, global_variables(), None)
+      .await
+      .context("failed to query db for variables")?
+      .into_iter()
+      .map(|v| (v.name.clone(), v))
+      .collect::<HashMap<_, _>>();
 
   let mut diffs = Vec::<DiffData>::new();
 
   if delete {
     for variable in map.values() {
       if !variables.iter().any(|v| v.name == variable.name) {
-        diffs.push(DiffData::Delete {
-          current: variable_to_toml(variable)?,
-        });
+        let mut current = variable_to_toml(&redacted(variable))?;
+        let references = variable_references(&variable.name);
+        if !references.is_empty() {
+          current = format!(
+            "# WARNING: Variable is still referenced by {}\n{current}",
+            references.join(", ")
+          );
+        }
+        diffs.push(DiffData::Delete { current });
       }
     }
   }
@@ -58,18 +72,19 @@ pub async fn get_updates_for_view(
       Some(original) => {
         if original.value == variable.value
           && original.description == variable.description
+          && original.is_secret == variable.is_secret
         {
           continue;
         }
         diffs.push(DiffData::Update {
-          proposed: variable_to_toml(variable)?,
-          current: variable_to_toml(original)?,
+          proposed: variable_to_toml(&redacted(variable))?,
+          current: variable_to_toml(&redacted(original))?,
         });
       }
       None => {
         diffs.push(DiffData::Create {
           name: variable.name.clone(),
-          proposed: variable_to_toml(variable)?,
+          proposed: variable_to_toml(&redacted(variable))?,
         });
       }
     }
@@ -78,16 +93,46 @@ pub async fn get_updates_for_view(
   Ok(diffs)
 }
 
+/// Syncs only manage global variables,
+/// Server scoped variables are left alone.
+fn global_variables() -> Document {
+  doc! { "server_id": null }
+}
+
+/// Secret values shouldn't be shown in the pending view.
+fn redacted(variable: &Variable) -> Variable {
+  let mut variable = variable.clone();
+  if variable.is_secret {
+    variable.value = "#".repeat(variable.value.len());
+  }
+  variable
+}
+
+/// Find the resources which interpolate the variable
+/// into their config using `[[NAME]]`.
+fn variable_references(name: &str) -> Vec<String> {
+  all_resources_cache()
+    .load()
+    .variable_references(name)
+    .into_iter()
+    .map(|(target, name)| {
+      let (resource_type, _) = target.extract_variant_id();
+      format!("{resource_type} '{name}'")
+    })
+    .collect()
+}
+
 pub async fn get_updates_for_execution(
   variables: Vec<Variable>,
   delete: bool,
 ) -> anyhow::Result<(Vec<Variable>, Vec<ToUpdateItem>, Vec<String>)> {
-  let map = find_collect(&db_client().variables, None, None)
-    .await
-    .context("failed to query db for variables")?
-    .into_iter()
-    .map(|v| (v.name.clone(), v))
-    .collect::<HashMap<_, _>>();
+  let map =
+    find_collect(&db_client().variables, global_variables(), None)
+      .await
+      .context("failed to query db for variables")?
+      .into_iter()
+      .map(|v| (v.name.clone(), v))
+      .collect::<HashMap<_, _>>();
 
   let mut to_create = Vec::<Variable>::new();
   let mut to_update = Vec::<ToUpdateItem>::new();
@@ -127,6 +172,21 @@ pub async fn get_updates_for_execution(
   Ok((to_create, to_update, to_delete))
 }
 
+pub fn dry_run_log(
+  to_create: &[Variable],
+  to_update: &[ToUpdateItem],
+  to_delete: &[String],
+) -> Option<Log> {
+  super::dry_run_log(
+    "Dry Run Variables",
+    to_create.iter().map(|variable| variable.name.as_str()),
+    to_update
+      .iter()
+      .map(|item| (item.variable.name.as_str(), None)),
+    to_delete.iter().map(String::as_str),
+  )
+}
+
 pub async fn run_updates(
   to_create: Vec<Variable>,
   to_update: Vec<ToUpdateItem>,
@@ -148,6 +208,7 @@ pub async fn run_updates(
       value: variable.value,
       description: variable.description,
       is_secret: variable.is_secret,
+      server_id: None,
     })
     .resolve(&WriteArgs {
       user: sync_user().to_owned(),
@@ -182,6 +243,7 @@ pub async fn run_updates(
       if let Err(e) = (UpdateVariableValue {
         name: variable.name.clone(),
         value: variable.value,
+        server_id: None,
       })
       .resolve(&WriteArgs {
         user: sync_user().to_owned(),
@@ -208,6 +270,7 @@ pub async fn run_updates(
       if let Err(e) = (UpdateVariableDescription {
         name: variable.name.clone(),
         description: variable.description,
+        server_id: None,
       })
       .resolve(&WriteArgs {
         user: sync_user().to_owned(),
@@ -234,6 +297,7 @@ pub async fn run_updates(
       if let Err(e) = (UpdateVariableIsSecret {
         name: variable.name.clone(),
         is_secret: variable.is_secret,
+        server_id: None,
       })
       .resolve(&WriteArgs {
         user: sync_user().to_owned(),
@@ -259,8 +323,18 @@ pub async fn run_updates(
   }
 
   for variable in to_delete {
+    let references = variable_references(&variable);
+    if !references.is_empty() {
+      log.push_str(&format!(
+        "\n{}: variable '{}' is still referenced by {}",
+        colored("WARN", Color::Yellow),
+        bold(&variable),
+        references.join(", ")
+      ));
+    }
     if let Err(e) = (DeleteVariable {
       name: variable.clone(),
+      server_id: None,
     })
     .resolve(&WriteArgs {
       user: sync_user().to_owned(),
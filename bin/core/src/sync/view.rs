@@ -20,6 +20,7 @@ pub async fn push_updates_for_view<Resource: ResourceSyncTrait>(
   match_resources: Option<&[String]>,
   id_to_tags: &HashMap<String, Tag>,
   match_tags: &[String],
+  namespace: &str,
   diffs: &mut Vec<ResourceDiff>,
 ) -> anyhow::Result<()> {
   let current_map = find_collect(Resource::coll(), None, None)
@@ -27,15 +28,16 @@ pub async fn push_updates_for_view<Resource: ResourceSyncTrait>(
     .context("failed to get resources from db")?
     .into_iter()
     .filter(|r| {
-      Resource::include_resource(
-        &r.name,
-        &r.config,
-        match_resource_type,
-        match_resources,
-        &r.tags,
-        id_to_tags,
-        match_tags,
-      )
+      super::in_namespace(&r.name, namespace)
+        && Resource::include_resource(
+          &r.name,
+          &r.config,
+          match_resource_type,
+          match_resources,
+          &r.tags,
+          id_to_tags,
+          match_tags,
+        )
     })
     .map(|r| (r.name.clone(), r))
     .collect::<HashMap<_, _>>();
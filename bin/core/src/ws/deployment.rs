@@ -22,7 +22,7 @@ pub async fn terminal(
 ) -> impl IntoResponse {
   ws.on_upgrade(|socket| async move {
     let Some((mut client_socket, user)) =
-      super::ws_login(socket).await
+      super::ws_login(socket, PermissionLevel::Write).await
     else {
       return;
     };
@@ -60,6 +60,7 @@ pub async fn terminal(
 
     super::handle_container_terminal(
       client_socket,
+      &user,
       &server,
       deployment.name,
       shell,
@@ -1,5 +1,11 @@
+use std::{
+  collections::HashMap,
+  sync::{Mutex, OnceLock},
+};
+
 use crate::{
   auth::{auth_api_key_check_enabled, auth_jwt_check_enabled},
+  config::core_config,
   helpers::query::get_user,
 };
 use anyhow::anyhow;
@@ -10,7 +16,9 @@ use axum::{
 };
 use futures::{SinkExt, StreamExt};
 use komodo_client::{
-  entities::{server::Server, user::User},
+  entities::{
+    permission::PermissionLevel, server::Server, user::User,
+  },
   ws::WsLoginMessage,
 };
 use tokio::net::TcpStream;
@@ -19,8 +27,11 @@ use tokio_tungstenite::{
 };
 use tokio_util::sync::CancellationToken;
 
+use self::recording::{TerminalRecorder, TerminalTarget};
+
 mod container;
 mod deployment;
+mod recording;
 mod stack;
 mod terminal;
 mod update;
@@ -34,9 +45,12 @@ pub fn router() -> Router {
     .route("/stack/terminal", get(stack::terminal))
 }
 
+/// Api keys with a permission ceiling must allow
+/// the `required` level to log in.
 #[instrument(level = "debug")]
 async fn ws_login(
   mut socket: WebSocket,
+  required: PermissionLevel,
 ) -> Option<(WebSocket, User)> {
   let login_msg = match socket.recv().await {
     Some(Ok(Message::Text(login_msg))) => {
@@ -81,7 +95,18 @@ async fn ws_login(
     }
     // login using api keys
     Ok(WsLoginMessage::ApiKeys { key, secret }) => {
-      match auth_api_key_check_enabled(&key, &secret).await {
+      let res = auth_api_key_check_enabled(&key, &secret)
+        .await
+        .and_then(|(user, ceiling)| match ceiling {
+          // Only terminals require Write, and so check operations.
+          Some(ceiling) if !ceiling.allows(required, "Terminal") => {
+            Err(anyhow!(
+              "Api key permission ceiling does not allow this websocket"
+            ))
+          }
+          _ => Ok(user),
+        });
+      match res {
         Ok(user) => {
           let _ = socket.send(Message::text("LOGGED_IN")).await;
           Some((socket, user))
@@ -125,12 +150,96 @@ async fn check_user_valid(user_id: &str) -> anyhow::Result<User> {
   Ok(user)
 }
 
+/// Held for the duration of a user's terminal session,
+/// to enforce `max_terminals_per_user`.
+/// The slot is freed when dropped.
+struct UserTerminalSlot {
+  user_id: String,
+}
+
+fn user_terminal_counts() -> &'static Mutex<HashMap<String, usize>> {
+  static USER_TERMINAL_COUNTS: OnceLock<
+    Mutex<HashMap<String, usize>>,
+  > = OnceLock::new();
+  USER_TERMINAL_COUNTS.get_or_init(Default::default)
+}
+
+impl UserTerminalSlot {
+  fn acquire(user: &User) -> anyhow::Result<UserTerminalSlot> {
+    Self::acquire_with_max(
+      &user.id,
+      core_config().max_terminals_per_user,
+    )
+  }
+
+  /// A `max` of 0 means no limit.
+  fn acquire_with_max(
+    user_id: &str,
+    max: usize,
+  ) -> anyhow::Result<UserTerminalSlot> {
+    let mut counts = user_terminal_counts().lock().unwrap();
+    let count = counts.entry(user_id.to_string()).or_default();
+    if max > 0 && *count >= max {
+      return Err(anyhow!(
+        "User has reached the maximum of {max} open terminals. Close an existing terminal before opening another."
+      ));
+    }
+    *count += 1;
+    Ok(UserTerminalSlot {
+      user_id: user_id.to_string(),
+    })
+  }
+}
+
+impl Drop for UserTerminalSlot {
+  fn drop(&mut self) {
+    let mut counts = user_terminal_counts().lock().unwrap();
+    if let Some(count) = counts.get_mut(&self.user_id) {
+      *count = count.saturating_sub(1);
+      if *count == 0 {
+        counts.remove(&self.user_id);
+      }
+    }
+  }
+}
+
 async fn handle_container_terminal(
   mut client_socket: WebSocket,
+  user: &User,
   server: &Server,
   container: String,
   shell: String,
 ) {
+  let _slot = match UserTerminalSlot::acquire(user) {
+    Ok(slot) => slot,
+    Err(e) => {
+      debug!("could not open terminal | {e:#}");
+      let _ = client_socket
+        .send(Message::text(format!("ERROR: {e:#}")))
+        .await;
+      let _ = client_socket.close().await;
+      return;
+    }
+  };
+
+  let recorder = match TerminalRecorder::start(
+    user,
+    server,
+    TerminalTarget::Container(container.clone()),
+  )
+  .await
+  {
+    Ok(recorder) => recorder,
+    Err(e) => {
+      warn!("Failed to start terminal recording | {e:#}");
+      let _ = client_socket
+        .send(Message::text(format!("ERROR: {e:#}")))
+        .await;
+      let _ = client_socket.close().await;
+      return;
+    }
+  };
+
   let periphery = match crate::helpers::periphery_client(server) {
     Ok(periphery) => periphery,
     Err(e) => {
@@ -164,12 +273,14 @@ async fn handle_container_terminal(
 
   trace!("connected to periphery container exec websocket");
 
-  core_periphery_forward_ws(client_socket, periphery_socket).await
+  core_periphery_forward_ws(client_socket, periphery_socket, recorder)
+    .await
 }
 
 async fn core_periphery_forward_ws(
   client_socket: axum::extract::ws::WebSocket,
   periphery_socket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+  recorder: Option<TerminalRecorder>,
 ) {
   let (mut periphery_send, mut periphery_receive) =
     periphery_socket.split();
@@ -189,6 +300,9 @@ async fn core_periphery_forward_ws(
       };
       match res {
         Some(Ok(msg)) => {
+          if let Some(recorder) = &recorder {
+            recorder.input(&msg).await;
+          }
           if let Err(e) =
             periphery_send.send(axum_to_tungstenite(msg)).await
           {
@@ -220,6 +334,9 @@ async fn core_periphery_forward_ws(
       };
       match res {
         Some(Ok(msg)) => {
+          if let Some(recorder) = &recorder {
+            recorder.output(&msg).await;
+          }
           if let Err(e) =
             core_send.send(tungstenite_to_axum(msg)).await
           {
@@ -288,3 +405,56 @@ fn tungstenite_to_axum(msg: tungstenite::Message) -> Message {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn open_count(user_id: &str) -> usize {
+    user_terminal_counts()
+      .lock()
+      .unwrap()
+      .get(user_id)
+      .copied()
+      .unwrap_or_default()
+  }
+
+  #[test]
+  fn limits_terminals_per_user() {
+    let _first =
+      UserTerminalSlot::acquire_with_max("limit-user", 2).unwrap();
+    let _second =
+      UserTerminalSlot::acquire_with_max("limit-user", 2).unwrap();
+    assert!(
+      UserTerminalSlot::acquire_with_max("limit-user", 2).is_err()
+    );
+    // Other users have their own limit
+    let _other =
+      UserTerminalSlot::acquire_with_max("limit-other", 2).unwrap();
+  }
+
+  #[test]
+  fn disconnect_frees_slot() {
+    let slot =
+      UserTerminalSlot::acquire_with_max("drop-user", 1).unwrap();
+    assert!(
+      UserTerminalSlot::acquire_with_max("drop-user", 1).is_err()
+    );
+    assert_eq!(open_count("drop-user"), 1);
+    drop(slot);
+    assert_eq!(open_count("drop-user"), 0);
+    let _slot =
+      UserTerminalSlot::acquire_with_max("drop-user", 1).unwrap();
+  }
+
+  #[test]
+  fn zero_max_is_unlimited() {
+    let _slots = (0..10)
+      .map(|_| {
+        UserTerminalSlot::acquire_with_max("unlimited-user", 0)
+      })
+      .collect::<anyhow::Result<Vec<_>>>()
+      .unwrap();
+    assert_eq!(open_count("unlimited-user"), 10);
+  }
+}
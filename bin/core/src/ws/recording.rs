@@ -0,0 +1,306 @@
+//! Terminal session recording for auditing, enabled with `record_terminals`.
+//!
+//! Each session is written as newline delimited json to
+//! `{terminal_recording_directory}/{started_at}_{server}_{target}_{user_id}.jsonl`.
+//! The first line is the [RecordingHeader], followed by
+//! one [RecordingEvent] per forwarded terminal message.
+//! Secret values (Core secrets and secret variables)
+//! are redacted before being written.
+
+use std::{borrow::Cow, path::PathBuf};
+
+use anyhow::Context;
+use axum::{body::Bytes, extract::ws::Message};
+use komodo_client::entities::{
+  komodo_timestamp, server::Server, to_path_compatible_name,
+  user::User,
+};
+use serde::Serialize;
+use tokio::{fs, io::AsyncWriteExt, sync::mpsc, task::JoinHandle};
+use tokio_tungstenite::tungstenite;
+
+use crate::{
+  config::core_config,
+  helpers::query::{VariablesAndSecrets, get_variables_and_secrets},
+};
+
+/// Max recording events waiting to be written.
+/// When full, forwarding waits on the writer rather than
+/// dropping events or buffering without limit.
+const RECORDING_BUFFER: usize = 1024;
+
+/// The terminal / container the session is connected to.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "name", rename_all = "snake_case")]
+pub enum TerminalTarget {
+  Terminal(String),
+  Container(String),
+}
+
+impl TerminalTarget {
+  fn name(&self) -> &str {
+    match self {
+      TerminalTarget::Terminal(name)
+      | TerminalTarget::Container(name) => name,
+    }
+  }
+}
+
+#[derive(Serialize)]
+struct RecordingHeader<'a> {
+  user_id: &'a str,
+  username: &'a str,
+  server_id: &'a str,
+  server: &'a str,
+  target: &'a TerminalTarget,
+  started_at: i64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Direction {
+  /// Client -> Terminal
+  Input,
+  /// Terminal -> Client
+  Output,
+}
+
+#[derive(Serialize)]
+struct RecordingEvent<'a> {
+  ts: i64,
+  direction: Direction,
+  data: Cow<'a, str>,
+}
+
+/// Redaction hook applied to all recorded data before it is written.
+/// Replaces secret values with their names, using the
+/// (value, name) pairs of the secret replacers.
+/// Only secrets contained within a single forwarded message are caught.
+fn redact<'a>(
+  data: &'a [u8],
+  replacers: &[(String, String)],
+) -> Cow<'a, str> {
+  let data = String::from_utf8_lossy(data);
+  if replacers.is_empty() {
+    return data;
+  }
+  Cow::Owned(svi::replace_in_string(&data, replacers))
+}
+
+/// Cheap to clone handle which sends forwarded messages
+/// to the recording writer task.
+#[derive(Clone)]
+pub struct TerminalRecorder {
+  sender: mpsc::Sender<(i64, Direction, Bytes)>,
+}
+
+impl TerminalRecorder {
+  /// Starts recording the session if `record_terminals` is enabled.
+  /// Returns None if recording is disabled.
+  pub async fn start(
+    user: &User,
+    server: &Server,
+    target: TerminalTarget,
+  ) -> anyhow::Result<Option<TerminalRecorder>> {
+    let config = core_config();
+    if !config.record_terminals {
+      return Ok(None);
+    }
+
+    let VariablesAndSecrets { secrets, .. } =
+      get_variables_and_secrets(Some(&server.id)).await?;
+    let replacers = secrets
+      .into_iter()
+      .filter(|(_, value)| !value.is_empty())
+      .map(|(name, value)| (value, name))
+      .collect::<Vec<_>>();
+
+    fs::create_dir_all(&config.terminal_recording_directory)
+      .await
+      .context("Failed to create terminal recording directory")?;
+
+    let started_at = komodo_timestamp();
+    let path = config.terminal_recording_directory.join(format!(
+      "{started_at}_{}_{}_{}.jsonl",
+      to_path_compatible_name(&server.name),
+      to_path_compatible_name(target.name()),
+      user.id
+    ));
+
+    let mut file =
+      fs::File::create(&path).await.with_context(|| {
+        format!(
+          "Failed to create terminal recording file at {path:?}"
+        )
+      })?;
+
+    let mut header = serde_json::to_vec(&RecordingHeader {
+      user_id: &user.id,
+      username: &user.username,
+      server_id: &server.id,
+      server: &server.name,
+      target: &target,
+      started_at,
+    })
+    .context("Failed to serialize terminal recording header")?;
+    header.push(b'\n');
+    file
+      .write_all(&header)
+      .await
+      .context("Failed to write terminal recording header")?;
+
+    let (recorder, _) =
+      TerminalRecorder::spawn_writer(file, path, replacers);
+
+    Ok(Some(recorder))
+  }
+
+  /// Spawns the task writing the recorded events to the file.
+  /// The task finishes once all recorder handles are dropped,
+  /// ie when the session ends.
+  fn spawn_writer(
+    mut file: fs::File,
+    path: PathBuf,
+    replacers: Vec<(String, String)>,
+  ) -> (TerminalRecorder, JoinHandle<()>) {
+    let (sender, mut receiver) = mpsc::channel(RECORDING_BUFFER);
+    let handle = tokio::spawn(async move {
+      while let Some((ts, direction, bytes)) = receiver.recv().await {
+        let event = RecordingEvent {
+          ts,
+          direction,
+          data: redact(&bytes, &replacers),
+        };
+        let mut line = match serde_json::to_vec(&event) {
+          Ok(line) => line,
+          Err(e) => {
+            warn!(
+              "Failed to serialize terminal recording event | {e:?}"
+            );
+            continue;
+          }
+        };
+        line.push(b'\n');
+        if let Err(e) = file.write_all(&line).await {
+          warn!(
+            "Failed to write terminal recording at {path:?} | {e:?}"
+          );
+          break;
+        }
+      }
+      if let Err(e) = file.flush().await {
+        warn!(
+          "Failed to flush terminal recording at {path:?} | {e:?}"
+        );
+      }
+    });
+    (TerminalRecorder { sender }, handle)
+  }
+
+  /// Record a message forwarded from the client to the terminal.
+  pub async fn input(&self, msg: &Message) {
+    match msg {
+      // Stdin is prefixed with 0x00, resize messages with 0xFF are skipped.
+      Message::Binary(bytes) if bytes.first() == Some(&0x00) => {
+        self.record(Direction::Input, bytes.slice(1..)).await
+      }
+      Message::Text(text) => {
+        self
+          .record(
+            Direction::Input,
+            Bytes::copy_from_slice(text.as_bytes()),
+          )
+          .await
+      }
+      _ => {}
+    }
+  }
+
+  /// Record a message forwarded from the terminal to the client.
+  pub async fn output(&self, msg: &tungstenite::Message) {
+    match msg {
+      tungstenite::Message::Binary(bytes) => {
+        self.record(Direction::Output, bytes.clone()).await
+      }
+      tungstenite::Message::Text(text) => {
+        self
+          .record(
+            Direction::Output,
+            Bytes::copy_from_slice(text.as_bytes()),
+          )
+          .await
+      }
+      _ => {}
+    }
+  }
+
+  async fn record(&self, direction: Direction, bytes: Bytes) {
+    // Only errors if the writer task has stopped,
+    // which has already been logged.
+    let _ = self
+      .sender
+      .send((komodo_timestamp(), direction, bytes))
+      .await;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn redacts_secret_values() {
+    let replacers =
+      [(String::from("hunter2"), String::from("DB_PASSWORD"))];
+    let redacted = redact(b"export PASS=hunter2", &replacers);
+    assert!(!redacted.contains("hunter2"));
+    assert!(redacted.contains("DB_PASSWORD"));
+    assert_eq!(redact(b"echo hi", &[]), "echo hi");
+  }
+
+  #[tokio::test]
+  async fn records_forwarded_messages() {
+    let path = std::env::temp_dir().join(format!(
+      "komodo-recording-test-{}.jsonl",
+      std::process::id()
+    ));
+    let file = fs::File::create(&path).await.unwrap();
+    let (recorder, writer) = TerminalRecorder::spawn_writer(
+      file,
+      path.clone(),
+      vec![(String::from("hunter2"), String::from("DB_PASSWORD"))],
+    );
+
+    let mut stdin = vec![0x00];
+    stdin.extend_from_slice(b"echo hunter2\n");
+    recorder.input(&Message::Binary(stdin.into())).await;
+    // Resize messages are not recorded
+    recorder
+      .input(&Message::Binary(Bytes::from_static(&[0xFF, 0x01])))
+      .await;
+    recorder
+      .output(&tungstenite::Message::Binary(Bytes::from_static(
+        b"hunter2\n",
+      )))
+      .await;
+    drop(recorder);
+    writer.await.unwrap();
+
+    let contents = fs::read_to_string(&path).await.unwrap();
+    let _ = fs::remove_file(&path).await;
+    let events = contents
+      .lines()
+      .map(|line| {
+        serde_json::from_str::<serde_json::Value>(line).unwrap()
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0]["direction"], "input");
+    let data = |i: usize| events[i]["data"].as_str().unwrap();
+    assert!(data(0).starts_with("echo "));
+    assert!(data(0).contains("DB_PASSWORD"));
+    assert_eq!(events[1]["direction"], "output");
+    assert!(data(1).contains("DB_PASSWORD"));
+    assert!(!contents.contains("hunter2"));
+  }
+}
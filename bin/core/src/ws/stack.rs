@@ -26,7 +26,7 @@ pub async fn terminal(
 ) -> impl IntoResponse {
   ws.on_upgrade(|socket| async move {
     let Some((mut client_socket, user)) =
-      super::ws_login(socket).await
+      super::ws_login(socket, PermissionLevel::Write).await
     else {
       return;
     };
@@ -103,6 +103,7 @@ pub async fn terminal(
 
     super::handle_container_terminal(
       client_socket,
+      &user,
       &server,
       container,
       shell,
@@ -9,8 +9,12 @@ use komodo_client::{
 };
 
 use crate::{
-  helpers::periphery_client, permission::get_check_permissions,
-  ws::core_periphery_forward_ws,
+  helpers::periphery_client,
+  permission::get_check_permissions,
+  ws::{
+    UserTerminalSlot, core_periphery_forward_ws,
+    recording::{TerminalRecorder, TerminalTarget},
+  },
 };
 
 #[instrument(name = "ConnectTerminal", skip(ws))]
@@ -22,7 +26,7 @@ pub async fn handler(
 ) -> impl IntoResponse {
   ws.on_upgrade(|socket| async move {
     let Some((mut client_socket, user)) =
-      super::ws_login(socket).await
+      super::ws_login(socket, PermissionLevel::Write).await
     else {
       return;
     };
@@ -57,6 +61,36 @@ pub async fn handler(
       }
     };
 
+    let _slot = match UserTerminalSlot::acquire(&user) {
+      Ok(slot) => slot,
+      Err(e) => {
+        debug!("could not open terminal | {e:#}");
+        let _ = client_socket
+          .send(Message::text(format!("ERROR: {e:#}")))
+          .await;
+        let _ = client_socket.close().await;
+        return;
+      }
+    };
+
+    let recorder = match TerminalRecorder::start(
+      &user,
+      &server,
+      TerminalTarget::Terminal(terminal.clone()),
+    )
+    .await
+    {
+      Ok(recorder) => recorder,
+      Err(e) => {
+        warn!("Failed to start terminal recording | {e:#}");
+        let _ = client_socket
+          .send(Message::text(format!("ERROR: {e:#}")))
+          .await;
+        let _ = client_socket.close().await;
+        return;
+      }
+    };
+
     trace!("connecting to periphery terminal websocket");
 
     let periphery_socket =
@@ -74,6 +108,11 @@ pub async fn handler(
 
     trace!("connected to periphery terminal websocket");
 
-    core_periphery_forward_ws(client_socket, periphery_socket).await
+    core_periphery_forward_ws(
+      client_socket,
+      periphery_socket,
+      recorder,
+    )
+    .await
   })
 }
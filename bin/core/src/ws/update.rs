@@ -23,8 +23,10 @@ pub async fn handler(ws: WebSocketUpgrade) -> impl IntoResponse {
 
   // handle http -> ws updgrade
   ws.on_upgrade(|socket| async move {
-    let Some((socket, user)) = super::ws_login(socket).await else {
-      return
+    let Some((socket, user)) =
+      super::ws_login(socket, PermissionLevel::Read).await
+    else {
+      return;
     };
 
     let (mut ws_sender, mut ws_reciever) = socket.split();
@@ -5,9 +5,10 @@ use std::{
 
 use anyhow::{Context, anyhow};
 use command::{
-  run_komodo_command, run_komodo_command_with_sanitization,
+  run_komodo_command, run_komodo_command_streaming,
+  run_komodo_command_with_sanitization,
 };
-use formatting::format_serror;
+use formatting::{code, format_serror};
 use interpolate::Interpolator;
 use komodo_client::entities::{
   EnvironmentVar, all_logs_success,
@@ -17,15 +18,20 @@ use komodo_client::entities::{
   update::Log,
 };
 use periphery_client::api::build::{
-  self, GetDockerfileContentsOnHost,
-  GetDockerfileContentsOnHostResponse, PruneBuilders, PruneBuildx,
-  WriteDockerfileContentsToHost,
+  self, GetBuildProgress, GetBuildProgressResponse,
+  GetDockerfileContentsOnHost, GetDockerfileContentsOnHostResponse,
+  PruneBuilders, PruneBuildx, WriteDockerfileContentsToHost,
 };
 use resolver_api::Resolve;
 use tokio::fs;
+use uuid::Uuid;
 
 use crate::{
-  build::{parse_build_args, parse_secret_args, write_dockerfile},
+  build::{
+    BuildOutputRenderer, build_progress, parse_build_args,
+    parse_build_context_args, parse_cache_args, parse_platform_args,
+    parse_secret_args, write_build_secrets, write_dockerfile,
+  },
   config::periphery_config,
   docker::docker_login,
   helpers::{parse_extra_args, parse_labels},
@@ -68,6 +74,7 @@ impl Resolve<super::Args> for GetDockerfileContentsOnHost {
     Ok(GetDockerfileContentsOnHostResponse {
       contents,
       path: full_path.display().to_string(),
+      base64_contents: false,
     })
   }
 }
@@ -109,7 +116,10 @@ impl Resolve<super::Args> for WriteDockerfileContentsToHost {
     })?;
     Ok(Log::simple(
       "Write dockerfile to host",
-      format!("dockerfile contents written to {full_path:?}"),
+      format!(
+        "dockerfile contents written to {}",
+        code(full_path.display())
+      ),
     ))
   }
 }
@@ -127,6 +137,7 @@ impl Resolve<super::Args> for build::Build {
       mut replacers,
       commit_hash,
       additional_tags,
+      track_progress,
     } = self;
 
     let mut logs = Vec::new();
@@ -148,9 +159,14 @@ impl Resolve<super::Args> for build::Build {
           dockerfile_path,
           build_args,
           secret_args,
+          build_secrets,
           labels,
           extra_args,
           use_buildx,
+          platforms,
+          cache_from,
+          cache_to,
+          build_contexts,
           image_registry,
           repo,
           files_on_host,
@@ -205,6 +221,42 @@ impl Resolve<super::Args> for build::Build {
       };
     }
 
+    // Multi-platform builds can't be loaded into the local image store,
+    // so they must be pushed directly to the registry using buildx.
+    if !platforms.is_empty() {
+      if !should_push {
+        logs.push(Log::error(
+          "Multi-Platform Build",
+          format_serror(
+            &anyhow!(
+              "Multi-platform builds must push to an image registry. Configure an image registry, or remove the platforms."
+            )
+            .into(),
+          ),
+        ));
+        return Ok(logs);
+      }
+      let buildx_log = run_komodo_command(
+        "Check Buildx",
+        None,
+        String::from("docker buildx version"),
+      )
+      .await;
+      if !buildx_log.success {
+        logs.push(buildx_log);
+        logs.push(Log::error(
+          "Multi-Platform Build",
+          format_serror(
+            &anyhow!(
+              "Multi-platform builds require buildx, which is not available on this builder. Install the docker buildx plugin, or remove the platforms."
+            )
+            .into(),
+          ),
+        ));
+        return Ok(logs);
+      }
+    }
+
     let build_path = if let Some(repo) = &linked_repo {
       periphery_config()
         .repo_dir()
@@ -278,13 +330,47 @@ impl Resolve<super::Args> for build::Build {
     let command_secret_args =
       parse_secret_args(&secret_args, &build_path).await?;
 
+    // Build secrets are written outside the build context,
+    // and always cleaned up when the build finishes, even on failure.
+    let secrets_dir = scopeguard::guard(
+      std::env::temp_dir()
+        .join(format!("komodo-build-secrets-{}", Uuid::new_v4())),
+      |secrets_dir| {
+        if let Err(e) = std::fs::remove_dir_all(&secrets_dir)
+          && e.kind() != std::io::ErrorKind::NotFound
+        {
+          warn!(
+            "Failed to clean up build secrets at {secrets_dir:?} | {e:?}"
+          );
+        }
+      },
+    );
+    let command_build_secrets =
+      write_build_secrets(build_secrets, &secrets_dir).await?;
+
     let labels = parse_labels(
       &environment_vars_from_str(labels).context("Invalid labels")?,
     );
 
     let extra_args = parse_extra_args(extra_args);
 
-    let buildx = if *use_buildx { " buildx" } else { "" };
+    // Secret mounts are passed using BuildKit,
+    // and only the inline cache can be exported without buildx.
+    let buildx = if *use_buildx
+      || !platforms.is_empty()
+      || !build_secrets.is_empty()
+      || cache_to.iter().any(|cache| cache != "type=inline")
+    {
+      " buildx"
+    } else {
+      ""
+    };
+
+    let platform_args = parse_platform_args(platforms);
+
+    let cache_args = parse_cache_args(cache_from, cache_to);
+
+    let build_context_args = parse_build_context_args(build_contexts);
 
     let image_tags = build
       .get_image_tags_as_arg(commit_hash.as_deref(), &additional_tags)
@@ -292,22 +378,49 @@ impl Resolve<super::Args> for build::Build {
 
     let maybe_push = if should_push { " --push" } else { "" };
 
+    // The rawjson progress mode is only available with buildx.
+    // Respect any progress mode passed in the extra args.
+    let track_progress = track_progress
+      && !buildx.is_empty()
+      && !extra_args.contains("--progress");
+    let progress = if track_progress {
+      " --progress=rawjson"
+    } else {
+      ""
+    };
+
     // Construct command
     let command = format!(
-      "docker{buildx} build{build_args}{command_secret_args}{extra_args}{labels}{image_tags}{maybe_push} -f {dockerfile_path} .",
+      "docker{buildx} build{progress}{platform_args}{build_args}{command_secret_args}{command_build_secrets}{cache_args}{build_context_args}{extra_args}{labels}{image_tags}{maybe_push} -f {dockerfile_path} .",
     );
 
-    if let Some(build_log) = run_komodo_command_with_sanitization(
+    // Track the build steps as they are reported,
+    // so Core can show build progress.
+    let _progress = scopeguard::guard(name.clone(), |name| {
+      build_progress().write().unwrap().remove(&name);
+    });
+    let mut renderer = BuildOutputRenderer::default();
+    let build_log = run_komodo_command_streaming(
       "Docker Build",
       build_path.as_ref(),
       command,
-      false,
       &replacers,
+      |line| {
+        if !track_progress {
+          return Some(line);
+        }
+        let (line, progress) = renderer.render(line);
+        if let Some(progress) = progress {
+          build_progress()
+            .write()
+            .unwrap()
+            .insert(name.clone(), progress);
+        }
+        line
+      },
     )
-    .await
-    {
-      logs.push(build_log);
-    };
+    .await;
+    logs.push(build_log);
 
     Ok(logs)
   }
@@ -315,6 +428,18 @@ impl Resolve<super::Args> for build::Build {
 
 //
 
+impl Resolve<super::Args> for GetBuildProgress {
+  #[instrument(name = "GetBuildProgress", level = "debug")]
+  async fn resolve(
+    self,
+    _: &super::Args,
+  ) -> serror::Result<GetBuildProgressResponse> {
+    Ok(build_progress().read().unwrap().get(&self.name).copied())
+  }
+}
+
+//
+
 impl Resolve<super::Args> for PruneBuilders {
   #[instrument(name = "PruneBuilders", skip_all)]
   async fn resolve(self, _: &super::Args) -> serror::Result<Log> {
@@ -1,15 +1,17 @@
 use anyhow::{Context, anyhow};
+use base64::Engine;
 use command::{
   run_komodo_command, run_komodo_command_with_sanitization,
 };
-use formatting::format_serror;
+use formatting::{code, format_serror};
 use git::write_commit_file;
 use interpolate::Interpolator;
 use komodo_client::entities::{
   FileContents, RepoExecutionResponse, all_logs_success,
   stack::{
     ComposeFile, ComposeProject, ComposeService,
-    ComposeServiceDeploy, StackRemoteFileContents, StackServiceNames,
+    ComposeServiceDeploy, StackRemoteFileContents, StackServiceInfo,
+    StackServiceNames,
   },
   to_path_compatible_name,
   update::Log,
@@ -18,16 +20,23 @@ use periphery_client::api::compose::*;
 use resolver_api::Resolve;
 use serde::{Deserialize, Serialize};
 use shell_escape::unix::escape;
-use std::{borrow::Cow, path::PathBuf};
+use std::{
+  borrow::Cow, collections::HashMap, path::PathBuf, time::Duration,
+};
 use tokio::fs;
 
 use crate::{
   compose::{
-    docker_compose, env_file_args, pull_or_clone_stack,
-    up::{maybe_login_registry, validate_files},
-    write::write_stack,
+    docker_compose, env_file_args, parallel_limit_env, profile_args,
+    pull_or_clone_stack, stack_run_directory,
+    up::{
+      maybe_login_registry, project_service_containers,
+      validate_files, wait_for_healthy,
+    },
+    write::{decode_file_contents, write_stack},
   },
   config::periphery_config,
+  docker::docker_client,
   helpers::{log_grep, parse_extra_args},
 };
 
@@ -93,6 +102,100 @@ pub struct DockerComposeLsItem {
 
 //
 
+impl Resolve<super::Args> for ListComposeServices {
+  #[instrument(name = "ListComposeServices", level = "debug")]
+  async fn resolve(
+    self,
+    _: &super::Args,
+  ) -> serror::Result<Vec<StackServiceInfo>> {
+    let docker_compose = docker_compose();
+    let res = run_komodo_command(
+      "List Services",
+      None,
+      format!(
+        "{docker_compose} -p {} ps --all --format json",
+        self.project
+      ),
+    )
+    .await;
+
+    if !res.success {
+      return Err(
+        anyhow!("{}", res.combined())
+          .context(format!(
+            "failed to list compose services using {docker_compose} ps"
+          ))
+          .into(),
+      );
+    }
+
+    // Older versions of compose output a json array,
+    // newer versions output one json object per line.
+    let stdout = res.stdout.trim();
+    let items = if stdout.starts_with('[') {
+      serde_json::from_str::<Vec<DockerComposePsItem>>(stdout)
+    } else {
+      stdout
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(serde_json::from_str::<DockerComposePsItem>)
+        .collect()
+    }
+    .with_context(|| res.stdout.clone())
+    .with_context(|| {
+      format!(
+        "failed to parse '{docker_compose} ps' response to json"
+      )
+    })?;
+
+    let mut digests = HashMap::<String, Option<String>>::new();
+    let mut services = Vec::<StackServiceInfo>::new();
+
+    for item in items {
+      // Only include the first replica of each service
+      if item.service.is_empty()
+        || services.iter().any(|s| s.name == item.service)
+      {
+        continue;
+      }
+      let image_digest = match digests.get(&item.image) {
+        Some(digest) => digest.clone(),
+        None => {
+          let digest = docker_client()
+            .inspect_image(&item.image)
+            .await
+            .ok()
+            .and_then(|image| image.repo_digests.into_iter().next());
+          digests.insert(item.image.clone(), digest.clone());
+          digest
+        }
+      };
+      services.push(StackServiceInfo {
+        name: item.service,
+        image: item.image,
+        image_digest,
+        state: item.state.parse().unwrap_or_default(),
+      });
+    }
+
+    services.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(services)
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerComposePsItem {
+  #[serde(default, alias = "Service")]
+  pub service: String,
+  #[serde(default, alias = "Image")]
+  pub image: String,
+  #[serde(default, alias = "State")]
+  pub state: String,
+}
+
+//
+
 impl Resolve<super::Args> for GetComposeLog {
   #[instrument(name = "GetComposeLog", level = "debug")]
   async fn resolve(self, _: &super::Args) -> serror::Result<Log> {
@@ -174,17 +277,28 @@ impl Resolve<super::Args> for GetComposeContentsOnHost {
         .join(&file.path)
         .components()
         .collect::<PathBuf>();
-      match fs::read_to_string(&full_path).await.with_context(|| {
+      match fs::read(&full_path).await.with_context(|| {
         format!(
           "Failed to read compose file contents at {full_path:?}"
         )
       }) {
-        Ok(contents) => {
+        Ok(bytes) => {
+          // Binary files (eg certificates) are base64 encoded.
+          let (contents, base64_contents) =
+            match String::from_utf8(bytes) {
+              Ok(contents) => (contents, false),
+              Err(e) => (
+                base64::engine::general_purpose::STANDARD
+                  .encode(e.into_bytes()),
+                true,
+              ),
+            };
           // The path we store here has to be the same as incoming file path in the array,
           // in order for WriteComposeContentsToHost to write to the correct path.
           res.contents.push(StackRemoteFileContents {
             path: file.path,
             contents,
+            base64_contents,
             services: file.services,
             requires: file.requires,
           });
@@ -193,6 +307,7 @@ impl Resolve<super::Args> for GetComposeContentsOnHost {
           res.errors.push(FileContents {
             path: file.path,
             contents: format_serror(&e.into()),
+            base64_contents: false,
           });
         }
       }
@@ -220,7 +335,9 @@ impl Resolve<super::Args> for WriteComposeContentsToHost {
       run_directory,
       file_path,
       contents,
+      base64_contents,
     } = self;
+    let contents = decode_file_contents(contents, base64_contents)?;
     let file_path = periphery_config()
       .stack_dir()
       .join(to_path_compatible_name(&name))
@@ -241,7 +358,10 @@ impl Resolve<super::Args> for WriteComposeContentsToHost {
     })?;
     Ok(Log::simple(
       "Write contents to host",
-      format!("File contents written to {file_path:?}"),
+      format!(
+        "File contents written to {}",
+        code(file_path.display())
+      ),
     ))
   }
 }
@@ -268,9 +388,12 @@ impl Resolve<super::Args> for WriteCommitComposeContents {
       username,
       file_path,
       contents,
+      base64_contents,
       git_token,
     } = self;
 
+    let contents = decode_file_contents(contents, base64_contents)?;
+
     let root =
       pull_or_clone_stack(&stack, repo.as_ref(), git_token).await?;
 
@@ -396,13 +519,15 @@ impl Resolve<super::Args> for ComposePull {
       &stack.config.additional_env_files,
     )?;
 
+    let profile_args = profile_args(&stack.config.compose_profiles);
+
     let project_name = stack.project_name(false);
 
     let log = run_komodo_command(
       "Compose Pull",
       run_directory.as_ref(),
       format!(
-        "{docker_compose} -p {project_name} -f {file_args}{env_file_args} pull{service_args}",
+        "{docker_compose} -p {project_name} -f {file_args}{env_file_args}{profile_args} pull{service_args}",
       ),
     )
     .await;
@@ -435,6 +560,7 @@ impl Resolve<super::Args> for ComposeUp {
       git_token,
       registry_token,
       mut replacers,
+      parallel_limit,
     } = self;
 
     let mut res = ComposeUpResponse::default();
@@ -521,11 +647,14 @@ impl Resolve<super::Args> for ComposeUp {
       &stack.config.additional_env_files,
     )?;
 
+    let profile_args = profile_args(&stack.config.compose_profiles);
+    let parallel_env = parallel_limit_env(parallel_limit);
+
     // Uses 'docker compose config' command to extract services (including image)
     // after performing interpolation
     {
       let command = format!(
-        "{docker_compose} -p {project_name} -f {file_args}{env_file_args} config",
+        "{docker_compose} -p {project_name} -f {file_args}{env_file_args}{profile_args} config",
       );
       let Some(config_log) = run_komodo_command_with_sanitization(
         "Compose Config",
@@ -590,7 +719,7 @@ impl Resolve<super::Args> for ComposeUp {
       let build_extra_args =
         parse_extra_args(&stack.config.build_extra_args);
       let command = format!(
-        "{docker_compose} -p {project_name} -f {file_args}{env_file_args} build{build_extra_args}{service_args}",
+        "{parallel_env}{docker_compose} -p {project_name} -f {file_args}{env_file_args}{profile_args} build{build_extra_args}{service_args}",
       );
       let Some(log) = run_komodo_command_with_sanitization(
         "Compose Build",
@@ -614,7 +743,7 @@ impl Resolve<super::Args> for ComposeUp {
       // Pull images before destroying to minimize downtime.
       // If this fails, do not continue.
       let command = format!(
-        "{docker_compose} -p {project_name} -f {file_args}{env_file_args} pull{service_args}",
+        "{parallel_env}{docker_compose} -p {project_name} -f {file_args}{env_file_args}{profile_args} pull{service_args}",
       );
       let log = run_komodo_command(
         "Compose Pull",
@@ -642,7 +771,7 @@ impl Resolve<super::Args> for ComposeUp {
     // Run compose up
     let extra_args = parse_extra_args(&stack.config.extra_args);
     let command = format!(
-      "{docker_compose} -p {project_name} -f {file_args}{env_file_args} up -d{extra_args}{service_args}",
+      "{parallel_env}{docker_compose} -p {project_name} -f {file_args}{env_file_args}{profile_args} up -d{extra_args}{service_args}",
     );
 
     let Some(log) = run_komodo_command_with_sanitization(
@@ -676,12 +805,92 @@ impl Resolve<super::Args> for ComposeUp {
       };
     }
 
+    if res.deployed && !stack.config.on_deploy.is_none() {
+      let healthy = async {
+        if stack.config.on_deploy_timeout == 0 {
+          return anyhow::Ok(());
+        }
+        let containers =
+          project_service_containers(&project_name, &services)
+            .await?;
+        wait_for_healthy(
+          &containers,
+          Duration::from_secs(stack.config.on_deploy_timeout),
+        )
+        .await
+      };
+      match healthy.await {
+        Ok(_) => {
+          let on_deploy_path =
+            run_directory.join(&stack.config.on_deploy.path);
+          if let Some(log) = run_komodo_command_with_sanitization(
+            "On Deploy",
+            on_deploy_path.as_path(),
+            &stack.config.on_deploy.command,
+            true,
+            &replacers,
+          )
+          .await
+          {
+            res.logs.push(log);
+          };
+        }
+        Err(e) => res.logs.push(Log::error(
+          "On Deploy",
+          format_serror(
+            &e.context(
+              "Stack did not become healthy, skipping On Deploy command",
+            )
+            .into(),
+          ),
+        )),
+      }
+    }
+
     Ok(res)
   }
 }
 
 //
 
+impl Resolve<super::Args> for ComposeOnDestroy {
+  #[instrument(
+    name = "ComposeOnDestroy",
+    skip_all,
+    fields(stack = &self.stack.name)
+  )]
+  async fn resolve(self, _: &super::Args) -> serror::Result<Log> {
+    let ComposeOnDestroy {
+      mut stack,
+      repo,
+      mut replacers,
+    } = self;
+
+    let mut interpolator =
+      Interpolator::new(None, &periphery_config().secrets);
+    interpolator.interpolate_stack(&mut stack)?;
+    replacers.extend(interpolator.secret_replacers);
+
+    let on_destroy_path = stack_run_directory(&stack, repo.as_ref())
+      .join(&stack.config.on_destroy.path);
+    let log = run_komodo_command_with_sanitization(
+      "On Destroy",
+      on_destroy_path.as_path(),
+      &stack.config.on_destroy.command,
+      true,
+      &replacers,
+    )
+    .await
+    .unwrap_or_else(|| {
+      Log::simple("On Destroy", String::from("No command to run"))
+    });
+
+    Ok(log)
+  }
+}
+
+//
+
 impl Resolve<super::Args> for ComposeExecution {
   #[instrument(name = "ComposeExecution")]
   async fn resolve(self, _: &super::Args) -> serror::Result<Log> {
@@ -767,6 +976,8 @@ impl Resolve<super::Args> for ComposeRun {
       &stack.config.additional_env_files,
     )?;
 
+    let profile_args = profile_args(&stack.config.compose_profiles);
+
     let project_name = stack.project_name(true);
 
     if pull.unwrap_or_default() {
@@ -774,7 +985,7 @@ impl Resolve<super::Args> for ComposeRun {
         "Compose Pull",
         run_directory.as_ref(),
         format!(
-          "{docker_compose} -p {project_name} -f {file_args}{env_file_args} pull {service}",
+          "{docker_compose} -p {project_name} -f {file_args}{env_file_args}{profile_args} pull {service}",
         ),
       )
       .await;
@@ -825,7 +1036,7 @@ impl Resolve<super::Args> for ComposeRun {
       .unwrap_or_default();
 
     let command = format!(
-      "{docker_compose} -p {project_name} -f {file_args}{env_file_args} run{run_flags} {service}{command_args}",
+      "{docker_compose} -p {project_name} -f {file_args}{env_file_args}{profile_args} run{run_flags} {service}{command_args}",
     );
 
     let Some(log) = run_komodo_command_with_sanitization(
@@ -1,7 +1,11 @@
-use anyhow::Context;
+use std::path::PathBuf;
+
+use anyhow::{Context, anyhow};
+use base64::Engine;
 use command::run_komodo_command;
 use futures::future::join_all;
 use komodo_client::entities::{
+  FileContents,
   docker::{
     container::{Container, ContainerListItem, ContainerStats},
     stats::FullContainerStats,
@@ -10,8 +14,13 @@ use komodo_client::entities::{
 };
 use periphery_client::api::container::*;
 use resolver_api::Resolve;
+use shell_escape::unix::escape;
+use tokio::fs;
+use uuid::Uuid;
 
 use crate::{
+  compose::write::decode_file_contents,
+  config::periphery_config,
   docker::{
     docker_client, stats::get_container_stats, stop_container_command,
   },
@@ -36,6 +45,227 @@ impl Resolve<super::Args> for InspectContainer {
 
 //
 
+impl Resolve<super::Args> for GetContainerEnv {
+  #[instrument(name = "GetContainerEnv", level = "debug")]
+  async fn resolve(
+    self,
+    _: &super::Args,
+  ) -> serror::Result<Vec<String>> {
+    let res = run_komodo_command(
+      "Get Container Env",
+      None,
+      format!(
+        "docker inspect --format '{{{{json .Config.Env}}}}' {}",
+        escape(self.container.into())
+      ),
+    )
+    .await;
+    if !res.success {
+      return Err(
+        anyhow!("{}", res.combined())
+          .context("Failed to inspect container env")
+          .into(),
+      );
+    }
+    // Env is null if the container has no environment.
+    let env =
+      serde_json::from_str::<Option<Vec<String>>>(res.stdout.trim())
+        .with_context(|| res.stdout.clone())
+        .context("Failed to parse container env as json")?;
+    Ok(env.unwrap_or_default())
+  }
+}
+
+//
+
+impl Resolve<super::Args> for ExecInContainer {
+  #[instrument(name = "ExecInContainer", skip(self), fields(container = &self.container))]
+  async fn resolve(self, _: &super::Args) -> serror::Result<Log> {
+    if periphery_config().disable_container_exec {
+      return Err(
+        anyhow!("Container exec is disabled in the periphery config")
+          .into(),
+      );
+    }
+    let ExecInContainer {
+      container,
+      command,
+      workdir,
+    } = self;
+    let workdir = workdir
+      .filter(|workdir| !workdir.is_empty())
+      .map(|workdir| format!(" --workdir {}", escape(workdir.into())))
+      .unwrap_or_default();
+    let command = format!(
+      "docker exec{workdir} {} sh -c {}",
+      escape(container.into()),
+      escape(command.into())
+    );
+    Ok(run_komodo_command("Exec In Container", None, command).await)
+  }
+}
+
+//
+
+/// The contents are held in memory and base64 encoded
+/// in the response, so larger files are rejected.
+const MAX_COPY_FILE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Allowance for the tar headers and padding around the file.
+const COPY_ARCHIVE_OVERHEAD_BYTES: u64 = 64 * 1024;
+
+impl Resolve<super::Args> for CopyFileFromContainer {
+  #[instrument(name = "CopyFileFromContainer")]
+  async fn resolve(
+    self,
+    _: &super::Args,
+  ) -> serror::Result<FileContents> {
+    if periphery_config().disable_container_exec {
+      return Err(
+        anyhow!("Container exec is disabled in the periphery config")
+          .into(),
+      );
+    }
+    let CopyFileFromContainer {
+      container,
+      src_path,
+    } = self;
+    // The download is streamed and stopped once over the limit,
+    // so large files are never fully copied.
+    let archive = docker_client()
+      .download_from_container(
+        &container,
+        &src_path,
+        MAX_COPY_FILE_BYTES + COPY_ARCHIVE_OVERHEAD_BYTES,
+      )
+      .await
+      .context("Failed to copy file from container")?;
+    let bytes = file_from_tar(&archive)
+      .with_context(|| format!("Failed to copy {src_path}"))?;
+    if bytes.len() as u64 > MAX_COPY_FILE_BYTES {
+      return Err(
+        anyhow!(
+          "{src_path} is {} bytes, over the {MAX_COPY_FILE_BYTES} byte limit",
+          bytes.len()
+        )
+        .into(),
+      );
+    }
+    Ok(FileContents {
+      path: src_path,
+      contents: base64::engine::general_purpose::STANDARD
+        .encode(bytes),
+      base64_contents: true,
+    })
+  }
+}
+
+/// Gets the file contents out of the tar archive docker
+/// returns when copying a single path from a container.
+fn file_from_tar(archive: &[u8]) -> anyhow::Result<&[u8]> {
+  let mut offset = 0;
+  while let Some(header) = archive.get(offset..offset + 512) {
+    // The archive ends with empty blocks
+    if header.iter().all(|byte| *byte == 0) {
+      break;
+    }
+    let size = std::str::from_utf8(&header[124..136])
+      .ok()
+      .map(|size| size.trim_matches(|c: char| c == '\0' || c == ' '))
+      .and_then(|size| usize::from_str_radix(size, 8).ok())
+      .context("Invalid entry size in tar archive")?;
+    let data = offset + 512;
+    match header[156] {
+      // Regular file
+      b'0' | 0 => {
+        return archive
+          .get(data..data + size)
+          .context("Tar archive is truncated");
+      }
+      // Extended headers preceding the entry, eg for long names
+      b'x' | b'g' | b'L' | b'K' => {}
+      b'5' => {
+        return Err(anyhow!(
+          "Path is a directory, only files can be copied"
+        ));
+      }
+      b'2' => {
+        return Err(anyhow!(
+          "Path is a symlink, copy the file it links to instead"
+        ));
+      }
+      _ => return Err(anyhow!("Only regular files can be copied")),
+    }
+    offset = data + size.div_ceil(512) * 512;
+  }
+  Err(anyhow!("No file in tar archive"))
+}
+
+//
+
+impl Resolve<super::Args> for CopyFileToContainer {
+  #[instrument(name = "CopyFileToContainer", skip(self), fields(container = &self.container, dest_path = &self.dest_path))]
+  async fn resolve(self, _: &super::Args) -> serror::Result<Log> {
+    if periphery_config().disable_container_exec {
+      return Err(
+        anyhow!("Container exec is disabled in the periphery config")
+          .into(),
+      );
+    }
+    let CopyFileToContainer {
+      container,
+      dest_path,
+      contents,
+      base64,
+    } = self;
+    let contents = decode_file_contents(contents, base64)?;
+    let temp_path = temp_copy_path();
+    fs::write(&*temp_path, contents)
+      .await
+      .context("Failed to write file contents to temp file")?;
+    Ok(
+      run_komodo_command(
+        "Copy File To Container",
+        None,
+        format!(
+          "docker cp {} {}:{}",
+          temp_path.display(),
+          escape(container.into()),
+          escape(dest_path.into())
+        ),
+      )
+      .await,
+    )
+  }
+}
+
+/// A unique temp file path for docker cp,
+/// which is removed when dropped.
+fn temp_copy_path()
+-> scopeguard::ScopeGuard<PathBuf, impl FnOnce(PathBuf)> {
+  scopeguard::guard(
+    std::env::temp_dir()
+      .join(format!("komodo-container-cp-{}", Uuid::new_v4())),
+    |path| {
+      // docker cp creates a directory when the source is one.
+      let res = if path.is_dir() {
+        std::fs::remove_dir_all(&path)
+      } else {
+        std::fs::remove_file(&path)
+      };
+      if let Err(e) = res
+        && e.kind() != std::io::ErrorKind::NotFound
+      {
+        warn!(
+          "Failed to clean up container copy at {path:?} | {e:?}"
+        );
+      }
+    },
+  )
+}
+
+//
+
 impl Resolve<super::Args> for GetContainerLog {
   #[instrument(name = "GetContainerLog", level = "debug")]
   async fn resolve(self, _: &super::Args) -> serror::Result<Log> {
@@ -412,3 +642,59 @@ impl Resolve<super::Args> for StopAllContainers {
     Ok(join_all(futures).await)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn tar_entry(kind: u8, data: &[u8]) -> Vec<u8> {
+    let mut entry = vec![0; 512];
+    entry[..4].copy_from_slice(b"file");
+    let size = format!("{:011o}", data.len());
+    entry[124..135].copy_from_slice(size.as_bytes());
+    entry[156] = kind;
+    entry.extend_from_slice(data);
+    entry.resize(512 + data.len().div_ceil(512) * 512, 0);
+    entry
+  }
+
+  fn archive(entries: &[Vec<u8>]) -> Vec<u8> {
+    let mut archive = entries.concat();
+    archive.extend_from_slice(&[0; 1024]);
+    archive
+  }
+
+  #[test]
+  fn extracts_file() {
+    let contents = vec![b'a'; 1000];
+    let archive = archive(&[tar_entry(b'0', &contents)]);
+    assert_eq!(file_from_tar(&archive).unwrap(), contents);
+  }
+
+  #[test]
+  fn skips_extended_headers() {
+    let archive = archive(&[
+      tar_entry(b'x', b"30 path=a/very/long/file/name\n"),
+      tar_entry(b'0', b"contents"),
+    ]);
+    assert_eq!(file_from_tar(&archive).unwrap(), b"contents");
+  }
+
+  #[test]
+  fn rejects_directory_and_symlink() {
+    assert!(
+      file_from_tar(&archive(&[tar_entry(b'5', &[])])).is_err()
+    );
+    assert!(
+      file_from_tar(&archive(&[tar_entry(b'2', &[])])).is_err()
+    );
+  }
+
+  #[test]
+  fn rejects_truncated_archive() {
+    let mut archive = tar_entry(b'0', &[b'a'; 1000]);
+    archive.truncate(600);
+    assert!(file_from_tar(&archive).is_err());
+    assert!(file_from_tar(&[]).is_err());
+  }
+}
@@ -7,7 +7,8 @@ use komodo_client::{
     EnvironmentVar,
     deployment::{
       Conversion, Deployment, DeploymentConfig, DeploymentImage,
-      RestartMode, conversions_from_str, extract_registry_domain,
+      PullPolicy, RestartMode, conversions_from_str,
+      extract_registry_domain,
     },
     environment_vars_from_str,
     update::Log,
@@ -79,8 +80,11 @@ impl Resolve<super::Args> for Deploy {
       ));
     }
 
-    let _ = pull_image(image).await;
-    debug!("image pulled");
+    // With `always`, docker run does the pull and fails if it fails.
+    if deployment.config.pull_policy == PullPolicy::Missing {
+      let _ = pull_image(image).await;
+      debug!("image pulled");
+    }
 
     let _ = (RemoveContainer {
       name: deployment.name.clone(),
@@ -122,6 +126,7 @@ fn docker_run_command(
         network,
         command,
         restart,
+        pull_policy,
         environment,
         labels,
         extra_args,
@@ -141,6 +146,7 @@ fn docker_run_command(
   );
   let network = parse_network(network);
   let restart = parse_restart(restart);
+  let pull = parse_pull_policy(pull_policy);
   let environment = parse_environment(
     &environment_vars_from_str(environment)
       .context("Invalid environment")?,
@@ -151,7 +157,7 @@ fn docker_run_command(
   let command = parse_command(command);
   let extra_args = parse_extra_args(extra_args);
   let command = format!(
-    "docker run -d --name {name}{ports}{volumes}{network}{restart}{environment}{labels}{extra_args} {image}{command}"
+    "docker run -d --name {name}{ports}{volumes}{network}{restart}{pull}{environment}{labels}{extra_args} {image}{command}"
   );
   Ok(command)
 }
@@ -196,6 +202,14 @@ fn parse_restart(restart: &RestartMode) -> String {
   format!(" --restart {restart}")
 }
 
+fn parse_pull_policy(pull_policy: &PullPolicy) -> String {
+  match pull_policy {
+    // Keeps the command the same as before the policy was added.
+    PullPolicy::Missing => String::new(),
+    _ => format!(" --pull {pull_policy}"),
+  }
+}
+
 fn parse_command(command: &str) -> String {
   if command.is_empty() {
     String::new()
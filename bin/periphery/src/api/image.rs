@@ -1,15 +1,17 @@
 use std::sync::OnceLock;
 
+use anyhow::{Context, anyhow};
 use cache::TimeoutCache;
 use command::run_komodo_command;
 use komodo_client::entities::{
   deployment::extract_registry_domain,
-  docker::image::{Image, ImageHistoryResponseItem},
+  docker::image::{Image, ImageDigests, ImageHistoryResponseItem},
   komodo_timestamp,
   update::Log,
 };
 use periphery_client::api::image::*;
 use resolver_api::Resolve;
+use serde::Deserialize;
 
 use crate::docker::{docker_client, docker_login};
 
@@ -94,6 +96,69 @@ impl Resolve<super::Args> for PullImage {
 
 //
 
+impl Resolve<super::Args> for GetImageDigests {
+  #[instrument(name = "GetImageDigests", skip_all, fields(name = &self.name))]
+  async fn resolve(
+    self,
+    _: &super::Args,
+  ) -> serror::Result<ImageDigests> {
+    let GetImageDigests {
+      name,
+      account,
+      token,
+    } = self;
+
+    let local = docker_client()
+      .inspect_image(&name)
+      .await?
+      .repo_digests
+      .into_iter()
+      .filter_map(|digest| {
+        digest.split_once('@').map(|(_, digest)| digest.to_string())
+      })
+      .collect();
+
+    docker_login(
+      &extract_registry_domain(&name)?,
+      account.as_deref().unwrap_or_default(),
+      token.as_deref(),
+    )
+    .await?;
+
+    let log = run_komodo_command(
+      "Inspect Registry Image",
+      None,
+      format!(
+        "docker buildx imagetools inspect {name} --format '{{{{json .Manifest}}}}'"
+      ),
+    )
+    .await;
+    if !log.success {
+      return Err(
+        anyhow!("{}", log.combined())
+          .context(
+            "Failed to inspect image on registry. This requires docker buildx."
+          )
+          .into(),
+      );
+    }
+
+    #[derive(Deserialize)]
+    struct Manifest {
+      digest: String,
+    }
+    let Manifest { digest } = serde_json::from_str(&log.stdout)
+      .context("Failed to parse registry manifest")?;
+
+    Ok(ImageDigests {
+      local,
+      remote: digest,
+    })
+  }
+}
+
+//
+
 impl Resolve<super::Args> for DeleteImage {
   #[instrument(name = "DeleteImage")]
   async fn resolve(self, _: &super::Args) -> serror::Result<Log> {
@@ -15,7 +15,12 @@ use resolver_api::Resolve;
 use response::Response;
 use serde::{Deserialize, Serialize};
 
-use crate::{config::periphery_config, docker::docker_client};
+use crate::{
+  config::periphery_config,
+  docker::{docker_available, docker_client},
+  helpers::start_time,
+  stats::stats_client,
+};
 
 mod build;
 mod compose;
@@ -71,10 +76,12 @@ pub enum PeripheryRequest {
   GetDockerfileContentsOnHost(GetDockerfileContentsOnHost),
   WriteDockerfileContentsToHost(WriteDockerfileContentsToHost),
   Build(Build),
+  GetBuildProgress(GetBuildProgress),
   PruneBuilders(PruneBuilders),
   PruneBuildx(PruneBuildx),
 
   // Compose (Read)
+  ListComposeServices(ListComposeServices),
   GetComposeContentsOnHost(GetComposeContentsOnHost),
   GetComposeLog(GetComposeLog),
   GetComposeLogSearch(GetComposeLogSearch),
@@ -85,10 +92,15 @@ pub enum PeripheryRequest {
   ComposePull(ComposePull),
   ComposeUp(ComposeUp),
   ComposeExecution(ComposeExecution),
+  ComposeOnDestroy(ComposeOnDestroy),
   ComposeRun(ComposeRun),
 
   // Container (Read)
   InspectContainer(InspectContainer),
+  GetContainerEnv(GetContainerEnv),
+  ExecInContainer(ExecInContainer),
+  CopyFileFromContainer(CopyFileFromContainer),
+  CopyFileToContainer(CopyFileToContainer),
   GetContainerLog(GetContainerLog),
   GetContainerLogSearch(GetContainerLogSearch),
   GetContainerStats(GetContainerStats),
@@ -125,11 +137,13 @@ pub enum PeripheryRequest {
 
   // Image (Write)
   PullImage(PullImage),
+  GetImageDigests(GetImageDigests),
   DeleteImage(DeleteImage),
   PruneImages(PruneImages),
 
   // Volume (Read)
   InspectVolume(InspectVolume),
+  GetVolumeBrowserListing(GetVolumeBrowserListing),
 
   // Volume (Write)
   DeleteVolume(DeleteVolume),
@@ -151,13 +165,33 @@ pub enum PeripheryRequest {
 
 //
 
+/// 1 GB
+const MIN_FREE_DISK_SPACE: u64 = 1024 * 1024 * 1024;
+
 impl Resolve<Args> for GetHealth {
   #[instrument(name = "GetHealth", level = "debug", skip_all)]
   async fn resolve(
     self,
     _: &Args,
   ) -> serror::Result<GetHealthResponse> {
-    Ok(GetHealthResponse {})
+    let config = periphery_config();
+    let disk_space_ok = {
+      let stats = stats_client().read().await;
+      [config.repo_dir(), config.stack_dir(), config.build_dir()]
+        .iter()
+        .all(|dir| {
+          // Unknown disks can't be checked, so don't fail on them.
+          stats
+            .available_space(dir)
+            .is_none_or(|space| space >= MIN_FREE_DISK_SPACE)
+        })
+    };
+    Ok(GetHealthResponse {
+      docker_available: docker_available(),
+      disk_space_ok,
+      version: env!("CARGO_PKG_VERSION").to_string(),
+      uptime_seconds: start_time().elapsed().as_secs(),
+    })
   }
 }
 
@@ -11,10 +11,13 @@ use axum::{
 use derive_variants::ExtractVariant;
 use resolver_api::Resolve;
 use serror::{AddStatusCode, AddStatusCodeError, Json};
-use std::net::{IpAddr, SocketAddr};
+use std::{
+  net::{IpAddr, SocketAddr},
+  time::Instant,
+};
 use uuid::Uuid;
 
-use crate::config::periphery_config;
+use crate::config::{pending_passkey_expiry, periphery_config};
 
 pub fn router() -> Router {
   Router::new()
@@ -100,6 +103,7 @@ async fn guard_request_by_passkey(
     .passkeys
     .iter()
     .any(|passkey| passkey == req_passkey)
+    || is_pending_passkey(req_passkey)
   {
     Ok(next.run(req).await)
   } else {
@@ -110,6 +114,14 @@ async fn guard_request_by_passkey(
   }
 }
 
+/// The pending passkey is accepted alongside `passkeys`
+/// until the rotation grace period after startup elapses.
+fn is_pending_passkey(req_passkey: &str) -> bool {
+  periphery_config().pending_passkey.as_deref().is_some_and(
+    |passkey| !passkey.is_empty() && passkey == req_passkey,
+  ) && Instant::now() < pending_passkey_expiry()
+}
+
 async fn guard_request_by_ip(
   req: Request<Body>,
   next: Next,
@@ -1,7 +1,15 @@
+use std::path::{Component, Path};
+
+use anyhow::{Context, anyhow};
 use command::run_komodo_command;
-use komodo_client::entities::{docker::volume::Volume, update::Log};
+use komodo_client::entities::{
+  docker::volume::{Volume, VolumeEntry},
+  update::Log,
+};
 use periphery_client::api::volume::*;
 use resolver_api::Resolve;
+use shell_escape::unix::escape;
+use uuid::Uuid;
 
 use crate::docker::docker_client;
 
@@ -16,6 +24,163 @@ impl Resolve<super::Args> for InspectVolume {
 
 //
 
+impl Resolve<super::Args> for GetVolumeBrowserListing {
+  #[instrument(name = "GetVolumeBrowserListing", level = "debug")]
+  async fn resolve(
+    self,
+    _: &super::Args,
+  ) -> serror::Result<Vec<VolumeEntry>> {
+    let GetVolumeBrowserListing { volume, path } = self;
+    // Anything other than a plain volume name, ie `/` or `/root`,
+    // would make docker bind mount the host path instead.
+    if !is_volume_name(&volume) {
+      return Err(anyhow!("Invalid volume name: {volume}").into());
+    }
+    docker_client()
+      .inspect_volume(&volume)
+      .await
+      .with_context(|| format!("No volume found named {volume}"))?;
+    let path = path.trim_matches('/');
+    if Path::new(path)
+      .components()
+      .any(|component| matches!(component, Component::ParentDir))
+    {
+      return Err(
+        anyhow!("Volume browser path cannot contain '..'").into(),
+      );
+    }
+    let container =
+      format!("komodo-volume-browser-{}", Uuid::new_v4());
+    let mount = format!("{volume}:/volume:ro");
+    let dir = format!("/volume/{path}");
+    // `-e` prints the full date and time, rather than
+    // dropping the time or year depending on the file age.
+    let command = format!(
+      "docker run --name {container} --network none -v {} alpine ls -lae {}",
+      escape(mount.into()),
+      escape(dir.into()),
+    );
+    let res = run_komodo_command("List Volume", None, command).await;
+
+    // Always remove the temporary container, even on failure.
+    let remove = run_komodo_command(
+      "Remove Volume Browser",
+      None,
+      format!("docker rm -f {container}"),
+    )
+    .await;
+    if !remove.success {
+      warn!(
+        "Failed to remove volume browser container {container} | {}",
+        remove.combined()
+      );
+    }
+
+    if !res.success {
+      return Err(
+        anyhow!("{}", res.combined())
+          .context(format!(
+            "Failed to list volume {volume} at /{path}"
+          ))
+          .into(),
+      );
+    }
+
+    let mut entries = res
+      .stdout
+      .lines()
+      .filter(|line| !line.is_empty() && !line.starts_with("total "))
+      .map(parse_volume_entry)
+      .filter_map(|entry| match entry {
+        Ok(Some(entry)) => Some(Ok(entry)),
+        Ok(None) => None,
+        Err(e) => Some(Err(e)),
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+    entries.sort_by(|a, b| {
+      b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name))
+    });
+    Ok(entries)
+  }
+}
+
+/// Docker volume names match `[a-zA-Z0-9][a-zA-Z0-9_.-]*`.
+fn is_volume_name(volume: &str) -> bool {
+  let mut chars = volume.chars();
+  chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+    && chars.all(|c| {
+      c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-')
+    })
+}
+
+/// Parses a busybox `ls -lae` line, eg:
+/// `-rw-r--r--    1 root     root          1024 Thu Oct 16 17:41:11 2026 file name`
+///
+/// Returns None for the `.` and `..` entries.
+fn parse_volume_entry(
+  line: &str,
+) -> anyhow::Result<Option<VolumeEntry>> {
+  let invalid = || format!("Invalid volume listing line: {line}");
+  let (mut fields, mut name) =
+    split_fields(line, 10).with_context(invalid)?;
+  // Devices list `major, minor` in place of the size
+  let is_device = fields[4].contains(',');
+  if fields[4].ends_with(',') {
+    (fields, name) = split_fields(line, 11).with_context(invalid)?;
+    fields.remove(5);
+  }
+  if name == "." || name == ".." {
+    return Ok(None);
+  }
+  let is_dir = fields[0].starts_with('d');
+  // Symlinks are listed as `name -> target`
+  let name = if fields[0].starts_with('l') {
+    name
+      .split_once(" -> ")
+      .map(|(name, _)| name)
+      .unwrap_or(name)
+  } else {
+    name
+  };
+  let size_bytes = if is_device {
+    0
+  } else {
+    fields[4]
+      .parse()
+      .with_context(|| format!("Invalid size in line: {line}"))?
+  };
+  let modified_at = chrono::NaiveDateTime::parse_from_str(
+    &fields[5..10].join(" "),
+    "%a %b %d %H:%M:%S %Y",
+  )
+  .with_context(|| format!("Invalid modified time in line: {line}"))?
+  // The browser container runs in UTC
+  .and_utc()
+  .timestamp_millis();
+  Ok(Some(VolumeEntry {
+    name: name.to_string(),
+    is_dir,
+    size_bytes,
+    modified_at,
+  }))
+}
+
+/// Splits off the first `n` whitespace separated fields,
+/// returning them along with the rest of the line,
+/// which keeps any whitespace within it.
+fn split_fields(line: &str, n: usize) -> Option<(Vec<&str>, &str)> {
+  let mut fields = Vec::with_capacity(n);
+  let mut rest = line.trim_start();
+  for _ in 0..n {
+    let end = rest.find(char::is_whitespace)?;
+    fields.push(&rest[..end]);
+    rest = rest[end..].trim_start();
+  }
+  (!rest.is_empty()).then_some((fields, rest))
+}
+
+//
+
 impl Resolve<super::Args> for DeleteVolume {
   #[instrument(name = "DeleteVolume")]
   async fn resolve(self, _: &super::Args) -> serror::Result<Log> {
@@ -33,3 +198,91 @@ impl Resolve<super::Args> for PruneVolumes {
     Ok(run_komodo_command("Prune Volumes", None, command).await)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(line: &str) -> VolumeEntry {
+    parse_volume_entry(line).unwrap().unwrap()
+  }
+
+  #[test]
+  fn parses_file() {
+    let entry = parse(
+      "-rw-r--r--    1 root     root          1024 Thu Oct 16 17:41:11 2026 config.toml",
+    );
+    assert_eq!(entry.name, "config.toml");
+    assert!(!entry.is_dir);
+    assert_eq!(entry.size_bytes, 1024);
+    assert_eq!(
+      entry.modified_at,
+      chrono::NaiveDate::from_ymd_opt(2026, 10, 16)
+        .unwrap()
+        .and_hms_opt(17, 41, 11)
+        .unwrap()
+        .and_utc()
+        .timestamp_millis()
+    );
+  }
+
+  #[test]
+  fn parses_directory() {
+    let entry = parse(
+      "drwxr-xr-x    2 root     root          4096 Thu Oct 16 17:41:11 2026 data",
+    );
+    assert_eq!(entry.name, "data");
+    assert!(entry.is_dir);
+  }
+
+  #[test]
+  fn parses_symlink_name() {
+    let entry = parse(
+      "lrwxrwxrwx    1 root     root            11 Thu Oct 16 17:41:11 2026 latest -> data/v2.log",
+    );
+    assert_eq!(entry.name, "latest");
+    assert!(!entry.is_dir);
+  }
+
+  #[test]
+  fn keeps_spaces_in_name() {
+    let entry = parse(
+      "-rw-r--r--    1 1000     1000             3 Thu Oct 16 17:41:11 2026 my  file name.txt",
+    );
+    assert_eq!(entry.name, "my  file name.txt");
+    assert_eq!(entry.size_bytes, 3);
+  }
+
+  #[test]
+  fn parses_device() {
+    let entry = parse(
+      "crw-rw-rw-    1 root     root        1,   3 Thu Oct 16 17:41:11 2026 null",
+    );
+    assert_eq!(entry.name, "null");
+    assert_eq!(entry.size_bytes, 0);
+  }
+
+  #[test]
+  fn skips_current_and_parent_dirs() {
+    for name in [".", ".."] {
+      let line = format!(
+        "drwxr-xr-x    2 root     root          4096 Thu Oct 16 17:41:11 2026 {name}"
+      );
+      assert!(parse_volume_entry(&line).unwrap().is_none());
+    }
+  }
+
+  #[test]
+  fn rejects_short_line() {
+    assert!(parse_volume_entry("-rw-r--r-- 1 root root").is_err());
+  }
+
+  #[test]
+  fn split_fields_keeps_rest() {
+    assert_eq!(
+      split_fields("  a b   c d  e ", 2),
+      Some((vec!["a", "b"], "c d  e "))
+    );
+    assert_eq!(split_fields("a b", 2), None);
+  }
+}
@@ -1,14 +1,130 @@
 use std::{
+  collections::{HashMap, HashSet},
   fmt::Write,
   path::{Path, PathBuf},
+  sync::{OnceLock, RwLock},
 };
 
 use anyhow::{Context, anyhow};
-use formatting::format_serror;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use formatting::{code, format_serror};
 use komodo_client::{
-  entities::{EnvironmentVar, update::Log},
+  entities::{EnvironmentVar, build::BuildSecret, update::Log},
   parsers::QUOTE_PATTERN,
 };
+use periphery_client::api::build::BuildProgress;
+use serde::Deserialize;
+
+/// The progress of in progress builds, keyed by build name.
+pub fn build_progress()
+-> &'static RwLock<HashMap<String, BuildProgress>> {
+  static BUILD_PROGRESS: OnceLock<
+    RwLock<HashMap<String, BuildProgress>>,
+  > = OnceLock::new();
+  BUILD_PROGRESS.get_or_init(Default::default)
+}
+
+/// Renders the `--progress=rawjson` build output as plain text
+/// for the build log, tracking the build step along the way.
+#[derive(Default)]
+pub struct BuildOutputRenderer {
+  /// Vertex digest -> the `#n` it is rendered with
+  vertexes: HashMap<String, usize>,
+  /// The vertexes which have had their start rendered
+  started: HashSet<String>,
+}
+
+/// <https://pkg.go.dev/github.com/moby/buildkit/client#SolveStatus>
+#[derive(Deserialize)]
+struct SolveStatus {
+  #[serde(default)]
+  vertexes: Vec<Vertex>,
+  #[serde(default)]
+  logs: Vec<VertexLog>,
+}
+
+#[derive(Deserialize)]
+struct Vertex {
+  digest: String,
+  #[serde(default)]
+  name: String,
+  #[serde(default)]
+  started: Option<String>,
+  #[serde(default)]
+  completed: Option<String>,
+  #[serde(default)]
+  cached: bool,
+  #[serde(default)]
+  error: String,
+}
+
+#[derive(Deserialize)]
+struct VertexLog {
+  vertex: String,
+  /// Base64 encoded
+  #[serde(default)]
+  data: String,
+}
+
+impl BuildOutputRenderer {
+  /// Returns the text to record in the log, and the latest build step
+  /// if the line started one. Lines which aren't rawjson,
+  /// such as errors from the docker cli, are passed through.
+  pub fn render(
+    &mut self,
+    line: String,
+  ) -> (Option<String>, Option<BuildProgress>) {
+    let Ok(status) = serde_json::from_str::<SolveStatus>(&line)
+    else {
+      return (Some(line), None);
+    };
+    let mut out = Vec::new();
+    let mut progress = None;
+    for vertex in status.vertexes {
+      let next = self.vertexes.len() + 1;
+      let n =
+        *self.vertexes.entry(vertex.digest.clone()).or_insert(next);
+      if vertex.started.is_some()
+        && self.started.insert(vertex.digest)
+      {
+        out.push(format!("#{n} {}", vertex.name));
+        progress = parse_build_step(&vertex.name).or(progress);
+      }
+      if !vertex.error.is_empty() {
+        out.push(format!("#{n} ERROR: {}", vertex.error));
+      } else if vertex.cached {
+        out.push(format!("#{n} CACHED"));
+      } else if vertex.completed.is_some() {
+        out.push(format!("#{n} DONE"));
+      }
+    }
+    for log in status.logs {
+      let Some(n) = self.vertexes.get(&log.vertex) else {
+        continue;
+      };
+      let Ok(data) = STANDARD.decode(&log.data) else {
+        continue;
+      };
+      for line in String::from_utf8_lossy(&data).lines() {
+        out.push(format!("#{n} {line}"));
+      }
+    }
+    let out = (!out.is_empty()).then(|| out.join("\n"));
+    (out, progress)
+  }
+}
+
+/// Parses the build step out of a build vertex name,
+/// eg `[builder 2/5] RUN cargo build` -> 2 / 5.
+pub fn parse_build_step(line: &str) -> Option<BuildProgress> {
+  let (_, rest) = line.split_once('[')?;
+  let (inner, _) = rest.split_once(']')?;
+  let (step, total) = inner.rsplit(' ').next()?.split_once('/')?;
+  Some(BuildProgress {
+    step: step.parse().ok()?,
+    total: total.parse().ok()?,
+  })
+}
 
 pub async fn write_dockerfile(
   build_path: &Path,
@@ -42,7 +158,8 @@ pub async fn write_dockerfile(
     logs.push(Log::simple(
       "Write Dockerfile",
       format!(
-        "Dockerfile contents written to {full_dockerfile_path:?}"
+        "Dockerfile contents written to {}",
+        code(full_dockerfile_path.display())
       ),
     ));
 
@@ -69,6 +186,38 @@ pub fn parse_build_args(build_args: &[EnvironmentVar]) -> String {
     .join("")
 }
 
+/// Multiple platforms are passed as a single comma separated list.
+/// <https://docs.docker.com/build/building/multi-platform/>
+pub fn parse_platform_args(platforms: &[String]) -> String {
+  if platforms.is_empty() {
+    String::new()
+  } else {
+    format!(" --platform {}", platforms.join(","))
+  }
+}
+
+/// <https://docs.docker.com/build/cache/backends/>
+pub fn parse_cache_args(
+  cache_from: &[String],
+  cache_to: &[String],
+) -> String {
+  cache_from
+    .iter()
+    .map(|cache| format!(" --cache-from {cache}"))
+    .chain(
+      cache_to.iter().map(|cache| format!(" --cache-to {cache}")),
+    )
+    .collect()
+}
+
+/// <https://docs.docker.com/reference/cli/docker/buildx/build/#build-context>
+pub fn parse_build_context_args(build_contexts: &[String]) -> String {
+  build_contexts
+    .iter()
+    .map(|context| format!(" --build-context {context}"))
+    .collect()
+}
+
 /// <https://docs.docker.com/build/building/secrets/#using-build-secrets>
 pub async fn parse_secret_args(
   secret_args: &[EnvironmentVar],
@@ -106,3 +255,115 @@ pub async fn parse_secret_args(
   }
   Ok(res)
 }
+
+/// Writes each build secret to a file in `secrets_dir`,
+/// which should be outside the build context so the secrets
+/// are never sent to the builder as part of it.
+/// The caller is responsible for removing `secrets_dir` after the build.
+///
+/// <https://docs.docker.com/build/building/secrets/#using-build-secrets>
+pub async fn write_build_secrets(
+  build_secrets: &[BuildSecret],
+  secrets_dir: &Path,
+) -> anyhow::Result<String> {
+  if build_secrets.is_empty() {
+    return Ok(String::new());
+  }
+  tokio::fs::DirBuilder::new()
+    .recursive(true)
+    .mode(0o700)
+    .create(secrets_dir)
+    .await
+    .with_context(|| {
+      format!(
+        "Failed to create build secrets directory at {}",
+        secrets_dir.display()
+      )
+    })?;
+  let mut res = String::new();
+  for BuildSecret { id, src } in build_secrets {
+    // Check edge cases
+    if id.is_empty() {
+      return Err(anyhow!("build secret id cannot be empty string"));
+    } else if id.contains(['=', ',', '/']) {
+      return Err(anyhow!(
+        "invalid build secret id {id}. id cannot contain '=', ',' or '/'"
+      ));
+    }
+    let path = secrets_dir.join(id);
+    tokio::fs::write(&path, src).await.with_context(|| {
+      format!(
+        "Failed to write build secret {id} to {}",
+        path.display()
+      )
+    })?;
+    write!(&mut res, " --secret id={id},src={}", path.display())
+      .with_context(|| {
+        format!("Failed to format build secret argument for {id}")
+      })?;
+  }
+  Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn cache_args_include_each_flag_verbatim() {
+    let cache_from = [
+      String::from(
+        "type=registry,ref=registry.example.com/app:cache",
+      ),
+      String::from("type=local,src=/tmp/cache"),
+    ];
+    let cache_to = [String::from(
+      "type=registry,ref=registry.example.com/app:cache,mode=max",
+    )];
+    assert_eq!(
+      parse_cache_args(&cache_from, &cache_to),
+      " --cache-from type=registry,ref=registry.example.com/app:cache --cache-from type=local,src=/tmp/cache --cache-to type=registry,ref=registry.example.com/app:cache,mode=max"
+    );
+  }
+
+  #[test]
+  fn cache_args_empty_without_caches() {
+    assert_eq!(parse_cache_args(&[], &[]), "");
+  }
+
+  #[test]
+  fn build_context_args_added_in_order() {
+    let build_contexts = [
+      String::from("shared=../shared"),
+      String::from("alpine=docker-image://alpine:3.20"),
+    ];
+    assert_eq!(
+      parse_build_context_args(&build_contexts),
+      " --build-context shared=../shared --build-context alpine=docker-image://alpine:3.20"
+    );
+  }
+
+  #[test]
+  fn platform_args_single_platform() {
+    assert_eq!(
+      parse_platform_args(&[String::from("linux/arm64")]),
+      " --platform linux/arm64"
+    );
+  }
+
+  #[test]
+  fn platform_args_multi_platform() {
+    assert_eq!(
+      parse_platform_args(&[
+        String::from("linux/amd64"),
+        String::from("linux/arm64"),
+      ]),
+      " --platform linux/amd64,linux/arm64"
+    );
+  }
+
+  #[test]
+  fn platform_args_empty_without_platforms() {
+    assert_eq!(parse_platform_args(&[]), "");
+  }
+}
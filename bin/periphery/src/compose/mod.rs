@@ -53,6 +53,53 @@ pub fn env_file_args(
   Ok(res)
 }
 
+/// The `--profile` args for the stack's compose profiles.
+/// They go after the `--env-file` args.
+pub fn profile_args(compose_profiles: &[String]) -> String {
+  compose_profiles
+    .iter()
+    .filter(|profile| !profile.is_empty())
+    .map(|profile| format!(" --profile {profile}"))
+    .collect()
+}
+
+/// Prefix for compose commands to bound parallelism.
+/// Set inline so it only applies to the single
+/// compose invocation, not the whole periphery process.
+pub fn parallel_limit_env(parallel_limit: Option<u32>) -> String {
+  parallel_limit
+    .map(|limit| format!("COMPOSE_PARALLEL_LIMIT={limit} "))
+    .unwrap_or_default()
+}
+
+/// Get the run directory of a stack which has already been written to the host,
+/// matching the one used by [write::write_stack].
+pub fn stack_run_directory(
+  stack: &Stack,
+  repo: Option<&Repo>,
+) -> PathBuf {
+  let stack_dir = periphery_config()
+    .stack_dir()
+    .join(to_path_compatible_name(&stack.name));
+  let root = if stack.config.files_on_host {
+    stack_dir
+  } else if let Some(repo) = repo {
+    periphery_config()
+      .repo_dir()
+      .join(to_path_compatible_name(&repo.name))
+      .join(&repo.config.path)
+  } else if !stack.config.repo.is_empty() {
+    stack_dir.join(&stack.config.clone_path)
+  } else {
+    // UI defined stacks are written directly to the stack dir
+    return stack_dir.components().collect();
+  };
+  root
+    .join(&stack.config.run_directory)
+    .components()
+    .collect()
+}
+
 pub async fn down(
   project: &str,
   services: &[String],
@@ -141,3 +188,26 @@ pub async fn pull_or_clone_stack(
 
   Ok(root)
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn profile_args_include_each_profile() {
+    let profiles = [
+      String::from("frontend"),
+      String::new(),
+      String::from("debug"),
+    ];
+    assert_eq!(
+      profile_args(&profiles),
+      " --profile frontend --profile debug"
+    );
+  }
+
+  #[test]
+  fn profile_args_empty_without_profiles() {
+    assert_eq!(profile_args(&[]), "");
+  }
+}
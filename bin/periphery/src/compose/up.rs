@@ -1,16 +1,20 @@
-use std::path::{Path, PathBuf};
+use std::{
+  path::{Path, PathBuf},
+  time::{Duration, Instant},
+};
 
 use anyhow::{Context, anyhow};
 use formatting::format_serror;
 use komodo_client::entities::{
   FileContents,
+  docker::container::{ContainerStateStatusEnum, HealthStatusEnum},
   stack::{Stack, StackRemoteFileContents},
   update::Log,
 };
 use periphery_client::api::compose::ComposeUpResponse;
 use tokio::fs;
 
-use crate::docker::docker_login;
+use crate::docker::{docker_client, docker_login};
 
 pub async fn validate_files(
   stack: &Stack,
@@ -68,6 +72,7 @@ pub async fn validate_files(
           res.remote_errors.push(FileContents {
             path: file.path,
             contents: error,
+            base64_contents: false,
           });
           return;
         }
@@ -75,6 +80,7 @@ pub async fn validate_files(
     res.file_contents.push(StackRemoteFileContents {
       path: file.path,
       contents: file_contents,
+      base64_contents: false,
       services: file.services,
       requires: file.requires,
     });
@@ -108,3 +114,94 @@ pub async fn maybe_login_registry(
     ));
   }
 }
+
+const ON_DEPLOY_HEALTH_POLL_INTERVAL: Duration =
+  Duration::from_secs(2);
+
+/// Gets the names of the project containers for the given services,
+/// matched by their compose labels. If `services` is empty,
+/// gets all the project containers.
+pub async fn project_service_containers(
+  project_name: &str,
+  services: &[String],
+) -> anyhow::Result<Vec<String>> {
+  let containers = docker_client()
+    .list_containers()
+    .await
+    .context("Failed to list containers")?
+    .into_iter()
+    .filter(|container| {
+      let label = |name: &str| container.labels.get(name);
+      label("com.docker.compose.project")
+        .is_some_and(|project| project == project_name)
+        && (services.is_empty()
+          || label("com.docker.compose.service")
+            .is_some_and(|service| services.contains(service)))
+    })
+    .map(|container| container.name)
+    .collect();
+  Ok(containers)
+}
+
+/// Polls the given containers until they are all running,
+/// and any configured health checks are passing.
+/// Containers which exited successfully (eg one-shot init services)
+/// are also considered done. Errors after `timeout`.
+pub async fn wait_for_healthy(
+  containers: &[String],
+  timeout: Duration,
+) -> anyhow::Result<()> {
+  let start = Instant::now();
+  loop {
+    let mut pending = Vec::new();
+    for name in containers {
+      let state = docker_client()
+        .inspect_container(name)
+        .await
+        .with_context(|| {
+          format!("Failed to inspect container {name}")
+        })?
+        .state
+        .unwrap_or_default();
+      let health = state.health.map(|health| health.status);
+      match (state.status, health) {
+        (_, Some(HealthStatusEnum::Unhealthy)) => {
+          return Err(anyhow!("Container {name} is unhealthy"));
+        }
+        (
+          ContainerStateStatusEnum::Running,
+          None
+          | Some(
+            HealthStatusEnum::Empty
+            | HealthStatusEnum::None
+            | HealthStatusEnum::Healthy,
+          ),
+        ) => {}
+        (ContainerStateStatusEnum::Exited, _)
+          if state.exit_code == Some(0) => {}
+        (
+          status @ (ContainerStateStatusEnum::Exited
+          | ContainerStateStatusEnum::Dead),
+          _,
+        ) => {
+          return Err(anyhow!(
+            "Container {name} is {status} | exit code: {:?}",
+            state.exit_code
+          ));
+        }
+        _ => pending.push(name.as_str()),
+      }
+    }
+    if pending.is_empty() {
+      return Ok(());
+    }
+    if start.elapsed() > timeout {
+      return Err(anyhow!(
+        "Timed out after {}s waiting for containers: {}",
+        timeout.as_secs(),
+        pending.join(", ")
+      ));
+    }
+    tokio::time::sleep(ON_DEPLOY_HEALTH_POLL_INTERVAL).await;
+  }
+}
@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use anyhow::{Context, anyhow};
+use base64::Engine;
 use formatting::format_serror;
 use komodo_client::entities::{
   FileContents, RepoExecutionArgs, all_logs_success, repo::Repo,
@@ -376,7 +377,23 @@ fn stack_git_token<R: WriteStackRes>(
     res.add_remote_error(FileContents {
       path: Default::default(),
       contents: error,
+      base64_contents: false,
     });
     anyhow!("failed to find required git token, stopping run")
   })
 }
+
+/// Gets the bytes to write to a stack file,
+/// decoding the contents if they are base64 encoded (eg binary files).
+pub fn decode_file_contents(
+  contents: String,
+  base64_contents: bool,
+) -> anyhow::Result<Vec<u8>> {
+  if base64_contents {
+    base64::engine::general_purpose::STANDARD
+      .decode(contents.trim())
+      .context("Failed to decode base64 file contents")
+  } else {
+    Ok(contents.into_bytes())
+  }
+}
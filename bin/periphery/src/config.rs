@@ -1,9 +1,15 @@
-use std::{path::PathBuf, sync::OnceLock};
+use std::{
+  path::PathBuf,
+  sync::OnceLock,
+  time::{Duration, Instant},
+};
 
 use clap::Parser;
 use colored::Colorize;
 use config::ConfigLoader;
-use environment_file::maybe_read_list_from_file;
+use environment_file::{
+  maybe_read_item_from_file, maybe_read_list_from_file,
+};
 use komodo_client::entities::{
   config::periphery::{CliArgs, Env, PeripheryConfig},
   logger::{LogConfig, LogLevel},
@@ -71,6 +77,12 @@ pub fn periphery_config() -> &'static PeripheryConfig {
       disable_container_exec: env
         .periphery_disable_container_exec
         .unwrap_or(config.disable_container_exec),
+      terminal_idle_timeout: env
+        .periphery_terminal_idle_timeout
+        .unwrap_or(config.terminal_idle_timeout),
+      max_terminals: env
+        .periphery_max_terminals
+        .unwrap_or(config.max_terminals),
       stats_polling_rate: env
         .periphery_stats_polling_rate
         .unwrap_or(config.stats_polling_rate),
@@ -113,6 +125,14 @@ pub fn periphery_config() -> &'static PeripheryConfig {
         env.periphery_passkeys,
       )
       .unwrap_or(config.passkeys),
+      pending_passkey: maybe_read_item_from_file(
+        env.periphery_pending_passkey_file,
+        env.periphery_pending_passkey,
+      )
+      .or(config.pending_passkey),
+      passkey_rotation_grace_period_seconds: env
+        .periphery_passkey_rotation_grace_period_seconds
+        .unwrap_or(config.passkey_rotation_grace_period_seconds),
       include_disk_mounts: env
         .periphery_include_disk_mounts
         .unwrap_or(config.include_disk_mounts),
@@ -128,9 +148,22 @@ pub fn periphery_config() -> &'static PeripheryConfig {
       ssl_cert_file: env
         .periphery_ssl_cert_file
         .or(config.ssl_cert_file),
+      tls_ca_cert: env.periphery_tls_ca_cert.or(config.tls_ca_cert),
       secrets: config.secrets,
       git_providers: config.git_providers,
       docker_registries: config.docker_registries,
     }
   })
 }
+
+/// The time after which the `pending_passkey` is no longer accepted.
+/// Initialized on startup, so the grace period runs from then.
+pub fn pending_passkey_expiry() -> Instant {
+  static PENDING_PASSKEY_EXPIRY: OnceLock<Instant> = OnceLock::new();
+  *PENDING_PASSKEY_EXPIRY.get_or_init(|| {
+    Instant::now()
+      + Duration::from_secs(
+        periphery_config().passkey_rotation_grace_period_seconds,
+      )
+  })
+}
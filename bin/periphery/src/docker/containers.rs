@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
-use anyhow::Context;
+use anyhow::{Context, anyhow};
 use bollard::query_parameters::{
-  InspectContainerOptions, ListContainersOptions,
+  DownloadFromContainerOptions, InspectContainerOptions,
+  ListContainersOptions,
 };
+use futures::StreamExt;
 use komodo_client::entities::docker::{
   ContainerConfig, GraphDriverData, HealthConfig, PortBinding,
   container::*,
@@ -11,6 +13,21 @@ use komodo_client::entities::docker::{
 
 use super::{DockerClient, stats::container_stats};
 
+/// The container list doesn't include health directly,
+/// but docker adds it to the status, eg `Up 5 minutes (unhealthy)`.
+/// Containers without a healthcheck have no health in the status.
+fn health_from_status(status: &str) -> Option<HealthStatusEnum> {
+  if status.ends_with("(healthy)") {
+    Some(HealthStatusEnum::Healthy)
+  } else if status.ends_with("(unhealthy)") {
+    Some(HealthStatusEnum::Unhealthy)
+  } else if status.ends_with("(health: starting)") {
+    Some(HealthStatusEnum::Starting)
+  } else {
+    None
+  }
+}
+
 impl DockerClient {
   pub async fn list_containers(
     &self,
@@ -46,6 +63,10 @@ impl DockerClient {
           state: convert_summary_container_state(
             container.state.context("no container state")?,
           ),
+          health: container
+            .status
+            .as_deref()
+            .and_then(health_from_status),
           status: container.status,
           network_mode: container
             .host_config
@@ -101,6 +122,34 @@ impl DockerClient {
     Ok(containers)
   }
 
+  /// Downloads the tar archive of the path in the container.
+  /// Stops downloading and errors once the archive
+  /// goes over `limit` bytes.
+  pub async fn download_from_container(
+    &self,
+    container_name: &str,
+    path: &str,
+    limit: u64,
+  ) -> anyhow::Result<Vec<u8>> {
+    let mut stream = self.docker.download_from_container(
+      container_name,
+      Some(DownloadFromContainerOptions {
+        path: path.to_string(),
+      }),
+    );
+    let mut archive = Vec::new();
+    while let Some(chunk) = stream.next().await {
+      let chunk = chunk.with_context(|| {
+        format!("Failed to download {path} from {container_name}")
+      })?;
+      if (archive.len() + chunk.len()) as u64 > limit {
+        return Err(anyhow!("{path} is over the {limit} byte limit"));
+      }
+      archive.extend_from_slice(&chunk);
+    }
+    Ok(archive)
+  }
+
   pub async fn inspect_container(
     &self,
     container_name: &str,
@@ -757,3 +806,26 @@ fn convert_isolation_mode(
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn health_parsed_from_status() {
+    assert_eq!(
+      health_from_status("Up 5 minutes (healthy)"),
+      Some(HealthStatusEnum::Healthy)
+    );
+    assert_eq!(
+      health_from_status("Up 2 hours (unhealthy)"),
+      Some(HealthStatusEnum::Unhealthy)
+    );
+    assert_eq!(
+      health_from_status("Up 3 seconds (health: starting)"),
+      Some(HealthStatusEnum::Starting)
+    );
+    assert_eq!(health_from_status("Up 5 minutes"), None);
+    assert_eq!(health_from_status("Exited (0) 2 minutes ago"), None);
+  }
+}
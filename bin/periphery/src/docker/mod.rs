@@ -1,4 +1,10 @@
-use std::sync::OnceLock;
+use std::{
+  sync::{
+    OnceLock,
+    atomic::{AtomicBool, Ordering},
+  },
+  time::Duration,
+};
 
 use anyhow::anyhow;
 use bollard::Docker;
@@ -31,6 +37,38 @@ impl Default for DockerClient {
   }
 }
 
+impl DockerClient {
+  /// Whether the docker daemon responds to ping.
+  pub async fn is_available(&self) -> bool {
+    tokio::time::timeout(Duration::from_secs(2), self.docker.ping())
+      .await
+      .is_ok_and(|res| res.is_ok())
+  }
+}
+
+static DOCKER_AVAILABLE: AtomicBool = AtomicBool::new(false);
+
+const DOCKER_AVAILABLE_POLL_INTERVAL: Duration =
+  Duration::from_secs(10);
+
+/// Whether the docker daemon responded to the latest ping.
+/// Core health checks Periphery before every request,
+/// so this is read from the background poll
+/// rather than pinging docker each time.
+pub fn docker_available() -> bool {
+  DOCKER_AVAILABLE.load(Ordering::Relaxed)
+}
+
+pub fn spawn_availability_thread() {
+  tokio::spawn(async move {
+    loop {
+      let available = docker_client().is_available().await;
+      DOCKER_AVAILABLE.store(available, Ordering::Relaxed);
+      tokio::time::sleep(DOCKER_AVAILABLE_POLL_INTERVAL).await;
+    }
+  });
+}
+
 /// Returns whether build result should be pushed after build
 #[instrument(skip(registry_token))]
 pub async fn docker_login(
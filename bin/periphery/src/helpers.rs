@@ -1,3 +1,5 @@
+use std::{sync::OnceLock, time::Instant};
+
 use anyhow::Context;
 use komodo_client::{
   entities::{EnvironmentVar, RepoExecutionArgs, SearchCombinator},
@@ -6,6 +8,13 @@ use komodo_client::{
 
 use crate::config::periphery_config;
 
+/// The time Periphery started.
+/// Initialized on startup in main.rs.
+pub fn start_time() -> Instant {
+  static START_TIME: OnceLock<Instant> = OnceLock::new();
+  *START_TIME.get_or_init(Instant::now)
+}
+
 pub fn git_token_simple(
   domain: &str,
   account_username: &str,
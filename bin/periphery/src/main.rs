@@ -20,6 +20,7 @@ mod stats;
 mod terminal;
 
 async fn app() -> anyhow::Result<()> {
+  helpers::start_time();
   dotenvy::dotenv().ok();
   let config = config::periphery_config();
   logger::init(&config.logging)?;
@@ -32,8 +33,19 @@ async fn app() -> anyhow::Result<()> {
     info!("{:?}", config.sanitized());
   }
 
+  if config.pending_passkey.is_some() {
+    // Starts the grace period for the pending passkey.
+    config::pending_passkey_expiry();
+    info!(
+      "Accepting pending passkey for {}s",
+      config.passkey_rotation_grace_period_seconds
+    );
+  }
+
   stats::spawn_polling_thread();
   docker::stats::spawn_polling_thread();
+  docker::spawn_availability_thread();
+  terminal::spawn_reaper_thread();
 
   let addr = format!(
     "{}:{}",
@@ -54,12 +66,17 @@ async fn app() -> anyhow::Result<()> {
       .expect("failed to install default rustls CryptoProvider");
     ssl::ensure_certs().await;
     info!("Komodo Periphery starting on https://{}", socket_addr);
-    let ssl_config = RustlsConfig::from_pem_file(
-      config.ssl_cert_file(),
-      config.ssl_key_file(),
-    )
-    .await
-    .context("Invalid ssl cert / key")?;
+    let ssl_config = if let Some(ca_cert) = &config.tls_ca_cert {
+      info!("🔒 Periphery mTLS Enabled");
+      ssl::mtls_config(ca_cert)?
+    } else {
+      RustlsConfig::from_pem_file(
+        config.ssl_cert_file(),
+        config.ssl_key_file(),
+      )
+      .await
+      .context("Invalid ssl cert / key")?
+    };
     axum_server::bind_rustls(socket_addr, ssl_config)
       .serve(app)
       .await?
@@ -1,3 +1,13 @@
+use std::{path::Path, sync::Arc};
+
+use anyhow::Context;
+use axum_server::tls_rustls::RustlsConfig;
+use rustls::{
+  RootCertStore, ServerConfig,
+  pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+  server::WebPkiClientVerifier,
+};
+
 use crate::config::periphery_config;
 
 pub async fn ensure_certs() {
@@ -43,3 +53,37 @@ async fn generate_self_signed_ssl_certs() {
     );
   }
 }
+
+/// Serves with the ssl cert / key, and requires clients
+/// to present a certificate signed by the CA at `ca_cert`.
+pub fn mtls_config(ca_cert: &Path) -> anyhow::Result<RustlsConfig> {
+  let config = periphery_config();
+
+  let mut roots = RootCertStore::empty();
+  for cert in CertificateDer::pem_file_iter(ca_cert)
+    .context("Failed to read tls_ca_cert")?
+  {
+    roots
+      .add(cert.context("Invalid cert in tls_ca_cert")?)
+      .context("Invalid CA cert")?;
+  }
+  let verifier = WebPkiClientVerifier::builder(Arc::new(roots))
+    .build()
+    .context("Failed to build client cert verifier")?;
+
+  let certs = CertificateDer::pem_file_iter(config.ssl_cert_file())
+    .context("Failed to read ssl cert")?
+    .collect::<Result<Vec<_>, _>>()
+    .context("Invalid ssl cert")?;
+  let key = PrivateKeyDer::from_pem_file(config.ssl_key_file())
+    .context("Failed to read ssl key")?;
+
+  let mut server_config = ServerConfig::builder()
+    .with_client_cert_verifier(verifier)
+    .with_single_cert(certs, key)
+    .context("Invalid ssl cert / key")?;
+  server_config.alpn_protocols =
+    vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+
+  Ok(RustlsConfig::from_config(Arc::new(server_config)))
+}
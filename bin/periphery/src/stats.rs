@@ -1,9 +1,12 @@
-use std::{cmp::Ordering, sync::OnceLock};
+use std::{
+  cmp::Ordering, ffi::CString, mem::MaybeUninit,
+  os::unix::ffi::OsStrExt, path::Path, sync::OnceLock,
+};
 
 use async_timing_util::wait_until_timelength;
 use komodo_client::entities::stats::{
-  SingleDiskUsage, SystemInformation, SystemLoadAverage,
-  SystemProcess, SystemStats,
+  SingleDiskUsage, SingleNetworkInterfaceUsage, SystemInformation,
+  SystemLoadAverage, SystemProcess, SystemStats,
 };
 use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
 use tokio::sync::RwLock;
@@ -91,10 +94,39 @@ impl StatsClient {
     let mut network_ingress_bytes: u64 = 0;
     let mut network_egress_bytes: u64 = 0;
 
-    for (_, network) in self.networks.iter() {
+    // received / transmitted are since the last refresh,
+    // which happens once per polling rate.
+    let polling_secs =
+      (self.stats.polling_rate.as_millis() as f64 / 1000.0).max(1.0);
+
+    let mut network_interfaces = Vec::new();
+    for (name, network) in self.networks.iter() {
       network_ingress_bytes += network.received();
       network_egress_bytes += network.transmitted();
+      network_interfaces.push(SingleNetworkInterfaceUsage {
+        name: name.clone(),
+        ingress_bytes: network.received() as f64,
+        egress_bytes: network.transmitted() as f64,
+        total_ingress_bytes: network.total_received() as f64,
+        total_egress_bytes: network.total_transmitted() as f64,
+        ingress_bytes_per_sec: network.received() as f64
+          / polling_secs,
+        egress_bytes_per_sec: network.transmitted() as f64
+          / polling_secs,
+      });
     }
+    network_interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let (network_ingress_bytes_per_sec, network_egress_bytes_per_sec) =
+      network_interfaces
+        .iter()
+        .filter(|network| network.name != "lo")
+        .fold((0.0, 0.0), |(ingress, egress), network| {
+          (
+            ingress + network.ingress_bytes_per_sec,
+            egress + network.egress_bytes_per_sec,
+          )
+        });
 
     let load_avg = System::load_average();
 
@@ -110,6 +142,9 @@ impl StatsClient {
       mem_total_gb: total_mem as f64 / BYTES_PER_GB,
       network_ingress_bytes: network_ingress_bytes as f64,
       network_egress_bytes: network_egress_bytes as f64,
+      network_ingress_bytes_per_sec,
+      network_egress_bytes_per_sec,
+      network_interfaces,
       disks: self.get_disks(),
       polling_rate: self.stats.polling_rate,
       refresh_ts: self.stats.refresh_ts,
@@ -117,6 +152,18 @@ impl StatsClient {
     }
   }
 
+  /// The available space in bytes on the disk containing `path`,
+  /// as of the last refresh.
+  pub fn available_space(&self, path: &Path) -> Option<u64> {
+    self
+      .disks
+      .list()
+      .iter()
+      .filter(|disk| path.starts_with(disk.mount_point()))
+      .max_by_key(|disk| disk.mount_point().as_os_str().len())
+      .map(|disk| disk.available_space())
+  }
+
   fn get_disks(&self) -> Vec<SingleDiskUsage> {
     let config = periphery_config();
     self
@@ -148,11 +195,15 @@ impl StatsClient {
           disk.file_system().to_string_lossy().to_string();
         let disk_total = disk.total_space() as f64 / BYTES_PER_GB;
         let disk_free = disk.available_space() as f64 / BYTES_PER_GB;
+        let (inodes_used, inodes_total) =
+          get_inode_usage(disk.mount_point());
         SingleDiskUsage {
           mount: disk.mount_point().to_owned(),
           used_gb: disk_total - disk_free,
           total_gb: disk_total,
           file_system,
+          inodes_used,
+          inodes_total,
         }
       })
       .collect()
@@ -218,3 +269,21 @@ fn get_system_information(
     container_exec_disabled: config.disable_container_exec,
   }
 }
+
+/// Returns the (used, total) inodes of the file system mounted
+/// at the path. Both are zero if they can't be read,
+/// or the file system doesn't have a fixed inode table.
+fn get_inode_usage(mount: &Path) -> (u64, u64) {
+  let Ok(mount) = CString::new(mount.as_os_str().as_bytes()) else {
+    return (0, 0);
+  };
+  let mut stat = MaybeUninit::<libc::statvfs>::uninit();
+  if unsafe { libc::statvfs(mount.as_ptr(), stat.as_mut_ptr()) } != 0
+  {
+    return (0, 0);
+  }
+  let stat = unsafe { stat.assume_init() };
+  let total = stat.f_files as u64;
+  let free = stat.f_ffree as u64;
+  (total.saturating_sub(free), total)
+}
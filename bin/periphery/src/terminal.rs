@@ -1,7 +1,10 @@
 use std::{
   collections::{HashMap, VecDeque},
   pin::Pin,
-  sync::{Arc, OnceLock},
+  sync::{
+    Arc, OnceLock,
+    atomic::{AtomicI64, Ordering},
+  },
   task::Poll,
   time::Duration,
 };
@@ -21,6 +24,8 @@ use serror::AddStatusCodeError;
 use tokio::sync::{broadcast, mpsc};
 use tokio_util::sync::CancellationToken;
 
+use crate::config::periphery_config;
+
 type PtyName = String;
 type PtyMap = tokio::sync::RwLock<HashMap<PtyName, Arc<Terminal>>>;
 type StdinSender = mpsc::Sender<StdinMsg>;
@@ -48,6 +53,16 @@ pub async fn create_terminal(
       ));
     }
   }
+  let max_terminals = periphery_config().max_terminals;
+  if max_terminals > 0 && !terminals.contains_key(&name) {
+    // Exited terminals shouldn't count towards the limit
+    terminals.retain(|_, terminal| !terminal.cancel.is_cancelled());
+    if terminals.len() >= max_terminals {
+      return Err(anyhow!(
+        "Server has reached the maximum of {max_terminals} terminals. Delete an existing terminal before creating another."
+      ));
+    }
+  }
   if let Some(prev) = terminals.insert(
     name,
     Terminal::new(command)
@@ -75,6 +90,7 @@ pub async fn list_terminals() -> Vec<TerminalInfo> {
       name: name.to_string(),
       command: terminal.command.clone(),
       stored_size_kb: terminal.history.size_kb(),
+      idle_ms: terminal.idle_ms(),
     })
     .collect::<Vec<_>>();
   terminals.sort_by(|a, b| a.name.cmp(&b.name));
@@ -110,6 +126,41 @@ pub async fn delete_all_terminals() {
   tokio::time::sleep(Duration::from_millis(100)).await;
 }
 
+/// How often to check for idle terminals to reap.
+const REAP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// This should be called before starting the server in main.rs.
+/// Deletes terminals which have been idle longer than `terminal_idle_timeout`.
+pub fn spawn_reaper_thread() {
+  tokio::spawn(async move {
+    let timeout_ms =
+      periphery_config().terminal_idle_timeout.as_millis();
+    loop {
+      tokio::time::sleep(REAP_INTERVAL).await;
+      let now = komodo_timestamp();
+      terminals().write().await.retain(|name, terminal| {
+        if should_reap(terminal.last_activity(), now, timeout_ms) {
+          info!("Reaping idle terminal {name}");
+          terminal.cancel();
+          false
+        } else {
+          true
+        }
+      });
+    }
+  });
+}
+
+/// Whether a terminal with the given last activity
+/// timestamp has been idle for longer than the timeout.
+fn should_reap(
+  last_activity: i64,
+  now: i64,
+  timeout_ms: i64,
+) -> bool {
+  now.saturating_sub(last_activity) > timeout_ms
+}
+
 fn terminals() -> &'static PtyMap {
   static TERMINALS: OnceLock<PtyMap> = OnceLock::new();
   TERMINALS.get_or_init(Default::default)
@@ -137,6 +188,9 @@ pub struct Terminal {
   pub stdout: StdoutReceiver,
 
   pub history: Arc<History>,
+
+  /// Timestamp (ms) of the last terminal input or output
+  last_activity: Arc<AtomicI64>,
 }
 
 impl Terminal {
@@ -187,6 +241,7 @@ impl Terminal {
       .context("Failed to clone terminal reader")?;
 
     let cancel = CancellationToken::new();
+    let last_activity = Arc::new(AtomicI64::new(komodo_timestamp()));
 
     // CHILD WAIT TASK
     let _cancel = cancel.clone();
@@ -222,13 +277,18 @@ impl Terminal {
     let (stdin, mut channel_read) =
       tokio::sync::mpsc::channel::<StdinMsg>(8192);
     let _cancel = cancel.clone();
+    let _last_activity = last_activity.clone();
     tokio::task::spawn_blocking(move || {
       loop {
         if _cancel.is_cancelled() {
           trace!("terminal write: cancelled from outside");
           break;
         }
-        match channel_read.blocking_recv() {
+        let msg = channel_read.blocking_recv();
+        if msg.is_some() {
+          _last_activity.store(komodo_timestamp(), Ordering::Relaxed);
+        }
+        match msg {
           Some(StdinMsg::Bytes(bytes)) => {
             if let Err(e) = terminal_write.write_all(&bytes) {
               debug!("Failed to write to PTY: {e:?}");
@@ -265,6 +325,7 @@ impl Terminal {
       tokio::sync::broadcast::channel::<Bytes>(8192);
     let _cancel = cancel.clone();
     let _history = history.clone();
+    let _last_activity = last_activity.clone();
     tokio::task::spawn_blocking(move || {
       let mut buf = [0u8; 8192];
       loop {
@@ -280,6 +341,8 @@ impl Terminal {
             break;
           }
           Ok(n) => {
+            _last_activity
+              .store(komodo_timestamp(), Ordering::Relaxed);
             _history.push(&buf[..n]);
             if let Err(e) =
               write.send(Bytes::copy_from_slice(&buf[..n]))
@@ -306,6 +369,7 @@ impl Terminal {
       stdin,
       stdout,
       history,
+      last_activity,
     })
   }
 
@@ -313,6 +377,16 @@ impl Terminal {
     trace!("Cancel called");
     self.cancel.cancel();
   }
+
+  /// Timestamp (ms) of the last terminal input or output
+  pub fn last_activity(&self) -> i64 {
+    self.last_activity.load(Ordering::Relaxed)
+  }
+
+  /// Milliseconds since the last terminal input or output
+  pub fn idle_ms(&self) -> i64 {
+    komodo_timestamp().saturating_sub(self.last_activity())
+  }
 }
 
 /// 1 MiB rolling max history size per terminal
@@ -443,3 +517,31 @@ impl AuthTokens {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::should_reap;
+
+  const TIMEOUT_MS: i64 = 60 * 60 * 1000;
+
+  #[test]
+  fn reaps_terminal_idle_beyond_timeout() {
+    let now = 10 * TIMEOUT_MS;
+    assert!(should_reap(now - TIMEOUT_MS - 1, now, TIMEOUT_MS));
+  }
+
+  #[test]
+  fn keeps_terminal_within_timeout() {
+    let now = 10 * TIMEOUT_MS;
+    assert!(!should_reap(now, now, TIMEOUT_MS));
+    assert!(!should_reap(now - TIMEOUT_MS + 1, now, TIMEOUT_MS));
+    assert!(!should_reap(now - TIMEOUT_MS, now, TIMEOUT_MS));
+  }
+
+  #[test]
+  fn keeps_terminal_with_activity_after_now() {
+    // Activity can be recorded between reading `now` and the check.
+    let now = 10 * TIMEOUT_MS;
+    assert!(!should_reap(now + 1_000, now, TIMEOUT_MS));
+  }
+}
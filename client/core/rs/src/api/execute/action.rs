@@ -5,7 +5,7 @@ use resolver_api::Resolve;
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::{JsonObject, update::Update};
+use crate::entities::{JsonObject, SearchCombinator, update::Update};
 
 use super::{BatchExecutionResponse, KomodoExecuteRequest};
 
@@ -67,4 +67,8 @@ pub struct BatchRunAction {
   /// extra-action-1, extra-action-2
   /// ```
   pub pattern: String,
+  /// `And` to only match resources matching all the patterns.
+  /// Default: `Or`, matching resources which match any pattern.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub combinator: Option<SearchCombinator>,
 }
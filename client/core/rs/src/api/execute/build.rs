@@ -4,7 +4,7 @@ use resolver_api::Resolve;
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::update::Update;
+use crate::entities::{SearchCombinator, update::Update};
 
 use super::{BatchExecutionResponse, KomodoExecuteRequest};
 
@@ -71,6 +71,10 @@ pub struct BatchRunBuild {
   /// extra-build-1, extra-build-2
   /// ```
   pub pattern: String,
+  /// `And` to only match resources matching all the patterns.
+  /// Default: `Or`, matching resources which match any pattern.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub combinator: Option<SearchCombinator>,
 }
 
 //
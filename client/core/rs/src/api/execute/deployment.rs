@@ -4,7 +4,9 @@ use resolver_api::Resolve;
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::{TerminationSignal, update::Update};
+use crate::entities::{
+  SearchCombinator, TerminationSignal, update::Update,
+};
 
 use super::{BatchExecutionResponse, KomodoExecuteRequest};
 
@@ -69,6 +71,10 @@ pub struct BatchDeploy {
   /// extra-deployment-1, extra-deployment-2
   /// ```
   pub pattern: String,
+  /// `And` to only match resources matching all the patterns.
+  /// Default: `Or`, matching resources which match any pattern.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub combinator: Option<SearchCombinator>,
 }
 
 //
@@ -277,4 +283,8 @@ pub struct BatchDestroyDeployment {
   /// extra-deployment-1, extra-deployment-2
   /// ```
   pub pattern: String,
+  /// `And` to only match resources matching all the patterns.
+  /// Default: `Or`, matching resources which match any pattern.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub combinator: Option<SearchCombinator>,
 }
@@ -4,7 +4,10 @@ use resolver_api::Resolve;
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::update::Update;
+use crate::entities::{
+  ResourceTargetVariant, config::cli::BackupCompression,
+  update::Update,
+};
 
 use super::KomodoExecuteRequest;
 
@@ -33,6 +36,16 @@ pub struct ClearRepoCache {}
 /// timestamped database dumps, which can be restored using
 /// the Komodo CLI.
 ///
+/// Set `KOMODO_CLI_BACKUP_ENCRYPTION_KEY` in the Core
+/// environment to encrypt the backup files.
+///
+/// With a `remote_target`, the new backup is also uploaded to
+/// S3, GCS or Azure Blob Storage, and old remote backups are pruned
+/// using the same `max_backups` (KOMODO_CLI_MAX_BACKUPS) limit.
+/// The target is referenced by name from the Core config
+/// `backup_remote_targets`, so the credentials never
+/// appear in the request or in Procedures which run it.
+///
 /// https://komo.do/docs/setup/backup
 #[typeshare]
 #[derive(
@@ -48,7 +61,51 @@ pub struct ClearRepoCache {}
 #[empty_traits(KomodoExecuteRequest)]
 #[response(Update)]
 #[error(serror::Error)]
-pub struct BackupCoreDatabase {}
+pub struct BackupCoreDatabase {
+  /// Override the backup compression configured for the CLI.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  #[arg(long, short = 'c')]
+  pub compression: Option<BackupCompression>,
+  /// Also upload the backup to the remote object store
+  /// configured with this name in the Core config `backup_remote_targets`.
+  #[serde(default, skip_serializing_if = "String::is_empty")]
+  #[arg(long, short = 'r', default_value_t = String::new())]
+  pub remote_target: String,
+}
+
+/// Restores the Komodo Core database from a backup folder
+/// created by [BackupCoreDatabase]. Super admin only. Response: [Update]
+///
+/// **This is destructive.** Each collection in the backup is dropped
+/// and recreated from the backup files, so anything created or
+/// changed since the backup is lost. Restart Core afterwards
+/// to clear any cached state.
+///
+/// Encrypted backups are decrypted using `KOMODO_CLI_BACKUP_ENCRYPTION_KEY`
+/// in the Core environment.
+#[typeshare]
+#[derive(
+  Debug,
+  Clone,
+  PartialEq,
+  Serialize,
+  Deserialize,
+  Resolve,
+  EmptyTraits,
+  Parser,
+)]
+#[empty_traits(KomodoExecuteRequest)]
+#[response(Update)]
+#[error(serror::Error)]
+pub struct RestoreDatabase {
+  /// The timestamped backup folder to restore, either its name
+  /// or its full path in the Core container. It must be directly
+  /// inside the configured backups folder.
+  /// Example: `2025-08-01_05-04-53` or `/backups/2025-08-01_05-04-53`
+  pub backup_path: String,
+  /// Must be exactly `I understand this is destructive`.
+  pub confirmation: String,
+}
 
 /// Trigger a global poll for image updates on Stacks and Deployments
 /// with `poll_for_updates` or `auto_update` enabled.
@@ -56,6 +113,14 @@ pub struct BackupCoreDatabase {}
 ///
 /// 1. `docker compose pull` any Stacks / Deployments with `poll_for_updates` or `auto_update` enabled. This will pick up any available updates.
 /// 2. Redeploy Stacks / Deployments that have updates found and 'auto_update' enabled.
+///
+/// The filters can scope the run to specific resources,
+/// for example to update staging before production.
+///
+/// With `dry_run`, nothing is pulled or redeployed. Instead the
+/// registry is checked for each running image, and the results are
+/// set on the Update `output` as a list of
+/// [ImageUpdateCheck][crate::entities::docker::image::ImageUpdateCheck].
 #[typeshare]
 #[derive(
   Debug,
@@ -70,4 +135,25 @@ pub struct BackupCoreDatabase {}
 #[empty_traits(KomodoExecuteRequest)]
 #[response(Update)]
 #[error(serror::Error)]
-pub struct GlobalAutoUpdate {}
+pub struct GlobalAutoUpdate {
+  /// Only report the available image updates,
+  /// without pulling or redeploying anything.
+  #[serde(default)]
+  #[arg(long, default_value_t = false)]
+  pub dry_run: bool,
+  /// Only include resources with all of these tags (id or name).
+  /// If empty, doesn't filter by tag.
+  #[serde(default)]
+  #[arg(long = "tag", short = 't')]
+  pub filter_tags: Vec<String>,
+  /// Only include resources on these servers (id or name).
+  /// If empty, doesn't filter by server.
+  #[serde(default)]
+  #[arg(long = "server", short = 's')]
+  pub filter_server_ids: Vec<String>,
+  /// Only include these resource types, `Stack` and / or `Deployment`.
+  /// If empty, includes both.
+  #[serde(default)]
+  #[arg(long = "resource-type", short = 'r')]
+  pub filter_resource_types: Vec<ResourceTargetVariant>,
+}
@@ -13,6 +13,7 @@ mod maintenance;
 mod procedure;
 mod repo;
 mod server;
+mod server_group;
 mod stack;
 mod sync;
 
@@ -24,6 +25,7 @@ pub use maintenance::*;
 pub use procedure::*;
 pub use repo::*;
 pub use server::*;
+pub use server_group::*;
 pub use stack::*;
 pub use sync::*;
 
@@ -128,6 +130,11 @@ pub enum Execution {
   PruneBuildx(PruneBuildx),
   PruneSystem(PruneSystem),
 
+  // SERVER GROUP
+  RunCommandOnGroup(RunCommandOnGroup),
+  PruneImagesOnGroup(PruneImagesOnGroup),
+  PruneSystemOnGroup(PruneSystemOnGroup),
+
   // SYNC
   /// Execute a Resource Sync. (alias: `sync`)
   #[clap(alias = "sync")]
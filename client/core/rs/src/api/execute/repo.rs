@@ -4,7 +4,7 @@ use resolver_api::Resolve;
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::update::Update;
+use crate::entities::{SearchCombinator, update::Update};
 
 use super::{BatchExecutionResponse, KomodoExecuteRequest};
 
@@ -67,6 +67,10 @@ pub struct BatchCloneRepo {
   /// extra-repo-1, extra-repo-2
   /// ```
   pub pattern: String,
+  /// `And` to only match resources matching all the patterns.
+  /// Default: `Or`, matching resources which match any pattern.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub combinator: Option<SearchCombinator>,
 }
 
 //
@@ -125,6 +129,10 @@ pub struct BatchPullRepo {
   /// extra-repo-1, extra-repo-2
   /// ```
   pub pattern: String,
+  /// `And` to only match resources matching all the patterns.
+  /// Default: `Or`, matching resources which match any pattern.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub combinator: Option<SearchCombinator>,
 }
 
 //
@@ -187,6 +195,10 @@ pub struct BatchBuildRepo {
   /// extra-repo-1, extra-repo-2
   /// ```
   pub pattern: String,
+  /// `And` to only match resources matching all the patterns.
+  /// Default: `Or`, matching resources which match any pattern.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub combinator: Option<SearchCombinator>,
 }
 
 //
@@ -0,0 +1,90 @@
+use clap::Parser;
+use derive_empty_traits::EmptyTraits;
+use resolver_api::Resolve;
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use crate::entities::update::Update;
+
+use super::KomodoExecuteRequest;
+
+/// Runs a shell command on all the servers in the group,
+/// in parallel. Response: [Update].
+///
+/// Requires the `Terminal` permission on the server group,
+/// and is skipped on member servers with terminals disabled.
+#[typeshare]
+#[derive(
+  Serialize,
+  Deserialize,
+  Debug,
+  Clone,
+  PartialEq,
+  Resolve,
+  EmptyTraits,
+  Parser,
+)]
+#[empty_traits(KomodoExecuteRequest)]
+#[response(Update)]
+#[error(serror::Error)]
+pub struct RunCommandOnGroup {
+  /// Id or name
+  pub server_group: String,
+  /// The command to run.
+  pub command: String,
+  /// The directory to run the command in.
+  /// If empty, uses the Periphery working directory.
+  #[serde(default)]
+  #[arg(long, short = 'p', default_value_t = String::new())]
+  pub path: String,
+}
+
+//
+
+/// Prunes the docker images on all the servers in the group,
+/// in parallel. Response: [Update].
+///
+/// 1. Runs `docker image prune -a -f` on each server.
+#[typeshare]
+#[derive(
+  Serialize,
+  Deserialize,
+  Debug,
+  Clone,
+  PartialEq,
+  Resolve,
+  EmptyTraits,
+  Parser,
+)]
+#[empty_traits(KomodoExecuteRequest)]
+#[response(Update)]
+#[error(serror::Error)]
+pub struct PruneImagesOnGroup {
+  /// Id or name
+  pub server_group: String,
+}
+
+//
+
+/// Prunes the docker system on all the servers in the group,
+/// in parallel, including volumes. Response: [Update].
+///
+/// 1. Runs `docker system prune -a -f --volumes` on each server.
+#[typeshare]
+#[derive(
+  Serialize,
+  Deserialize,
+  Debug,
+  Clone,
+  PartialEq,
+  Resolve,
+  EmptyTraits,
+  Parser,
+)]
+#[empty_traits(KomodoExecuteRequest)]
+#[response(Update)]
+#[error(serror::Error)]
+pub struct PruneSystemOnGroup {
+  /// Id or name
+  pub server_group: String,
+}
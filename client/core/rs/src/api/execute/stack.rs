@@ -1,4 +1,4 @@
-use crate::entities::update::Update;
+use crate::entities::{SearchCombinator, update::Update};
 use anyhow::Context;
 use clap::ArgAction::SetTrue;
 use clap::Parser;
@@ -66,6 +66,10 @@ pub struct BatchDeployStack {
   /// extra-stack-1, extra-stack-2
   /// ```
   pub pattern: String,
+  /// `And` to only match resources matching all the patterns.
+  /// Default: `Or`, matching resources which match any pattern.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub combinator: Option<SearchCombinator>,
 }
 
 //
@@ -124,6 +128,10 @@ pub struct BatchDeployStackIfChanged {
   /// extra-stack-1, extra-stack-2
   /// ```
   pub pattern: String,
+  /// `And` to only match resources matching all the patterns.
+  /// Default: `Or`, matching resources which match any pattern.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub combinator: Option<SearchCombinator>,
 }
 
 //
@@ -181,6 +189,10 @@ pub struct BatchPullStack {
   /// extra-stack-1, extra-stack-2
   /// ```
   pub pattern: String,
+  /// `And` to only match resources matching all the patterns.
+  /// Default: `Or`, matching resources which match any pattern.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub combinator: Option<SearchCombinator>,
 }
 
 //
@@ -435,4 +447,8 @@ pub struct BatchDestroyStack {
   /// extra-stack-1, extra-stack-2
   /// ```
   pub pattern: String,
+  /// `And` to only match resources matching all the patterns.
+  /// Default: `Or`, matching resources which match any pattern.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub combinator: Option<SearchCombinator>,
 }
@@ -33,4 +33,9 @@ pub struct RunSync {
   /// Combine with `resource_type` to specify resources.
   /// Supports name or id.
   pub resources: Option<Vec<String>>,
+  /// Only compute the changes and log them to the Update,
+  /// without executing any of them.
+  #[serde(default)]
+  #[arg(long, default_value_t = false)]
+  pub dry_run: bool,
 }
@@ -0,0 +1,37 @@
+use derive_empty_traits::EmptyTraits;
+use resolver_api::Resolve;
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use crate::entities::{I64, ResourceTarget, audit::AuditEntry};
+
+use super::KomodoReadRequest;
+
+/// List the audit log of write and execute requests,
+/// sorted by timestamp descending. At most 1000 entries are returned.
+/// Admin only.
+/// Response: [ListAuditLogResponse].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Default, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(ListAuditLogResponse)]
+#[error(serror::Error)]
+pub struct ListAuditLog {
+  /// Only include requests made by this user id.
+  pub user_id: Option<String>,
+  /// Only include requests acting on this resource.
+  pub resource_target: Option<ResourceTarget>,
+  /// Only include requests made at or after this
+  /// unix timestamp in milliseconds.
+  #[serde(default)]
+  pub from_ts: I64,
+  /// Only include requests made at or before this
+  /// unix timestamp in milliseconds. `0` means no upper bound.
+  #[serde(default)]
+  pub to_ts: I64,
+}
+
+#[typeshare]
+pub type ListAuditLogResponse = Vec<AuditEntry>;
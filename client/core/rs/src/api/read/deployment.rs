@@ -125,6 +125,30 @@ pub type InspectDeploymentContainerResponse = Container;
 
 //
 
+/// Get the environment variables of the Deployment's
+/// running container, as `KEY=value` lines.
+/// Secret values are redacted.
+/// Response: [GetDeploymentContainerEnvResponse].
+///
+/// Note. Requires the `Inspect` permission on the Deployment.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(GetDeploymentContainerEnvResponse)]
+#[error(serror::Error)]
+pub struct GetDeploymentContainerEnv {
+  /// Id or name
+  #[serde(alias = "id", alias = "name")]
+  pub deployment: String,
+}
+
+#[typeshare]
+pub type GetDeploymentContainerEnvResponse = Vec<String>;
+
+//
+
 /// Get the deployment log's tail, split by stdout/stderr.
 /// Response: [Log].
 ///
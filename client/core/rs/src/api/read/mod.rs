@@ -6,6 +6,7 @@ use typeshare::typeshare;
 mod action;
 mod alert;
 mod alerter;
+mod audit;
 mod build;
 mod builder;
 mod deployment;
@@ -15,6 +16,7 @@ mod provider;
 mod repo;
 mod schedule;
 mod server;
+mod server_group;
 mod stack;
 mod sync;
 mod tag;
@@ -27,6 +29,7 @@ mod variable;
 pub use action::*;
 pub use alert::*;
 pub use alerter::*;
+pub use audit::*;
 pub use build::*;
 pub use builder::*;
 pub use deployment::*;
@@ -36,6 +39,7 @@ pub use provider::*;
 pub use repo::*;
 pub use schedule::*;
 pub use server::*;
+pub use server_group::*;
 pub use stack::*;
 pub use sync::*;
 pub use tag::*;
@@ -9,7 +9,7 @@ use crate::entities::{
     container::{Container, ContainerListItem},
     image::{Image, ImageHistoryResponseItem, ImageListItem},
     network::{Network, NetworkListItem},
-    volume::{Volume, VolumeListItem},
+    volume::{Volume, VolumeEntry, VolumeListItem},
   },
   server::{
     Server, ServerActionState, ServerListItem, ServerQuery,
@@ -484,6 +484,34 @@ pub type InspectDockerVolumeResponse = Volume;
 
 //
 
+/// List the files in a directory of a docker volume on the server.
+/// Response: [GetVolumeBrowserListingResponse].
+///
+/// Note. Requires the `Inspect` permission on the server.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(GetVolumeBrowserListingResponse)]
+#[error(serror::Error)]
+pub struct GetVolumeBrowserListing {
+  /// Id or name
+  #[serde(alias = "id", alias = "name")]
+  pub server: String,
+  /// The volume name
+  pub volume: String,
+  /// The directory path relative to the volume root.
+  /// If empty, lists the volume root.
+  #[serde(default)]
+  pub path: String,
+}
+
+#[typeshare]
+pub type GetVolumeBrowserListingResponse = Vec<VolumeEntry>;
+
+//
+
 /// List all docker compose projects on the target server.
 /// Response: [ListComposeProjectsResponse].
 #[typeshare]
@@ -0,0 +1,65 @@
+use derive_empty_traits::EmptyTraits;
+use resolver_api::Resolve;
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use crate::entities::server_group::{
+  ServerGroup, ServerGroupListItem, ServerGroupQuery,
+};
+
+use super::KomodoReadRequest;
+
+//
+
+/// Get a specific server group. Response: [ServerGroup].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(GetServerGroupResponse)]
+#[error(serror::Error)]
+pub struct GetServerGroup {
+  /// Id or name
+  #[serde(alias = "id", alias = "name")]
+  pub server_group: String,
+}
+
+#[typeshare]
+pub type GetServerGroupResponse = ServerGroup;
+
+//
+
+/// List server groups matching optional query. Response: [ListServerGroupsResponse].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Default, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(ListServerGroupsResponse)]
+#[error(serror::Error)]
+pub struct ListServerGroups {
+  /// Structured query to filter server groups.
+  #[serde(default)]
+  pub query: ServerGroupQuery,
+}
+
+#[typeshare]
+pub type ListServerGroupsResponse = Vec<ServerGroupListItem>;
+
+/// List full server groups matching optional query. Response: [ListFullServerGroupsResponse].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Default, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(ListFullServerGroupsResponse)]
+#[error(serror::Error)]
+pub struct ListFullServerGroups {
+  /// Structured query to filter server groups.
+  #[serde(default)]
+  pub query: ServerGroupQuery,
+}
+
+#[typeshare]
+pub type ListFullServerGroupsResponse = Vec<ServerGroup>;
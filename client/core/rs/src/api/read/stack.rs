@@ -76,6 +76,32 @@ pub type InspectStackContainerResponse = Container;
 
 //
 
+/// Get the environment variables of a Stack service's
+/// running container, as `KEY=value` lines.
+/// Secret values are redacted.
+/// Response: [GetStackContainerEnvResponse].
+///
+/// Note. Requires the `Inspect` permission on the Stack.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(GetStackContainerEnvResponse)]
+#[error(serror::Error)]
+pub struct GetStackContainerEnv {
+  /// Id or name
+  #[serde(alias = "id", alias = "name")]
+  pub stack: String,
+  /// The service name
+  pub service: String,
+}
+
+#[typeshare]
+pub type GetStackContainerEnvResponse = Vec<String>;
+
+//
+
 /// Get a stack's logs. Filter down included services. Response: [GetStackLogResponse].
 ///
 /// Note. This call will hit the underlying server directly for most up to date log.
@@ -112,6 +138,40 @@ pub type GetStackLogResponse = Log;
 
 //
 
+/// Get the logs for a single service in a stack.
+/// Response: [GetStackServiceLogsResponse].
+///
+/// The service must be one of the stack's known services.
+///
+/// Note. This call will hit the underlying server directly for most up to date log.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(GetStackServiceLogsResponse)]
+#[error(serror::Error)]
+pub struct GetStackServiceLogs {
+  /// Id or name
+  #[serde(alias = "id", alias = "name", alias = "stack_id")]
+  pub stack: String,
+  /// The service to get the logs for.
+  pub service: String,
+  /// The number of lines of the log tail to include.
+  /// Default: 100.
+  /// Max: 5000.
+  #[serde(default = "default_tail")]
+  pub tail: U64,
+  /// Enable `--timestamps`
+  #[serde(default)]
+  pub timestamps: bool,
+}
+
+#[typeshare]
+pub type GetStackServiceLogsResponse = Log;
+
+//
+
 /// Search the stack log's tail using `grep`. All lines go to stdout.
 /// Response: [SearchStackLogResponse].
 ///
@@ -5,7 +5,7 @@ use typeshare::typeshare;
 
 use crate::entities::sync::{
   ResourceSync, ResourceSyncActionState, ResourceSyncListItem,
-  ResourceSyncQuery,
+  ResourceSyncQuery, SyncConflict,
 };
 
 use super::KomodoReadRequest;
@@ -88,6 +88,31 @@ pub type GetResourceSyncActionStateResponse = ResourceSyncActionState;
 
 //
 
+/// List the fields of live resources which differ from the
+/// sync files, without running the sync.
+/// Response: [ListResourceSyncConflictsResponse].
+///
+/// The live resources are exported when requested, and compared
+/// against the sync files as of the last sync refresh. Only resources
+/// with pending updates as of the last refresh are compared.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(ListResourceSyncConflictsResponse)]
+#[error(serror::Error)]
+pub struct ListResourceSyncConflicts {
+  /// Id or name
+  #[serde(alias = "id", alias = "name", alias = "sync_id")]
+  pub sync: String,
+}
+
+#[typeshare]
+pub type ListResourceSyncConflictsResponse = Vec<SyncConflict>;
+
+//
+
 /// Gets a summary of data relating to all syncs.
 /// Response: [GetResourceSyncsSummaryResponse].
 #[typeshare]
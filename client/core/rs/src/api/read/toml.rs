@@ -40,10 +40,18 @@ pub struct ExportAllResourcesToToml {
   /// Default: false
   #[serde(default)]
   pub include_variables: bool,
+  /// Whether to include tags in the exported contents.
+  /// Default: false
+  #[serde(default)]
+  pub include_tags: bool,
   /// Whether to include user groups in the exported contents.
   /// Default: false
   #[serde(default)]
   pub include_user_groups: bool,
+  /// Only export resources with names prefixed by `<namespace>-`,
+  /// and strip the prefix from the exported names.
+  #[serde(default)]
+  pub namespace: String,
 }
 
 fn default_include_resources() -> bool {
@@ -74,7 +82,55 @@ pub struct ExportResourcesToToml {
   /// Whether to include variables
   #[serde(default)]
   pub include_variables: bool,
+  /// Whether to include tags
+  #[serde(default)]
+  pub include_tags: bool,
+  /// Only export resources with names prefixed by `<namespace>-`,
+  /// and strip the prefix from the exported names.
+  #[serde(default)]
+  pub namespace: String,
 }
 
 #[typeshare]
 pub type ExportResourcesToTomlResponse = TomlResponse;
+
+//
+
+/// Parse resource toml, in the same format used by Resource Syncs,
+/// and preview how it differs from the current resources.
+/// Nothing is changed. Response: [ImportToTomlResponse].
+///
+/// Note. Admin only.
+#[typeshare]
+#[derive(
+  Debug, Clone, Default, Serialize, Deserialize, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(ImportToTomlResponse)]
+#[error(serror::Error)]
+pub struct ImportResourcesToToml {
+  /// The resource toml to compare against current resources.
+  pub toml: String,
+}
+
+#[typeshare]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportToTomlResponse {
+  /// Resources in the toml which don't exist yet.
+  /// These targets hold the resource name, as there is no id yet.
+  pub pending_creates: Vec<ResourceTarget>,
+  /// Existing resources which differ from the toml.
+  pub pending_updates: Vec<PendingTomlUpdate>,
+  /// Existing resources which are missing from the toml.
+  pub pending_deletes: Vec<ResourceTarget>,
+}
+
+#[typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingTomlUpdate {
+  /// The resource to update.
+  pub target: ResourceTarget,
+  /// Line diff from the current toml to the proposed toml,
+  /// with removed lines prefixed by `-` and added lines by `+`.
+  pub diff: String,
+}
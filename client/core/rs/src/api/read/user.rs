@@ -3,7 +3,10 @@ use resolver_api::Resolve;
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::{api_key::ApiKey, user::User};
+use crate::entities::{
+  api_key::ApiKey, service_account::ServiceAccount, user::User,
+  user_session::UserSession,
+};
 
 use super::KomodoReadRequest;
 
@@ -83,6 +86,45 @@ pub type ListUsersResponse = Vec<User>;
 
 //
 
+/// **Admin only.**
+/// Gets list of service accounts. Api key secrets are not included.
+/// Response: [ListServiceAccountsResponse]
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Default, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(ListServiceAccountsResponse)]
+#[error(serror::Error)]
+pub struct ListServiceAccounts {}
+
+#[typeshare]
+pub type ListServiceAccountsResponse = Vec<ServiceAccount>;
+
+//
+
+/// **Admin only.**
+/// Gets list of active login sessions,
+/// optionally filtered to a specific user.
+/// Response: [ListUserSessionsResponse]
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Default, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(ListUserSessionsResponse)]
+#[error(serror::Error)]
+pub struct ListUserSessions {
+  /// Id or username
+  #[serde(default, alias = "id", alias = "username")]
+  pub user: Option<String>,
+}
+
+#[typeshare]
+pub type ListUserSessionsResponse = Vec<UserSession>;
+
+//
+
 /// Gets the username of a specific user.
 /// Response: [GetUsernameResponse]
 #[typeshare]
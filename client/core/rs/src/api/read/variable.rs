@@ -3,13 +3,17 @@ use resolver_api::Resolve;
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::variable::Variable;
+use crate::entities::{ResourceTarget, variable::Variable};
 
 use super::KomodoReadRequest;
 
-/// List all available global variables.
+/// Get a variable by name.
 /// Response: [Variable]
 ///
+/// When `server_id` is passed, returns the variable scoped to
+/// that Server if one exists, otherwise the global variable.
+/// This is the value which resources on the Server will use.
+///
 /// Note. For non admin users making this call,
 /// secret variables will have their values obscured.
 #[typeshare]
@@ -22,6 +26,9 @@ use super::KomodoReadRequest;
 pub struct GetVariable {
   /// The name of the variable to get.
   pub name: String,
+  /// Resolve the variable in the context of this Server.
+  #[serde(default)]
+  pub server_id: Option<String>,
 }
 
 #[typeshare]
@@ -45,3 +52,38 @@ pub struct ListVariables {}
 
 #[typeshare]
 pub type ListVariablesResponse = Vec<Variable>;
+
+//
+
+/// Audit all global variables, including which resources
+/// reference them. Values are never included.
+/// Response: [GetAllVariablesResponse]
+///
+/// Note. Admin only.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Default, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoReadRequest)]
+#[response(GetAllVariablesResponse)]
+#[error(serror::Error)]
+pub struct GetAllVariables {}
+
+#[typeshare]
+pub type GetAllVariablesResponse = Vec<VariableSummary>;
+
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VariableSummary {
+  /// The name of the variable.
+  pub name: String,
+  /// The description of the variable.
+  pub description: String,
+  /// Whether the variable is secret.
+  pub is_secret: bool,
+  /// The Server the variable is scoped to, if any.
+  pub server_id: Option<String>,
+  /// The resources which interpolate the variable
+  /// into their config using `[[name]]`.
+  pub referenced_by: Vec<ResourceTarget>,
+}
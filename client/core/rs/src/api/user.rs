@@ -3,7 +3,9 @@ use resolver_api::{HasResponse, Resolve};
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::{I64, NoData, ResourceTarget};
+use crate::entities::{
+  I64, NoData, ResourceTarget, api_key::ApiKeyPermissionCeiling,
+};
 
 pub trait KomodoUserRequest: HasResponse {}
 
@@ -65,6 +67,11 @@ pub struct CreateApiKey {
   /// Default is 0, which means no expiry.
   #[serde(default)]
   pub expires: I64,
+
+  /// Optionally limit what the key can do.
+  /// Default is None, the key has the full permissions of the user.
+  #[serde(default)]
+  pub permission_ceiling: Option<ApiKeyPermissionCeiling>,
 }
 
 /// Response for [CreateApiKey].
@@ -99,3 +106,27 @@ pub struct DeleteApiKey {
 
 #[typeshare]
 pub type DeleteApiKeyResponse = NoData;
+
+//
+
+/// Generate a new secret for one of the calling user's api keys.
+/// The old secret stops working immediately, while the key,
+/// name, expiry, and permission ceiling are kept.
+/// Response: [RotateApiKeyResponse].
+///
+/// Note. After the response is served, there will be no way
+/// to get the secret later.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoUserRequest)]
+#[response(RotateApiKeyResponse)]
+#[error(serror::Error)]
+pub struct RotateApiKey {
+  /// The key which the user intends to rotate.
+  pub key: String,
+}
+
+#[typeshare]
+pub type RotateApiKeyResponse = CreateApiKeyResponse;
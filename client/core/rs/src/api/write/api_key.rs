@@ -5,7 +5,7 @@ use typeshare::typeshare;
 
 use crate::{
   api::user::CreateApiKeyResponse,
-  entities::{I64, NoData},
+  entities::{I64, NoData, api_key::ApiKeyPermissionCeiling},
 };
 
 use super::KomodoWriteRequest;
@@ -30,6 +30,11 @@ pub struct CreateApiKeyForServiceUser {
   /// Default is 0, which means no expiry.
   #[serde(default)]
   pub expires: I64,
+
+  /// Optionally limit what the key can do.
+  /// Default is None, the key has the full permissions of the user.
+  #[serde(default)]
+  pub permission_ceiling: Option<ApiKeyPermissionCeiling>,
 }
 
 #[typeshare]
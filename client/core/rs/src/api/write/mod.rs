@@ -10,6 +10,8 @@ mod provider;
 mod repo;
 mod resource;
 mod server;
+mod server_group;
+mod service_account;
 mod stack;
 mod sync;
 mod tags;
@@ -29,6 +31,8 @@ pub use provider::*;
 pub use repo::*;
 pub use resource::*;
 pub use server::*;
+pub use server_group::*;
+pub use service_account::*;
 pub use stack::*;
 pub use sync::*;
 pub use tags::*;
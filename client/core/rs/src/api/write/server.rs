@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
 use crate::entities::{
-  NoData,
+  FileContents, NoData,
   server::{_PartialServerConfig, Server},
   update::Update,
 };
@@ -127,6 +127,91 @@ pub struct CreateNetwork {
 
 //
 
+/// Copy a file into a docker container on the server.
+/// Response: [Update]
+///
+/// Note. Requires `Execute` and `Terminal` permissions on the server.
+/// Fails if container exec is disabled in the periphery config.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(Update)]
+#[error(serror::Error)]
+pub struct CopyFileToContainer {
+  /// Server Id or name
+  pub server: String,
+  /// The container name
+  pub container: String,
+  /// The path to write the file to inside the container.
+  pub dest_path: String,
+  /// The file contents.
+  pub contents: String,
+  /// Whether the contents are base64 encoded,
+  /// used for binary files.
+  #[serde(default)]
+  pub base64: bool,
+}
+
+//
+
+/// Run a single command in a docker container on the server.
+/// The output is recorded in the logs of the returned [Update].
+/// Response: [Update]
+///
+/// Note. Requires `Execute` and `Terminal` permissions on the server.
+/// Fails if container exec is disabled in the periphery config.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(Update)]
+#[error(serror::Error)]
+pub struct RunContainerCommand {
+  /// Server Id or name
+  pub server: String,
+  /// The container name
+  pub container: String,
+  /// The command to run, with `sh -c`.
+  pub command: String,
+  /// The working directory inside the container.
+  /// If not provided, uses the container's default.
+  #[serde(default)]
+  pub workdir: Option<String>,
+}
+
+//
+
+/// Copy a file out of a docker container on the server.
+/// The contents are base64 encoded. The copy is recorded
+/// as an Update, without the contents.
+/// Response: [CopyFileFromContainerResponse].
+///
+/// Note. Requires `Execute` and `Terminal` permissions on the server.
+/// Fails if container exec is disabled in the periphery config.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(CopyFileFromContainerResponse)]
+#[error(serror::Error)]
+pub struct CopyFileFromContainer {
+  /// Server Id or name
+  pub server: String,
+  /// The container name
+  pub container: String,
+  /// The path of the file inside the container.
+  pub src_path: String,
+}
+
+#[typeshare]
+pub type CopyFileFromContainerResponse = FileContents;
+
+//
+
 /// Configures the behavior of [CreateTerminal] if the
 /// specified terminal name already exists.
 #[typeshare]
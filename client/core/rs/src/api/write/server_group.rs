@@ -0,0 +1,103 @@
+use derive_empty_traits::EmptyTraits;
+use resolver_api::Resolve;
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use crate::entities::{
+  server_group::{_PartialServerGroupConfig, ServerGroup},
+  update::Update,
+};
+
+use super::KomodoWriteRequest;
+
+//
+
+/// Create a server group. Response: [ServerGroup].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(ServerGroup)]
+#[error(serror::Error)]
+pub struct CreateServerGroup {
+  /// The name given to newly created server group.
+  pub name: String,
+  /// Optional partial config to initialize the server group with.
+  #[serde(default)]
+  pub config: _PartialServerGroupConfig,
+}
+
+//
+
+/// Creates a new server group with given `name` and the configuration
+/// of the server group at the given `id`. Response: [ServerGroup].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(ServerGroup)]
+#[error(serror::Error)]
+pub struct CopyServerGroup {
+  /// The name of the new server group.
+  pub name: String,
+  /// The id of the server group to copy.
+  pub id: String,
+}
+
+//
+
+/// Deletes the server group at the given id, and returns the deleted server group.
+/// Response: [ServerGroup]
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(ServerGroup)]
+#[error(serror::Error)]
+pub struct DeleteServerGroup {
+  /// The id or name of the server group to delete.
+  pub id: String,
+}
+
+//
+
+/// Update the server group at the given id, and return the updated server group. Response: [ServerGroup].
+///
+/// Note. This method updates only the fields which are set in the [PartialServerGroupConfig][crate::entities::server_group::PartialServerGroupConfig],
+/// effectively merging diffs into the final document. This is helpful when multiple users are using
+/// the same resources concurrently by ensuring no unintentional
+/// field changes occur from out of date local state.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(ServerGroup)]
+#[error(serror::Error)]
+pub struct UpdateServerGroup {
+  /// The id of the server group to update.
+  pub id: String,
+  /// The partial config update to apply.
+  pub config: _PartialServerGroupConfig,
+}
+
+//
+
+/// Rename the ServerGroup at id to the given name.
+/// Response: [Update].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(Update)]
+#[error(serror::Error)]
+pub struct RenameServerGroup {
+  /// The id or name of the ServerGroup to rename.
+  pub id: String,
+  /// The new name.
+  pub name: String,
+}
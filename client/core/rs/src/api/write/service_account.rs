@@ -0,0 +1,113 @@
+use derive_empty_traits::EmptyTraits;
+use indexmap::IndexMap;
+use resolver_api::Resolve;
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use crate::{
+  api::user::CreateApiKeyResponse,
+  entities::{
+    I64, NoData, ResourceTargetVariant,
+    api_key::ApiKeyPermissionCeiling,
+    permission::PermissionLevelAndSpecifics,
+    service_account::ServiceAccount,
+  },
+};
+
+use super::KomodoWriteRequest;
+
+//
+
+/// **Admin only.** Create a service account.
+/// Response: [ServiceAccount].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(CreateServiceAccountResponse)]
+#[error(serror::Error)]
+pub struct CreateServiceAccount {
+  /// The name for the service account.
+  pub name: String,
+  /// A description for the service account.
+  #[serde(default)]
+  pub description: String,
+  /// Permissions on all resources of a certain type.
+  #[serde(default)]
+  pub permissions:
+    IndexMap<ResourceTargetVariant, PermissionLevelAndSpecifics>,
+}
+
+#[typeshare]
+pub type CreateServiceAccountResponse = ServiceAccount;
+
+//
+
+/// **Admin only.** Delete a service account, along with its api keys.
+/// Response: [ServiceAccount].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(DeleteServiceAccountResponse)]
+#[error(serror::Error)]
+pub struct DeleteServiceAccount {
+  /// Id or name
+  #[serde(alias = "id", alias = "name")]
+  pub service_account: String,
+}
+
+#[typeshare]
+pub type DeleteServiceAccountResponse = ServiceAccount;
+
+//
+
+/// **Admin only.** Create an api key for a service account.
+/// Response: [CreateApiKeyResponse].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(CreateServiceAccountApiKeyResponse)]
+#[error(serror::Error)]
+pub struct CreateServiceAccountApiKey {
+  /// Id or name
+  #[serde(alias = "id", alias = "name")]
+  pub service_account: String,
+  /// The name for the api key
+  pub key_name: String,
+  /// A unix timestamp in millseconds specifying api key expire time.
+  /// Default is 0, which means no expiry.
+  #[serde(default)]
+  pub expires: I64,
+
+  /// Optionally limit what the key can do.
+  /// Default is None, the key has the full permissions of the service account.
+  #[serde(default)]
+  pub permission_ceiling: Option<ApiKeyPermissionCeiling>,
+}
+
+#[typeshare]
+pub type CreateServiceAccountApiKeyResponse = CreateApiKeyResponse;
+
+//
+
+/// **Admin only.** Delete an api key of a service account.
+/// Response: [NoData].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(DeleteServiceAccountApiKeyResponse)]
+#[error(serror::Error)]
+pub struct DeleteServiceAccountApiKey {
+  /// The api key to delete
+  pub key: String,
+}
+
+#[typeshare]
+pub type DeleteServiceAccountApiKeyResponse = NoData;
@@ -126,6 +126,11 @@ pub struct WriteStackFileContents {
   pub file_path: String,
   /// The contents to write.
   pub contents: String,
+  /// Whether the contents are base64 encoded,
+  /// and should be decoded before writing.
+  /// Use for binary files such as certificates.
+  #[serde(default)]
+  pub base64_contents: bool,
 }
 
 //
@@ -5,9 +5,11 @@ use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
 use crate::entities::{
-  NoData,
-  sync::{_PartialResourceSyncConfig, ResourceSync},
-  update::Update,
+  NoData, ResourceTargetVariant,
+  sync::{
+    _PartialResourceSyncConfig, DiffData, ResourceDiff, ResourceSync,
+  },
+  update::{Log, Update},
 };
 
 use super::KomodoWriteRequest;
@@ -172,6 +174,62 @@ pub struct CommitSync {
 
 //
 
+/// Import resources, variables, and user groups from a toml string,
+/// in the same format used by Resource Syncs.
+/// Resources which already exist are updated to match,
+/// nothing is deleted, and no deploys are triggered.
+/// Response: [ImportResourcesFromTomlResponse].
+///
+/// Note. Admin only.
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(ImportResourcesFromTomlResponse)]
+#[error(serror::Error)]
+pub struct ImportResourcesFromToml {
+  /// The resource toml to import.
+  pub toml: String,
+  /// Only compute the changes, without applying them.
+  #[serde(default)]
+  pub dry_run: bool,
+}
+
+#[typeshare]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportResourcesFromTomlResponse {
+  /// Whether this was a dry run, ie no changes were applied.
+  pub dry_run: bool,
+  /// The resources which were (or would be) created.
+  pub created: Vec<ImportedResource>,
+  /// The resources which were (or would be) updated.
+  pub updated: Vec<ImportedResource>,
+  /// The resources which already match the toml,
+  /// and were skipped.
+  pub skipped: Vec<ImportedResource>,
+  /// The computed diffs for the resources.
+  pub resource_updates: Vec<ResourceDiff>,
+  /// The computed diffs for the variables.
+  pub variable_updates: Vec<DiffData>,
+  /// The computed diffs for the user groups.
+  pub user_group_updates: Vec<DiffData>,
+  /// The logs from applying the changes.
+  /// Empty on dry run.
+  pub logs: Vec<Log>,
+}
+
+#[typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedResource {
+  /// The resource type.
+  pub resource_type: ResourceTargetVariant,
+  /// The resource name.
+  pub name: String,
+}
+
+//
+
 #[typeshare]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SyncWebhookAction {
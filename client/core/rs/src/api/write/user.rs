@@ -3,7 +3,9 @@ use resolver_api::Resolve;
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::{NoData, user::User};
+use crate::entities::{
+  NoData, user::User, user_session::UserSession,
+};
 
 use super::KomodoWriteRequest;
 
@@ -69,6 +71,26 @@ pub type DeleteUserResponse = User;
 
 //
 
+/// **Admin only**. Revoke a login session,
+/// so its JWT can no longer be used.
+/// Response: [UserSession].
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(RevokeUserSessionResponse)]
+#[error(serror::Error)]
+pub struct RevokeUserSession {
+  /// The session jwt id
+  pub jwt_id: String,
+}
+
+#[typeshare]
+pub type RevokeUserSessionResponse = UserSession;
+
+//
+
 /// **Admin only.** Create a local user.
 /// Response: [User].
 ///
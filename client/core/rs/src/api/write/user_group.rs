@@ -3,7 +3,9 @@ use resolver_api::Resolve;
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::user_group::UserGroup;
+use crate::entities::{
+  permission::PermissionLevelAndSpecifics, user_group::UserGroup,
+};
 
 use super::KomodoWriteRequest;
 
@@ -121,3 +123,25 @@ pub struct SetEveryoneUserGroup {
   /// Whether this user group applies to everyone.
   pub everyone: bool,
 }
+
+//
+
+/// **Admin only.** Give the User Group permissions on all
+/// resources with the given tag. Setting the level to `None`
+/// with no specific permissions removes the tag permission.
+/// Response: [UserGroup]
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Resolve, EmptyTraits,
+)]
+#[empty_traits(KomodoWriteRequest)]
+#[response(UserGroup)]
+#[error(serror::Error)]
+pub struct UpdateUserGroupTagPermission {
+  /// Id or name.
+  pub user_group: String,
+  /// Tag id or name.
+  pub tag: String,
+  /// The permission to give on resources with the tag.
+  pub permission: PermissionLevelAndSpecifics,
+}
@@ -27,6 +27,10 @@ pub struct CreateVariable {
   /// Whether to make this a secret variable.
   #[serde(default)]
   pub is_secret: bool,
+  /// Scope the variable to a Server by id,
+  /// overriding the global variable with the same name.
+  #[serde(default)]
+  pub server_id: Option<String>,
 }
 
 #[typeshare]
@@ -47,6 +51,10 @@ pub struct UpdateVariableValue {
   pub name: String,
   /// The value to set.
   pub value: String,
+  /// The Server id of a scoped variable,
+  /// or None for the global variable.
+  #[serde(default)]
+  pub server_id: Option<String>,
 }
 
 #[typeshare]
@@ -67,6 +75,10 @@ pub struct UpdateVariableDescription {
   pub name: String,
   /// The description to set.
   pub description: String,
+  /// The Server id of a scoped variable,
+  /// or None for the global variable.
+  #[serde(default)]
+  pub server_id: Option<String>,
 }
 
 #[typeshare]
@@ -87,6 +99,10 @@ pub struct UpdateVariableIsSecret {
   pub name: String,
   /// Whether variable is secret.
   pub is_secret: bool,
+  /// The Server id of a scoped variable,
+  /// or None for the global variable.
+  #[serde(default)]
+  pub server_id: Option<String>,
 }
 
 #[typeshare]
@@ -104,6 +120,10 @@ pub type UpdateVariableIsSecretResponse = Variable;
 #[error(serror::Error)]
 pub struct DeleteVariable {
   pub name: String,
+  /// The Server id of a scoped variable,
+  /// or None for the global variable.
+  #[serde(default)]
+  pub server_id: Option<String>,
 }
 
 #[typeshare]
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use strum::{Display, EnumString};
 use typeshare::typeshare;
 
-use crate::entities::{I64, MongoId};
+use crate::entities::{I64, MongoId, U64};
 
 use super::{
   _Serror, ResourceTarget, ResourceTargetVariant, Version,
@@ -137,6 +137,22 @@ pub enum AlertData {
     total_gb: f64,
   },
 
+  /// A server has high inode usage on a disk.
+  ServerInodes {
+    /// The id of the server
+    id: String,
+    /// The name of the server
+    name: String,
+    /// The region of the server
+    region: Option<String>,
+    /// The mount path of the disk
+    path: PathBuf,
+    /// The number of used inodes
+    used: U64,
+    /// The total number of inodes
+    total: U64,
+  },
+
   /// A server has a version mismatch with the core.
   ServerVersionMismatch {
     /// The id of the server
@@ -241,6 +257,24 @@ pub enum AlertData {
     images: Vec<String>,
   },
 
+  /// A container's docker healthcheck has started failing.
+  ContainerUnhealthy {
+    /// The id of the deployment or stack
+    id: String,
+    /// The name of the deployment or stack
+    name: String,
+    /// The type of resource, Deployment or Stack
+    resource_type: ResourceTargetVariant,
+    /// The server id of server that the container is on
+    server_id: String,
+    /// The server name
+    server_name: String,
+    /// The container name
+    container: String,
+    /// The service name, if the container is part of a stack
+    service: Option<String>,
+  },
+
   /// An AWS builder failed to terminate.
   AwsBuilderTerminationFailed {
     /// The id of the aws instance which failed to terminate
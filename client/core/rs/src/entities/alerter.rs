@@ -171,12 +171,27 @@ pub struct SlackAlerterEndpoint {
   #[serde(default = "default_slack_url")]
   #[builder(default = "default_slack_url()")]
   pub url: String,
+
+  /// Optionally post to this channel instead of the
+  /// webhook default, eg. `#alerts`.
+  /// If empty, uses the webhook default.
+  pub channel: Option<String>,
+
+  /// Optionally override the username the alert is posted with.
+  pub username: Option<String>,
+
+  /// Optionally override the icon the alert is posted with,
+  /// eg. `:lizard:`.
+  pub icon_emoji: Option<String>,
 }
 
 impl Default for SlackAlerterEndpoint {
   fn default() -> Self {
     Self {
       url: default_slack_url(),
+      channel: None,
+      username: None,
+      icon_emoji: None,
     }
   }
 }
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use super::I64;
+use super::{I64, permission::PermissionLevel};
 
 /// An api key used to authenticate requests via request headers.
 #[typeshare]
@@ -30,6 +30,16 @@ pub struct ApiKey {
 
   /// Expiry of key, or 0 if never expires
   pub expires: I64,
+
+  /// Timestamp the key was last used to authenticate, or 0 if never.
+  /// Only updated once per minute.
+  #[serde(default)]
+  pub last_used_at: I64,
+
+  /// Limits what the key can do, below the permissions of the user.
+  /// Keys without a ceiling have the full permissions of the user.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub permission_ceiling: Option<ApiKeyPermissionCeiling>,
 }
 
 impl ApiKey {
@@ -37,3 +47,36 @@ impl ApiKey {
     self.secret.clear()
   }
 }
+
+/// Caps the requests an api key is allowed to make.
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ApiKeyPermissionCeiling {
+  /// The max level of request the key can make.
+  /// - Read: only /read requests
+  /// - Execute: also /execute requests
+  /// - Write: also /write and /user requests, and terminals
+  #[serde(default)]
+  pub level: PermissionLevel,
+
+  /// Restrict /execute, /write, and /user requests to these
+  /// request types, eg `Deploy`. Use `Terminal` to allow terminals.
+  /// Empty allows all requests within the `level`.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<String>,
+}
+
+impl ApiKeyPermissionCeiling {
+  /// Whether the key can make a request of this type,
+  /// which requires the given level.
+  pub fn allows(
+    &self,
+    level: PermissionLevel,
+    request: &str,
+  ) -> bool {
+    self.level >= level
+      && (level <= PermissionLevel::Read
+        || self.operations.is_empty()
+        || self.operations.iter().any(|op| op == request))
+  }
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use super::{I64, MongoId, ResourceTarget};
+
+/// A record of a write or execute request made to Komodo Core.
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(
+  feature = "mongo",
+  derive(mongo_indexed::derive::MongoIndexed)
+)]
+#[cfg_attr(feature = "mongo", doc_index({ "resource_target.type": 1 }))]
+#[cfg_attr(feature = "mongo", doc_index({ "resource_target.id": 1 }))]
+pub struct AuditEntry {
+  /// The Mongo ID of the audit entry.
+  /// This field is de/serialized from/to JSON as
+  /// `{ "_id": { "$oid": "..." }, ...(rest of serialized AuditEntry) }`
+  #[serde(
+    default,
+    rename = "_id",
+    skip_serializing_if = "String::is_empty",
+    with = "bson::serde_helpers::hex_string_as_object_id"
+  )]
+  pub id: MongoId,
+
+  /// The id of the user who made the request.
+  #[cfg_attr(feature = "mongo", index)]
+  pub user_id: String,
+
+  /// The username of the user at the time of the request.
+  pub username: String,
+
+  /// The request type, eg `DeployStack`.
+  pub request_type: String,
+
+  /// The resource the request acted on, when known.
+  pub resource_target: Option<ResourceTarget>,
+
+  /// Unix timestamp in milliseconds the request was made.
+  #[cfg_attr(feature = "mongo", index)]
+  pub timestamp: I64,
+
+  /// The ip of the client which made the request.
+  pub ip: Option<String>,
+
+  /// Whether the request succeeded.
+  pub success: bool,
+}
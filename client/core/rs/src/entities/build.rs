@@ -182,6 +182,8 @@ pub struct BuildListItemInfo {
   pub built_hash: Option<String>,
   /// Latest short commit hash, or null. Only for repo based stacks
   pub latest_hash: Option<String>,
+  /// Whether the dockerfile has changed since the last successful build.
+  pub needs_rebuild: bool,
 
   /// The first listed image registry domain
   pub image_registry_domain: Option<String>,
@@ -240,6 +242,20 @@ pub struct BuildInfo {
   pub latest_hash: Option<String>,
   /// Latest remote commit message, or null
   pub latest_message: Option<String>,
+
+  /// Sha256 hash of the latest dockerfile contents, or null.
+  /// This is updated whenever Komodo refreshes the build cache.
+  pub dockerfile_hash: Option<String>,
+  /// Sha256 hash of the dockerfile contents at the last successful build, or null.
+  pub built_dockerfile_hash: Option<String>,
+  /// Whether the dockerfile has changed since the last successful build.
+  #[serde(default)]
+  pub needs_rebuild: bool,
+
+  /// The current step of the in progress build, or null.
+  pub build_step: Option<u32>,
+  /// The total steps of the in progress build stage, or null.
+  pub build_step_total: Option<u32>,
 }
 
 #[typeshare(serialized_as = "Partial<BuildConfig>")]
@@ -409,11 +425,62 @@ pub struct BuildConfig {
   #[builder(default)]
   pub skip_secret_interp: bool,
 
+  /// Whether to skip only variable interpolation, still interpolating secrets.
+  /// Useful if the contents use `[[...]]` for something other than Komodo variables.
+  /// If `skip_secret_interp` is also enabled, all interpolation is skipped.
+  #[serde(default)]
+  #[builder(default)]
+  pub skip_variable_interp: bool,
+
   /// Whether to use buildx to build (eg `docker buildx build ...`)
   #[serde(default)]
   #[builder(default)]
   pub use_buildx: bool,
 
+  /// The platforms to build the image for, eg `["linux/amd64", "linux/arm64"]`.
+  /// If non-empty, builds with `docker buildx build --platform ... --push`.
+  /// This requires buildx set up on the builder, and an image registry to push to.
+  #[serde(default, deserialize_with = "string_list_deserializer")]
+  #[partial_attr(serde(
+    default,
+    deserialize_with = "option_string_list_deserializer"
+  ))]
+  #[builder(default)]
+  pub platforms: Vec<String>,
+
+  /// External cache sources passed with `--cache-from`,
+  /// eg `type=registry,ref=registry.example.com/app:cache`.
+  /// See <https://docs.docker.com/build/cache/backends>.
+  #[serde(default, deserialize_with = "string_list_deserializer")]
+  #[partial_attr(serde(
+    default,
+    deserialize_with = "option_string_list_deserializer"
+  ))]
+  #[builder(default)]
+  pub cache_from: Vec<String>,
+
+  /// Cache export destinations passed with `--cache-to`,
+  /// eg `type=inline` or `type=registry,ref=registry.example.com/app:cache,mode=max`.
+  /// Exporting to anything other than `type=inline` requires buildx.
+  #[serde(default, deserialize_with = "string_list_deserializer")]
+  #[partial_attr(serde(
+    default,
+    deserialize_with = "option_string_list_deserializer"
+  ))]
+  #[builder(default)]
+  pub cache_to: Vec<String>,
+
+  /// Additional build contexts passed with `--build-context`,
+  /// eg `shared=../shared` or `alpine=docker-image://alpine:3.20`.
+  /// Each must be in the form `name=value`.
+  #[serde(default, deserialize_with = "string_list_deserializer")]
+  #[partial_attr(serde(
+    default,
+    deserialize_with = "option_string_list_deserializer"
+  ))]
+  #[builder(default)]
+  pub build_contexts: Vec<String>,
+
   /// Any extra docker cli arguments to be included in the build command
   #[serde(default, deserialize_with = "string_list_deserializer")]
   #[partial_attr(serde(
@@ -463,6 +530,15 @@ pub struct BuildConfig {
   #[builder(default)]
   pub secret_args: String,
 
+  /// BuildKit secret mounts.
+  ///
+  /// Each secret is written to a temporary file outside the build context
+  /// for the duration of the build, and passed with `--secret id=...,src=...`.
+  /// The secret can be used in RUN commands with `--mount=type=secret,id=...`.
+  #[serde(default)]
+  #[builder(default)]
+  pub build_secrets: Vec<BuildSecret>,
+
   /// Docker labels
   #[serde(default, deserialize_with = "labels_deserializer")]
   #[partial_attr(serde(
@@ -516,6 +592,7 @@ impl Default for BuildConfig {
     Self {
       builder_id: Default::default(),
       skip_secret_interp: Default::default(),
+      skip_variable_interp: Default::default(),
       version: Default::default(),
       auto_increment_version: default_auto_increment_version(),
       image_name: Default::default(),
@@ -536,9 +613,14 @@ impl Default for BuildConfig {
       dockerfile_path: default_dockerfile_path(),
       build_args: Default::default(),
       secret_args: Default::default(),
+      build_secrets: Default::default(),
       labels: Default::default(),
       extra_args: Default::default(),
       use_buildx: Default::default(),
+      platforms: Default::default(),
+      cache_from: Default::default(),
+      cache_to: Default::default(),
+      build_contexts: Default::default(),
       image_registry: Default::default(),
       webhook_enabled: default_webhook_enabled(),
       webhook_secret: Default::default(),
@@ -576,6 +658,22 @@ impl ImageRegistryConfig {
   }
 }
 
+/// A BuildKit secret mounted during the build.
+#[typeshare]
+#[derive(
+  Debug, Clone, Default, PartialEq, Serialize, Deserialize,
+)]
+pub struct BuildSecret {
+  /// The secret id, used with `RUN --mount=type=secret,id=...`.
+  #[serde(default)]
+  pub id: String,
+
+  /// The secret value, written to the mounted secret file.
+  /// Supports interpolation, eg `[[MY_SECRET]]`.
+  #[serde(default)]
+  pub src: String,
+}
+
 #[typeshare]
 #[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct BuildActionState {
@@ -1,5 +1,7 @@
 use std::path::PathBuf;
 
+use crate::entities::config::cli::BackupCompression;
+
 #[derive(Debug, Clone, clap::Subcommand)]
 pub enum DatabaseCommand {
   /// Triggers database backup to compressed files
@@ -10,6 +12,10 @@ pub enum DatabaseCommand {
     /// Default: `/backups`
     #[arg(long, short = 'f')]
     backups_folder: Option<PathBuf>,
+    /// Optionally override the backup compression.
+    /// Default: `gzip`
+    #[arg(long, short = 'c')]
+    compression: Option<BackupCompression>,
     /// Always continue on user confirmation prompts.
     #[arg(long, short = 'y', default_value_t = false)]
     yes: bool,
@@ -66,6 +72,19 @@ pub enum DatabaseCommand {
     /// Whether to index the target database. Default: true
     #[arg(long, short = 'i', default_value_t = true)]
     index: bool,
+    /// The maximum number of documents to buffer per collection
+    /// before writing to the target.
+    /// Default: 1,000 for Update, 10,000 for the others.
+    #[arg(long, short = 'b')]
+    batch_size: Option<usize>,
+    /// Only copy these collections. Can be passed multiple times.
+    /// If not provided, copies all the collections.
+    #[arg(long = "collection", short = 'c')]
+    collections: Option<Vec<String>>,
+    /// Resume a previous copy which failed midway, skipping
+    /// collections and documents which were already copied.
+    #[arg(long, short = 'r', default_value_t = false)]
+    resume: bool,
     /// Always continue on user confirmation prompts.
     #[arg(long, short = 'y', default_value_t = false)]
     yes: bool,
@@ -7,6 +7,7 @@ use crate::api::execute::Execution;
 pub mod container;
 pub mod database;
 pub mod list;
+pub mod server;
 pub mod update;
 
 #[derive(Debug, clap::Parser)]
@@ -73,6 +74,13 @@ pub enum Command {
   )]
   Execute(Execute),
 
+  /// Server utilities. (alias: `srv`)
+  #[clap(alias = "srv")]
+  Server {
+    #[command(subcommand)]
+    command: server::ServerCommand,
+  },
+
   /// Update resource configuration. (alias: `set`)
   #[clap(alias = "set")]
   Update {
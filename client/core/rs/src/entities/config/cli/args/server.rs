@@ -0,0 +1,25 @@
+#[derive(Debug, Clone, clap::Subcommand)]
+pub enum ServerCommand {
+  /// Show realtime CPU, memory, and disk usage
+  /// for all accessible servers. (alias: `st`)
+  #[clap(alias = "st")]
+  Status(ServerStatus),
+}
+
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ServerStatus {
+  /// Filter servers by name. Supports wildcard syntax.
+  pub name_pattern: Option<String>,
+  /// Keep refreshing the stats until interrupted.
+  #[arg(long, short = 'w', default_value_t = false)]
+  pub watch: bool,
+  /// The refresh interval in seconds when using --watch.
+  #[arg(long, short = 'i', default_value_t = 5)]
+  pub interval: u64,
+  /// Skip servers which don't respond within this many seconds.
+  #[arg(long, short = 't', default_value_t = 5)]
+  pub timeout: u64,
+  /// Specify the format of the output.
+  #[arg(long, short = 'f', default_value_t = super::CliFormat::Table)]
+  pub format: super::CliFormat,
+}
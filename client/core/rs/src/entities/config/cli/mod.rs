@@ -1,6 +1,8 @@
 use std::{path::PathBuf, str::FromStr};
 
 use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+use typeshare::typeshare;
 
 use crate::{
   deserializers::string_list_deserializer,
@@ -70,6 +72,16 @@ pub struct Env {
   pub komodo_cli_backups_folder: Option<PathBuf>,
   /// Override `max_backups`
   pub komodo_cli_max_backups: Option<u16>,
+  /// Override `backup_compression`
+  pub komodo_cli_backup_compression: Option<BackupCompression>,
+  /// Set `backup_encryption_key`
+  pub komodo_cli_backup_encryption_key: Option<String>,
+  /// Set `backup_encryption_key` from the contents of a file
+  pub komodo_cli_backup_encryption_key_file: Option<PathBuf>,
+  /// Override `copy_batch_size`
+  pub komodo_cli_copy_batch_size: Option<usize>,
+  /// Override `copy_collections`. Comma separated.
+  pub komodo_cli_copy_collections: Option<Vec<String>>,
 
   /// Override `database_target_uri`
   #[serde(alias = "komodo_cli_database_copy_uri")]
@@ -211,6 +223,34 @@ pub struct CliConfig {
   /// if there are more backups than `max_backups`
   #[serde(default = "default_max_backups")]
   pub max_backups: u16,
+
+  /// The compression to use for new backups.
+  /// Restore detects the compression from the file extensions.
+  /// Default: `gzip`
+  #[serde(default)]
+  pub backup_compression: BackupCompression,
+
+  /// Encrypts new backups using AES-256-GCM with this key,
+  /// and decrypts encrypted backups on restore.
+  ///
+  /// Only set with `KOMODO_CLI_BACKUP_ENCRYPTION_KEY`
+  /// or `KOMODO_CLI_BACKUP_ENCRYPTION_KEY_FILE`,
+  /// so it is never read from or written to config files.
+  #[serde(skip)]
+  pub backup_encryption_key: Option<String>,
+
+  /// The maximum number of documents to buffer in memory
+  /// per collection during database copy,
+  /// before writing them to the target database.
+  /// Default: `0`, which uses 1,000 for the Update collection
+  /// and 10,000 for the others.
+  #[serde(default)]
+  pub copy_batch_size: usize,
+
+  /// Only copy these collections during database copy.
+  /// If empty, copies all the collections.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub copy_collections: Vec<String>,
   // Same as Core
   /// Configure database connection
   #[serde(
@@ -280,6 +320,10 @@ impl Default for CliConfig {
       table_borders: Default::default(),
       backups_folder: default_backups_folder(),
       max_backups: default_max_backups(),
+      backup_compression: Default::default(),
+      backup_encryption_key: Default::default(),
+      copy_batch_size: Default::default(),
+      copy_collections: Default::default(),
       database: default_database_config(),
       database_target: default_database_config(),
       host: Default::default(),
@@ -306,6 +350,13 @@ impl CliConfig {
       table_borders: self.table_borders,
       backups_folder: self.backups_folder.clone(),
       max_backups: self.max_backups,
+      backup_compression: self.backup_compression,
+      backup_encryption_key: self
+        .backup_encryption_key
+        .as_ref()
+        .map(|key| empty_or_redacted(key)),
+      copy_batch_size: self.copy_batch_size,
+      copy_collections: self.copy_collections.clone(),
       database_target: self.database_target.sanitized(),
       host: self.host.clone(),
       database: self.database.sanitized(),
@@ -332,3 +383,98 @@ pub enum CliTableBorders {
   /// All borders
   All,
 }
+
+/// The compression used for database backup files.
+#[typeshare]
+#[derive(
+  Debug,
+  Clone,
+  Copy,
+  Default,
+  PartialEq,
+  Eq,
+  Serialize,
+  Deserialize,
+  Display,
+  EnumString,
+  clap::ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum BackupCompression {
+  /// No compression, files are written as `.jsonl`.
+  None,
+  /// Gzip compression, files are written as `.gz`. Default.
+  #[default]
+  Gzip,
+  /// Zstd compression, files are written as `.zst`.
+  Zstd,
+}
+
+impl BackupCompression {
+  /// The file extension used for this compression
+  pub fn extension(self) -> &'static str {
+    match self {
+      BackupCompression::None => "jsonl",
+      BackupCompression::Gzip => "gz",
+      BackupCompression::Zstd => "zst",
+    }
+  }
+}
+
+/// A remote object store to upload database backups to.
+/// The backup folders are uploaded under the `prefix`,
+/// using the same folder names as the local backups.
+#[typeshare]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "params")]
+pub enum RemoteBackupTarget {
+  /// Upload to an AWS S3 (or S3 compatible) bucket.
+  S3 {
+    bucket: String,
+    #[serde(default)]
+    prefix: String,
+    region: String,
+    access_key_id: String,
+    secret_access_key: String,
+  },
+  /// Upload to a Google Cloud Storage bucket.
+  Gcs {
+    bucket: String,
+    #[serde(default)]
+    prefix: String,
+    /// The service account key, in JSON format.
+    service_account_json: String,
+  },
+  /// Upload to an Azure Blob Storage container.
+  AzureBlob {
+    container: String,
+    /// The storage account name
+    account: String,
+    /// The storage account access key
+    key: String,
+  },
+}
+
+impl RemoteBackupTarget {
+  /// Redacts the credentials.
+  pub fn sanitized(&self) -> RemoteBackupTarget {
+    let mut target = self.clone();
+    match &mut target {
+      RemoteBackupTarget::S3 {
+        secret_access_key, ..
+      } => *secret_access_key = empty_or_redacted(secret_access_key),
+      RemoteBackupTarget::Gcs {
+        service_account_json,
+        ..
+      } => {
+        *service_account_json =
+          empty_or_redacted(service_account_json)
+      }
+      RemoteBackupTarget::AzureBlob { key, .. } => {
+        *key = empty_or_redacted(key)
+      }
+    }
+    target
+  }
+}
@@ -15,7 +15,7 @@ use serde::Deserialize;
 
 use crate::entities::{
   Timelength,
-  config::DatabaseConfig,
+  config::{DatabaseConfig, cli::RemoteBackupTarget},
   logger::{LogConfig, LogLevel, StdioLogMode},
 };
 
@@ -71,6 +71,8 @@ pub struct Env {
   pub komodo_port: Option<u16>,
   /// Override `bind_ip`
   pub komodo_bind_ip: Option<String>,
+  /// Override `shutdown_grace_period`
+  pub komodo_shutdown_grace_period: Option<Timelength>,
   /// Override `passkey`
   pub komodo_passkey: Option<String>,
   /// Override `passkey` with file
@@ -96,14 +98,24 @@ pub struct Env {
   pub komodo_repo_directory: Option<PathBuf>,
   /// Override `action_directory`
   pub komodo_action_directory: Option<PathBuf>,
+  /// Override `terminal_recording_directory`
+  pub komodo_terminal_recording_directory: Option<PathBuf>,
   /// Override `resource_poll_interval`
   pub komodo_resource_poll_interval: Option<Timelength>,
   /// Override `monitoring_interval`
   pub komodo_monitoring_interval: Option<Timelength>,
+  /// Override `prune_interval`
+  pub komodo_prune_interval: Option<Timelength>,
   /// Override `keep_stats_for_days`
   pub komodo_keep_stats_for_days: Option<u64>,
   /// Override `keep_alerts_for_days`
   pub komodo_keep_alerts_for_days: Option<u64>,
+  /// Override `keep_terminal_recordings_for_days`
+  pub komodo_keep_terminal_recordings_for_days: Option<u64>,
+  /// Override `keep_updates_for_days`
+  pub komodo_keep_updates_for_days: Option<u64>,
+  /// Override `keep_audit_log_for_days`
+  pub komodo_keep_audit_log_for_days: Option<u64>,
   /// Override `webhook_secret`
   pub komodo_webhook_secret: Option<String>,
   /// Override `webhook_secret` with file
@@ -132,6 +144,12 @@ pub struct Env {
   pub komodo_transparent_mode: Option<bool>,
   /// Override `ui_write_disabled`
   pub komodo_ui_write_disabled: Option<bool>,
+  /// Override `read_only`
+  pub komodo_read_only: Option<bool>,
+  /// Override `record_terminals`
+  pub komodo_record_terminals: Option<bool>,
+  /// Override `max_terminals_per_user`
+  pub komodo_max_terminals_per_user: Option<usize>,
   /// Override `enable_new_users`
   pub komodo_enable_new_users: Option<bool>,
   /// Override `disable_user_registration`
@@ -151,6 +169,18 @@ pub struct Env {
 
   /// Override `local_auth`
   pub komodo_local_auth: Option<bool>,
+  /// Override `login_max_failed_attempts`
+  pub komodo_login_max_failed_attempts: Option<u32>,
+  /// Override `login_failed_attempts_window`
+  pub komodo_login_failed_attempts_window: Option<Timelength>,
+  /// Override `login_lockout_duration`
+  pub komodo_login_lockout_duration: Option<Timelength>,
+  /// Override `rate_limit_per_minute`
+  pub komodo_rate_limit_per_minute: Option<u64>,
+  /// Override `admin_rate_limit_per_minute`
+  pub komodo_admin_rate_limit_per_minute: Option<u64>,
+  /// Override `service_user_rate_limit_per_minute`
+  pub komodo_service_user_rate_limit_per_minute: Option<u64>,
   /// Override `init_admin_username`
   pub komodo_init_admin_username: Option<String>,
   /// Override `init_admin_username` from file
@@ -180,6 +210,26 @@ pub struct Env {
   pub komodo_oidc_additional_audiences: Option<Vec<String>>,
   /// Override `oidc_additional_audiences` from file
   pub komodo_oidc_additional_audiences_file: Option<PathBuf>,
+  /// Override `oidc_additional_scopes`
+  pub komodo_oidc_additional_scopes: Option<Vec<String>>,
+  /// Override `oidc_admin_groups`
+  pub komodo_oidc_admin_groups: Option<Vec<String>>,
+  /// Override `oidc_groups_claim`
+  pub komodo_oidc_groups_claim: Option<String>,
+  /// Override `oidc_group_map[i].claim_value`. Accepts comma seperated list.
+  ///
+  /// Note. Paired by index with values in `komodo_oidc_group_map_user_group_names`
+  pub komodo_oidc_group_map_claim_values: Option<Vec<String>>,
+  /// Override `oidc_group_map[i].user_group_name`. Accepts comma seperated list.
+  ///
+  /// Note. Paired by index with values in `komodo_oidc_group_map_claim_values`
+  pub komodo_oidc_group_map_user_group_names: Option<Vec<String>>,
+  /// Override `scim_enabled`
+  pub komodo_scim_enabled: Option<bool>,
+  /// Override `scim_api_key`
+  pub komodo_scim_api_key: Option<String>,
+  /// Override `scim_api_key` from file
+  pub komodo_scim_api_key_file: Option<PathBuf>,
 
   /// Override `google_oauth.enabled`
   pub komodo_google_oauth_enabled: Option<bool>,
@@ -202,6 +252,8 @@ pub struct Env {
   pub komodo_github_oauth_secret: Option<String>,
   /// Override `github_oauth.secret` from file
   pub komodo_github_oauth_secret_file: Option<PathBuf>,
+  /// Override `github_oauth.allowed_orgs`
+  pub komodo_github_oauth_allowed_orgs: Option<Vec<String>>,
 
   /// Override `github_webhook_app.app_id`
   pub komodo_github_webhook_app_app_id: Option<i64>,
@@ -249,6 +301,14 @@ pub struct Env {
   /// Override `database.db_name`
   #[serde(alias = "komodo_mongo_db_name")]
   pub komodo_database_db_name: Option<String>,
+  /// Override `database.max_pool_size`
+  pub komodo_database_max_pool_size: Option<u32>,
+  /// Override `database.min_pool_size`
+  pub komodo_database_min_pool_size: Option<u32>,
+  /// Override `database.connect_timeout_ms`
+  pub komodo_database_connect_timeout_ms: Option<u64>,
+  /// Override `database.server_selection_timeout_ms`
+  pub komodo_database_server_selection_timeout_ms: Option<u64>,
 
   /// Override `aws.access_key_id`
   pub komodo_aws_access_key_id: Option<String>,
@@ -268,6 +328,14 @@ pub struct Env {
   pub komodo_ssl_key_file: Option<PathBuf>,
   /// Override `ssl_cert_file`
   pub komodo_ssl_cert_file: Option<PathBuf>,
+  /// Override `cors_allowed_origins`
+  pub komodo_cors_allowed_origins: Option<Vec<String>>,
+  /// Override `max_request_body_mb`
+  pub komodo_max_request_body_mb: Option<u64>,
+  /// Override `trust_forwarded_for`
+  pub komodo_trust_forwarded_for: Option<bool>,
+  /// Override `strict_interpolation`
+  pub komodo_strict_interpolation: Option<bool>,
 }
 
 fn default_core_config_paths() -> Vec<PathBuf> {
@@ -313,7 +381,16 @@ pub struct CoreConfig {
   #[serde(default = "default_core_bind_ip")]
   pub bind_ip: String,
 
+  /// On SIGTERM, Core stops accepting requests and waits
+  /// up to this long for running executions to finish.
+  /// A second SIGTERM exits immediately.
+  /// Default: `1-min`.
+  #[serde(default = "default_shutdown_grace_period")]
+  pub shutdown_grace_period: Timelength,
+
   /// Interface to use as default route in multi-NIC environments.
+  /// This is Core's own network interface. For the network
+  /// interface used in server stats, see the Server's `network_interface`.
   #[serde(default)]
   pub internet_interface: String,
 
@@ -332,6 +409,24 @@ pub struct CoreConfig {
   #[serde(default)]
   pub ui_write_disabled: bool,
 
+  /// Reject all requests to the `/write` and `/execute` apis
+  /// with 503 Service Unavailable, for both UI and API callers.
+  /// Useful for standby Core instances, such as during blue-green upgrades.
+  #[serde(default)]
+  pub read_only: bool,
+
+  /// Record all terminal session input / output proxied through Core
+  /// to files in `terminal_recording_directory` for auditing.
+  /// Default: false
+  #[serde(default)]
+  pub record_terminals: bool,
+
+  /// The maximum number of terminal sessions
+  /// each user can have open through Core at once, or 0 for no limit.
+  /// Default: 0
+  #[serde(default)]
+  pub max_terminals_per_user: usize,
+
   /// Disable the popup confirm dialogs. All buttons will just be double click.
   #[serde(default)]
   pub disable_confirm_dialog: bool,
@@ -374,6 +469,44 @@ pub struct CoreConfig {
   #[serde(default)]
   pub local_auth: bool,
 
+  /// Number of failed local logins for a username (or client ip)
+  /// within `login_failed_attempts_window` before further
+  /// attempts are rejected for `login_lockout_duration`.
+  /// Set to 0 to disable.
+  /// Default: 5
+  #[serde(default = "default_login_max_failed_attempts")]
+  pub login_max_failed_attempts: u32,
+
+  /// The sliding window in which failed logins are counted.
+  /// Default: `5-min`
+  #[serde(default = "default_login_failed_attempts_window")]
+  pub login_failed_attempts_window: Timelength,
+
+  /// How long login is rejected after too many failed attempts.
+  /// Default: `15-min`
+  #[serde(default = "default_login_lockout_duration")]
+  pub login_lockout_duration: Timelength,
+
+  /// Max authenticated api requests per user per minute.
+  /// Bursts up to the full minute's worth are allowed.
+  /// Requests over the limit are rejected with 429.
+  /// Set to 0 to disable.
+  /// Default: 600
+  #[serde(default = "default_rate_limit_per_minute")]
+  pub rate_limit_per_minute: u64,
+
+  /// Max api requests per minute for admin users.
+  /// Set to 0 to disable.
+  /// Default: 3000
+  #[serde(default = "default_elevated_rate_limit_per_minute")]
+  pub admin_rate_limit_per_minute: u64,
+
+  /// Max api requests per minute for service users.
+  /// Set to 0 to disable.
+  /// Default: 3000
+  #[serde(default = "default_elevated_rate_limit_per_minute")]
+  pub service_user_rate_limit_per_minute: u64,
+
   /// Upon fresh launch, initalize an Admin user with this username.
   /// If this is not provided, no initial user will be created.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -465,6 +598,43 @@ pub struct CoreConfig {
   #[serde(default, skip_serializing_if = "Vec::is_empty")]
   pub oidc_additional_audiences: Vec<String>,
 
+  /// Scopes to request in addition to `openid`, `profile`, and `email`,
+  /// for providers which only return some claims (eg `groups`) when asked.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub oidc_additional_scopes: Vec<String>,
+
+  /// Users in any of these provider groups are made admin.
+  /// Evaluated on every login, so group changes propagate.
+  /// Super admins are never demoted.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub oidc_admin_groups: Vec<String>,
+
+  /// The claim containing the user's groups, used with
+  /// `oidc_admin_groups`. Read from the ID token, falling back
+  /// to user info if the ID token doesn't include it.
+  /// Nested claims can be given as a path, eg `realm_access.roles`.
+  /// Default: `groups`
+  #[serde(default = "default_oidc_groups_claim")]
+  pub oidc_groups_claim: String,
+
+  /// Maps values of the `oidc_groups_claim` to Komodo User Groups.
+  /// On every login, the user is added to the mapped groups they have
+  /// the claim value for, and removed from the ones they don't.
+  /// User Groups not in the map are left untouched.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub oidc_group_map: Vec<OidcGroupMap>,
+
+  /// Enable the SCIM 2.0 user provisioning endpoint at `/scim/v2`,
+  /// so an identity provider can create, update, and remove OIDC users.
+  /// Requires `oidc_enabled`.
+  #[serde(default)]
+  pub scim_enabled: bool,
+
+  /// The bearer token the identity provider
+  /// uses to authenticate with the SCIM endpoint.
+  #[serde(default)]
+  pub scim_api_key: String,
+
   // =========
   // = Oauth =
   // =========
@@ -474,7 +644,7 @@ pub struct CoreConfig {
 
   /// Configure github oauth
   #[serde(default)]
-  pub github_oauth: OauthCredentials,
+  pub github_oauth: GithubOauthCredentials,
 
   // ============
   // = Webhooks =
@@ -532,6 +702,24 @@ pub struct CoreConfig {
   #[serde(default = "default_prune_days")]
   pub keep_alerts_for_days: u64,
 
+  /// Number of days to keep terminal recordings, or 0 to disable pruning.
+  /// Recordings older than this number of days are deleted on a daily cycle
+  /// Default: 14
+  #[serde(default = "default_prune_days")]
+  pub keep_terminal_recordings_for_days: u64,
+
+  /// Number of days to keep updates, or 0 to disable pruning.
+  /// Completed updates older than this number of days are deleted on a daily cycle
+  /// Default: 0
+  #[serde(default)]
+  pub keep_updates_for_days: u64,
+
+  /// Number of days to keep audit log entries, or 0 to disable pruning.
+  /// Entries older than this number of days are deleted on a daily cycle
+  /// Default: 0
+  #[serde(default)]
+  pub keep_audit_log_for_days: u64,
+
   // ==================
   // = Poll Intervals =
   // ==================
@@ -546,6 +734,12 @@ pub struct CoreConfig {
   #[serde(default = "default_monitoring_interval")]
   pub monitoring_interval: Timelength,
 
+  /// Interval at which to prune old stats, alerts, updates,
+  /// terminal recordings, and docker images on servers with auto prune.
+  /// Default: `1-day`
+  #[serde(default = "default_prune_interval")]
+  pub prune_interval: Timelength,
+
   // ===================
   // = Cloud Providers =
   // ===================
@@ -553,6 +747,12 @@ pub struct CoreConfig {
   #[serde(default)]
   pub aws: AwsCredentials,
 
+  /// Remote object stores to upload Core database backups to, by name.
+  /// [BackupCoreDatabase][crate::api::execute::BackupCoreDatabase]
+  /// references one by name, so the credentials stay in the Core config.
+  #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+  pub backup_remote_targets: HashMap<String, RemoteBackupTarget>,
+
   // =================
   // = Git Providers =
   // =================
@@ -586,6 +786,13 @@ pub struct CoreConfig {
   #[serde(default, skip_serializing_if = "HashMap::is_empty")]
   pub secrets: HashMap<String, String>,
 
+  /// Fail Deployment deploys and Builds when their config references
+  /// a `[[SECRET]]` which isn't defined in Core, on the Periphery,
+  /// or as a Variable, rather than leaving the token in place.
+  /// Default: false
+  #[serde(default)]
+  pub strict_interpolation: bool,
+
   // =======
   // = SSL =
   // =======
@@ -603,6 +810,28 @@ pub struct CoreConfig {
   #[serde(default = "default_ssl_cert_file")]
   pub ssl_cert_file: PathBuf,
 
+  /// Origins allowed to make cross origin requests to Core,
+  /// eg `https://komodo.example.com`. Credentials are allowed
+  /// for these origins. If empty, any origin is allowed.
+  /// Default: empty list
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub cors_allowed_origins: Vec<String>,
+
+  /// The max size of a request body to the `/write` and `/execute`
+  /// apis in MB, eg. when writing large sync or compose files.
+  /// Larger requests are rejected with 413.
+  /// Default: `50`
+  #[serde(default = "default_max_request_body_mb")]
+  pub max_request_body_mb: u64,
+
+  /// Whether to trust the `X-Forwarded-For` / `X-Real-IP` headers
+  /// for the client ip. Only enable this when Core is behind
+  /// a reverse proxy which sets them, otherwise clients can spoof them.
+  /// When disabled, the ip of the connecting peer is used.
+  /// Default: `false`
+  #[serde(default)]
+  pub trust_forwarded_for: bool,
+
   // =========
   // = Other =
   // =========
@@ -621,6 +850,12 @@ pub struct CoreConfig {
   /// Default: `/action-cache`
   #[serde(default = "default_action_directory")]
   pub action_directory: PathBuf,
+
+  /// Specify the directory used to write terminal recordings,
+  /// when `record_terminals` is enabled.
+  /// Default: `/terminal-recordings`
+  #[serde(default = "default_terminal_recording_directory")]
+  pub terminal_recording_directory: PathBuf,
 }
 
 fn default_title() -> String {
@@ -651,6 +886,30 @@ fn default_first_server_name() -> String {
   String::from("Local")
 }
 
+fn default_login_max_failed_attempts() -> u32 {
+  5
+}
+
+fn default_login_failed_attempts_window() -> Timelength {
+  Timelength::FiveMinutes
+}
+
+fn default_login_lockout_duration() -> Timelength {
+  Timelength::FifteenMinutes
+}
+
+fn default_rate_limit_per_minute() -> u64 {
+  600
+}
+
+fn default_elevated_rate_limit_per_minute() -> u64 {
+  3000
+}
+
+fn default_shutdown_grace_period() -> Timelength {
+  Timelength::OneMinute
+}
+
 fn default_jwt_ttl() -> Timelength {
   Timelength::OneDay
 }
@@ -659,6 +918,10 @@ fn default_init_admin_password() -> String {
   String::from("changeme")
 }
 
+fn default_oidc_groups_claim() -> String {
+  String::from("groups")
+}
+
 fn default_sync_directory() -> PathBuf {
   // unwrap ok: `/syncs` will always be valid path
   PathBuf::from_str("/syncs").unwrap()
@@ -674,6 +937,11 @@ fn default_action_directory() -> PathBuf {
   PathBuf::from_str("/action-cache").unwrap()
 }
 
+fn default_terminal_recording_directory() -> PathBuf {
+  // unwrap ok: `/terminal-recordings` will always be valid path
+  PathBuf::from_str("/terminal-recordings").unwrap()
+}
+
 fn default_prune_days() -> u64 {
   14
 }
@@ -686,6 +954,14 @@ fn default_monitoring_interval() -> Timelength {
   Timelength::FifteenSeconds
 }
 
+fn default_prune_interval() -> Timelength {
+  Timelength::OneDay
+}
+
+fn default_max_request_body_mb() -> u64 {
+  50
+}
+
 fn default_ssl_key_file() -> PathBuf {
   "/config/ssl/key.pem".parse().unwrap()
 }
@@ -701,10 +977,14 @@ impl Default for CoreConfig {
       host: default_host(),
       port: default_core_port(),
       bind_ip: default_core_bind_ip(),
+      shutdown_grace_period: default_shutdown_grace_period(),
       internet_interface: Default::default(),
       passkey: default_passkey(),
       timezone: Default::default(),
       ui_write_disabled: Default::default(),
+      read_only: Default::default(),
+      record_terminals: Default::default(),
+      max_terminals_per_user: Default::default(),
       disable_confirm_dialog: Default::default(),
       disable_websocket_reconnect: Default::default(),
       disable_init_resources: Default::default(),
@@ -714,6 +994,15 @@ impl Default for CoreConfig {
       frontend_path: default_frontend_path(),
       database: Default::default(),
       local_auth: Default::default(),
+      login_max_failed_attempts: default_login_max_failed_attempts(),
+      login_failed_attempts_window:
+        default_login_failed_attempts_window(),
+      login_lockout_duration: default_login_lockout_duration(),
+      rate_limit_per_minute: default_rate_limit_per_minute(),
+      admin_rate_limit_per_minute:
+        default_elevated_rate_limit_per_minute(),
+      service_user_rate_limit_per_minute:
+        default_elevated_rate_limit_per_minute(),
       init_admin_username: Default::default(),
       init_admin_password: default_init_admin_password(),
       transparent_mode: Default::default(),
@@ -730,6 +1019,12 @@ impl Default for CoreConfig {
       oidc_client_secret: Default::default(),
       oidc_use_full_email: Default::default(),
       oidc_additional_audiences: Default::default(),
+      oidc_additional_scopes: Default::default(),
+      oidc_admin_groups: Default::default(),
+      oidc_groups_claim: default_oidc_groups_claim(),
+      oidc_group_map: Default::default(),
+      scim_enabled: Default::default(),
+      scim_api_key: Default::default(),
       google_oauth: Default::default(),
       github_oauth: Default::default(),
       webhook_secret: Default::default(),
@@ -740,18 +1035,29 @@ impl Default for CoreConfig {
       unsafe_unsanitized_startup_config: Default::default(),
       keep_stats_for_days: default_prune_days(),
       keep_alerts_for_days: default_prune_days(),
+      keep_terminal_recordings_for_days: default_prune_days(),
+      keep_updates_for_days: Default::default(),
+      keep_audit_log_for_days: Default::default(),
       resource_poll_interval: default_poll_interval(),
       monitoring_interval: default_monitoring_interval(),
+      prune_interval: default_prune_interval(),
       aws: Default::default(),
+      backup_remote_targets: Default::default(),
       git_providers: Default::default(),
       docker_registries: Default::default(),
       secrets: Default::default(),
+      strict_interpolation: Default::default(),
       ssl_enabled: Default::default(),
       ssl_key_file: default_ssl_key_file(),
       ssl_cert_file: default_ssl_cert_file(),
+      cors_allowed_origins: Default::default(),
+      max_request_body_mb: default_max_request_body_mb(),
+      trust_forwarded_for: Default::default(),
       sync_directory: default_sync_directory(),
       repo_directory: default_repo_directory(),
       action_directory: default_action_directory(),
+      terminal_recording_directory:
+        default_terminal_recording_directory(),
     }
   }
 }
@@ -764,6 +1070,7 @@ impl CoreConfig {
       host: config.host,
       port: config.port,
       bind_ip: config.bind_ip,
+      shutdown_grace_period: config.shutdown_grace_period,
       passkey: empty_or_redacted(&config.passkey),
       timezone: config.timezone,
       first_server: config.first_server,
@@ -773,18 +1080,28 @@ impl CoreConfig {
       jwt_ttl: config.jwt_ttl,
       repo_directory: config.repo_directory,
       action_directory: config.action_directory,
+      terminal_recording_directory: config
+        .terminal_recording_directory,
       sync_directory: config.sync_directory,
       internet_interface: config.internet_interface,
       resource_poll_interval: config.resource_poll_interval,
       monitoring_interval: config.monitoring_interval,
+      prune_interval: config.prune_interval,
       keep_stats_for_days: config.keep_stats_for_days,
       keep_alerts_for_days: config.keep_alerts_for_days,
+      keep_terminal_recordings_for_days: config
+        .keep_terminal_recordings_for_days,
+      keep_updates_for_days: config.keep_updates_for_days,
+      keep_audit_log_for_days: config.keep_audit_log_for_days,
       logging: config.logging,
       pretty_startup_config: config.pretty_startup_config,
       unsafe_unsanitized_startup_config: config
         .unsafe_unsanitized_startup_config,
       transparent_mode: config.transparent_mode,
       ui_write_disabled: config.ui_write_disabled,
+      read_only: config.read_only,
+      record_terminals: config.record_terminals,
+      max_terminals_per_user: config.max_terminals_per_user,
       disable_confirm_dialog: config.disable_confirm_dialog,
       disable_websocket_reconnect: config.disable_websocket_reconnect,
       disable_init_resources: config.disable_init_resources,
@@ -794,6 +1111,14 @@ impl CoreConfig {
       disable_non_admin_create: config.disable_non_admin_create,
       lock_login_credentials_for: config.lock_login_credentials_for,
       local_auth: config.local_auth,
+      login_max_failed_attempts: config.login_max_failed_attempts,
+      login_failed_attempts_window: config
+        .login_failed_attempts_window,
+      login_lockout_duration: config.login_lockout_duration,
+      rate_limit_per_minute: config.rate_limit_per_minute,
+      admin_rate_limit_per_minute: config.admin_rate_limit_per_minute,
+      service_user_rate_limit_per_minute: config
+        .service_user_rate_limit_per_minute,
       init_admin_username: config
         .init_admin_username
         .map(|u| empty_or_redacted(&u)),
@@ -813,15 +1138,22 @@ impl CoreConfig {
         .iter()
         .map(|aud| empty_or_redacted(aud))
         .collect(),
+      oidc_additional_scopes: config.oidc_additional_scopes,
+      oidc_admin_groups: config.oidc_admin_groups,
+      oidc_groups_claim: config.oidc_groups_claim,
+      oidc_group_map: config.oidc_group_map,
+      scim_enabled: config.scim_enabled,
+      scim_api_key: empty_or_redacted(&config.scim_api_key),
       google_oauth: OauthCredentials {
         enabled: config.google_oauth.enabled,
         id: empty_or_redacted(&config.google_oauth.id),
         secret: empty_or_redacted(&config.google_oauth.id),
       },
-      github_oauth: OauthCredentials {
+      github_oauth: GithubOauthCredentials {
         enabled: config.github_oauth.enabled,
         id: empty_or_redacted(&config.github_oauth.id),
         secret: empty_or_redacted(&config.github_oauth.id),
+        allowed_orgs: config.github_oauth.allowed_orgs,
       },
       webhook_secret: empty_or_redacted(&config.webhook_secret),
       webhook_base_url: config.webhook_base_url,
@@ -833,11 +1165,17 @@ impl CoreConfig {
           &config.aws.secret_access_key,
         ),
       },
+      backup_remote_targets: config
+        .backup_remote_targets
+        .into_iter()
+        .map(|(name, target)| (name, target.sanitized()))
+        .collect(),
       secrets: config
         .secrets
         .into_iter()
         .map(|(id, secret)| (id, empty_or_redacted(&secret)))
         .collect(),
+      strict_interpolation: config.strict_interpolation,
       git_providers: config
         .git_providers
         .into_iter()
@@ -862,6 +1200,9 @@ impl CoreConfig {
       ssl_enabled: config.ssl_enabled,
       ssl_key_file: config.ssl_key_file,
       ssl_cert_file: config.ssl_cert_file,
+      cors_allowed_origins: config.cors_allowed_origins,
+      max_request_body_mb: config.max_request_body_mb,
+      trust_forwarded_for: config.trust_forwarded_for,
     }
   }
 }
@@ -880,6 +1221,24 @@ pub struct OauthCredentials {
   pub secret: String,
 }
 
+/// Github Oauth credentials
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GithubOauthCredentials {
+  /// Whether this oauth method is available for usage.
+  #[serde(default)]
+  pub enabled: bool,
+  /// The Oauth client id.
+  #[serde(default)]
+  pub id: String,
+  /// The Oauth client secret.
+  #[serde(default)]
+  pub secret: String,
+  /// Only allow login for members of these Github organizations.
+  /// Allows all Github users if empty.
+  #[serde(default)]
+  pub allowed_orgs: Vec<String>,
+}
+
 /// Provide AWS credentials for Komodo to use.
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct AwsCredentials {
@@ -889,6 +1248,15 @@ pub struct AwsCredentials {
   pub secret_access_key: String,
 }
 
+/// Maps an OIDC groups claim value to a Komodo User Group.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OidcGroupMap {
+  /// The value in the groups claim, eg the provider group name.
+  pub claim_value: String,
+  /// The name of the Komodo User Group.
+  pub user_group_name: String,
+}
+
 /// Provide configuration for a Github Webhook app.
 #[derive(Debug, Clone, Deserialize)]
 pub struct GithubWebhookAppConfig {
@@ -50,6 +50,22 @@ pub struct DatabaseConfig {
   /// Default: `komodo`.
   #[serde(default = "default_database_db_name")]
   pub db_name: String,
+  /// Maximum number of connections in the connection pool.
+  /// Default: MongoDB driver default (`10`).
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub max_pool_size: Option<u32>,
+  /// Minimum number of connections kept open in the connection pool.
+  /// Default: MongoDB driver default (`0`).
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub min_pool_size: Option<u32>,
+  /// Timeout in milliseconds when opening a connection.
+  /// Default: No timeout.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub connect_timeout_ms: Option<u64>,
+  /// Timeout in milliseconds when selecting a server for an operation.
+  /// Default: MongoDB driver default (`30000`).
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub server_selection_timeout_ms: Option<u64>,
 }
 
 fn default_database_address() -> String {
@@ -73,6 +89,10 @@ impl Default for DatabaseConfig {
       password: Default::default(),
       app_name: default_database_app_name(),
       db_name: default_database_db_name(),
+      max_pool_size: None,
+      min_pool_size: None,
+      connect_timeout_ms: None,
+      server_selection_timeout_ms: None,
     }
   }
 }
@@ -92,6 +112,10 @@ impl DatabaseConfig {
       password: empty_or_redacted(&self.password),
       app_name: self.app_name.clone(),
       db_name: self.db_name.clone(),
+      max_pool_size: self.max_pool_size,
+      min_pool_size: self.min_pool_size,
+      connect_timeout_ms: self.connect_timeout_ms,
+      server_selection_timeout_ms: self.server_selection_timeout_ms,
     }
   }
 
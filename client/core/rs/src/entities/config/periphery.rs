@@ -136,6 +136,10 @@ pub struct Env {
   pub periphery_disable_terminals: Option<bool>,
   /// Override `disable_container_exec`
   pub periphery_disable_container_exec: Option<bool>,
+  /// Override `terminal_idle_timeout`
+  pub periphery_terminal_idle_timeout: Option<Timelength>,
+  /// Override `max_terminals`
+  pub periphery_max_terminals: Option<usize>,
   /// Override `stats_polling_rate`
   pub periphery_stats_polling_rate: Option<Timelength>,
   /// Override `container_stats_polling_rate`
@@ -165,6 +169,12 @@ pub struct Env {
   pub periphery_passkeys: Option<Vec<String>>,
   /// Override `passkeys` from file
   pub periphery_passkeys_file: Option<PathBuf>,
+  /// Override `pending_passkey`
+  pub periphery_pending_passkey: Option<String>,
+  /// Override `pending_passkey` from file
+  pub periphery_pending_passkey_file: Option<PathBuf>,
+  /// Override `passkey_rotation_grace_period_seconds`
+  pub periphery_passkey_rotation_grace_period_seconds: Option<u64>,
   /// Override `include_disk_mounts`
   pub periphery_include_disk_mounts: Option<ForgivingVec<PathBuf>>,
   /// Override `exclude_disk_mounts`
@@ -176,6 +186,8 @@ pub struct Env {
   pub periphery_ssl_key_file: Option<PathBuf>,
   /// Override `ssl_cert_file`
   pub periphery_ssl_cert_file: Option<PathBuf>,
+  /// Override `tls_ca_cert`
+  pub periphery_tls_ca_cert: Option<PathBuf>,
 }
 
 /// # Periphery Configuration File
@@ -233,6 +245,19 @@ pub struct PeripheryConfig {
   #[serde(default)]
   pub disable_container_exec: bool,
 
+  /// Terminals which have had no input or output for this long
+  /// will be deleted, killing the underlying shell process.
+  /// Options: https://docs.rs/komodo_client/latest/komodo_client/entities/enum.Timelength.html
+  /// Default: `1-day`
+  #[serde(default = "default_terminal_idle_timeout")]
+  pub terminal_idle_timeout: Timelength,
+
+  /// The maximum number of terminals (including container exec terminals)
+  /// which can exist on the host at once, or 0 for no limit.
+  /// Default: 0
+  #[serde(default)]
+  pub max_terminals: usize,
+
   /// The rate at which the system stats will be polled to update the cache.
   /// Options: https://docs.rs/komodo_client/latest/komodo_client/entities/enum.Timelength.html
   /// Default: `5-sec`
@@ -274,6 +299,18 @@ pub struct PeripheryConfig {
   #[serde(default)]
   pub passkeys: Vec<String>,
 
+  /// A new passkey to accept alongside `passkeys` while rotating.
+  /// Add the new passkey here, update Core to use it, then move it
+  /// into `passkeys` and remove the old one.
+  /// Only accepted for `passkey_rotation_grace_period_seconds` after startup.
+  /// Default: none
+  pub pending_passkey: Option<String>,
+
+  /// How long after startup the `pending_passkey` is accepted.
+  /// Default: `86400` (1 day)
+  #[serde(default = "default_passkey_rotation_grace_period_seconds")]
+  pub passkey_rotation_grace_period_seconds: u64,
+
   /// If non-empty, only includes specific mount paths in the disk report.
   #[serde(default)]
   pub include_disk_mounts: ForgivingVec<PathBuf>,
@@ -309,6 +346,12 @@ pub struct PeripheryConfig {
   /// Path to the ssl cert.
   /// Default: `${root_directory}/ssl/cert.pem`.
   pub ssl_cert_file: Option<PathBuf>,
+
+  /// Path to a CA cert (PEM) used to verify client certificates.
+  /// When set along with `ssl_enabled`, clients must present
+  /// a certificate signed by this CA (mTLS), in addition to the passkey.
+  /// Default: none
+  pub tls_ca_cert: Option<PathBuf>,
 }
 
 fn default_periphery_port() -> u16 {
@@ -331,6 +374,14 @@ fn default_container_stats_polling_rate() -> Timelength {
   Timelength::ThirtySeconds
 }
 
+fn default_terminal_idle_timeout() -> Timelength {
+  Timelength::OneDay
+}
+
+fn default_passkey_rotation_grace_period_seconds() -> u64 {
+  86400
+}
+
 fn default_ssl_enabled() -> bool {
   true
 }
@@ -346,6 +397,8 @@ impl Default for PeripheryConfig {
       build_dir: None,
       disable_terminals: Default::default(),
       disable_container_exec: Default::default(),
+      terminal_idle_timeout: default_terminal_idle_timeout(),
+      max_terminals: Default::default(),
       stats_polling_rate: default_stats_polling_rate(),
       container_stats_polling_rate:
         default_container_stats_polling_rate(),
@@ -354,6 +407,9 @@ impl Default for PeripheryConfig {
       pretty_startup_config: Default::default(),
       allowed_ips: Default::default(),
       passkeys: Default::default(),
+      pending_passkey: None,
+      passkey_rotation_grace_period_seconds:
+        default_passkey_rotation_grace_period_seconds(),
       include_disk_mounts: Default::default(),
       exclude_disk_mounts: Default::default(),
       secrets: Default::default(),
@@ -362,6 +418,7 @@ impl Default for PeripheryConfig {
       ssl_enabled: default_ssl_enabled(),
       ssl_key_file: None,
       ssl_cert_file: None,
+      tls_ca_cert: None,
     }
   }
 }
@@ -377,6 +434,8 @@ impl PeripheryConfig {
       build_dir: self.build_dir.clone(),
       disable_terminals: self.disable_terminals,
       disable_container_exec: self.disable_container_exec,
+      terminal_idle_timeout: self.terminal_idle_timeout,
+      max_terminals: self.max_terminals,
       stats_polling_rate: self.stats_polling_rate,
       container_stats_polling_rate: self.container_stats_polling_rate,
       legacy_compose_cli: self.legacy_compose_cli,
@@ -388,6 +447,12 @@ impl PeripheryConfig {
         .iter()
         .map(|passkey| empty_or_redacted(passkey))
         .collect(),
+      pending_passkey: self
+        .pending_passkey
+        .as_deref()
+        .map(empty_or_redacted),
+      passkey_rotation_grace_period_seconds: self
+        .passkey_rotation_grace_period_seconds,
       include_disk_mounts: self.include_disk_mounts.clone(),
       exclude_disk_mounts: self.exclude_disk_mounts.clone(),
       secrets: self
@@ -432,6 +497,7 @@ impl PeripheryConfig {
       ssl_enabled: self.ssl_enabled,
       ssl_key_file: self.ssl_key_file.clone(),
       ssl_cert_file: self.ssl_cert_file.clone(),
+      tls_ca_cert: self.tls_ca_cert.clone(),
     }
   }
 
@@ -85,6 +85,13 @@ pub struct DeploymentConfig {
   #[builder(default)]
   pub skip_secret_interp: bool,
 
+  /// Whether to skip only variable interpolation, still interpolating secrets.
+  /// Useful if the contents use `[[...]]` for something other than Komodo variables.
+  /// If `skip_secret_interp` is also enabled, all interpolation is skipped.
+  #[serde(default)]
+  #[builder(default)]
+  pub skip_variable_interp: bool,
+
   /// Whether to redeploy the deployment whenever the attached build finishes.
   #[serde(default)]
   #[builder(default)]
@@ -126,6 +133,13 @@ pub struct DeploymentConfig {
   #[builder(default)]
   pub restart: RestartMode,
 
+  /// When to pull the image before running the container,
+  /// passed to `docker run --pull`.
+  /// Use `always` to force a fresh pull on redeploy without changing the tag.
+  #[serde(default)]
+  #[builder(default)]
+  pub pull_policy: PullPolicy,
+
   /// This is interpolated at the end of the `docker run` command,
   /// which means they are either passed to the containers inner process,
   /// or replaces the container command, depending on use of ENTRYPOINT or CMD in dockerfile.
@@ -237,6 +251,7 @@ impl Default for DeploymentConfig {
       image: Default::default(),
       image_registry_account: Default::default(),
       skip_secret_interp: Default::default(),
+      skip_variable_interp: Default::default(),
       redeploy_on_build: Default::default(),
       poll_for_updates: Default::default(),
       auto_update: Default::default(),
@@ -249,6 +264,7 @@ impl Default for DeploymentConfig {
       labels: Default::default(),
       network: default_network(),
       restart: Default::default(),
+      pull_policy: Default::default(),
       command: Default::default(),
       extra_args: Default::default(),
     }
@@ -416,6 +432,38 @@ pub enum RestartMode {
   UnlessStopped,
 }
 
+#[typeshare]
+#[derive(
+  Serialize,
+  Deserialize,
+  Debug,
+  PartialEq,
+  Hash,
+  Eq,
+  Clone,
+  Copy,
+  Default,
+  Display,
+  EnumString,
+)]
+pub enum PullPolicy {
+  /// Docker's default, only pulls the image if it is missing locally.
+  /// Komodo still tries to pull the image before deploying,
+  /// but falls back to the local image if the pull fails.
+  #[default]
+  #[serde(rename = "missing")]
+  #[strum(serialize = "missing")]
+  Missing,
+  /// Always pull the image, failing the deploy if the pull fails.
+  #[serde(rename = "always")]
+  #[strum(serialize = "always")]
+  Always,
+  /// Never pull the image, it must already exist locally.
+  #[serde(rename = "never")]
+  #[strum(serialize = "never")]
+  Never,
+}
+
 #[typeshare]
 #[derive(
   Serialize,
@@ -43,6 +43,10 @@ pub struct ContainerListItem {
   /// Additional human-readable status of this container (e.g. `Exit 0`)
   #[serde(skip_serializing_if = "Option::is_none")]
   pub status: Option<String>,
+  /// The docker healthcheck status, parsed from `status`.
+  /// None if the container has no healthcheck.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub health: Option<HealthStatusEnum>,
   /// The network mode
   #[serde(skip_serializing_if = "Option::is_none")]
   pub network_mode: Option<String>,
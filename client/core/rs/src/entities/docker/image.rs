@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::I64;
+use crate::entities::{I64, ResourceTargetVariant};
 
 use super::{ContainerConfig, GraphDriverData};
 
@@ -145,3 +145,34 @@ pub struct ImageHistoryResponseItem {
   #[serde(rename = "Comment")]
   pub comment: String,
 }
+
+/// The local and registry digests for an image.
+#[typeshare]
+#[derive(
+  Debug, Clone, Default, PartialEq, Serialize, Deserialize,
+)]
+pub struct ImageDigests {
+  /// The manifest digests the local image is referenced from.
+  pub local: Vec<String>,
+  /// The manifest digest currently on the registry.
+  pub remote: String,
+}
+
+/// The result of checking the registry for a newer
+/// version of a running image.
+#[typeshare]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageUpdateCheck {
+  /// The type of resource running the image.
+  pub resource_type: ResourceTargetVariant,
+  /// The name of the resource running the image.
+  /// For Stacks, this is `stack | service`.
+  pub resource_name: String,
+  /// The running image, with the local digest if known.
+  /// Eg. `nginx:latest@sha256:...`
+  pub current_image: String,
+  /// The image with the digest currently on the registry.
+  pub latest_image: String,
+  /// Whether the registry digest differs from the local one.
+  pub has_update: bool,
+}
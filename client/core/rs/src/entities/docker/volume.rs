@@ -24,6 +24,22 @@ pub struct VolumeListItem {
   pub in_use: bool,
 }
 
+/// A file or directory inside a docker volume.
+#[typeshare]
+#[derive(
+  Debug, Clone, Default, PartialEq, Serialize, Deserialize,
+)]
+pub struct VolumeEntry {
+  /// The file / directory name
+  pub name: String,
+  /// Whether the entry is a directory
+  pub is_dir: bool,
+  /// The size of the entry in bytes
+  pub size_bytes: U64,
+  /// Unix timestamp in milliseconds of the last modification
+  pub modified_at: I64,
+}
+
 #[typeshare]
 #[derive(
   Debug, Clone, Default, PartialEq, Serialize, Deserialize,
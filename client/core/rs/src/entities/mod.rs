@@ -13,7 +13,7 @@ use serde::{
   de::{Visitor, value::MapAccessDeserializer},
 };
 use serror::Serror;
-use strum::{AsRefStr, Display, EnumString};
+use strum::{AsRefStr, Display, EnumString, VariantArray};
 use typeshare::typeshare;
 
 use crate::{
@@ -29,6 +29,8 @@ pub mod alert;
 pub mod alerter;
 /// Subtypes of [ApiKey][api_key::ApiKey].
 pub mod api_key;
+/// Subtypes of [AuditEntry][audit::AuditEntry].
+pub mod audit;
 /// Subtypes of [Build][build::Build].
 pub mod build;
 /// Subtypes of [Builder][builder::Builder].
@@ -55,6 +57,10 @@ pub mod resource;
 pub mod schedule;
 /// Subtypes of [Server][server::Server].
 pub mod server;
+/// Subtypes of [ServerGroup][server_group::ServerGroup].
+pub mod server_group;
+/// Subtypes of [ServiceAccount][service_account::ServiceAccount].
+pub mod service_account;
 /// Subtypes of [Stack][stack::Stack]
 pub mod stack;
 /// Subtypes for server stats reporting.
@@ -71,6 +77,8 @@ pub mod update;
 pub mod user;
 /// Subtypes of [UserGroup][user_group::UserGroup].
 pub mod user_group;
+/// Subtypes of [UserSession][user_session::UserSession].
+pub mod user_session;
 /// Subtypes of [Variable][variable::Variable]
 pub mod variable;
 
@@ -360,6 +368,10 @@ pub struct FileContents {
   pub path: String,
   /// The contents of the file
   pub contents: String,
+  /// Whether the contents are base64 encoded,
+  /// used for binary files such as certificates.
+  #[serde(default)]
+  pub base64_contents: bool,
 }
 
 /// Represents a scheduled maintenance window
@@ -444,6 +456,13 @@ pub struct RepoExecutionArgs {
   /// The default folder to use.
   /// Depends on the resource type.
   pub default_folder: DefaultRepoFolder,
+  /// Only check out these paths using `git sparse-checkout`.
+  /// Empty means the whole repo is checked out.
+  #[serde(default)]
+  pub sparse_checkout: Vec<String>,
+  /// Make a shallow clone with this depth. 0 means a full clone.
+  #[serde(default)]
+  pub clone_depth: u32,
 }
 
 impl RepoExecutionArgs {
@@ -519,6 +538,8 @@ impl From<&self::stack::Stack> for RepoExecutionArgs {
       commit: optional_string(&stack.config.commit),
       destination: optional_string(&stack.config.clone_path),
       default_folder: DefaultRepoFolder::Stacks,
+      sparse_checkout: Vec::new(),
+      clone_depth: 0,
     }
   }
 }
@@ -537,6 +558,8 @@ impl From<&self::build::Build> for RepoExecutionArgs {
       commit: optional_string(&build.config.commit),
       destination: None,
       default_folder: DefaultRepoFolder::Builds,
+      sparse_checkout: Vec::new(),
+      clone_depth: 0,
     }
   }
 }
@@ -555,6 +578,8 @@ impl From<&self::repo::Repo> for RepoExecutionArgs {
       commit: optional_string(&repo.config.commit),
       destination: optional_string(&repo.config.path),
       default_folder: DefaultRepoFolder::Repos,
+      sparse_checkout: repo.config.sparse_checkout.clone(),
+      clone_depth: repo.config.clone_depth,
     }
   }
 }
@@ -573,6 +598,8 @@ impl From<&self::sync::ResourceSync> for RepoExecutionArgs {
       commit: optional_string(&sync.config.commit),
       destination: None,
       default_folder: DefaultRepoFolder::NotApplicable,
+      sparse_checkout: Vec::new(),
+      clone_depth: 0,
     }
   }
 }
@@ -603,6 +630,7 @@ pub struct RepoExecutionResponse {
   Deserialize,
   Display,
   EnumString,
+  VariantArray,
 )]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
@@ -694,6 +722,39 @@ pub enum Timelength {
   ThirtyDays,
 }
 
+impl Timelength {
+  /// The length of time in milliseconds.
+  pub fn as_millis(&self) -> i64 {
+    const SECOND: i64 = 1_000;
+    const MINUTE: i64 = 60 * SECOND;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    match self {
+      Timelength::OneSecond => SECOND,
+      Timelength::FiveSeconds => 5 * SECOND,
+      Timelength::TenSeconds => 10 * SECOND,
+      Timelength::FifteenSeconds => 15 * SECOND,
+      Timelength::ThirtySeconds => 30 * SECOND,
+      Timelength::OneMinute => MINUTE,
+      Timelength::TwoMinutes => 2 * MINUTE,
+      Timelength::FiveMinutes => 5 * MINUTE,
+      Timelength::TenMinutes => 10 * MINUTE,
+      Timelength::FifteenMinutes => 15 * MINUTE,
+      Timelength::ThirtyMinutes => 30 * MINUTE,
+      Timelength::OneHour => HOUR,
+      Timelength::TwoHours => 2 * HOUR,
+      Timelength::SixHours => 6 * HOUR,
+      Timelength::EightHours => 8 * HOUR,
+      Timelength::TwelveHours => 12 * HOUR,
+      Timelength::OneDay => DAY,
+      Timelength::ThreeDay => 3 * DAY,
+      Timelength::OneWeek => 7 * DAY,
+      Timelength::TwoWeeks => 14 * DAY,
+      Timelength::ThirtyDays => 30 * DAY,
+    }
+  }
+}
+
 impl TryInto<async_timing_util::Timelength> for Timelength {
   type Error = anyhow::Error;
   fn try_into(
@@ -780,7 +841,8 @@ pub enum MaintenanceScheduleType {
   OneTime, // ISO 8601 date format (YYYY-MM-DD)
 }
 
-/// One representative IANA zone for each distinct base UTC offset in the tz database.
+/// One representative IANA zone for each distinct base UTC offset in the tz database,
+/// plus the widely used named zones, which follow their local DST rules.
 /// https://en.wikipedia.org/wiki/List_of_tz_database_time_zones.
 ///
 /// The `serde`/`strum` renames ensure the canonical identifier is used
@@ -793,6 +855,7 @@ pub enum MaintenanceScheduleType {
   PartialEq,
   Default,
   EnumString,
+  VariantArray,
   Serialize,
   Deserialize,
 )]
@@ -827,26 +890,81 @@ pub enum IanaTimezone {
   #[strum(serialize = "America/Los_Angeles")]
   AmericaLosAngeles,
 
+  /// UTC−08:00
+  #[serde(rename = "America/Vancouver")]
+  #[strum(serialize = "America/Vancouver")]
+  AmericaVancouver,
+
+  /// UTC−08:00
+  #[serde(rename = "America/Tijuana")]
+  #[strum(serialize = "America/Tijuana")]
+  AmericaTijuana,
+
   /// UTC−07:00
   #[serde(rename = "America/Denver")]
   #[strum(serialize = "America/Denver")]
   AmericaDenver,
 
+  /// UTC−07:00
+  #[serde(rename = "America/Phoenix")]
+  #[strum(serialize = "America/Phoenix")]
+  AmericaPhoenix,
+
+  /// UTC−07:00
+  #[serde(rename = "America/Edmonton")]
+  #[strum(serialize = "America/Edmonton")]
+  AmericaEdmonton,
+
   /// UTC−06:00
   #[serde(rename = "America/Chicago")]
   #[strum(serialize = "America/Chicago")]
   AmericaChicago,
 
+  /// UTC−06:00
+  #[serde(rename = "America/Mexico_City")]
+  #[strum(serialize = "America/Mexico_City")]
+  AmericaMexicoCity,
+
+  /// UTC−06:00
+  #[serde(rename = "America/Winnipeg")]
+  #[strum(serialize = "America/Winnipeg")]
+  AmericaWinnipeg,
+
   /// UTC−05:00
   #[serde(rename = "America/New_York")]
   #[strum(serialize = "America/New_York")]
   AmericaNewYork,
 
+  /// UTC−05:00
+  #[serde(rename = "America/Toronto")]
+  #[strum(serialize = "America/Toronto")]
+  AmericaToronto,
+
+  /// UTC−05:00
+  #[serde(rename = "America/Bogota")]
+  #[strum(serialize = "America/Bogota")]
+  AmericaBogota,
+
+  /// UTC−05:00
+  #[serde(rename = "America/Lima")]
+  #[strum(serialize = "America/Lima")]
+  AmericaLima,
+
   /// UTC−04:00
   #[serde(rename = "America/Halifax")]
   #[strum(serialize = "America/Halifax")]
   AmericaHalifax,
 
+  /// UTC−04:00
+  #[serde(rename = "America/Caracas")]
+  #[strum(serialize = "America/Caracas")]
+  AmericaCaracas,
+
+  /// UTC−04:00
+  #[serde(rename = "America/Santiago")]
+  #[strum(serialize = "America/Santiago")]
+  AmericaSantiago,
+
   /// UTC−03:30
   #[serde(rename = "America/St_Johns")]
   #[strum(serialize = "America/St_Johns")]
@@ -857,6 +975,16 @@ pub enum IanaTimezone {
   #[strum(serialize = "America/Sao_Paulo")]
   AmericaSaoPaulo,
 
+  /// UTC−03:00
+  #[serde(rename = "America/Argentina/Buenos_Aires")]
+  #[strum(serialize = "America/Argentina/Buenos_Aires")]
+  AmericaArgentinaBuenosAires,
+
+  /// UTC−03:00
+  #[serde(rename = "America/Montevideo")]
+  #[strum(serialize = "America/Montevideo")]
+  AmericaMontevideo,
+
   /// UTC−02:00
   #[serde(rename = "America/Noronha")]
   #[strum(serialize = "America/Noronha")]
@@ -867,27 +995,167 @@ pub enum IanaTimezone {
   #[strum(serialize = "Atlantic/Azores")]
   AtlanticAzores,
 
+  /// UTC−01:00
+  #[serde(rename = "Atlantic/Cape_Verde")]
+  #[strum(serialize = "Atlantic/Cape_Verde")]
+  AtlanticCapeVerde,
+
   /// UTC±00:00
   #[default]
   #[serde(rename = "Etc/UTC")]
   #[strum(serialize = "Etc/UTC")]
   EtcUtc,
 
+  /// UTC±00:00
+  #[serde(rename = "Europe/London")]
+  #[strum(serialize = "Europe/London")]
+  EuropeLondon,
+
+  /// UTC±00:00
+  #[serde(rename = "Europe/Dublin")]
+  #[strum(serialize = "Europe/Dublin")]
+  EuropeDublin,
+
+  /// UTC±00:00
+  #[serde(rename = "Europe/Lisbon")]
+  #[strum(serialize = "Europe/Lisbon")]
+  EuropeLisbon,
+
+  /// UTC±00:00
+  #[serde(rename = "Atlantic/Reykjavik")]
+  #[strum(serialize = "Atlantic/Reykjavik")]
+  AtlanticReykjavik,
+
   /// UTC+01:00
   #[serde(rename = "Europe/Berlin")]
   #[strum(serialize = "Europe/Berlin")]
   EuropeBerlin,
 
+  /// UTC+01:00
+  #[serde(rename = "Europe/Paris")]
+  #[strum(serialize = "Europe/Paris")]
+  EuropeParis,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Madrid")]
+  #[strum(serialize = "Europe/Madrid")]
+  EuropeMadrid,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Rome")]
+  #[strum(serialize = "Europe/Rome")]
+  EuropeRome,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Amsterdam")]
+  #[strum(serialize = "Europe/Amsterdam")]
+  EuropeAmsterdam,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Brussels")]
+  #[strum(serialize = "Europe/Brussels")]
+  EuropeBrussels,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Vienna")]
+  #[strum(serialize = "Europe/Vienna")]
+  EuropeVienna,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Zurich")]
+  #[strum(serialize = "Europe/Zurich")]
+  EuropeZurich,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Stockholm")]
+  #[strum(serialize = "Europe/Stockholm")]
+  EuropeStockholm,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Oslo")]
+  #[strum(serialize = "Europe/Oslo")]
+  EuropeOslo,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Copenhagen")]
+  #[strum(serialize = "Europe/Copenhagen")]
+  EuropeCopenhagen,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Warsaw")]
+  #[strum(serialize = "Europe/Warsaw")]
+  EuropeWarsaw,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Prague")]
+  #[strum(serialize = "Europe/Prague")]
+  EuropePrague,
+
+  /// UTC+01:00
+  #[serde(rename = "Europe/Budapest")]
+  #[strum(serialize = "Europe/Budapest")]
+  EuropeBudapest,
+
+  /// UTC+01:00
+  #[serde(rename = "Africa/Lagos")]
+  #[strum(serialize = "Africa/Lagos")]
+  AfricaLagos,
+
   /// UTC+02:00
   #[serde(rename = "Europe/Bucharest")]
   #[strum(serialize = "Europe/Bucharest")]
   EuropeBucharest,
 
+  /// UTC+02:00
+  #[serde(rename = "Europe/Athens")]
+  #[strum(serialize = "Europe/Athens")]
+  EuropeAthens,
+
+  /// UTC+02:00
+  #[serde(rename = "Europe/Helsinki")]
+  #[strum(serialize = "Europe/Helsinki")]
+  EuropeHelsinki,
+
+  /// UTC+02:00
+  #[serde(rename = "Europe/Kyiv")]
+  #[strum(serialize = "Europe/Kyiv")]
+  EuropeKyiv,
+
+  /// UTC+02:00
+  #[serde(rename = "Asia/Jerusalem")]
+  #[strum(serialize = "Asia/Jerusalem")]
+  AsiaJerusalem,
+
+  /// UTC+02:00
+  #[serde(rename = "Africa/Cairo")]
+  #[strum(serialize = "Africa/Cairo")]
+  AfricaCairo,
+
+  /// UTC+02:00
+  #[serde(rename = "Africa/Johannesburg")]
+  #[strum(serialize = "Africa/Johannesburg")]
+  AfricaJohannesburg,
+
   /// UTC+03:00
   #[serde(rename = "Europe/Moscow")]
   #[strum(serialize = "Europe/Moscow")]
   EuropeMoscow,
 
+  /// UTC+03:00
+  #[serde(rename = "Europe/Istanbul")]
+  #[strum(serialize = "Europe/Istanbul")]
+  EuropeIstanbul,
+
+  /// UTC+03:00
+  #[serde(rename = "Asia/Riyadh")]
+  #[strum(serialize = "Asia/Riyadh")]
+  AsiaRiyadh,
+
+  /// UTC+03:00
+  #[serde(rename = "Africa/Nairobi")]
+  #[strum(serialize = "Africa/Nairobi")]
+  AfricaNairobi,
+
   /// UTC+03:30
   #[serde(rename = "Asia/Tehran")]
   #[strum(serialize = "Asia/Tehran")]
@@ -908,6 +1176,11 @@ pub enum IanaTimezone {
   #[strum(serialize = "Asia/Karachi")]
   AsiaKarachi,
 
+  /// UTC+05:00
+  #[serde(rename = "Asia/Tashkent")]
+  #[strum(serialize = "Asia/Tashkent")]
+  AsiaTashkent,
+
   /// UTC+05:30
   #[serde(rename = "Asia/Kolkata")]
   #[strum(serialize = "Asia/Kolkata")]
@@ -933,11 +1206,46 @@ pub enum IanaTimezone {
   #[strum(serialize = "Asia/Bangkok")]
   AsiaBangkok,
 
+  /// UTC+07:00
+  #[serde(rename = "Asia/Jakarta")]
+  #[strum(serialize = "Asia/Jakarta")]
+  AsiaJakarta,
+
+  /// UTC+07:00
+  #[serde(rename = "Asia/Ho_Chi_Minh")]
+  #[strum(serialize = "Asia/Ho_Chi_Minh")]
+  AsiaHoChiMinh,
+
   /// UTC+08:00
   #[serde(rename = "Asia/Shanghai")]
   #[strum(serialize = "Asia/Shanghai")]
   AsiaShanghai,
 
+  /// UTC+08:00
+  #[serde(rename = "Asia/Singapore")]
+  #[strum(serialize = "Asia/Singapore")]
+  AsiaSingapore,
+
+  /// UTC+08:00
+  #[serde(rename = "Asia/Hong_Kong")]
+  #[strum(serialize = "Asia/Hong_Kong")]
+  AsiaHongKong,
+
+  /// UTC+08:00
+  #[serde(rename = "Asia/Taipei")]
+  #[strum(serialize = "Asia/Taipei")]
+  AsiaTaipei,
+
+  /// UTC+08:00
+  #[serde(rename = "Asia/Manila")]
+  #[strum(serialize = "Asia/Manila")]
+  AsiaManila,
+
+  /// UTC+08:00
+  #[serde(rename = "Australia/Perth")]
+  #[strum(serialize = "Australia/Perth")]
+  AustraliaPerth,
+
   /// UTC+08:45
   #[serde(rename = "Australia/Eucla")]
   #[strum(serialize = "Australia/Eucla")]
@@ -948,16 +1256,36 @@ pub enum IanaTimezone {
   #[strum(serialize = "Asia/Tokyo")]
   AsiaTokyo,
 
+  /// UTC+09:00
+  #[serde(rename = "Asia/Seoul")]
+  #[strum(serialize = "Asia/Seoul")]
+  AsiaSeoul,
+
   /// UTC+09:30
   #[serde(rename = "Australia/Adelaide")]
   #[strum(serialize = "Australia/Adelaide")]
   AustraliaAdelaide,
 
+  /// UTC+09:30
+  #[serde(rename = "Australia/Darwin")]
+  #[strum(serialize = "Australia/Darwin")]
+  AustraliaDarwin,
+
   /// UTC+10:00
   #[serde(rename = "Australia/Sydney")]
   #[strum(serialize = "Australia/Sydney")]
   AustraliaSydney,
 
+  /// UTC+10:00
+  #[serde(rename = "Australia/Melbourne")]
+  #[strum(serialize = "Australia/Melbourne")]
+  AustraliaMelbourne,
+
+  /// UTC+10:00
+  #[serde(rename = "Australia/Brisbane")]
+  #[strum(serialize = "Australia/Brisbane")]
+  AustraliaBrisbane,
+
   /// UTC+10:30
   #[serde(rename = "Australia/Lord_Howe")]
   #[strum(serialize = "Australia/Lord_Howe")]
@@ -973,6 +1301,11 @@ pub enum IanaTimezone {
   #[strum(serialize = "Pacific/Auckland")]
   PacificAuckland,
 
+  /// UTC+12:00
+  #[serde(rename = "Pacific/Fiji")]
+  #[strum(serialize = "Pacific/Fiji")]
+  PacificFiji,
+
   /// UTC+12:45
   #[serde(rename = "Pacific/Chatham")]
   #[strum(serialize = "Pacific/Chatham")]
@@ -989,6 +1322,109 @@ pub enum IanaTimezone {
   PacificKiritimati,
 }
 
+impl IanaTimezone {
+  /// The matching `chrono_tz` timezone.
+  pub fn tz(&self) -> chrono_tz::Tz {
+    use chrono_tz::Tz;
+    match self {
+      IanaTimezone::EtcGmtMinus12 => Tz::Etc__GMTPlus12,
+      IanaTimezone::PacificPagoPago => Tz::Pacific__Pago_Pago,
+      IanaTimezone::PacificHonolulu => Tz::Pacific__Honolulu,
+      IanaTimezone::PacificMarquesas => Tz::Pacific__Marquesas,
+      IanaTimezone::AmericaAnchorage => Tz::America__Anchorage,
+      IanaTimezone::AmericaLosAngeles => Tz::America__Los_Angeles,
+      IanaTimezone::AmericaVancouver => Tz::America__Vancouver,
+      IanaTimezone::AmericaTijuana => Tz::America__Tijuana,
+      IanaTimezone::AmericaDenver => Tz::America__Denver,
+      IanaTimezone::AmericaPhoenix => Tz::America__Phoenix,
+      IanaTimezone::AmericaEdmonton => Tz::America__Edmonton,
+      IanaTimezone::AmericaChicago => Tz::America__Chicago,
+      IanaTimezone::AmericaMexicoCity => Tz::America__Mexico_City,
+      IanaTimezone::AmericaWinnipeg => Tz::America__Winnipeg,
+      IanaTimezone::AmericaNewYork => Tz::America__New_York,
+      IanaTimezone::AmericaToronto => Tz::America__Toronto,
+      IanaTimezone::AmericaBogota => Tz::America__Bogota,
+      IanaTimezone::AmericaLima => Tz::America__Lima,
+      IanaTimezone::AmericaHalifax => Tz::America__Halifax,
+      IanaTimezone::AmericaCaracas => Tz::America__Caracas,
+      IanaTimezone::AmericaSantiago => Tz::America__Santiago,
+      IanaTimezone::AmericaStJohns => Tz::America__St_Johns,
+      IanaTimezone::AmericaSaoPaulo => Tz::America__Sao_Paulo,
+      IanaTimezone::AmericaArgentinaBuenosAires => {
+        Tz::America__Argentina__Buenos_Aires
+      }
+      IanaTimezone::AmericaMontevideo => Tz::America__Montevideo,
+      IanaTimezone::AmericaNoronha => Tz::America__Noronha,
+      IanaTimezone::AtlanticAzores => Tz::Atlantic__Azores,
+      IanaTimezone::AtlanticCapeVerde => Tz::Atlantic__Cape_Verde,
+      IanaTimezone::EtcUtc => Tz::Etc__UTC,
+      IanaTimezone::EuropeLondon => Tz::Europe__London,
+      IanaTimezone::EuropeDublin => Tz::Europe__Dublin,
+      IanaTimezone::EuropeLisbon => Tz::Europe__Lisbon,
+      IanaTimezone::AtlanticReykjavik => Tz::Atlantic__Reykjavik,
+      IanaTimezone::EuropeBerlin => Tz::Europe__Berlin,
+      IanaTimezone::EuropeParis => Tz::Europe__Paris,
+      IanaTimezone::EuropeMadrid => Tz::Europe__Madrid,
+      IanaTimezone::EuropeRome => Tz::Europe__Rome,
+      IanaTimezone::EuropeAmsterdam => Tz::Europe__Amsterdam,
+      IanaTimezone::EuropeBrussels => Tz::Europe__Brussels,
+      IanaTimezone::EuropeVienna => Tz::Europe__Vienna,
+      IanaTimezone::EuropeZurich => Tz::Europe__Zurich,
+      IanaTimezone::EuropeStockholm => Tz::Europe__Stockholm,
+      IanaTimezone::EuropeOslo => Tz::Europe__Oslo,
+      IanaTimezone::EuropeCopenhagen => Tz::Europe__Copenhagen,
+      IanaTimezone::EuropeWarsaw => Tz::Europe__Warsaw,
+      IanaTimezone::EuropePrague => Tz::Europe__Prague,
+      IanaTimezone::EuropeBudapest => Tz::Europe__Budapest,
+      IanaTimezone::AfricaLagos => Tz::Africa__Lagos,
+      IanaTimezone::EuropeBucharest => Tz::Europe__Bucharest,
+      IanaTimezone::EuropeAthens => Tz::Europe__Athens,
+      IanaTimezone::EuropeHelsinki => Tz::Europe__Helsinki,
+      IanaTimezone::EuropeKyiv => Tz::Europe__Kyiv,
+      IanaTimezone::AsiaJerusalem => Tz::Asia__Jerusalem,
+      IanaTimezone::AfricaCairo => Tz::Africa__Cairo,
+      IanaTimezone::AfricaJohannesburg => Tz::Africa__Johannesburg,
+      IanaTimezone::EuropeMoscow => Tz::Europe__Moscow,
+      IanaTimezone::EuropeIstanbul => Tz::Europe__Istanbul,
+      IanaTimezone::AsiaRiyadh => Tz::Asia__Riyadh,
+      IanaTimezone::AfricaNairobi => Tz::Africa__Nairobi,
+      IanaTimezone::AsiaTehran => Tz::Asia__Tehran,
+      IanaTimezone::AsiaDubai => Tz::Asia__Dubai,
+      IanaTimezone::AsiaKabul => Tz::Asia__Kabul,
+      IanaTimezone::AsiaKarachi => Tz::Asia__Karachi,
+      IanaTimezone::AsiaTashkent => Tz::Asia__Tashkent,
+      IanaTimezone::AsiaKolkata => Tz::Asia__Kolkata,
+      IanaTimezone::AsiaKathmandu => Tz::Asia__Kathmandu,
+      IanaTimezone::AsiaDhaka => Tz::Asia__Dhaka,
+      IanaTimezone::AsiaYangon => Tz::Asia__Yangon,
+      IanaTimezone::AsiaBangkok => Tz::Asia__Bangkok,
+      IanaTimezone::AsiaJakarta => Tz::Asia__Jakarta,
+      IanaTimezone::AsiaHoChiMinh => Tz::Asia__Ho_Chi_Minh,
+      IanaTimezone::AsiaShanghai => Tz::Asia__Shanghai,
+      IanaTimezone::AsiaSingapore => Tz::Asia__Singapore,
+      IanaTimezone::AsiaHongKong => Tz::Asia__Hong_Kong,
+      IanaTimezone::AsiaTaipei => Tz::Asia__Taipei,
+      IanaTimezone::AsiaManila => Tz::Asia__Manila,
+      IanaTimezone::AustraliaPerth => Tz::Australia__Perth,
+      IanaTimezone::AustraliaEucla => Tz::Australia__Eucla,
+      IanaTimezone::AsiaTokyo => Tz::Asia__Tokyo,
+      IanaTimezone::AsiaSeoul => Tz::Asia__Seoul,
+      IanaTimezone::AustraliaAdelaide => Tz::Australia__Adelaide,
+      IanaTimezone::AustraliaDarwin => Tz::Australia__Darwin,
+      IanaTimezone::AustraliaSydney => Tz::Australia__Sydney,
+      IanaTimezone::AustraliaMelbourne => Tz::Australia__Melbourne,
+      IanaTimezone::AustraliaBrisbane => Tz::Australia__Brisbane,
+      IanaTimezone::AustraliaLordHowe => Tz::Australia__Lord_Howe,
+      IanaTimezone::PacificPortMoresby => Tz::Pacific__Port_Moresby,
+      IanaTimezone::PacificAuckland => Tz::Pacific__Auckland,
+      IanaTimezone::PacificFiji => Tz::Pacific__Fiji,
+      IanaTimezone::PacificChatham => Tz::Pacific__Chatham,
+      IanaTimezone::PacificTongatapu => Tz::Pacific__Tongatapu,
+      IanaTimezone::PacificKiritimati => Tz::Pacific__Kiritimati,
+    }
+  }
+}
+
 #[typeshare]
 #[derive(
   Debug,
@@ -1027,6 +1463,9 @@ pub enum Operation {
   StopAllContainers,
   PruneContainers,
   CreateNetwork,
+  CopyFileToContainer,
+  CopyFileFromContainer,
+  RunContainerCommand,
   DeleteNetwork,
   PruneNetworks,
   DeleteImage,
@@ -1037,6 +1476,15 @@ pub enum Operation {
   PruneBuildx,
   PruneSystem,
 
+  // server group
+  CreateServerGroup,
+  UpdateServerGroup,
+  RenameServerGroup,
+  DeleteServerGroup,
+  RunCommandOnGroup,
+  PruneImagesOnGroup,
+  PruneSystemOnGroup,
+
   // stack
   CreateStack,
   UpdateStack,
@@ -1137,6 +1585,7 @@ pub enum Operation {
   // maintenance
   ClearRepoCache,
   BackupCoreDatabase,
+  RestoreDatabase,
   GlobalAutoUpdate,
 
   // variable
@@ -1243,6 +1692,7 @@ pub enum ResourceTarget {
   Builder(String),
   Alerter(String),
   ResourceSync(String),
+  ServerGroup(String),
 }
 
 impl ResourceTarget {
@@ -1271,6 +1721,7 @@ impl ResourceTarget {
       ResourceTarget::Builder(id) => id.is_empty(),
       ResourceTarget::Alerter(id) => id.is_empty(),
       ResourceTarget::ResourceSync(id) => id.is_empty(),
+      ResourceTarget::ServerGroup(id) => id.is_empty(),
     }
   }
 
@@ -1289,6 +1740,7 @@ impl ResourceTarget {
       ResourceTarget::Procedure(id) => id,
       ResourceTarget::Action(id) => id,
       ResourceTarget::ResourceSync(id) => id,
+      ResourceTarget::ServerGroup(id) => id,
     };
     (self.extract_variant(), id)
   }
@@ -1312,6 +1764,12 @@ impl From<&server::Server> for ResourceTarget {
   }
 }
 
+impl From<&server_group::ServerGroup> for ResourceTarget {
+  fn from(server_group: &server_group::ServerGroup) -> Self {
+    Self::ServerGroup(server_group.id.clone())
+  }
+}
+
 impl From<&repo::Repo> for ResourceTarget {
   fn from(repo: &repo::Repo) -> Self {
     Self::Repo(repo.id.clone())
@@ -1369,6 +1827,7 @@ impl ResourceTargetVariant {
       ResourceTargetVariant::ResourceSync => "resource_sync",
       ResourceTargetVariant::Stack => "stack",
       ResourceTargetVariant::Action => "action",
+      ResourceTargetVariant::ServerGroup => "server_group",
     }
   }
 }
@@ -1399,6 +1858,12 @@ pub enum FileFormat {
 /// Used with ExecuteTerminal to capture the exit code
 pub const KOMODO_EXIT_CODE: &str = "__KOMODO_EXIT_CODE:";
 
+/// Actions can print a line starting with this marker,
+/// followed by JSON, to set the [Update][update::Update] output.
+/// Eg. `__KOMODO_OUTPUT:{"tag":"1.2.3"}`.
+/// If printed multiple times, the last line is used.
+pub const KOMODO_OUTPUT: &str = "__KOMODO_OUTPUT:";
+
 pub fn resource_link(
   host: &str,
   resource_type: ResourceTargetVariant,
@@ -1432,6 +1897,161 @@ pub fn resource_link(
     ResourceTargetVariant::ResourceSync => {
       format!("/resource-syncs/{id}")
     }
+    ResourceTargetVariant::ServerGroup => {
+      format!("/server-groups/{id}")
+    }
   };
   format!("{host}{path}")
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn timelength_as_millis_matches_every_variant() {
+    for timelength in Timelength::VARIANTS {
+      let expected = async_timing_util::get_timelength_in_ms(
+        (*timelength).try_into().unwrap(),
+      );
+      assert_eq!(
+        timelength.as_millis() as u128,
+        expected,
+        "{timelength}"
+      );
+    }
+  }
+
+  #[test]
+  fn timelength_as_millis_values() {
+    assert_eq!(Timelength::OneSecond.as_millis(), 1_000);
+    assert_eq!(Timelength::FiveMinutes.as_millis(), 300_000);
+    assert_eq!(Timelength::OneDay.as_millis(), 86_400_000);
+    assert_eq!(Timelength::ThirtyDays.as_millis(), 2_592_000_000);
+  }
+
+  /// The standard (non DST) UTC offset in minutes
+  /// given in the doc comment of each variant.
+  fn iana_timezone_doc_offsets() -> Vec<(IanaTimezone, i32)> {
+    vec![
+      (IanaTimezone::EtcGmtMinus12, -720),
+      (IanaTimezone::PacificPagoPago, -660),
+      (IanaTimezone::PacificHonolulu, -600),
+      (IanaTimezone::PacificMarquesas, -570),
+      (IanaTimezone::AmericaAnchorage, -540),
+      (IanaTimezone::AmericaLosAngeles, -480),
+      (IanaTimezone::AmericaVancouver, -480),
+      (IanaTimezone::AmericaTijuana, -480),
+      (IanaTimezone::AmericaDenver, -420),
+      (IanaTimezone::AmericaPhoenix, -420),
+      (IanaTimezone::AmericaEdmonton, -420),
+      (IanaTimezone::AmericaChicago, -360),
+      (IanaTimezone::AmericaMexicoCity, -360),
+      (IanaTimezone::AmericaWinnipeg, -360),
+      (IanaTimezone::AmericaNewYork, -300),
+      (IanaTimezone::AmericaToronto, -300),
+      (IanaTimezone::AmericaBogota, -300),
+      (IanaTimezone::AmericaLima, -300),
+      (IanaTimezone::AmericaHalifax, -240),
+      (IanaTimezone::AmericaCaracas, -240),
+      (IanaTimezone::AmericaSantiago, -240),
+      (IanaTimezone::AmericaStJohns, -210),
+      (IanaTimezone::AmericaSaoPaulo, -180),
+      (IanaTimezone::AmericaArgentinaBuenosAires, -180),
+      (IanaTimezone::AmericaMontevideo, -180),
+      (IanaTimezone::AmericaNoronha, -120),
+      (IanaTimezone::AtlanticAzores, -60),
+      (IanaTimezone::AtlanticCapeVerde, -60),
+      (IanaTimezone::EtcUtc, 0),
+      (IanaTimezone::EuropeLondon, 0),
+      (IanaTimezone::EuropeDublin, 0),
+      (IanaTimezone::EuropeLisbon, 0),
+      (IanaTimezone::AtlanticReykjavik, 0),
+      (IanaTimezone::EuropeBerlin, 60),
+      (IanaTimezone::EuropeParis, 60),
+      (IanaTimezone::EuropeMadrid, 60),
+      (IanaTimezone::EuropeRome, 60),
+      (IanaTimezone::EuropeAmsterdam, 60),
+      (IanaTimezone::EuropeBrussels, 60),
+      (IanaTimezone::EuropeVienna, 60),
+      (IanaTimezone::EuropeZurich, 60),
+      (IanaTimezone::EuropeStockholm, 60),
+      (IanaTimezone::EuropeOslo, 60),
+      (IanaTimezone::EuropeCopenhagen, 60),
+      (IanaTimezone::EuropeWarsaw, 60),
+      (IanaTimezone::EuropePrague, 60),
+      (IanaTimezone::EuropeBudapest, 60),
+      (IanaTimezone::AfricaLagos, 60),
+      (IanaTimezone::EuropeBucharest, 120),
+      (IanaTimezone::EuropeAthens, 120),
+      (IanaTimezone::EuropeHelsinki, 120),
+      (IanaTimezone::EuropeKyiv, 120),
+      (IanaTimezone::AsiaJerusalem, 120),
+      (IanaTimezone::AfricaCairo, 120),
+      (IanaTimezone::AfricaJohannesburg, 120),
+      (IanaTimezone::EuropeMoscow, 180),
+      (IanaTimezone::EuropeIstanbul, 180),
+      (IanaTimezone::AsiaRiyadh, 180),
+      (IanaTimezone::AfricaNairobi, 180),
+      (IanaTimezone::AsiaTehran, 210),
+      (IanaTimezone::AsiaDubai, 240),
+      (IanaTimezone::AsiaKabul, 270),
+      (IanaTimezone::AsiaKarachi, 300),
+      (IanaTimezone::AsiaTashkent, 300),
+      (IanaTimezone::AsiaKolkata, 330),
+      (IanaTimezone::AsiaKathmandu, 345),
+      (IanaTimezone::AsiaDhaka, 360),
+      (IanaTimezone::AsiaYangon, 390),
+      (IanaTimezone::AsiaBangkok, 420),
+      (IanaTimezone::AsiaJakarta, 420),
+      (IanaTimezone::AsiaHoChiMinh, 420),
+      (IanaTimezone::AsiaShanghai, 480),
+      (IanaTimezone::AsiaSingapore, 480),
+      (IanaTimezone::AsiaHongKong, 480),
+      (IanaTimezone::AsiaTaipei, 480),
+      (IanaTimezone::AsiaManila, 480),
+      (IanaTimezone::AustraliaPerth, 480),
+      (IanaTimezone::AustraliaEucla, 525),
+      (IanaTimezone::AsiaTokyo, 540),
+      (IanaTimezone::AsiaSeoul, 540),
+      (IanaTimezone::AustraliaAdelaide, 570),
+      (IanaTimezone::AustraliaDarwin, 570),
+      (IanaTimezone::AustraliaSydney, 600),
+      (IanaTimezone::AustraliaMelbourne, 600),
+      (IanaTimezone::AustraliaBrisbane, 600),
+      (IanaTimezone::AustraliaLordHowe, 630),
+      (IanaTimezone::PacificPortMoresby, 660),
+      (IanaTimezone::PacificAuckland, 720),
+      (IanaTimezone::PacificFiji, 720),
+      (IanaTimezone::PacificChatham, 765),
+      (IanaTimezone::PacificTongatapu, 780),
+      (IanaTimezone::PacificKiritimati, 840),
+    ]
+  }
+
+  #[test]
+  fn iana_timezone_tz_matches_every_variant() {
+    use chrono::{Offset, TimeZone};
+    let offsets = iana_timezone_doc_offsets();
+    assert_eq!(offsets.len(), IanaTimezone::VARIANTS.len());
+    for (timezone, doc_offset) in offsets {
+      let tz = timezone.tz();
+      assert_eq!(tz.name().parse::<IanaTimezone>(), Ok(timezone));
+      // DST only moves clocks forward, so the standard
+      // offset is the lower of the winter and summer offsets.
+      let offset = [1, 7]
+        .into_iter()
+        .map(|month| {
+          tz.with_ymd_and_hms(2025, month, 15, 12, 0, 0)
+            .unwrap()
+            .offset()
+            .fix()
+            .local_minus_utc()
+            / 60
+        })
+        .min()
+        .unwrap();
+      assert_eq!(offset, doc_offset, "{}", tz.name());
+    }
+  }
+}
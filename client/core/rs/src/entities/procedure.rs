@@ -9,7 +9,7 @@ use typeshare::typeshare;
 use crate::api::execute::Execution;
 
 use super::{
-  I64, ScheduleFormat,
+  I64, ScheduleFormat, U64,
   resource::{Resource, ResourceListItem, ResourceQuery},
 };
 
@@ -189,11 +189,46 @@ pub struct ProcedureStage {
   /// Whether the stage should be run as part of the procedure.
   #[serde(default = "default_enabled")]
   pub enabled: bool,
+  /// Run the stage based on the result of the previous stages.
+  /// Default: `OnSuccess`
+  #[serde(default)]
+  pub run_condition: StageRunCondition,
+  /// Fail the stage if its executions take longer than this
+  /// many seconds. If not provided, the stage can run indefinitely.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub timeout_seconds: Option<U64>,
   /// The executions in the stage
   #[serde(default, alias = "execution")]
   pub executions: Vec<EnabledExecution>,
 }
 
+/// When to run a [ProcedureStage],
+/// based on the result of the previous stages.
+#[typeshare]
+#[derive(
+  Debug,
+  Clone,
+  Copy,
+  PartialEq,
+  Eq,
+  Default,
+  Serialize,
+  Deserialize,
+  Display,
+)]
+pub enum StageRunCondition {
+  /// Run the stage whether or not a previous stage failed.
+  Always,
+  /// Only run the stage if no previous stage failed.
+  /// This matches procedures without conditions,
+  /// which stop at the first failed stage.
+  #[default]
+  OnSuccess,
+  /// Only run the stage if a previous stage failed.
+  /// Can be used for cleanup / notification on failure.
+  OnFailure,
+}
+
 /// Allows to enable / disabled procedures in the sequence / parallel vec on the fly
 #[typeshare]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -155,6 +155,13 @@ pub struct RepoConfig {
   #[builder(default)]
   pub commit: String,
 
+  /// Make a shallow clone with history truncated to this many commits,
+  /// ie `git clone --depth {clone_depth}`. 1 is standard for CI builds.
+  /// 0 means a full clone.
+  #[serde(default)]
+  #[builder(default)]
+  pub clone_depth: u32,
+
   /// Explicitly specify the folder to clone the repo in.
   /// - If absolute (has leading '/')
   ///   - Used directly as the path
@@ -164,6 +171,18 @@ pub struct RepoConfig {
   #[builder(default)]
   pub path: String,
 
+  /// Only check out these directories in the repo, using `git sparse-checkout`.
+  /// Useful to avoid checking out an entire monorepo
+  /// when only a subdirectory is needed.
+  /// Empty means the whole repo is checked out.
+  #[serde(default, deserialize_with = "string_list_deserializer")]
+  #[partial_attr(serde(
+    default,
+    deserialize_with = "option_string_list_deserializer"
+  ))]
+  #[builder(default)]
+  pub sparse_checkout: Vec<String>,
+
   /// Whether incoming webhooks actually trigger action.
   #[serde(default = "default_webhook_enabled")]
   #[builder(default = "default_webhook_enabled()")]
@@ -222,6 +241,13 @@ pub struct RepoConfig {
   #[serde(default)]
   #[builder(default)]
   pub skip_secret_interp: bool,
+
+  /// Whether to skip only variable interpolation, still interpolating secrets.
+  /// Useful if the contents use `[[...]]` for something other than Komodo variables.
+  /// If `skip_secret_interp` is also enabled, all interpolation is skipped.
+  #[serde(default)]
+  #[builder(default)]
+  pub skip_variable_interp: bool,
 }
 
 impl RepoConfig {
@@ -267,12 +293,15 @@ impl Default for RepoConfig {
       commit: Default::default(),
       git_account: Default::default(),
       path: Default::default(),
+      clone_depth: Default::default(),
+      sparse_checkout: Default::default(),
       on_clone: Default::default(),
       on_pull: Default::default(),
       links: Default::default(),
       environment: Default::default(),
       env_file_path: default_env_file_path(),
       skip_secret_interp: Default::default(),
+      skip_variable_interp: Default::default(),
       webhook_enabled: default_webhook_enabled(),
       webhook_secret: Default::default(),
     }
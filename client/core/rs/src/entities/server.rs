@@ -106,6 +106,14 @@ pub struct ServerConfig {
   #[builder(default)]
   pub passkey: String,
 
+  /// Path on the Core host to a PEM file containing
+  /// the client certificate and private key.
+  /// Required when Periphery is configured with `tls_ca_cert` (mTLS).
+  /// If this is empty, no client certificate is sent.
+  #[serde(default)]
+  #[builder(default)]
+  pub client_cert_path: String,
+
   /// Sometimes the system stats reports a mount path that is not desired.
   /// Use this field to filter it out from the report.
   #[serde(default, deserialize_with = "string_list_deserializer")]
@@ -116,6 +124,13 @@ pub struct ServerConfig {
   #[builder(default)]
   pub ignore_mounts: Vec<String>,
 
+  /// The network interface used for the network rates
+  /// recorded in the stats history, eg "eth0".
+  /// If empty (or not found), the rates over all interfaces are used.
+  #[serde(default)]
+  #[builder(default)]
+  pub network_interface: String,
+
   /// Whether to monitor any server stats beyond passing health check.
   /// default: true
   #[serde(default = "default_stats_monitoring")]
@@ -205,6 +220,19 @@ pub struct ServerConfig {
   #[partial_default(default_disk_critical())]
   pub disk_critical: f64,
 
+  /// The percentage of used inodes which triggers WARNING state for a DISK.
+  /// Inode alerts are sent along with `send_disk_alerts`.
+  /// Disabled if 0.
+  #[serde(default)]
+  #[builder(default)]
+  pub inode_warning: f64,
+
+  /// The percentage of used inodes which triggers CRITICAL state for a DISK.
+  /// Disabled if 0.
+  #[serde(default)]
+  #[builder(default)]
+  pub inode_critical: f64,
+
   /// Scheduled maintenance windows during which alerts will be suppressed.
   #[serde(default)]
   #[builder(default)]
@@ -273,6 +301,7 @@ impl Default for ServerConfig {
       enabled: default_enabled(),
       timeout_seconds: default_timeout_seconds(),
       ignore_mounts: Default::default(),
+      network_interface: Default::default(),
       stats_monitoring: default_stats_monitoring(),
       auto_prune: default_auto_prune(),
       links: Default::default(),
@@ -283,12 +312,15 @@ impl Default for ServerConfig {
       send_version_mismatch_alerts: default_send_alerts(),
       region: Default::default(),
       passkey: Default::default(),
+      client_cert_path: Default::default(),
       cpu_warning: default_cpu_warning(),
       cpu_critical: default_cpu_critical(),
       mem_warning: default_mem_warning(),
       mem_critical: default_mem_critical(),
       disk_warning: default_disk_warning(),
       disk_critical: default_disk_critical(),
+      inode_warning: Default::default(),
+      inode_critical: Default::default(),
       maintenance_windows: Default::default(),
     }
   }
@@ -310,6 +342,10 @@ pub struct ServerHealth {
   pub cpu: ServerHealthState,
   pub mem: ServerHealthState,
   pub disks: HashMap<PathBuf, ServerHealthState>,
+  /// Only includes disks reporting inodes,
+  /// when inode thresholds are configured.
+  #[serde(default)]
+  pub inodes: HashMap<PathBuf, ServerHealthState>,
 }
 
 /// Info about an active terminal on a server.
@@ -323,6 +359,9 @@ pub struct TerminalInfo {
   pub command: String,
   /// The size of the terminal history in memory.
   pub stored_size_kb: f64,
+  /// Milliseconds since the last terminal input or output.
+  #[serde(default)]
+  pub idle_ms: I64,
 }
 
 /// Current pending actions on the server.
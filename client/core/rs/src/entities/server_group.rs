@@ -0,0 +1,79 @@
+use bson::{Document, doc};
+use derive_builder::Builder;
+use derive_default_builder::DefaultBuilder;
+use partial_derive2::Partial;
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use crate::deserializers::{
+  option_string_list_deserializer, string_list_deserializer,
+};
+
+use super::resource::{Resource, ResourceListItem, ResourceQuery};
+
+#[typeshare]
+pub type ServerGroup = Resource<ServerGroupConfig, ()>;
+
+#[typeshare]
+pub type ServerGroupListItem =
+  ResourceListItem<ServerGroupListItemInfo>;
+
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ServerGroupListItemInfo {
+  /// The ids of the servers in the group.
+  pub members: Vec<String>,
+}
+
+#[typeshare(serialized_as = "Partial<ServerGroupConfig>")]
+pub type _PartialServerGroupConfig = PartialServerGroupConfig;
+
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Default, Builder, Partial,
+)]
+#[partial_derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[partial(skip_serializing_none, from, diff)]
+pub struct ServerGroupConfig {
+  /// The servers in the group, by id.
+  /// Names are also accepted on create / update,
+  /// and will be converted to ids.
+  ///
+  /// Group executions run on all the members in parallel.
+  #[serde(default, deserialize_with = "string_list_deserializer")]
+  #[partial_attr(serde(
+    default,
+    deserialize_with = "option_string_list_deserializer"
+  ))]
+  #[builder(default)]
+  pub members: Vec<String>,
+}
+
+impl ServerGroupConfig {
+  pub fn builder() -> ServerGroupConfigBuilder {
+    ServerGroupConfigBuilder::default()
+  }
+}
+
+#[typeshare]
+pub type ServerGroupQuery = ResourceQuery<ServerGroupQuerySpecifics>;
+
+#[typeshare]
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Default, DefaultBuilder,
+)]
+pub struct ServerGroupQuerySpecifics {
+  /// Only include groups which have these servers as members.
+  /// If empty, don't filter by member.
+  #[serde(default)]
+  pub members: Vec<String>,
+}
+
+impl super::resource::AddFilters for ServerGroupQuerySpecifics {
+  fn add_filters(&self, filters: &mut Document) {
+    if !self.members.is_empty() {
+      filters
+        .insert("config.members", doc! { "$all": &self.members });
+    }
+  }
+}
@@ -0,0 +1,119 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use super::{
+  I64, MongoId, ResourceTargetVariant,
+  api_key::ApiKey,
+  permission::PermissionLevelAndSpecifics,
+  user::{User, UserConfig},
+};
+
+/// An account for machine-to-machine access, separate from users.
+/// Service accounts have no password and can't log in to the UI,
+/// they can only make api calls using their api keys.
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(
+  feature = "mongo",
+  derive(mongo_indexed::derive::MongoIndexed)
+)]
+#[cfg_attr(feature = "mongo", doc_index({ "api_keys.key": 1 }))]
+pub struct ServiceAccount {
+  /// The Mongo ID of the ServiceAccount.
+  /// This field is de/serialized from/to JSON as
+  /// `{ "_id": { "$oid": "..." }, ...(rest of ServiceAccount schema) }`
+  #[serde(
+    default,
+    rename = "_id",
+    skip_serializing_if = "String::is_empty",
+    with = "bson::serde_helpers::hex_string_as_object_id"
+  )]
+  pub id: MongoId,
+
+  /// The globally unique name of the service account.
+  #[cfg_attr(feature = "mongo", unique_index)]
+  pub name: String,
+
+  /// A description of what the service account is used for.
+  #[serde(default)]
+  pub description: String,
+
+  /// Give the service account permissions on all resources of a certain type.
+  #[serde(default)]
+  pub permissions:
+    IndexMap<ResourceTargetVariant, PermissionLevelAndSpecifics>,
+
+  /// The api keys of the service account. Secrets are stored hashed.
+  #[serde(default)]
+  pub api_keys: Vec<ApiKey>,
+
+  /// Unix time (ms) when the service account was last updated
+  #[serde(default)]
+  pub updated_at: I64,
+}
+
+impl ServiceAccount {
+  /// Prepares the service account for transport
+  /// by removing the api key secret hashes.
+  pub fn sanitize(&mut self) {
+    self.api_keys.iter_mut().for_each(ApiKey::sanitize);
+  }
+
+  /// The name api calls made by the service account are recorded under,
+  /// eg as the operator of Updates: `ServiceAccount(name)`.
+  pub fn actor(&self) -> String {
+    format!("ServiceAccount({})", self.name)
+  }
+
+  /// The user which api calls authenticated with the
+  /// service account's api keys are made as.
+  /// It has the service account id and [ServiceAccount::actor] as username.
+  pub fn user(&self) -> User {
+    User {
+      id: self.id.clone(),
+      username: self.actor(),
+      enabled: true,
+      config: UserConfig::Service {
+        description: self.description.clone(),
+      },
+      all: self.permissions.clone(),
+      updated_at: self.updated_at,
+      ..Default::default()
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::entities::permission::PermissionLevel;
+
+  use super::*;
+
+  #[test]
+  fn user_acts_as_service_account() {
+    let mut service_account = ServiceAccount {
+      id: String::from("66f2f2a5f6c8d3b8e4a1c001"),
+      name: String::from("ci"),
+      description: String::from("deploys from ci"),
+      ..Default::default()
+    };
+    service_account.permissions.insert(
+      ResourceTargetVariant::Stack,
+      PermissionLevel::Execute.into(),
+    );
+    let user = service_account.user();
+    assert_eq!(user.id, service_account.id);
+    assert_eq!(user.username, "ServiceAccount(ci)");
+    assert!(user.enabled);
+    assert!(!user.admin && !user.super_admin);
+    assert!(matches!(
+      user.config,
+      UserConfig::Service { description } if description == "deploys from ci"
+    ));
+    assert_eq!(
+      user.all.get(&ResourceTargetVariant::Stack).map(|p| p.level),
+      Some(PermissionLevel::Execute)
+    );
+  }
+}
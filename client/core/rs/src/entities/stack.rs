@@ -24,8 +24,8 @@ use crate::{
 };
 
 use super::{
-  FileContents, SystemCommand,
-  docker::container::ContainerListItem,
+  FileContents, SystemCommand, U64,
+  docker::container::{ContainerListItem, ContainerStateStatusEnum},
   resource::{Resource, ResourceListItem, ResourceQuery},
 };
 
@@ -234,6 +234,11 @@ pub struct StackInfo {
   /// This is updated whenever the stack cache refreshes, using the latest file contents (either db defined or remote).
   #[serde(default)]
   pub latest_services: Vec<StackServiceNames>,
+  /// The services of the deployed project currently on the host,
+  /// along with their image digests.
+  /// This is updated whenever the server status cache refreshes, using `docker compose ps`.
+  #[serde(default)]
+  pub services: Vec<StackServiceInfo>,
 
   /// The remote compose / additional file contents, whether on host or in repo.
   /// This is updated whenever Komodo refreshes the stack cache.
@@ -295,6 +300,13 @@ pub struct StackConfig {
   #[builder(default)]
   pub run_build: bool,
 
+  /// Limit the number of images pulled / built in parallel
+  /// during deploy, passed as `COMPOSE_PARALLEL_LIMIT`.
+  /// If 0, uses the docker compose default.
+  #[serde(default)]
+  #[builder(default)]
+  pub compose_parallel_limit: u32,
+
   /// Whether to poll for any updates to the images.
   #[serde(default)]
   #[builder(default)]
@@ -326,6 +338,13 @@ pub struct StackConfig {
   #[builder(default)]
   pub skip_secret_interp: bool,
 
+  /// Whether to skip only variable interpolation, still interpolating secrets.
+  /// Useful if the contents use `[[...]]` for something other than Komodo variables.
+  /// If `skip_secret_interp` is also enabled, all interpolation is skipped.
+  #[serde(default)]
+  #[builder(default)]
+  pub skip_variable_interp: bool,
+
   /// Choose a Komodo Repo (Resource) to source the compose files.
   #[serde(default)]
   #[builder(default)]
@@ -444,6 +463,17 @@ pub struct StackConfig {
   #[builder(default)]
   pub additional_env_files: Vec<String>,
 
+  /// Compose profiles to enable with `--profile`.
+  /// Services in other profiles are not started.
+  /// Empty only starts services without a profile.
+  #[serde(default, deserialize_with = "string_list_deserializer")]
+  #[partial_attr(serde(
+    default,
+    deserialize_with = "option_string_list_deserializer"
+  ))]
+  #[builder(default)]
+  pub compose_profiles: Vec<String>,
+
   /// Add additional config files either in repo or on host to track.
   /// Can add any files associated with the stack to enable editing them in the UI.
   /// Doing so will also include diffing these when deciding to deploy in `DeployStackIfChanged`.
@@ -482,6 +512,27 @@ pub struct StackConfig {
   #[builder(default)]
   pub post_deploy: SystemCommand,
 
+  /// The optional command to run once the Stack has reached the
+  /// Running state after deploy, with all service health checks passing.
+  #[serde(default)]
+  #[builder(default)]
+  pub on_deploy: SystemCommand,
+
+  /// The max seconds to wait for the Stack to become healthy
+  /// before running the `on_deploy` command. The deploy request
+  /// waits on this, so keep it within the request timeout.
+  /// If 0, runs `on_deploy` without waiting.
+  #[serde(default = "default_on_deploy_timeout")]
+  #[builder(default = "default_on_deploy_timeout()")]
+  #[partial_default(default_on_deploy_timeout())]
+  pub on_deploy_timeout: U64,
+
+  /// The optional command to run before the Stack is destroyed,
+  /// ie before `docker compose down`.
+  #[serde(default)]
+  #[builder(default)]
+  pub on_destroy: SystemCommand,
+
   /// The extra arguments to pass after `docker compose up -d`.
   /// If empty, no extra arguments will be passed.
   #[serde(default, deserialize_with = "string_list_deserializer")]
@@ -555,6 +606,10 @@ fn default_env_file_path() -> String {
   String::from(".env")
 }
 
+fn default_on_deploy_timeout() -> U64 {
+  300
+}
+
 fn default_auto_pull() -> bool {
   true
 }
@@ -597,15 +652,21 @@ impl Default for StackConfig {
       ignore_services: Default::default(),
       pre_deploy: Default::default(),
       post_deploy: Default::default(),
+      on_deploy: Default::default(),
+      on_deploy_timeout: default_on_deploy_timeout(),
+      on_destroy: Default::default(),
       extra_args: Default::default(),
       environment: Default::default(),
       env_file_path: default_env_file_path(),
       additional_env_files: Default::default(),
+      compose_profiles: Default::default(),
       config_files: Default::default(),
       run_build: Default::default(),
+      compose_parallel_limit: Default::default(),
       destroy_before_deploy: Default::default(),
       build_extra_args: Default::default(),
       skip_secret_interp: Default::default(),
+      skip_variable_interp: Default::default(),
       linked_repo: Default::default(),
       git_provider: default_git_provider(),
       git_https: default_git_https(),
@@ -675,6 +736,22 @@ pub struct StackService {
   pub update_available: bool,
 }
 
+#[typeshare]
+#[derive(
+  Debug, Clone, Default, PartialEq, Serialize, Deserialize,
+)]
+pub struct StackServiceInfo {
+  /// The service name
+  pub name: String,
+  /// The image the service container is using
+  pub image: String,
+  /// The image repo digest, eg `mongo@sha256:...`.
+  /// Only available if the image was pulled from or pushed to a registry.
+  pub image_digest: Option<String>,
+  /// The service container state
+  pub state: ContainerStateStatusEnum,
+}
+
 #[typeshare]
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, Default)]
 pub struct StackActionState {
@@ -770,6 +847,11 @@ pub struct StackRemoteFileContents {
   pub path: String,
   /// The contents of the file
   pub contents: String,
+  /// Whether the contents are base64 encoded.
+  /// Files on host which are not valid UTF-8 (eg binary files)
+  /// will be base64 encoded.
+  #[serde(default)]
+  pub base64_contents: bool,
   /// The services depending on this file,
   /// or empty for global requirement (eg all compose files and env files).
   #[serde(default)]
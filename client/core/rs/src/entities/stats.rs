@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::entities::{I64, Timelength};
+use crate::entities::{I64, Timelength, U64};
 
 /// System information of a server
 #[typeshare]
@@ -64,9 +64,18 @@ pub struct SystemStatsRecord {
   /// Total network egress in bytes
   #[serde(default)]
   pub network_egress_bytes: f64,
-  // /// Network usage by interface name (ingress, egress in bytes)
-  // #[serde(default)]
-  // pub network_usage_interface: Vec<SingleNetworkInterfaceUsage>, // interface -> (ingress, egress)
+  /// Network ingress rate in bytes per second on the server's
+  /// configured network interface, or over all interfaces.
+  #[serde(default)]
+  pub network_ingress_bytes_per_sec: f64,
+  /// Network egress rate in bytes per second on the server's
+  /// configured network interface, or over all interfaces.
+  #[serde(default)]
+  pub network_egress_bytes_per_sec: f64,
+  /// Usage of the server's configured network interface.
+  /// Empty if none is configured.
+  #[serde(default)]
+  pub network_interfaces: Vec<SingleNetworkInterfaceUsage>,
 }
 
 /// Realtime system stats data.
@@ -96,9 +105,17 @@ pub struct SystemStats {
   /// Network egress usage in MB
   #[serde(default)]
   pub network_egress_bytes: f64,
-  // /// Network usage by interface name (ingress, egress in bytes)
-  // #[serde(default)]
-  // pub network_usage_interface: Vec<SingleNetworkInterfaceUsage>, // interface -> (ingress, egress)
+  /// Network ingress rate in bytes per second,
+  /// summed over all non-loopback interfaces.
+  #[serde(default)]
+  pub network_ingress_bytes_per_sec: f64,
+  /// Network egress rate in bytes per second,
+  /// summed over all non-loopback interfaces.
+  #[serde(default)]
+  pub network_egress_bytes_per_sec: f64,
+  /// Network usage by interface
+  #[serde(default)]
+  pub network_interfaces: Vec<SingleNetworkInterfaceUsage>,
   // metadata
   /// The rate the system stats are being polled from the system
   pub polling_rate: Timelength,
@@ -120,18 +137,38 @@ pub struct SingleDiskUsage {
   pub used_gb: f64,
   /// Total size of the disk in GB
   pub total_gb: f64,
+  /// Number of used inodes on the disk.
+  /// Zero if the file system doesn't report inodes.
+  #[serde(default)]
+  pub inodes_used: U64,
+  /// Total number of inodes on the disk.
+  /// Zero if the file system doesn't report inodes.
+  #[serde(default)]
+  pub inodes_total: U64,
 }
 
 /// Info for network interface usage.
 #[typeshare]
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct SingleNetworkInterfaceUsage {
   /// The network interface name
   pub name: String,
-  /// The ingress in bytes
+  /// The ingress in bytes since the last poll
   pub ingress_bytes: f64,
-  /// The egress in bytes
+  /// The egress in bytes since the last poll
   pub egress_bytes: f64,
+  /// The total received bytes counter
+  #[serde(default)]
+  pub total_ingress_bytes: f64,
+  /// The total transmitted bytes counter
+  #[serde(default)]
+  pub total_egress_bytes: f64,
+  /// The ingress rate in bytes per second
+  #[serde(default)]
+  pub ingress_bytes_per_sec: f64,
+  /// The egress rate in bytes per second
+  #[serde(default)]
+  pub egress_bytes_per_sec: f64,
 }
 
 pub fn sum_disk_usage(disks: &[SingleDiskUsage]) -> TotalDiskUsage {
@@ -100,6 +100,9 @@ pub struct ResourceSyncInfo {
   /// The list of pending updates to variables
   #[serde(default)]
   pub variable_updates: Vec<DiffData>,
+  /// The list of pending updates to tags
+  #[serde(default)]
+  pub tag_updates: Vec<DiffData>,
   /// The list of pending updates to user groups
   #[serde(default)]
   pub user_group_updates: Vec<DiffData>,
@@ -155,6 +158,21 @@ pub enum DiffData {
   },
 }
 
+/// A field which differs between a live resource
+/// and the resource declared in the sync files.
+#[typeshare]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncConflict {
+  /// The live resource
+  pub resource_target: ResourceTarget,
+  /// The path to the field, eg. `config.image`
+  pub field: String,
+  /// The live value, in TOML. Null if not set on the live resource.
+  pub live_value: Option<String>,
+  /// The value in the sync files, in TOML. Null if not set in the files.
+  pub sync_value: Option<String>,
+}
+
 #[typeshare]
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SyncDeployUpdate {
@@ -280,11 +298,27 @@ pub struct ResourceSyncConfig {
   #[builder(default)]
   pub match_tags: Vec<String>,
 
+  /// Optionally scope the resource names managed by this sync.
+  /// When set, `<namespace>-` is prepended to all the resource names
+  /// in the sync files, and only resources with the prefix are
+  /// updated or deleted. Exports strip the prefix again.
+  /// Resources referenced in configs (eg. servers) must use the full name.
+  /// Variables and user groups are global, so they can't be included
+  /// in a sync with a namespace.
+  #[serde(default)]
+  #[builder(default)]
+  pub namespace: String,
+
   /// Whether sync should include variables.
   #[serde(default)]
   #[builder(default)]
   pub include_variables: bool,
 
+  /// Whether sync should include tags.
+  #[serde(default)]
+  #[builder(default)]
+  pub include_tags: bool,
+
   /// Whether sync should include user groups.
   #[serde(default)]
   #[builder(default)]
@@ -364,7 +398,9 @@ impl Default for ResourceSyncConfig {
       managed: Default::default(),
       include_resources: default_include_resources(),
       match_tags: Default::default(),
+      namespace: Default::default(),
       include_variables: Default::default(),
+      include_tags: Default::default(),
       include_user_groups: Default::default(),
       delete: Default::default(),
       webhook_enabled: default_webhook_enabled(),
@@ -15,8 +15,10 @@ use super::{
   procedure::_PartialProcedureConfig,
   repo::_PartialRepoConfig,
   server::_PartialServerConfig,
+  server_group::_PartialServerGroupConfig,
   stack::_PartialStackConfig,
   sync::_PartialResourceSyncConfig,
+  tag::TagColor,
   variable::Variable,
 };
 
@@ -94,6 +96,20 @@ pub struct ResourcesToml {
   )]
   pub resource_syncs: Vec<ResourceToml<_PartialResourceSyncConfig>>,
 
+  #[serde(
+    default,
+    alias = "server_group",
+    skip_serializing_if = "Vec::is_empty"
+  )]
+  pub server_groups: Vec<ResourceToml<_PartialServerGroupConfig>>,
+
+  #[serde(
+    default,
+    alias = "tag",
+    skip_serializing_if = "Vec::is_empty"
+  )]
+  pub tags: Vec<TagToml>,
+
   #[serde(
     default,
     alias = "user_group",
@@ -151,6 +167,17 @@ fn is_false(b: &bool) -> bool {
   !b
 }
 
+#[typeshare]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagToml {
+  /// Tag name
+  pub name: String,
+
+  /// Tag color. Default: Slate.
+  #[serde(default)]
+  pub color: TagColor,
+}
+
 #[typeshare]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserGroupToml {
@@ -4,7 +4,8 @@ use strum::{Display, EnumString};
 use typeshare::typeshare;
 
 use crate::entities::{
-  I64, MongoId, Operation, all_logs_success, komodo_timestamp,
+  I64, JsonValue, MongoId, Operation, all_logs_success,
+  komodo_timestamp,
 };
 
 use super::{ResourceTarget, Version};
@@ -78,6 +79,13 @@ pub struct Update {
   /// If the update is for resource config update, give the current (at time of Update) toml contents
   #[serde(default, skip_serializing_if = "String::is_empty")]
   pub current_toml: String,
+  /// The ip of the client which made the request, if made through the api.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub client_ip: Option<String>,
+  /// Structured output of the execution.
+  /// Actions set this by printing a [KOMODO_OUTPUT][super::KOMODO_OUTPUT] line.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub output: Option<JsonValue>,
 }
 
 impl Update {
@@ -52,6 +52,12 @@ pub struct UserGroup {
   pub all:
     IndexMap<ResourceTargetVariant, PermissionLevelAndSpecifics>,
 
+  /// Give the user group permissions on all resources with a certain tag.
+  /// Keyed by tag id. These are resolved when permissions are checked,
+  /// so resources are included as soon as they are tagged.
+  #[serde(default)]
+  pub tags: IndexMap<String, PermissionLevelAndSpecifics>,
+
   /// Unix time (ms) when user group last updated
   #[serde(default)]
   pub updated_at: I64,
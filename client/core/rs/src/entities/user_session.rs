@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use typeshare::typeshare;
+
+use super::I64;
+
+/// A login session, created whenever a JWT is issued.
+/// Requests using the JWT are only authenticated while
+/// the session exists, so deleting it revokes the JWT.
+#[typeshare]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[cfg_attr(
+  feature = "mongo",
+  derive(mongo_indexed::derive::MongoIndexed)
+)]
+pub struct UserSession {
+  /// Unique id of the JWT, matching the `jti` claim.
+  #[cfg_attr(feature = "mongo", unique_index)]
+  pub jwt_id: String,
+
+  /// User associated with the session
+  #[cfg_attr(feature = "mongo", index)]
+  pub user_id: String,
+
+  /// Timestamp the JWT was issued
+  pub issued_at: I64,
+
+  /// Timestamp the JWT expires
+  pub expires_at: I64,
+
+  /// The user agent of the client which logged in
+  pub user_agent: Option<String>,
+
+  /// The ip of the client which logged in,
+  /// when forwarded by a reverse proxy.
+  pub ip: Option<String>,
+}
@@ -1,18 +1,24 @@
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
+use super::ResourceTarget;
+
 /// A non-secret global variable which can be interpolated into deployment
 /// environment variable values and build argument values.
+///
+/// Variables can also be scoped to a Server, in which case they
+/// override the global variable with the same name for
+/// resources running on that Server.
 #[typeshare]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[cfg_attr(
   feature = "mongo",
   derive(mongo_indexed::derive::MongoIndexed)
 )]
+#[cfg_attr(feature = "mongo", unique_doc_index({ "name": 1, "server_id": 1 }))]
 pub struct Variable {
-  /// Unique name associated with the variable.
+  /// Name associated with the variable, unique per scope.
   /// Instances of '[[variable.name]]' in value will be replaced with 'variable.value'.
-  #[cfg_attr(feature = "mongo", unique_index)]
   pub name: String,
   /// A description for the variable.
   #[serde(default, skip_serializing_if = "String::is_empty")]
@@ -28,4 +34,21 @@ pub struct Variable {
   /// of the database (system level encryption, network isolation, etc.)
   #[serde(default)]
   pub is_secret: bool,
+  /// Scope the variable to a Server by id.
+  /// Stacks, Deployments, and Repos on the Server will use this value
+  /// over the global variable with the same name.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub server_id: Option<String>,
+  /// The number of resources which reference the variable
+  /// using '[[variable.name]]'. Computed by Core, not stored.
+  #[serde(default, skip_serializing_if = "is_zero")]
+  pub reference_count: u32,
+  /// The resources which reference the variable.
+  /// Computed by Core, not stored.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub referenced_by: Vec<ResourceTarget>,
+}
+
+fn is_zero(count: &u32) -> bool {
+  *count == 0
 }
@@ -13,6 +13,7 @@ export type UserResponses = {
   SetLastSeenUpdate: Types.SetLastSeenUpdateResponse;
   CreateApiKey: Types.CreateApiKeyResponse;
   DeleteApiKey: Types.DeleteApiKeyResponse;
+  RotateApiKey: Types.RotateApiKeyResponse;
 };
 
 export type ReadResponses = {
@@ -27,10 +28,13 @@ export type ReadResponses = {
   GetPermission: Types.GetPermissionResponse;
   FindUser: Types.FindUserResponse;
   ListUsers: Types.ListUsersResponse;
+  ListServiceAccounts: Types.ListServiceAccountsResponse;
+  ListUserSessions: Types.ListUserSessionsResponse;
   ListApiKeys: Types.ListApiKeysResponse;
   ListApiKeysForServiceUser: Types.ListApiKeysForServiceUserResponse;
   ListPermissions: Types.ListPermissionsResponse;
   ListUserTargetPermissions: Types.ListUserTargetPermissionsResponse;
+  ListAuditLog: Types.ListAuditLogResponse;
 
   // ==== USER GROUP ====
   GetUserGroup: Types.GetUserGroupResponse;
@@ -72,6 +76,7 @@ export type ReadResponses = {
   ListDockerImageHistory: Types.ListDockerImageHistoryResponse;
   ListDockerVolumes: Types.ListDockerVolumesResponse;
   InspectDockerVolume: Types.InspectDockerVolumeResponse;
+  GetVolumeBrowserListing: Types.GetVolumeBrowserListingResponse;
   ListComposeProjects: Types.ListComposeProjectsResponse;
   GetServerActionState: Types.GetServerActionStateResponse;
   GetHistoricalServerStats: Types.GetHistoricalServerStatsResponse;
@@ -79,14 +84,21 @@ export type ReadResponses = {
   ListFullServers: Types.ListFullServersResponse;
   ListTerminals: Types.ListTerminalsResponse;
 
+  // ==== SERVER GROUP ====
+  GetServerGroup: Types.GetServerGroupResponse;
+  ListServerGroups: Types.ListServerGroupsResponse;
+  ListFullServerGroups: Types.ListFullServerGroupsResponse;
+
   // ==== STACK ====
   GetStacksSummary: Types.GetStacksSummaryResponse;
   GetStack: Types.GetStackResponse;
   GetStackActionState: Types.GetStackActionStateResponse;
   GetStackWebhooksEnabled: Types.GetStackWebhooksEnabledResponse;
   GetStackLog: Types.GetStackLogResponse;
+  GetStackServiceLogs: Types.GetStackServiceLogsResponse;
   SearchStackLog: Types.SearchStackLogResponse;
   InspectStackContainer: Types.InspectStackContainerResponse;
+  GetStackContainerEnv: Types.GetStackContainerEnvResponse;
   ListStacks: Types.ListStacksResponse;
   ListFullStacks: Types.ListFullStacksResponse;
   ListStackServices: Types.ListStackServicesResponse;
@@ -102,6 +114,7 @@ export type ReadResponses = {
   GetDeploymentLog: Types.GetDeploymentLogResponse;
   SearchDeploymentLog: Types.SearchDeploymentLogResponse;
   InspectDeploymentContainer: Types.InspectDeploymentContainerResponse;
+  GetDeploymentContainerEnv: Types.GetDeploymentContainerEnvResponse;
   ListDeployments: Types.ListDeploymentsResponse;
   ListFullDeployments: Types.ListFullDeploymentsResponse;
   ListCommonDeploymentExtraArgs: Types.ListCommonDeploymentExtraArgsResponse;
@@ -129,6 +142,7 @@ export type ReadResponses = {
   GetResourceSyncsSummary: Types.GetResourceSyncsSummaryResponse;
   GetResourceSync: Types.GetResourceSyncResponse;
   GetResourceSyncActionState: Types.GetResourceSyncActionStateResponse;
+  ListResourceSyncConflicts: Types.ListResourceSyncConflictsResponse;
   GetSyncWebhooksEnabled: Types.GetSyncWebhooksEnabledResponse;
   ListResourceSyncs: Types.ListResourceSyncsResponse;
   ListFullResourceSyncs: Types.ListFullResourceSyncsResponse;
@@ -148,6 +162,7 @@ export type ReadResponses = {
   // ==== TOML ====
   ExportAllResourcesToToml: Types.ExportAllResourcesToTomlResponse;
   ExportResourcesToToml: Types.ExportResourcesToTomlResponse;
+  ImportResourcesToToml: Types.ImportToTomlResponse;
 
   // ==== TAG ====
   GetTag: Types.GetTagResponse;
@@ -169,6 +184,7 @@ export type ReadResponses = {
   // ==== VARIABLE ====
   GetVariable: Types.GetVariableResponse;
   ListVariables: Types.ListVariablesResponse;
+  GetAllVariables: Types.GetAllVariablesResponse;
 
   // ==== PROVIDER ====
   GetGitProviderAccount: Types.GetGitProviderAccountResponse;
@@ -183,12 +199,17 @@ export type WriteResponses = {
   UpdateUserUsername: Types.UpdateUserUsernameResponse;
   UpdateUserPassword: Types.UpdateUserPasswordResponse;
   DeleteUser: Types.DeleteUserResponse;
+  RevokeUserSession: Types.RevokeUserSessionResponse;
 
   // ==== SERVICE USER ====
   CreateServiceUser: Types.CreateServiceUserResponse;
   UpdateServiceUserDescription: Types.UpdateServiceUserDescriptionResponse;
   CreateApiKeyForServiceUser: Types.CreateApiKeyForServiceUserResponse;
   DeleteApiKeyForServiceUser: Types.DeleteApiKeyForServiceUserResponse;
+  CreateServiceAccount: Types.CreateServiceAccountResponse;
+  DeleteServiceAccount: Types.DeleteServiceAccountResponse;
+  CreateServiceAccountApiKey: Types.CreateServiceAccountApiKeyResponse;
+  DeleteServiceAccountApiKey: Types.DeleteServiceAccountApiKeyResponse;
 
   // ==== USER GROUP ====
   CreateUserGroup: Types.UserGroup;
@@ -198,6 +219,7 @@ export type WriteResponses = {
   RemoveUserFromUserGroup: Types.UserGroup;
   SetUsersInUserGroup: Types.UserGroup;
   SetEveryoneUserGroup: Types.UserGroup;
+  UpdateUserGroupTagPermission: Types.UserGroup;
 
   // ==== PERMISSIONS ====
   UpdateUserAdmin: Types.UpdateUserAdminResponse;
@@ -215,10 +237,20 @@ export type WriteResponses = {
   UpdateServer: Types.Server;
   RenameServer: Types.Update;
   CreateNetwork: Types.Update;
+  CopyFileToContainer: Types.Update;
+  CopyFileFromContainer: Types.CopyFileFromContainerResponse;
+  RunContainerCommand: Types.Update;
   CreateTerminal: Types.NoData;
   DeleteTerminal: Types.NoData;
   DeleteAllTerminals: Types.NoData;
 
+  // ==== SERVER GROUP ====
+  CreateServerGroup: Types.ServerGroup;
+  CopyServerGroup: Types.ServerGroup;
+  DeleteServerGroup: Types.ServerGroup;
+  UpdateServerGroup: Types.ServerGroup;
+  RenameServerGroup: Types.Update;
+
   // ==== STACK ====
   CreateStack: Types.Stack;
   CopyStack: Types.Stack;
@@ -294,6 +326,7 @@ export type WriteResponses = {
   UpdateResourceSync: Types.ResourceSync;
   RenameResourceSync: Types.Update;
   CommitSync: Types.Update;
+  ImportResourcesFromToml: Types.ImportResourcesFromTomlResponse;
   WriteSyncFileContents: Types.Update;
   RefreshResourceSyncPending: Types.ResourceSync;
   CreateSyncWebhook: Types.CreateSyncWebhookResponse;
@@ -345,6 +378,11 @@ export type ExecuteResponses = {
   PruneBuildx: Types.Update;
   PruneSystem: Types.Update;
 
+  // ==== SERVER GROUP ====
+  RunCommandOnGroup: Types.Update;
+  PruneImagesOnGroup: Types.Update;
+  PruneSystemOnGroup: Types.Update;
+
   // ==== STACK ====
   DeployStack: Types.Update;
   BatchDeployStack: Types.BatchExecutionResponse;
@@ -414,5 +452,6 @@ export type ExecuteResponses = {
   // ==== MAINTENANCE ====
   ClearRepoCache: Types.Update;
   BackupCoreDatabase: Types.Update;
+  RestoreDatabase: Types.Update;
   GlobalAutoUpdate: Types.Update;
 };
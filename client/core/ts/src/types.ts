@@ -241,7 +241,8 @@ export type ResourceTarget =
 	| { type: "Action", id: string }
 	| { type: "Builder", id: string }
 	| { type: "Alerter", id: string }
-	| { type: "ResourceSync", id: string };
+	| { type: "ResourceSync", id: string }
+	| { type: "ServerGroup", id: string };
 
 /** Types of maintenance schedules */
 export enum MaintenanceScheduleType {
@@ -363,6 +364,9 @@ export enum Operation {
 	StopAllContainers = "StopAllContainers",
 	PruneContainers = "PruneContainers",
 	CreateNetwork = "CreateNetwork",
+	CopyFileToContainer = "CopyFileToContainer",
+	CopyFileFromContainer = "CopyFileFromContainer",
+	RunContainerCommand = "RunContainerCommand",
 	DeleteNetwork = "DeleteNetwork",
 	PruneNetworks = "PruneNetworks",
 	DeleteImage = "DeleteImage",
@@ -372,6 +376,13 @@ export enum Operation {
 	PruneDockerBuilders = "PruneDockerBuilders",
 	PruneBuildx = "PruneBuildx",
 	PruneSystem = "PruneSystem",
+	CreateServerGroup = "CreateServerGroup",
+	UpdateServerGroup = "UpdateServerGroup",
+	RenameServerGroup = "RenameServerGroup",
+	DeleteServerGroup = "DeleteServerGroup",
+	RunCommandOnGroup = "RunCommandOnGroup",
+	PruneImagesOnGroup = "PruneImagesOnGroup",
+	PruneSystemOnGroup = "PruneSystemOnGroup",
 	CreateStack = "CreateStack",
 	UpdateStack = "UpdateStack",
 	RenameStack = "RenameStack",
@@ -451,6 +462,7 @@ export enum Operation {
 	RunSync = "RunSync",
 	ClearRepoCache = "ClearRepoCache",
 	BackupCoreDatabase = "BackupCoreDatabase",
+	RestoreDatabase = "RestoreDatabase",
 	GlobalAutoUpdate = "GlobalAutoUpdate",
 	CreateVariable = "CreateVariable",
 	UpdateVariableValue = "UpdateVariableValue",
@@ -543,6 +555,13 @@ export interface Update {
 	prev_toml?: string;
 	/** If the update is for resource config update, give the current (at time of Update) toml contents */
 	current_toml?: string;
+	/** The ip of the client which made the request, if made through the api. */
+	client_ip?: string;
+	/**
+	 * Structured output of the execution.
+	 * Actions set this by printing a [KOMODO_OUTPUT][super::KOMODO_OUTPUT] line.
+	 */
+	output?: JsonValue;
 }
 
 export type BoxUpdate = Update;
@@ -563,6 +582,17 @@ export interface ImageRegistryConfig {
 	organization?: string;
 }
 
+/** A BuildKit secret mounted during the build. */
+export interface BuildSecret {
+	/** The secret id, used with `RUN --mount=type=secret,id=...`. */
+	id?: string;
+	/**
+	 * The secret value, written to the mounted secret file.
+	 * Supports interpolation, eg `[[MY_SECRET]]`.
+	 */
+	src?: string;
+}
+
 export interface SystemCommand {
 	path?: string;
 	command?: string;
@@ -658,8 +688,38 @@ export interface BuildConfig {
 	image_registry?: ImageRegistryConfig[];
 	/** Whether to skip secret interpolation in the build_args. */
 	skip_secret_interp?: boolean;
+	/**
+	 * Whether to skip only variable interpolation, still interpolating secrets.
+	 * Useful if the contents use `[[...]]` for something other than Komodo variables.
+	 * If `skip_secret_interp` is also enabled, all interpolation is skipped.
+	 */
+	skip_variable_interp?: boolean;
 	/** Whether to use buildx to build (eg `docker buildx build ...`) */
 	use_buildx?: boolean;
+	/**
+	 * The platforms to build the image for, eg `["linux/amd64", "linux/arm64"]`.
+	 * If non-empty, builds with `docker buildx build --platform ... --push`.
+	 * This requires buildx set up on the builder, and an image registry to push to.
+	 */
+	platforms?: string[];
+	/**
+	 * External cache sources passed with `--cache-from`,
+	 * eg `type=registry,ref=registry.example.com/app:cache`.
+	 * See <https://docs.docker.com/build/cache/backends>.
+	 */
+	cache_from?: string[];
+	/**
+	 * Cache export destinations passed with `--cache-to`,
+	 * eg `type=inline` or `type=registry,ref=registry.example.com/app:cache,mode=max`.
+	 * Exporting to anything other than `type=inline` requires buildx.
+	 */
+	cache_to?: string[];
+	/**
+	 * Additional build contexts passed with `--build-context`,
+	 * eg `shared=../shared` or `alpine=docker-image://alpine:3.20`.
+	 * Each must be in the form `name=value`.
+	 */
+	build_contexts?: string[];
 	/** Any extra docker cli arguments to be included in the build command */
 	extra_args?: string[];
 	/** The optional command run after repo clone and before docker build. */
@@ -688,6 +748,14 @@ export interface BuildConfig {
 	 * ```
 	 */
 	secret_args?: string;
+	/**
+	 * BuildKit secret mounts.
+	 * 
+	 * Each secret is written to a temporary file outside the build context
+	 * for the duration of the build, and passed with `--secret id=...,src=...`.
+	 * The secret can be used in RUN commands with `--mount=type=secret,id=...`.
+	 */
+	build_secrets?: BuildSecret[];
 	/** Docker labels */
 	labels?: string;
 }
@@ -718,6 +786,19 @@ export interface BuildInfo {
 	latest_hash?: string;
 	/** Latest remote commit message, or null */
 	latest_message?: string;
+	/**
+	 * Sha256 hash of the latest dockerfile contents, or null.
+	 * This is updated whenever Komodo refreshes the build cache.
+	 */
+	dockerfile_hash?: string;
+	/** Sha256 hash of the dockerfile contents at the last successful build, or null. */
+	built_dockerfile_hash?: string;
+	/** Whether the dockerfile has changed since the last successful build. */
+	needs_rebuild?: boolean;
+	/** The current step of the in progress build, or null. */
+	build_step?: number;
+	/** The total steps of the in progress build stage, or null. */
+	build_step_total?: number;
 }
 
 export type Build = Resource<BuildConfig, BuildInfo>;
@@ -760,6 +841,8 @@ export interface BuildListItemInfo {
 	built_hash?: string;
 	/** Latest short commit hash, or null. Only for repo based stacks */
 	latest_hash?: string;
+	/** Whether the dockerfile has changed since the last successful build. */
+	needs_rebuild: boolean;
 	/** The first listed image registry domain */
 	image_registry_domain?: string;
 }
@@ -860,6 +943,9 @@ export type Execution =
 	| { type: "PruneDockerBuilders", params: PruneDockerBuilders }
 	| { type: "PruneBuildx", params: PruneBuildx }
 	| { type: "PruneSystem", params: PruneSystem }
+	| { type: "RunCommandOnGroup", params: RunCommandOnGroup }
+	| { type: "PruneImagesOnGroup", params: PruneImagesOnGroup }
+	| { type: "PruneSystemOnGroup", params: PruneSystemOnGroup }
 	/** Execute a Resource Sync. (alias: `sync`) */
 	| { type: "RunSync", params: RunSync }
 	/** Commit a Resource Sync. (alias: `commit`) */
@@ -894,12 +980,42 @@ export interface EnabledExecution {
 	enabled: boolean;
 }
 
+/**
+ * When to run a [ProcedureStage],
+ * based on the result of the previous stages.
+ */
+export enum StageRunCondition {
+	/** Run the stage whether or not a previous stage failed. */
+	Always = "Always",
+	/**
+	 * Only run the stage if no previous stage failed.
+	 * This matches procedures without conditions,
+	 * which stop at the first failed stage.
+	 */
+	OnSuccess = "OnSuccess",
+	/**
+	 * Only run the stage if a previous stage failed.
+	 * Can be used for cleanup / notification on failure.
+	 */
+	OnFailure = "OnFailure",
+}
+
 /** A single stage of a procedure. Runs a list of executions in parallel. */
 export interface ProcedureStage {
 	/** A name for the procedure */
 	name: string;
 	/** Whether the stage should be run as part of the procedure. */
 	enabled: boolean;
+	/**
+	 * Run the stage based on the result of the previous stages.
+	 * Default: `OnSuccess`
+	 */
+	run_condition?: StageRunCondition;
+	/**
+	 * Fail the stage if its executions take longer than this
+	 * many seconds. If not provided, the stage can run indefinitely.
+	 */
+	timeout_seconds?: U64;
 	/** The executions in the stage */
 	executions?: EnabledExecution[];
 }
@@ -975,6 +1091,8 @@ export interface CreateApiKeyResponse {
 	secret: string;
 }
 
+export type RotateApiKeyResponse = CreateApiKeyResponse;
+
 export type CreateApiKeyForServiceUserResponse = CreateApiKeyResponse;
 
 export type CreateBuildWebhookResponse = NoData;
@@ -1098,6 +1216,34 @@ export type CreateProcedureResponse = Procedure;
 
 export type CreateRepoWebhookResponse = NoData;
 
+export type CreateServiceAccountApiKeyResponse = CreateApiKeyResponse;
+
+/**
+ * An account for machine-to-machine access, separate from users.
+ * Service accounts have no password and can't log in to the UI,
+ * they can only make api calls using their api keys.
+ */
+export interface ServiceAccount {
+	/**
+	 * The Mongo ID of the ServiceAccount.
+	 * This field is de/serialized from/to JSON as
+	 * `{ "_id": { "$oid": "..." }, ...(rest of ServiceAccount schema) }`
+	 */
+	_id?: MongoId;
+	/** The globally unique name of the service account. */
+	name: string;
+	/** A description of what the service account is used for. */
+	description?: string;
+	/** Give the service account permissions on all resources of a certain type. */
+	permissions?: Record<ResourceTarget["type"], PermissionLevelAndSpecifics | PermissionLevel>;
+	/** The api keys of the service account. Secrets are stored hashed. */
+	api_keys?: ApiKey[];
+	/** Unix time (ms) when the service account was last updated */
+	updated_at?: I64;
+}
+
+export type CreateServiceAccountResponse = ServiceAccount;
+
 export type CreateServiceUserResponse = User;
 
 export type CreateStackWebhookResponse = NoData;
@@ -1107,10 +1253,14 @@ export type CreateSyncWebhookResponse = NoData;
 /**
  * A non-secret global variable which can be interpolated into deployment
  * environment variable values and build argument values.
+ * 
+ * Variables can also be scoped to a Server, in which case they
+ * override the global variable with the same name for
+ * resources running on that Server.
  */
 export interface Variable {
 	/**
-	 * Unique name associated with the variable.
+	 * Name associated with the variable, unique per scope.
 	 * Instances of '[[variable.name]]' in value will be replaced with 'variable.value'.
 	 */
 	name: string;
@@ -1127,6 +1277,22 @@ export interface Variable {
 	 * of the database (system level encryption, network isolation, etc.)
 	 */
 	is_secret?: boolean;
+	/**
+	 * Scope the variable to a Server by id.
+	 * Stacks, Deployments, and Repos on the Server will use this value
+	 * over the global variable with the same name.
+	 */
+	server_id?: string;
+	/**
+	 * The number of resources which reference the variable
+	 * using '[[variable.name]]'. Computed by Core, not stored.
+	 */
+	reference_count?: number;
+	/**
+	 * The resources which reference the variable.
+	 * Computed by Core, not stored.
+	 */
+	referenced_by?: ResourceTarget[];
 }
 
 export type CreateVariableResponse = Variable;
@@ -1147,12 +1313,41 @@ export type DeleteProcedureResponse = Procedure;
 
 export type DeleteRepoWebhookResponse = NoData;
 
+export type DeleteServiceAccountApiKeyResponse = NoData;
+
+export type DeleteServiceAccountResponse = ServiceAccount;
+
 export type DeleteStackWebhookResponse = NoData;
 
 export type DeleteSyncWebhookResponse = NoData;
 
 export type DeleteUserResponse = User;
 
+/**
+ * A login session, created whenever a JWT is issued.
+ * Requests using the JWT are only authenticated while
+ * the session exists, so deleting it revokes the JWT.
+ */
+export interface UserSession {
+	/** Unique id of the JWT, matching the `jti` claim. */
+	jwt_id: string;
+	/** User associated with the session */
+	user_id: string;
+	/** Timestamp the JWT was issued */
+	issued_at: I64;
+	/** Timestamp the JWT expires */
+	expires_at: I64;
+	/** The user agent of the client which logged in */
+	user_agent?: string;
+	/**
+	 * The ip of the client which logged in,
+	 * when forwarded by a reverse proxy.
+	 */
+	ip?: string;
+}
+
+export type RevokeUserSessionResponse = UserSession;
+
 export type DeleteVariableResponse = Variable;
 
 export type DeploymentImage = 
@@ -1179,6 +1374,19 @@ export enum RestartMode {
 	UnlessStopped = "unless-stopped",
 }
 
+export enum PullPolicy {
+	/**
+	 * Docker's default, only pulls the image if it is missing locally.
+	 * Komodo still tries to pull the image before deploying,
+	 * but falls back to the local image if the pull fails.
+	 */
+	Missing = "missing",
+	/** Always pull the image, failing the deploy if the pull fails. */
+	Always = "always",
+	/** Never pull the image, it must already exist locally. */
+	Never = "never",
+}
+
 export enum TerminationSignal {
 	SigHup = "SIGHUP",
 	SigInt = "SIGINT",
@@ -1205,6 +1413,12 @@ export interface DeploymentConfig {
 	image_registry_account?: string;
 	/** Whether to skip secret interpolation into the deployment environment variables. */
 	skip_secret_interp?: boolean;
+	/**
+	 * Whether to skip only variable interpolation, still interpolating secrets.
+	 * Useful if the contents use `[[...]]` for something other than Komodo variables.
+	 * If `skip_secret_interp` is also enabled, all interpolation is skipped.
+	 */
+	skip_variable_interp?: boolean;
 	/** Whether to redeploy the deployment whenever the attached build finishes. */
 	redeploy_on_build?: boolean;
 	/** Whether to poll for any updates to the image. */
@@ -1227,6 +1441,12 @@ export interface DeploymentConfig {
 	network: string;
 	/** The restart mode given to the container. */
 	restart?: RestartMode;
+	/**
+	 * When to pull the image before running the container,
+	 * passed to `docker run --pull`.
+	 * Use `always` to force a fresh pull on redeploy without changing the tag.
+	 */
+	pull_policy?: PullPolicy;
 	/**
 	 * This is interpolated at the end of the `docker run` command,
 	 * which means they are either passed to the containers inner process,
@@ -1451,6 +1671,21 @@ export type AlertData =
 	used_gb: number;
 	/** The total size of the disk in GB */
 	total_gb: number;
+}}
+	/** A server has high inode usage on a disk. */
+	| { type: "ServerInodes", data: {
+	/** The id of the server */
+	id: string;
+	/** The name of the server */
+	name: string;
+	/** The region of the server */
+	region?: string;
+	/** The mount path of the disk */
+	path: string;
+	/** The number of used inodes */
+	used: U64;
+	/** The total number of inodes */
+	total: U64;
 }}
 	/** A server has a version mismatch with the core. */
 	| { type: "ServerVersionMismatch", data: {
@@ -1548,6 +1783,23 @@ export type AlertData =
 	server_name: string;
 	/** One or more images that were updated */
 	images: string[];
+}}
+	/** A container's docker healthcheck has started failing. */
+	| { type: "ContainerUnhealthy", data: {
+	/** The id of the deployment or stack */
+	id: string;
+	/** The name of the deployment or stack */
+	name: string;
+	/** The type of resource, Deployment or Stack */
+	resource_type: ResourceTarget["type"];
+	/** The server id of server that the container is on */
+	server_id: string;
+	/** The server name */
+	server_name: string;
+	/** The container name */
+	container: string;
+	/** The service name, if the container is part of a stack */
+	service?: string;
 }}
 	/** An AWS builder failed to terminate. */
 	| { type: "AwsBuilderTerminationFailed", data: {
@@ -1639,6 +1891,24 @@ export type GetAlertResponse = Alert;
 
 export type GetAlerterResponse = Alerter;
 
+export interface VariableSummary {
+	/** The name of the variable. */
+	name: string;
+	/** The description of the variable. */
+	description: string;
+	/** Whether the variable is secret. */
+	is_secret: boolean;
+	/** The Server the variable is scoped to, if any. */
+	server_id?: string;
+	/**
+	 * The resources which interpolate the variable
+	 * into their config using `[[name]]`.
+	 */
+	referenced_by: ResourceTarget[];
+}
+
+export type GetAllVariablesResponse = VariableSummary[];
+
 export interface BuildActionState {
 	building: boolean;
 }
@@ -1735,6 +2005,12 @@ export interface RepoConfig {
 	branch: string;
 	/** Optionally set a specific commit hash. */
 	commit?: string;
+	/**
+	 * Make a shallow clone with history truncated to this many commits,
+	 * ie `git clone --depth {clone_depth}`. 1 is standard for CI builds.
+	 * 0 means a full clone.
+	 */
+	clone_depth?: number;
 	/**
 	 * Explicitly specify the folder to clone the repo in.
 	 * - If absolute (has leading '/')
@@ -1743,6 +2019,13 @@ export interface RepoConfig {
 	 * - Taken relative to Periphery `repo_dir` (ie `${root_directory}/repos`)
 	 */
 	path?: string;
+	/**
+	 * Only check out these directories in the repo, using `git sparse-checkout`.
+	 * Useful to avoid checking out an entire monorepo
+	 * when only a subdirectory is needed.
+	 * Empty means the whole repo is checked out.
+	 */
+	sparse_checkout?: string[];
 	/** Whether incoming webhooks actually trigger action. */
 	webhook_enabled: boolean;
 	/**
@@ -1778,6 +2061,12 @@ export interface RepoConfig {
 	env_file_path: string;
 	/** Whether to skip secret interpolation into the repo environment variable file. */
 	skip_secret_interp?: boolean;
+	/**
+	 * Whether to skip only variable interpolation, still interpolating secrets.
+	 * Useful if the contents use `[[...]]` for something other than Komodo variables.
+	 * If `skip_secret_interp` is also enabled, all interpolation is skipped.
+	 */
+	skip_variable_interp?: boolean;
 }
 
 export interface RepoInfo {
@@ -1875,8 +2164,20 @@ export interface ResourceSyncConfig {
 	 * matching all of the given tags. If none, will match all resources.
 	 */
 	match_tags?: string[];
+	/**
+	 * Optionally scope the resource names managed by this sync.
+	 * When set, `<namespace>-` is prepended to all the resource names
+	 * in the sync files, and only resources with the prefix are
+	 * updated or deleted. Exports strip the prefix again.
+	 * Resources referenced in configs (eg. servers) must use the full name.
+	 * Variables and user groups are global, so they can't be included
+	 * in a sync with a namespace.
+	 */
+	namespace?: string;
 	/** Whether sync should include variables. */
 	include_variables?: boolean;
+	/** Whether sync should include tags. */
+	include_tags?: boolean;
 	/** Whether sync should include user groups. */
 	include_user_groups?: boolean;
 	/**
@@ -1917,6 +2218,23 @@ export interface ResourceDiff {
 	data: DiffData;
 }
 
+/**
+ * A field which differs between a live resource
+ * and the resource declared in the sync files.
+ */
+export interface SyncConflict {
+	/** The live resource */
+	resource_target: ResourceTarget;
+	/** The path to the field, eg. `config.image` */
+	field: string;
+	/** The live value, in TOML. Null if not set on the live resource. */
+	live_value?: string;
+	/** The value in the sync files, in TOML. Null if not set in the files. */
+	sync_value?: string;
+}
+
+export type ListResourceSyncConflictsResponse = SyncConflict[];
+
 export interface SyncDeployUpdate {
 	/** Resources to deploy */
 	to_deploy: number;
@@ -1944,6 +2262,8 @@ export interface ResourceSyncInfo {
 	resource_updates?: ResourceDiff[];
 	/** The list of pending updates to variables */
 	variable_updates?: DiffData[];
+	/** The list of pending updates to tags */
+	tag_updates?: DiffData[];
 	/** The list of pending updates to user groups */
 	user_group_updates?: DiffData[];
 	/** The list of pending deploys to resources. */
@@ -2026,11 +2346,24 @@ export interface ServerConfig {
 	 * If this is empty, will use passkey in core config.
 	 */
 	passkey?: string;
+	/**
+	 * Path on the Core host to a PEM file containing
+	 * the client certificate and private key.
+	 * Required when Periphery is configured with `tls_ca_cert` (mTLS).
+	 * If this is empty, no client certificate is sent.
+	 */
+	client_cert_path?: string;
 	/**
 	 * Sometimes the system stats reports a mount path that is not desired.
 	 * Use this field to filter it out from the report.
 	 */
 	ignore_mounts?: string[];
+	/**
+	 * The network interface used for the network rates
+	 * recorded in the stats history, eg "eth0".
+	 * If empty (or not found), the rates over all interfaces are used.
+	 */
+	network_interface?: string;
 	/**
 	 * Whether to monitor any server stats beyond passing health check.
 	 * default: true
@@ -2065,6 +2398,17 @@ export interface ServerConfig {
 	disk_warning: number;
 	/** The percentage threshhold which triggers CRITICAL state for DISK. */
 	disk_critical: number;
+	/**
+	 * The percentage of used inodes which triggers WARNING state for a DISK.
+	 * Inode alerts are sent along with `send_disk_alerts`.
+	 * Disabled if 0.
+	 */
+	inode_warning?: number;
+	/**
+	 * The percentage of used inodes which triggers CRITICAL state for a DISK.
+	 * Disabled if 0.
+	 */
+	inode_critical?: number;
 	/** Scheduled maintenance windows during which alerts will be suppressed. */
 	maintenance_windows?: MaintenanceWindow[];
 }
@@ -2073,6 +2417,8 @@ export type Server = Resource<ServerConfig, undefined>;
 
 export type GetServerResponse = Server;
 
+export type GetServerGroupResponse = ServerGroup;
+
 export interface StackActionState {
 	pulling: boolean;
 	deploying: boolean;
@@ -2088,6 +2434,8 @@ export type GetStackActionStateResponse = StackActionState;
 
 export type GetStackLogResponse = Log;
 
+export type GetStackServiceLogsResponse = Log;
+
 export enum StackFileRequires {
 	/** Diff requires service redeploy. */
 	Redeploy = "Redeploy",
@@ -2132,6 +2480,12 @@ export interface StackConfig {
 	 * Combine with build_extra_args for custom behaviors.
 	 */
 	run_build?: boolean;
+	/**
+	 * Limit the number of images pulled / built in parallel
+	 * during deploy, passed as `COMPOSE_PARALLEL_LIMIT`.
+	 * If 0, uses the docker compose default.
+	 */
+	compose_parallel_limit?: number;
 	/** Whether to poll for any updates to the images. */
 	poll_for_updates?: boolean;
 	/**
@@ -2152,6 +2506,12 @@ export interface StackConfig {
 	destroy_before_deploy?: boolean;
 	/** Whether to skip secret interpolation into the stack environment variables. */
 	skip_secret_interp?: boolean;
+	/**
+	 * Whether to skip only variable interpolation, still interpolating secrets.
+	 * Useful if the contents use `[[...]]` for something other than Komodo variables.
+	 * If `skip_secret_interp` is also enabled, all interpolation is skipped.
+	 */
+	skip_variable_interp?: boolean;
 	/** Choose a Komodo Repo (Resource) to source the compose files. */
 	linked_repo?: string;
 	/** The git provider domain. Default: github.com */
@@ -2226,6 +2586,12 @@ export interface StackConfig {
 	 * Don't add it again there.
 	 */
 	additional_env_files?: string[];
+	/**
+	 * Compose profiles to enable with `--profile`.
+	 * Services in other profiles are not started.
+	 * Empty only starts services without a profile.
+	 */
+	compose_profiles?: string[];
 	/**
 	 * Add additional config files either in repo or on host to track.
 	 * Can add any files associated with the stack to enable editing them in the UI.
@@ -2246,6 +2612,23 @@ export interface StackConfig {
 	pre_deploy?: SystemCommand;
 	/** The optional command to run after the Stack is deployed. */
 	post_deploy?: SystemCommand;
+	/**
+	 * The optional command to run once the Stack has reached the
+	 * Running state after deploy, with all service health checks passing.
+	 */
+	on_deploy?: SystemCommand;
+	/**
+	 * The max seconds to wait for the Stack to become healthy
+	 * before running the `on_deploy` command. The deploy request
+	 * waits on this, so keep it within the request timeout.
+	 * If 0, runs `on_deploy` without waiting.
+	 */
+	on_deploy_timeout: U64;
+	/**
+	 * The optional command to run before the Stack is destroyed,
+	 * ie before `docker compose down`.
+	 */
+	on_destroy?: SystemCommand;
 	/**
 	 * The extra arguments to pass after `docker compose up -d`.
 	 * If empty, no extra arguments will be passed.
@@ -2285,8 +2668,15 @@ export interface FileContents {
 	path: string;
 	/** The contents of the file */
 	contents: string;
+	/**
+	 * Whether the contents are base64 encoded,
+	 * used for binary files such as certificates.
+	 */
+	base64_contents?: boolean;
 }
 
+export type CopyFileFromContainerResponse = FileContents;
+
 export interface StackServiceNames {
 	/** The name of the service */
 	service_name: string;
@@ -2322,6 +2712,12 @@ export interface StackRemoteFileContents {
 	path: string;
 	/** The contents of the file */
 	contents: string;
+	/**
+	 * Whether the contents are base64 encoded.
+	 * Files on host which are not valid UTF-8 (eg binary files)
+	 * will be base64 encoded.
+	 */
+	base64_contents?: boolean;
 	/**
 	 * The services depending on this file,
 	 * or empty for global requirement (eg all compose files and env files).
@@ -2331,6 +2727,20 @@ export interface StackRemoteFileContents {
 	requires?: StackFileRequires;
 }
 
+export interface StackServiceInfo {
+	/** The service name */
+	name: string;
+	/** The image the service container is using */
+	image: string;
+	/**
+	 * The image repo digest, eg `mongo@sha256:...`.
+	 * Only available if the image was pulled from or pushed to a registry.
+	 */
+	image_digest?: string;
+	/** The service container state */
+	state: ContainerStateStatusEnum;
+}
+
 export interface StackInfo {
 	/**
 	 * If any of the expected compose / additional files are missing in the repo,
@@ -2368,6 +2778,12 @@ export interface StackInfo {
 	 * This is updated whenever the stack cache refreshes, using the latest file contents (either db defined or remote).
 	 */
 	latest_services?: StackServiceNames[];
+	/**
+	 * The services of the deployed project currently on the host,
+	 * along with their image digests.
+	 * This is updated whenever the server status cache refreshes, using `docker compose ps`.
+	 */
+	services?: StackServiceInfo[];
 	/**
 	 * The remote compose / additional file contents, whether on host or in repo.
 	 * This is updated whenever Komodo refreshes the stack cache.
@@ -2427,6 +2843,16 @@ export interface SingleDiskUsage {
 	used_gb: number;
 	/** Total size of the disk in GB */
 	total_gb: number;
+	/**
+	 * Number of used inodes on the disk.
+	 * Zero if the file system doesn't report inodes.
+	 */
+	inodes_used?: U64;
+	/**
+	 * Total number of inodes on the disk.
+	 * Zero if the file system doesn't report inodes.
+	 */
+	inodes_total?: U64;
 }
 
 export enum Timelength {
@@ -2497,6 +2923,18 @@ export interface SystemStats {
 	network_ingress_bytes?: number;
 	/** Network egress usage in MB */
 	network_egress_bytes?: number;
+	/**
+	 * Network ingress rate in bytes per second,
+	 * summed over all non-loopback interfaces.
+	 */
+	network_ingress_bytes_per_sec?: number;
+	/**
+	 * Network egress rate in bytes per second,
+	 * summed over all non-loopback interfaces.
+	 */
+	network_egress_bytes_per_sec?: number;
+	/** Network usage by interface */
+	network_interfaces?: SingleNetworkInterfaceUsage[];
 	/** The rate the system stats are being polled from the system */
 	polling_rate: Timelength;
 	/** Unix timestamp in milliseconds when stats were last polled */
@@ -2604,6 +3042,12 @@ export interface UserGroup {
 	users?: string[];
 	/** Give the user group elevated permissions on all resources of a certain type */
 	all?: Record<ResourceTarget["type"], PermissionLevelAndSpecifics | PermissionLevel>;
+	/**
+	 * Give the user group permissions on all resources with a certain tag.
+	 * Keyed by tag id. These are resolved when permissions are checked,
+	 * so resources are included as soon as they are tagged.
+	 */
+	tags?: Record<string, PermissionLevelAndSpecifics>;
 	/** Unix time (ms) when user group last updated */
 	updated_at?: I64;
 }
@@ -3166,6 +3610,8 @@ export interface Container {
 
 export type InspectDeploymentContainerResponse = Container;
 
+export type GetDeploymentContainerEnvResponse = string[];
+
 export type InspectDockerContainerResponse = Container;
 
 /** Information about the image's RootFS, including the layer IDs. */
@@ -3416,8 +3862,78 @@ export interface Volume {
 
 export type InspectDockerVolumeResponse = Volume;
 
+/** A file or directory inside a docker volume. */
+export interface VolumeEntry {
+	/** The file / directory name */
+	name: string;
+	/** Whether the entry is a directory */
+	is_dir: boolean;
+	/** The size of the entry in bytes */
+	size_bytes: U64;
+	/** Unix timestamp in milliseconds of the last modification */
+	modified_at: I64;
+}
+
+export type GetVolumeBrowserListingResponse = VolumeEntry[];
+
 export type InspectStackContainerResponse = Container;
 
+export type GetStackContainerEnvResponse = string[];
+
+export interface ImportedResource {
+	/** The resource type. */
+	resource_type: ResourceTarget["type"];
+	/** The resource name. */
+	name: string;
+}
+
+export interface ImportResourcesFromTomlResponse {
+	/** Whether this was a dry run, ie no changes were applied. */
+	dry_run: boolean;
+	/** The resources which were (or would be) created. */
+	created: ImportedResource[];
+	/** The resources which were (or would be) updated. */
+	updated: ImportedResource[];
+	/**
+	 * The resources which already match the toml,
+	 * and were skipped.
+	 */
+	skipped: ImportedResource[];
+	/** The computed diffs for the resources. */
+	resource_updates: ResourceDiff[];
+	/** The computed diffs for the variables. */
+	variable_updates: DiffData[];
+	/** The computed diffs for the user groups. */
+	user_group_updates: DiffData[];
+	/**
+	 * The logs from applying the changes.
+	 * Empty on dry run.
+	 */
+	logs: Log[];
+}
+
+export interface PendingTomlUpdate {
+	/** The resource to update. */
+	target: ResourceTarget;
+	/**
+	 * Line diff from the current toml to the proposed toml,
+	 * with removed lines prefixed by `-` and added lines by `+`.
+	 */
+	diff: string;
+}
+
+export interface ImportToTomlResponse {
+	/**
+	 * Resources in the toml which don't exist yet.
+	 * These targets hold the resource name, as there is no id yet.
+	 */
+	pending_creates: ResourceTarget[];
+	/** Existing resources which differ from the toml. */
+	pending_updates: PendingTomlUpdate[];
+	/** Existing resources which are missing from the toml. */
+	pending_deletes: ResourceTarget[];
+}
+
 export type JsonObject = any;
 
 export type JsonValue = any;
@@ -3466,6 +3982,11 @@ export interface ContainerListItem {
 	state: ContainerStateStatusEnum;
 	/** Additional human-readable status of this container (e.g. `Exit 0`) */
 	status?: string;
+	/**
+	 * The docker healthcheck status, parsed from `status`.
+	 * None if the container has no healthcheck.
+	 */
+	health?: HealthStatusEnum;
 	/** The network mode */
 	network_mode?: string;
 	/** The network names attached to container */
@@ -3486,6 +4007,23 @@ export interface ContainerListItem {
 
 export type ListAllDockerContainersResponse = ContainerListItem[];
 
+/** Caps the requests an api key is allowed to make. */
+export interface ApiKeyPermissionCeiling {
+	/**
+	 * The max level of request the key can make.
+	 * - Read: only /read requests
+	 * - Execute: also /execute requests
+	 * - Write: also /write and /user requests, and terminals
+	 */
+	level?: PermissionLevel;
+	/**
+	 * Restrict /execute, /write, and /user requests to these
+	 * request types, eg `Deploy`. Use `Terminal` to allow terminals.
+	 * Empty allows all requests within the `level`.
+	 */
+	operations?: string[];
+}
+
 /** An api key used to authenticate requests via request headers. */
 export interface ApiKey {
 	/** Unique key associated with secret */
@@ -3500,12 +4038,48 @@ export interface ApiKey {
 	created_at: I64;
 	/** Expiry of key, or 0 if never expires */
 	expires: I64;
+	/**
+	 * Timestamp the key was last used to authenticate, or 0 if never.
+	 * Only updated once per minute.
+	 */
+	last_used_at?: I64;
+	/**
+	 * Limits what the key can do, below the permissions of the user.
+	 * Keys without a ceiling have the full permissions of the user.
+	 */
+	permission_ceiling?: ApiKeyPermissionCeiling;
 }
 
 export type ListApiKeysForServiceUserResponse = ApiKey[];
 
 export type ListApiKeysResponse = ApiKey[];
 
+/** A record of a write or execute request made to Komodo Core. */
+export interface AuditEntry {
+	/**
+	 * The Mongo ID of the audit entry.
+	 * This field is de/serialized from/to JSON as
+	 * `{ "_id": { "$oid": "..." }, ...(rest of serialized AuditEntry) }`
+	 */
+	_id?: MongoId;
+	/** The id of the user who made the request. */
+	user_id: string;
+	/** The username of the user at the time of the request. */
+	username: string;
+	/** The request type, eg `DeployStack`. */
+	request_type: string;
+	/** The resource the request acted on, when known. */
+	resource_target?: ResourceTarget;
+	/** Unix timestamp in milliseconds the request was made. */
+	timestamp: I64;
+	/** The ip of the client which made the request. */
+	ip?: string;
+	/** Whether the request succeeded. */
+	success: boolean;
+}
+
+export type ListAuditLogResponse = AuditEntry[];
+
 export interface BuildVersionResponseItem {
 	version: Version;
 	ts: I64;
@@ -3552,6 +4126,37 @@ export interface ImageHistoryResponseItem {
 
 export type ListDockerImageHistoryResponse = ImageHistoryResponseItem[];
 
+/** The local and registry digests for an image. */
+export interface ImageDigests {
+	/** The manifest digests the local image is referenced from. */
+	local: string[];
+	/** The manifest digest currently on the registry. */
+	remote: string;
+}
+
+/**
+ * The result of checking the registry for a newer
+ * version of a running image.
+ */
+export interface ImageUpdateCheck {
+	/** The type of resource running the image. */
+	resource_type: ResourceTarget["type"];
+	/**
+	 * The name of the resource running the image.
+	 * For Stacks, this is `stack | service`.
+	 */
+	resource_name: string;
+	/**
+	 * The running image, with the local digest if known.
+	 * Eg. `nginx:latest@sha256:...`
+	 */
+	current_image: string;
+	/** The image with the digest currently on the registry. */
+	latest_image: string;
+	/** Whether the registry digest differs from the local one. */
+	has_update: boolean;
+}
+
 export interface ImageListItem {
 	/** The first tag in `repo_tags`, or Id if no tags. */
 	name: string;
@@ -3644,6 +4249,8 @@ export type ListFullResourceSyncsResponse = ResourceSync[];
 
 export type ListFullServersResponse = Server[];
 
+export type ListFullServerGroupsResponse = ServerGroup[];
+
 export type ListFullStacksResponse = Stack[];
 
 export type ListGitProviderAccountsResponse = GitProviderAccount[];
@@ -3883,6 +4490,8 @@ export type ServerListItem = ResourceListItem<ServerListItemInfo>;
 
 export type ListServersResponse = ServerListItem[];
 
+export type ListServerGroupsResponse = ServerGroupListItem[];
+
 export interface StackService {
 	/** The service name */
 	service: string;
@@ -3894,6 +4503,10 @@ export interface StackService {
 	update_available: boolean;
 }
 
+export type ListServiceAccountsResponse = ServiceAccount[];
+
+export type ListUserSessionsResponse = UserSession[];
+
 export type ListStackServicesResponse = StackService[];
 
 export enum StackState {
@@ -4018,6 +4631,8 @@ export interface TerminalInfo {
 	command: string;
 	/** The size of the terminal history in memory. */
 	stored_size_kb: number;
+	/** Milliseconds since the last terminal input or output. */
+	idle_ms?: I64;
 }
 
 export type ListTerminalsResponse = TerminalInfo[];
@@ -4068,6 +4683,36 @@ export interface ServerQuerySpecifics {
 /** Server-specific query */
 export type ServerQuery = ResourceQuery<ServerQuerySpecifics>;
 
+export interface ServerGroupConfig {
+	/**
+	 * The servers in the group, by id.
+	 * Names are also accepted on create / update,
+	 * and will be converted to ids.
+	 * 
+	 * Group executions run on all the members in parallel.
+	 */
+	members?: string[];
+}
+
+export type ServerGroup = Resource<ServerGroupConfig, undefined>;
+
+export interface ServerGroupListItemInfo {
+	/** The ids of the servers in the group. */
+	members: string[];
+}
+
+export type ServerGroupListItem = ResourceListItem<ServerGroupListItemInfo>;
+
+export interface ServerGroupQuerySpecifics {
+	/**
+	 * Only include groups which have these servers as members.
+	 * If empty, don't filter by member.
+	 */
+	members?: string[];
+}
+
+export type ServerGroupQuery = ResourceQuery<ServerGroupQuerySpecifics>;
+
 export type SetLastSeenUpdateResponse = NoData;
 
 /** Response for [SignUpLocalUser]. */
@@ -4147,6 +4792,8 @@ export type _PartialServerBuilderConfig = Partial<ServerBuilderConfig>;
 
 export type _PartialServerConfig = Partial<ServerConfig>;
 
+export type _PartialServerGroupConfig = Partial<ServerGroupConfig>;
+
 export type _PartialStackConfig = Partial<StackConfig>;
 
 export type _PartialTag = Partial<Tag>;
@@ -4225,16 +4872,97 @@ export interface AwsBuilderConfig {
  * timestamped database dumps, which can be restored using
  * the Komodo CLI.
  * 
+ * Set `KOMODO_CLI_BACKUP_ENCRYPTION_KEY` in the Core
+ * environment to encrypt the backup files.
+ * 
+ * With a `remote_target`, the new backup is also uploaded to
+ * S3, GCS or Azure Blob Storage, and old remote backups are pruned
+ * using the same `max_backups` (KOMODO_CLI_MAX_BACKUPS) limit.
+ * The target is referenced by name from the Core config
+ * `backup_remote_targets`, so the credentials never
+ * appear in the request or in Procedures which run it.
+ * 
  * https://komo.do/docs/setup/backup
  */
 export interface BackupCoreDatabase {
+	/** Override the backup compression configured for the CLI. */
+	compression?: BackupCompression;
+	/**
+	 * Also upload the backup to the remote object store
+	 * configured with this name in the Core config `backup_remote_targets`.
+	 */
+	remote_target?: string;
 }
 
-/** Builds multiple Repos in parallel that match pattern. Response: [BatchExecutionResponse]. */
-export interface BatchBuildRepo {
+/**
+ * Restores the Komodo Core database from a backup folder
+ * created by [BackupCoreDatabase]. Super admin only. Response: [Update]
+ * 
+ * **This is destructive.** Each collection in the backup is dropped
+ * and recreated from the backup files, so anything created or
+ * changed since the backup is lost. Restart Core afterwards
+ * to clear any cached state.
+ * 
+ * Encrypted backups are decrypted using `KOMODO_CLI_BACKUP_ENCRYPTION_KEY`
+ * in the Core environment.
+ */
+export interface RestoreDatabase {
 	/**
-	 * Id or name or wildcard pattern or regex.
-	 * Supports multiline and comma delineated combinations of the above.
+	 * The timestamped backup folder to restore, either its name
+	 * or its full path in the Core container. It must be directly
+	 * inside the configured backups folder.
+	 * Example: `2025-08-01_05-04-53` or `/backups/2025-08-01_05-04-53`
+	 */
+	backup_path: string;
+	/** Must be exactly `I understand this is destructive`. */
+	confirmation: string;
+}
+
+/** The compression used for database backup files. */
+export enum BackupCompression {
+	/** No compression, files are written as `.jsonl`. */
+	None = "none",
+	/** Gzip compression, files are written as `.gz`. Default. */
+	Gzip = "gzip",
+	/** Zstd compression, files are written as `.zst`. */
+	Zstd = "zstd",
+}
+
+/**
+ * A remote object store to upload database backups to.
+ * The backup folders are uploaded under the `prefix`,
+ * using the same folder names as the local backups.
+ */
+export type RemoteBackupTarget = 
+	/** Upload to an AWS S3 (or S3 compatible) bucket. */
+	| { type: "S3", params: {
+	bucket: string;
+	prefix?: string;
+	region: string;
+	access_key_id: string;
+	secret_access_key: string;
+}}
+	/** Upload to a Google Cloud Storage bucket. */
+	| { type: "Gcs", params: {
+	bucket: string;
+	prefix?: string;
+	/** The service account key, in JSON format. */
+	service_account_json: string;
+}}
+	/** Upload to an Azure Blob Storage container. */
+	| { type: "AzureBlob", params: {
+	container: string;
+	/** The storage account name */
+	account: string;
+	/** The storage account access key */
+	key: string;
+}};
+
+/** Builds multiple Repos in parallel that match pattern. Response: [BatchExecutionResponse]. */
+export interface BatchBuildRepo {
+	/**
+	 * Id or name or wildcard pattern or regex.
+	 * Supports multiline and comma delineated combinations of the above.
 	 * 
 	 * Example:
 	 * ```text
@@ -4245,6 +4973,11 @@ export interface BatchBuildRepo {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 /** Clones multiple Repos in parallel that match pattern. Response: [BatchExecutionResponse]. */
@@ -4262,6 +4995,11 @@ export interface BatchCloneRepo {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 /** Deploys multiple Deployments in parallel that match pattern. Response: [BatchExecutionResponse]. */
@@ -4279,6 +5017,11 @@ export interface BatchDeploy {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 /** Deploys multiple Stacks in parallel that match pattern. Response: [BatchExecutionResponse]. */
@@ -4296,6 +5039,11 @@ export interface BatchDeployStack {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 /** Deploys multiple Stacks if changed in parallel that match pattern. Response: [BatchExecutionResponse]. */
@@ -4313,6 +5061,11 @@ export interface BatchDeployStackIfChanged {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 /** Destroys multiple Deployments in parallel that match pattern. Response: [BatchExecutionResponse]. */
@@ -4330,6 +5083,11 @@ export interface BatchDestroyDeployment {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 /** Destroys multiple Stacks in parallel that match pattern. Response: [BatchExecutionResponse]. */
@@ -4347,6 +5105,11 @@ export interface BatchDestroyStack {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 export interface BatchExecutionResponseItemErr {
@@ -4369,6 +5132,11 @@ export interface BatchPullRepo {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 /** Pulls multiple Stacks in parallel that match pattern. Response: [BatchExecutionResponse]. */
@@ -4386,6 +5154,11 @@ export interface BatchPullStack {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 /** Runs multiple Actions in parallel that match pattern. Response: [BatchExecutionResponse] */
@@ -4403,6 +5176,11 @@ export interface BatchRunAction {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 /** Runs multiple builds in parallel that match pattern. Response: [BatchExecutionResponse]. */
@@ -4420,6 +5198,11 @@ export interface BatchRunBuild {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 /** Runs multiple Procedures in parallel that match pattern. Response: [BatchExecutionResponse]. */
@@ -4437,6 +5220,11 @@ export interface BatchRunProcedure {
 	 * ```
 	 */
 	pattern: string;
+	/**
+	 * `And` to only match resources matching all the patterns.
+	 * Default: `Or`, matching resources which match any pattern.
+	 */
+	combinator?: SearchCombinator;
 }
 
 /**
@@ -4885,6 +5673,17 @@ export interface CopyServer {
 	id: string;
 }
 
+/**
+ * Creates a new server group with given `name` and the configuration
+ * of the server group at the given `id`. Response: [ServerGroup].
+ */
+export interface CopyServerGroup {
+	/** The name of the new server group. */
+	name: string;
+	/** The id of the server group to copy. */
+	id: string;
+}
+
 /**
  * Creates a new stack with given `name` and the configuration
  * of the stack at the given `id`. Response: [Stack].
@@ -4936,6 +5735,11 @@ export interface CreateApiKey {
 	 * Default is 0, which means no expiry.
 	 */
 	expires?: I64;
+	/**
+	 * Optionally limit what the key can do.
+	 * Default is None, the key has the full permissions of the user.
+	 */
+	permission_ceiling?: ApiKeyPermissionCeiling;
 }
 
 /**
@@ -4952,6 +5756,11 @@ export interface CreateApiKeyForServiceUser {
 	 * Default is 0, which means no expiry.
 	 */
 	expires?: I64;
+	/**
+	 * Optionally limit what the key can do.
+	 * Default is None, the key has the full permissions of the user.
+	 */
+	permission_ceiling?: ApiKeyPermissionCeiling;
 }
 
 /** Create a build. Response: [Build]. */
@@ -5049,6 +5858,69 @@ export interface CreateNetwork {
 	name: string;
 }
 
+/**
+ * Copy a file into a docker container on the server.
+ * Response: [Update]
+ * 
+ * Note. Requires `Execute` and `Terminal` permissions on the server.
+ * Fails if container exec is disabled in the periphery config.
+ */
+export interface CopyFileToContainer {
+	/** Server Id or name */
+	server: string;
+	/** The container name */
+	container: string;
+	/** The path to write the file to inside the container. */
+	dest_path: string;
+	/** The file contents. */
+	contents: string;
+	/**
+	 * Whether the contents are base64 encoded,
+	 * used for binary files.
+	 */
+	base64?: boolean;
+}
+
+/**
+ * Copy a file out of a docker container on the server.
+ * The contents are base64 encoded. The copy is recorded
+ * as an Update, without the contents.
+ * Response: [CopyFileFromContainerResponse].
+ * 
+ * Note. Requires `Execute` and `Terminal` permissions on the server.
+ * Fails if container exec is disabled in the periphery config.
+ */
+export interface CopyFileFromContainer {
+	/** Server Id or name */
+	server: string;
+	/** The container name */
+	container: string;
+	/** The path of the file inside the container. */
+	src_path: string;
+}
+
+/**
+ * Run a single command in a docker container on the server.
+ * The output is recorded in the logs of the returned [Update].
+ * Response: [Update]
+ * 
+ * Note. Requires `Execute` and `Terminal` permissions on the server.
+ * Fails if container exec is disabled in the periphery config.
+ */
+export interface RunContainerCommand {
+	/** Server Id or name */
+	server: string;
+	/** The container name */
+	container: string;
+	/** The command to run, with `sh -c`. */
+	command: string;
+	/**
+	 * The working directory inside the container.
+	 * If not provided, uses the container's default.
+	 */
+	workdir?: string;
+}
+
 /** Create a procedure. Response: [Procedure]. */
 export interface CreateProcedure {
 	/** The name given to newly created build. */
@@ -5098,6 +5970,48 @@ export interface CreateServer {
 	config?: _PartialServerConfig;
 }
 
+/** Create a server group. Response: [ServerGroup]. */
+export interface CreateServerGroup {
+	/** The name given to newly created server group. */
+	name: string;
+	/** Optional partial config to initialize the server group with. */
+	config?: _PartialServerGroupConfig;
+}
+
+/**
+ * **Admin only.** Create a service account.
+ * Response: [ServiceAccount].
+ */
+export interface CreateServiceAccount {
+	/** The name for the service account. */
+	name: string;
+	/** A description for the service account. */
+	description?: string;
+	/** Permissions on all resources of a certain type. */
+	permissions?: Record<ResourceTarget["type"], PermissionLevelAndSpecifics | PermissionLevel>;
+}
+
+/**
+ * **Admin only.** Create an api key for a service account.
+ * Response: [CreateApiKeyResponse].
+ */
+export interface CreateServiceAccountApiKey {
+	/** Id or name */
+	service_account: string;
+	/** The name for the api key */
+	key_name: string;
+	/**
+	 * A unix timestamp in millseconds specifying api key expire time.
+	 * Default is 0, which means no expiry.
+	 */
+	expires?: I64;
+	/**
+	 * Optionally limit what the key can do.
+	 * Default is None, the key has the full permissions of the service account.
+	 */
+	permission_ceiling?: ApiKeyPermissionCeiling;
+}
+
 /**
  * **Admin only.** Create a service user.
  * Response: [User].
@@ -5211,6 +6125,11 @@ export interface CreateVariable {
 	description?: string;
 	/** Whether to make this a secret variable. */
 	is_secret?: boolean;
+	/**
+	 * Scope the variable to a Server by id,
+	 * overriding the global variable with the same name.
+	 */
+	server_id?: string;
 }
 
 /** Configuration for a Custom alerter endpoint. */
@@ -5398,6 +6317,33 @@ export interface DeleteServer {
 	id: string;
 }
 
+/**
+ * Deletes the server group at the given id, and returns the deleted server group.
+ * Response: [ServerGroup]
+ */
+export interface DeleteServerGroup {
+	/** The id or name of the server group to delete. */
+	id: string;
+}
+
+/**
+ * **Admin only.** Delete a service account, along with its api keys.
+ * Response: [ServiceAccount].
+ */
+export interface DeleteServiceAccount {
+	/** Id or name */
+	service_account: string;
+}
+
+/**
+ * **Admin only.** Delete an api key of a service account.
+ * Response: [NoData].
+ */
+export interface DeleteServiceAccountApiKey {
+	/** The api key to delete */
+	key: string;
+}
+
 /**
  * Deletes the stack at the given id, and returns the deleted stack.
  * Response: [Stack]
@@ -5463,6 +6409,16 @@ export interface DeleteUser {
 	user: string;
 }
 
+/**
+ * **Admin only**. Revoke a login session,
+ * so its JWT can no longer be used.
+ * Response: [UserSession].
+ */
+export interface RevokeUserSession {
+	/** The session jwt id */
+	jwt_id: string;
+}
+
 /** **Admin only.** Delete a user group. Response: [UserGroup] */
 export interface DeleteUserGroup {
 	/** The id of the UserGroup */
@@ -5472,6 +6428,11 @@ export interface DeleteUserGroup {
 /** **Admin only.** Delete a variable. Response: [Variable]. */
 export interface DeleteVariable {
 	name: string;
+	/**
+	 * The Server id of a scoped variable,
+	 * or None for the global variable.
+	 */
+	server_id?: string;
 }
 
 /**
@@ -5691,11 +6652,21 @@ export interface ExportAllResourcesToToml {
 	 * Default: false
 	 */
 	include_variables?: boolean;
+	/**
+	 * Whether to include tags in the exported contents.
+	 * Default: false
+	 */
+	include_tags?: boolean;
 	/**
 	 * Whether to include user groups in the exported contents.
 	 * Default: false
 	 */
 	include_user_groups?: boolean;
+	/**
+	 * Only export resources with names prefixed by `<namespace>-`,
+	 * and strip the prefix from the exported names.
+	 */
+	namespace?: string;
 }
 
 /**
@@ -5709,6 +6680,13 @@ export interface ExportResourcesToToml {
 	user_groups?: string[];
 	/** Whether to include variables */
 	include_variables?: boolean;
+	/** Whether to include tags */
+	include_tags?: boolean;
+	/**
+	 * Only export resources with names prefixed by `<namespace>-`,
+	 * and strip the prefix from the exported names.
+	 */
+	namespace?: string;
 }
 
 /**
@@ -5821,6 +6799,16 @@ export interface GetAlertersSummaryResponse {
 	total: number;
 }
 
+/**
+ * Audit all global variables, including which resources
+ * reference them. Values are never included.
+ * Response: [GetAllVariablesResponse]
+ * 
+ * Note. Admin only.
+ */
+export interface GetAllVariables {
+}
+
 /** Get a specific build. Response: [Build]. */
 export interface GetBuild {
 	/** Id or name */
@@ -6130,6 +7118,21 @@ export interface SystemStatsRecord {
 	network_ingress_bytes?: number;
 	/** Total network egress in bytes */
 	network_egress_bytes?: number;
+	/**
+	 * Network ingress rate in bytes per second on the server's
+	 * configured network interface, or over all interfaces.
+	 */
+	network_ingress_bytes_per_sec?: number;
+	/**
+	 * Network egress rate in bytes per second on the server's
+	 * configured network interface, or over all interfaces.
+	 */
+	network_egress_bytes_per_sec?: number;
+	/**
+	 * Usage of the server's configured network interface.
+	 * Empty if none is configured.
+	 */
+	network_interfaces?: SingleNetworkInterfaceUsage[];
 }
 
 /** Response to [GetHistoricalServerStats]. */
@@ -6332,6 +7335,12 @@ export interface GetServer {
 	server: string;
 }
 
+/** Get a specific server group. Response: [ServerGroup]. */
+export interface GetServerGroup {
+	/** Id or name */
+	server_group: string;
+}
+
 /** Get current action state for the servers. Response: [ServerActionState]. */
 export interface GetServerActionState {
 	/** Id or name */
@@ -6406,6 +7415,29 @@ export interface GetStackLog {
 	timestamps?: boolean;
 }
 
+/**
+ * Get the logs for a single service in a stack.
+ * Response: [GetStackServiceLogsResponse].
+ * 
+ * The service must be one of the stack's known services.
+ * 
+ * Note. This call will hit the underlying server directly for most up to date log.
+ */
+export interface GetStackServiceLogs {
+	/** Id or name */
+	stack: string;
+	/** The service to get the logs for. */
+	service: string;
+	/**
+	 * The number of lines of the log tail to include.
+	 * Default: 100.
+	 * Max: 5000.
+	 */
+	tail: U64;
+	/** Enable `--timestamps` */
+	timestamps?: boolean;
+}
+
 /** Get a target stack's configured webhooks. Response: [GetStackWebhooksEnabledResponse]. */
 export interface GetStackWebhooksEnabled {
 	/** Id or name */
@@ -6537,15 +7569,21 @@ export interface GetUsernameResponse {
 }
 
 /**
- * List all available global variables.
+ * Get a variable by name.
  * Response: [Variable]
  * 
+ * When `server_id` is passed, returns the variable scoped to
+ * that Server if one exists, otherwise the global variable.
+ * This is the value which resources on the Server will use.
+ * 
  * Note. For non admin users making this call,
  * secret variables will have their values obscured.
  */
 export interface GetVariable {
 	/** The name of the variable to get. */
 	name: string;
+	/** Resolve the variable in the context of this Server. */
+	server_id?: string;
 }
 
 /**
@@ -6568,8 +7606,36 @@ export interface GetVersionResponse {
  * 
  * 1. `docker compose pull` any Stacks / Deployments with `poll_for_updates` or `auto_update` enabled. This will pick up any available updates.
  * 2. Redeploy Stacks / Deployments that have updates found and 'auto_update' enabled.
+ * 
+ * The filters can scope the run to specific resources,
+ * for example to update staging before production.
+ * 
+ * With `dry_run`, nothing is pulled or redeployed. Instead the
+ * registry is checked for each running image, and the results are
+ * set on the Update `output` as a list of
+ * [ImageUpdateCheck][crate::entities::docker::image::ImageUpdateCheck].
  */
 export interface GlobalAutoUpdate {
+	/**
+	 * Only report the available image updates,
+	 * without pulling or redeploying anything.
+	 */
+	dry_run?: boolean;
+	/**
+	 * Only include resources with all of these tags (id or name).
+	 * If empty, doesn't filter by tag.
+	 */
+	filter_tags?: string[];
+	/**
+	 * Only include resources on these servers (id or name).
+	 * If empty, doesn't filter by server.
+	 */
+	filter_server_ids?: string[];
+	/**
+	 * Only include these resource types, `Stack` and / or `Deployment`.
+	 * If empty, includes both.
+	 */
+	filter_resource_types?: ResourceTarget["type"][];
 }
 
 /**
@@ -6581,6 +7647,19 @@ export interface InspectDeploymentContainer {
 	deployment: string;
 }
 
+/**
+ * Get the environment variables of the Deployment's
+ * running container, as `KEY=value` lines.
+ * Secret values are redacted.
+ * Response: [GetDeploymentContainerEnvResponse].
+ * 
+ * Note. Requires the `Inspect` permission on the Deployment.
+ */
+export interface GetDeploymentContainerEnv {
+	/** Id or name */
+	deployment: string;
+}
+
 /** Inspect a docker container on the server. Response: [Container]. */
 export interface InspectDockerContainer {
 	/** Id or name */
@@ -6613,6 +7692,24 @@ export interface InspectDockerVolume {
 	volume: string;
 }
 
+/**
+ * List the files in a directory of a docker volume on the server.
+ * Response: [GetVolumeBrowserListingResponse].
+ * 
+ * Note. Requires the `Inspect` permission on the server.
+ */
+export interface GetVolumeBrowserListing {
+	/** Id or name */
+	server: string;
+	/** The volume name */
+	volume: string;
+	/**
+	 * The directory path relative to the volume root.
+	 * If empty, lists the volume root.
+	 */
+	path?: string;
+}
+
 /**
  * Inspect the docker container associated with the Stack.
  * Response: [Container].
@@ -6624,6 +7721,49 @@ export interface InspectStackContainer {
 	service: string;
 }
 
+/**
+ * Get the environment variables of a Stack service's
+ * running container, as `KEY=value` lines.
+ * Secret values are redacted.
+ * Response: [GetStackContainerEnvResponse].
+ * 
+ * Note. Requires the `Inspect` permission on the Stack.
+ */
+export interface GetStackContainerEnv {
+	/** Id or name */
+	stack: string;
+	/** The service name */
+	service: string;
+}
+
+/**
+ * Import resources, variables, and user groups from a toml string,
+ * in the same format used by Resource Syncs.
+ * Resources which already exist are updated to match,
+ * nothing is deleted, and no deploys are triggered.
+ * Response: [ImportResourcesFromTomlResponse].
+ * 
+ * Note. Admin only.
+ */
+export interface ImportResourcesFromToml {
+	/** The resource toml to import. */
+	toml: string;
+	/** Only compute the changes, without applying them. */
+	dry_run?: boolean;
+}
+
+/**
+ * Parse resource toml, in the same format used by Resource Syncs,
+ * and preview how it differs from the current resources.
+ * Nothing is changed. Response: [ImportToTomlResponse].
+ * 
+ * Note. Admin only.
+ */
+export interface ImportResourcesToToml {
+	/** The resource toml to compare against current resources. */
+	toml: string;
+}
+
 export interface LatestCommit {
 	hash: string;
 	message: string;
@@ -6706,6 +7846,29 @@ export interface ListAllDockerContainers {
 export interface ListApiKeys {
 }
 
+/**
+ * List the audit log of write and execute requests,
+ * sorted by timestamp descending. At most 1000 entries are returned.
+ * Admin only.
+ * Response: [ListAuditLogResponse].
+ */
+export interface ListAuditLog {
+	/** Only include requests made by this user id. */
+	user_id?: string;
+	/** Only include requests acting on this resource. */
+	resource_target?: ResourceTarget;
+	/**
+	 * Only include requests made at or after this
+	 * unix timestamp in milliseconds.
+	 */
+	from_ts?: I64;
+	/**
+	 * Only include requests made at or before this
+	 * unix timestamp in milliseconds. `0` means no upper bound.
+	 */
+	to_ts?: I64;
+}
+
 /**
  * **Admin only.**
  * Gets list of api keys for the user.
@@ -6925,6 +8088,12 @@ export interface ListFullServers {
 	query?: ServerQuery;
 }
 
+/** List full server groups matching optional query. Response: [ListFullServerGroupsResponse]. */
+export interface ListFullServerGroups {
+	/** Structured query to filter server groups. */
+	query?: ServerGroupQuery;
+}
+
 /** List stacks matching optional query. Response: [ListFullStacksResponse]. */
 export interface ListFullStacks {
 	/** optional structured query to filter stacks. */
@@ -6979,6 +8148,20 @@ export interface ListRepos {
 	query?: RepoQuery;
 }
 
+/**
+ * List the fields of live resources which differ from the
+ * sync files, without running the sync.
+ * Response: [ListResourceSyncConflictsResponse].
+ * 
+ * The live resources are exported when requested, and compared
+ * against the sync files as of the last sync refresh. Only resources
+ * with pending updates as of the last refresh are compared.
+ */
+export interface ListResourceSyncConflicts {
+	/** Id or name */
+	sync: string;
+}
+
 /** List syncs matching optional query. Response: [ListResourceSyncsResponse]. */
 export interface ListResourceSyncs {
 	/** optional structured query to filter syncs. */
@@ -7014,6 +8197,31 @@ export interface ListServers {
 	query?: ServerQuery;
 }
 
+/** List server groups matching optional query. Response: [ListServerGroupsResponse]. */
+export interface ListServerGroups {
+	/** Structured query to filter server groups. */
+	query?: ServerGroupQuery;
+}
+
+/**
+ * **Admin only.**
+ * Gets list of service accounts. Api key secrets are not included.
+ * Response: [ListServiceAccountsResponse]
+ */
+export interface ListServiceAccounts {
+}
+
+/**
+ * **Admin only.**
+ * Gets list of active login sessions,
+ * optionally filtered to a specific user.
+ * Response: [ListUserSessionsResponse]
+ */
+export interface ListUserSessions {
+	/** Id or username */
+	user?: string;
+}
+
 /** Lists a specific stacks services (the containers). Response: [ListStackServicesResponse]. */
 export interface ListStackServices {
 	/** Id or name */
@@ -7282,6 +8490,17 @@ export interface PruneImages {
 	server: string;
 }
 
+/**
+ * Prunes the docker images on all the servers in the group,
+ * in parallel. Response: [Update].
+ * 
+ * 1. Runs `docker image prune -a -f` on each server.
+ */
+export interface PruneImagesOnGroup {
+	/** Id or name */
+	server_group: string;
+}
+
 /**
  * Prunes the docker networks on the target server. Response: [Update].
  * 
@@ -7302,6 +8521,17 @@ export interface PruneSystem {
 	server: string;
 }
 
+/**
+ * Prunes the docker system on all the servers in the group,
+ * in parallel, including volumes. Response: [Update].
+ * 
+ * 1. Runs `docker system prune -a -f --volumes` on each server.
+ */
+export interface PruneSystemOnGroup {
+	/** Id or name */
+	server_group: string;
+}
+
 /**
  * Prunes the docker volumes on the target server. Response: [Update].
  * 
@@ -7495,6 +8725,17 @@ export interface RenameServer {
 	name: string;
 }
 
+/**
+ * Rename the ServerGroup at id to the given name.
+ * Response: [Update].
+ */
+export interface RenameServerGroup {
+	/** The id or name of the ServerGroup to rename. */
+	id: string;
+	/** The new name. */
+	name: string;
+}
+
 /** Rename the stack at id to the given name. Response: [Update]. */
 export interface RenameStack {
 	/** The id of the stack to rename. */
@@ -7519,6 +8760,20 @@ export interface RenameUserGroup {
 	name: string;
 }
 
+/**
+ * Generate a new secret for one of the calling user's api keys.
+ * The old secret stops working immediately, while the key,
+ * name, expiry, and permission ceiling are kept.
+ * Response: [RotateApiKeyResponse].
+ * 
+ * Note. After the response is served, there will be no way
+ * to get the secret later.
+ */
+export interface RotateApiKey {
+	/** The key which the user intends to rotate. */
+	key: string;
+}
+
 export enum DefaultRepoFolder {
 	/** /${root_directory}/stacks */
 	Stacks = "Stacks",
@@ -7559,6 +8814,13 @@ export interface RepoExecutionArgs {
 	 * Depends on the resource type.
 	 */
 	default_folder: DefaultRepoFolder;
+	/**
+	 * Only check out these paths using `git sparse-checkout`.
+	 * Empty means the whole repo is checked out.
+	 */
+	sparse_checkout?: string[];
+	/** Make a shallow clone with this depth. 0 means a full clone. */
+	clone_depth?: number;
 }
 
 export interface RepoExecutionResponse {
@@ -7600,6 +8862,13 @@ export interface ResourceToml<PartialConfig> {
 	config?: PartialConfig;
 }
 
+export interface TagToml {
+	/** Tag name */
+	name: string;
+	/** Tag color. Default: Slate. */
+	color?: TagColor;
+}
+
 export interface UserGroupToml {
 	/** User group name */
 	name: string;
@@ -7625,6 +8894,8 @@ export interface ResourcesToml {
 	alerters?: ResourceToml<_PartialAlerterConfig>[];
 	builders?: ResourceToml<_PartialBuilderConfig>[];
 	resource_syncs?: ResourceToml<_PartialResourceSyncConfig>[];
+	server_groups?: ResourceToml<_PartialServerGroupConfig>[];
+	tags?: TagToml[];
 	user_groups?: UserGroupToml[];
 	variables?: Variable[];
 }
@@ -7701,6 +8972,25 @@ export interface RunBuild {
 	build: string;
 }
 
+/**
+ * Runs a shell command on all the servers in the group,
+ * in parallel. Response: [Update].
+ * 
+ * Requires the `Terminal` permission on the server group,
+ * and is skipped on member servers with terminals disabled.
+ */
+export interface RunCommandOnGroup {
+	/** Id or name */
+	server_group: string;
+	/** The command to run. */
+	command: string;
+	/**
+	 * The directory to run the command in.
+	 * If empty, uses the Periphery working directory.
+	 */
+	path?: string;
+}
+
 /** Runs the target Procedure. Response: [Update] */
 export interface RunProcedure {
 	/** Id or name */
@@ -7750,6 +9040,11 @@ export interface RunSync {
 	 * Supports name or id.
 	 */
 	resources?: string[];
+	/**
+	 * Only compute the changes and log them to the Update,
+	 * without executing any of them.
+	 */
+	dry_run?: boolean;
 }
 
 export enum SearchCombinator {
@@ -7870,6 +9165,11 @@ export interface ServerHealth {
 	cpu: ServerHealthState;
 	mem: ServerHealthState;
 	disks: Record<string, ServerHealthState>;
+	/**
+	 * Only includes disks reporting inodes,
+	 * when inode thresholds are configured.
+	 */
+	inodes?: Record<string, ServerHealthState>;
 }
 
 /**
@@ -7924,16 +9224,37 @@ export interface SignUpLocalUser {
 export interface SingleNetworkInterfaceUsage {
 	/** The network interface name */
 	name: string;
-	/** The ingress in bytes */
+	/** The ingress in bytes since the last poll */
 	ingress_bytes: number;
-	/** The egress in bytes */
+	/** The egress in bytes since the last poll */
 	egress_bytes: number;
+	/** The total received bytes counter */
+	total_ingress_bytes?: number;
+	/** The total transmitted bytes counter */
+	total_egress_bytes?: number;
+	/** The ingress rate in bytes per second */
+	ingress_bytes_per_sec?: number;
+	/** The egress rate in bytes per second */
+	egress_bytes_per_sec?: number;
 }
 
 /** Configuration for a Slack alerter. */
 export interface SlackAlerterEndpoint {
 	/** The Slack app webhook url */
 	url: string;
+	/**
+	 * Optionally post to this channel instead of the
+	 * webhook default, eg. `#alerts`.
+	 * If empty, uses the webhook default.
+	 */
+	channel?: string;
+	/** Optionally override the username the alert is posted with. */
+	username?: string;
+	/**
+	 * Optionally override the icon the alert is posted with,
+	 * eg. `:lizard:`.
+	 */
+	icon_emoji?: string;
 }
 
 /** Sleeps for the specified time. */
@@ -8327,6 +9648,21 @@ export interface UpdateServer {
 	config: _PartialServerConfig;
 }
 
+/**
+ * Update the server group at the given id, and return the updated server group. Response: [ServerGroup].
+ * 
+ * Note. This method updates only the fields which are set in the [PartialServerGroupConfig][crate::entities::server_group::PartialServerGroupConfig],
+ * effectively merging diffs into the final document. This is helpful when multiple users are using
+ * the same resources concurrently by ensuring no unintentional
+ * field changes occur from out of date local state.
+ */
+export interface UpdateServerGroup {
+	/** The id of the server group to update. */
+	id: string;
+	/** The partial config update to apply. */
+	config: _PartialServerGroupConfig;
+}
+
 /**
  * **Admin only.** Update a service user's description.
  * Response: [User].
@@ -8392,6 +9728,21 @@ export interface UpdateUserBasePermissions {
 	create_builds?: boolean;
 }
 
+/**
+ * **Admin only.** Give the User Group permissions on all
+ * resources with the given tag. Setting the level to `None`
+ * with no specific permissions removes the tag permission.
+ * Response: [UserGroup]
+ */
+export interface UpdateUserGroupTagPermission {
+	/** Id or name. */
+	user_group: string;
+	/** Tag id or name. */
+	tag: string;
+	/** The permission to give on resources with the tag. */
+	permission: PermissionLevelAndSpecifics;
+}
+
 /**
  * **Only for local users**. Update the calling users password.
  * Response: [NoData].
@@ -8414,6 +9765,11 @@ export interface UpdateVariableDescription {
 	name: string;
 	/** The description to set. */
 	description: string;
+	/**
+	 * The Server id of a scoped variable,
+	 * or None for the global variable.
+	 */
+	server_id?: string;
 }
 
 /** **Admin only.** Update whether variable is secret. Response: [Variable]. */
@@ -8422,6 +9778,11 @@ export interface UpdateVariableIsSecret {
 	name: string;
 	/** Whether variable is secret. */
 	is_secret: boolean;
+	/**
+	 * The Server id of a scoped variable,
+	 * or None for the global variable.
+	 */
+	server_id?: string;
 }
 
 /** **Admin only.** Update variable value. Response: [Variable]. */
@@ -8430,6 +9791,11 @@ export interface UpdateVariableValue {
 	name: string;
 	/** The value to set. */
 	value: string;
+	/**
+	 * The Server id of a scoped variable,
+	 * or None for the global variable.
+	 */
+	server_id?: string;
 }
 
 /** Configuration for a Komodo Url Builder. */
@@ -8459,6 +9825,12 @@ export interface WriteStackFileContents {
 	file_path: string;
 	/** The contents to write. */
 	contents: string;
+	/**
+	 * Whether the contents are base64 encoded,
+	 * and should be decoded before writing.
+	 * Use for binary files such as certificates.
+	 */
+	base64_contents?: boolean;
 }
 
 /** Rename the stack at id to the given name. Response: [Update]. */
@@ -8516,6 +9888,9 @@ export type ExecuteRequest =
 	| { type: "PruneDockerBuilders", params: PruneDockerBuilders }
 	| { type: "PruneBuildx", params: PruneBuildx }
 	| { type: "PruneSystem", params: PruneSystem }
+	| { type: "RunCommandOnGroup", params: RunCommandOnGroup }
+	| { type: "PruneImagesOnGroup", params: PruneImagesOnGroup }
+	| { type: "PruneSystemOnGroup", params: PruneSystemOnGroup }
 	| { type: "DeployStack", params: DeployStack }
 	| { type: "BatchDeployStack", params: BatchDeployStack }
 	| { type: "DeployStackIfChanged", params: DeployStackIfChanged }
@@ -8559,6 +9934,7 @@ export type ExecuteRequest =
 	| { type: "RunSync", params: RunSync }
 	| { type: "ClearRepoCache", params: ClearRepoCache }
 	| { type: "BackupCoreDatabase", params: BackupCoreDatabase }
+	| { type: "RestoreDatabase", params: RestoreDatabase }
 	| { type: "GlobalAutoUpdate", params: GlobalAutoUpdate };
 
 /**
@@ -8657,10 +10033,13 @@ export type ReadRequest =
 	| { type: "GetPermission", params: GetPermission }
 	| { type: "FindUser", params: FindUser }
 	| { type: "ListUsers", params: ListUsers }
+	| { type: "ListServiceAccounts", params: ListServiceAccounts }
+	| { type: "ListUserSessions", params: ListUserSessions }
 	| { type: "ListApiKeys", params: ListApiKeys }
 	| { type: "ListApiKeysForServiceUser", params: ListApiKeysForServiceUser }
 	| { type: "ListPermissions", params: ListPermissions }
 	| { type: "ListUserTargetPermissions", params: ListUserTargetPermissions }
+	| { type: "ListAuditLog", params: ListAuditLog }
 	| { type: "GetUserGroup", params: GetUserGroup }
 	| { type: "ListUserGroups", params: ListUserGroups }
 	| { type: "GetProceduresSummary", params: GetProceduresSummary }
@@ -8690,6 +10069,7 @@ export type ReadRequest =
 	| { type: "InspectDockerImage", params: InspectDockerImage }
 	| { type: "ListDockerImageHistory", params: ListDockerImageHistory }
 	| { type: "InspectDockerVolume", params: InspectDockerVolume }
+	| { type: "GetVolumeBrowserListing", params: GetVolumeBrowserListing }
 	| { type: "GetDockerContainersSummary", params: GetDockerContainersSummary }
 	| { type: "ListAllDockerContainers", params: ListAllDockerContainers }
 	| { type: "ListDockerContainers", params: ListDockerContainers }
@@ -8698,6 +10078,9 @@ export type ReadRequest =
 	| { type: "ListDockerVolumes", params: ListDockerVolumes }
 	| { type: "ListComposeProjects", params: ListComposeProjects }
 	| { type: "ListTerminals", params: ListTerminals }
+	| { type: "GetServerGroup", params: GetServerGroup }
+	| { type: "ListServerGroups", params: ListServerGroups }
+	| { type: "ListFullServerGroups", params: ListFullServerGroups }
 	| { type: "GetSystemInformation", params: GetSystemInformation }
 	| { type: "GetSystemStats", params: GetSystemStats }
 	| { type: "ListSystemProcesses", params: ListSystemProcesses }
@@ -8706,8 +10089,10 @@ export type ReadRequest =
 	| { type: "GetStackActionState", params: GetStackActionState }
 	| { type: "GetStackWebhooksEnabled", params: GetStackWebhooksEnabled }
 	| { type: "GetStackLog", params: GetStackLog }
+	| { type: "GetStackServiceLogs", params: GetStackServiceLogs }
 	| { type: "SearchStackLog", params: SearchStackLog }
 	| { type: "InspectStackContainer", params: InspectStackContainer }
+	| { type: "GetStackContainerEnv", params: GetStackContainerEnv }
 	| { type: "ListStacks", params: ListStacks }
 	| { type: "ListFullStacks", params: ListFullStacks }
 	| { type: "ListStackServices", params: ListStackServices }
@@ -8721,6 +10106,7 @@ export type ReadRequest =
 	| { type: "GetDeploymentLog", params: GetDeploymentLog }
 	| { type: "SearchDeploymentLog", params: SearchDeploymentLog }
 	| { type: "InspectDeploymentContainer", params: InspectDeploymentContainer }
+	| { type: "GetDeploymentContainerEnv", params: GetDeploymentContainerEnv }
 	| { type: "ListDeployments", params: ListDeployments }
 	| { type: "ListFullDeployments", params: ListFullDeployments }
 	| { type: "ListCommonDeploymentExtraArgs", params: ListCommonDeploymentExtraArgs }
@@ -8742,6 +10128,7 @@ export type ReadRequest =
 	| { type: "GetResourceSyncsSummary", params: GetResourceSyncsSummary }
 	| { type: "GetResourceSync", params: GetResourceSync }
 	| { type: "GetResourceSyncActionState", params: GetResourceSyncActionState }
+	| { type: "ListResourceSyncConflicts", params: ListResourceSyncConflicts }
 	| { type: "GetSyncWebhooksEnabled", params: GetSyncWebhooksEnabled }
 	| { type: "ListResourceSyncs", params: ListResourceSyncs }
 	| { type: "ListFullResourceSyncs", params: ListFullResourceSyncs }
@@ -8755,6 +10142,7 @@ export type ReadRequest =
 	| { type: "ListFullAlerters", params: ListFullAlerters }
 	| { type: "ExportAllResourcesToToml", params: ExportAllResourcesToToml }
 	| { type: "ExportResourcesToToml", params: ExportResourcesToToml }
+	| { type: "ImportResourcesToToml", params: ImportResourcesToToml }
 	| { type: "GetTag", params: GetTag }
 	| { type: "ListTags", params: ListTags }
 	| { type: "GetUpdate", params: GetUpdate }
@@ -8763,6 +10151,7 @@ export type ReadRequest =
 	| { type: "GetAlert", params: GetAlert }
 	| { type: "GetVariable", params: GetVariable }
 	| { type: "ListVariables", params: ListVariables }
+	| { type: "GetAllVariables", params: GetAllVariables }
 	| { type: "GetGitProviderAccount", params: GetGitProviderAccount }
 	| { type: "ListGitProviderAccounts", params: ListGitProviderAccounts }
 	| { type: "GetDockerRegistryAccount", params: GetDockerRegistryAccount }
@@ -8811,17 +10200,23 @@ export type UserRequest =
 	| { type: "PushRecentlyViewed", params: PushRecentlyViewed }
 	| { type: "SetLastSeenUpdate", params: SetLastSeenUpdate }
 	| { type: "CreateApiKey", params: CreateApiKey }
-	| { type: "DeleteApiKey", params: DeleteApiKey };
+	| { type: "DeleteApiKey", params: DeleteApiKey }
+	| { type: "RotateApiKey", params: RotateApiKey };
 
 export type WriteRequest = 
 	| { type: "CreateLocalUser", params: CreateLocalUser }
 	| { type: "UpdateUserUsername", params: UpdateUserUsername }
 	| { type: "UpdateUserPassword", params: UpdateUserPassword }
 	| { type: "DeleteUser", params: DeleteUser }
+	| { type: "RevokeUserSession", params: RevokeUserSession }
 	| { type: "CreateServiceUser", params: CreateServiceUser }
 	| { type: "UpdateServiceUserDescription", params: UpdateServiceUserDescription }
 	| { type: "CreateApiKeyForServiceUser", params: CreateApiKeyForServiceUser }
 	| { type: "DeleteApiKeyForServiceUser", params: DeleteApiKeyForServiceUser }
+	| { type: "CreateServiceAccount", params: CreateServiceAccount }
+	| { type: "DeleteServiceAccount", params: DeleteServiceAccount }
+	| { type: "CreateServiceAccountApiKey", params: CreateServiceAccountApiKey }
+	| { type: "DeleteServiceAccountApiKey", params: DeleteServiceAccountApiKey }
 	| { type: "CreateUserGroup", params: CreateUserGroup }
 	| { type: "RenameUserGroup", params: RenameUserGroup }
 	| { type: "DeleteUserGroup", params: DeleteUserGroup }
@@ -8829,6 +10224,7 @@ export type WriteRequest =
 	| { type: "RemoveUserFromUserGroup", params: RemoveUserFromUserGroup }
 	| { type: "SetUsersInUserGroup", params: SetUsersInUserGroup }
 	| { type: "SetEveryoneUserGroup", params: SetEveryoneUserGroup }
+	| { type: "UpdateUserGroupTagPermission", params: UpdateUserGroupTagPermission }
 	| { type: "UpdateUserAdmin", params: UpdateUserAdmin }
 	| { type: "UpdateUserBasePermissions", params: UpdateUserBasePermissions }
 	| { type: "UpdatePermissionOnResourceType", params: UpdatePermissionOnResourceType }
@@ -8840,9 +10236,17 @@ export type WriteRequest =
 	| { type: "UpdateServer", params: UpdateServer }
 	| { type: "RenameServer", params: RenameServer }
 	| { type: "CreateNetwork", params: CreateNetwork }
+	| { type: "CopyFileToContainer", params: CopyFileToContainer }
+	| { type: "CopyFileFromContainer", params: CopyFileFromContainer }
+	| { type: "RunContainerCommand", params: RunContainerCommand }
 	| { type: "CreateTerminal", params: CreateTerminal }
 	| { type: "DeleteTerminal", params: DeleteTerminal }
 	| { type: "DeleteAllTerminals", params: DeleteAllTerminals }
+	| { type: "CreateServerGroup", params: CreateServerGroup }
+	| { type: "CopyServerGroup", params: CopyServerGroup }
+	| { type: "DeleteServerGroup", params: DeleteServerGroup }
+	| { type: "UpdateServerGroup", params: UpdateServerGroup }
+	| { type: "RenameServerGroup", params: RenameServerGroup }
 	| { type: "CreateStack", params: CreateStack }
 	| { type: "CopyStack", params: CopyStack }
 	| { type: "DeleteStack", params: DeleteStack }
@@ -8902,6 +10306,7 @@ export type WriteRequest =
 	| { type: "RenameResourceSync", params: RenameResourceSync }
 	| { type: "WriteSyncFileContents", params: WriteSyncFileContents }
 	| { type: "CommitSync", params: CommitSync }
+	| { type: "ImportResourcesFromToml", params: ImportResourcesFromToml }
 	| { type: "RefreshResourceSyncPending", params: RefreshResourceSyncPending }
 	| { type: "CreateSyncWebhook", params: CreateSyncWebhook }
 	| { type: "DeleteSyncWebhook", params: DeleteSyncWebhook }
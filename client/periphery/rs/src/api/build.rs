@@ -23,12 +23,40 @@ pub struct Build {
   /// Add more tags for this build in addition to the version tags.
   #[serde(default)]
   pub additional_tags: Vec<String>,
+  /// Report build progress for [GetBuildProgress].
+  /// Only has an effect when the build uses buildx.
+  #[serde(default)]
+  pub track_progress: bool,
 }
 
 pub type BuildResponse = Vec<Log>;
 
 //
 
+/// Get the progress of an in progress build,
+/// parsed from the docker build output.
+/// Returns None if the build isn't running,
+/// or no build steps have been reported yet.
+#[derive(Serialize, Deserialize, Debug, Clone, Resolve)]
+#[response(GetBuildProgressResponse)]
+#[error(serror::Error)]
+pub struct GetBuildProgress {
+  /// The name of the build
+  pub name: String,
+}
+
+pub type GetBuildProgressResponse = Option<BuildProgress>;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct BuildProgress {
+  /// The current build step
+  pub step: u32,
+  /// The total steps in the current build stage
+  pub total: u32,
+}
+
+//
+
 /// Get the dockerfile contents on the host, for builds using
 /// `files_on_host`.
 #[derive(Debug, Clone, Serialize, Deserialize, Resolve)]
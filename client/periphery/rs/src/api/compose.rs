@@ -3,7 +3,7 @@ use komodo_client::entities::{
   repo::Repo,
   stack::{
     ComposeProject, Stack, StackFileDependency,
-    StackRemoteFileContents, StackServiceNames,
+    StackRemoteFileContents, StackServiceInfo, StackServiceNames,
   },
   update::Log,
 };
@@ -23,6 +23,19 @@ pub struct ListComposeProjects {}
 
 //
 
+/// List the services of a compose project on the host,
+/// along with their image digests.
+/// Runs `docker compose -p <project> ps --all --format json`
+#[derive(Debug, Clone, Serialize, Deserialize, Resolve)]
+#[response(Vec<StackServiceInfo>)]
+#[error(serror::Error)]
+pub struct ListComposeServices {
+  /// The name of the project
+  pub project: String,
+}
+
+//
+
 /// Get the compose contents on the host, for stacks using
 /// `files_on_host`.
 #[derive(Debug, Clone, Serialize, Deserialize, Resolve)]
@@ -111,6 +124,10 @@ pub struct WriteComposeContentsToHost {
   pub file_path: String,
   /// The contents to write.
   pub contents: String,
+  /// Whether the contents are base64 encoded,
+  /// and should be decoded before writing.
+  #[serde(default)]
+  pub base64_contents: bool,
 }
 
 //
@@ -131,6 +148,10 @@ pub struct WriteCommitComposeContents {
   pub file_path: String,
   /// The contents to write.
   pub contents: String,
+  /// Whether the contents are base64 encoded,
+  /// and should be decoded before writing.
+  #[serde(default)]
+  pub base64_contents: bool,
   /// If provided, use it to login in. Otherwise check periphery local git providers.
   pub git_token: Option<String>,
 }
@@ -193,6 +214,10 @@ pub struct ComposeUp {
   /// Propogate any secret replacers from core interpolation.
   #[serde(default)]
   pub replacers: Vec<(String, String)>,
+  /// Set `COMPOSE_PARALLEL_LIMIT` for the build / pull / up commands.
+  /// If null, uses the docker compose default.
+  #[serde(default)]
+  pub parallel_limit: Option<u32>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -244,6 +269,23 @@ pub struct ComposeExecution {
 
 //
 
+/// Runs the Stack `on_destroy` command in the Stack run directory.
+/// Used before `docker compose down` when the Stack is destroyed.
+#[derive(Debug, Clone, Serialize, Deserialize, Resolve)]
+#[response(Log)]
+#[error(serror::Error)]
+pub struct ComposeOnDestroy {
+  /// The stack being destroyed
+  pub stack: Stack,
+  /// The linked repo, if it exists.
+  pub repo: Option<Repo>,
+  /// Propogate any secret replacers from core interpolation.
+  #[serde(default)]
+  pub replacers: Vec<(String, String)>,
+}
+
+//
+
 /// docker compose run one-time service execution.
 #[derive(Debug, Clone, Serialize, Deserialize, Resolve)]
 #[response(Log)]
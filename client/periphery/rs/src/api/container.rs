@@ -1,5 +1,5 @@
 use komodo_client::entities::{
-  SearchCombinator, TerminationSignal,
+  FileContents, SearchCombinator, TerminationSignal,
   deployment::Deployment,
   docker::{
     container::{Container, ContainerStats},
@@ -21,6 +21,62 @@ pub struct InspectContainer {
 
 //
 
+/// Get the environment variables of the container,
+/// as `KEY=value` lines.
+#[derive(Serialize, Deserialize, Debug, Clone, Resolve)]
+#[response(Vec<String>)]
+#[error(serror::Error)]
+pub struct GetContainerEnv {
+  pub container: String,
+}
+
+//
+
+/// Run a single command in the container with `docker exec`,
+/// and return the output once it finishes.
+#[derive(Serialize, Deserialize, Debug, Clone, Resolve)]
+#[response(Log)]
+#[error(serror::Error)]
+pub struct ExecInContainer {
+  pub container: String,
+  /// Run with `sh -c`.
+  pub command: String,
+  /// The working directory inside the container.
+  /// If not provided, uses the container's default.
+  pub workdir: Option<String>,
+}
+
+//
+
+/// Copy a file out of the container with `docker cp`.
+/// The contents are always base64 encoded.
+#[derive(Serialize, Deserialize, Debug, Clone, Resolve)]
+#[response(FileContents)]
+#[error(serror::Error)]
+pub struct CopyFileFromContainer {
+  pub container: String,
+  /// The path of the file inside the container.
+  pub src_path: String,
+}
+
+//
+
+/// Copy a file into the container with `docker cp`.
+#[derive(Serialize, Deserialize, Debug, Clone, Resolve)]
+#[response(Log)]
+#[error(serror::Error)]
+pub struct CopyFileToContainer {
+  pub container: String,
+  /// The path to write the file to inside the container.
+  pub dest_path: String,
+  pub contents: String,
+  /// Whether the contents are base64 encoded.
+  #[serde(default)]
+  pub base64: bool,
+}
+
+//
+
 #[derive(Serialize, Deserialize, Debug, Clone, Resolve)]
 #[response(Log)]
 #[error(serror::Error)]
@@ -1,5 +1,5 @@
 use komodo_client::entities::{
-  docker::image::{Image, ImageHistoryResponseItem},
+  docker::image::{Image, ImageDigests, ImageHistoryResponseItem},
   update::Log,
 };
 use resolver_api::Resolve;
@@ -39,6 +39,22 @@ pub struct PullImage {
 
 //
 
+/// Gets the local image digests along with the digest currently
+/// on the registry, without pulling the image.
+#[derive(Debug, Clone, Serialize, Deserialize, Resolve)]
+#[response(ImageDigests)]
+#[error(serror::Error)]
+pub struct GetImageDigests {
+  /// The name of the image.
+  pub name: String,
+  /// Optional account to use to inspect the image
+  pub account: Option<String>,
+  /// Override registry token for account with one sent from core.
+  pub token: Option<String>,
+}
+
+//
+
 #[derive(Serialize, Deserialize, Debug, Clone, Resolve)]
 #[response(Log)]
 #[error(serror::Error)]
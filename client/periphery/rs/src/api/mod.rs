@@ -29,8 +29,21 @@ pub mod volume;
 #[error(serror::Error)]
 pub struct GetHealth {}
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct GetHealthResponse {}
+/// Defaults are for compatibility with older Periphery,
+/// which responds with an empty object.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct GetHealthResponse {
+  /// Whether the docker daemon responds.
+  pub docker_available: bool,
+  /// Whether the repo, stack, and build directories
+  /// each have at least 1 GB of free disk space.
+  pub disk_space_ok: bool,
+  /// The Periphery version.
+  pub version: String,
+  /// The seconds since Periphery started.
+  pub uptime_seconds: u64,
+}
 
 //
 
@@ -1,4 +1,7 @@
-use komodo_client::entities::{docker::volume::Volume, update::Log};
+use komodo_client::entities::{
+  docker::volume::{Volume, VolumeEntry},
+  update::Log,
+};
 use resolver_api::Resolve;
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +16,21 @@ pub struct InspectVolume {
 
 //
 
+/// List the files in a directory of the volume.
+/// The volume is mounted read-only into a temporary
+/// alpine container to read the listing.
+#[derive(Debug, Clone, Serialize, Deserialize, Resolve)]
+#[response(Vec<VolumeEntry>)]
+#[error(serror::Error)]
+pub struct GetVolumeBrowserListing {
+  pub volume: String,
+  /// The directory path relative to the volume root.
+  #[serde(default)]
+  pub path: String,
+}
+
+//
+
 #[derive(Serialize, Deserialize, Debug, Clone, Resolve)]
 #[response(Log)]
 #[error(serror::Error)]
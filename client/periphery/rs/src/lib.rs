@@ -1,4 +1,9 @@
-use std::{sync::OnceLock, time::Duration};
+use std::{
+  collections::HashMap,
+  path::{Path, PathBuf},
+  sync::{Mutex, OnceLock},
+  time::{Duration, SystemTime},
+};
 
 use anyhow::Context;
 use reqwest::StatusCode;
@@ -22,10 +27,52 @@ fn periphery_http_client() -> &'static reqwest::Client {
   })
 }
 
+/// Clients authenticating with a client certificate (mTLS),
+/// by the path to the certificate / key PEM file.
+/// Clients are rebuilt when the file is modified,
+/// so rotated certificates are picked up without a restart.
+fn periphery_mtls_http_client(
+  path: &Path,
+) -> anyhow::Result<reqwest::Client> {
+  static PERIPHERY_MTLS_HTTP_CLIENTS: OnceLock<
+    Mutex<HashMap<PathBuf, (SystemTime, reqwest::Client)>>,
+  > = OnceLock::new();
+  let modified = std::fs::metadata(path)
+    .and_then(|metadata| metadata.modified())
+    .with_context(|| {
+      format!("Failed to read client cert at {path:?}")
+    })?;
+  let mut clients = PERIPHERY_MTLS_HTTP_CLIENTS
+    .get_or_init(Default::default)
+    .lock()
+    .unwrap();
+  if let Some((cached_modified, client)) = clients.get(path)
+    && *cached_modified == modified
+  {
+    return Ok(client.clone());
+  }
+  let pem = std::fs::read(path).with_context(|| {
+    format!("Failed to read client cert at {path:?}")
+  })?;
+  let identity = reqwest::Identity::from_pem(&pem)
+    .context("Invalid client cert / key")?;
+  let client = reqwest::Client::builder()
+    // Use to allow communication with Periphery self-signed certs.
+    .danger_accept_invalid_certs(true)
+    .identity(identity)
+    .build()
+    .context("Failed to build Periphery mTLS http client")?;
+  clients.insert(path.to_path_buf(), (modified, client.clone()));
+  Ok(client)
+}
+
+#[derive(Clone)]
 pub struct PeripheryClient {
   address: String,
   passkey: String,
   timeout: Duration,
+  request_timeout: Option<Duration>,
+  client_cert_path: Option<PathBuf>,
 }
 
 impl PeripheryClient {
@@ -38,6 +85,38 @@ impl PeripheryClient {
       address: address.into(),
       passkey: passkey.into(),
       timeout: timeout.into(),
+      request_timeout: None,
+      client_cert_path: None,
+    }
+  }
+
+  /// Set a timeout for the requests made with [PeripheryClient::request].
+  /// By default only the health check before each request is
+  /// bounded (by the server timeout), and the request itself
+  /// can take as long as it needs, eg for builds.
+  pub fn with_request_timeout(
+    mut self,
+    timeout: impl Into<Duration>,
+  ) -> PeripheryClient {
+    self.request_timeout = Some(timeout.into());
+    self
+  }
+
+  /// Authenticate with the client certificate and key
+  /// in the PEM file at `path`, for Periphery using mTLS.
+  /// The file is read on first use, and read again when it is modified.
+  pub fn with_client_cert(
+    mut self,
+    path: impl Into<PathBuf>,
+  ) -> PeripheryClient {
+    self.client_cert_path = Some(path.into());
+    self
+  }
+
+  fn http_client(&self) -> anyhow::Result<reqwest::Client> {
+    match &self.client_cert_path {
+      Some(path) => periphery_mtls_http_client(path),
+      None => Ok(periphery_http_client().clone()),
     }
   }
 
@@ -58,7 +137,7 @@ impl PeripheryClient {
     tracing::debug!("running health check");
     self.health_check().await?;
     tracing::debug!("health check passed. running inner request");
-    self.request_inner(request, None).await
+    self.request_inner(request, self.request_timeout).await
   }
 
   #[tracing::instrument(level = "debug", skip(self))]
@@ -83,7 +162,8 @@ impl PeripheryClient {
     tracing::trace!(
       "sending request | type: {req_type} | body: {request:?}"
     );
-    let mut req = periphery_http_client()
+    let mut req = self
+      .http_client()?
       .post(&self.address)
       .json(&json!({
         "type": req_type,
@@ -1,9 +1,13 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use anyhow::Context;
 use komodo_client::terminal::TerminalStreamResponse;
 use reqwest::RequestBuilder;
-use rustls::{ClientConfig, client::danger::ServerCertVerifier};
+use rustls::{
+  ClientConfig,
+  client::danger::ServerCertVerifier,
+  pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+};
 use tokio::net::TcpStream;
 use tokio_tungstenite::{Connector, MaybeTlsStream, WebSocketStream};
 
@@ -36,7 +40,7 @@ impl PeripheryClient {
       self.address.replacen("http", "ws", 1)
     );
 
-    connect_websocket(&url).await
+    connect_websocket(&url, self.client_cert_path.as_deref()).await
   }
 
   /// Executes command on specified terminal,
@@ -61,7 +65,8 @@ impl PeripheryClient {
     tracing::trace!(
       "sending request | type: ExecuteTerminal | terminal name: {terminal} | command: {command}",
     );
-    let req = crate::periphery_http_client()
+    let req = self
+      .http_client()?
       .post(format!("{}/terminal/execute", self.address))
       .json(&ExecuteTerminalBody { terminal, command })
       .header("authorization", &self.passkey);
@@ -96,7 +101,7 @@ impl PeripheryClient {
       self.address.replacen("http", "ws", 1)
     );
 
-    connect_websocket(&url).await
+    connect_websocket(&url, self.client_cert_path.as_deref()).await
   }
 
   /// Executes command on specified container,
@@ -122,7 +127,8 @@ impl PeripheryClient {
     tracing::trace!(
       "sending request | type: ExecuteContainerExec | container: {container} | shell: {shell} | command: {command}",
     );
-    let req = crate::periphery_http_client()
+    let req = self
+      .http_client()?
       .post(format!("{}/terminal/execute/container", self.address))
       .json(&ExecuteContainerExecBody {
         container,
@@ -136,20 +142,16 @@ impl PeripheryClient {
 
 async fn connect_websocket(
   url: &str,
+  client_cert_path: Option<&Path>,
 ) -> anyhow::Result<WebSocketStream<MaybeTlsStream<TcpStream>>> {
   let (stream, _) = if url.starts_with("wss") {
     tokio_tungstenite::connect_async_tls_with_config(
       url,
       None,
       false,
-      Some(Connector::Rustls(Arc::new(
-        ClientConfig::builder()
-          .dangerous()
-          .with_custom_certificate_verifier(Arc::new(
-            InsecureVerifier,
-          ))
-          .with_no_client_auth(),
-      ))),
+      Some(Connector::Rustls(Arc::new(tls_client_config(
+        client_cert_path,
+      )?))),
     )
     .await
     .with_context(|| {
@@ -164,6 +166,28 @@ async fn connect_websocket(
   Ok(stream)
 }
 
+fn tls_client_config(
+  client_cert_path: Option<&Path>,
+) -> anyhow::Result<ClientConfig> {
+  let builder = ClientConfig::builder()
+    .dangerous()
+    .with_custom_certificate_verifier(Arc::new(InsecureVerifier));
+  let Some(path) = client_cert_path else {
+    return Ok(builder.with_no_client_auth());
+  };
+  let certs = CertificateDer::pem_file_iter(path)
+    .with_context(|| {
+      format!("Failed to read client cert at {path:?}")
+    })?
+    .collect::<Result<Vec<_>, _>>()
+    .context("Invalid client cert")?;
+  let key = PrivateKeyDer::from_pem_file(path)
+    .context("Failed to read client key")?;
+  builder
+    .with_client_auth_cert(certs, key)
+    .context("Invalid client cert / key")
+}
+
 async fn terminal_stream_response(
   req: RequestBuilder,
 ) -> anyhow::Result<TerminalStreamResponse> {
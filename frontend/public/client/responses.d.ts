@@ -11,6 +11,7 @@ export type UserResponses = {
     SetLastSeenUpdate: Types.SetLastSeenUpdateResponse;
     CreateApiKey: Types.CreateApiKeyResponse;
     DeleteApiKey: Types.DeleteApiKeyResponse;
+    RotateApiKey: Types.RotateApiKeyResponse;
 };
 export type ReadResponses = {
     GetVersion: Types.GetVersionResponse;
@@ -22,10 +23,13 @@ export type ReadResponses = {
     GetPermission: Types.GetPermissionResponse;
     FindUser: Types.FindUserResponse;
     ListUsers: Types.ListUsersResponse;
+    ListServiceAccounts: Types.ListServiceAccountsResponse;
+    ListUserSessions: Types.ListUserSessionsResponse;
     ListApiKeys: Types.ListApiKeysResponse;
     ListApiKeysForServiceUser: Types.ListApiKeysForServiceUserResponse;
     ListPermissions: Types.ListPermissionsResponse;
     ListUserTargetPermissions: Types.ListUserTargetPermissionsResponse;
+    ListAuditLog: Types.ListAuditLogResponse;
     GetUserGroup: Types.GetUserGroupResponse;
     ListUserGroups: Types.ListUserGroupsResponse;
     GetProceduresSummary: Types.GetProceduresSummaryResponse;
@@ -57,19 +61,25 @@ export type ReadResponses = {
     ListDockerImageHistory: Types.ListDockerImageHistoryResponse;
     ListDockerVolumes: Types.ListDockerVolumesResponse;
     InspectDockerVolume: Types.InspectDockerVolumeResponse;
+    GetVolumeBrowserListing: Types.GetVolumeBrowserListingResponse;
     ListComposeProjects: Types.ListComposeProjectsResponse;
     GetServerActionState: Types.GetServerActionStateResponse;
     GetHistoricalServerStats: Types.GetHistoricalServerStatsResponse;
     ListServers: Types.ListServersResponse;
     ListFullServers: Types.ListFullServersResponse;
     ListTerminals: Types.ListTerminalsResponse;
+    GetServerGroup: Types.GetServerGroupResponse;
+    ListServerGroups: Types.ListServerGroupsResponse;
+    ListFullServerGroups: Types.ListFullServerGroupsResponse;
     GetStacksSummary: Types.GetStacksSummaryResponse;
     GetStack: Types.GetStackResponse;
     GetStackActionState: Types.GetStackActionStateResponse;
     GetStackWebhooksEnabled: Types.GetStackWebhooksEnabledResponse;
     GetStackLog: Types.GetStackLogResponse;
+    GetStackServiceLogs: Types.GetStackServiceLogsResponse;
     SearchStackLog: Types.SearchStackLogResponse;
     InspectStackContainer: Types.InspectStackContainerResponse;
+    GetStackContainerEnv: Types.GetStackContainerEnvResponse;
     ListStacks: Types.ListStacksResponse;
     ListFullStacks: Types.ListFullStacksResponse;
     ListStackServices: Types.ListStackServicesResponse;
@@ -83,6 +93,7 @@ export type ReadResponses = {
     GetDeploymentLog: Types.GetDeploymentLogResponse;
     SearchDeploymentLog: Types.SearchDeploymentLogResponse;
     InspectDeploymentContainer: Types.InspectDeploymentContainerResponse;
+    GetDeploymentContainerEnv: Types.GetDeploymentContainerEnvResponse;
     ListDeployments: Types.ListDeploymentsResponse;
     ListFullDeployments: Types.ListFullDeploymentsResponse;
     ListCommonDeploymentExtraArgs: Types.ListCommonDeploymentExtraArgsResponse;
@@ -104,6 +115,7 @@ export type ReadResponses = {
     GetResourceSyncsSummary: Types.GetResourceSyncsSummaryResponse;
     GetResourceSync: Types.GetResourceSyncResponse;
     GetResourceSyncActionState: Types.GetResourceSyncActionStateResponse;
+    ListResourceSyncConflicts: Types.ListResourceSyncConflictsResponse;
     GetSyncWebhooksEnabled: Types.GetSyncWebhooksEnabledResponse;
     ListResourceSyncs: Types.ListResourceSyncsResponse;
     ListFullResourceSyncs: Types.ListFullResourceSyncsResponse;
@@ -117,6 +129,7 @@ export type ReadResponses = {
     ListFullAlerters: Types.ListFullAlertersResponse;
     ExportAllResourcesToToml: Types.ExportAllResourcesToTomlResponse;
     ExportResourcesToToml: Types.ExportResourcesToTomlResponse;
+    ImportResourcesToToml: Types.ImportToTomlResponse;
     GetTag: Types.GetTagResponse;
     ListTags: Types.ListTagsResponse;
     GetUpdate: Types.GetUpdateResponse;
@@ -128,6 +141,7 @@ export type ReadResponses = {
     ListSystemProcesses: Types.ListSystemProcessesResponse;
     GetVariable: Types.GetVariableResponse;
     ListVariables: Types.ListVariablesResponse;
+    GetAllVariables: Types.GetAllVariablesResponse;
     GetGitProviderAccount: Types.GetGitProviderAccountResponse;
     ListGitProviderAccounts: Types.ListGitProviderAccountsResponse;
     GetDockerRegistryAccount: Types.GetDockerRegistryAccountResponse;
@@ -138,10 +152,15 @@ export type WriteResponses = {
     UpdateUserUsername: Types.UpdateUserUsernameResponse;
     UpdateUserPassword: Types.UpdateUserPasswordResponse;
     DeleteUser: Types.DeleteUserResponse;
+    RevokeUserSession: Types.RevokeUserSessionResponse;
     CreateServiceUser: Types.CreateServiceUserResponse;
     UpdateServiceUserDescription: Types.UpdateServiceUserDescriptionResponse;
     CreateApiKeyForServiceUser: Types.CreateApiKeyForServiceUserResponse;
     DeleteApiKeyForServiceUser: Types.DeleteApiKeyForServiceUserResponse;
+    CreateServiceAccount: Types.CreateServiceAccountResponse;
+    DeleteServiceAccount: Types.DeleteServiceAccountResponse;
+    CreateServiceAccountApiKey: Types.CreateServiceAccountApiKeyResponse;
+    DeleteServiceAccountApiKey: Types.DeleteServiceAccountApiKeyResponse;
     CreateUserGroup: Types.UserGroup;
     RenameUserGroup: Types.UserGroup;
     DeleteUserGroup: Types.UserGroup;
@@ -149,6 +168,7 @@ export type WriteResponses = {
     RemoveUserFromUserGroup: Types.UserGroup;
     SetUsersInUserGroup: Types.UserGroup;
     SetEveryoneUserGroup: Types.UserGroup;
+    UpdateUserGroupTagPermission: Types.UserGroup;
     UpdateUserAdmin: Types.UpdateUserAdminResponse;
     UpdateUserBasePermissions: Types.UpdateUserBasePermissionsResponse;
     UpdatePermissionOnResourceType: Types.UpdatePermissionOnResourceTypeResponse;
@@ -160,9 +180,17 @@ export type WriteResponses = {
     UpdateServer: Types.Server;
     RenameServer: Types.Update;
     CreateNetwork: Types.Update;
+    CopyFileToContainer: Types.Update;
+    CopyFileFromContainer: Types.CopyFileFromContainerResponse;
+    RunContainerCommand: Types.Update;
     CreateTerminal: Types.NoData;
     DeleteTerminal: Types.NoData;
     DeleteAllTerminals: Types.NoData;
+    CreateServerGroup: Types.ServerGroup;
+    CopyServerGroup: Types.ServerGroup;
+    DeleteServerGroup: Types.ServerGroup;
+    UpdateServerGroup: Types.ServerGroup;
+    RenameServerGroup: Types.Update;
     CreateStack: Types.Stack;
     CopyStack: Types.Stack;
     DeleteStack: Types.Stack;
@@ -221,6 +249,7 @@ export type WriteResponses = {
     UpdateResourceSync: Types.ResourceSync;
     RenameResourceSync: Types.Update;
     CommitSync: Types.Update;
+    ImportResourcesFromToml: Types.ImportResourcesFromTomlResponse;
     WriteSyncFileContents: Types.Update;
     RefreshResourceSyncPending: Types.ResourceSync;
     CreateSyncWebhook: Types.CreateSyncWebhookResponse;
@@ -263,6 +292,9 @@ export type ExecuteResponses = {
     PruneDockerBuilders: Types.Update;
     PruneBuildx: Types.Update;
     PruneSystem: Types.Update;
+    RunCommandOnGroup: Types.Update;
+    PruneImagesOnGroup: Types.Update;
+    PruneSystemOnGroup: Types.Update;
     DeployStack: Types.Update;
     BatchDeployStack: Types.BatchExecutionResponse;
     DeployStackIfChanged: Types.Update;
@@ -313,5 +345,6 @@ export type ExecuteResponses = {
     SendAlert: Types.Update;
     ClearRepoCache: Types.Update;
     BackupCoreDatabase: Types.Update;
+    RestoreDatabase: Types.Update;
     GlobalAutoUpdate: Types.Update;
 };
@@ -253,6 +253,9 @@ export type ResourceTarget = {
 } | {
     type: "ResourceSync";
     id: string;
+} | {
+    type: "ServerGroup";
+    id: string;
 };
 /** Types of maintenance schedules */
 export declare enum MaintenanceScheduleType {
@@ -368,6 +371,9 @@ export declare enum Operation {
     StopAllContainers = "StopAllContainers",
     PruneContainers = "PruneContainers",
     CreateNetwork = "CreateNetwork",
+    CopyFileToContainer = "CopyFileToContainer",
+    CopyFileFromContainer = "CopyFileFromContainer",
+    RunContainerCommand = "RunContainerCommand",
     DeleteNetwork = "DeleteNetwork",
     PruneNetworks = "PruneNetworks",
     DeleteImage = "DeleteImage",
@@ -377,6 +383,13 @@ export declare enum Operation {
     PruneDockerBuilders = "PruneDockerBuilders",
     PruneBuildx = "PruneBuildx",
     PruneSystem = "PruneSystem",
+    CreateServerGroup = "CreateServerGroup",
+    UpdateServerGroup = "UpdateServerGroup",
+    RenameServerGroup = "RenameServerGroup",
+    DeleteServerGroup = "DeleteServerGroup",
+    RunCommandOnGroup = "RunCommandOnGroup",
+    PruneImagesOnGroup = "PruneImagesOnGroup",
+    PruneSystemOnGroup = "PruneSystemOnGroup",
     CreateStack = "CreateStack",
     UpdateStack = "UpdateStack",
     RenameStack = "RenameStack",
@@ -456,6 +469,7 @@ export declare enum Operation {
     RunSync = "RunSync",
     ClearRepoCache = "ClearRepoCache",
     BackupCoreDatabase = "BackupCoreDatabase",
+    RestoreDatabase = "RestoreDatabase",
     GlobalAutoUpdate = "GlobalAutoUpdate",
     CreateVariable = "CreateVariable",
     UpdateVariableValue = "UpdateVariableValue",
@@ -544,6 +558,13 @@ export interface Update {
     prev_toml?: string;
     /** If the update is for resource config update, give the current (at time of Update) toml contents */
     current_toml?: string;
+    /** The ip of the client which made the request, if made through the api. */
+    client_ip?: string;
+    /**
+     * Structured output of the execution.
+     * Actions set this by printing a [KOMODO_OUTPUT][super::KOMODO_OUTPUT] line.
+     */
+    output?: JsonValue;
 }
 export type BoxUpdate = Update;
 /** Configuration for an image registry */
@@ -561,6 +582,16 @@ export interface ImageRegistryConfig {
      */
     organization?: string;
 }
+/** A BuildKit secret mounted during the build. */
+export interface BuildSecret {
+    /** The secret id, used with `RUN --mount=type=secret,id=...`. */
+    id?: string;
+    /**
+     * The secret value, written to the mounted secret file.
+     * Supports interpolation, eg `[[MY_SECRET]]`.
+     */
+    src?: string;
+}
 export interface SystemCommand {
     path?: string;
     command?: string;
@@ -655,8 +686,38 @@ export interface BuildConfig {
     image_registry?: ImageRegistryConfig[];
     /** Whether to skip secret interpolation in the build_args. */
     skip_secret_interp?: boolean;
+    /**
+     * Whether to skip only variable interpolation, still interpolating secrets.
+     * Useful if the contents use `[[...]]` for something other than Komodo variables.
+     * If `skip_secret_interp` is also enabled, all interpolation is skipped.
+     */
+    skip_variable_interp?: boolean;
     /** Whether to use buildx to build (eg `docker buildx build ...`) */
     use_buildx?: boolean;
+    /**
+     * The platforms to build the image for, eg `["linux/amd64", "linux/arm64"]`.
+     * If non-empty, builds with `docker buildx build --platform ... --push`.
+     * This requires buildx set up on the builder, and an image registry to push to.
+     */
+    platforms?: string[];
+    /**
+     * External cache sources passed with `--cache-from`,
+     * eg `type=registry,ref=registry.example.com/app:cache`.
+     * See <https://docs.docker.com/build/cache/backends>.
+     */
+    cache_from?: string[];
+    /**
+     * Cache export destinations passed with `--cache-to`,
+     * eg `type=inline` or `type=registry,ref=registry.example.com/app:cache,mode=max`.
+     * Exporting to anything other than `type=inline` requires buildx.
+     */
+    cache_to?: string[];
+    /**
+     * Additional build contexts passed with `--build-context`,
+     * eg `shared=../shared` or `alpine=docker-image://alpine:3.20`.
+     * Each must be in the form `name=value`.
+     */
+    build_contexts?: string[];
     /** Any extra docker cli arguments to be included in the build command */
     extra_args?: string[];
     /** The optional command run after repo clone and before docker build. */
@@ -685,6 +746,14 @@ export interface BuildConfig {
      * ```
      */
     secret_args?: string;
+    /**
+     * BuildKit secret mounts.
+     *
+     * Each secret is written to a temporary file outside the build context
+     * for the duration of the build, and passed with `--secret id=...,src=...`.
+     * The secret can be used in RUN commands with `--mount=type=secret,id=...`.
+     */
+    build_secrets?: BuildSecret[];
     /** Docker labels */
     labels?: string;
 }
@@ -714,6 +783,19 @@ export interface BuildInfo {
     latest_hash?: string;
     /** Latest remote commit message, or null */
     latest_message?: string;
+    /**
+     * Sha256 hash of the latest dockerfile contents, or null.
+     * This is updated whenever Komodo refreshes the build cache.
+     */
+    dockerfile_hash?: string;
+    /** Sha256 hash of the dockerfile contents at the last successful build, or null. */
+    built_dockerfile_hash?: string;
+    /** Whether the dockerfile has changed since the last successful build. */
+    needs_rebuild?: boolean;
+    /** The current step of the in progress build, or null. */
+    build_step?: number;
+    /** The total steps of the in progress build stage, or null. */
+    build_step_total?: number;
 }
 export type Build = Resource<BuildConfig, BuildInfo>;
 export declare enum BuildState {
@@ -753,6 +835,8 @@ export interface BuildListItemInfo {
     built_hash?: string;
     /** Latest short commit hash, or null. Only for repo based stacks */
     latest_hash?: string;
+    /** Whether the dockerfile has changed since the last successful build. */
+    needs_rebuild: boolean;
     /** The first listed image registry domain */
     image_registry_domain?: string;
 }
@@ -949,6 +1033,15 @@ export type Execution =
 } | {
     type: "PruneSystem";
     params: PruneSystem;
+} | {
+    type: "RunCommandOnGroup";
+    params: RunCommandOnGroup;
+} | {
+    type: "PruneImagesOnGroup";
+    params: PruneImagesOnGroup;
+} | {
+    type: "PruneSystemOnGroup";
+    params: PruneSystemOnGroup;
 }
 /** Execute a Resource Sync. (alias: `sync`) */
  | {
@@ -1029,12 +1122,41 @@ export interface EnabledExecution {
     /** Whether the execution is enabled to run in the procedure. */
     enabled: boolean;
 }
+/**
+ * When to run a [ProcedureStage],
+ * based on the result of the previous stages.
+ */
+export declare enum StageRunCondition {
+    /** Run the stage whether or not a previous stage failed. */
+    Always = "Always",
+    /**
+     * Only run the stage if no previous stage failed.
+     * This matches procedures without conditions,
+     * which stop at the first failed stage.
+     */
+    OnSuccess = "OnSuccess",
+    /**
+     * Only run the stage if a previous stage failed.
+     * Can be used for cleanup / notification on failure.
+     */
+    OnFailure = "OnFailure",
+}
 /** A single stage of a procedure. Runs a list of executions in parallel. */
 export interface ProcedureStage {
     /** A name for the procedure */
     name: string;
     /** Whether the stage should be run as part of the procedure. */
     enabled: boolean;
+    /**
+     * Run the stage based on the result of the previous stages.
+     * Default: `OnSuccess`
+     */
+    run_condition?: StageRunCondition;
+    /**
+     * Fail the stage if its executions take longer than this
+     * many seconds. If not provided, the stage can run indefinitely.
+     */
+    timeout_seconds?: U64;
     /** The executions in the stage */
     executions?: EnabledExecution[];
 }
@@ -1104,6 +1226,7 @@ export interface CreateApiKeyResponse {
      */
     secret: string;
 }
+export type RotateApiKeyResponse = CreateApiKeyResponse;
 export type CreateApiKeyForServiceUserResponse = CreateApiKeyResponse;
 export type CreateBuildWebhookResponse = NoData;
 /** Configuration to access private image repositories on various registries. */
@@ -1231,16 +1354,47 @@ export interface User {
 export type CreateLocalUserResponse = User;
 export type CreateProcedureResponse = Procedure;
 export type CreateRepoWebhookResponse = NoData;
+export type CreateServiceAccountApiKeyResponse = CreateApiKeyResponse;
+
+/**
+ * An account for machine-to-machine access, separate from users.
+ * Service accounts have no password and can't log in to the UI,
+ * they can only make api calls using their api keys.
+ */
+export interface ServiceAccount {
+    /**
+     * The Mongo ID of the ServiceAccount.
+     * This field is de/serialized from/to JSON as
+     * `{ "_id": { "$oid": "..." }, ...(rest of ServiceAccount schema) }`
+     */
+    _id?: MongoId;
+    /** The globally unique name of the service account. */
+    name: string;
+    /** A description of what the service account is used for. */
+    description?: string;
+    /** Give the service account permissions on all resources of a certain type. */
+    permissions?: Record<ResourceTarget["type"], PermissionLevelAndSpecifics | PermissionLevel>;
+    /** The api keys of the service account. Secrets are stored hashed. */
+    api_keys?: ApiKey[];
+    /** Unix time (ms) when the service account was last updated */
+    updated_at?: I64;
+}
+
+export type CreateServiceAccountResponse = ServiceAccount;
 export type CreateServiceUserResponse = User;
 export type CreateStackWebhookResponse = NoData;
 export type CreateSyncWebhookResponse = NoData;
 /**
  * A non-secret global variable which can be interpolated into deployment
  * environment variable values and build argument values.
+ *
+ * Variables can also be scoped to a Server, in which case they
+ * override the global variable with the same name for
+ * resources running on that Server.
  */
 export interface Variable {
     /**
-     * Unique name associated with the variable.
+     * Name associated with the variable, unique per scope.
      * Instances of '[[variable.name]]' in value will be replaced with 'variable.value'.
      */
     name: string;
@@ -1257,6 +1411,22 @@ export interface Variable {
      * of the database (system level encryption, network isolation, etc.)
      */
     is_secret?: boolean;
+    /**
+     * Scope the variable to a Server by id.
+     * Stacks, Deployments, and Repos on the Server will use this value
+     * over the global variable with the same name.
+     */
+    server_id?: string;
+    /**
+     * The number of resources which reference the variable
+     * using '[[variable.name]]'. Computed by Core, not stored.
+     */
+    reference_count?: number;
+    /**
+     * The resources which reference the variable.
+     * Computed by Core, not stored.
+     */
+    referenced_by?: ResourceTarget[];
 }
 export type CreateVariableResponse = Variable;
 export type DeleteActionWebhookResponse = NoData;
@@ -1267,9 +1437,35 @@ export type DeleteDockerRegistryAccountResponse = DockerRegistryAccount;
 export type DeleteGitProviderAccountResponse = GitProviderAccount;
 export type DeleteProcedureResponse = Procedure;
 export type DeleteRepoWebhookResponse = NoData;
+export type DeleteServiceAccountApiKeyResponse = NoData;
+
+export type DeleteServiceAccountResponse = ServiceAccount;
 export type DeleteStackWebhookResponse = NoData;
 export type DeleteSyncWebhookResponse = NoData;
 export type DeleteUserResponse = User;
+/**
+ * A login session, created whenever a JWT is issued.
+ * Requests using the JWT are only authenticated while
+ * the session exists, so deleting it revokes the JWT.
+ */
+export interface UserSession {
+    /** Unique id of the JWT, matching the `jti` claim. */
+    jwt_id: string;
+    /** User associated with the session */
+    user_id: string;
+    /** Timestamp the JWT was issued */
+    issued_at: I64;
+    /** Timestamp the JWT expires */
+    expires_at: I64;
+    /** The user agent of the client which logged in */
+    user_agent?: string;
+    /**
+     * The ip of the client which logged in,
+     * when forwarded by a reverse proxy.
+     */
+    ip?: string;
+}
+export type RevokeUserSessionResponse = UserSession;
 export type DeleteVariableResponse = Variable;
 export type DeploymentImage = 
 /** Deploy any external image. */
@@ -1299,6 +1495,18 @@ export declare enum RestartMode {
     Always = "always",
     UnlessStopped = "unless-stopped"
 }
+export declare enum PullPolicy {
+    /**
+     * Docker's default, only pulls the image if it is missing locally.
+     * Komodo still tries to pull the image before deploying,
+     * but falls back to the local image if the pull fails.
+     */
+    Missing = "missing",
+    /** Always pull the image, failing the deploy if the pull fails. */
+    Always = "always",
+    /** Never pull the image, it must already exist locally. */
+    Never = "never",
+}
 export declare enum TerminationSignal {
     SigHup = "SIGHUP",
     SigInt = "SIGINT",
@@ -1324,6 +1532,12 @@ export interface DeploymentConfig {
     image_registry_account?: string;
     /** Whether to skip secret interpolation into the deployment environment variables. */
     skip_secret_interp?: boolean;
+    /**
+     * Whether to skip only variable interpolation, still interpolating secrets.
+     * Useful if the contents use `[[...]]` for something other than Komodo variables.
+     * If `skip_secret_interp` is also enabled, all interpolation is skipped.
+     */
+    skip_variable_interp?: boolean;
     /** Whether to redeploy the deployment whenever the attached build finishes. */
     redeploy_on_build?: boolean;
     /** Whether to poll for any updates to the image. */
@@ -1346,6 +1560,12 @@ export interface DeploymentConfig {
     network: string;
     /** The restart mode given to the container. */
     restart?: RestartMode;
+    /**
+     * When to pull the image before running the container,
+     * passed to `docker run --pull`.
+     * Use `always` to force a fresh pull on redeploy without changing the tag.
+     */
+    pull_policy?: PullPolicy;
     /**
      * This is interpolated at the end of the `docker run` command,
      * which means they are either passed to the containers inner process,
@@ -1571,6 +1791,24 @@ export type AlertData =
         total_gb: number;
     };
 }
+/** A server has high inode usage on a disk. */
+ | {
+    type: "ServerInodes";
+    data: {
+        /** The id of the server */
+        id: string;
+        /** The name of the server */
+        name: string;
+        /** The region of the server */
+        region?: string;
+        /** The mount path of the disk */
+        path: string;
+        /** The number of used inodes */
+        used: U64;
+        /** The total number of inodes */
+        total: U64;
+    };
+}
 /** A server has a version mismatch with the core. */
  | {
     type: "ServerVersionMismatch";
@@ -1689,6 +1927,26 @@ export type AlertData =
         images: string[];
     };
 }
+/** A container's docker healthcheck has started failing. */
+ | {
+    type: "ContainerUnhealthy";
+    data: {
+        /** The id of the deployment or stack */
+        id: string;
+        /** The name of the deployment or stack */
+        name: string;
+        /** The type of resource, Deployment or Stack */
+        resource_type: ResourceTarget["type"];
+        /** The server id of server that the container is on */
+        server_id: string;
+        /** The server name */
+        server_name: string;
+        /** The container name */
+        container: string;
+        /** The service name, if the container is part of a stack */
+        service?: string;
+    };
+}
 /** An AWS builder failed to terminate. */
  | {
     type: "AwsBuilderTerminationFailed";
@@ -1799,6 +2057,22 @@ export interface Alert {
 }
 export type GetAlertResponse = Alert;
 export type GetAlerterResponse = Alerter;
+export interface VariableSummary {
+    /** The name of the variable. */
+    name: string;
+    /** The description of the variable. */
+    description: string;
+    /** Whether the variable is secret. */
+    is_secret: boolean;
+    /** The Server the variable is scoped to, if any. */
+    server_id?: string;
+    /**
+     * The resources which interpolate the variable
+     * into their config using `[[name]]`.
+     */
+    referenced_by: ResourceTarget[];
+}
+export type GetAllVariablesResponse = VariableSummary[];
 export interface BuildActionState {
     building: boolean;
 }
@@ -1876,6 +2150,12 @@ export interface RepoConfig {
     branch: string;
     /** Optionally set a specific commit hash. */
     commit?: string;
+    /**
+     * Make a shallow clone with history truncated to this many commits,
+     * ie `git clone --depth {clone_depth}`. 1 is standard for CI builds.
+     * 0 means a full clone.
+     */
+    clone_depth?: number;
     /**
      * Explicitly specify the folder to clone the repo in.
      * - If absolute (has leading '/')
@@ -1884,6 +2164,13 @@ export interface RepoConfig {
      * - Taken relative to Periphery `repo_dir` (ie `${root_directory}/repos`)
      */
     path?: string;
+    /**
+     * Only check out these directories in the repo, using `git sparse-checkout`.
+     * Useful to avoid checking out an entire monorepo
+     * when only a subdirectory is needed.
+     * Empty means the whole repo is checked out.
+     */
+    sparse_checkout?: string[];
     /** Whether incoming webhooks actually trigger action. */
     webhook_enabled: boolean;
     /**
@@ -1919,6 +2206,12 @@ export interface RepoConfig {
     env_file_path: string;
     /** Whether to skip secret interpolation into the repo environment variable file. */
     skip_secret_interp?: boolean;
+    /**
+     * Whether to skip only variable interpolation, still interpolating secrets.
+     * Useful if the contents use `[[...]]` for something other than Komodo variables.
+     * If `skip_secret_interp` is also enabled, all interpolation is skipped.
+     */
+    skip_variable_interp?: boolean;
 }
 export interface RepoInfo {
     /** When repo was last pulled */
@@ -2010,8 +2303,20 @@ export interface ResourceSyncConfig {
      * matching all of the given tags. If none, will match all resources.
      */
     match_tags?: string[];
+    /**
+     * Optionally scope the resource names managed by this sync.
+     * When set, `<namespace>-` is prepended to all the resource names
+     * in the sync files, and only resources with the prefix are
+     * updated or deleted. Exports strip the prefix again.
+     * Resources referenced in configs (eg. servers) must use the full name.
+     * Variables and user groups are global, so they can't be included
+     * in a sync with a namespace.
+     */
+    namespace?: string;
     /** Whether sync should include variables. */
     include_variables?: boolean;
+    /** Whether sync should include tags. */
+    include_tags?: boolean;
     /** Whether sync should include user groups. */
     include_user_groups?: boolean;
     /**
@@ -2056,6 +2361,21 @@ export interface ResourceDiff {
     /** The data associated with the diff. */
     data: DiffData;
 }
+/**
+ * A field which differs between a live resource
+ * and the resource declared in the sync files.
+ */
+export interface SyncConflict {
+    /** The live resource */
+    resource_target: ResourceTarget;
+    /** The path to the field, eg. `config.image` */
+    field: string;
+    /** The live value, in TOML. Null if not set on the live resource. */
+    live_value?: string;
+    /** The value in the sync files, in TOML. Null if not set in the files. */
+    sync_value?: string;
+}
+export type ListResourceSyncConflictsResponse = SyncConflict[];
 export interface SyncDeployUpdate {
     /** Resources to deploy */
     to_deploy: number;
@@ -2081,6 +2401,8 @@ export interface ResourceSyncInfo {
     resource_updates?: ResourceDiff[];
     /** The list of pending updates to variables */
     variable_updates?: DiffData[];
+    /** The list of pending updates to tags */
+    tag_updates?: DiffData[];
     /** The list of pending updates to user groups */
     user_group_updates?: DiffData[];
     /** The list of pending deploys to resources. */
@@ -2158,11 +2480,24 @@ export interface ServerConfig {
      * If this is empty, will use passkey in core config.
      */
     passkey?: string;
+    /**
+     * Path on the Core host to a PEM file containing
+     * the client certificate and private key.
+     * Required when Periphery is configured with `tls_ca_cert` (mTLS).
+     * If this is empty, no client certificate is sent.
+     */
+    client_cert_path?: string;
     /**
      * Sometimes the system stats reports a mount path that is not desired.
      * Use this field to filter it out from the report.
      */
     ignore_mounts?: string[];
+    /**
+     * The network interface used for the network rates
+     * recorded in the stats history, eg "eth0".
+     * If empty (or not found), the rates over all interfaces are used.
+     */
+    network_interface?: string;
     /**
      * Whether to monitor any server stats beyond passing health check.
      * default: true
@@ -2197,11 +2532,23 @@ export interface ServerConfig {
     disk_warning: number;
     /** The percentage threshhold which triggers CRITICAL state for DISK. */
     disk_critical: number;
+    /**
+     * The percentage of used inodes which triggers WARNING state for a DISK.
+     * Inode alerts are sent along with `send_disk_alerts`.
+     * Disabled if 0.
+     */
+    inode_warning?: number;
+    /**
+     * The percentage of used inodes which triggers CRITICAL state for a DISK.
+     * Disabled if 0.
+     */
+    inode_critical?: number;
     /** Scheduled maintenance windows during which alerts will be suppressed. */
     maintenance_windows?: MaintenanceWindow[];
 }
 export type Server = Resource<ServerConfig, undefined>;
 export type GetServerResponse = Server;
+export type GetServerGroupResponse = ServerGroup;
 export interface StackActionState {
     pulling: boolean;
     deploying: boolean;
@@ -2214,6 +2561,7 @@ export interface StackActionState {
 }
 export type GetStackActionStateResponse = StackActionState;
 export type GetStackLogResponse = Log;
+export type GetStackServiceLogsResponse = Log;
 export declare enum StackFileRequires {
     /** Diff requires service redeploy. */
     Redeploy = "Redeploy",
@@ -2256,6 +2604,12 @@ export interface StackConfig {
      * Combine with build_extra_args for custom behaviors.
      */
     run_build?: boolean;
+    /**
+     * Limit the number of images pulled / built in parallel
+     * during deploy, passed as `COMPOSE_PARALLEL_LIMIT`.
+     * If 0, uses the docker compose default.
+     */
+    compose_parallel_limit?: number;
     /** Whether to poll for any updates to the images. */
     poll_for_updates?: boolean;
     /**
@@ -2276,6 +2630,12 @@ export interface StackConfig {
     destroy_before_deploy?: boolean;
     /** Whether to skip secret interpolation into the stack environment variables. */
     skip_secret_interp?: boolean;
+    /**
+     * Whether to skip only variable interpolation, still interpolating secrets.
+     * Useful if the contents use `[[...]]` for something other than Komodo variables.
+     * If `skip_secret_interp` is also enabled, all interpolation is skipped.
+     */
+    skip_variable_interp?: boolean;
     /** Choose a Komodo Repo (Resource) to source the compose files. */
     linked_repo?: string;
     /** The git provider domain. Default: github.com */
@@ -2350,6 +2710,12 @@ export interface StackConfig {
      * Don't add it again there.
      */
     additional_env_files?: string[];
+    /**
+     * Compose profiles to enable with `--profile`.
+     * Services in other profiles are not started.
+     * Empty only starts services without a profile.
+     */
+    compose_profiles?: string[];
     /**
      * Add additional config files either in repo or on host to track.
      * Can add any files associated with the stack to enable editing them in the UI.
@@ -2370,6 +2736,23 @@ export interface StackConfig {
     pre_deploy?: SystemCommand;
     /** The optional command to run after the Stack is deployed. */
     post_deploy?: SystemCommand;
+    /**
+     * The optional command to run once the Stack has reached the
+     * Running state after deploy, with all service health checks passing.
+     */
+    on_deploy?: SystemCommand;
+    /**
+     * The max seconds to wait for the Stack to become healthy
+     * before running the `on_deploy` command. The deploy request
+     * waits on this, so keep it within the request timeout.
+     * If 0, runs `on_deploy` without waiting.
+     */
+    on_deploy_timeout: U64;
+    /**
+     * The optional command to run before the Stack is destroyed,
+     * ie before `docker compose down`.
+     */
+    on_destroy?: SystemCommand;
     /**
      * The extra arguments to pass after `docker compose up -d`.
      * If empty, no extra arguments will be passed.
@@ -2408,7 +2791,13 @@ export interface FileContents {
     path: string;
     /** The contents of the file */
     contents: string;
+    /**
+     * Whether the contents are base64 encoded,
+     * used for binary files such as certificates.
+     */
+    base64_contents?: boolean;
 }
+export type CopyFileFromContainerResponse = FileContents;
 export interface StackServiceNames {
     /** The name of the service */
     service_name: string;
@@ -2443,6 +2832,12 @@ export interface StackRemoteFileContents {
     path: string;
     /** The contents of the file */
     contents: string;
+    /**
+     * Whether the contents are base64 encoded.
+     * Files on host which are not valid UTF-8 (eg binary files)
+     * will be base64 encoded.
+     */
+    base64_contents?: boolean;
     /**
      * The services depending on this file,
      * or empty for global requirement (eg all compose files and env files).
@@ -2451,6 +2846,19 @@ export interface StackRemoteFileContents {
     /** Whether diff requires Redeploy / Restart / None */
     requires?: StackFileRequires;
 }
+export interface StackServiceInfo {
+    /** The service name */
+    name: string;
+    /** The image the service container is using */
+    image: string;
+    /**
+     * The image repo digest, eg `mongo@sha256:...`.
+     * Only available if the image was pulled from or pushed to a registry.
+     */
+    image_digest?: string;
+    /** The service container state */
+    state: ContainerStateStatusEnum;
+}
 export interface StackInfo {
     /**
      * If any of the expected compose / additional files are missing in the repo,
@@ -2488,6 +2896,12 @@ export interface StackInfo {
      * This is updated whenever the stack cache refreshes, using the latest file contents (either db defined or remote).
      */
     latest_services?: StackServiceNames[];
+    /**
+     * The services of the deployed project currently on the host,
+     * along with their image digests.
+     * This is updated whenever the server status cache refreshes, using `docker compose ps`.
+     */
+    services?: StackServiceInfo[];
     /**
      * The remote compose / additional file contents, whether on host or in repo.
      * This is updated whenever Komodo refreshes the stack cache.
@@ -2541,6 +2955,16 @@ export interface SingleDiskUsage {
     used_gb: number;
     /** Total size of the disk in GB */
     total_gb: number;
+    /**
+     * Number of used inodes on the disk.
+     * Zero if the file system doesn't report inodes.
+     */
+    inodes_used?: U64;
+    /**
+     * Total number of inodes on the disk.
+     * Zero if the file system doesn't report inodes.
+     */
+    inodes_total?: U64;
 }
 export declare enum Timelength {
     /** `1-sec` */
@@ -2609,6 +3033,18 @@ export interface SystemStats {
     network_ingress_bytes?: number;
     /** Network egress usage in MB */
     network_egress_bytes?: number;
+    /**
+     * Network ingress rate in bytes per second,
+     * summed over all non-loopback interfaces.
+     */
+    network_ingress_bytes_per_sec?: number;
+    /**
+     * Network egress rate in bytes per second,
+     * summed over all non-loopback interfaces.
+     */
+    network_egress_bytes_per_sec?: number;
+    /** Network usage by interface */
+    network_interfaces?: SingleNetworkInterfaceUsage[];
     /** The rate the system stats are being polled from the system */
     polling_rate: Timelength;
     /** Unix timestamp in milliseconds when stats were last polled */
@@ -2710,6 +3146,12 @@ export interface UserGroup {
     users?: string[];
     /** Give the user group elevated permissions on all resources of a certain type */
     all?: Record<ResourceTarget["type"], PermissionLevelAndSpecifics | PermissionLevel>;
+    /**
+     * Give the user group permissions on all resources with a certain tag.
+     * Keyed by tag id. These are resolved when permissions are checked,
+     * so resources are included as soon as they are tagged.
+     */
+    tags?: Record<string, PermissionLevelAndSpecifics>;
     /** Unix time (ms) when user group last updated */
     updated_at?: I64;
 }
@@ -3234,6 +3676,7 @@ export interface Container {
     NetworkSettings?: NetworkSettings;
 }
 export type InspectDeploymentContainerResponse = Container;
+export type GetDeploymentContainerEnvResponse = string[];
 export type InspectDockerContainerResponse = Container;
 /** Information about the image's RootFS, including the layer IDs. */
 export interface ImageInspectRootFs {
@@ -3455,7 +3898,70 @@ export interface Volume {
     UsageData?: VolumeUsageData;
 }
 export type InspectDockerVolumeResponse = Volume;
+/** A file or directory inside a docker volume. */
+export interface VolumeEntry {
+    /** The file / directory name */
+    name: string;
+    /** Whether the entry is a directory */
+    is_dir: boolean;
+    /** The size of the entry in bytes */
+    size_bytes: U64;
+    /** Unix timestamp in milliseconds of the last modification */
+    modified_at: I64;
+}
+export type GetVolumeBrowserListingResponse = VolumeEntry[];
 export type InspectStackContainerResponse = Container;
+export type GetStackContainerEnvResponse = string[];
+export interface ImportedResource {
+    /** The resource type. */
+    resource_type: ResourceTarget["type"];
+    /** The resource name. */
+    name: string;
+}
+export interface ImportResourcesFromTomlResponse {
+    /** Whether this was a dry run, ie no changes were applied. */
+    dry_run: boolean;
+    /** The resources which were (or would be) created. */
+    created: ImportedResource[];
+    /** The resources which were (or would be) updated. */
+    updated: ImportedResource[];
+    /**
+     * The resources which already match the toml,
+     * and were skipped.
+     */
+    skipped: ImportedResource[];
+    /** The computed diffs for the resources. */
+    resource_updates: ResourceDiff[];
+    /** The computed diffs for the variables. */
+    variable_updates: DiffData[];
+    /** The computed diffs for the user groups. */
+    user_group_updates: DiffData[];
+    /**
+     * The logs from applying the changes.
+     * Empty on dry run.
+     */
+    logs: Log[];
+}
+export interface PendingTomlUpdate {
+    /** The resource to update. */
+    target: ResourceTarget;
+    /**
+     * Line diff from the current toml to the proposed toml,
+     * with removed lines prefixed by `-` and added lines by `+`.
+     */
+    diff: string;
+}
+export interface ImportToTomlResponse {
+    /**
+     * Resources in the toml which don't exist yet.
+     * These targets hold the resource name, as there is no id yet.
+     */
+    pending_creates: ResourceTarget[];
+    /** Existing resources which differ from the toml. */
+    pending_updates: PendingTomlUpdate[];
+    /** Existing resources which are missing from the toml. */
+    pending_deletes: ResourceTarget[];
+}
 export type JsonObject = any;
 export type JsonValue = any;
 export type ListActionsResponse = ActionListItem[];
@@ -3498,6 +4004,11 @@ export interface ContainerListItem {
     state: ContainerStateStatusEnum;
     /** Additional human-readable status of this container (e.g. `Exit 0`) */
     status?: string;
+    /**
+     * The docker healthcheck status, parsed from `status`.
+     * None if the container has no healthcheck.
+     */
+    health?: HealthStatusEnum;
     /** The network mode */
     network_mode?: string;
     /** The network names attached to container */
@@ -3516,6 +4027,22 @@ export interface ContainerListItem {
     labels?: Record<string, string>;
 }
 export type ListAllDockerContainersResponse = ContainerListItem[];
+/** Caps the requests an api key is allowed to make. */
+export interface ApiKeyPermissionCeiling {
+    /**
+     * The max level of request the key can make.
+     * - Read: only /read requests
+     * - Execute: also /execute requests
+     * - Write: also /write and /user requests, and terminals
+     */
+    level?: PermissionLevel;
+    /**
+     * Restrict /execute, /write, and /user requests to these
+     * request types, eg `Deploy`. Use `Terminal` to allow terminals.
+     * Empty allows all requests within the `level`.
+     */
+    operations?: string[];
+}
 /** An api key used to authenticate requests via request headers. */
 export interface ApiKey {
     /** Unique key associated with secret */
@@ -3530,9 +4057,43 @@ export interface ApiKey {
     created_at: I64;
     /** Expiry of key, or 0 if never expires */
     expires: I64;
+    /**
+     * Timestamp the key was last used to authenticate, or 0 if never.
+     * Only updated once per minute.
+     */
+    last_used_at?: I64;
+    /**
+     * Limits what the key can do, below the permissions of the user.
+     * Keys without a ceiling have the full permissions of the user.
+     */
+    permission_ceiling?: ApiKeyPermissionCeiling;
 }
 export type ListApiKeysForServiceUserResponse = ApiKey[];
 export type ListApiKeysResponse = ApiKey[];
+/** A record of a write or execute request made to Komodo Core. */
+export interface AuditEntry {
+    /**
+     * The Mongo ID of the audit entry.
+     * This field is de/serialized from/to JSON as
+     * `{ "_id": { "$oid": "..." }, ...(rest of serialized AuditEntry) }`
+     */
+    _id?: MongoId;
+    /** The id of the user who made the request. */
+    user_id: string;
+    /** The username of the user at the time of the request. */
+    username: string;
+    /** The request type, eg `DeployStack`. */
+    request_type: string;
+    /** The resource the request acted on, when known. */
+    resource_target?: ResourceTarget;
+    /** Unix timestamp in milliseconds the request was made. */
+    timestamp: I64;
+    /** The ip of the client which made the request. */
+    ip?: string;
+    /** Whether the request succeeded. */
+    success: boolean;
+}
+export type ListAuditLogResponse = AuditEntry[];
 export interface BuildVersionResponseItem {
     version: Version;
     ts: I64;
@@ -3565,6 +4126,35 @@ export interface ImageHistoryResponseItem {
     Comment: string;
 }
 export type ListDockerImageHistoryResponse = ImageHistoryResponseItem[];
+/** The local and registry digests for an image. */
+export interface ImageDigests {
+    /** The manifest digests the local image is referenced from. */
+    local: string[];
+    /** The manifest digest currently on the registry. */
+    remote: string;
+}
+/**
+ * The result of checking the registry for a newer
+ * version of a running image.
+ */
+export interface ImageUpdateCheck {
+    /** The type of resource running the image. */
+    resource_type: ResourceTarget["type"];
+    /**
+     * The name of the resource running the image.
+     * For Stacks, this is `stack | service`.
+     */
+    resource_name: string;
+    /**
+     * The running image, with the local digest if known.
+     * Eg. `nginx:latest@sha256:...`
+     */
+    current_image: string;
+    /** The image with the digest currently on the registry. */
+    latest_image: string;
+    /** Whether the registry digest differs from the local one. */
+    has_update: boolean;
+}
 export interface ImageListItem {
     /** The first tag in `repo_tags`, or Id if no tags. */
     name: string;
@@ -3638,6 +4228,7 @@ export type ListFullProceduresResponse = Procedure[];
 export type ListFullReposResponse = Repo[];
 export type ListFullResourceSyncsResponse = ResourceSync[];
 export type ListFullServersResponse = Server[];
+export type ListFullServerGroupsResponse = ServerGroup[];
 export type ListFullStacksResponse = Stack[];
 export type ListGitProviderAccountsResponse = GitProviderAccount[];
 export interface GitProvider {
@@ -3857,6 +4448,7 @@ export interface ServerListItemInfo {
 }
 export type ServerListItem = ResourceListItem<ServerListItemInfo>;
 export type ListServersResponse = ServerListItem[];
+export type ListServerGroupsResponse = ServerGroupListItem[];
 export interface StackService {
     /** The service name */
     service: string;
@@ -3867,6 +4459,8 @@ export interface StackService {
     /** Whether there is an update available for this services image. */
     update_available: boolean;
 }
+export type ListServiceAccountsResponse = ServiceAccount[];
+export type ListUserSessionsResponse = UserSession[];
 export type ListStackServicesResponse = StackService[];
 export declare enum StackState {
     /** The stack is currently re/deploying */
@@ -3982,6 +4576,8 @@ export interface TerminalInfo {
     command: string;
     /** The size of the terminal history in memory. */
     stored_size_kb: number;
+    /** Milliseconds since the last terminal input or output. */
+    idle_ms?: I64;
 }
 export type ListTerminalsResponse = TerminalInfo[];
 export type ListUserGroupsResponse = UserGroup[];
@@ -4012,6 +4608,30 @@ export interface ServerQuerySpecifics {
 }
 /** Server-specific query */
 export type ServerQuery = ResourceQuery<ServerQuerySpecifics>;
+export interface ServerGroupConfig {
+    /**
+     * The servers in the group, by id.
+     * Names are also accepted on create / update,
+     * and will be converted to ids.
+     *
+     * Group executions run on all the members in parallel.
+     */
+    members?: string[];
+}
+export type ServerGroup = Resource<ServerGroupConfig, undefined>;
+export interface ServerGroupListItemInfo {
+    /** The ids of the servers in the group. */
+    members: string[];
+}
+export type ServerGroupListItem = ResourceListItem<ServerGroupListItemInfo>;
+export interface ServerGroupQuerySpecifics {
+    /**
+     * Only include groups which have these servers as members.
+     * If empty, don't filter by member.
+     */
+    members?: string[];
+}
+export type ServerGroupQuery = ResourceQuery<ServerGroupQuerySpecifics>;
 export type SetLastSeenUpdateResponse = NoData;
 /** Response for [SignUpLocalUser]. */
 export type SignUpLocalUserResponse = JwtResponse;
@@ -4060,6 +4680,7 @@ export type _PartialRepoConfig = Partial<RepoConfig>;
 export type _PartialResourceSyncConfig = Partial<ResourceSyncConfig>;
 export type _PartialServerBuilderConfig = Partial<ServerBuilderConfig>;
 export type _PartialServerConfig = Partial<ServerConfig>;
+export type _PartialServerGroupConfig = Partial<ServerGroupConfig>;
 export type _PartialStackConfig = Partial<StackConfig>;
 export type _PartialTag = Partial<Tag>;
 export type _PartialUrlBuilderConfig = Partial<UrlBuilderConfig>;
@@ -4131,10 +4752,97 @@ export interface AwsBuilderConfig {
  * timestamped database dumps, which can be restored using
  * the Komodo CLI.
  *
+ * Set `KOMODO_CLI_BACKUP_ENCRYPTION_KEY` in the Core
+ * environment to encrypt the backup files.
+ *
+ * With a `remote_target`, the new backup is also uploaded to
+ * S3, GCS or Azure Blob Storage, and old remote backups are pruned
+ * using the same `max_backups` (KOMODO_CLI_MAX_BACKUPS) limit.
+ * The target is referenced by name from the Core config
+ * `backup_remote_targets`, so the credentials never
+ * appear in the request or in Procedures which run it.
+ *
  * https://komo.do/docs/setup/backup
  */
 export interface BackupCoreDatabase {
+    /** Override the backup compression configured for the CLI. */
+    compression?: BackupCompression;
+    /**
+     * Also upload the backup to the remote object store
+     * configured with this name in the Core config `backup_remote_targets`.
+     */
+    remote_target?: string;
+}
+/**
+ * Restores the Komodo Core database from a backup folder
+ * created by [BackupCoreDatabase]. Super admin only. Response: [Update]
+ *
+ * **This is destructive.** Each collection in the backup is dropped
+ * and recreated from the backup files, so anything created or
+ * changed since the backup is lost. Restart Core afterwards
+ * to clear any cached state.
+ *
+ * Encrypted backups are decrypted using `KOMODO_CLI_BACKUP_ENCRYPTION_KEY`
+ * in the Core environment.
+ */
+export interface RestoreDatabase {
+    /**
+     * The timestamped backup folder to restore, either its name
+     * or its full path in the Core container. It must be directly
+     * inside the configured backups folder.
+     * Example: `2025-08-01_05-04-53` or `/backups/2025-08-01_05-04-53`
+     */
+    backup_path: string;
+    /** Must be exactly `I understand this is destructive`. */
+    confirmation: string;
+}
+/** The compression used for database backup files. */
+export declare enum BackupCompression {
+    /** No compression, files are written as `.jsonl`. */
+    None = "none",
+    /** Gzip compression, files are written as `.gz`. Default. */
+    Gzip = "gzip",
+    /** Zstd compression, files are written as `.zst`. */
+    Zstd = "zstd",
 }
+/**
+ * A remote object store to upload database backups to.
+ * The backup folders are uploaded under the `prefix`,
+ * using the same folder names as the local backups.
+ */
+export type RemoteBackupTarget = 
+/** Upload to an AWS S3 (or S3 compatible) bucket. */
+{
+    type: "S3";
+    params: {
+        bucket: string;
+        prefix?: string;
+        region: string;
+        access_key_id: string;
+        secret_access_key: string;
+    };
+}
+/** Upload to a Google Cloud Storage bucket. */
+ | {
+    type: "Gcs";
+    params: {
+        bucket: string;
+        prefix?: string;
+        /** The service account key, in JSON format. */
+        service_account_json: string;
+    };
+}
+/** Upload to an Azure Blob Storage container. */
+ | {
+    type: "AzureBlob";
+    params: {
+        container: string;
+        /** The storage account name */
+        account: string;
+        /** The storage account access key */
+        key: string;
+    };
+};
 /** Builds multiple Repos in parallel that match pattern. Response: [BatchExecutionResponse]. */
 export interface BatchBuildRepo {
     /**
@@ -4150,6 +4858,11 @@ export interface BatchBuildRepo {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 /** Clones multiple Repos in parallel that match pattern. Response: [BatchExecutionResponse]. */
 export interface BatchCloneRepo {
@@ -4166,6 +4879,11 @@ export interface BatchCloneRepo {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 /** Deploys multiple Deployments in parallel that match pattern. Response: [BatchExecutionResponse]. */
 export interface BatchDeploy {
@@ -4182,6 +4900,11 @@ export interface BatchDeploy {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 /** Deploys multiple Stacks in parallel that match pattern. Response: [BatchExecutionResponse]. */
 export interface BatchDeployStack {
@@ -4198,6 +4921,11 @@ export interface BatchDeployStack {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 /** Deploys multiple Stacks if changed in parallel that match pattern. Response: [BatchExecutionResponse]. */
 export interface BatchDeployStackIfChanged {
@@ -4214,6 +4942,11 @@ export interface BatchDeployStackIfChanged {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 /** Destroys multiple Deployments in parallel that match pattern. Response: [BatchExecutionResponse]. */
 export interface BatchDestroyDeployment {
@@ -4230,6 +4963,11 @@ export interface BatchDestroyDeployment {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 /** Destroys multiple Stacks in parallel that match pattern. Response: [BatchExecutionResponse]. */
 export interface BatchDestroyStack {
@@ -4246,6 +4984,11 @@ export interface BatchDestroyStack {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 export interface BatchExecutionResponseItemErr {
     name: string;
@@ -4266,6 +5009,11 @@ export interface BatchPullRepo {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 /** Pulls multiple Stacks in parallel that match pattern. Response: [BatchExecutionResponse]. */
 export interface BatchPullStack {
@@ -4282,6 +5030,11 @@ export interface BatchPullStack {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 /** Runs multiple Actions in parallel that match pattern. Response: [BatchExecutionResponse] */
 export interface BatchRunAction {
@@ -4298,6 +5051,11 @@ export interface BatchRunAction {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 /** Runs multiple builds in parallel that match pattern. Response: [BatchExecutionResponse]. */
 export interface BatchRunBuild {
@@ -4314,6 +5072,11 @@ export interface BatchRunBuild {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 /** Runs multiple Procedures in parallel that match pattern. Response: [BatchExecutionResponse]. */
 export interface BatchRunProcedure {
@@ -4330,6 +5093,11 @@ export interface BatchRunProcedure {
      * ```
      */
     pattern: string;
+    /**
+     * `And` to only match resources matching all the patterns.
+     * Default: `Or`, matching resources which match any pattern.
+     */
+    combinator?: SearchCombinator;
 }
 /**
  * Builds the target repo, using the attached builder. Response: [Update].
@@ -4747,6 +5515,16 @@ export interface CopyServer {
     /** The id of the server to copy. */
     id: string;
 }
+/**
+ * Creates a new server group with given `name` and the configuration
+ * of the server group at the given `id`. Response: [ServerGroup].
+ */
+export interface CopyServerGroup {
+    /** The name of the new server group. */
+    name: string;
+    /** The id of the server group to copy. */
+    id: string;
+}
 /**
  * Creates a new stack with given `name` and the configuration
  * of the stack at the given `id`. Response: [Stack].
@@ -4794,6 +5572,11 @@ export interface CreateApiKey {
      * Default is 0, which means no expiry.
      */
     expires?: I64;
+    /**
+     * Optionally limit what the key can do.
+     * Default is None, the key has the full permissions of the user.
+     */
+    permission_ceiling?: ApiKeyPermissionCeiling;
 }
 /**
  * Admin only method to create an api key for a service user.
@@ -4809,6 +5592,11 @@ export interface CreateApiKeyForServiceUser {
      * Default is 0, which means no expiry.
      */
     expires?: I64;
+    /**
+     * Optionally limit what the key can do.
+     * Default is None, the key has the full permissions of the user.
+     */
+    permission_ceiling?: ApiKeyPermissionCeiling;
 }
 /** Create a build. Response: [Build]. */
 export interface CreateBuild {
@@ -4901,6 +5689,66 @@ export interface CreateNetwork {
     /** The name of the network to create. */
     name: string;
 }
+/**
+ * Copy a file into a docker container on the server.
+ * Response: [Update]
+ *
+ * Note. Requires `Execute` and `Terminal` permissions on the server.
+ * Fails if container exec is disabled in the periphery config.
+ */
+export interface CopyFileToContainer {
+    /** Server Id or name */
+    server: string;
+    /** The container name */
+    container: string;
+    /** The path to write the file to inside the container. */
+    dest_path: string;
+    /** The file contents. */
+    contents: string;
+    /**
+     * Whether the contents are base64 encoded,
+     * used for binary files.
+     */
+    base64?: boolean;
+}
+/**
+ * Copy a file out of a docker container on the server.
+ * The contents are base64 encoded. The copy is recorded
+ * as an Update, without the contents.
+ * Response: [CopyFileFromContainerResponse].
+ *
+ * Note. Requires `Execute` and `Terminal` permissions on the server.
+ * Fails if container exec is disabled in the periphery config.
+ */
+export interface CopyFileFromContainer {
+    /** Server Id or name */
+    server: string;
+    /** The container name */
+    container: string;
+    /** The path of the file inside the container. */
+    src_path: string;
+}
+/**
+ * Run a single command in a docker container on the server.
+ * The output is recorded in the logs of the returned [Update].
+ * Response: [Update]
+ *
+ * Note. Requires `Execute` and `Terminal` permissions on the server.
+ * Fails if container exec is disabled in the periphery config.
+ */
+export interface RunContainerCommand {
+    /** Server Id or name */
+    server: string;
+    /** The container name */
+    container: string;
+    /** The command to run, with `sh -c`. */
+    command: string;
+    /**
+     * The working directory inside the container.
+     * If not provided, uses the container's default.
+     */
+    workdir?: string;
+}
 /** Create a procedure. Response: [Procedure]. */
 export interface CreateProcedure {
     /** The name given to newly created build. */
@@ -4941,8 +5789,48 @@ export interface CreateResourceSync {
 export interface CreateServer {
     /** The name given to newly created server. */
     name: string;
-    /** Optional partial config to initialize the server with. */
-    config?: _PartialServerConfig;
+    /** Optional partial config to initialize the server with. */
+    config?: _PartialServerConfig;
+}
+/** Create a server group. Response: [ServerGroup]. */
+export interface CreateServerGroup {
+    /** The name given to newly created server group. */
+    name: string;
+    /** Optional partial config to initialize the server group with. */
+    config?: _PartialServerGroupConfig;
+}
+/**
+ * **Admin only.** Create a service account.
+ * Response: [ServiceAccount].
+ */
+export interface CreateServiceAccount {
+    /** The name for the service account. */
+    name: string;
+    /** A description for the service account. */
+    description?: string;
+    /** Permissions on all resources of a certain type. */
+    permissions?: Record<ResourceTarget["type"], PermissionLevelAndSpecifics | PermissionLevel>;
+}
+
+/**
+ * **Admin only.** Create an api key for a service account.
+ * Response: [CreateApiKeyResponse].
+ */
+export interface CreateServiceAccountApiKey {
+    /** Id or name */
+    service_account: string;
+    /** The name for the api key */
+    key_name: string;
+    /**
+     * A unix timestamp in millseconds specifying api key expire time.
+     * Default is 0, which means no expiry.
+     */
+    expires?: I64;
+    /**
+     * Optionally limit what the key can do.
+     * Default is None, the key has the full permissions of the service account.
+     */
+    permission_ceiling?: ApiKeyPermissionCeiling;
 }
 /**
  * **Admin only.** Create a service user.
@@ -5047,6 +5935,11 @@ export interface CreateVariable {
     description?: string;
     /** Whether to make this a secret variable. */
     is_secret?: boolean;
+    /**
+     * Scope the variable to a Server by id,
+     * overriding the global variable with the same name.
+     */
+    server_id?: string;
 }
 /** Configuration for a Custom alerter endpoint. */
 export interface CustomAlerterEndpoint {
@@ -5213,6 +6106,31 @@ export interface DeleteServer {
     /** The id or name of the server to delete. */
     id: string;
 }
+/**
+ * Deletes the server group at the given id, and returns the deleted server group.
+ * Response: [ServerGroup]
+ */
+export interface DeleteServerGroup {
+    /** The id or name of the server group to delete. */
+    id: string;
+}
+/**
+ * **Admin only.** Delete a service account, along with its api keys.
+ * Response: [ServiceAccount].
+ */
+export interface DeleteServiceAccount {
+    /** Id or name */
+    service_account: string;
+}
+
+/**
+ * **Admin only.** Delete an api key of a service account.
+ * Response: [NoData].
+ */
+export interface DeleteServiceAccountApiKey {
+    /** The api key to delete */
+    key: string;
+}
 /**
  * Deletes the stack at the given id, and returns the deleted stack.
  * Response: [Stack]
@@ -5272,6 +6190,15 @@ export interface DeleteUser {
     /** User id or username */
     user: string;
 }
+/**
+ * **Admin only**. Revoke a login session,
+ * so its JWT can no longer be used.
+ * Response: [UserSession].
+ */
+export interface RevokeUserSession {
+    /** The session jwt id */
+    jwt_id: string;
+}
 /** **Admin only.** Delete a user group. Response: [UserGroup] */
 export interface DeleteUserGroup {
     /** The id of the UserGroup */
@@ -5280,6 +6207,11 @@ export interface DeleteUserGroup {
 /** **Admin only.** Delete a variable. Response: [Variable]. */
 export interface DeleteVariable {
     name: string;
+    /**
+     * The Server id of a scoped variable,
+     * or None for the global variable.
+     */
+    server_id?: string;
 }
 /**
  * Delete a docker volume.
@@ -5484,11 +6416,21 @@ export interface ExportAllResourcesToToml {
      * Default: false
      */
     include_variables?: boolean;
+    /**
+     * Whether to include tags in the exported contents.
+     * Default: false
+     */
+    include_tags?: boolean;
     /**
      * Whether to include user groups in the exported contents.
      * Default: false
      */
     include_user_groups?: boolean;
+    /**
+     * Only export resources with names prefixed by `<namespace>-`,
+     * and strip the prefix from the exported names.
+     */
+    namespace?: string;
 }
 /**
  * Get pretty formatted monrun sync toml for specific resources and user groups.
@@ -5501,6 +6443,13 @@ export interface ExportResourcesToToml {
     user_groups?: string[];
     /** Whether to include variables */
     include_variables?: boolean;
+    /** Whether to include tags */
+    include_tags?: boolean;
+    /**
+     * Only export resources with names prefixed by `<namespace>-`,
+     * and strip the prefix from the exported names.
+     */
+    namespace?: string;
 }
 /**
  * **Admin only.**
@@ -5602,6 +6551,15 @@ export interface GetAlertersSummary {
 export interface GetAlertersSummaryResponse {
     total: number;
 }
+/**
+ * Audit all global variables, including which resources
+ * reference them. Values are never included.
+ * Response: [GetAllVariablesResponse]
+ *
+ * Note. Admin only.
+ */
+export interface GetAllVariables {
+}
 /** Get a specific build. Response: [Build]. */
 export interface GetBuild {
     /** Id or name */
@@ -5884,6 +6842,21 @@ export interface SystemStatsRecord {
     network_ingress_bytes?: number;
     /** Total network egress in bytes */
     network_egress_bytes?: number;
+    /**
+     * Network ingress rate in bytes per second on the server's
+     * configured network interface, or over all interfaces.
+     */
+    network_ingress_bytes_per_sec?: number;
+    /**
+     * Network egress rate in bytes per second on the server's
+     * configured network interface, or over all interfaces.
+     */
+    network_egress_bytes_per_sec?: number;
+    /**
+     * Usage of the server's configured network interface.
+     * Empty if none is configured.
+     */
+    network_interfaces?: SingleNetworkInterfaceUsage[];
 }
 /** Response to [GetHistoricalServerStats]. */
 export interface GetHistoricalServerStatsResponse {
@@ -6062,6 +7035,11 @@ export interface GetServer {
     /** Id or name */
     server: string;
 }
+/** Get a specific server group. Response: [ServerGroup]. */
+export interface GetServerGroup {
+    /** Id or name */
+    server_group: string;
+}
 /** Get current action state for the servers. Response: [ServerActionState]. */
 export interface GetServerActionState {
     /** Id or name */
@@ -6128,6 +7106,28 @@ export interface GetStackLog {
     /** Enable `--timestamps` */
     timestamps?: boolean;
 }
+/**
+ * Get the logs for a single service in a stack.
+ * Response: [GetStackServiceLogsResponse].
+ *
+ * The service must be one of the stack's known services.
+ *
+ * Note. This call will hit the underlying server directly for most up to date log.
+ */
+export interface GetStackServiceLogs {
+    /** Id or name */
+    stack: string;
+    /** The service to get the logs for. */
+    service: string;
+    /**
+     * The number of lines of the log tail to include.
+     * Default: 100.
+     * Max: 5000.
+     */
+    tail: U64;
+    /** Enable `--timestamps` */
+    timestamps?: boolean;
+}
 /** Get a target stack's configured webhooks. Response: [GetStackWebhooksEnabledResponse]. */
 export interface GetStackWebhooksEnabled {
     /** Id or name */
@@ -6245,15 +7245,21 @@ export interface GetUsernameResponse {
     avatar?: string;
 }
 /**
- * List all available global variables.
+ * Get a variable by name.
  * Response: [Variable]
  *
+ * When `server_id` is passed, returns the variable scoped to
+ * that Server if one exists, otherwise the global variable.
+ * This is the value which resources on the Server will use.
+ *
  * Note. For non admin users making this call,
  * secret variables will have their values obscured.
  */
 export interface GetVariable {
     /** The name of the variable to get. */
     name: string;
+    /** Resolve the variable in the context of this Server. */
+    server_id?: string;
 }
 /**
  * Get the version of the Komodo Core api.
@@ -6273,8 +7279,36 @@ export interface GetVersionResponse {
  *
  * 1. `docker compose pull` any Stacks / Deployments with `poll_for_updates` or `auto_update` enabled. This will pick up any available updates.
  * 2. Redeploy Stacks / Deployments that have updates found and 'auto_update' enabled.
+ *
+ * The filters can scope the run to specific resources,
+ * for example to update staging before production.
+ *
+ * With `dry_run`, nothing is pulled or redeployed. Instead the
+ * registry is checked for each running image, and the results are
+ * set on the Update `output` as a list of
+ * [ImageUpdateCheck][crate::entities::docker::image::ImageUpdateCheck].
  */
 export interface GlobalAutoUpdate {
+    /**
+     * Only report the available image updates,
+     * without pulling or redeploying anything.
+     */
+    dry_run?: boolean;
+    /**
+     * Only include resources with all of these tags (id or name).
+     * If empty, doesn't filter by tag.
+     */
+    filter_tags?: string[];
+    /**
+     * Only include resources on these servers (id or name).
+     * If empty, doesn't filter by server.
+     */
+    filter_server_ids?: string[];
+    /**
+     * Only include these resource types, `Stack` and / or `Deployment`.
+     * If empty, includes both.
+     */
+    filter_resource_types?: ResourceTarget["type"][];
 }
 /**
  * Inspect the docker container associated with the Deployment.
@@ -6284,6 +7318,18 @@ export interface InspectDeploymentContainer {
     /** Id or name */
     deployment: string;
 }
+/**
+ * Get the environment variables of the Deployment's
+ * running container, as `KEY=value` lines.
+ * Secret values are redacted.
+ * Response: [GetDeploymentContainerEnvResponse].
+ *
+ * Note. Requires the `Inspect` permission on the Deployment.
+ */
+export interface GetDeploymentContainerEnv {
+    /** Id or name */
+    deployment: string;
+}
 /** Inspect a docker container on the server. Response: [Container]. */
 export interface InspectDockerContainer {
     /** Id or name */
@@ -6312,6 +7358,23 @@ export interface InspectDockerVolume {
     /** The volume name */
     volume: string;
 }
+/**
+ * List the files in a directory of a docker volume on the server.
+ * Response: [GetVolumeBrowserListingResponse].
+ *
+ * Note. Requires the `Inspect` permission on the server.
+ */
+export interface GetVolumeBrowserListing {
+    /** Id or name */
+    server: string;
+    /** The volume name */
+    volume: string;
+    /**
+     * The directory path relative to the volume root.
+     * If empty, lists the volume root.
+     */
+    path?: string;
+}
 /**
  * Inspect the docker container associated with the Stack.
  * Response: [Container].
@@ -6322,6 +7385,46 @@ export interface InspectStackContainer {
     /** The service name to inspect */
     service: string;
 }
+/**
+ * Get the environment variables of a Stack service's
+ * running container, as `KEY=value` lines.
+ * Secret values are redacted.
+ * Response: [GetStackContainerEnvResponse].
+ *
+ * Note. Requires the `Inspect` permission on the Stack.
+ */
+export interface GetStackContainerEnv {
+    /** Id or name */
+    stack: string;
+    /** The service name */
+    service: string;
+}
+/**
+ * Import resources, variables, and user groups from a toml string,
+ * in the same format used by Resource Syncs.
+ * Resources which already exist are updated to match,
+ * nothing is deleted, and no deploys are triggered.
+ * Response: [ImportResourcesFromTomlResponse].
+ *
+ * Note. Admin only.
+ */
+export interface ImportResourcesFromToml {
+    /** The resource toml to import. */
+    toml: string;
+    /** Only compute the changes, without applying them. */
+    dry_run?: boolean;
+}
+/**
+ * Parse resource toml, in the same format used by Resource Syncs,
+ * and preview how it differs from the current resources.
+ * Nothing is changed. Response: [ImportToTomlResponse].
+ *
+ * Note. Admin only.
+ */
+export interface ImportResourcesToToml {
+    /** The resource toml to compare against current resources. */
+    toml: string;
+}
 export interface LatestCommit {
     hash: string;
     message: string;
@@ -6397,6 +7500,28 @@ export interface ListAllDockerContainers {
  */
 export interface ListApiKeys {
 }
+/**
+ * List the audit log of write and execute requests,
+ * sorted by timestamp descending. At most 1000 entries are returned.
+ * Admin only.
+ * Response: [ListAuditLogResponse].
+ */
+export interface ListAuditLog {
+    /** Only include requests made by this user id. */
+    user_id?: string;
+    /** Only include requests acting on this resource. */
+    resource_target?: ResourceTarget;
+    /**
+     * Only include requests made at or after this
+     * unix timestamp in milliseconds.
+     */
+    from_ts?: I64;
+    /**
+     * Only include requests made at or before this
+     * unix timestamp in milliseconds. `0` means no upper bound.
+     */
+    to_ts?: I64;
+}
 /**
  * **Admin only.**
  * Gets list of api keys for the user.
@@ -6590,6 +7715,11 @@ export interface ListFullServers {
     /** optional structured query to filter servers. */
     query?: ServerQuery;
 }
+/** List full server groups matching optional query. Response: [ListFullServerGroupsResponse]. */
+export interface ListFullServerGroups {
+    /** Structured query to filter server groups. */
+    query?: ServerGroupQuery;
+}
 /** List stacks matching optional query. Response: [ListFullStacksResponse]. */
 export interface ListFullStacks {
     /** optional structured query to filter stacks. */
@@ -6638,6 +7768,19 @@ export interface ListRepos {
     /** optional structured query to filter repos. */
     query?: RepoQuery;
 }
+/**
+ * List the fields of live resources which differ from the
+ * sync files, without running the sync.
+ * Response: [ListResourceSyncConflictsResponse].
+ *
+ * The live resources are exported when requested, and compared
+ * against the sync files as of the last sync refresh. Only resources
+ * with pending updates as of the last refresh are compared.
+ */
+export interface ListResourceSyncConflicts {
+    /** Id or name */
+    sync: string;
+}
 /** List syncs matching optional query. Response: [ListResourceSyncsResponse]. */
 export interface ListResourceSyncs {
     /** optional structured query to filter syncs. */
@@ -6669,6 +7812,28 @@ export interface ListServers {
     /** optional structured query to filter servers. */
     query?: ServerQuery;
 }
+/** List server groups matching optional query. Response: [ListServerGroupsResponse]. */
+export interface ListServerGroups {
+    /** Structured query to filter server groups. */
+    query?: ServerGroupQuery;
+}
+/**
+ * **Admin only.**
+ * Gets list of service accounts. Api key secrets are not included.
+ * Response: [ListServiceAccountsResponse]
+ */
+export interface ListServiceAccounts {
+}
+/**
+ * **Admin only.**
+ * Gets list of active login sessions,
+ * optionally filtered to a specific user.
+ * Response: [ListUserSessionsResponse]
+ */
+export interface ListUserSessions {
+    /** Id or username */
+    user?: string;
+}
 /** Lists a specific stacks services (the containers). Response: [ListStackServicesResponse]. */
 export interface ListStackServices {
     /** Id or name */
@@ -6913,6 +8078,16 @@ export interface PruneImages {
     /** Id or name */
     server: string;
 }
+/**
+ * Prunes the docker images on all the servers in the group,
+ * in parallel. Response: [Update].
+ *
+ * 1. Runs `docker image prune -a -f` on each server.
+ */
+export interface PruneImagesOnGroup {
+    /** Id or name */
+    server_group: string;
+}
 /**
  * Prunes the docker networks on the target server. Response: [Update].
  *
@@ -6931,6 +8106,16 @@ export interface PruneSystem {
     /** Id or name */
     server: string;
 }
+/**
+ * Prunes the docker system on all the servers in the group,
+ * in parallel, including volumes. Response: [Update].
+ *
+ * 1. Runs `docker system prune -a -f --volumes` on each server.
+ */
+export interface PruneSystemOnGroup {
+    /** Id or name */
+    server_group: string;
+}
 /**
  * Prunes the docker volumes on the target server. Response: [Update].
  *
@@ -7104,6 +8289,16 @@ export interface RenameServer {
     /** The new name. */
     name: string;
 }
+/**
+ * Rename the ServerGroup at id to the given name.
+ * Response: [Update].
+ */
+export interface RenameServerGroup {
+    /** The id or name of the ServerGroup to rename. */
+    id: string;
+    /** The new name. */
+    name: string;
+}
 /** Rename the stack at id to the given name. Response: [Update]. */
 export interface RenameStack {
     /** The id of the stack to rename. */
@@ -7125,6 +8320,19 @@ export interface RenameUserGroup {
     /** The new name for the UserGroup */
     name: string;
 }
+/**
+ * Generate a new secret for one of the calling user's api keys.
+ * The old secret stops working immediately, while the key,
+ * name, expiry, and permission ceiling are kept.
+ * Response: [RotateApiKeyResponse].
+ *
+ * Note. After the response is served, there will be no way
+ * to get the secret later.
+ */
+export interface RotateApiKey {
+    /** The key which the user intends to rotate. */
+    key: string;
+}
 export declare enum DefaultRepoFolder {
     /** /${root_directory}/stacks */
     Stacks = "Stacks",
@@ -7164,6 +8372,13 @@ export interface RepoExecutionArgs {
      * Depends on the resource type.
      */
     default_folder: DefaultRepoFolder;
+    /**
+     * Only check out these paths using `git sparse-checkout`.
+     * Empty means the whole repo is checked out.
+     */
+    sparse_checkout?: string[];
+    /** Make a shallow clone with this depth. 0 means a full clone. */
+    clone_depth?: number;
 }
 export interface RepoExecutionResponse {
     /** Response logs */
@@ -7202,6 +8417,12 @@ export interface ResourceToml<PartialConfig> {
     /** Resource specific configuration. */
     config?: PartialConfig;
 }
+export interface TagToml {
+    /** Tag name */
+    name: string;
+    /** Tag color. Default: Slate. */
+    color?: TagColor;
+}
 export interface UserGroupToml {
     /** User group name */
     name: string;
@@ -7226,6 +8447,8 @@ export interface ResourcesToml {
     alerters?: ResourceToml<_PartialAlerterConfig>[];
     builders?: ResourceToml<_PartialBuilderConfig>[];
     resource_syncs?: ResourceToml<_PartialResourceSyncConfig>[];
+    server_groups?: ResourceToml<_PartialServerGroupConfig>[];
+    tags?: TagToml[];
     user_groups?: UserGroupToml[];
     variables?: Variable[];
 }
@@ -7295,6 +8518,24 @@ export interface RunBuild {
     /** Can be build id or name */
     build: string;
 }
+/**
+ * Runs a shell command on all the servers in the group,
+ * in parallel. Response: [Update].
+ *
+ * Requires the `Terminal` permission on the server group,
+ * and is skipped on member servers with terminals disabled.
+ */
+export interface RunCommandOnGroup {
+    /** Id or name */
+    server_group: string;
+    /** The command to run. */
+    command: string;
+    /**
+     * The directory to run the command in.
+     * If empty, uses the Periphery working directory.
+     */
+    path?: string;
+}
 /** Runs the target Procedure. Response: [Update] */
 export interface RunProcedure {
     /** Id or name */
@@ -7342,6 +8583,11 @@ export interface RunSync {
      * Supports name or id.
      */
     resources?: string[];
+    /**
+     * Only compute the changes and log them to the Update,
+     * without executing any of them.
+     */
+    dry_run?: boolean;
 }
 export declare enum SearchCombinator {
     Or = "Or",
@@ -7454,6 +8700,11 @@ export interface ServerHealth {
     cpu: ServerHealthState;
     mem: ServerHealthState;
     disks: Record<string, ServerHealthState>;
+    /**
+     * Only includes disks reporting inodes,
+     * when inode thresholds are configured.
+     */
+    inodes?: Record<string, ServerHealthState>;
 }
 /**
  * **Admin only.** Set `everyone` property of User Group.
@@ -7503,15 +8754,36 @@ export interface SignUpLocalUser {
 export interface SingleNetworkInterfaceUsage {
     /** The network interface name */
     name: string;
-    /** The ingress in bytes */
+    /** The ingress in bytes since the last poll */
     ingress_bytes: number;
-    /** The egress in bytes */
+    /** The egress in bytes since the last poll */
     egress_bytes: number;
+    /** The total received bytes counter */
+    total_ingress_bytes?: number;
+    /** The total transmitted bytes counter */
+    total_egress_bytes?: number;
+    /** The ingress rate in bytes per second */
+    ingress_bytes_per_sec?: number;
+    /** The egress rate in bytes per second */
+    egress_bytes_per_sec?: number;
 }
 /** Configuration for a Slack alerter. */
 export interface SlackAlerterEndpoint {
     /** The Slack app webhook url */
     url: string;
+    /**
+     * Optionally post to this channel instead of the
+     * webhook default, eg. `#alerts`.
+     * If empty, uses the webhook default.
+     */
+    channel?: string;
+    /** Optionally override the username the alert is posted with. */
+    username?: string;
+    /**
+     * Optionally override the icon the alert is posted with,
+     * eg. `:lizard:`.
+     */
+    icon_emoji?: string;
 }
 /** Sleeps for the specified time. */
 export interface Sleep {
@@ -7874,6 +9146,20 @@ export interface UpdateServer {
     /** The partial config update to apply. */
     config: _PartialServerConfig;
 }
+/**
+ * Update the server group at the given id, and return the updated server group. Response: [ServerGroup].
+ *
+ * Note. This method updates only the fields which are set in the [PartialServerGroupConfig][crate::entities::server_group::PartialServerGroupConfig],
+ * effectively merging diffs into the final document. This is helpful when multiple users are using
+ * the same resources concurrently by ensuring no unintentional
+ * field changes occur from out of date local state.
+ */
+export interface UpdateServerGroup {
+    /** The id of the server group to update. */
+    id: string;
+    /** The partial config update to apply. */
+    config: _PartialServerGroupConfig;
+}
 /**
  * **Admin only.** Update a service user's description.
  * Response: [User].
@@ -7934,6 +9220,20 @@ export interface UpdateUserBasePermissions {
     /** If specified, will update user's ability to create builds. */
     create_builds?: boolean;
 }
+/**
+ * **Admin only.** Give the User Group permissions on all
+ * resources with the given tag. Setting the level to `None`
+ * with no specific permissions removes the tag permission.
+ * Response: [UserGroup]
+ */
+export interface UpdateUserGroupTagPermission {
+    /** Id or name. */
+    user_group: string;
+    /** Tag id or name. */
+    tag: string;
+    /** The permission to give on resources with the tag. */
+    permission: PermissionLevelAndSpecifics;
+}
 /**
  * **Only for local users**. Update the calling users password.
  * Response: [NoData].
@@ -7954,6 +9254,11 @@ export interface UpdateVariableDescription {
     name: string;
     /** The description to set. */
     description: string;
+    /**
+     * The Server id of a scoped variable,
+     * or None for the global variable.
+     */
+    server_id?: string;
 }
 /** **Admin only.** Update whether variable is secret. Response: [Variable]. */
 export interface UpdateVariableIsSecret {
@@ -7961,6 +9266,11 @@ export interface UpdateVariableIsSecret {
     name: string;
     /** Whether variable is secret. */
     is_secret: boolean;
+    /**
+     * The Server id of a scoped variable,
+     * or None for the global variable.
+     */
+    server_id?: string;
 }
 /** **Admin only.** Update variable value. Response: [Variable]. */
 export interface UpdateVariableValue {
@@ -7968,6 +9278,11 @@ export interface UpdateVariableValue {
     name: string;
     /** The value to set. */
     value: string;
+    /**
+     * The Server id of a scoped variable,
+     * or None for the global variable.
+     */
+    server_id?: string;
 }
 /** Configuration for a Komodo Url Builder. */
 export interface UrlBuilderConfig {
@@ -7994,6 +9309,12 @@ export interface WriteStackFileContents {
     file_path: string;
     /** The contents to write. */
     contents: string;
+    /**
+     * Whether the contents are base64 encoded,
+     * and should be decoded before writing.
+     * Use for binary files such as certificates.
+     */
+    base64_contents?: boolean;
 }
 /** Rename the stack at id to the given name. Response: [Update]. */
 export interface WriteSyncFileContents {
@@ -8098,6 +9419,15 @@ export type ExecuteRequest = {
 } | {
     type: "PruneSystem";
     params: PruneSystem;
+} | {
+    type: "RunCommandOnGroup";
+    params: RunCommandOnGroup;
+} | {
+    type: "PruneImagesOnGroup";
+    params: PruneImagesOnGroup;
+} | {
+    type: "PruneSystemOnGroup";
+    params: PruneSystemOnGroup;
 } | {
     type: "DeployStack";
     params: DeployStack;
@@ -8227,6 +9557,9 @@ export type ExecuteRequest = {
 } | {
     type: "BackupCoreDatabase";
     params: BackupCoreDatabase;
+} | {
+    type: "RestoreDatabase";
+    params: RestoreDatabase;
 } | {
     type: "GlobalAutoUpdate";
     params: GlobalAutoUpdate;
@@ -8343,6 +9676,12 @@ export type ReadRequest = {
 } | {
     type: "ListUsers";
     params: ListUsers;
+} | {
+    type: "ListServiceAccounts";
+    params: ListServiceAccounts;
+} | {
+    type: "ListUserSessions";
+    params: ListUserSessions;
 } | {
     type: "ListApiKeys";
     params: ListApiKeys;
@@ -8355,6 +9694,9 @@ export type ReadRequest = {
 } | {
     type: "ListUserTargetPermissions";
     params: ListUserTargetPermissions;
+} | {
+    type: "ListAuditLog";
+    params: ListAuditLog;
 } | {
     type: "GetUserGroup";
     params: GetUserGroup;
@@ -8442,6 +9784,9 @@ export type ReadRequest = {
 } | {
     type: "InspectDockerVolume";
     params: InspectDockerVolume;
+} | {
+    type: "GetVolumeBrowserListing";
+    params: GetVolumeBrowserListing;
 } | {
     type: "GetDockerContainersSummary";
     params: GetDockerContainersSummary;
@@ -8466,6 +9811,15 @@ export type ReadRequest = {
 } | {
     type: "ListTerminals";
     params: ListTerminals;
+} | {
+    type: "GetServerGroup";
+    params: GetServerGroup;
+} | {
+    type: "ListServerGroups";
+    params: ListServerGroups;
+} | {
+    type: "ListFullServerGroups";
+    params: ListFullServerGroups;
 } | {
     type: "GetSystemInformation";
     params: GetSystemInformation;
@@ -8490,12 +9844,18 @@ export type ReadRequest = {
 } | {
     type: "GetStackLog";
     params: GetStackLog;
+} | {
+    type: "GetStackServiceLogs";
+    params: GetStackServiceLogs;
 } | {
     type: "SearchStackLog";
     params: SearchStackLog;
 } | {
     type: "InspectStackContainer";
     params: InspectStackContainer;
+} | {
+    type: "GetStackContainerEnv";
+    params: GetStackContainerEnv;
 } | {
     type: "ListStacks";
     params: ListStacks;
@@ -8535,6 +9895,9 @@ export type ReadRequest = {
 } | {
     type: "InspectDeploymentContainer";
     params: InspectDeploymentContainer;
+} | {
+    type: "GetDeploymentContainerEnv";
+    params: GetDeploymentContainerEnv;
 } | {
     type: "ListDeployments";
     params: ListDeployments;
@@ -8598,6 +9961,9 @@ export type ReadRequest = {
 } | {
     type: "GetResourceSyncActionState";
     params: GetResourceSyncActionState;
+} | {
+    type: "ListResourceSyncConflicts";
+    params: ListResourceSyncConflicts;
 } | {
     type: "GetSyncWebhooksEnabled";
     params: GetSyncWebhooksEnabled;
@@ -8637,6 +10003,9 @@ export type ReadRequest = {
 } | {
     type: "ExportResourcesToToml";
     params: ExportResourcesToToml;
+} | {
+    type: "ImportResourcesToToml";
+    params: ImportResourcesToToml;
 } | {
     type: "GetTag";
     params: GetTag;
@@ -8661,6 +10030,9 @@ export type ReadRequest = {
 } | {
     type: "ListVariables";
     params: ListVariables;
+} | {
+    type: "GetAllVariables";
+    params: GetAllVariables;
 } | {
     type: "GetGitProviderAccount";
     params: GetGitProviderAccount;
@@ -8724,6 +10096,9 @@ export type UserRequest = {
 } | {
     type: "DeleteApiKey";
     params: DeleteApiKey;
+} | {
+    type: "RotateApiKey";
+    params: RotateApiKey;
 };
 export type WriteRequest = {
     type: "CreateLocalUser";
@@ -8737,6 +10112,9 @@ export type WriteRequest = {
 } | {
     type: "DeleteUser";
     params: DeleteUser;
+} | {
+    type: "RevokeUserSession";
+    params: RevokeUserSession;
 } | {
     type: "CreateServiceUser";
     params: CreateServiceUser;
@@ -8749,6 +10127,18 @@ export type WriteRequest = {
 } | {
     type: "DeleteApiKeyForServiceUser";
     params: DeleteApiKeyForServiceUser;
+} | {
+    type: "CreateServiceAccount";
+    params: CreateServiceAccount;
+} | {
+    type: "DeleteServiceAccount";
+    params: DeleteServiceAccount;
+} | {
+    type: "CreateServiceAccountApiKey";
+    params: CreateServiceAccountApiKey;
+} | {
+    type: "DeleteServiceAccountApiKey";
+    params: DeleteServiceAccountApiKey;
 } | {
     type: "CreateUserGroup";
     params: CreateUserGroup;
@@ -8770,6 +10160,9 @@ export type WriteRequest = {
 } | {
     type: "SetEveryoneUserGroup";
     params: SetEveryoneUserGroup;
+} | {
+    type: "UpdateUserGroupTagPermission";
+    params: UpdateUserGroupTagPermission;
 } | {
     type: "UpdateUserAdmin";
     params: UpdateUserAdmin;
@@ -8803,6 +10196,15 @@ export type WriteRequest = {
 } | {
     type: "CreateNetwork";
     params: CreateNetwork;
+} | {
+    type: "CopyFileToContainer";
+    params: CopyFileToContainer;
+} | {
+    type: "CopyFileFromContainer";
+    params: CopyFileFromContainer;
+} | {
+    type: "RunContainerCommand";
+    params: RunContainerCommand;
 } | {
     type: "CreateTerminal";
     params: CreateTerminal;
@@ -8812,6 +10214,21 @@ export type WriteRequest = {
 } | {
     type: "DeleteAllTerminals";
     params: DeleteAllTerminals;
+} | {
+    type: "CreateServerGroup";
+    params: CreateServerGroup;
+} | {
+    type: "CopyServerGroup";
+    params: CopyServerGroup;
+} | {
+    type: "DeleteServerGroup";
+    params: DeleteServerGroup;
+} | {
+    type: "UpdateServerGroup";
+    params: UpdateServerGroup;
+} | {
+    type: "RenameServerGroup";
+    params: RenameServerGroup;
 } | {
     type: "CreateStack";
     params: CreateStack;
@@ -8989,6 +10406,9 @@ export type WriteRequest = {
 } | {
     type: "CommitSync";
     params: CommitSync;
+} | {
+    type: "ImportResourcesFromToml";
+    params: ImportResourcesFromToml;
 } | {
     type: "RefreshResourceSyncPending";
     params: RefreshResourceSyncPending;
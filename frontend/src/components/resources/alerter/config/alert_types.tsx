@@ -11,6 +11,7 @@ const ALERT_TYPES: Types.AlertData["type"][] = [
   "ServerCpu",
   "ServerMem",
   "ServerDisk",
+  "ServerInodes",
   // Stack
   "StackStateChange",
   "StackImageUpdateAvailable",
@@ -19,6 +20,7 @@ const ALERT_TYPES: Types.AlertData["type"][] = [
   "ContainerStateChange",
   "DeploymentImageUpdateAvailable",
   "DeploymentAutoUpdated",
+  "ContainerUnhealthy",
   // Misc
   "ScheduleRun",
   "BuildFailed",
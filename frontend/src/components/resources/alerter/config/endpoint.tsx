@@ -80,6 +80,63 @@ export const EndpointConfig = ({
       ) : (
         ""
       )}
+      {endpoint.type == "Slack" ? (
+        <>
+          <ConfigItem
+            label="Channel"
+            description="Optionally post to this channel instead of the webhook default."
+          >
+            <Input
+              value={endpoint.params.channel ?? ""}
+              readOnly={disabled}
+              placeholder="#alerts"
+              onChange={(input) =>
+                set({
+                  ...endpoint,
+                  params: { ...endpoint.params, channel: input.target.value },
+                })
+              }
+            ></Input>
+          </ConfigItem>
+          <ConfigItem
+            label="Username"
+            description="Optionally override the username the alert is posted with."
+          >
+            <Input
+              value={endpoint.params.username ?? ""}
+              readOnly={disabled}
+              placeholder="Komodo"
+              onChange={(input) =>
+                set({
+                  ...endpoint,
+                  params: { ...endpoint.params, username: input.target.value },
+                })
+              }
+            ></Input>
+          </ConfigItem>
+          <ConfigItem
+            label="Icon Emoji"
+            description="Optionally override the icon the alert is posted with."
+          >
+            <Input
+              value={endpoint.params.icon_emoji ?? ""}
+              readOnly={disabled}
+              placeholder=":lizard:"
+              onChange={(input) =>
+                set({
+                  ...endpoint,
+                  params: {
+                    ...endpoint.params,
+                    icon_emoji: input.target.value,
+                  },
+                })
+              }
+            ></Input>
+          </ConfigItem>
+        </>
+      ) : (
+        ""
+      )}
     </ConfigItem>
   );
 };
@@ -94,6 +94,9 @@ type MinExecutionType = Exclude<
   | "DeleteImage"
   | "DeleteVolume"
   | "TestAlerter"
+  | "RunCommandOnGroup"
+  | "PruneImagesOnGroup"
+  | "PruneSystemOnGroup"
 >;
 
 type ExecutionConfigParams<T extends MinExecutionType> = Extract<
@@ -448,6 +451,48 @@ const Stage = ({
           className="w-[300px] text-md"
         />
         <div className="flex gap-4 items-center">
+          <div>Run:</div>
+          <Select
+            value={stage.run_condition ?? Types.StageRunCondition.OnSuccess}
+            onValueChange={(run_condition) =>
+              setStage({
+                ...stage,
+                run_condition: run_condition as Types.StageRunCondition,
+              })
+            }
+            disabled={disabled}
+          >
+            <SelectTrigger className="w-[150px]" disabled={disabled}>
+              <SelectValue placeholder="Select Condition" />
+            </SelectTrigger>
+            <SelectContent>
+              {Object.values(Types.StageRunCondition).map((condition) => (
+                <SelectItem
+                  key={condition}
+                  value={condition}
+                  className="cursor-pointer"
+                >
+                  {fmt_upper_camelcase(condition)}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          <div>Timeout:</div>
+          <Input
+            type="number"
+            placeholder="None"
+            value={stage.timeout_seconds ?? ""}
+            onChange={(e) =>
+              setStage({
+                ...stage,
+                timeout_seconds: e.target.value
+                  ? Math.max(0, Number(e.target.value))
+                  : undefined,
+              })
+            }
+            className="w-[100px]"
+            disabled={disabled}
+          />
           <div>Enabled:</div>
           <Switch
             checked={stage.enabled}
@@ -206,6 +206,10 @@ export const ResourceSyncConfig = ({
         label: "Sync Variables",
         description: "Include variables in the sync.",
       },
+      include_tags: {
+        label: "Sync Tags",
+        description: "Include tags in the sync.",
+      },
       include_user_groups: {
         label: "Sync User Groups",
         description: "Include user groups in the sync.",
@@ -228,6 +232,16 @@ export const ResourceSyncConfig = ({
       ),
     },
   };
+  const namespace: ConfigComponent<Types.ResourceSyncConfig> = {
+    label: "Namespace",
+    description:
+      "Optionally prefix all the resource names in the sync with '<namespace>-', and only manage resources with the prefix. Variables and user groups can't be synced with a namespace.",
+    components: {
+      namespace: {
+        placeholder: "Enter namespace",
+      },
+    },
+  };
 
   const pending_alerts: ConfigComponent<Types.ResourceSyncConfig> = {
     label: "Alerts",
@@ -266,6 +280,7 @@ export const ResourceSyncConfig = ({
           },
         },
         match_tags,
+        namespace,
         include_toggles,
         pending_alerts,
       ],
@@ -528,6 +543,7 @@ export const ResourceSyncConfig = ({
           },
         },
         match_tags,
+        namespace,
         include_toggles,
         pending_alerts,
         webhooks_config,
@@ -565,6 +581,7 @@ export const ResourceSyncConfig = ({
         },
         general_common,
         match_tags,
+        namespace,
         include_toggles,
         pending_alerts,
       ],
@@ -1,10 +1,11 @@
 import { atomWithStorage, useRead, useUser } from "@lib/hooks";
-import { RequiredResourceComponents } from "@types";
+import { RequiredResourceComponents, UsableResource } from "@types";
 import { Card } from "@ui/card";
 import { Clock, FolderSync } from "lucide-react";
 import {
   DeleteResource,
   NewResource,
+  ResourceLink,
   ResourcePageHeader,
   StandardSource,
 } from "../common";
@@ -218,6 +219,47 @@ export const ResourceSyncComponents: RequiredResourceComponents = {
   },
 
   Status: {
+    Conflicts: ({ id }) => {
+      const conflicts = useRead(
+        "ListResourceSyncConflicts",
+        { sync: id },
+        { refetchInterval: 30_000 }
+      ).data;
+      if (!conflicts?.length) {
+        return null;
+      }
+      return (
+        <Tooltip>
+          <TooltipTrigger asChild>
+            <Card className="px-3 py-2 bg-destructive/75 hover:bg-destructive transition-colors cursor-pointer">
+              <div className="text-sm text-nowrap overflow-hidden overflow-ellipsis">
+                {conflicts.length} Conflict{conflicts.length > 1 ? "s" : ""}
+              </div>
+            </Card>
+          </TooltipTrigger>
+          <TooltipContent>
+            <div className="grid gap-2">
+              <div className="text-muted-foreground">
+                Live resource fields which differ from the sync files, as of
+                the last refresh.
+              </div>
+              {conflicts.map((conflict) => (
+                <div
+                  key={`${conflict.resource_target.id}-${conflict.field}`}
+                  className="flex items-center gap-2"
+                >
+                  <ResourceLink
+                    type={conflict.resource_target.type as UsableResource}
+                    id={conflict.resource_target.id}
+                  />
+                  <span className="font-mono">{conflict.field}</span>
+                </div>
+              ))}
+            </div>
+          </TooltipContent>
+        </Tooltip>
+      );
+    },
     Hash: ({ id }) => {
       const info = useFullResourceSync(id)?.info;
       if (!info?.pending_hash) {
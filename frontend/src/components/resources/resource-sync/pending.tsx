@@ -232,6 +232,60 @@ export const ResourceSyncPending = ({
           </Card>
         );
       })}
+      {/* Pending Tag Update */}
+      {sync?.info?.tag_updates?.map((data, i) => {
+        return (
+          <Card key={i}>
+            <CardHeader
+              className={cn(
+                "font-mono pb-2",
+                text_color_class_by_intention(
+                  diff_type_intention(data.type, view === "Commit")
+                )
+              )}
+            >
+              {view === "Commit" ? reverse_pending_type(data.type) : data.type}{" "}
+              Tag
+            </CardHeader>
+            <CardContent>
+              {data.type === "Create" && (
+                <MonacoEditor
+                  value={data.data.proposed}
+                  language="fancy_toml"
+                  readOnly
+                />
+              )}
+              {data.type === "Update" && (
+                <>
+                  {view === "Execute" && (
+                    <MonacoDiffEditor
+                      original={data.data.current}
+                      modified={data.data.proposed}
+                      language="fancy_toml"
+                      readOnly
+                    />
+                  )}
+                  {view === "Commit" && (
+                    <MonacoDiffEditor
+                      original={data.data.proposed}
+                      modified={data.data.current}
+                      language="fancy_toml"
+                      readOnly
+                    />
+                  )}
+                </>
+              )}
+              {data.type === "Delete" && (
+                <MonacoEditor
+                  value={data.data.current}
+                  language="fancy_toml"
+                  readOnly
+                />
+              )}
+            </CardContent>
+          </Card>
+        );
+      })}
       {/* Pending User Group Update */}
       {sync?.info?.user_group_updates?.map((data, i) => {
         return (
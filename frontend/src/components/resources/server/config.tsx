@@ -117,6 +117,11 @@ export const ServerConfig = ({
                 description:
                   "Whether to store historical CPU, RAM, and disk usage.",
               },
+              network_interface: {
+                description:
+                  "The network interface used for historical network rates, eg 'eth0'. Leave empty to use all interfaces.",
+                placeholder: "eth0",
+              },
             },
           },
           {
@@ -214,6 +219,14 @@ export const ServerConfig = ({
                 description:
                   "Send a 'Critical' alert if the disk usage in % is above these thresholds",
               },
+              inode_warning: {
+                description:
+                  "Send a 'Warning' alert if the inode usage in % (for any mounted disk) is above this threshold. Disabled if 0.",
+              },
+              inode_critical: {
+                description:
+                  "Send a 'Critical' alert if the inode usage in % (for any mounted disk) is above this threshold. Disabled if 0.",
+              },
             },
           },
           {
@@ -374,6 +374,38 @@ export const StackConfig = ({
         ),
       },
     },
+    {
+      label: "On Deploy",
+      description:
+        "Execute a shell command once the Stack is running and all health checks pass. The 'path' is relative to the Run Directory",
+      components: {
+        on_deploy: (value, set) => (
+          <SystemCommand
+            value={value}
+            set={(value) => set({ on_deploy: value })}
+            disabled={disabled}
+          />
+        ),
+        on_deploy_timeout: {
+          description:
+            "The max seconds to wait for the Stack to become healthy before running the command. If 0, runs without waiting.",
+        },
+      },
+    },
+    {
+      label: "On Destroy",
+      description:
+        "Execute a shell command before running docker compose down. The 'path' is relative to the Run Directory",
+      components: {
+        on_destroy: (value, set) => (
+          <SystemCommand
+            value={value}
+            set={(value) => set({ on_destroy: value })}
+            disabled={disabled}
+          />
+        ),
+      },
+    },
     {
       label: "Extra Args",
       labelHidden: true,
@@ -410,7 +410,8 @@ export const TopbarUpdates = () => {
 
 const SingleUpdate = ({ update }: { update: Types.UpdateListItem }) => {
   const Components =
-    update.target.type !== "System"
+    update.target.type !== "System" &&
+    update.target.type !== "ServerGroup"
       ? ResourceComponents[update.target.type]
       : null;
 
@@ -447,7 +448,9 @@ const SingleUpdate = ({ update }: { update: Types.UpdateListItem }) => {
               {!Components && (
                 <>
                   <Settings className="w-4 h-4" />
-                  System
+                  {update.target.type === "ServerGroup"
+                    ? "Server Group"
+                    : "System"}
                 </>
               )}
             </div>
@@ -146,7 +146,8 @@ export const UpdateDetailsContent = ({
       </div>
     );
   const Components =
-    update.target.type === "System"
+    update.target.type === "System" ||
+    update.target.type === "ServerGroup"
       ? null
       : ResourceComponents[update.target.type];
   return (
@@ -180,7 +181,9 @@ export const UpdateDetailsContent = ({
             ) : (
               <div className="flex items-center gap-2">
                 <Settings className="w-4 h-4" />
-                System
+                {update.target.type === "ServerGroup"
+                  ? "Server Group"
+                  : "System"}
               </div>
             )}
             {update.version && (
@@ -44,10 +44,13 @@ export const UpdatesTable = ({
           showTarget && {
             header: "Target",
             cell: ({ row }) =>
-              row.original.target.type === "System" ? (
+              row.original.target.type === "System" ||
+              row.original.target.type === "ServerGroup" ? (
                 <div className="flex items-center gap-2">
                   <Settings className="w-4 h-4" />
-                  System
+                  {row.original.target.type === "ServerGroup"
+                    ? "Server Group"
+                    : "System"}
                 </div>
               ) : (
                 <ResourceLink
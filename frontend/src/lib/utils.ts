@@ -245,6 +245,7 @@ export const sync_no_changes = (sync: Types.ResourceSync) => {
     (sync.info?.pending_deploy?.to_deploy ?? 0) === 0 &&
     (sync.info?.resource_updates?.length ?? 0) === 0 &&
     (sync.info?.variable_updates?.length ?? 0) === 0 &&
+    (sync.info?.tag_updates?.length ?? 0) === 0 &&
     (sync.info?.user_group_updates?.length ?? 0) === 0
   );
 };
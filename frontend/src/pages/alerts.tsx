@@ -26,12 +26,24 @@ import { ResourceComponents } from "@components/resources";
 import { ResourceSelector } from "@components/resources/common";
 
 const ALERT_TYPES_BY_RESOURCE: { [key: string]: Types.AlertData["type"][] } = {
-  Server: ["ServerUnreachable", "ServerCpu", "ServerMem", "ServerDisk"],
-  Stack: ["StackStateChange", "StackImageUpdateAvailable", "StackAutoUpdated"],
+  Server: [
+    "ServerUnreachable",
+    "ServerCpu",
+    "ServerMem",
+    "ServerDisk",
+    "ServerInodes",
+  ],
+  Stack: [
+    "StackStateChange",
+    "StackImageUpdateAvailable",
+    "StackAutoUpdated",
+    "ContainerUnhealthy",
+  ],
   Deployment: [
     "ContainerStateChange",
     "DeploymentImageUpdateAvailable",
     "DeploymentAutoUpdated",
+    "ContainerUnhealthy",
   ],
   Build: ["BuildFailed"],
   Repo: ["RepoBuildFailed"],
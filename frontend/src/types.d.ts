@@ -1,6 +1,10 @@
 import { Types } from "komodo_client";
 
-export type UsableResource = Exclude<Types.ResourceTarget["type"], "System">;
+// Server Groups are only managed through the api / syncs for now.
+export type UsableResource = Exclude<
+  Types.ResourceTarget["type"],
+  "System" | "ServerGroup"
+>;
 
 type IdComponent = React.FC<{ id: string }>;
 type OptionalIdComponent = React.FC<{ id?: string }>;
@@ -1,10 +1,19 @@
-use std::path::Path;
+use std::{
+  ops::{Deref, DerefMut},
+  path::Path,
+  process::Stdio,
+};
 
 use komodo_client::{
   entities::{komodo_timestamp, update::Log},
   parsers::parse_multiline_command,
 };
-use run_command::{CommandOutput, async_run_command};
+use run_command::CommandOutput;
+use tokio::{
+  io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, BufReader},
+  process::{Child, Command},
+  sync::mpsc,
+};
 
 pub async fn run_komodo_command(
   stage: &str,
@@ -17,8 +26,110 @@ pub async fn run_komodo_command(
     command.as_ref().to_string()
   };
   let start_ts = komodo_timestamp();
-  let output = async_run_command(&command).await;
-  output_into_log(stage, command, start_ts, output)
+
+  let mut log = Log {
+    stage: stage.to_string(),
+    start_ts,
+    ..Default::default()
+  };
+
+  let mut child = match KomodoChildProcess::spawn(&command) {
+    Ok(child) => child,
+    Err(e) => {
+      log.command = command;
+      log.stderr = format!("Failed to spawn command | {e:?}");
+      log.end_ts = komodo_timestamp();
+      return log;
+    }
+  };
+
+  let stdout = child.stdout.take();
+  let stderr = child.stderr.take();
+  let (stdout, mut stderr, status) = tokio::join!(
+    read_output(stdout),
+    read_output(stderr),
+    child.wait()
+  );
+
+  match status {
+    Ok(status) => log.success = status.success(),
+    Err(e) => {
+      stderr.push_str(&format!("Failed to wait on command | {e:?}"))
+    }
+  }
+
+  log.command = command;
+  log.stdout = stdout;
+  log.stderr = stderr;
+  log.end_ts = komodo_timestamp();
+  log
+}
+
+/// Wraps a child process spawned in its own process group.
+/// If dropped before the process is waited on to completion,
+/// for example when the calling future is cancelled or
+/// Periphery shuts down, sends SIGTERM to the whole group
+/// so processes like `docker build` are not leaked.
+///
+/// Note this can't help if the parent is killed with SIGKILL,
+/// as no destructors run.
+pub struct KomodoChildProcess(Child);
+
+impl KomodoChildProcess {
+  /// Spawns `sh -c <command>` with piped stdout / stderr.
+  pub fn spawn(command: &str) -> std::io::Result<Self> {
+    Command::new("sh")
+      .arg("-c")
+      .arg(command)
+      .stdin(Stdio::null())
+      .stdout(Stdio::piped())
+      .stderr(Stdio::piped())
+      // Start a new process group with the child as leader,
+      // so the group id is the child pid.
+      .process_group(0)
+      .spawn()
+      .map(Self)
+  }
+}
+
+impl Deref for KomodoChildProcess {
+  type Target = Child;
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl DerefMut for KomodoChildProcess {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
+
+impl Drop for KomodoChildProcess {
+  fn drop(&mut self) {
+    // The id is None once the child has been waited on,
+    // so this won't signal a reused process group id.
+    let Some(pid) = self.0.id() else {
+      return;
+    };
+    // Negative pid sends the signal to the process group.
+    unsafe {
+      libc::kill(-(pid as libc::pid_t), libc::SIGTERM);
+    }
+  }
+}
+
+async fn read_output(
+  reader: Option<impl AsyncRead + Unpin>,
+) -> String {
+  let Some(mut reader) = reader else {
+    return String::new();
+  };
+  let mut buf = Vec::new();
+  if let Err(e) = reader.read_to_end(&mut buf).await {
+    return format!("Failed to read command output | {e:?}");
+  }
+  String::from_utf8_lossy(&buf).into_owned()
 }
 
 /// Parses commands out of multiline string
@@ -69,6 +180,113 @@ pub async fn run_komodo_command_with_sanitization(
   Some(log)
 }
 
+/// Executes the command, calling `on_line` with each line of
+/// stdout / stderr as it is produced, for example to track progress.
+/// The text returned by `on_line` is recorded in the log in place of the line,
+/// so machine readable output can be rendered. Returning None skips the line.
+/// The output is sanitized to avoid exposing secrets in the log.
+pub async fn run_komodo_command_streaming(
+  stage: &str,
+  path: impl Into<Option<&Path>>,
+  command: impl AsRef<str>,
+  replacers: &[(String, String)],
+  mut on_line: impl FnMut(String) -> Option<String>,
+) -> Log {
+  let command = if let Some(path) = path.into() {
+    format!("cd {} && {}", path.display(), command.as_ref())
+  } else {
+    command.as_ref().to_string()
+  };
+  let start_ts = komodo_timestamp();
+
+  let mut log = Log {
+    stage: stage.to_string(),
+    command: svi::replace_in_string(&command, replacers),
+    start_ts,
+    ..Default::default()
+  };
+
+  let mut child = match KomodoChildProcess::spawn(&command) {
+    Ok(child) => child,
+    Err(e) => {
+      log.stderr = format!("Failed to spawn command | {e:?}");
+      log.end_ts = komodo_timestamp();
+      return log;
+    }
+  };
+
+  let (sender, mut receiver) = mpsc::unbounded_channel();
+  if let Some(stdout) = child.stdout.take() {
+    spawn_line_reader(stdout, Channel::Stdout, sender.clone());
+  }
+  if let Some(stderr) = child.stderr.take() {
+    spawn_line_reader(stderr, Channel::Stderr, sender);
+  }
+
+  let mut stdout = String::new();
+  let mut stderr = String::new();
+
+  // Finishes once both readers reach the end of their output
+  while let Some((channel, line)) = receiver.recv().await {
+    let Some(line) = on_line(line) else {
+      continue;
+    };
+    let buf = match channel {
+      Channel::Stdout => &mut stdout,
+      Channel::Stderr => &mut stderr,
+    };
+    buf.push_str(&line);
+    buf.push('\n');
+  }
+
+  match child.wait().await {
+    Ok(status) => log.success = status.success(),
+    Err(e) => {
+      stderr.push_str(&format!("Failed to wait on command | {e:?}"))
+    }
+  }
+
+  log.stdout = svi::replace_in_string(&stdout, replacers);
+  log.stderr = svi::replace_in_string(&stderr, replacers);
+  log.end_ts = komodo_timestamp();
+  log
+}
+
+#[derive(Clone, Copy)]
+enum Channel {
+  Stdout,
+  Stderr,
+}
+
+fn spawn_line_reader(
+  reader: impl AsyncRead + Unpin + Send + 'static,
+  channel: Channel,
+  sender: mpsc::UnboundedSender<(Channel, String)>,
+) {
+  tokio::spawn(async move {
+    let mut reader = BufReader::new(reader);
+    let mut buf = Vec::new();
+    loop {
+      buf.clear();
+      match reader.read_until(b'\n', &mut buf).await {
+        Ok(0) | Err(_) => break,
+        Ok(_) => {}
+      }
+      if buf.ends_with(b"\n") {
+        buf.pop();
+        if buf.ends_with(b"\r") {
+          buf.pop();
+        }
+      }
+      // Lossy, so a non UTF-8 line doesn't end the output early.
+      let line = String::from_utf8_lossy(&buf).into_owned();
+      if sender.send((channel, line)).is_err() {
+        break;
+      }
+    }
+  });
+}
+
 pub fn output_into_log(
   stage: &str,
   command: String,
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 use anyhow::{Context, anyhow};
 use komodo_client::entities::{
@@ -6,6 +6,7 @@ use komodo_client::entities::{
   alert::Alert,
   alerter::Alerter,
   api_key::ApiKey,
+  audit::AuditEntry,
   build::Build,
   builder::Builder,
   config::DatabaseConfig,
@@ -15,6 +16,8 @@ use komodo_client::entities::{
   provider::{DockerRegistryAccount, GitProviderAccount},
   repo::Repo,
   server::Server,
+  server_group::ServerGroup,
+  service_account::ServiceAccount,
   stack::Stack,
   stats::SystemStatsRecord,
   sync::ResourceSync,
@@ -22,15 +25,14 @@ use komodo_client::entities::{
   update::Update,
   user::{User, UserConfig},
   user_group::UserGroup,
+  user_session::UserSession,
   variable::Variable,
 };
 use mongo_indexed::{create_index, create_unique_index};
-use mungos::{
-  init::MongoBuilder,
-  mongodb::{
-    Collection, Database,
-    bson::{doc, oid::ObjectId},
-  },
+use mungos::mongodb::{
+  Collection, Database,
+  bson::{doc, oid::ObjectId},
+  options::{ClientOptions, Credential},
 };
 
 pub use mongo_indexed;
@@ -44,6 +46,8 @@ pub struct Client {
   pub user_groups: Collection<UserGroup>,
   pub permissions: Collection<Permission>,
   pub api_keys: Collection<ApiKey>,
+  pub service_accounts: Collection<ServiceAccount>,
+  pub sessions: Collection<UserSession>,
   pub tags: Collection<Tag>,
   pub variables: Collection<Variable>,
   pub git_accounts: Collection<GitProviderAccount>,
@@ -51,6 +55,7 @@ pub struct Client {
   pub updates: Collection<Update>,
   pub alerts: Collection<Alert>,
   pub stats: Collection<SystemStatsRecord>,
+  pub audit_log: Collection<AuditEntry>,
   // RESOURCES
   pub servers: Collection<Server>,
   pub deployments: Collection<Deployment>,
@@ -62,6 +67,7 @@ pub struct Client {
   pub alerters: Collection<Alerter>,
   pub resource_syncs: Collection<ResourceSync>,
   pub stacks: Collection<Stack>,
+  pub server_groups: Collection<ServerGroup>,
   //
   pub db: Database,
 }
@@ -75,11 +81,14 @@ impl Client {
   }
 
   pub async fn from_database(db: Database) -> anyhow::Result<Client> {
+    drop_legacy_variable_index(&db).await;
     let client = Client {
       users: mongo_indexed::collection(&db, true).await?,
       user_groups: mongo_indexed::collection(&db, true).await?,
       permissions: mongo_indexed::collection(&db, true).await?,
       api_keys: mongo_indexed::collection(&db, true).await?,
+      service_accounts: mongo_indexed::collection(&db, true).await?,
+      sessions: mongo_indexed::collection(&db, true).await?,
       tags: mongo_indexed::collection(&db, true).await?,
       variables: mongo_indexed::collection(&db, true).await?,
       git_accounts: mongo_indexed::collection(&db, true).await?,
@@ -87,6 +96,7 @@ impl Client {
       updates: mongo_indexed::collection(&db, true).await?,
       alerts: mongo_indexed::collection(&db, true).await?,
       stats: mongo_indexed::collection(&db, true).await?,
+      audit_log: mongo_indexed::collection(&db, true).await?,
       // RESOURCES
       servers: resource_collection(&db, "Server").await?,
       deployments: resource_collection(&db, "Deployment").await?,
@@ -99,6 +109,7 @@ impl Client {
       resource_syncs: resource_collection(&db, "ResourceSync")
         .await?,
       stacks: resource_collection(&db, "Stack").await?,
+      server_groups: resource_collection(&db, "ServerGroup").await?,
       //
       db,
     };
@@ -139,48 +150,110 @@ impl Client {
 
 /// Initializes unindexed database handle.
 pub async fn init(
+  config: &DatabaseConfig,
+) -> anyhow::Result<Database> {
+  let options = client_options(config).await?;
+  let client = mungos::mongodb::Client::with_options(options)
+    .context("Failed to initialize database connection.")?;
+  Ok(client.database(&config.db_name))
+}
+
+/// Builds the mongo client options from the database config,
+/// including connection pool and timeout settings.
+pub async fn client_options(
   DatabaseConfig {
     uri,
     address,
     username,
     password,
     app_name,
-    db_name,
+    db_name: _,
+    max_pool_size,
+    min_pool_size,
+    connect_timeout_ms,
+    server_selection_timeout_ms,
   }: &DatabaseConfig,
-) -> anyhow::Result<Database> {
-  let mut client = MongoBuilder::default().app_name(app_name);
-
-  match (
+) -> anyhow::Result<ClientOptions> {
+  let mut options = match (
     !uri.is_empty(),
     !address.is_empty(),
     !username.is_empty(),
     !password.is_empty(),
   ) {
-    (true, _, _, _) => {
-      client = client.uri(uri);
-    }
+    (true, _, _, _) => ClientOptions::parse(uri)
+      .await
+      .context("Failed to parse 'config.database.uri'")?,
     (_, true, true, true) => {
-      client = client
-        .address(address)
-        .username(username)
-        .password(password);
+      let mut options =
+        ClientOptions::parse(format!("mongodb://{address}"))
+          .await
+          .context("Failed to parse 'config.database.address'")?;
+      options.credential = Some(
+        Credential::builder()
+          .username(username.clone())
+          .password(password.clone())
+          .build(),
+      );
+      options
     }
     (_, true, _, _) => {
-      client = client.address(address);
+      ClientOptions::parse(format!("mongodb://{address}"))
+        .await
+        .context("Failed to parse 'config.database.address'")?
     }
     _ => {
       return Err(anyhow!(
         "'config.database' not configured correctly. must pass either 'config.database.uri', or 'config.database.address' + 'config.database.username' + 'config.database.password'"
       ));
     }
+  };
+
+  if *max_pool_size == Some(0) {
+    return Err(anyhow!(
+      "'config.database.max_pool_size' must be greater than 0"
+    ));
+  }
+  if let (Some(min), Some(max)) = (min_pool_size, max_pool_size)
+    && min > max
+  {
+    return Err(anyhow!(
+      "'config.database.min_pool_size' ({min}) cannot be greater than 'config.database.max_pool_size' ({max})"
+    ));
+  }
+  if *server_selection_timeout_ms == Some(0) {
+    return Err(anyhow!(
+      "'config.database.server_selection_timeout_ms' must be greater than 0"
+    ));
   }
 
-  let client = client
-    .build()
-    .await
-    .context("Failed to initialize database connection.")?;
+  options.app_name = Some(app_name.clone());
+  if max_pool_size.is_some() {
+    options.max_pool_size = *max_pool_size;
+  }
+  if min_pool_size.is_some() {
+    options.min_pool_size = *min_pool_size;
+  }
+  // Zero duration disables the connect timeout,
+  // which is the existing behavior when not configured.
+  options.connect_timeout = Some(Duration::from_millis(
+    connect_timeout_ms.unwrap_or_default(),
+  ));
+  if let Some(timeout) = server_selection_timeout_ms {
+    options.server_selection_timeout =
+      Some(Duration::from_millis(*timeout));
+  }
+
+  Ok(options)
+}
 
-  Ok(client.database(db_name))
+/// Variables used to be unique by name alone. This index must be
+/// dropped so Server scoped variables can share the global name.
+async fn drop_legacy_variable_index(db: &Database) {
+  // Errors if the index doesn't exist, which is fine.
+  let _ = db
+    .collection::<Variable>("Variable")
+    .drop_index("name_1")
+    .await;
 }
 
 async fn resource_collection<T: Send + Sync>(
@@ -204,3 +277,49 @@ where
   bcrypt::hash(password, BCRYPT_COST)
     .context("failed to hash password")
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn applies_pool_sizes() {
+    let options = client_options(&DatabaseConfig {
+      max_pool_size: Some(20),
+      min_pool_size: Some(5),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+    assert_eq!(options.max_pool_size, Some(20));
+    assert_eq!(options.min_pool_size, Some(5));
+  }
+
+  #[tokio::test]
+  async fn rejects_invalid_pool_sizes() {
+    assert!(
+      client_options(&DatabaseConfig {
+        max_pool_size: Some(0),
+        ..Default::default()
+      })
+      .await
+      .is_err()
+    );
+    assert!(
+      client_options(&DatabaseConfig {
+        max_pool_size: Some(5),
+        min_pool_size: Some(10),
+        ..Default::default()
+      })
+      .await
+      .is_err()
+    );
+  }
+
+  #[tokio::test]
+  async fn no_connect_timeout_by_default() {
+    let options =
+      client_options(&DatabaseConfig::default()).await.unwrap();
+    assert_eq!(options.connect_timeout, Some(Duration::ZERO));
+  }
+}
@@ -4,22 +4,32 @@ use std::{
 };
 
 use anyhow::{Context, anyhow};
-use async_compression::tokio::write::GzipEncoder;
 use chrono::Local;
 use futures_util::{
   SinkExt, StreamExt, TryStreamExt, stream::FuturesUnordered,
 };
+use komodo_client::entities::config::cli::BackupCompression;
 use mungos::mongodb::{
-  Database,
+  Collection, Database,
   bson::{Document, RawDocumentBuf},
 };
-use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::io::{AsyncWrite, AsyncWriteExt, BufWriter};
 use tokio_util::codec::{FramedWrite, LinesCodec};
 use tracing::{error, info, warn};
 
+use super::file::{
+  EncryptionKey, backup_file_name, compression_encoder, encrypt,
+  parse_backup_file_name,
+};
+
+/// Backs up all the collections to files using the compression.
+/// If the encryption key is provided, the files are also
+/// encrypted using AES-256-GCM, see [encrypt].
 pub async fn backup(
   db: &Database,
   backups_folder: &Path,
+  compression: BackupCompression,
+  encryption_key: Option<&str>,
 ) -> anyhow::Result<()> {
   let collections = db
     .list_collection_names()
@@ -35,77 +45,68 @@ pub async fn backup(
 
   info!("Backing up to {now_backups_folder:?}...");
 
+  let encryption_key = match encryption_key {
+    Some(key) => Some(Arc::new(EncryptionKey::generate(key).await?)),
+    None => None,
+  };
+
   let has_error = Arc::new(atomic::AtomicBool::new(false));
 
   let mut handles = collections
     .into_iter()
     .map(|collection| {
       let source = db.collection::<RawDocumentBuf>(&collection);
-      let file_path = if collection == "Stats" {
-        backups_folder.join("Stats.gz")
+      let file_name = backup_file_name(
+        &collection,
+        compression,
+        encryption_key.is_some(),
+      );
+      // Stats is always written to the same file in the root folder.
+      // It is written to a temporary file first, and only replaces
+      // the previous Stats backup once it succeeds.
+      let (file_path, stats_path) = if collection == "Stats" {
+        (
+          backups_folder.join(format!("{file_name}.tmp")),
+          Some(backups_folder.join(file_name)),
+        )
       } else {
-        now_backups_folder.join(format!("{collection}.gz"))
+        (now_backups_folder.join(file_name), None)
       };
+      let backups_folder = backups_folder.to_path_buf();
+      let encryption_key = encryption_key.clone();
       let has_error = has_error.clone();
       tokio::spawn(async move {
         let res = async {
-          let mut count = 0;
           let _ = tokio::fs::remove_file(&file_path).await;
           let file =
             tokio::fs::File::create(&file_path).await.with_context(
               || format!("Failed to create file at {file_path:?}"),
             )?;
-          let mut writer = FramedWrite::new(
-            BufWriter::new(GzipEncoder::with_quality(
-              file,
-              async_compression::Level::Best,
-            )),
-            LinesCodec::new(),
-          );
-          let mut cursor = source
-            .find(Document::new())
-            .await
-            .context("Failed to query source collection")?;
-          while let Some(doc) = cursor
-            .try_next()
-            .await
-            .context("Failed to get next document")?
-          {
-            count += 1;
-            let str = match serde_json::to_string(&doc)
-              .context("Failed to serialize document")
-            {
-              Ok(str) => str,
-              Err(e) => {
-                warn!("{e:#}");
-                continue;
-              }
-            };
-            if let Err(e) = writer
-              .send(str)
-              .await
-              .context("Failed to write document to file")
-            {
-              warn!("{e:#}");
+          let count = match encryption_key {
+            Some(encryption_key) => {
+              // Pipe the compressed contents through the encryption
+              let (writer, reader) = tokio::io::duplex(64 * 1024);
+              let (count, _) = tokio::try_join!(
+                write_collection(&source, writer, compression),
+                encrypt(&encryption_key, reader, file),
+              )?;
+              count
+            }
+            None => {
+              write_collection(&source, file, compression).await?
             }
-          }
-
-          if let Err(e) = <_ as SinkExt<String>>::flush(&mut writer)
-            .await
-            .context("Failed to flush writer")
-          {
-            error!("{e:#}");
           };
-
-          if let Err(e) = writer
-            .into_inner()
-            .shutdown()
-            .await
-            .context("Failed to shutdown writer compression")
-          {
-            error!("{e:#}");
+          if let Some(stats_path) = &stats_path {
+            // Clear any left by previous backups using another format.
+            remove_stats_files(&backups_folder).await;
+            tokio::fs::rename(&file_path, stats_path)
+              .await
+              .with_context(|| {
+                format!(
+                  "Failed to move Stats backup to {stats_path:?}"
+                )
+              })?;
           }
-
           anyhow::Ok(count)
         }
         .await;
@@ -116,6 +117,9 @@ pub async fn backup(
             }
           }
           Err(e) => {
+            if stats_path.is_some() {
+              let _ = tokio::fs::remove_file(&file_path).await;
+            }
             error!("[{collection}]: {e:#}");
             has_error.store(true, atomic::Ordering::Relaxed);
           }
@@ -141,3 +145,80 @@ pub async fn backup(
     Ok(())
   }
 }
+
+/// Writes all the documents in the collection to the writer
+/// as compressed json lines. Returns the number of documents.
+async fn write_collection(
+  source: &Collection<RawDocumentBuf>,
+  writer: impl AsyncWrite + Unpin + Send,
+  compression: BackupCompression,
+) -> anyhow::Result<usize> {
+  let mut count = 0;
+  let mut writer = FramedWrite::new(
+    BufWriter::new(compression_encoder(writer, compression)),
+    LinesCodec::new(),
+  );
+  let mut cursor = source
+    .find(Document::new())
+    .await
+    .context("Failed to query source collection")?;
+  while let Some(doc) = cursor
+    .try_next()
+    .await
+    .context("Failed to get next document")?
+  {
+    count += 1;
+    let str = match serde_json::to_string(&doc)
+      .context("Failed to serialize document")
+    {
+      Ok(str) => str,
+      Err(e) => {
+        warn!("{e:#}");
+        continue;
+      }
+    };
+    if let Err(e) = writer
+      .send(str)
+      .await
+      .context("Failed to write document to file")
+    {
+      warn!("{e:#}");
+    }
+  }
+
+  if let Err(e) = <_ as SinkExt<String>>::flush(&mut writer)
+    .await
+    .context("Failed to flush writer")
+  {
+    error!("{e:#}");
+  };
+
+  if let Err(e) = writer
+    .into_inner()
+    .shutdown()
+    .await
+    .context("Failed to shutdown writer compression")
+  {
+    error!("{e:#}");
+  }
+
+  Ok(count)
+}
+
+async fn remove_stats_files(backups_folder: &Path) {
+  let Ok(mut dir) = tokio::fs::read_dir(backups_folder).await else {
+    return;
+  };
+  while let Ok(Some(entry)) = dir.next_entry().await {
+    let path = entry.path();
+    let is_stats = path
+      .file_name()
+      .and_then(|name| name.to_str())
+      .and_then(parse_backup_file_name)
+      .map(|(collection, _, _)| collection == "Stats")
+      .unwrap_or_default();
+    if is_stats && path.is_file() {
+      let _ = tokio::fs::remove_file(&path).await;
+    }
+  }
+}
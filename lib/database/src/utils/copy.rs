@@ -1,91 +1,292 @@
-use anyhow::Context;
+use anyhow::{Context, anyhow};
 use futures_util::{
   StreamExt, TryStreamExt, stream::FuturesUnordered,
 };
 use mungos::{
   bulk_update::{BulkUpdate, bulk_update_retry_too_big},
   mongodb::{
-    Database,
-    bson::{Document, doc},
+    Collection, Database,
+    bson::{Document, doc, oid::ObjectId},
   },
 };
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
+/// Stores the copy progress on the target database,
+/// so a failed copy can be resumed.
+const CHECKPOINT_COLLECTION: &str = "CopyCheckpoint";
+
+/// Copies the collections to the target database. Documents are streamed
+/// from a cursor and written to the target in batches of `batch_size`,
+/// so whole collections are never held in memory.
+///
+/// If `batch_size` is 0, uses 1,000 for the Update collection
+/// and 10,000 for the others.
+/// If `collections` is empty, copies all the collections.
+///
+/// After each batch is written, the last copied `_id` is checkpointed
+/// on the target database. With `resume`, collections which finished
+/// are skipped, and the others continue after the checkpointed `_id`.
+/// Documents are upserted by `_id`, so a resumed copy never duplicates them.
 pub async fn copy(
   source_db: &Database,
   target_db: &Database,
+  batch_size: usize,
+  collections: &[String],
+  resume: bool,
 ) -> anyhow::Result<()> {
-  let mut handles = source_db
+  let checkpoints =
+    target_db.collection::<Document>(CHECKPOINT_COLLECTION);
+
+  if !resume {
+    checkpoints
+      .drop()
+      .await
+      .context("Failed to clear copy checkpoints on target db")?;
+  }
+
+  let source_collections = source_db
     .list_collection_names()
     .await
-    .context("Failed to list collections on source db")?.into_iter().map(|collection| {
+    .context("Failed to list collections on source db")?;
+
+  for collection in collections {
+    if !source_collections.contains(collection) {
+      warn!("Collection {collection} not found on source db");
+    }
+  }
+
+  let mut handles = source_collections
+    .into_iter()
+    .filter(|collection| {
+      collection != CHECKPOINT_COLLECTION
+        && (collections.is_empty() || collections.contains(collection))
+    })
+    .map(|collection| {
       let source = source_db.collection::<Document>(&collection);
       let target_db = target_db.clone();
+      let checkpoints = checkpoints.clone();
       tokio::spawn(async move {
-        let res = async {
-          let mut buffer = Vec::<BulkUpdate>::new();
-          // The update collection is bigger than others,
-          // can hit the max bson limit on the bulk upsert call without this.
-          let max_buffer = if collection == "Update" {
-            1_000
-          } else {
-            10_000
-          };
-          let mut count = 0;
-          let mut cursor = source
-            .find(Document::new())
-            .await
-            .context("Failed to query source collection")?;
-          while let Some(document) = cursor
-            .try_next()
-            .await
-            .context("Failed to get next document")?
-          {
-            let Some(id) = document.get("_id").and_then(|id| id.as_object_id()) else {
-              continue;
-            };
-            count += 1;
-            buffer.push(BulkUpdate { query: doc! { "_id": id }, update: doc! { "$set": document } });
-            if buffer.len() >= max_buffer {
-              if let Err(e) = bulk_update_retry_too_big(&target_db, &collection, &buffer, true).await.context("Failed to flush documents")
-              {
-                error!("Failed to flush document batch in {collection} collection | {e:#}");
-              };
-              buffer.clear();
-            }
-          }
-          if !buffer.is_empty() {
-            bulk_update_retry_too_big(&target_db, &collection, &buffer, true)
-              .await
-              .context("Failed to flush documents")?;
-          }
-          anyhow::Ok(count)
-        }
+        let res = copy_collection(
+          &source,
+          &target_db,
+          &checkpoints,
+          &collection,
+          batch_size,
+          resume,
+        )
         .await;
         match res {
           Ok(count) => {
             if count > 0 {
-              info!("Finished copying {collection} collection | Copied {count}");
+              info!(
+                "Finished copying {collection} collection | Copied {count}"
+              );
             }
+            true
           }
           Err(e) => {
-            error!("Failed to copy {collection} collection | {e:#}")
+            error!("Failed to copy {collection} collection | {e:#}");
+            false
           }
         }
       })
-    }).collect::<FuturesUnordered<_>>();
+    })
+    .collect::<FuturesUnordered<_>>();
+
+  let mut failed = 0;
 
   loop {
     match handles.next().await {
-      Some(Ok(())) => {}
+      Some(Ok(true)) => {}
+      Some(Ok(false)) => failed += 1,
       Some(Err(e)) => {
         error!("{e:#}");
+        failed += 1;
       }
       None => break,
     }
   }
 
+  if failed > 0 {
+    return Err(anyhow!(
+      "Failed to copy {failed} collection/s. Run the copy again with resume enabled to continue from the last checkpoint."
+    ));
+  }
+
+  // Copy is complete, checkpoints are no longer needed.
+  if let Err(e) = checkpoints
+    .drop()
+    .await
+    .context("Failed to clean up copy checkpoints on target db")
+  {
+    warn!("{e:#}");
+  }
+
   info!("Finished copying database ✅");
 
   Ok(())
 }
+
+/// Returns the number of documents copied.
+async fn copy_collection(
+  source: &Collection<Document>,
+  target_db: &Database,
+  checkpoints: &Collection<Document>,
+  collection: &str,
+  batch_size: usize,
+  resume: bool,
+) -> anyhow::Result<usize> {
+  let checkpoint = if resume {
+    checkpoints
+      .find_one(doc! { "_id": collection })
+      .await
+      .context("Failed to query copy checkpoint")?
+  } else {
+    None
+  };
+
+  let Some(filter) = resume_filter(checkpoint.as_ref()) else {
+    info!("Skipping {collection} collection | Already copied");
+    return Ok(0);
+  };
+  if let Ok(last_id) = filter
+    .get_document("_id")
+    .and_then(|id| id.get_object_id("$gt"))
+  {
+    info!("Resuming {collection} collection after {last_id}");
+  }
+
+  let mut buffer = Vec::<BulkUpdate>::new();
+  // The update collection is bigger than others,
+  // can hit the max bson limit on the bulk upsert call without this.
+  let max_buffer = if batch_size > 0 {
+    batch_size
+  } else if collection == "Update" {
+    1_000
+  } else {
+    10_000
+  };
+  let mut count = 0;
+  // Sorted by _id so the checkpoint covers
+  // every document before it.
+  let mut cursor = source
+    .find(filter)
+    .sort(doc! { "_id": 1 })
+    .batch_size(max_buffer.min(u32::MAX as usize) as u32)
+    .await
+    .context("Failed to query source collection")?;
+  let mut last_id = None;
+
+  while let Some(document) = cursor
+    .try_next()
+    .await
+    .context("Failed to get next document")?
+  {
+    let Some(id) =
+      document.get("_id").and_then(|id| id.as_object_id())
+    else {
+      continue;
+    };
+    count += 1;
+    last_id = Some(id);
+    buffer.push(BulkUpdate {
+      query: doc! { "_id": id },
+      update: doc! { "$set": document },
+    });
+    if buffer.len() >= max_buffer {
+      flush(target_db, checkpoints, collection, &buffer, last_id)
+        .await?;
+      buffer.clear();
+    }
+  }
+
+  if !buffer.is_empty() {
+    flush(target_db, checkpoints, collection, &buffer, last_id)
+      .await?;
+  }
+
+  checkpoints
+    .update_one(
+      doc! { "_id": collection },
+      doc! { "$set": { "complete": true } },
+    )
+    .upsert(true)
+    .await
+    .context("Failed to save copy checkpoint")?;
+
+  Ok(count)
+}
+
+/// The source query filter for the collection's checkpoint,
+/// or None if the collection was already fully copied.
+fn resume_filter(checkpoint: Option<&Document>) -> Option<Document> {
+  let Some(checkpoint) = checkpoint else {
+    return Some(Document::new());
+  };
+  if checkpoint.get_bool("complete").unwrap_or_default() {
+    return None;
+  }
+  let filter = match checkpoint.get_object_id("last_id") {
+    Ok(last_id) => doc! { "_id": { "$gt": last_id } },
+    Err(_) => Document::new(),
+  };
+  Some(filter)
+}
+
+/// Writes the batch to the target, then checkpoints the last `_id`.
+/// Failing the whole collection on a failed batch keeps the
+/// checkpoint from skipping past documents which were never written.
+async fn flush(
+  target_db: &Database,
+  checkpoints: &Collection<Document>,
+  collection: &str,
+  buffer: &[BulkUpdate],
+  last_id: Option<ObjectId>,
+) -> anyhow::Result<()> {
+  bulk_update_retry_too_big(target_db, collection, buffer, true)
+    .await
+    .context("Failed to flush documents")?;
+  if let Some(last_id) = last_id {
+    checkpoints
+      .update_one(
+        doc! { "_id": collection },
+        doc! { "$set": { "last_id": last_id } },
+      )
+      .upsert(true)
+      .await
+      .context("Failed to save copy checkpoint")?;
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn resume_filter_without_checkpoint_copies_all() {
+    assert_eq!(resume_filter(None), Some(Document::new()));
+    let started = doc! { "_id": "Build" };
+    assert_eq!(resume_filter(Some(&started)), Some(Document::new()));
+  }
+
+  #[test]
+  fn resume_filter_continues_after_last_id() {
+    let last_id = ObjectId::new();
+    let checkpoint = doc! { "_id": "Update", "last_id": last_id };
+    assert_eq!(
+      resume_filter(Some(&checkpoint)),
+      Some(doc! { "_id": { "$gt": last_id } })
+    );
+  }
+
+  #[test]
+  fn resume_filter_skips_complete_collection() {
+    let checkpoint = doc! {
+      "_id": "Update",
+      "last_id": ObjectId::new(),
+      "complete": true,
+    };
+    assert_eq!(resume_filter(Some(&checkpoint)), None);
+  }
+}
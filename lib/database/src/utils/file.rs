@@ -0,0 +1,388 @@
+use aes_gcm::{
+  Aes256Gcm, Key,
+  aead::{
+    KeyInit, OsRng,
+    generic_array::GenericArray,
+    rand_core::RngCore,
+    stream::{DecryptorBE32, EncryptorBE32},
+  },
+};
+use anyhow::{Context, anyhow};
+use async_compression::tokio::{
+  bufread::{GzipDecoder, ZstdDecoder},
+  write::{GzipEncoder, ZstdEncoder},
+};
+use bytes::Bytes;
+use futures_util::{StreamExt, stream};
+use komodo_client::entities::config::cli::BackupCompression;
+use pbkdf2::pbkdf2_hmac;
+use sha2::Sha256;
+use tokio::io::{
+  AsyncBufRead, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt,
+};
+use tokio_util::io::StreamReader;
+
+/// Appended to the file name of encrypted backup files
+const ENCRYPTED_EXTENSION: &str = "enc";
+
+const SALT_LENGTH: usize = 16;
+
+/// The STREAM construction uses the last 5 bytes
+/// of the 12 byte nonce for the chunk counter.
+const NONCE_PREFIX_LENGTH: usize = 7;
+
+/// Size of the plaintext chunks which are encrypted individually.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Each encrypted chunk is followed by the 16 byte tag.
+const ENCRYPTED_CHUNK_SIZE: usize = CHUNK_SIZE + 16;
+
+/// OWASP recommendation for PBKDF2-HMAC-SHA256.
+#[cfg(not(test))]
+const KDF_ROUNDS: u32 = 600_000;
+
+/// Keeps the key derivation fast in unoptimized test builds.
+#[cfg(test)]
+const KDF_ROUNDS: u32 = 1_000;
+
+/// Eg. `Update.gz`, or `Update.zst.enc` when encrypted.
+pub fn backup_file_name(
+  collection: &str,
+  compression: BackupCompression,
+  encrypted: bool,
+) -> String {
+  let extension = compression.extension();
+  if encrypted {
+    format!("{collection}.{extension}.{ENCRYPTED_EXTENSION}")
+  } else {
+    format!("{collection}.{extension}")
+  }
+}
+
+/// Parses (collection, compression, encrypted) from the backup file name.
+/// Returns None if the file name doesn't match a backup file.
+pub fn parse_backup_file_name(
+  file_name: &str,
+) -> Option<(&str, BackupCompression, bool)> {
+  let (file_name, encrypted) = match file_name
+    .strip_suffix(ENCRYPTED_EXTENSION)
+    .and_then(|name| name.strip_suffix('.'))
+  {
+    Some(file_name) => (file_name, true),
+    None => (file_name, false),
+  };
+  [
+    BackupCompression::Gzip,
+    BackupCompression::Zstd,
+    BackupCompression::None,
+  ]
+  .into_iter()
+  .find_map(|compression| {
+    let collection = file_name
+      .strip_suffix(compression.extension())?
+      .strip_suffix('.')?;
+    Some((collection, compression, encrypted))
+  })
+}
+
+pub fn compression_encoder<'a>(
+  writer: impl AsyncWrite + Unpin + Send + 'a,
+  compression: BackupCompression,
+) -> Box<dyn AsyncWrite + Unpin + Send + 'a> {
+  match compression {
+    BackupCompression::None => Box::new(writer),
+    BackupCompression::Gzip => Box::new(GzipEncoder::with_quality(
+      writer,
+      async_compression::Level::Best,
+    )),
+    BackupCompression::Zstd => Box::new(ZstdEncoder::new(writer)),
+  }
+}
+
+pub fn compression_decoder<'a>(
+  reader: impl AsyncBufRead + Unpin + Send + 'a,
+  compression: BackupCompression,
+) -> Box<dyn AsyncRead + Unpin + Send + 'a> {
+  match compression {
+    BackupCompression::None => Box::new(reader),
+    BackupCompression::Gzip => Box::new(GzipDecoder::new(reader)),
+    BackupCompression::Zstd => Box::new(ZstdDecoder::new(reader)),
+  }
+}
+
+/// Holds the key derived from the passphrase, and
+/// the random salt used to derive it.
+#[derive(Clone)]
+pub struct EncryptionKey {
+  salt: [u8; SALT_LENGTH],
+  key: Key<Aes256Gcm>,
+}
+
+impl EncryptionKey {
+  /// Derives a key from the passphrase using a new random salt.
+  pub async fn generate(
+    passphrase: &str,
+  ) -> anyhow::Result<EncryptionKey> {
+    let mut salt = [0u8; SALT_LENGTH];
+    OsRng.fill_bytes(&mut salt);
+    EncryptionKey::derive(passphrase, salt).await
+  }
+
+  /// PBKDF2 is deliberately slow, so run it on the blocking pool.
+  async fn derive(
+    passphrase: &str,
+    salt: [u8; SALT_LENGTH],
+  ) -> anyhow::Result<EncryptionKey> {
+    let passphrase = passphrase.to_string();
+    tokio::task::spawn_blocking(move || {
+      let mut key = Key::<Aes256Gcm>::default();
+      pbkdf2_hmac::<Sha256>(
+        passphrase.as_bytes(),
+        &salt,
+        KDF_ROUNDS,
+        &mut key,
+      );
+      EncryptionKey { salt, key }
+    })
+    .await
+    .context("Failed to derive encryption key")
+  }
+}
+
+/// Encrypts the reader into the writer with AES-256-GCM,
+/// using the STREAM construction so the contents are
+/// encrypted chunk by chunk rather than held in memory.
+///
+/// The header contains the key salt and the nonce prefix.
+pub async fn encrypt(
+  key: &EncryptionKey,
+  mut reader: impl AsyncRead + Unpin,
+  mut writer: impl AsyncWrite + Unpin,
+) -> anyhow::Result<()> {
+  let mut nonce = [0u8; NONCE_PREFIX_LENGTH];
+  OsRng.fill_bytes(&mut nonce);
+  writer
+    .write_all(&key.salt)
+    .await
+    .context("Failed to write encryption header")?;
+  writer
+    .write_all(&nonce)
+    .await
+    .context("Failed to write encryption header")?;
+  let mut encryptor = EncryptorBE32::from_aead(
+    Aes256Gcm::new(&key.key),
+    GenericArray::from_slice(&nonce),
+  );
+  let mut current = read_chunk(&mut reader, CHUNK_SIZE)
+    .await
+    .context("Failed to read backup contents")?;
+  loop {
+    // Only a full chunk can be followed by another one.
+    let next = if current.len() == CHUNK_SIZE {
+      read_chunk(&mut reader, CHUNK_SIZE)
+        .await
+        .context("Failed to read backup contents")?
+    } else {
+      Vec::new()
+    };
+    if next.is_empty() {
+      let encrypted = encryptor
+        .encrypt_last(current.as_slice())
+        .map_err(|_| anyhow!("Failed to encrypt backup file"))?;
+      writer
+        .write_all(&encrypted)
+        .await
+        .context("Failed to write encrypted contents")?;
+      break;
+    }
+    let encrypted = encryptor
+      .encrypt_next(current.as_slice())
+      .map_err(|_| anyhow!("Failed to encrypt backup file"))?;
+    writer
+      .write_all(&encrypted)
+      .await
+      .context("Failed to write encrypted contents")?;
+    current = next;
+  }
+  writer
+    .shutdown()
+    .await
+    .context("Failed to flush encrypted contents")
+}
+
+type DecryptState<R> = (R, DecryptorBE32<Aes256Gcm>, Vec<u8>);
+
+/// Decrypts contents produced by [encrypt] as they are read.
+/// The first chunk is decrypted before returning,
+/// so an incorrect key fails here rather than mid restore.
+pub async fn decrypt(
+  passphrase: &str,
+  mut reader: impl AsyncRead + Unpin + Send + 'static,
+) -> anyhow::Result<impl AsyncBufRead + Unpin + Send> {
+  let mut salt = [0u8; SALT_LENGTH];
+  let mut nonce = [0u8; NONCE_PREFIX_LENGTH];
+  reader
+    .read_exact(&mut salt)
+    .await
+    .context("Encrypted backup file is too short")?;
+  reader
+    .read_exact(&mut nonce)
+    .await
+    .context("Encrypted backup file is too short")?;
+  let key = EncryptionKey::derive(passphrase, salt).await?;
+  let decryptor = DecryptorBE32::from_aead(
+    Aes256Gcm::new(&key.key),
+    GenericArray::from_slice(&nonce),
+  );
+  let current = read_chunk(&mut reader, ENCRYPTED_CHUNK_SIZE)
+    .await
+    .context("Failed to read encrypted contents")?;
+  let (first, state) = decrypt_chunk((reader, decryptor, current))
+    .await
+    .context("Check the backup encryption key is correct")?;
+  let rest = stream::try_unfold(state, |state| async move {
+    match state {
+      Some(state) => decrypt_chunk(state).await.map(Some),
+      None => Ok(None),
+    }
+  });
+  Ok(StreamReader::new(Box::pin(
+    stream::once(async { Ok(first) }).chain(rest),
+  )))
+}
+
+/// Decrypts the current chunk, and reads the next one
+/// if the current one is not the last.
+async fn decrypt_chunk<R: AsyncRead + Unpin>(
+  (mut reader, mut decryptor, current): DecryptState<R>,
+) -> std::io::Result<(Bytes, Option<DecryptState<R>>)> {
+  let next = if current.len() == ENCRYPTED_CHUNK_SIZE {
+    read_chunk(&mut reader, ENCRYPTED_CHUNK_SIZE).await?
+  } else {
+    Vec::new()
+  };
+  if next.is_empty() {
+    let decrypted = decryptor
+      .decrypt_last(current.as_slice())
+      .map_err(|_| decrypt_error())?;
+    return Ok((decrypted.into(), None));
+  }
+  let decrypted = decryptor
+    .decrypt_next(current.as_slice())
+    .map_err(|_| decrypt_error())?;
+  Ok((decrypted.into(), Some((reader, decryptor, next))))
+}
+
+fn decrypt_error() -> std::io::Error {
+  std::io::Error::new(
+    std::io::ErrorKind::InvalidData,
+    "Failed to decrypt backup file",
+  )
+}
+
+/// Reads until the chunk is full or the reader is exhausted.
+async fn read_chunk<R: AsyncRead + Unpin>(
+  reader: &mut R,
+  size: usize,
+) -> std::io::Result<Vec<u8>> {
+  let mut chunk = Vec::with_capacity(size);
+  reader.take(size as u64).read_to_end(&mut chunk).await?;
+  Ok(chunk)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Cursor;
+
+  use super::*;
+
+  async fn encrypt_to_vec(
+    passphrase: &str,
+    contents: &[u8],
+  ) -> Vec<u8> {
+    let key = EncryptionKey::generate(passphrase).await.unwrap();
+    let mut encrypted = Vec::new();
+    encrypt(&key, contents, &mut encrypted).await.unwrap();
+    encrypted
+  }
+
+  async fn decrypt_to_vec(
+    passphrase: &str,
+    encrypted: Vec<u8>,
+  ) -> anyhow::Result<Vec<u8>> {
+    let mut reader =
+      decrypt(passphrase, Cursor::new(encrypted)).await?;
+    let mut decrypted = Vec::new();
+    reader.read_to_end(&mut decrypted).await?;
+    Ok(decrypted)
+  }
+
+  async fn assert_round_trip(contents: Vec<u8>) {
+    let encrypted = encrypt_to_vec("passphrase", &contents).await;
+    assert_ne!(encrypted, contents);
+    let decrypted =
+      decrypt_to_vec("passphrase", encrypted).await.unwrap();
+    assert_eq!(decrypted, contents);
+  }
+
+  fn contents(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+  }
+
+  #[tokio::test]
+  async fn round_trip_empty() {
+    assert_round_trip(Vec::new()).await;
+  }
+
+  #[tokio::test]
+  async fn round_trip_exactly_one_chunk() {
+    assert_round_trip(contents(CHUNK_SIZE)).await;
+  }
+
+  #[tokio::test]
+  async fn round_trip_multiple_chunks() {
+    assert_round_trip(contents(CHUNK_SIZE * 2 + CHUNK_SIZE / 2))
+      .await;
+    assert_round_trip(contents(CHUNK_SIZE * 3)).await;
+  }
+
+  #[tokio::test]
+  async fn decrypt_with_wrong_key_fails() {
+    let encrypted =
+      encrypt_to_vec("passphrase", &contents(100)).await;
+    assert!(decrypt_to_vec("wrong", encrypted).await.is_err());
+  }
+
+  #[tokio::test]
+  async fn decrypt_truncated_file_fails() {
+    let mut encrypted =
+      encrypt_to_vec("passphrase", &contents(CHUNK_SIZE * 2)).await;
+    encrypted.truncate(encrypted.len() - 1);
+    assert!(decrypt_to_vec("passphrase", encrypted).await.is_err());
+  }
+
+  #[test]
+  fn backup_file_name_round_trip() {
+    for compression in [
+      BackupCompression::Gzip,
+      BackupCompression::Zstd,
+      BackupCompression::None,
+    ] {
+      for encrypted in [false, true] {
+        let file_name =
+          backup_file_name("Update", compression, encrypted);
+        let (collection, parsed_compression, parsed_encrypted) =
+          parse_backup_file_name(&file_name).unwrap();
+        assert_eq!(collection, "Update");
+        assert_eq!(parsed_compression, compression);
+        assert_eq!(parsed_encrypted, encrypted);
+      }
+    }
+  }
+
+  #[test]
+  fn parse_backup_file_name_rejects_other_files() {
+    assert!(parse_backup_file_name("Update").is_none());
+    assert!(parse_backup_file_name("notes.txt").is_none());
+  }
+}
@@ -1,7 +1,10 @@
 mod backup;
 mod copy;
+mod file;
+mod remote;
 mod restore;
 
 pub use backup::backup;
 pub use copy::copy;
+pub use remote::upload_latest_backup;
 pub use restore::restore;
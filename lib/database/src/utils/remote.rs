@@ -0,0 +1,261 @@
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use anyhow::Context;
+use chrono::NaiveDateTime;
+use futures_util::TryStreamExt;
+use komodo_client::entities::config::cli::RemoteBackupTarget;
+use object_store::{
+  ObjectStore, aws::AmazonS3Builder, azure::MicrosoftAzureBuilder,
+  buffered::BufWriter, gcp::GoogleCloudStorageBuilder,
+  path::Path as ObjectPath,
+};
+use tokio::io::AsyncWriteExt;
+use tracing::{info, warn};
+
+use super::{
+  file::parse_backup_file_name, restore::latest_restore_folder,
+};
+
+/// Uploads the latest backup folder to the remote target,
+/// along with the Stats file from the root backups folder.
+/// Then prunes the oldest remote backup folders if there are
+/// more than `max_backups`. Pruning is disabled if `max_backups` is 0.
+///
+/// Returns the uploaded object paths.
+pub async fn upload_latest_backup(
+  backups_folder: &Path,
+  target: &RemoteBackupTarget,
+  max_backups: u16,
+) -> anyhow::Result<Vec<String>> {
+  let (store, prefix) = remote_store(target)?;
+
+  let latest_folder = latest_restore_folder(backups_folder).await?;
+  let folder_name = latest_folder
+    .file_name()
+    .and_then(|name| name.to_str())
+    .context("Failed to find latest backup folder")?;
+  let remote_folder = prefix.child(folder_name);
+
+  info!("Uploading {latest_folder:?} to {remote_folder}...");
+
+  // Stats is stored in the root backups folder
+  let mut files = read_backup_files(backups_folder)
+    .await?
+    .into_iter()
+    .filter(|(collection, _)| collection == "Stats")
+    .collect::<Vec<_>>();
+  files.extend(read_backup_files(&latest_folder).await?);
+
+  let mut uploaded = Vec::with_capacity(files.len());
+
+  for (_, path) in files {
+    let Some(file_name) = path.file_name().and_then(|n| n.to_str())
+    else {
+      continue;
+    };
+    let location = remote_folder.child(file_name);
+    upload_file(&store, &path, location.clone()).await?;
+    uploaded.push(location.to_string());
+  }
+
+  info!("Finished uploading backup to {remote_folder}");
+
+  if max_backups > 0 {
+    prune_remote(store.as_ref(), &prefix, max_backups as usize)
+      .await?;
+  }
+
+  Ok(uploaded)
+}
+
+fn remote_store(
+  target: &RemoteBackupTarget,
+) -> anyhow::Result<(Arc<dyn ObjectStore>, ObjectPath)> {
+  let (store, prefix): (Arc<dyn ObjectStore>, &str) = match target {
+    RemoteBackupTarget::S3 {
+      bucket,
+      prefix,
+      region,
+      access_key_id,
+      secret_access_key,
+    } => (
+      Arc::new(
+        AmazonS3Builder::new()
+          .with_bucket_name(bucket)
+          .with_region(region)
+          .with_access_key_id(access_key_id)
+          .with_secret_access_key(secret_access_key)
+          .build()
+          .context("Failed to initialize S3 client")?,
+      ),
+      prefix,
+    ),
+    RemoteBackupTarget::Gcs {
+      bucket,
+      prefix,
+      service_account_json,
+    } => (
+      Arc::new(
+        GoogleCloudStorageBuilder::new()
+          .with_bucket_name(bucket)
+          .with_service_account_key(service_account_json)
+          .build()
+          .context("Failed to initialize GCS client")?,
+      ),
+      prefix,
+    ),
+    RemoteBackupTarget::AzureBlob {
+      container,
+      account,
+      key,
+    } => (
+      Arc::new(
+        MicrosoftAzureBuilder::new()
+          .with_container_name(container)
+          .with_account(account)
+          .with_access_key(key)
+          .build()
+          .context("Failed to initialize Azure Blob client")?,
+      ),
+      "",
+    ),
+  };
+  Ok((store, ObjectPath::from(prefix)))
+}
+
+/// Streams the file to the object store, using
+/// a multipart upload for larger files.
+async fn upload_file(
+  store: &Arc<dyn ObjectStore>,
+  path: &Path,
+  location: ObjectPath,
+) -> anyhow::Result<()> {
+  let mut file =
+    tokio::fs::File::open(path).await.with_context(|| {
+      format!("Failed to open backup file {path:?}")
+    })?;
+  let mut writer = BufWriter::new(store.clone(), location.clone());
+  tokio::io::copy(&mut file, &mut writer)
+    .await
+    .with_context(|| format!("Failed to upload {path:?}"))?;
+  writer.shutdown().await.with_context(|| {
+    format!("Failed to complete upload to {location}")
+  })?;
+  Ok(())
+}
+
+/// Deletes the oldest remote backup folders
+/// when there are more than `max_backups`.
+async fn prune_remote(
+  store: &dyn ObjectStore,
+  prefix: &ObjectPath,
+  max_backups: usize,
+) -> anyhow::Result<()> {
+  let prefix = (!prefix.as_ref().is_empty()).then_some(prefix);
+
+  // Only consider folders named like backups,
+  // in case the prefix is shared with other objects.
+  let mut backup_folders = store
+    .list_with_delimiter(prefix)
+    .await
+    .context("Failed to list remote backups for prune")?
+    .common_prefixes
+    .into_iter()
+    .filter(|folder| {
+      folder.filename().is_some_and(|name| {
+        NaiveDateTime::parse_from_str(name, "%Y-%m-%d_%H-%M-%S")
+          .is_ok()
+      })
+    })
+    .collect::<Vec<_>>();
+  // Ordered from oldest -> newest
+  backup_folders.sort();
+
+  let backup_folders_len = backup_folders.len();
+
+  // Early return if under the backup count threshold
+  if backup_folders_len <= max_backups {
+    info!("No remote backups to prune");
+    return Ok(());
+  }
+
+  let to_delete =
+    &backup_folders[..(backup_folders_len - max_backups)];
+
+  info!("Pruning old remote backups: {to_delete:?}");
+
+  for folder in to_delete {
+    let locations = match store
+      .list(Some(folder))
+      .map_ok(|object| object.location)
+      .try_collect::<Vec<_>>()
+      .await
+      .with_context(|| format!("Failed to list objects in {folder}"))
+    {
+      Ok(locations) => locations,
+      Err(e) => {
+        warn!("{e:#}");
+        continue;
+      }
+    };
+    for location in locations {
+      if let Err(e) =
+        store.delete(&location).await.with_context(|| {
+          format!("Failed to delete remote backup file {location}")
+        })
+      {
+        warn!("{e:#}");
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/// Returns (collection, path) for the backup files in the folder.
+async fn read_backup_files(
+  folder: &Path,
+) -> anyhow::Result<Vec<(String, PathBuf)>> {
+  let mut dir =
+    tokio::fs::read_dir(folder).await.with_context(|| {
+      format!("Failed to read backup directory {folder:?}")
+    })?;
+
+  let mut files = Vec::new();
+
+  loop {
+    match dir
+      .next_entry()
+      .await
+      .context("Failed to read backup dir entry")
+    {
+      Ok(Some(entry)) => {
+        let path = entry.path();
+        if !path.is_file() {
+          continue;
+        }
+        let Some(file_name) =
+          path.file_name().and_then(|n| n.to_str())
+        else {
+          continue;
+        };
+        let Some((collection, _, _)) =
+          parse_backup_file_name(file_name)
+        else {
+          continue;
+        };
+        files.push((collection.to_string(), path));
+      }
+      Ok(None) => break,
+      Err(e) => {
+        warn!("{e:#}");
+        continue;
+      }
+    }
+  }
+
+  Ok(files)
+}
@@ -1,10 +1,13 @@
-use std::path::{Path, PathBuf};
+use std::{
+  path::{Path, PathBuf},
+  sync::{Arc, atomic},
+};
 
-use anyhow::Context;
-use async_compression::tokio::bufread::GzipDecoder;
+use anyhow::{Context, anyhow};
 use futures_util::{
   StreamExt, TryStreamExt, stream::FuturesUnordered,
 };
+use komodo_client::entities::config::cli::BackupCompression;
 use mungos::{
   bulk_update::{BulkUpdate, bulk_update_retry_too_big},
   mongodb::{
@@ -12,14 +15,34 @@ use mungos::{
     bson::{Document, doc},
   },
 };
-use tokio::io::BufReader;
+use tokio::io::{AsyncBufRead, BufReader};
 use tokio_util::codec::{FramedRead, LinesCodec};
 use tracing::{error, info, warn};
 
+use super::file::{
+  compression_decoder, decrypt, parse_backup_file_name,
+};
+
+/// Appended to the name of the temporary collection
+/// restored into when dropping collections.
+const RESTORE_SUFFIX: &str = ".restore";
+
+/// Restores the collections from the backup files.
+/// The compression is detected from the file extension.
+/// The encryption key is required to restore encrypted files.
+///
+/// With `drop_collections`, each collection in the backup is restored
+/// into a temporary collection, which replaces the existing one only
+/// once fully restored. This removes documents created since the backup,
+/// and leaves the existing collection untouched if the restore fails.
+/// Otherwise the backup documents are upserted.
+/// Note that replacing the collections also drops their indexes.
 pub async fn restore(
   db: &Database,
   backups_folder: &Path,
   restore_folder: Option<&Path>,
+  encryption_key: Option<&str>,
+  drop_collections: bool,
 ) -> anyhow::Result<()> {
   // Get the specific dated folder to restore contents of
   let restore_folder = if let Some(restore_folder) = restore_folder {
@@ -35,11 +58,17 @@ pub async fn restore(
   let restore_files =
     get_restore_files(backups_folder, &restore_folder).await?;
 
+  let encryption_key = encryption_key.map(str::to_string);
+
+  let has_error = Arc::new(atomic::AtomicBool::new(false));
+
   let mut handles = restore_files
     .into_iter()
-    .map(|(collection, restore_file)| {
+    .map(|RestoreFile { collection, path: restore_file, compression, encrypted }| {
       let db = db.clone();
-      async {
+      let encryption_key = encryption_key.clone();
+      let has_error = has_error.clone();
+      async move {
         let col = collection.clone();
         tokio::join!(
           async { col },
@@ -55,12 +84,36 @@ pub async fn restore(
               };
               let mut count = 0;
 
-              let file = tokio::fs::File::open(&restore_file)
-                .await
-                .with_context(|| format!("Failed to open file {restore_file:?}"))?;
+              let reader: Box<dyn AsyncBufRead + Unpin + Send> = if encrypted {
+                let encryption_key = encryption_key.context(
+                  "Backup file is encrypted, but no encryption key was provided (KOMODO_CLI_BACKUP_ENCRYPTION_KEY)"
+                )?;
+                let file = tokio::fs::File::open(&restore_file)
+                  .await
+                  .with_context(|| format!("Failed to open file {restore_file:?}"))?;
+                Box::new(decrypt(&encryption_key, file).await?)
+              } else {
+                let file = tokio::fs::File::open(&restore_file)
+                  .await
+                  .with_context(|| format!("Failed to open file {restore_file:?}"))?;
+                Box::new(BufReader::new(file))
+              };
+
+              // When dropping, restore into a temporary collection which
+              // only replaces the existing one once fully restored.
+              let target = if drop_collections {
+                let target = format!("{collection}{RESTORE_SUFFIX}");
+                db.collection::<Document>(&target)
+                  .drop()
+                  .await
+                  .with_context(|| format!("Failed to clear {target} collection"))?;
+                target
+              } else {
+                collection.clone()
+              };
 
               let mut reader = FramedRead::new(
-                GzipDecoder::new(BufReader::new(file)),
+                compression_decoder(reader, compression),
                 LinesCodec::new()
               );
 
@@ -86,15 +139,22 @@ pub async fn restore(
                 count += 1;
                 buffer.push(BulkUpdate { query: doc! { "_id": id }, update: doc! { "$set": document } });
                 if buffer.len() >= max_buffer {
-                  if let Err(e) = bulk_update_retry_too_big(&db, &collection, &buffer, true).await.context("Failed to flush documents")
+                  if let Err(e) = bulk_update_retry_too_big(&db, &target, &buffer, true).await.context("Failed to flush documents")
                   {
+                    // The existing collection must not be replaced by a partial restore
+                    if drop_collections {
+                      return Err(e);
+                    }
                     error!("Failed to flush document batch in {collection} collection | {e:#}");
                   };
                   buffer.clear();
                 }
               }
               if !buffer.is_empty() {
-                bulk_update_retry_too_big(&db, &collection, &buffer, true).await.context("Failed to flush documents")?;
+                bulk_update_retry_too_big(&db, &target, &buffer, true).await.context("Failed to flush documents")?;
+              }
+              if drop_collections {
+                replace_collection(&db, &target, &collection).await?;
               }
               anyhow::Ok(count)
             }.await;
@@ -105,7 +165,14 @@ pub async fn restore(
                 }
               }
               Err(e) => {
+                if drop_collections {
+                  let _ = db
+                    .collection::<Document>(&format!("{collection}{RESTORE_SUFFIX}"))
+                    .drop()
+                    .await;
+                }
                 error!("[{collection}]: {e:#}");
+                has_error.store(true, atomic::Ordering::Relaxed);
               }
             }
           })
@@ -124,12 +191,39 @@ pub async fn restore(
     }
   }
 
-  info!("Finished restoring database ✅");
+  if has_error.load(atomic::Ordering::Relaxed) {
+    Err(anyhow!("Finished restoring database with errors 🚨"))
+  } else {
+    info!("Finished restoring database ✅");
+    Ok(())
+  }
+}
 
+/// Atomically replaces the collection with the restored one,
+/// dropping the existing collection.
+async fn replace_collection(
+  db: &Database,
+  restored: &str,
+  collection: &str,
+) -> anyhow::Result<()> {
+  let db_name = db.name();
+  db.client()
+    .database("admin")
+    .run_command(doc! {
+      "renameCollection": format!("{db_name}.{restored}"),
+      "to": format!("{db_name}.{collection}"),
+      "dropTarget": true,
+    })
+    .await
+    .with_context(|| {
+      format!(
+        "Failed to replace {collection} with restored collection"
+      )
+    })?;
   Ok(())
 }
 
-async fn latest_restore_folder(
+pub(super) async fn latest_restore_folder(
   backups_folder: &Path,
 ) -> anyhow::Result<PathBuf> {
   let mut max = PathBuf::new();
@@ -158,19 +252,44 @@ async fn latest_restore_folder(
   Ok(max.components().collect())
 }
 
+struct RestoreFile {
+  collection: String,
+  path: PathBuf,
+  compression: BackupCompression,
+  encrypted: bool,
+}
+
 async fn get_restore_files(
   backups_folder: &Path,
   restore_folder: &Path,
-) -> anyhow::Result<Vec<(String, PathBuf)>> {
+) -> anyhow::Result<Vec<RestoreFile>> {
+  // Stats is stored in the root backups folder
+  let mut restore_files = read_restore_files(backups_folder)
+    .await?
+    .into_iter()
+    .filter(|file| file.collection == "Stats")
+    .collect::<Vec<_>>();
+
+  if restore_files.len() > 1 {
+    return Err(anyhow!(
+      "Found multiple Stats backup files in {backups_folder:?}"
+    ));
+  }
+
+  restore_files.extend(read_restore_files(restore_folder).await?);
+
+  Ok(restore_files)
+}
+
+async fn read_restore_files(
+  folder: &Path,
+) -> anyhow::Result<Vec<RestoreFile>> {
   let mut restore_dir =
-    tokio::fs::read_dir(restore_folder).await.with_context(|| {
-      format!("Failed to read restore directory {restore_folder:?}")
+    tokio::fs::read_dir(folder).await.with_context(|| {
+      format!("Failed to read restore directory {folder:?}")
     })?;
 
-  let mut restore_files: Vec<(String, PathBuf)> = vec![(
-    String::from("Stats"),
-    backups_folder.join("Stats.gz").components().collect(),
-  )];
+  let mut restore_files = Vec::new();
 
   loop {
     match restore_dir
@@ -180,19 +299,26 @@ async fn get_restore_files(
     {
       Ok(Some(file)) => {
         let path = file.path();
+        if !path.is_file() {
+          continue;
+        }
         let Some(file_name) = path.file_name() else {
           continue;
         };
         let Some(file_name) = file_name.to_str() else {
           continue;
         };
-        let Some(collection) = file_name.strip_suffix(".gz") else {
+        let Some((collection, compression, encrypted)) =
+          parse_backup_file_name(file_name)
+        else {
           continue;
         };
-        restore_files.push((
-          collection.to_string(),
-          path.components().collect(),
-        ));
+        restore_files.push(RestoreFile {
+          collection: collection.to_string(),
+          path: path.components().collect(),
+          compression,
+          encrypted,
+        });
       }
       Ok(None) => break,
       Err(e) => {
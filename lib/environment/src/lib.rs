@@ -1,4 +1,8 @@
-use std::path::{Path, PathBuf};
+use std::{
+  borrow::Cow,
+  collections::HashSet,
+  path::{Path, PathBuf},
+};
 
 use anyhow::Context;
 use formatting::format_serror;
@@ -26,9 +30,13 @@ pub async fn write_env_file(
     return None;
   }
 
+  let (environment, duplicates) = dedup_environment(environment);
+
   let contents = environment
     .iter()
-    .map(|env| format!("{}={}", env.variable, env.value))
+    .map(|(variable, value)| {
+      format!("{variable}={}", quote_env_value(value))
+    })
     .collect::<Vec<_>>()
     .join("\n");
 
@@ -44,12 +52,31 @@ pub async fn write_env_file(
     return None;
   }
 
-  if let Err(e) = tokio::fs::write(&env_file_path, contents)
-    .await
-    .with_context(|| {
-      format!("Failed to write environment file to {env_file_path:?}")
-    })
+  // Write to a temp file in the same directory and rename into place,
+  // so compose never reads a partially written env file.
+  let tmp_path = env_file_path.with_file_name(format!(
+    ".{}.tmp",
+    env_file_path
+      .file_name()
+      .map(|name| name.to_string_lossy())
+      .unwrap_or_default()
+  ));
+  if let Err(e) = async {
+    tokio::fs::write(&tmp_path, contents).await.with_context(|| {
+      format!("Failed to write environment file to {tmp_path:?}")
+    })?;
+    tokio::fs::rename(&tmp_path, &env_file_path)
+      .await
+      .with_context(|| {
+        format!(
+          "Failed to move environment file from {tmp_path:?} to {env_file_path:?}"
+        )
+      })
+  }
+  .await
   {
+    // Don't leave the temp file behind on failure
+    let _ = tokio::fs::remove_file(&tmp_path).await;
     logs.push(Log::error(
       "Write Environment File",
       format_serror(&e.into()),
@@ -57,10 +84,176 @@ pub async fn write_env_file(
     return None;
   }
 
-  logs.push(Log::simple(
-    "Write Environment File",
-    format!("Environment file written to {env_file_path:?}"),
-  ));
+  let mut log =
+    format!("Environment file written to {env_file_path:?}");
+  if duplicates > 0 {
+    log.push_str(&format!(
+      "\nCollapsed {duplicates} duplicate variable/s, keeping the last value"
+    ));
+  }
+  logs.push(Log::simple("Write Environment File", log));
 
   Some(env_file_path)
 }
+
+/// Trims the variable names and removes duplicates,
+/// keeping the last occurrence of each. Duplicates are common
+/// after merging the stack and Komodo environments,
+/// and compose warns about them.
+/// Returns the (variable, value) pairs along with the number of duplicates removed.
+fn dedup_environment(
+  environment: &[EnvironmentVar],
+) -> (Vec<(&str, &str)>, usize) {
+  let mut seen = HashSet::new();
+  let mut res = environment
+    .iter()
+    .rev()
+    .map(|env| (env.variable.trim(), env.value.as_str()))
+    .filter(|(variable, _)| seen.insert(*variable))
+    .collect::<Vec<_>>();
+  res.reverse();
+  let duplicates = environment.len() - res.len();
+  (res, duplicates)
+}
+
+/// Wraps values which would otherwise be misparsed, ie containing
+/// whitespace, `#`, quotes or backslashes, in double quotes,
+/// escaping any embedded quotes / backslashes / newlines.
+/// Safe values, and values the user already wrapped in quotes,
+/// are written as is.
+fn quote_env_value(value: &str) -> Cow<'_, str> {
+  let already_quoted = value.len() > 1
+    && ((value.starts_with('"') && value.ends_with('"'))
+      || (value.starts_with('\'') && value.ends_with('\'')));
+  if already_quoted
+    || !value.contains(|c: char| {
+      c.is_whitespace() || matches!(c, '#' | '"' | '\'' | '\\')
+    })
+  {
+    return Cow::Borrowed(value);
+  }
+  let mut res = String::with_capacity(value.len() + 2);
+  res.push('"');
+  for c in value.chars() {
+    match c {
+      '"' => res.push_str("\\\""),
+      '\\' => res.push_str("\\\\"),
+      '\n' => res.push_str("\\n"),
+      '\r' => res.push_str("\\r"),
+      c => res.push(c),
+    }
+  }
+  res.push('"');
+  Cow::Owned(res)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn env(variable: &str, value: &str) -> EnvironmentVar {
+    EnvironmentVar {
+      variable: variable.to_string(),
+      value: value.to_string(),
+    }
+  }
+
+  #[test]
+  fn dedup_keeps_last_occurrence() {
+    let environment =
+      [env("FOO", "1"), env("BAR", "bar"), env(" FOO ", "2")];
+    let (environment, duplicates) = dedup_environment(&environment);
+    assert_eq!(environment, [("BAR", "bar"), ("FOO", "2")]);
+    assert_eq!(duplicates, 1);
+  }
+
+  #[test]
+  fn quotes_value_with_space() {
+    assert_eq!(quote_env_value("hello world"), "\"hello world\"");
+  }
+
+  #[test]
+  fn escapes_value_with_quote() {
+    assert_eq!(
+      quote_env_value(r#"say "hi" \ bye"#),
+      r#""say \"hi\" \\ bye""#
+    );
+  }
+
+  #[test]
+  fn leaves_plain_value_unquoted() {
+    assert_eq!(
+      quote_env_value("https://example.com/path?a=1"),
+      "https://example.com/path?a=1"
+    );
+    assert_eq!(
+      quote_env_value(r#""already quoted""#),
+      r#""already quoted""#
+    );
+  }
+
+  #[tokio::test]
+  async fn env_file_only_contains_last_duplicate() {
+    let folder = std::env::temp_dir()
+      .join(format!("komodo-env-test-{}", std::process::id()));
+    let mut logs = Vec::new();
+    let path = write_env_file(
+      &[env("FOO", "1"), env("FOO", "2")],
+      &folder,
+      ".env",
+      &mut logs,
+    )
+    .await
+    .expect("env file should be written");
+    let contents = tokio::fs::read_to_string(&path).await.unwrap();
+    let _ = tokio::fs::remove_dir_all(&folder).await;
+    assert_eq!(contents, "FOO=2");
+    assert!(logs.iter().all(|log| log.success));
+  }
+
+  fn test_folder(name: &str) -> PathBuf {
+    std::env::temp_dir()
+      .join(format!("komodo-env-test-{name}-{}", std::process::id()))
+  }
+
+  #[tokio::test]
+  async fn env_file_written_completely_without_temp_file() {
+    let folder = test_folder("complete");
+    let mut logs = Vec::new();
+    let path = write_env_file(
+      &[env("FOO", "1"), env("BAR", "hello world")],
+      &folder,
+      "nested/.env",
+      &mut logs,
+    )
+    .await
+    .expect("env file should be written");
+    let contents = tokio::fs::read_to_string(&path).await.unwrap();
+    let tmp_exists = folder.join("nested/..env.tmp").exists();
+    let _ = tokio::fs::remove_dir_all(&folder).await;
+    assert_eq!(contents, "FOO=1\nBAR=\"hello world\"");
+    assert!(!tmp_exists);
+  }
+
+  #[tokio::test]
+  async fn temp_file_removed_when_rename_fails() {
+    let folder = test_folder("rename-fails");
+    // A non empty directory at the target path fails the rename
+    tokio::fs::create_dir_all(folder.join(".env/inner"))
+      .await
+      .unwrap();
+    let mut logs = Vec::new();
+    let path =
+      write_env_file(&[env("FOO", "1")], &folder, ".env", &mut logs)
+        .await;
+    let tmp_exists = folder.join("..env.tmp").exists();
+    let _ = tokio::fs::remove_dir_all(&folder).await;
+    assert!(path.is_none());
+    assert!(!tmp_exists);
+    assert_eq!(logs.len(), 1);
+    assert!(!logs[0].success);
+    assert!(
+      logs[0].stderr.contains("Failed to move environment file")
+    );
+  }
+}
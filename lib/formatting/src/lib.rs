@@ -8,6 +8,20 @@ pub fn bold(content: impl std::fmt::Display) -> String {
   format!("<span class=\"font-bold\">{content}</span>")
 }
 
+/// Inline monospace, eg for a command or path in a log line.
+pub fn code(content: impl std::fmt::Display) -> String {
+  format!(
+    "<span class=\"font-mono bg-muted rounded px-1\">{content}</span>"
+  )
+}
+
+/// Multi-line monospace, preserving whitespace.
+pub fn code_block(content: impl std::fmt::Display) -> String {
+  format!(
+    "<span class=\"block font-mono whitespace-pre bg-muted rounded p-2\">{content}</span>"
+  )
+}
+
 pub fn colored(
   content: impl std::fmt::Display,
   color: Color,
@@ -19,6 +33,8 @@ pub enum Color {
   Red,
   Green,
   Blue,
+  Yellow,
+  Gray,
 }
 
 impl std::fmt::Display for Color {
@@ -29,6 +45,10 @@ impl std::fmt::Display for Color {
         f.write_str("text-green-700 dark:text-green-400")
       }
       Color::Blue => f.write_str("text-blue-700 dark:text-blue-400"),
+      Color::Yellow => {
+        f.write_str("text-yellow-700 dark:text-yellow-400")
+      }
+      Color::Gray => f.write_str("text-gray-600 dark:text-gray-400"),
     }
   }
 }
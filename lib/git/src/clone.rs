@@ -8,7 +8,7 @@ use komodo_client::entities::{
   update::Log,
 };
 
-use crate::get_commit_hash_log;
+use crate::{get_commit_hash_log, set_sparse_checkout};
 
 /// Will delete the existing repo folder,
 /// clone the repo, get the latest hash / message,
@@ -68,8 +68,22 @@ where
     _ => {}
   }
 
+  // Sparse clones skip downloading file contents
+  // outside of the checked out paths.
+  let sparse = if args.sparse_checkout.is_empty() {
+    ""
+  } else {
+    " --filter=blob:none --sparse"
+  };
+
+  let depth = if args.clone_depth > 0 {
+    format!(" --depth {}", args.clone_depth)
+  } else {
+    String::new()
+  };
+
   let command = format!(
-    "git clone {repo_url} {} -b {}",
+    "git clone{depth}{sparse} {repo_url} {} -b {}",
     res.path.display(),
     args.branch
   );
@@ -88,7 +102,35 @@ where
     return Ok(res);
   }
 
+  if !args.sparse_checkout.is_empty() {
+    set_sparse_checkout(
+      &res.path,
+      &args.sparse_checkout,
+      &mut res.logs,
+    )
+    .await;
+    if !all_logs_success(&res.logs) {
+      return Ok(res);
+    }
+  }
+
   if let Some(commit) = args.commit {
+    // The commit may not be included in a shallow clone
+    if args.clone_depth > 0 {
+      let fetch_log = run_komodo_command(
+        "Fetch commit",
+        res.path.as_path(),
+        format!(
+          "git fetch --depth {} origin {commit}",
+          args.clone_depth
+        ),
+      )
+      .await;
+      res.logs.push(fetch_log);
+      if !all_logs_success(&res.logs) {
+        return Ok(res);
+      }
+    }
     let reset_log = run_komodo_command(
       "set commit",
       res.path.as_path(),
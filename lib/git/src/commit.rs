@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use command::run_komodo_command;
-use formatting::format_serror;
+use formatting::{code, format_serror};
 use komodo_client::entities::{
   RepoExecutionResponse, all_logs_success, update::Log,
 };
@@ -18,7 +18,28 @@ pub async fn write_commit_file(
   repo_dir: &Path,
   // relative to repo root
   relative_file_path: &Path,
-  contents: &str,
+  contents: impl AsRef<[u8]>,
+  branch: &str,
+) -> anyhow::Result<RepoExecutionResponse> {
+  write_commit_file_with_message(
+    &default_commit_message(commit_msg, relative_file_path),
+    repo_dir,
+    relative_file_path,
+    contents,
+    branch,
+  )
+  .await
+}
+
+/// Write file, add, commit, force push.
+/// Uses the full `message` as given, which may span multiple lines.
+/// Repo must be cloned.
+pub async fn write_commit_file_with_message(
+  message: &str,
+  repo_dir: &Path,
+  // relative to repo root
+  relative_file_path: &Path,
+  contents: impl AsRef<[u8]>,
   branch: &str,
 ) -> anyhow::Result<RepoExecutionResponse> {
   let mut res = RepoExecutionResponse {
@@ -48,11 +69,14 @@ pub async fn write_commit_file(
 
   res.logs.push(Log::simple(
     "Write file",
-    format!("File contents written to {full_file_path:?}"),
+    format!(
+      "File contents written to {}",
+      code(full_file_path.display())
+    ),
   ));
 
   commit_file_inner(
-    commit_msg,
+    message,
     &mut res,
     repo_dir,
     relative_file_path,
@@ -63,6 +87,10 @@ pub async fn write_commit_file(
   Ok(res)
 }
 
+fn default_commit_message(commit_msg: &str, file: &Path) -> String {
+  format!("[Komodo] {commit_msg}: update {}", file.display())
+}
+
 /// Add file, commit, force push.
 /// Repo must be cloned.
 pub async fn commit_file(
@@ -79,14 +107,20 @@ pub async fn commit_file(
     commit_message: None,
   };
 
-  commit_file_inner(commit_msg, &mut res, repo_dir, file, branch)
-    .await;
+  commit_file_inner(
+    &default_commit_message(commit_msg, file),
+    &mut res,
+    repo_dir,
+    file,
+    branch,
+  )
+  .await;
 
   res
 }
 
 pub async fn commit_file_inner(
-  commit_msg: &str,
+  message: &str,
   res: &mut RepoExecutionResponse,
   repo_dir: &Path,
   // relative to repo root
@@ -109,9 +143,7 @@ pub async fn commit_file_inner(
   let commit_log = run_komodo_command(
     "Commit",
     repo_dir,
-    format!(
-      "git commit -m \"[Komodo] {commit_msg}: update {file:?}\"",
-    ),
+    format!("git commit -m '{}'", message.replace('\'', "'\\''")),
   )
   .await;
 
@@ -1,6 +1,7 @@
 use std::path::Path;
 
 use anyhow::{Context, anyhow};
+use command::run_komodo_command;
 use formatting::{bold, muted};
 use komodo_client::entities::{
   LatestCommit, komodo_timestamp, update::Log,
@@ -16,7 +17,10 @@ mod pull_or_clone;
 
 pub use crate::{
   clone::clone,
-  commit::{commit_all, commit_file, write_commit_file},
+  commit::{
+    commit_all, commit_file, write_commit_file,
+    write_commit_file_with_message,
+  },
   init::init_folder_as_repo,
   pull::pull,
   pull_or_clone::pull_or_clone,
@@ -107,3 +111,39 @@ pub async fn get_remote_url(path: &Path) -> anyhow::Result<String> {
     ))
   }
 }
+
+/// Checks out only the given paths using `git sparse-checkout`.
+/// If no paths are given, disables sparse checkout
+/// in case it was enabled by a previous clone.
+pub(crate) async fn set_sparse_checkout(
+  repo_dir: &Path,
+  paths: &[String],
+  logs: &mut Vec<Log>,
+) {
+  if paths.is_empty() {
+    if repo_dir.join(".git/info/sparse-checkout").exists() {
+      logs.push(
+        run_komodo_command(
+          "Disable Sparse Checkout",
+          repo_dir,
+          "git sparse-checkout disable",
+        )
+        .await,
+      );
+    }
+    return;
+  }
+  let paths = paths
+    .iter()
+    .map(|path| format!("'{}'", path.replace('\'', "'\\''")))
+    .collect::<Vec<_>>()
+    .join(" ");
+  logs.push(
+    run_komodo_command(
+      "Sparse Checkout",
+      repo_dir,
+      format!("git sparse-checkout set {paths}"),
+    )
+    .await,
+  );
+}
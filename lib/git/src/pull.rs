@@ -11,7 +11,7 @@ use komodo_client::entities::{
   komodo_timestamp, update::Log,
 };
 
-use crate::get_commit_hash_log;
+use crate::{get_commit_hash_log, set_sparse_checkout};
 
 /// Wait this long after a pull to allow another pull through
 const PULL_TIMEOUT: i64 = 5_000;
@@ -135,6 +135,22 @@ where
     }
 
     if let Some(commit) = args.commit {
+      // The commit may not be included in a shallow clone
+      if args.clone_depth > 0 {
+        let fetch_log = run_komodo_command(
+          "Fetch commit",
+          res.path.as_ref(),
+          format!(
+            "git fetch --depth {} origin {commit}",
+            args.clone_depth
+          ),
+        )
+        .await;
+        res.logs.push(fetch_log);
+        if !all_logs_success(&res.logs) {
+          return Ok(res);
+        }
+      }
       let reset_log = run_komodo_command(
         "Set commit",
         res.path.as_ref(),
@@ -147,6 +163,17 @@ where
       }
     }
 
+    // Apply any changes to the sparse checkout paths
+    set_sparse_checkout(
+      &res.path,
+      &args.sparse_checkout,
+      &mut res.logs,
+    )
+    .await;
+    if !all_logs_success(&res.logs) {
+      return Ok(res);
+    }
+
     match get_commit_hash_log(&res.path).await {
       Ok((log, hash, message)) => {
         res.logs.push(log);
@@ -1,16 +1,30 @@
 use std::collections::{HashMap, HashSet};
 
-use anyhow::Context;
+use anyhow::{Context, anyhow};
 use komodo_client::entities::{
-  EnvironmentVar, build::Build, deployment::Deployment, repo::Repo,
-  stack::Stack, update::Log,
+  EnvironmentVar,
+  build::{Build, BuildSecret},
+  deployment::Deployment,
+  repo::Repo,
+  stack::Stack,
+  update::Log,
 };
 
+/// Max rounds of interpolation for nested values.
+const MAX_INTERPOLATION_ROUNDS: usize = 5;
+
 pub struct Interpolator<'a> {
   variables: Option<&'a HashMap<String, String>>,
   secrets: &'a HashMap<String, String>,
   variable_replacers: HashSet<(String, String)>,
   pub secret_replacers: HashSet<(String, String)>,
+  /// Error if any `[[...]]` token is left unresolved.
+  strict: bool,
+  /// Only run the secrets pass, see `skip_variable_interp`.
+  skip_variables: bool,
+  /// Secrets which Periphery interpolates after Core,
+  /// which strict interpolation allows to remain.
+  periphery_secrets: HashSet<String>,
 }
 
 impl<'a> Interpolator<'a> {
@@ -23,9 +37,38 @@ impl<'a> Interpolator<'a> {
       secrets,
       variable_replacers: Default::default(),
       secret_replacers: Default::default(),
+      strict: false,
+      skip_variables: false,
+      periphery_secrets: Default::default(),
+    }
+  }
+
+  /// Like [Interpolator::new], but interpolation will return an error
+  /// if any `[[name]]` token remains which doesn't match a variable or secret,
+  /// rather than leaving the token in place.
+  ///
+  /// Tokens for variables are allowed to remain when
+  /// `skip_variable_interp` is set.
+  pub fn new_strict(
+    variables: Option<&'a HashMap<String, String>>,
+    secrets: &'a HashMap<String, String>,
+  ) -> Interpolator<'a> {
+    Interpolator {
+      strict: true,
+      ..Interpolator::new(variables, secrets)
     }
   }
 
+  /// Allow the tokens for the secrets configured on the Periphery
+  /// to remain in strict mode, as they are interpolated there.
+  pub fn with_periphery_secrets(
+    mut self,
+    periphery_secrets: impl IntoIterator<Item = String>,
+  ) -> Interpolator<'a> {
+    self.periphery_secrets.extend(periphery_secrets);
+    self
+  }
+
   pub fn interpolate_stack(
     &mut self,
     stack: &mut Stack,
@@ -33,13 +76,20 @@ impl<'a> Interpolator<'a> {
     if stack.config.skip_secret_interp {
       return Ok(self);
     }
-    self
-      .interpolate_string(&mut stack.config.file_contents)?
-      .interpolate_string(&mut stack.config.environment)?
-      .interpolate_string(&mut stack.config.pre_deploy.command)?
-      .interpolate_string(&mut stack.config.post_deploy.command)?
-      .interpolate_extra_args(&mut stack.config.extra_args)?
-      .interpolate_extra_args(&mut stack.config.build_extra_args)
+    self.with_skip_variables(
+      stack.config.skip_variable_interp,
+      |interpolator| {
+        interpolator
+          .interpolate_string(&mut stack.config.file_contents)?
+          .interpolate_string(&mut stack.config.environment)?
+          .interpolate_string(&mut stack.config.pre_deploy.command)?
+          .interpolate_string(&mut stack.config.post_deploy.command)?
+          .interpolate_string(&mut stack.config.on_deploy.command)?
+          .interpolate_string(&mut stack.config.on_destroy.command)?
+          .interpolate_extra_args(&mut stack.config.extra_args)?
+          .interpolate_extra_args(&mut stack.config.build_extra_args)
+      },
+    )
   }
 
   pub fn interpolate_repo(
@@ -49,10 +99,15 @@ impl<'a> Interpolator<'a> {
     if repo.config.skip_secret_interp {
       return Ok(self);
     }
-    self
-      .interpolate_string(&mut repo.config.environment)?
-      .interpolate_string(&mut repo.config.on_clone.command)?
-      .interpolate_string(&mut repo.config.on_pull.command)
+    self.with_skip_variables(
+      repo.config.skip_variable_interp,
+      |interpolator| {
+        interpolator
+          .interpolate_string(&mut repo.config.environment)?
+          .interpolate_string(&mut repo.config.on_clone.command)?
+          .interpolate_string(&mut repo.config.on_pull.command)
+      },
+    )
   }
 
   pub fn interpolate_build(
@@ -62,13 +117,22 @@ impl<'a> Interpolator<'a> {
     if build.config.skip_secret_interp {
       return Ok(self);
     }
-    self
-      .interpolate_string(&mut build.config.build_args)?
-      .interpolate_string(&mut build.config.secret_args)?
-      .interpolate_string(&mut build.config.labels)?
-      .interpolate_string(&mut build.config.pre_build.command)?
-      .interpolate_string(&mut build.config.dockerfile)?
-      .interpolate_extra_args(&mut build.config.extra_args)
+    self.with_skip_variables(
+      build.config.skip_variable_interp,
+      |interpolator| {
+        interpolator
+          .interpolate_string(&mut build.config.build_args)?
+          .interpolate_string(&mut build.config.secret_args)?
+          .interpolate_string(&mut build.config.labels)?
+          .interpolate_string(&mut build.config.pre_build.command)?
+          .interpolate_string(&mut build.config.dockerfile)?
+          .interpolate_build_secrets(&mut build.config.build_secrets)?
+          .interpolate_extra_args(&mut build.config.extra_args)?
+          .interpolate_extra_args(&mut build.config.cache_from)?
+          .interpolate_extra_args(&mut build.config.cache_to)?
+          .interpolate_extra_args(&mut build.config.build_contexts)
+      },
+    )
   }
 
   pub fn interpolate_deployment(
@@ -78,13 +142,32 @@ impl<'a> Interpolator<'a> {
     if deployment.config.skip_secret_interp {
       return Ok(self);
     }
-    self
-      .interpolate_string(&mut deployment.config.environment)?
-      .interpolate_string(&mut deployment.config.ports)?
-      .interpolate_string(&mut deployment.config.volumes)?
-      .interpolate_string(&mut deployment.config.labels)?
-      .interpolate_string(&mut deployment.config.command)?
-      .interpolate_extra_args(&mut deployment.config.extra_args)
+    self.with_skip_variables(
+      deployment.config.skip_variable_interp,
+      |interpolator| {
+        interpolator
+          .interpolate_string(&mut deployment.config.environment)?
+          .interpolate_string(&mut deployment.config.ports)?
+          .interpolate_string(&mut deployment.config.volumes)?
+          .interpolate_string(&mut deployment.config.labels)?
+          .interpolate_string(&mut deployment.config.command)?
+          .interpolate_extra_args(&mut deployment.config.extra_args)
+      },
+    )
+  }
+
+  /// Runs the interpolation with the variables pass optionally skipped,
+  /// restoring the previous setting after.
+  fn with_skip_variables(
+    &mut self,
+    skip_variables: bool,
+    interpolate: impl FnOnce(&mut Self) -> anyhow::Result<&mut Self>,
+  ) -> anyhow::Result<&mut Self> {
+    let prev =
+      std::mem::replace(&mut self.skip_variables, skip_variables);
+    let res = interpolate(self).map(|_| ());
+    self.skip_variables = prev;
+    res.map(|_| self)
   }
 
   pub fn interpolate_string(
@@ -95,19 +178,67 @@ impl<'a> Interpolator<'a> {
       return Ok(self);
     }
 
+    // Variables and secrets can themselves contain
+    // interpolation tokens, eg a variable with value `[[db_password]]`.
+    // Repeat the passes until the result stops changing,
+    // bounded to avoid looping forever on self referential values.
+    let mut res = target.to_string();
+    for _ in 0..MAX_INTERPOLATION_ROUNDS {
+      let next = self.interpolate_round(&res).with_context(|| {
+        format!("failed to interpolate into target '{target}'")
+      })?;
+      if next == res {
+        break;
+      }
+      res = next;
+    }
+
+    if self.strict {
+      let unresolved = unresolved_tokens(&res)
+        .into_iter()
+        .filter(|name| !self.is_deferred(name))
+        .map(|name| format!("[[{name}]]"))
+        .collect::<Vec<_>>();
+      if !unresolved.is_empty() {
+        return Err(anyhow!(
+          "No variable or secret found for {}",
+          unresolved.join(", ")
+        ));
+      }
+    }
+
+    // Set with result
+    *target = res;
+
+    Ok(self)
+  }
+
+  /// Whether the token is left for Periphery, or is a variable
+  /// while variable interpolation is skipped.
+  fn is_deferred(&self, name: &str) -> bool {
+    self.periphery_secrets.contains(name)
+      || (self.skip_variables
+        && self
+          .variables
+          .is_some_and(|variables| variables.contains_key(name)))
+  }
+
+  /// A single variables pass followed by a secrets pass.
+  fn interpolate_round(
+    &mut self,
+    target: &str,
+  ) -> anyhow::Result<String> {
     // first pass - variables
-    let res = if let Some(variables) = self.variables {
+    let res = if let Some(variables) = self.variables
+      && !self.skip_variables
+    {
       let (res, more_replacers) = svi::interpolate_variables(
         target,
         variables,
         svi::Interpolator::DoubleBrackets,
         false,
       )
-      .with_context(|| {
-        format!(
-          "failed to interpolate variables into target '{target}'",
-        )
-      })?;
+      .context("failed to interpolate variables")?;
       self.variable_replacers.extend(more_replacers);
       res
     } else {
@@ -121,15 +252,10 @@ impl<'a> Interpolator<'a> {
       svi::Interpolator::DoubleBrackets,
       false,
     )
-    .with_context(|| {
-      format!("failed to interpolate secrets into target '{target}'",)
-    })?;
+    .context("failed to interpolate secrets")?;
     self.secret_replacers.extend(more_replacers);
 
-    // Set with result
-    *target = res;
-
-    Ok(self)
+    Ok(res)
   }
 
   pub fn interpolate_extra_args(
@@ -144,6 +270,23 @@ impl<'a> Interpolator<'a> {
     Ok(self)
   }
 
+  pub fn interpolate_build_secrets(
+    &mut self,
+    build_secrets: &mut Vec<BuildSecret>,
+  ) -> anyhow::Result<&mut Self> {
+    for secret in build_secrets {
+      self.interpolate_string(&mut secret.src).with_context(
+        || {
+          format!(
+            "failed interpolation into build secret {}",
+            secret.id
+          )
+        },
+      )?;
+    }
+    Ok(self)
+  }
+
   pub fn interpolate_env_vars(
     &mut self,
     env_vars: &mut Vec<EnvironmentVar>,
@@ -179,3 +322,175 @@ impl<'a> Interpolator<'a> {
     }
   }
 }
+
+/// Finds the names of any remaining `[[name]]` tokens,
+/// deduplicated in order of appearance.
+/// Only names made of word characters, `-` and `.` are matched,
+/// so eg. bash tests like `[[ -f file ]]` are ignored.
+fn unresolved_tokens(target: &str) -> Vec<&str> {
+  let mut names = Vec::new();
+  let mut rest = target;
+  while let Some(start) = rest.find("[[") {
+    rest = &rest[start + 2..];
+    let Some(end) = rest.find("]]") else {
+      break;
+    };
+    let name = &rest[..end];
+    if !is_token_name(name) {
+      continue;
+    }
+    if !names.contains(&name) {
+      names.push(name);
+    }
+    rest = &rest[end + 2..];
+  }
+  names
+}
+
+fn is_token_name(name: &str) -> bool {
+  name.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+    && name.chars().all(|c| {
+      c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn interpolates_build_contexts_in_order() {
+    let secrets = HashMap::from([(
+      String::from("SHARED_PATH"),
+      String::from("../shared"),
+    )]);
+    let mut build = Build::default();
+    build.config.build_contexts = vec![
+      String::from("shared=[[SHARED_PATH]]"),
+      String::from("docs=../docs"),
+    ];
+    Interpolator::new(None, &secrets)
+      .interpolate_build(&mut build)
+      .unwrap();
+    assert_eq!(
+      build.config.build_contexts,
+      ["shared=../shared", "docs=../docs"]
+    );
+  }
+
+  fn secrets() -> HashMap<String, String> {
+    HashMap::from([(
+      String::from("db_password"),
+      String::from("hunter2"),
+    )])
+  }
+
+  #[test]
+  fn strict_error_names_missing_token() {
+    let secrets = secrets();
+    let mut deployment = Deployment::default();
+    deployment.config.environment = String::from(
+      "DB_PASSWORD=[[db_password]]\nAPI_KEY=[[api_key]]",
+    );
+    let err = Interpolator::new_strict(None, &secrets)
+      .interpolate_deployment(&mut deployment)
+      .map(|_| ())
+      .unwrap_err();
+    let err = format!("{err:#}");
+    assert!(err.contains("[[api_key]]"), "{err}");
+    assert!(!err.contains("[[db_password]]"), "{err}");
+  }
+
+  #[test]
+  fn lenient_leaves_missing_token() {
+    let secrets = secrets();
+    let mut target = String::from("API_KEY=[[api_key]]");
+    Interpolator::new(None, &secrets)
+      .interpolate_string(&mut target)
+      .unwrap();
+    assert_eq!(target, "API_KEY=[[api_key]]");
+  }
+
+  #[test]
+  fn strict_ignores_bash_tests() {
+    let secrets = secrets();
+    let mut target = String::from(
+      "if [[ -f /config ]]; then echo [[db_password]]; fi",
+    );
+    Interpolator::new_strict(None, &secrets)
+      .interpolate_string(&mut target)
+      .unwrap();
+    assert_eq!(target, "if [[ -f /config ]]; then echo hunter2; fi");
+  }
+
+  #[test]
+  fn strict_allows_skipped_variables() {
+    let variables =
+      HashMap::from([(String::from("host"), String::from("db"))]);
+    let secrets = secrets();
+    let mut deployment = Deployment::default();
+    deployment.config.skip_variable_interp = true;
+    deployment.config.environment = String::from("DB_HOST=[[host]]");
+    Interpolator::new_strict(Some(&variables), &secrets)
+      .interpolate_deployment(&mut deployment)
+      .unwrap();
+    assert_eq!(deployment.config.environment, "DB_HOST=[[host]]");
+  }
+
+  #[test]
+  fn strict_allows_periphery_secrets() {
+    let secrets = secrets();
+    let mut target = String::from("TOKEN=[[periphery_token]]");
+    Interpolator::new_strict(None, &secrets)
+      .with_periphery_secrets([String::from("periphery_token")])
+      .interpolate_string(&mut target)
+      .unwrap();
+    assert_eq!(target, "TOKEN=[[periphery_token]]");
+  }
+
+  #[test]
+  fn unresolved_tokens_only_match_names() {
+    assert_eq!(
+      unresolved_tokens(
+        "[[a]] [[ b ]] [[c.d-e_f]] [[a]] [[[[g]] [[$(h)]]"
+      ),
+      ["a", "c.d-e_f", "g"]
+    );
+  }
+
+  #[test]
+  fn variable_resolves_to_secret() {
+    let variables = HashMap::from([(
+      String::from("DB_PASSWORD"),
+      String::from("[[db_password]]"),
+    )]);
+    let secrets = secrets();
+    let mut target = String::from("PASSWORD=[[DB_PASSWORD]]");
+    let mut interpolator =
+      Interpolator::new(Some(&variables), &secrets);
+    interpolator.interpolate_string(&mut target).unwrap();
+    assert_eq!(target, "PASSWORD=hunter2");
+    // The nested secret is still sanitized from logs.
+    assert!(interpolator.secret_replacers.contains(&(
+      String::from("hunter2"),
+      String::from("db_password")
+    )));
+  }
+
+  #[test]
+  fn self_referencing_value_stops_at_round_limit() {
+    let variables = HashMap::from([(
+      String::from("loop"),
+      String::from("x[[loop]]"),
+    )]);
+    let secrets = HashMap::new();
+    let mut target = String::from("[[loop]]");
+    Interpolator::new(Some(&variables), &secrets)
+      .interpolate_string(&mut target)
+      .unwrap();
+    assert_eq!(
+      target,
+      format!("{}[[loop]]", "x".repeat(MAX_INTERPOLATION_ROUNDS))
+    );
+  }
+}